@@ -0,0 +1,107 @@
+use crate::storage::row::Row;
+use crate::types::column_value::ColumnValue;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// The counter of the auto-increment column of a table, which hands out sequential values
+/// (starting at 1) to the rows inserted with `Null` in the column.
+///
+/// The counter only moves forward: a value is never handed out again, even after the row holding
+/// it is deleted, and a value inserted explicitly moves the counter past it.
+pub(crate) struct AutoIncrement {
+    position: usize,
+    next_value: AtomicI64,
+}
+
+impl AutoIncrement {
+    /// Creates an `AutoIncrement` for the column at the given position.
+    pub(crate) fn new(position: usize) -> Self {
+        Self {
+            position,
+            next_value: AtomicI64::new(1),
+        }
+    }
+
+    /// Returns the value the counter hands out next.
+    pub(crate) fn next_value(&self) -> i64 {
+        self.next_value.load(Ordering::SeqCst)
+    }
+
+    /// Moves the counter to the given value, unless it is already past it.
+    pub(crate) fn move_to(&self, next_value: i64) {
+        self.next_value.fetch_max(next_value, Ordering::SeqCst);
+    }
+
+    /// Fills the auto-increment column of the row with the next value if it is `Null`, or moves
+    /// the counter past its value otherwise.
+    pub(crate) fn fill(&self, row: &mut Row) {
+        match row.column_value_at(self.position) {
+            Some(ColumnValue::Int(value)) => {
+                self.next_value
+                    .fetch_max(value.saturating_add(1), Ordering::SeqCst);
+            }
+            _ => {
+                let value = self.next_value.fetch_add(1, Ordering::SeqCst);
+                row.replace_at(self.position, ColumnValue::Int(value));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::row;
+
+    #[test]
+    fn fill_rows_with_sequential_values() {
+        let auto_increment = AutoIncrement::new(0);
+
+        let mut first = Row::filled(vec![ColumnValue::null(), ColumnValue::text("relop")]);
+        let mut second = Row::filled(vec![ColumnValue::null(), ColumnValue::text("query")]);
+        auto_increment.fill(&mut first);
+        auto_increment.fill(&mut second);
+
+        assert_eq!(row![1, "relop"], first);
+        assert_eq!(row![2, "query"], second);
+    }
+
+    #[test]
+    fn move_past_an_explicit_value() {
+        let auto_increment = AutoIncrement::new(0);
+
+        let mut explicit = row![10];
+        let mut filled = Row::single(ColumnValue::null());
+        auto_increment.fill(&mut explicit);
+        auto_increment.fill(&mut filled);
+
+        assert_eq!(row![10], explicit);
+        assert_eq!(row![11], filled);
+    }
+
+    #[test]
+    fn do_not_move_back_for_a_smaller_explicit_value() {
+        let auto_increment = AutoIncrement::new(0);
+
+        let mut first = Row::single(ColumnValue::null());
+        let mut explicit = row![-5];
+        let mut second = Row::single(ColumnValue::null());
+        auto_increment.fill(&mut first);
+        auto_increment.fill(&mut explicit);
+        auto_increment.fill(&mut second);
+
+        assert_eq!(row![1], first);
+        assert_eq!(row![2], second);
+    }
+
+    #[test]
+    fn move_to_a_saved_next_value() {
+        let auto_increment = AutoIncrement::new(0);
+        auto_increment.move_to(5);
+
+        let mut row = Row::single(ColumnValue::null());
+        auto_increment.fill(&mut row);
+
+        assert_eq!(row![5], row);
+        assert_eq!(6, auto_increment.next_value());
+    }
+}
@@ -0,0 +1,308 @@
+use crate::catalog::error::LoadCsvError;
+use crate::schema::Schema;
+use crate::storage::row::Row;
+use crate::types::column_type::ColumnType;
+use crate::types::column_value::ColumnValue;
+use crate::types::date::Date;
+
+/// A field of a CSV record, along with whether it was enclosed in double quotes.
+///
+/// An empty field that was not quoted is loaded as `Null`, whereas `""` is loaded as an empty
+/// text.
+#[derive(Debug, PartialEq)]
+pub(crate) struct CsvField {
+    value: String,
+    quoted: bool,
+}
+
+impl CsvField {
+    fn new(value: String, quoted: bool) -> Self {
+        Self { value, quoted }
+    }
+}
+
+/// A CSV record, along with its (1-based) number in the input.
+#[derive(Debug, PartialEq)]
+pub(crate) struct CsvRecord {
+    number: usize,
+    fields: Vec<CsvField>,
+}
+
+/// Splits the CSV input into records.
+///
+/// Fields are separated by commas and records by `\n` or `\r\n`. Fields may be enclosed in
+/// double quotes (as in RFC 4180), in which case they may contain commas, line breaks and
+/// doubled double quotes. Empty lines are skipped.
+pub(crate) fn read_records(input: &str) -> Result<Vec<CsvRecord>, LoadCsvError> {
+    let mut records = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while chars.peek().is_some() {
+        let number = records.len() + 1;
+        let mut fields = Vec::new();
+        let mut value = String::new();
+        let mut quoted = false;
+
+        loop {
+            match chars.next() {
+                Some('"') if value.is_empty() && !quoted => {
+                    quoted = true;
+                    loop {
+                        match chars.next() {
+                            Some('"') if chars.peek() == Some(&'"') => {
+                                chars.next();
+                                value.push('"');
+                            }
+                            Some('"') => break,
+                            Some(ch) => value.push(ch),
+                            None => {
+                                return Err(LoadCsvError::UnterminatedQuotedField { row: number })
+                            }
+                        }
+                    }
+                }
+                Some(',') => {
+                    fields.push(CsvField::new(std::mem::take(&mut value), quoted));
+                    quoted = false;
+                }
+                Some('\r') if chars.peek() == Some(&'\n') => {}
+                Some('\n') | None => break,
+                Some(ch) => value.push(ch),
+            }
+        }
+
+        if fields.is_empty() && value.is_empty() && !quoted {
+            continue;
+        }
+        fields.push(CsvField::new(value, quoted));
+        records.push(CsvRecord { number, fields });
+    }
+    Ok(records)
+}
+
+/// Checks that the header record names the columns of the table, in order.
+///
+/// A header field may either be the column name, or the column name qualified with the table
+/// name (as in the header of [`crate::query::executor::result::QueryResult::to_csv`]).
+pub(crate) fn check_header(
+    header: &CsvRecord,
+    table_name: &str,
+    schema: &Schema,
+) -> Result<(), LoadCsvError> {
+    let expected = schema.column_names();
+    let matches = header.fields.len() == expected.len()
+        && header
+            .fields
+            .iter()
+            .zip(&expected)
+            .all(|(field, column_name)| {
+                field.value == *column_name
+                    || field.value == format!("{}.{}", table_name, column_name)
+            });
+
+    if !matches {
+        return Err(LoadCsvError::HeaderMismatch {
+            expected: expected.iter().map(|name| name.to_string()).collect(),
+            actual: header
+                .fields
+                .iter()
+                .map(|field| field.value.clone())
+                .collect(),
+        });
+    }
+    Ok(())
+}
+
+/// Converts a CSV record to a `Row`, parsing each field as the type of its column.
+pub(crate) fn to_row(record: CsvRecord, schema: &Schema) -> Result<Row, LoadCsvError> {
+    if record.fields.len() != schema.column_count() {
+        return Err(LoadCsvError::FieldCountMismatch {
+            row: record.number,
+            expected: schema.column_count(),
+            actual: record.fields.len(),
+        });
+    }
+
+    let mut values = Vec::with_capacity(record.fields.len());
+    for (field, column) in record.fields.into_iter().zip(schema.columns()) {
+        if field.value.is_empty() && !field.quoted {
+            values.push(ColumnValue::null());
+            continue;
+        }
+        let value = match column.column_type() {
+            ColumnType::Int => field.value.trim().parse::<i64>().ok().map(ColumnValue::int),
+            ColumnType::Text => Some(ColumnValue::text(field.value.as_str())),
+            ColumnType::Bool => match field.value.trim().to_lowercase().as_str() {
+                "true" => Some(ColumnValue::bool(true)),
+                "false" => Some(ColumnValue::bool(false)),
+                _ => None,
+            },
+            ColumnType::Date => Date::parse(field.value.trim()).map(ColumnValue::date),
+        };
+        match value {
+            Some(value) => values.push(value),
+            None => {
+                return Err(LoadCsvError::InvalidValue {
+                    row: record.number,
+                    column: column.name().to_string(),
+                    expected: column.column_type().clone(),
+                    value: field.value,
+                })
+            }
+        }
+    }
+    Ok(Row::filled(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema;
+
+    fn fields(record: &CsvRecord) -> Vec<&str> {
+        record
+            .fields
+            .iter()
+            .map(|field| field.value.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn read_records_separated_by_line_breaks() {
+        let records = read_records("1,relop\n2,query\r\n").unwrap();
+
+        assert_eq!(2, records.len());
+        assert_eq!(vec!["1", "relop"], fields(&records[0]));
+        assert_eq!(vec!["2", "query"], fields(&records[1]));
+    }
+
+    #[test]
+    fn read_records_without_a_trailing_line_break() {
+        let records = read_records("1,relop").unwrap();
+
+        assert_eq!(1, records.len());
+        assert_eq!(vec!["1", "relop"], fields(&records[0]));
+    }
+
+    #[test]
+    fn read_records_skipping_empty_lines() {
+        let records = read_records("1,relop\n\n2,query\n").unwrap();
+
+        assert_eq!(2, records.len());
+        assert_eq!(1, records[0].number);
+        assert_eq!(2, records[1].number);
+    }
+
+    #[test]
+    fn read_records_with_quoted_fields() {
+        let records = read_records("1,\"relop, \"\"the\"\"\nlibrary\"\n").unwrap();
+
+        assert_eq!(1, records.len());
+        assert_eq!(vec!["1", "relop, \"the\"\nlibrary"], fields(&records[0]));
+        assert!(records[0].fields[1].quoted);
+    }
+
+    #[test]
+    fn read_records_with_empty_fields() {
+        let records = read_records("1,,\"\"\n").unwrap();
+
+        assert_eq!(
+            vec![
+                CsvField::new("1".to_string(), false),
+                CsvField::new("".to_string(), false),
+                CsvField::new("".to_string(), true),
+            ],
+            records[0].fields
+        );
+    }
+
+    #[test]
+    fn attempt_to_read_records_with_an_unterminated_quoted_field() {
+        let result = read_records("1,relop\n2,\"query\n");
+
+        assert!(matches!(
+            result,
+            Err(LoadCsvError::UnterminatedQuotedField { row }) if row == 2
+        ));
+    }
+
+    #[test]
+    fn check_header_with_column_names() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let records = read_records("id,employees.name\n").unwrap();
+
+        assert!(check_header(&records[0], "employees", &schema).is_ok());
+    }
+
+    #[test]
+    fn attempt_to_check_header_with_different_column_names() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let records = read_records("name,id\n").unwrap();
+
+        let result = check_header(&records[0], "employees", &schema);
+        assert!(matches!(
+            result,
+            Err(LoadCsvError::HeaderMismatch { expected, actual })
+                if expected == vec!["id", "name"] && actual == vec!["name", "id"]
+        ));
+    }
+
+    #[test]
+    fn record_to_row() {
+        let schema = schema![
+            "id" => ColumnType::Int,
+            "name" => ColumnType::Text,
+            "active" => ColumnType::Bool
+        ]
+        .unwrap();
+        let mut records = read_records("1,relop,TRUE\n").unwrap();
+
+        let row = to_row(records.remove(0), &schema).unwrap();
+        assert_eq!(
+            Row::filled(vec![
+                ColumnValue::int(1),
+                ColumnValue::text("relop"),
+                ColumnValue::bool(true)
+            ]),
+            row
+        );
+    }
+
+    #[test]
+    fn record_with_empty_fields_to_row() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let mut records = read_records(",\"\"\n").unwrap();
+
+        let row = to_row(records.remove(0), &schema).unwrap();
+        assert_eq!(
+            Row::filled(vec![ColumnValue::null(), ColumnValue::text("")]),
+            row
+        );
+    }
+
+    #[test]
+    fn attempt_to_convert_record_with_invalid_int_to_row() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let mut records = read_records("1,relop\none,query\n").unwrap();
+
+        let result = to_row(records.remove(1), &schema);
+        assert!(matches!(
+            result,
+            Err(LoadCsvError::InvalidValue { row, column, expected, value })
+                if row == 2 && column == "id" && expected == ColumnType::Int && value == "one"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_convert_record_with_fewer_fields_to_row() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let mut records = read_records("1\n").unwrap();
+
+        let result = to_row(records.remove(0), &schema);
+        assert!(matches!(
+            result,
+            Err(LoadCsvError::FieldCountMismatch { row, expected, actual })
+                if row == 1 && expected == 2 && actual == 1
+        ));
+    }
+}
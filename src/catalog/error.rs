@@ -1,4 +1,7 @@
 use crate::schema::error::SchemaError;
+use crate::storage::primary_key_index::PrimaryKeyColumnValues;
+use crate::types::column_type::ColumnType;
+use crate::types::column_value::ColumnValue;
 
 /// Represents errors that can occur during catalog operations.
 #[derive(Debug, PartialEq)]
@@ -7,6 +10,10 @@ pub enum CatalogError {
     TableAlreadyExists(String),
     /// Indicates that a table with the given name does not exist.
     TableDoesNotExist(String),
+    /// Indicates that a column with the given name does not exist in the table.
+    ColumnDoesNotExist(String),
+    /// Indicates that the column with the given name is already indexed.
+    IndexAlreadyExists(String),
 }
 
 /// Represents errors that can occur during data insertion.
@@ -16,4 +23,84 @@ pub enum InsertError {
     Catalog(CatalogError),
     /// Errors related to schema validation (e.g., type mismatch).
     Schema(SchemaError),
+    /// Indicates that a row has the same primary key as another row of the table.
+    DuplicatePrimaryKey(PrimaryKeyColumnValues),
+    /// Indicates that the given primary key column of a row is `Null`.
+    NullPrimaryKey(String),
+    /// Indicates that a row has the same value in a uniquely-constrained column as another row
+    /// of the table.
+    DuplicateUniqueValue {
+        /// The name of the uniquely-constrained column.
+        column: String,
+        /// The duplicate value.
+        value: ColumnValue,
+    },
+    /// Errors of a row of a stream of rows (inserted via `insert_stream`).
+    Row {
+        /// The (0-based) index of the failing row in the stream.
+        index: usize,
+        /// The error of the row.
+        error: Box<InsertError>,
+    },
+}
+
+/// Represents errors that can occur while loading the rows of a table from CSV.
+///
+/// Rows are numbered from 1, in the order of the records in the input (including the header
+/// record, if present).
+#[derive(Debug, PartialEq)]
+pub enum LoadCsvError {
+    /// Errors related to catalog operations (e.g., table not found).
+    Catalog(CatalogError),
+    /// Indicates that the input could not be read.
+    Io(String),
+    /// Indicates that a quoted field is not closed before the end of the input.
+    UnterminatedQuotedField {
+        /// The row containing the field.
+        row: usize,
+    },
+    /// Indicates that the header does not name the columns of the table, in order.
+    HeaderMismatch {
+        /// The column names of the table.
+        expected: Vec<String>,
+        /// The names in the header.
+        actual: Vec<String>,
+    },
+    /// Indicates that a row does not have one field for every column of the table.
+    FieldCountMismatch {
+        /// The row with the mismatching field count.
+        row: usize,
+        /// The number of columns in the table.
+        expected: usize,
+        /// The number of fields in the row.
+        actual: usize,
+    },
+    /// Indicates that a field could not be parsed as the type of its column.
+    InvalidValue {
+        /// The row containing the field.
+        row: usize,
+        /// The name of the column.
+        column: String,
+        /// The type of the column.
+        expected: ColumnType,
+        /// The field which could not be parsed.
+        value: String,
+    },
+    /// Errors related to inserting the loaded rows.
+    Insert(InsertError),
+}
+
+/// Represents errors that can occur while saving a catalog to a file, or loading it from a file.
+#[derive(Debug, PartialEq)]
+pub enum PersistenceError {
+    /// Indicates that the file could not be read or written.
+    Io(String),
+    /// Indicates that the file is not a saved catalog, or that it is corrupted.
+    InvalidFormat(String),
+    /// Errors related to the schema of a loaded table (e.g., a duplicate column name).
+    Schema(SchemaError),
+    /// Errors related to creating a loaded table.
+    Catalog(CatalogError),
+    /// Errors related to inserting the loaded rows.
+    Insert(InsertError),
 }
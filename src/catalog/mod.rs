@@ -1,17 +1,25 @@
-use crate::catalog::error::{CatalogError, InsertError};
+use crate::catalog::error::{CatalogError, InsertError, LoadCsvError, PersistenceError};
 use crate::catalog::table::Table;
 use crate::catalog::table_entry::TableEntry;
+use crate::catalog::table_statistics::TableStatistics;
 use crate::schema::Schema;
 use crate::storage::batch::Batch;
 use crate::storage::row::Row;
 use crate::storage::table_store::RowId;
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
 use std::sync::{Arc, RwLock};
 
+pub(crate) mod auto_increment;
+pub(crate) mod csv;
 pub mod error;
+pub(crate) mod persistence;
 pub mod table;
 pub(crate) mod table_entry;
 pub mod table_scan;
+pub mod table_statistics;
 
 /// Manages the database tables and their associated memory storage.
 pub struct Catalog {
@@ -34,6 +42,82 @@ impl Catalog {
         })
     }
 
+    /// Loads a catalog from the file at the given path, written by [`Catalog::save`].
+    ///
+    /// The tables are created with their saved schemas (including their primary keys), and their
+    /// rows are inserted in the order in which they were saved, which rebuilds the primary key
+    /// indexes. The counters of the auto-increment columns continue from their saved values. The
+    /// `RowId`s of the loaded rows start at 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    ///
+    /// let path = std::env::temp_dir().join("relop_catalog_load_example");
+    /// Catalog::new().save(&path).unwrap();
+    ///
+    /// let catalog = Catalog::load(&path).unwrap();
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> Result<Arc<Catalog>, PersistenceError> {
+        let file = File::open(path).map_err(|error| PersistenceError::Io(error.to_string()))?;
+        let tables = persistence::read_tables(&mut BufReader::new(file))?;
+
+        let catalog = Catalog::new();
+        for table in tables {
+            catalog
+                .create_table(&table.name, table.schema)
+                .map_err(PersistenceError::Catalog)?;
+            if let Some(next_value) = table.next_auto_increment_value {
+                catalog
+                    .table_entry_or_error(&table.name)
+                    .map_err(PersistenceError::Catalog)?
+                    .restore_auto_increment(next_value);
+            }
+            catalog
+                .insert_all_into(&table.name, table.rows)
+                .map_err(PersistenceError::Insert)?;
+        }
+        Ok(catalog)
+    }
+
+    /// Saves the schemas and the rows of all the tables to the file at the given path,
+    /// replacing the file if it exists.
+    ///
+    /// The rows of a table are saved in the order of their `RowId`s, and the indexes created with
+    /// `create_index` are not saved. Rows inserted, updated or deleted concurrently with `save`
+    /// may or may not be saved.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), PersistenceError> {
+        let mut table_entries = self
+            .tables
+            .read()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        table_entries.sort_by(|left, right| left.table_ref().name().cmp(right.table_ref().name()));
+
+        let file = File::create(path).map_err(|error| PersistenceError::Io(error.to_string()))?;
+        let mut writer = BufWriter::new(file);
+        persistence::write_tables(
+            &mut writer,
+            table_entries.iter().map(|table_entry| {
+                let table = table_entry.table_ref();
+                let rows = table_entry.entries().map(|(_, row)| row).collect();
+                (
+                    table.name(),
+                    table.schema_ref(),
+                    table_entry.next_auto_increment_value(),
+                    rows,
+                )
+            }),
+        )?;
+        writer
+            .flush()
+            .map_err(|error| PersistenceError::Io(error.to_string()))
+    }
+
     /// Creates a new table with the given name and schema.
     ///
     /// Returns an error if a table with the same name already exists.
@@ -55,13 +139,42 @@ impl Catalog {
         Ok(())
     }
 
-    /// Returns a list of all table names in the catalog.
+    /// Drops the table with the given name, along with its rows.
+    ///
+    /// Returns an error if the table does not exist.
+    pub(crate) fn drop_table(&self, table_name: &str) -> Result<(), CatalogError> {
+        let mut tables = self.tables.write().unwrap();
+        tables
+            .remove(table_name)
+            .map(|_| ())
+            .ok_or_else(|| CatalogError::TableDoesNotExist(table_name.to_string()))
+    }
+
+    /// Removes all the rows from the table with the given name, keeping its schema.
+    ///
+    /// The `RowId`s of the rows inserted after truncation start at 1 again.
+    /// Returns an error if the table does not exist.
+    pub(crate) fn truncate_table(&self, table_name: &str) -> Result<(), CatalogError> {
+        let table_entry = self.table_entry_or_error(table_name)?;
+        table_entry.truncate();
+        Ok(())
+    }
+
+    /// Returns a list of all table names in the catalog, sorted alphabetically (by bytes, so
+    /// uppercase names come before lowercase ones).
     pub(crate) fn show_tables(&self) -> Vec<String> {
         let tables = self.tables.read().unwrap();
-        tables
+        let mut table_names = tables
             .keys()
             .map(|table_name| table_name.to_string())
-            .collect()
+            .collect::<Vec<_>>();
+        table_names.sort();
+        table_names
+    }
+
+    /// Returns `true` if a table with the given name exists.
+    pub(crate) fn table_exists(&self, table_name: &str) -> bool {
+        self.tables.read().unwrap().contains_key(table_name)
     }
 
     /// Returns the descriptor for the specified table.
@@ -70,6 +183,38 @@ impl Catalog {
         Ok(table_entry.table())
     }
 
+    /// Returns the descriptor (name, columns and primary key) of the specified table, for
+    /// inspecting its schema without executing `describe table`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema::primary_key::PrimaryKey;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let catalog = Catalog::new();
+    /// let relop = Relop::new(catalog.clone());
+    /// let schema = Schema::new()
+    ///     .add_column("id", ColumnType::Int)
+    ///     .unwrap()
+    ///     .add_column("name", ColumnType::Text)
+    ///     .unwrap()
+    ///     .add_primary_key(PrimaryKey::single("id"))
+    ///     .unwrap();
+    /// relop.create_table("employees", schema).unwrap();
+    ///
+    /// let table = catalog.table_schema("employees").unwrap();
+    /// assert_eq!(2, table.column_count());
+    /// assert_eq!(vec!["id", "name"], table.column_names());
+    /// assert_eq!(&["id".to_string()], table.primary_key().unwrap().column_names());
+    /// ```
+    pub fn table_schema(&self, table_name: &str) -> Result<Arc<Table>, CatalogError> {
+        self.describe_table(table_name)
+    }
+
     /// Inserts a single row into the specified table.
     ///
     /// Returns the `RowId` of the inserted row.
@@ -107,6 +252,106 @@ impl Catalog {
         table_entry.insert_all(batch)
     }
 
+    /// Inserts the rows of a stream into the specified table, one by one, as they are pulled.
+    ///
+    /// Stops at the first row which fails to insert, returning its (0-based) index with its error;
+    /// the rows before it remain inserted.
+    ///
+    /// Returns the number of inserted rows.
+    pub(crate) fn insert_stream(
+        &self,
+        table_name: &str,
+        rows: impl Iterator<Item = Row>,
+    ) -> Result<usize, InsertError> {
+        let table_entry = self
+            .table_entry_or_error(table_name)
+            .map_err(InsertError::Catalog)?;
+        let schema = table_entry.table_ref().schema_ref();
+
+        let mut count = 0;
+        for (index, row) in rows.enumerate() {
+            schema
+                .check_type_compatability(row.column_values())
+                .map_err(InsertError::Schema)
+                .and_then(|_| table_entry.insert(row))
+                .map_err(|error| InsertError::Row {
+                    index,
+                    error: Box::new(error),
+                })?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Loads rows from CSV into the specified table.
+    ///
+    /// Each field is parsed as the type of its column: an empty (unquoted) field is loaded
+    /// as `Null`. If `has_header` is true, the first record must name the columns of the table,
+    /// in order. No row is inserted if any of the records fails to load.
+    ///
+    /// Returns the `RowId`s of all inserted rows.
+    pub(crate) fn load_csv(
+        &self,
+        table_name: &str,
+        mut reader: impl Read,
+        has_header: bool,
+    ) -> Result<Vec<RowId>, LoadCsvError> {
+        let schema = self.schema_for(table_name).map_err(LoadCsvError::Catalog)?;
+
+        let mut input = String::new();
+        reader
+            .read_to_string(&mut input)
+            .map_err(|error| LoadCsvError::Io(error.to_string()))?;
+
+        let mut records = csv::read_records(&input)?.into_iter();
+        if has_header {
+            if let Some(header) = records.next() {
+                csv::check_header(&header, table_name, &schema)?;
+            }
+        }
+        let rows = records
+            .map(|record| csv::to_row(record, &schema))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.insert_all_into(table_name, rows)
+            .map_err(LoadCsvError::Insert)
+    }
+
+    /// Deletes the rows with the given `RowId`s from the specified table.
+    ///
+    /// Returns the number of deleted rows.
+    pub(crate) fn delete_from(
+        &self,
+        table_name: &str,
+        row_ids: &[RowId],
+    ) -> Result<usize, CatalogError> {
+        let table_entry = self.table_entry_or_error(table_name)?;
+        Ok(table_entry.delete_all(row_ids))
+    }
+
+    /// Replaces the rows with the given `RowId`s in the specified table.
+    ///
+    /// The rows are type-checked against the schema of the table before any of them is replaced.
+    /// Returns the number of updated rows.
+    pub(crate) fn update_in(
+        &self,
+        table_name: &str,
+        rows: Vec<(RowId, Row)>,
+    ) -> Result<usize, InsertError> {
+        let table_entry = self
+            .table_entry_or_error(table_name)
+            .map_err(InsertError::Catalog)?;
+
+        let schema = table_entry.table_ref().schema_ref();
+        for (_, row) in &rows {
+            schema
+                .check_type_compatability(row.column_values())
+                .map_err(InsertError::Schema)?;
+        }
+
+        table_entry.update_all(rows)
+    }
+
     /// Returns the table entry and table definition for the specified table.
     ///
     /// The caller is responsible for creating the scan iterator from the returned entry.
@@ -118,6 +363,31 @@ impl Catalog {
         Ok((table_entry.clone(), table_entry.table()))
     }
 
+    /// Creates an index on the specified column of the specified table, which the executor uses
+    /// to find the rows matching an equality on the column (e.g., `department_id = 10`).
+    ///
+    /// The index is built from the existing rows, and maintained as rows are inserted, updated
+    /// and deleted. Returns an error if the table or the column does not exist, or if the column
+    /// is already indexed.
+    pub(crate) fn create_index(
+        &self,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<(), CatalogError> {
+        let table_entry = self.table_entry_or_error(table_name)?;
+        table_entry.create_index(column_name)
+    }
+
+    /// Returns the `TableStatistics` for the specified table, computed on the first request after
+    /// the rows of the table change.
+    pub(crate) fn table_statistics(
+        &self,
+        table_name: &str,
+    ) -> Result<Arc<TableStatistics>, CatalogError> {
+        let table_entry = self.table_entry_or_error(table_name)?;
+        Ok(table_entry.statistics())
+    }
+
     /// Returns the `Schema` for the specified table.
     pub(crate) fn schema_for(&self, table_name: &str) -> Result<Arc<Schema>, CatalogError> {
         let table_entry = self.table_entry_or_error(table_name)?;
@@ -154,7 +424,9 @@ mod tests {
     use crate::schema;
     use crate::schema::column::Column;
     use crate::schema::error::SchemaError;
+    use crate::schema::primary_key::PrimaryKey;
     use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
 
     #[test]
     fn create_table() {
@@ -186,6 +458,21 @@ mod tests {
         assert_eq!(vec!["employees"], tables);
     }
 
+    #[test]
+    fn get_all_tables_in_sorted_order() {
+        let catalog = Catalog::new();
+        for table_name in ["roles", "employees", "projects", "departments"] {
+            let result =
+                catalog.create_table(table_name, schema!["id" => ColumnType::Int].unwrap());
+            assert!(result.is_ok());
+        }
+
+        assert_eq!(
+            vec!["departments", "employees", "projects", "roles"],
+            catalog.show_tables()
+        );
+    }
+
     #[test]
     fn get_all_tables_given_no_tables_are_created() {
         let catalog = Catalog::new();
@@ -193,6 +480,86 @@ mod tests {
         assert_eq!(0, tables.len());
     }
 
+    #[test]
+    fn table_exists() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        assert!(catalog.table_exists("employees"));
+        assert!(!catalog.table_exists("departments"));
+    }
+
+    #[test]
+    fn drop_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let result = catalog.drop_table("employees");
+        assert!(result.is_ok());
+
+        assert!(catalog.show_tables().is_empty());
+        assert!(catalog.table_entry("employees").is_none());
+    }
+
+    #[test]
+    fn attempt_to_drop_a_non_existent_table() {
+        let catalog = Catalog::new();
+
+        let result = catalog.drop_table("employees");
+        assert!(
+            matches!(result, Err(CatalogError::TableDoesNotExist(ref table_name)) if table_name == "employees")
+        );
+    }
+
+    #[test]
+    fn drop_table_and_create_it_again() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+        catalog.insert_into("employees", row![1]).unwrap();
+
+        catalog.drop_table("employees").unwrap();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let (table_entry, _) = catalog.scan("employees").unwrap();
+        assert_eq!(0, table_entry.scan().iter().count());
+    }
+
+    #[test]
+    fn truncate_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+        catalog
+            .insert_all_into("employees", rows![[1], [2]])
+            .unwrap();
+
+        catalog.truncate_table("employees").unwrap();
+
+        let (table_entry, _) = catalog.scan("employees").unwrap();
+        assert_eq!(0, table_entry.scan().iter().count());
+        assert_eq!(
+            vec!["id"],
+            catalog.describe_table("employees").unwrap().column_names()
+        );
+
+        let row_id = catalog.insert_into("employees", row![3]).unwrap();
+        assert_eq!(1, row_id);
+    }
+
+    #[test]
+    fn attempt_to_truncate_a_non_existent_table() {
+        let catalog = Catalog::new();
+
+        let result = catalog.truncate_table("employees");
+        assert!(
+            matches!(result, Err(CatalogError::TableDoesNotExist(ref table_name)) if table_name == "employees")
+        );
+    }
+
     #[test]
     fn describe_table_with_name() {
         let catalog = Catalog::new();
@@ -213,6 +580,59 @@ mod tests {
         assert_eq!(vec!["id"], table.column_names());
     }
 
+    #[test]
+    fn table_schema_with_a_primary_key() {
+        let catalog = Catalog::new();
+        let schema = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_column("name", ColumnType::Text)
+            .unwrap()
+            .add_column("joined", ColumnType::Date)
+            .unwrap()
+            .add_primary_key(PrimaryKey::single("id"))
+            .unwrap();
+        let result = catalog.create_table("employees", schema);
+        assert!(result.is_ok());
+
+        let table = catalog.table_schema("employees").unwrap();
+        assert_eq!(3, table.column_count());
+        assert_eq!(vec!["id", "name", "joined"], table.column_names());
+        assert_eq!(
+            vec![ColumnType::Int, ColumnType::Text, ColumnType::Date],
+            table.column_types()
+        );
+        assert_eq!(
+            &["id".to_string()],
+            table.primary_key().unwrap().column_names()
+        );
+    }
+
+    #[test]
+    fn table_schema_without_a_primary_key() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        let table = catalog.table_schema("employees").unwrap();
+        assert_eq!(2, table.column_count());
+        assert_eq!(vec!["id", "name"], table.column_names());
+        assert!(table.primary_key().is_none());
+    }
+
+    #[test]
+    fn attempt_to_get_table_schema_for_non_existent_table() {
+        let catalog = Catalog::new();
+        let result = catalog.table_schema("employees");
+
+        assert!(
+            matches!(result, Err(CatalogError::TableDoesNotExist(ref table_name)) if table_name == "employees")
+        );
+    }
+
     #[test]
     fn get_table_by_non_existing_name() {
         let catalog = Catalog::new();
@@ -246,6 +666,25 @@ mod tests {
         assert_eq!(expected_row, row);
     }
 
+    #[test]
+    fn scan_table_with_the_row_ids_returned_by_insert_into() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let row_id1 = catalog.insert_into("employees", row![1]).unwrap();
+        let row_id2 = catalog.insert_into("employees", row![2]).unwrap();
+
+        let (table_entry, _) = catalog.scan("employees").unwrap();
+        let entries = table_entry
+            .scan()
+            .iter_with_ids()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(vec![(row_id1, row![1]), (row_id2, row![2])], entries);
+    }
+
     #[test]
     fn attempt_to_insert_into_non_existent_table() {
         let catalog = Catalog::new();
@@ -348,6 +787,49 @@ mod tests {
         ))
     }
 
+    #[test]
+    fn insert_stream_into_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let count = catalog
+            .insert_stream("employees", (1..=3).map(|id| row![id]))
+            .unwrap();
+        assert_eq!(3, count);
+
+        let table_entry = catalog.table_entry("employees").unwrap();
+        assert_eq!(3, table_entry.scan().iter().count());
+    }
+
+    #[test]
+    fn attempt_to_insert_stream_with_incompatible_column_values() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let result = catalog.insert_stream("employees", rows![[1], ["relop"], [3]].into_iter());
+        assert!(matches!(
+            result,
+            Err(InsertError::Row { index, ref error })
+                if index == 1 && matches!(error.as_ref(), InsertError::Schema(SchemaError::ColumnTypeMismatch { column, .. }) if column == "id")
+        ));
+
+        let table_entry = catalog.table_entry("employees").unwrap();
+        assert_eq!(1, table_entry.scan().iter().count());
+    }
+
+    #[test]
+    fn attempt_to_insert_stream_into_non_existent_table() {
+        let catalog = Catalog::new();
+        let result = catalog.insert_stream("employees", rows![[1]].into_iter());
+
+        assert!(
+            matches!(result, Err(InsertError::Catalog(CatalogError::TableDoesNotExist(ref table_name)))
+                    if table_name == "employees"),
+        )
+    }
+
     #[test]
     fn attempt_to_insert_all_into_non_existent_table() {
         let catalog = Catalog::new();
@@ -359,6 +841,168 @@ mod tests {
         )
     }
 
+    #[test]
+    fn load_csv_into_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        let csv = "id,name\n1,relop\n2,\"query, operator\"\n";
+        let row_ids = catalog.load_csv("employees", csv.as_bytes(), true).unwrap();
+
+        assert_eq!(2, row_ids.len());
+        assert_eq!(
+            row![1, "relop"],
+            catalog.get("employees", row_ids[0]).unwrap().unwrap()
+        );
+        assert_eq!(
+            row![2, "query, operator"],
+            catalog.get("employees", row_ids[1]).unwrap().unwrap()
+        );
+    }
+
+    #[test]
+    fn load_csv_without_header_into_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let row_ids = catalog
+            .load_csv("employees", "1\n2\n".as_bytes(), false)
+            .unwrap();
+
+        assert_eq!(2, row_ids.len());
+        assert_eq!(
+            row![2],
+            catalog.get("employees", row_ids[1]).unwrap().unwrap()
+        );
+    }
+
+    #[test]
+    fn attempt_to_load_csv_with_an_invalid_value_into_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "active" => ColumnType::Bool].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        let csv = "id,active\n1,true\n2,yes\n";
+        let result = catalog.load_csv("employees", csv.as_bytes(), true);
+
+        assert!(matches!(
+            result,
+            Err(LoadCsvError::InvalidValue { row, column, expected, value })
+                if row == 3 && column == "active" && expected == ColumnType::Bool && value == "yes"
+        ));
+        assert_eq!(
+            0,
+            catalog.scan("employees").unwrap().0.scan().iter().count()
+        );
+    }
+
+    #[test]
+    fn attempt_to_load_csv_with_a_mismatching_header_into_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let result = catalog.load_csv("employees", "name\nrelop\n".as_bytes(), true);
+
+        assert!(matches!(
+            result,
+            Err(LoadCsvError::HeaderMismatch { expected, actual })
+                if expected == vec!["id"] && actual == vec!["name"]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_load_csv_into_non_existent_table() {
+        let catalog = Catalog::new();
+        let result = catalog.load_csv("employees", "1\n".as_bytes(), false);
+
+        assert!(matches!(
+            result,
+            Err(LoadCsvError::Catalog(CatalogError::TableDoesNotExist(ref table_name)))
+                if table_name == "employees"
+        ));
+    }
+
+    #[test]
+    fn delete_from_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let row_ids = catalog
+            .insert_all_into("employees", rows![[1], [2]])
+            .unwrap();
+
+        let deleted = catalog.delete_from("employees", &row_ids[..1]).unwrap();
+        assert_eq!(1, deleted);
+
+        assert!(catalog.get("employees", row_ids[0]).unwrap().is_none());
+        assert_eq!(
+            row![2],
+            catalog.get("employees", row_ids[1]).unwrap().unwrap()
+        );
+    }
+
+    #[test]
+    fn attempt_to_delete_from_non_existent_table() {
+        let catalog = Catalog::new();
+
+        let result = catalog.delete_from("employees", &[1]);
+        assert!(
+            matches!(result, Err(CatalogError::TableDoesNotExist(ref table_name)) if table_name == "employees")
+        );
+    }
+
+    #[test]
+    fn update_in_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let row_ids = catalog
+            .insert_all_into("employees", rows![[1], [2]])
+            .unwrap();
+
+        let updated = catalog
+            .update_in("employees", vec![(row_ids[1], row![20])])
+            .unwrap();
+        assert_eq!(1, updated);
+
+        assert_eq!(
+            row![1],
+            catalog.get("employees", row_ids[0]).unwrap().unwrap()
+        );
+        assert_eq!(
+            row![20],
+            catalog.get("employees", row_ids[1]).unwrap().unwrap()
+        );
+    }
+
+    #[test]
+    fn attempt_to_update_in_table_with_incompatible_column_values() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let row_id = catalog.insert_into("employees", row![1]).unwrap();
+
+        let result = catalog.update_in("employees", vec![(row_id, row!["relop"])]);
+        assert!(matches!(
+            result,
+            Err(InsertError::Schema(SchemaError::ColumnTypeMismatch {column, expected, actual}))
+                if column == "id" && expected == ColumnType::Int && actual == ColumnType::Text
+        ));
+        assert_eq!(row![1], catalog.get("employees", row_id).unwrap().unwrap());
+    }
+
     #[test]
     fn get_by_row_id_from_table() {
         let catalog = Catalog::new();
@@ -396,7 +1040,8 @@ mod tests {
             .0
             .scan()
             .iter()
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
         assert_eq!(1, rows.len());
 
         let expected_row = row![1];
@@ -432,4 +1077,199 @@ mod tests {
             matches!(result, Err(CatalogError::TableDoesNotExist(ref table_name)) if table_name == "employees")
         );
     }
+
+    #[test]
+    fn table_statistics_after_inserts() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        catalog.insert_into("employees", row![1, "relop"]).unwrap();
+        catalog
+            .insert_all_into("employees", rows![[2, "query"], [3, "relop"]])
+            .unwrap();
+
+        let statistics = catalog.table_statistics("employees").unwrap();
+        assert_eq!(3, statistics.row_count());
+        assert_eq!(3, statistics.column_statistics(0).unwrap().distinct_count());
+        assert_eq!(2, statistics.column_statistics(1).unwrap().distinct_count());
+    }
+
+    #[test]
+    fn table_statistics_after_delete() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let row_ids = catalog
+            .insert_all_into("employees", rows![[1], [2]])
+            .unwrap();
+        assert_eq!(
+            2,
+            catalog.table_statistics("employees").unwrap().row_count()
+        );
+
+        catalog.delete_from("employees", &row_ids[..1]).unwrap();
+        assert_eq!(
+            1,
+            catalog.table_statistics("employees").unwrap().row_count()
+        );
+    }
+
+    #[test]
+    fn attempt_to_get_table_statistics_for_non_existent_table() {
+        let catalog = Catalog::new();
+        let result = catalog.table_statistics("employees");
+
+        assert!(
+            matches!(result, Err(CatalogError::TableDoesNotExist(ref table_name)) if table_name == "employees")
+        );
+    }
+
+    #[test]
+    fn save_and_load_catalog() {
+        let path = std::env::temp_dir().join("relop_save_and_load_catalog");
+        let catalog = Catalog::new();
+        catalog
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        catalog
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        let row_ids = catalog
+            .insert_all_into(
+                "employees",
+                rows![[1, "relop"], [2, "query"], [3, "engine"]],
+            )
+            .unwrap();
+        catalog.delete_from("employees", &row_ids[1..2]).unwrap();
+
+        catalog.save(&path).unwrap();
+        let loaded = Catalog::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(vec!["departments", "employees"], loaded.show_tables());
+        assert_eq!(
+            catalog.schema_for("employees").unwrap(),
+            loaded.schema_for("employees").unwrap()
+        );
+        assert_eq!(Some(row![1, "relop"]), loaded.get("employees", 1).unwrap());
+        assert_eq!(Some(row![3, "engine"]), loaded.get("employees", 2).unwrap());
+        assert_eq!(
+            0,
+            loaded.table_statistics("departments").unwrap().row_count()
+        );
+    }
+
+    #[test]
+    fn save_and_load_catalog_with_an_auto_increment_column() {
+        let path = std::env::temp_dir().join("relop_save_and_load_catalog_with_auto_increment");
+        let catalog = Catalog::new();
+        catalog
+            .create_table(
+                "employees",
+                Schema::new()
+                    .add_column_definition(Column::new("id", ColumnType::Int).auto_increment())
+                    .unwrap()
+                    .add_column("name", ColumnType::Text)
+                    .unwrap(),
+            )
+            .unwrap();
+        let row_ids = catalog
+            .insert_all_into(
+                "employees",
+                vec![
+                    Row::filled(vec![ColumnValue::null(), ColumnValue::text("relop")]),
+                    Row::filled(vec![ColumnValue::null(), ColumnValue::text("query")]),
+                ],
+            )
+            .unwrap();
+        catalog.delete_from("employees", &row_ids[1..]).unwrap();
+
+        catalog.save(&path).unwrap();
+        let loaded = Catalog::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let row_id = loaded
+            .insert_into(
+                "employees",
+                Row::filled(vec![ColumnValue::null(), ColumnValue::text("engine")]),
+            )
+            .unwrap();
+        assert_eq!(
+            Some(row![3, "engine"]),
+            loaded.get("employees", row_id).unwrap()
+        );
+    }
+
+    #[test]
+    fn attempt_to_load_catalog_from_a_non_existent_file() {
+        let result = Catalog::load(std::env::temp_dir().join("relop_non_existent_catalog"));
+        assert!(matches!(result, Err(PersistenceError::Io(_))));
+    }
+
+    #[test]
+    fn create_index_on_table() {
+        let catalog = Catalog::new();
+        catalog
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        catalog
+            .insert_all_into("employees", rows![[1, 10], [2, 20], [3, 10]])
+            .unwrap();
+
+        catalog.create_index("employees", "department_id").unwrap();
+
+        let (table_entry, _) = catalog.scan("employees").unwrap();
+        assert_eq!(
+            Some(vec![1, 3]),
+            table_entry.index_lookup("department_id", &ColumnValue::int(10))
+        );
+    }
+
+    #[test]
+    fn attempt_to_create_index_on_non_existent_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_index("employees", "id");
+
+        assert!(
+            matches!(result, Err(CatalogError::TableDoesNotExist(ref table_name)) if table_name == "employees")
+        );
+    }
+
+    #[test]
+    fn attempt_to_create_index_on_non_existent_column() {
+        let catalog = Catalog::new();
+        catalog
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        let result = catalog.create_index("employees", "department_id");
+
+        assert!(
+            matches!(result, Err(CatalogError::ColumnDoesNotExist(ref column_name)) if column_name == "department_id")
+        );
+    }
+
+    #[test]
+    fn attempt_to_create_an_already_created_index() {
+        let catalog = Catalog::new();
+        catalog
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        catalog.create_index("employees", "id").unwrap();
+        let result = catalog.create_index("employees", "id");
+
+        assert!(
+            matches!(result, Err(CatalogError::IndexAlreadyExists(ref column_name)) if column_name == "id")
+        );
+    }
 }
@@ -0,0 +1,507 @@
+use crate::catalog::error::PersistenceError;
+use crate::schema::column::Column;
+use crate::schema::primary_key::PrimaryKey;
+use crate::schema::unique_constraint::UniqueConstraint;
+use crate::schema::Schema;
+use crate::storage::row::Row;
+use crate::types::column_type::ColumnType;
+use crate::types::column_value::ColumnValue;
+use crate::types::date::Date;
+use std::io::{Read, Write};
+
+/// The bytes every saved catalog starts with, followed by the version of the format.
+const MAGIC: &[u8; 5] = b"RELOP";
+const VERSION: u8 = 2;
+
+const INT: u8 = 0;
+const TEXT: u8 = 1;
+const BOOL: u8 = 2;
+const NULL: u8 = 3;
+const DATE: u8 = 4;
+
+/// A table, as it is saved: its name, its schema, the next value of its auto-increment column
+/// (if any) and its rows.
+pub(crate) struct SavedTable {
+    pub(crate) name: String,
+    pub(crate) schema: Schema,
+    pub(crate) next_auto_increment_value: Option<i64>,
+    pub(crate) rows: Vec<Row>,
+}
+
+/// Writes the tables in a length-prefixed binary format:
+///
+/// ```text
+/// catalog = "RELOP" version:u8 table_count:u32 table*
+/// table   = name:string column_count:u32 column*
+///           key_column_count:u32 (name:string)* unique_count:u32 (name:string)*
+///           (0 | 1 next_auto_increment_value:i64) row_count:u64 row*
+/// column  = name:string type:u8 nullable:u8 auto_increment:u8 (0 | 1 default:value)
+/// row     = value*                  (one value per column)
+/// value   = 0 i64 | 1 string | 2 u8 | 3 | 4 i32 u8 u8    (int, text, bool, null, date)
+/// string  = length:u32 utf-8 bytes
+/// ```
+///
+/// A table without a primary key has no key columns, and each unique constraint is saved as the
+/// name of its column. The counter of an auto-increment column is saved as the value it hands out
+/// next, so that the values of deleted rows are not handed out again after a load. All the
+/// integers are written in little-endian order.
+pub(crate) fn write_tables<'a>(
+    writer: &mut impl Write,
+    tables: impl ExactSizeIterator<Item = (&'a str, &'a Schema, Option<i64>, Vec<Row>)>,
+) -> Result<(), PersistenceError> {
+    write_bytes(writer, MAGIC)?;
+    write_bytes(writer, &[VERSION])?;
+    write_u32(writer, tables.len())?;
+
+    for (name, schema, next_auto_increment_value, rows) in tables {
+        write_string(writer, name)?;
+        write_u32(writer, schema.column_count())?;
+        for column in schema.columns() {
+            write_string(writer, column.name())?;
+            write_bytes(
+                writer,
+                &[
+                    column_type_tag(column.column_type()),
+                    u8::from(column.is_nullable()),
+                    u8::from(column.is_auto_increment()),
+                ],
+            )?;
+            match column.default_value() {
+                Some(default_value) => {
+                    write_bytes(writer, &[1])?;
+                    write_value(writer, default_value)?;
+                }
+                None => write_bytes(writer, &[0])?,
+            }
+        }
+        let key_column_names = schema
+            .primary_key()
+            .map_or(&[][..], |primary_key| primary_key.column_names());
+        write_u32(writer, key_column_names.len())?;
+        for column_name in key_column_names {
+            write_string(writer, column_name)?;
+        }
+        write_u32(writer, schema.unique_constraints().len())?;
+        for unique_constraint in schema.unique_constraints() {
+            write_string(writer, unique_constraint.column_name())?;
+        }
+        match next_auto_increment_value {
+            Some(next_value) => {
+                write_bytes(writer, &[1])?;
+                write_bytes(writer, &next_value.to_le_bytes())?;
+            }
+            None => write_bytes(writer, &[0])?,
+        }
+        write_bytes(writer, &(rows.len() as u64).to_le_bytes())?;
+        for row in rows {
+            for value in row.column_values() {
+                write_value(writer, value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the tables written by `write_tables`.
+pub(crate) fn read_tables(reader: &mut impl Read) -> Result<Vec<SavedTable>, PersistenceError> {
+    let mut magic = [0; 5];
+    read_exact(reader, &mut magic)?;
+    if &magic != MAGIC {
+        return Err(PersistenceError::InvalidFormat(
+            "not a saved catalog".to_string(),
+        ));
+    }
+    let version = read_u8(reader)?;
+    if version != VERSION {
+        return Err(PersistenceError::InvalidFormat(format!(
+            "unsupported version {}",
+            version
+        )));
+    }
+
+    let table_count = read_u32(reader)?;
+    let mut tables = Vec::new();
+    for _ in 0..table_count {
+        let name = read_string(reader)?;
+
+        let column_count = read_u32(reader)?;
+        let mut schema = Schema::new();
+        let mut column_types = Vec::new();
+        for _ in 0..column_count {
+            let column_name = read_string(reader)?;
+            let column_type = column_type_from_tag(read_u8(reader)?)?;
+            let mut column = Column::new(column_name, column_type.clone());
+            if read_u8(reader)? == 0 {
+                column = column.not_null();
+            }
+            if read_u8(reader)? != 0 {
+                column = column.auto_increment();
+            }
+            if read_u8(reader)? != 0 {
+                column = column.with_default(read_value(reader)?);
+            }
+            schema = schema
+                .add_column_definition(column)
+                .map_err(PersistenceError::Schema)?;
+            column_types.push(column_type);
+        }
+
+        let key_column_count = read_u32(reader)?;
+        if key_column_count > 0 {
+            let key_column_names = (0..key_column_count)
+                .map(|_| read_string(reader))
+                .collect::<Result<Vec<_>, _>>()?;
+            schema = schema
+                .add_primary_key(PrimaryKey::composite(key_column_names))
+                .map_err(PersistenceError::Schema)?;
+        }
+
+        let unique_count = read_u32(reader)?;
+        for _ in 0..unique_count {
+            schema = schema
+                .add_unique_constraint(UniqueConstraint::new(read_string(reader)?))
+                .map_err(PersistenceError::Schema)?;
+        }
+
+        let next_auto_increment_value = if read_u8(reader)? != 0 {
+            Some(read_i64(reader)?)
+        } else {
+            None
+        };
+
+        let row_count = read_u64(reader)?;
+        let mut rows = Vec::new();
+        for _ in 0..row_count {
+            let values = column_types
+                .iter()
+                .map(|_| read_value(reader))
+                .collect::<Result<Vec<_>, _>>()?;
+            rows.push(Row::filled(values));
+        }
+        tables.push(SavedTable {
+            name,
+            schema,
+            next_auto_increment_value,
+            rows,
+        });
+    }
+    Ok(tables)
+}
+
+fn column_type_tag(column_type: &ColumnType) -> u8 {
+    match column_type {
+        ColumnType::Int => INT,
+        ColumnType::Text => TEXT,
+        ColumnType::Bool => BOOL,
+        ColumnType::Date => DATE,
+    }
+}
+
+fn column_type_from_tag(tag: u8) -> Result<ColumnType, PersistenceError> {
+    match tag {
+        INT => Ok(ColumnType::Int),
+        TEXT => Ok(ColumnType::Text),
+        BOOL => Ok(ColumnType::Bool),
+        DATE => Ok(ColumnType::Date),
+        _ => Err(PersistenceError::InvalidFormat(format!(
+            "unknown column type {}",
+            tag
+        ))),
+    }
+}
+
+fn write_value(writer: &mut impl Write, value: &ColumnValue) -> Result<(), PersistenceError> {
+    match value {
+        ColumnValue::Int(value) => {
+            write_bytes(writer, &[INT])?;
+            write_bytes(writer, &value.to_le_bytes())
+        }
+        ColumnValue::Text(value) => {
+            write_bytes(writer, &[TEXT])?;
+            write_string(writer, value)
+        }
+        ColumnValue::Bool(value) => write_bytes(writer, &[BOOL, u8::from(*value)]),
+        ColumnValue::Date(value) => {
+            write_bytes(writer, &[DATE])?;
+            write_bytes(writer, &value.year().to_le_bytes())?;
+            write_bytes(writer, &[value.month(), value.day()])
+        }
+        ColumnValue::Null => write_bytes(writer, &[NULL]),
+    }
+}
+
+fn read_value(reader: &mut impl Read) -> Result<ColumnValue, PersistenceError> {
+    match read_u8(reader)? {
+        INT => Ok(ColumnValue::Int(read_i64(reader)?)),
+        TEXT => Ok(ColumnValue::Text(read_string(reader)?)),
+        BOOL => Ok(ColumnValue::Bool(read_u8(reader)? != 0)),
+        NULL => Ok(ColumnValue::Null),
+        DATE => {
+            let mut year = [0; 4];
+            read_exact(reader, &mut year)?;
+            let month = read_u8(reader)?;
+            let day = read_u8(reader)?;
+            Date::new(i32::from_le_bytes(year), month, day)
+                .map(ColumnValue::Date)
+                .ok_or_else(|| PersistenceError::InvalidFormat("invalid date".to_string()))
+        }
+        tag => Err(PersistenceError::InvalidFormat(format!(
+            "unknown value {}",
+            tag
+        ))),
+    }
+}
+
+fn write_string(writer: &mut impl Write, value: &str) -> Result<(), PersistenceError> {
+    write_u32(writer, value.len())?;
+    write_bytes(writer, value.as_bytes())
+}
+
+fn read_string(reader: &mut impl Read) -> Result<String, PersistenceError> {
+    let length = read_u32(reader)?;
+    let mut bytes = Vec::new();
+    reader
+        .by_ref()
+        .take(length as u64)
+        .read_to_end(&mut bytes)
+        .map_err(|error| PersistenceError::Io(error.to_string()))?;
+    if bytes.len() != length {
+        return Err(PersistenceError::InvalidFormat(
+            "unexpected end of file".to_string(),
+        ));
+    }
+    String::from_utf8(bytes)
+        .map_err(|_| PersistenceError::InvalidFormat("invalid utf-8 text".to_string()))
+}
+
+fn write_u32(writer: &mut impl Write, value: usize) -> Result<(), PersistenceError> {
+    let value = u32::try_from(value)
+        .map_err(|_| PersistenceError::InvalidFormat(format!("length {} too large", value)))?;
+    write_bytes(writer, &value.to_le_bytes())
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<usize, PersistenceError> {
+    let mut bytes = [0; 4];
+    read_exact(reader, &mut bytes)?;
+    Ok(u32::from_le_bytes(bytes) as usize)
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64, PersistenceError> {
+    let mut bytes = [0; 8];
+    read_exact(reader, &mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_i64(reader: &mut impl Read) -> Result<i64, PersistenceError> {
+    let mut bytes = [0; 8];
+    read_exact(reader, &mut bytes)?;
+    Ok(i64::from_le_bytes(bytes))
+}
+
+fn read_u8(reader: &mut impl Read) -> Result<u8, PersistenceError> {
+    let mut byte = [0; 1];
+    read_exact(reader, &mut byte)?;
+    Ok(byte[0])
+}
+
+fn write_bytes(writer: &mut impl Write, bytes: &[u8]) -> Result<(), PersistenceError> {
+    writer
+        .write_all(bytes)
+        .map_err(|error| PersistenceError::Io(error.to_string()))
+}
+
+fn read_exact(reader: &mut impl Read, bytes: &mut [u8]) -> Result<(), PersistenceError> {
+    reader.read_exact(bytes).map_err(|error| {
+        if error.kind() == std::io::ErrorKind::UnexpectedEof {
+            PersistenceError::InvalidFormat("unexpected end of file".to_string())
+        } else {
+            PersistenceError::Io(error.to_string())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{row, schema};
+
+    fn round_trip(tables: Vec<(&str, Schema, Option<i64>, Vec<Row>)>) -> Vec<SavedTable> {
+        let mut bytes = Vec::new();
+        write_tables(
+            &mut bytes,
+            tables
+                .iter()
+                .map(|(name, schema, next_auto_increment_value, rows)| {
+                    (*name, schema, *next_auto_increment_value, rows.clone())
+                }),
+        )
+        .unwrap();
+        read_tables(&mut bytes.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn round_trip_all_column_types_and_values() {
+        let schema = || {
+            schema![
+                "id" => ColumnType::Int,
+                "name" => ColumnType::Text,
+                "active" => ColumnType::Bool,
+                "joined_on" => ColumnType::Date
+            ]
+            .unwrap()
+        };
+        let date = |text| ColumnValue::date(Date::parse(text).unwrap());
+        let rows = vec![
+            row![-1, "relop", true].insert(date("2024-01-15")),
+            row![i64::MAX, "", false].insert(date("0001-12-31")),
+            Row::filled(vec![
+                ColumnValue::null(),
+                ColumnValue::null(),
+                ColumnValue::null(),
+                ColumnValue::null(),
+            ]),
+        ];
+
+        let tables = round_trip(vec![("employees", schema(), None, rows.clone())]);
+
+        assert_eq!(1, tables.len());
+        assert_eq!("employees", tables[0].name);
+        assert_eq!(schema(), tables[0].schema);
+        assert_eq!(rows, tables[0].rows);
+    }
+
+    #[test]
+    fn round_trip_composite_primary_key_and_unique_constraints() {
+        let schema = || {
+            schema![
+                "department_id" => ColumnType::Int,
+                "id" => ColumnType::Int,
+                "email" => ColumnType::Text
+            ]
+            .unwrap()
+            .add_primary_key(PrimaryKey::composite(["department_id", "id"]))
+            .unwrap()
+            .add_unique_constraint(UniqueConstraint::new("email"))
+            .unwrap()
+        };
+
+        let tables = round_trip(vec![(
+            "employees",
+            schema(),
+            None,
+            vec![row![1, 10, "relop"]],
+        )]);
+
+        assert_eq!(schema(), tables[0].schema);
+        assert_eq!(vec![row![1, 10, "relop"]], tables[0].rows);
+    }
+
+    #[test]
+    fn round_trip_column_defaults_not_null_and_auto_increment_columns() {
+        let schema = || {
+            Schema::new()
+                .add_column_definition(
+                    Column::new("id", ColumnType::Int)
+                        .not_null()
+                        .auto_increment(),
+                )
+                .unwrap()
+                .add_column_definition(
+                    Column::new("name", ColumnType::Text).with_default(ColumnValue::text("relop")),
+                )
+                .unwrap()
+                .add_column_definition(
+                    Column::new("active", ColumnType::Bool)
+                        .not_null()
+                        .with_default(ColumnValue::bool(false)),
+                )
+                .unwrap()
+        };
+
+        let tables = round_trip(vec![(
+            "employees",
+            schema(),
+            Some(3),
+            vec![row![1, "db", true]],
+        )]);
+
+        assert_eq!(schema(), tables[0].schema);
+        assert_eq!(Some(3), tables[0].next_auto_increment_value);
+        assert_eq!(vec![row![1, "db", true]], tables[0].rows);
+    }
+
+    #[test]
+    fn round_trip_tables_without_rows() {
+        let tables = round_trip(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int].unwrap(),
+                None,
+                vec![],
+            ),
+            (
+                "departments",
+                schema!["name" => ColumnType::Text].unwrap(),
+                None,
+                vec![],
+            ),
+        ]);
+
+        assert_eq!(2, tables.len());
+        assert_eq!("departments", tables[1].name);
+        assert!(tables[1].rows.is_empty());
+    }
+
+    #[test]
+    fn attempt_to_read_tables_without_the_magic_bytes() {
+        let result = read_tables(&mut b"CSV,1,2".as_slice());
+
+        assert!(matches!(result, Err(PersistenceError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn attempt_to_read_tables_with_an_unsupported_version() {
+        let result = read_tables(&mut b"RELOP\x09".as_slice());
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::InvalidFormat(message)) if message == "unsupported version 9"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_read_truncated_tables() {
+        let mut bytes = Vec::new();
+        let schema = schema!["id" => ColumnType::Int].unwrap();
+        write_tables(
+            &mut bytes,
+            [("employees", &schema, None, vec![row![1]])].into_iter(),
+        )
+        .unwrap();
+        bytes.pop();
+
+        let result = read_tables(&mut bytes.as_slice());
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::InvalidFormat(message)) if message == "unexpected end of file"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_read_tables_with_a_text_length_past_the_end_of_the_file() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        bytes.extend_from_slice(b"employees");
+
+        let result = read_tables(&mut bytes.as_slice());
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::InvalidFormat(message)) if message == "unexpected end of file"
+        ));
+    }
+}
@@ -1,4 +1,6 @@
+use crate::schema::primary_key::PrimaryKey;
 use crate::schema::Schema;
+use crate::types::column_type::ColumnType;
 use std::sync::Arc;
 
 /// Represents a table in the database catalog.
@@ -23,6 +25,53 @@ impl Table {
         &self.name
     }
 
+    /// Returns the names of the columns, in order.
+    pub fn column_names(&self) -> Vec<&str> {
+        self.schema.column_names()
+    }
+
+    /// Returns the number of columns.
+    pub fn column_count(&self) -> usize {
+        self.schema.column_count()
+    }
+
+    /// Returns the primary key of the table, if it has one.
+    pub fn primary_key(&self) -> Option<&PrimaryKey> {
+        self.schema.primary_key()
+    }
+
+    /// Returns the types of the columns, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::table::Table;
+    /// use relop::schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let table = Table::new(
+    ///     "employees",
+    ///     schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+    /// );
+    /// assert_eq!(vec![ColumnType::Int, ColumnType::Text], table.column_types());
+    /// ```
+    pub fn column_types(&self) -> Vec<ColumnType> {
+        self.schema
+            .columns()
+            .iter()
+            .map(|column| column.column_type().clone())
+            .collect()
+    }
+
+    /// Returns whether each column accepts `Null` values, in the order of the columns.
+    pub fn column_nullability(&self) -> Vec<bool> {
+        self.schema
+            .columns()
+            .iter()
+            .map(|column| column.is_nullable())
+            .collect()
+    }
+
     /// Returns the table schema reference.
     pub(crate) fn schema_ref(&self) -> &Schema {
         &self.schema
@@ -35,8 +84,38 @@ impl Table {
 }
 
 #[cfg(test)]
-impl Table {
-    pub(crate) fn column_names(&self) -> Vec<&str> {
-        self.schema.column_names()
+mod tests {
+    use super::*;
+    use crate::schema::column::Column;
+    use crate::types::column_type::ColumnType;
+
+    #[test]
+    fn column_names_and_types_of_a_mixed_table() {
+        let schema = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_column("name", ColumnType::Text)
+            .unwrap()
+            .add_column("joined", ColumnType::Date)
+            .unwrap();
+        let table = Table::new("employees", schema);
+
+        assert_eq!(vec!["id", "name", "joined"], table.column_names());
+        assert_eq!(
+            vec![ColumnType::Int, ColumnType::Text, ColumnType::Date],
+            table.column_types()
+        );
+    }
+
+    #[test]
+    fn column_nullability_of_a_table() {
+        let schema = Schema::new()
+            .add_column_definition(Column::new("id", ColumnType::Int).not_null())
+            .unwrap()
+            .add_column("name", ColumnType::Text)
+            .unwrap();
+        let table = Table::new("employees", schema);
+
+        assert_eq!(vec![false, true], table.column_nullability());
     }
 }
@@ -1,19 +1,42 @@
-use crate::catalog::error::InsertError;
+use crate::catalog::auto_increment::AutoIncrement;
+use crate::catalog::error::{CatalogError, InsertError};
 use crate::catalog::table::Table;
 use crate::catalog::table_scan::TableScan;
+use crate::catalog::table_statistics::TableStatistics;
 use crate::storage::batch::Batch;
+use crate::storage::primary_key_index::{PrimaryKeyColumnValues, PrimaryKeyIndex};
 use crate::storage::row::Row;
 use crate::storage::row_filter::{NoFilter, RowFilter};
-use crate::storage::table_store::{RowId, TableStore};
-use std::sync::Arc;
+use crate::storage::secondary_index::SecondaryIndex;
+use crate::storage::table_store::{RowId, TableStore, TableStoreEntryIterator};
+use crate::storage::unique_index::UniqueIndex;
+use crate::types::column_value::ColumnValue;
+use std::sync::{Arc, RwLock, RwLockWriteGuard};
 
 /// It holds a reference to the `Table` definition and the underlying `TableStore` for data storage.
 ///
 /// `TableEntry` is responsible for managing concurrent access to the table data (delegating to `TableStore`), ensuring
 /// thread safety during insertions.
+///
+/// It also caches the `TableStatistics` of the table, which are computed (lazily) on the first
+/// request after the rows of the table change.
+///
+/// If the table has a primary key or unique constraints, `TableEntry` maintains their indexes
+/// (`PrimaryKeyIndex` and `UniqueIndex`), along with the `SecondaryIndex`es created on the table:
+/// rows are inserted, updated and deleted while holding the locks of all the indexes, so that the
+/// uniqueness checks, the change of the rows and the change of the indexes happen atomically.
+///
+/// If the table has an auto-increment column, `TableEntry` holds its `AutoIncrement` counter,
+/// which fills the column of the inserted rows (while holding the locks of the indexes, so that
+/// the filled values are checked against the primary key and unique indexes).
 pub(crate) struct TableEntry {
     table: Arc<Table>,
     store: Arc<TableStore>,
+    statistics: RwLock<Option<Arc<TableStatistics>>>,
+    primary_key_index: Option<RwLock<PrimaryKeyIndex>>,
+    unique_indexes: Vec<RwLock<UniqueIndex>>,
+    secondary_indexes: RwLock<Vec<SecondaryIndex>>,
+    auto_increment: Option<AutoIncrement>,
 }
 
 impl TableEntry {
@@ -21,20 +44,265 @@ impl TableEntry {
     ///
     /// This also initializes the `TableStore`.
     pub(crate) fn new(table: Table) -> Arc<TableEntry> {
+        let schema = table.schema_ref();
+        let primary_key_index = schema.primary_key().map(|primary_key| {
+            RwLock::new(PrimaryKeyIndex::new(
+                primary_key.column_names().to_vec(),
+                //SAFETY: the schema has a primary key.
+                schema.primary_key_column_positions().unwrap(),
+            ))
+        });
+        let unique_indexes = schema
+            .unique_column_positions()
+            .into_iter()
+            .map(|(column_name, position)| RwLock::new(UniqueIndex::new(column_name, position)))
+            .collect();
+        let auto_increment = schema
+            .auto_increment_column_position()
+            .map(AutoIncrement::new);
         Arc::new(Self {
             table: Arc::new(table),
             store: Arc::new(TableStore::new()),
+            statistics: RwLock::new(None),
+            primary_key_index,
+            unique_indexes,
+            secondary_indexes: RwLock::new(Vec::new()),
+            auto_increment,
         })
     }
 
-    /// Inserts a single row into the table.
-    pub(crate) fn insert(&self, row: Row) -> Result<RowId, InsertError> {
-        Ok(self.store.insert(row))
+    /// Inserts a single row into the table, filling its auto-increment column (if any).
+    ///
+    /// Returns an error if the primary key of the row is `Null` or already present in the table,
+    /// or if the value of a uniquely-constrained column is already present in the table.
+    pub(crate) fn insert(&self, mut row: Row) -> Result<RowId, InsertError> {
+        let mut indexes = self.lock_indexes();
+        self.fill_auto_increment(std::slice::from_mut(&mut row));
+        let keys = indexes.keys_for_insert(std::slice::from_ref(&row))?;
+        let row_id = self.store.insert(row);
+        indexes.insert(keys, &[row_id]);
+        drop(indexes);
+
+        self.invalidate_statistics();
+        Ok(row_id)
     }
 
-    /// Inserts a batch of rows into the table.
+    /// Inserts a batch of rows into the table, filling their auto-increment column (if any).
+    ///
+    /// No row is inserted if the primary key of any of the rows is `Null`, already present in
+    /// the table, or repeated in the batch (and likewise for the values of the
+    /// uniquely-constrained columns, which may be `Null`).
     pub(crate) fn insert_all(&self, batch: Batch) -> Result<Vec<RowId>, InsertError> {
-        Ok(self.store.insert_all(batch.into_rows()))
+        let mut rows = batch.into_rows();
+        let mut indexes = self.lock_indexes();
+        self.fill_auto_increment(&mut rows);
+        let keys = indexes.keys_for_insert(&rows)?;
+        let row_ids = self.store.insert_all(rows);
+        indexes.insert(keys, &row_ids);
+        drop(indexes);
+
+        self.invalidate_statistics();
+        Ok(row_ids)
+    }
+
+    /// Deletes the rows with the given `RowId`s from the table.
+    ///
+    /// Returns the number of deleted rows.
+    pub(crate) fn delete_all(&self, row_ids: &[RowId]) -> usize {
+        let mut indexes = self.lock_indexes();
+        if !indexes.is_empty() {
+            for row_id in row_ids {
+                if let Some(row) = self.store.get(*row_id) {
+                    indexes.remove(&row, *row_id);
+                }
+            }
+        }
+        let deleted = self.store.delete_all(row_ids);
+        drop(indexes);
+
+        self.invalidate_statistics();
+        deleted
+    }
+
+    /// Replaces the rows with the given `RowId`s in the table.
+    ///
+    /// No row is replaced if the primary key of any of the rows is `Null`, repeated among the
+    /// rows, or present in the table for a row which is not replaced (and likewise for the
+    /// values of the uniquely-constrained columns, which may be `Null`).
+    /// Returns the number of updated rows.
+    pub(crate) fn update_all(&self, rows: Vec<(RowId, Row)>) -> Result<usize, InsertError> {
+        let mut indexes = self.lock_indexes();
+        let updated = if indexes.is_empty() {
+            self.store.update_all(rows)
+        } else {
+            let (rows, existing_rows): (Vec<_>, Vec<_>) = rows
+                .into_iter()
+                .filter_map(|(row_id, row)| {
+                    self.store
+                        .get(row_id)
+                        .map(|existing_row| ((row_id, row), existing_row))
+                })
+                .unzip();
+            let keys = indexes.keys_for_update(&rows)?;
+            for ((row_id, _), existing_row) in rows.iter().zip(&existing_rows) {
+                indexes.remove(existing_row, *row_id);
+            }
+            let row_ids = rows.iter().map(|(row_id, _)| *row_id).collect::<Vec<_>>();
+            indexes.insert(keys, &row_ids);
+            self.store.update_all(rows)
+        };
+        drop(indexes);
+
+        self.invalidate_statistics();
+        Ok(updated)
+    }
+
+    /// Removes all the rows from the table, keeping its definition.
+    pub(crate) fn truncate(&self) {
+        let mut indexes = self.lock_indexes();
+        indexes.clear();
+        self.store.clear();
+        drop(indexes);
+
+        self.invalidate_statistics();
+    }
+
+    /// Creates a `SecondaryIndex` on the column with the given name, indexing the existing rows.
+    ///
+    /// Returns an error if the table has no column with the given name, or if the column is
+    /// already indexed.
+    pub(crate) fn create_index(&self, column_name: &str) -> Result<(), CatalogError> {
+        let position = self
+            .table
+            .schema_ref()
+            .columns()
+            .iter()
+            .position(|column| column.name() == column_name)
+            .ok_or_else(|| CatalogError::ColumnDoesNotExist(column_name.to_string()))?;
+
+        let mut indexes = self.lock_indexes();
+        if indexes
+            .secondary_indexes
+            .iter()
+            .any(|index| index.column_name() == column_name)
+        {
+            return Err(CatalogError::IndexAlreadyExists(column_name.to_string()));
+        }
+        let mut index = SecondaryIndex::new(column_name, position);
+        for (row_id, row) in self.store.entries() {
+            index.insert(index.value_of(&row), row_id);
+        }
+        indexes.secondary_indexes.push(index);
+        Ok(())
+    }
+
+    /// Returns the `RowId`s of the rows having the given value in the column with the given name,
+    /// if the column is indexed.
+    pub(crate) fn index_lookup(
+        &self,
+        column_name: &str,
+        value: &ColumnValue,
+    ) -> Option<Vec<RowId>> {
+        self.secondary_indexes
+            .read()
+            .unwrap()
+            .iter()
+            .find(|index| index.column_name() == column_name)
+            .map(|index| index.row_ids(value))
+    }
+
+    /// Returns the `RowId` of the row (if any) having the given values in the primary key columns,
+    /// if the table has a primary key and each of its columns is given a value (by column name).
+    pub(crate) fn primary_key_lookup(
+        &self,
+        values_by_column: &[(&str, ColumnValue)],
+    ) -> Option<Vec<RowId>> {
+        let primary_key_index = self.primary_key_index.as_ref()?.read().unwrap();
+        let values = primary_key_index
+            .column_names()
+            .iter()
+            .map(|column_name| {
+                values_by_column
+                    .iter()
+                    .find(|(name, _)| name == column_name)
+                    .map(|(_, value)| value.clone())
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let key = PrimaryKeyColumnValues::from_values(values);
+        Some(primary_key_index.get(&key).into_iter().collect())
+    }
+
+    /// Returns the row with the given `RowId`, if it is present.
+    pub(crate) fn get(&self, row_id: RowId) -> Option<Row> {
+        self.store.get(row_id)
+    }
+
+    /// Returns the value the auto-increment column hands out next, if the table has one.
+    pub(crate) fn next_auto_increment_value(&self) -> Option<i64> {
+        self.auto_increment
+            .as_ref()
+            .map(|auto_increment| auto_increment.next_value())
+    }
+
+    /// Moves the counter of the auto-increment column (if any) to the given value, unless it is
+    /// already past it.
+    pub(crate) fn restore_auto_increment(&self, next_value: i64) {
+        if let Some(auto_increment) = &self.auto_increment {
+            auto_increment.move_to(next_value);
+        }
+    }
+
+    fn fill_auto_increment(&self, rows: &mut [Row]) {
+        if let Some(auto_increment) = &self.auto_increment {
+            for row in rows {
+                auto_increment.fill(row);
+            }
+        }
+    }
+
+    /// Acquires the locks of all the indexes, always in the same order (the primary key index
+    /// first, then the unique indexes in the order of the unique constraints, then the secondary
+    /// indexes).
+    fn lock_indexes(&self) -> LockedIndexes<'_> {
+        LockedIndexes {
+            primary_key_index: self
+                .primary_key_index
+                .as_ref()
+                .map(|primary_key_index| primary_key_index.write().unwrap()),
+            unique_indexes: self
+                .unique_indexes
+                .iter()
+                .map(|unique_index| unique_index.write().unwrap())
+                .collect(),
+            secondary_indexes: self.secondary_indexes.write().unwrap(),
+        }
+    }
+
+    /// Returns the statistics of the rows of the table, computing them if the rows changed
+    /// since they were last computed.
+    ///
+    /// The statistics are estimates: rows changed concurrently with the computation may not be
+    /// reflected in them.
+    pub(crate) fn statistics(&self) -> Arc<TableStatistics> {
+        if let Some(statistics) = self.statistics.read().unwrap().as_ref() {
+            return statistics.clone();
+        }
+        let statistics = Arc::new(TableStatistics::compute(
+            self.table.schema_ref().column_count(),
+            self.store.iter(),
+        ));
+        *self.statistics.write().unwrap() = Some(statistics.clone());
+        statistics
+    }
+
+    fn invalidate_statistics(&self) {
+        *self.statistics.write().unwrap() = None;
+    }
+
+    /// Returns an iterator over the rows in the table, along with their `RowId`s.
+    pub(crate) fn entries(&self) -> TableStoreEntryIterator<'_> {
+        self.store.entries()
     }
 
     /// Creates a `TableScan` which can be used to iterate over the rows in the table.
@@ -58,15 +326,119 @@ impl TableEntry {
     }
 }
 
+/// The indexes of a table, locked for a change of its rows.
+struct LockedIndexes<'a> {
+    primary_key_index: Option<RwLockWriteGuard<'a, PrimaryKeyIndex>>,
+    unique_indexes: Vec<RwLockWriteGuard<'a, UniqueIndex>>,
+    secondary_indexes: RwLockWriteGuard<'a, Vec<SecondaryIndex>>,
+}
+
+/// The keys of rows in each of the indexes of a table, in the order of the rows.
+struct IndexKeys {
+    primary_keys: Option<Vec<PrimaryKeyColumnValues>>,
+    unique_values: Vec<Vec<ColumnValue>>,
+    secondary_values: Vec<Vec<ColumnValue>>,
+}
+
+impl LockedIndexes<'_> {
+    fn is_empty(&self) -> bool {
+        self.primary_key_index.is_none()
+            && self.unique_indexes.is_empty()
+            && self.secondary_indexes.is_empty()
+    }
+
+    fn keys_for_insert(&self, rows: &[Row]) -> Result<IndexKeys, InsertError> {
+        Ok(IndexKeys {
+            primary_keys: self
+                .primary_key_index
+                .as_ref()
+                .map(|primary_key_index| primary_key_index.keys_for_insert(rows))
+                .transpose()?,
+            unique_values: self
+                .unique_indexes
+                .iter()
+                .map(|unique_index| unique_index.values_for_insert(rows))
+                .collect::<Result<_, _>>()?,
+            secondary_values: self.secondary_values(rows.iter()),
+        })
+    }
+
+    fn keys_for_update(&self, rows: &[(RowId, Row)]) -> Result<IndexKeys, InsertError> {
+        Ok(IndexKeys {
+            primary_keys: self
+                .primary_key_index
+                .as_ref()
+                .map(|primary_key_index| primary_key_index.keys_for_update(rows))
+                .transpose()?,
+            unique_values: self
+                .unique_indexes
+                .iter()
+                .map(|unique_index| unique_index.values_for_update(rows))
+                .collect::<Result<_, _>>()?,
+            secondary_values: self.secondary_values(rows.iter().map(|(_, row)| row)),
+        })
+    }
+
+    fn secondary_values<'r>(
+        &self,
+        rows: impl Iterator<Item = &'r Row> + Clone,
+    ) -> Vec<Vec<ColumnValue>> {
+        self.secondary_indexes
+            .iter()
+            .map(|index| rows.clone().map(|row| index.value_of(row)).collect())
+            .collect()
+    }
+
+    fn insert(&mut self, keys: IndexKeys, row_ids: &[RowId]) {
+        if let (Some(primary_key_index), Some(primary_keys)) =
+            (self.primary_key_index.as_mut(), keys.primary_keys)
+        {
+            for (key, row_id) in primary_keys.into_iter().zip(row_ids) {
+                primary_key_index.insert(key, *row_id);
+            }
+        }
+        for (unique_index, values) in self.unique_indexes.iter_mut().zip(keys.unique_values) {
+            for (value, row_id) in values.into_iter().zip(row_ids) {
+                unique_index.insert(value, *row_id);
+            }
+        }
+        for (index, values) in self.secondary_indexes.iter_mut().zip(keys.secondary_values) {
+            for (value, row_id) in values.into_iter().zip(row_ids) {
+                index.insert(value, *row_id);
+            }
+        }
+    }
+
+    fn remove(&mut self, row: &Row, row_id: RowId) {
+        if let Some(primary_key_index) = self.primary_key_index.as_mut() {
+            primary_key_index.remove(row);
+        }
+        for unique_index in self.unique_indexes.iter_mut() {
+            unique_index.remove(row);
+        }
+        for index in self.secondary_indexes.iter_mut() {
+            index.remove(row, row_id);
+        }
+    }
+
+    fn clear(&mut self) {
+        if let Some(primary_key_index) = self.primary_key_index.as_mut() {
+            primary_key_index.clear();
+        }
+        for unique_index in self.unique_indexes.iter_mut() {
+            unique_index.clear();
+        }
+        for index in self.secondary_indexes.iter_mut() {
+            index.clear();
+        }
+    }
+}
+
 #[cfg(test)]
 impl TableEntry {
     pub(crate) fn table_name(&self) -> &str {
         self.table.name()
     }
-
-    pub(crate) fn get(&self, row_id: RowId) -> Option<Row> {
-        self.store.get(row_id)
-    }
 }
 
 #[cfg(test)]
@@ -75,7 +447,14 @@ mod tests {
     use crate::row;
     use crate::rows;
     use crate::schema;
+    use crate::schema::column::Column;
+    use crate::schema::primary_key::PrimaryKey;
+    use crate::schema::unique_constraint::UniqueConstraint;
+    use crate::schema::Schema;
+    use crate::storage::primary_key_index::PrimaryKeyColumnValues;
     use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use std::convert::Infallible;
 
     #[test]
     fn insert_row() {
@@ -85,7 +464,11 @@ mod tests {
         ));
         table_entry.insert(row![100]).unwrap();
 
-        let rows = table_entry.scan().iter().collect::<Vec<_>>();
+        let rows = table_entry
+            .scan()
+            .iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
 
         assert_eq!(1, rows.len());
         assert_eq!(100, rows[0].column_values()[0].int_value().unwrap());
@@ -100,7 +483,11 @@ mod tests {
         let batch = Batch::new(rows![[10], [20]]);
         table_entry.insert_all(batch).unwrap();
 
-        let rows = table_entry.scan().iter().collect::<Vec<_>>();
+        let rows = table_entry
+            .scan()
+            .iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
         assert_eq!(2, rows.len());
 
         assert!(rows.contains(&row![10]));
@@ -131,6 +518,23 @@ mod tests {
         assert!(entry.is_none());
     }
 
+    #[test]
+    fn delete_rows() {
+        let table_entry = TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int].unwrap(),
+        ));
+        let row_ids = table_entry
+            .insert_all(Batch::new(rows![[10], [20]]))
+            .unwrap();
+
+        let deleted = table_entry.delete_all(&row_ids[..1]);
+        assert_eq!(1, deleted);
+
+        let entries = table_entry.entries().collect::<Vec<_>>();
+        assert_eq!(vec![(row_ids[1], row![20])], entries);
+    }
+
     #[test]
     fn scan_with_filter() {
         let table_entry = TableEntry::new(Table::new(
@@ -142,17 +546,380 @@ mod tests {
 
         struct Id20Filter;
         impl RowFilter for Id20Filter {
-            fn matches(&self, row: &Row) -> bool {
-                row.column_value_at(0).unwrap().int_value().unwrap() == 20
+            type Error = Infallible;
+
+            fn matches(&self, row: &Row) -> Result<bool, Infallible> {
+                Ok(row.column_value_at(0).unwrap().int_value().unwrap() == 20)
             }
         }
 
         let rows = table_entry
             .scan_with_filter(Id20Filter)
             .iter()
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
 
         assert_eq!(1, rows.len());
         assert_eq!(row![20], rows[0]);
     }
+
+    #[test]
+    fn statistics_after_inserts() {
+        let table_entry = TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int].unwrap(),
+        ));
+        table_entry.insert(row![10]).unwrap();
+        assert_eq!(1, table_entry.statistics().row_count());
+
+        table_entry
+            .insert_all(Batch::new(rows![[20], [10]]))
+            .unwrap();
+
+        let statistics = table_entry.statistics();
+        assert_eq!(3, statistics.row_count());
+        assert_eq!(2, statistics.column_statistics(0).unwrap().distinct_count());
+    }
+
+    #[test]
+    fn statistics_after_deletes_and_updates() {
+        let table_entry = TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int].unwrap(),
+        ));
+        let row_ids = table_entry
+            .insert_all(Batch::new(rows![[10], [20], [30]]))
+            .unwrap();
+        assert_eq!(3, table_entry.statistics().row_count());
+
+        table_entry.delete_all(&[row_ids[0]]);
+        table_entry
+            .update_all(vec![(row_ids[1], row![40])])
+            .unwrap();
+
+        let statistics = table_entry.statistics();
+        assert_eq!(2, statistics.row_count());
+        assert_eq!(
+            Some(&ColumnValue::int(30)),
+            statistics.column_statistics(0).unwrap().min()
+        );
+        assert_eq!(
+            Some(&ColumnValue::int(40)),
+            statistics.column_statistics(0).unwrap().max()
+        );
+    }
+
+    #[test]
+    fn statistics_after_truncate() {
+        let table_entry = TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int].unwrap(),
+        ));
+        table_entry.insert(row![10]).unwrap();
+        assert_eq!(1, table_entry.statistics().row_count());
+
+        table_entry.truncate();
+        assert_eq!(0, table_entry.statistics().row_count());
+    }
+
+    #[test]
+    fn statistics_are_cached_until_the_rows_change() {
+        let table_entry = TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int].unwrap(),
+        ));
+        table_entry.insert(row![10]).unwrap();
+
+        let statistics = table_entry.statistics();
+        assert!(Arc::ptr_eq(&statistics, &table_entry.statistics()));
+
+        table_entry.insert(row![20]).unwrap();
+        assert!(!Arc::ptr_eq(&statistics, &table_entry.statistics()));
+    }
+
+    fn employees_with_composite_primary_key() -> Arc<TableEntry> {
+        TableEntry::new(Table::new(
+            "employees",
+            schema!["department_id" => ColumnType::Int, "id" => ColumnType::Int]
+                .unwrap()
+                .add_primary_key(PrimaryKey::composite(["department_id", "id"]))
+                .unwrap(),
+        ))
+    }
+
+    #[test]
+    fn insert_rows_sharing_a_primary_key_column_value() {
+        let table_entry = employees_with_composite_primary_key();
+        table_entry.insert(row![1, 10]).unwrap();
+
+        let row_ids = table_entry
+            .insert_all(Batch::new(rows![[1, 20], [2, 10]]))
+            .unwrap();
+        assert_eq!(vec![2, 3], row_ids);
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_duplicate_primary_key() {
+        let table_entry = employees_with_composite_primary_key();
+        table_entry.insert(row![1, 10]).unwrap();
+
+        let result = table_entry.insert(row![1, 10]);
+        assert!(matches!(
+            result,
+            Err(InsertError::DuplicatePrimaryKey(key)) if key.values() == [ColumnValue::int(1), ColumnValue::int(10)]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_insert_rows_with_duplicate_primary_key_in_batch() {
+        let table_entry = employees_with_composite_primary_key();
+
+        let result = table_entry.insert_all(Batch::new(rows![[1, 10], [2, 10], [1, 10]]));
+        assert!(matches!(result, Err(InsertError::DuplicatePrimaryKey(_))));
+        assert_eq!(0, table_entry.statistics().row_count());
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_null_primary_key() {
+        let table_entry = employees_with_composite_primary_key();
+
+        let result = table_entry.insert(row![1, ColumnValue::null()]);
+        assert!(matches!(result, Err(InsertError::NullPrimaryKey(column)) if column == "id"));
+    }
+
+    #[test]
+    fn insert_row_with_primary_key_of_deleted_row() {
+        let table_entry = employees_with_composite_primary_key();
+        let row_id = table_entry.insert(row![1, 10]).unwrap();
+        table_entry.delete_all(&[row_id]);
+
+        assert!(table_entry.insert(row![1, 10]).is_ok());
+    }
+
+    #[test]
+    fn insert_row_with_primary_key_of_truncated_row() {
+        let table_entry = employees_with_composite_primary_key();
+        table_entry.insert(row![1, 10]).unwrap();
+        table_entry.truncate();
+
+        assert!(table_entry.insert(row![1, 10]).is_ok());
+    }
+
+    #[test]
+    fn update_primary_key_of_row() {
+        let table_entry = employees_with_composite_primary_key();
+        let row_id = table_entry.insert(row![1, 10]).unwrap();
+
+        assert_eq!(
+            1,
+            table_entry.update_all(vec![(row_id, row![1, 20])]).unwrap()
+        );
+        assert!(table_entry.insert(row![1, 10]).is_ok());
+        assert!(matches!(
+            table_entry.insert(row![1, 20]),
+            Err(InsertError::DuplicatePrimaryKey(_))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_update_row_to_the_primary_key_of_another_row() {
+        let table_entry = employees_with_composite_primary_key();
+        let row_ids = table_entry
+            .insert_all(Batch::new(rows![[1, 10], [1, 20]]))
+            .unwrap();
+
+        let result = table_entry.update_all(vec![(row_ids[0], row![1, 20])]);
+        assert_eq!(
+            Err(InsertError::DuplicatePrimaryKey(
+                PrimaryKeyColumnValues::new(&row![1, 20], &[0, 1])
+            )),
+            result
+        );
+        assert_eq!(Some(row![1, 10]), table_entry.get(row_ids[0]));
+    }
+
+    fn employees_with_unique_email() -> Arc<TableEntry> {
+        TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "email" => ColumnType::Text]
+                .unwrap()
+                .add_primary_key(PrimaryKey::single("id"))
+                .unwrap()
+                .add_unique_constraint(UniqueConstraint::new("email"))
+                .unwrap(),
+        ))
+    }
+
+    #[test]
+    fn insert_rows_with_distinct_unique_values() {
+        let table_entry = employees_with_unique_email();
+
+        let row_ids = table_entry
+            .insert_all(Batch::new(rows![[1, "a@relop"], [2, "b@relop"]]))
+            .unwrap();
+        assert_eq!(vec![1, 2], row_ids);
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_duplicate_unique_value() {
+        let table_entry = employees_with_unique_email();
+        table_entry.insert(row![1, "a@relop"]).unwrap();
+
+        let result = table_entry.insert(row![2, "a@relop"]);
+        assert_eq!(
+            Err(InsertError::DuplicateUniqueValue {
+                column: "email".to_string(),
+                value: ColumnValue::text("a@relop"),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_duplicate_unique_value_does_not_insert_its_primary_key() {
+        let table_entry = employees_with_unique_email();
+        table_entry.insert(row![1, "a@relop"]).unwrap();
+
+        assert!(table_entry.insert(row![2, "a@relop"]).is_err());
+        assert!(table_entry.insert(row![2, "b@relop"]).is_ok());
+    }
+
+    #[test]
+    fn insert_rows_with_null_unique_values() {
+        let table_entry = employees_with_unique_email();
+
+        let result = table_entry.insert_all(Batch::new(vec![
+            row![1, ColumnValue::null()],
+            row![2, ColumnValue::null()],
+        ]));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn attempt_to_update_row_to_the_unique_value_of_another_row() {
+        let table_entry = employees_with_unique_email();
+        let row_ids = table_entry
+            .insert_all(Batch::new(rows![[1, "a@relop"], [2, "b@relop"]]))
+            .unwrap();
+
+        let result = table_entry.update_all(vec![(row_ids[1], row![2, "a@relop"])]);
+        assert!(matches!(
+            result,
+            Err(InsertError::DuplicateUniqueValue { .. })
+        ));
+        assert_eq!(Some(row![2, "b@relop"]), table_entry.get(row_ids[1]));
+    }
+
+    #[test]
+    fn update_unique_value_of_row() {
+        let table_entry = employees_with_unique_email();
+        let row_id = table_entry.insert(row![1, "a@relop"]).unwrap();
+
+        table_entry
+            .update_all(vec![(row_id, row![1, "c@relop"])])
+            .unwrap();
+        assert!(table_entry.insert(row![2, "a@relop"]).is_ok());
+        assert!(table_entry.insert(row![3, "c@relop"]).is_err());
+    }
+
+    #[test]
+    fn maintain_secondary_index_on_inserts_updates_and_deletes() {
+        let table_entry = TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+        ));
+        table_entry.insert(row![1, 10]).unwrap();
+        table_entry.create_index("department_id").unwrap();
+
+        let row_ids = table_entry
+            .insert_all(Batch::new(rows![[2, 10], [3, 20]]))
+            .unwrap();
+        assert_eq!(
+            Some(vec![1, 2]),
+            table_entry.index_lookup("department_id", &ColumnValue::int(10))
+        );
+
+        table_entry
+            .update_all(vec![(row_ids[0], row![2, 20])])
+            .unwrap();
+        table_entry.delete_all(&[row_ids[1]]);
+        assert_eq!(
+            Some(vec![1]),
+            table_entry.index_lookup("department_id", &ColumnValue::int(10))
+        );
+        assert_eq!(
+            Some(vec![2]),
+            table_entry.index_lookup("department_id", &ColumnValue::int(20))
+        );
+
+        table_entry.truncate();
+        assert_eq!(
+            Some(vec![]),
+            table_entry.index_lookup("department_id", &ColumnValue::int(10))
+        );
+    }
+
+    #[test]
+    fn index_lookup_on_a_non_indexed_column() {
+        let table_entry = TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int].unwrap(),
+        ));
+        assert!(table_entry
+            .index_lookup("id", &ColumnValue::int(1))
+            .is_none());
+    }
+
+    fn employees_with_auto_increment_id() -> Arc<TableEntry> {
+        TableEntry::new(Table::new(
+            "employees",
+            Schema::new()
+                .add_column_definition(Column::new("id", ColumnType::Int).auto_increment())
+                .unwrap()
+                .add_column("name", ColumnType::Text)
+                .unwrap(),
+        ))
+    }
+
+    #[test]
+    fn insert_rows_filling_the_auto_increment_column() {
+        let table_entry = employees_with_auto_increment_id();
+        let row_id = table_entry
+            .insert(Row::filled(vec![
+                ColumnValue::null(),
+                ColumnValue::text("relop"),
+            ]))
+            .unwrap();
+        let row_ids = table_entry
+            .insert_all(Batch::new(vec![
+                Row::filled(vec![ColumnValue::null(), ColumnValue::text("query")]),
+                Row::filled(vec![ColumnValue::null(), ColumnValue::text("plan")]),
+            ]))
+            .unwrap();
+
+        assert_eq!(Some(row![1, "relop"]), table_entry.get(row_id));
+        assert_eq!(Some(row![2, "query"]), table_entry.get(row_ids[0]));
+        assert_eq!(Some(row![3, "plan"]), table_entry.get(row_ids[1]));
+    }
+
+    #[test]
+    fn do_not_reuse_auto_increment_values_after_deletes() {
+        let table_entry = employees_with_auto_increment_id();
+        let row_ids = table_entry
+            .insert_all(Batch::new(vec![
+                Row::filled(vec![ColumnValue::null(), ColumnValue::text("relop")]),
+                Row::filled(vec![ColumnValue::null(), ColumnValue::text("query")]),
+            ]))
+            .unwrap();
+        table_entry.delete_all(&row_ids);
+
+        let row_id = table_entry
+            .insert(Row::filled(vec![
+                ColumnValue::null(),
+                ColumnValue::text("plan"),
+            ]))
+            .unwrap();
+
+        assert_eq!(Some(row![3, "plan"]), table_entry.get(row_id));
+    }
 }
@@ -1,6 +1,8 @@
 use crate::storage::row::Row;
 use crate::storage::row_filter::{NoFilter, RowFilter};
-use crate::storage::table_store::{TableStore, TableStoreIterator};
+use crate::storage::table_store::{RowId, TableStore, TableStoreEntryIterator, TableStoreIterator};
+#[cfg(test)]
+use std::convert::Infallible;
 use std::sync::Arc;
 
 /// A handle to a table scan operation that owns the `TableStore`.
@@ -42,22 +44,108 @@ impl<F: RowFilter> TableScan<F> {
             filter: self.filter.clone(),
         }
     }
+
+    /// Returns an iterator over the rows in the table, along with their `RowId`s (e.g., for
+    /// deleting or updating the matching rows).
+    ///
+    /// The returned `TableEntryIterator` borrows from this `TableScan` to ensure validity.
+    pub(crate) fn iter_with_ids(&self) -> TableEntryIterator<'_, F> {
+        TableEntryIterator {
+            iter: self.store.entries(),
+            filter: self.filter.clone(),
+        }
+    }
+
+    /// Returns the rows in the table matching the filter, evaluating the filter across the given
+    /// number of threads.
+    ///
+    /// References to the rows are partitioned into (contiguous) chunks, one per thread, which
+    /// evaluate the filter on the borrowed rows and clone only the matching ones. The matching rows
+    /// of the chunks are merged in the order of the chunks, so the rows are returned in the same
+    /// order as the ones produced by `.iter()`.
+    ///
+    /// Returns the error of the first row (in the order of the rows) the filter can not be
+    /// evaluated on, if any.
+    pub(crate) fn filter_in_parallel(&self, parallelism: usize) -> Result<Vec<Row>, F::Error> {
+        let row_refs = self.store.row_refs();
+        let chunk_size = row_refs.len().div_ceil(parallelism.max(1)).max(1);
+
+        std::thread::scope(|scope| {
+            let workers = row_refs
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(|| {
+                        chunk
+                            .iter()
+                            .map(|row_ref| row_ref.value())
+                            .filter_map(|row| match self.filter.matches(row) {
+                                Ok(true) => Some(Ok(row.clone())),
+                                Ok(false) => None,
+                                Err(error) => Some(Err(error)),
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut rows = Vec::new();
+            for worker in workers {
+                let matching_rows = worker
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))?;
+                rows.extend(matching_rows);
+            }
+            Ok(rows)
+        })
+    }
 }
 
 /// Iterator that scans rows in a table.
 ///
 /// This iterator borrows from `TableScan` (via the `TableStore` reference)
 /// and thus cannot outlive the `TableScan`.
+///
+/// It yields the rows matching the filter, and the error of each row the filter can not be
+/// evaluated on.
 pub(crate) struct TableIterator<'a, F: RowFilter = NoFilter> {
     iter: TableStoreIterator<'a>,
     filter: Arc<F>,
 }
 
 impl<F: RowFilter> Iterator for TableIterator<'_, F> {
-    type Item = Row;
+    type Item = Result<Row, F::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .find_map(|row| match self.filter.matches(&row) {
+                Ok(true) => Some(Ok(row)),
+                Ok(false) => None,
+                Err(error) => Some(Err(error)),
+            })
+    }
+}
+
+/// Iterator that scans rows in a table, along with their `RowId`s.
+///
+/// It yields the rows matching the filter, and the error of each row the filter can not be
+/// evaluated on.
+pub(crate) struct TableEntryIterator<'a, F: RowFilter = NoFilter> {
+    iter: TableStoreEntryIterator<'a>,
+    filter: Arc<F>,
+}
+
+impl<F: RowFilter> Iterator for TableEntryIterator<'_, F> {
+    type Item = Result<(RowId, Row), F::Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.by_ref().find(|row| self.filter.matches(row))
+        self.iter
+            .by_ref()
+            .find_map(|(row_id, row)| match self.filter.matches(&row) {
+                Ok(true) => Some(Ok((row_id, row))),
+                Ok(false) => None,
+                Err(error) => Some(Err(error)),
+            })
     }
 }
 
@@ -75,15 +163,29 @@ mod tests {
         let table_scan = TableScan::new(store);
         let mut iterator = table_scan.iter();
 
-        let row1 = iterator.next().unwrap();
+        let row1 = iterator.next().unwrap().unwrap();
         assert_eq!(row![1], row1);
 
-        let row2 = iterator.next().unwrap();
+        let row2 = iterator.next().unwrap().unwrap();
         assert_eq!(row![2], row2);
 
         assert!(iterator.next().is_none());
     }
 
+    #[test]
+    fn scan_table_with_ids() {
+        let store = Arc::new(TableStore::new());
+        let row_id1 = store.insert(row![1]);
+        let row_id2 = store.insert(row![2]);
+
+        let table_scan = TableScan::new(store);
+        let mut iterator = table_scan.iter_with_ids();
+
+        assert_eq!((row_id1, row![1]), iterator.next().unwrap().unwrap());
+        assert_eq!((row_id2, row![2]), iterator.next().unwrap().unwrap());
+        assert!(iterator.next().is_none());
+    }
+
     #[test]
     fn scan_empty_table() {
         let store = Arc::new(TableStore::new());
@@ -102,17 +204,104 @@ mod tests {
 
         struct Over25Filter;
         impl RowFilter for Over25Filter {
-            fn matches(&self, row: &Row) -> bool {
-                row.column_value_at(0).unwrap().int_value().unwrap() > 25
+            type Error = Infallible;
+
+            fn matches(&self, row: &Row) -> Result<bool, Infallible> {
+                Ok(row.column_value_at(0).unwrap().int_value().unwrap() > 25)
             }
         }
 
         let table_scan = TableScan::with_filter(store, Over25Filter);
         let mut iterator = table_scan.iter();
 
-        let row = iterator.next().unwrap();
+        let row = iterator.next().unwrap().unwrap();
         assert_eq!(row![30], row);
 
         assert!(iterator.next().is_none());
     }
+
+    /// Matches the rows with an even id, and can not be evaluated on the rows with a negative id.
+    struct EvenFilter;
+    impl RowFilter for EvenFilter {
+        type Error = i64;
+
+        fn matches(&self, row: &Row) -> Result<bool, i64> {
+            let id = row.column_value_at(0).unwrap().int_value().unwrap();
+            if id < 0 {
+                return Err(id);
+            }
+            Ok(id % 2 == 0)
+        }
+    }
+
+    #[test]
+    fn scan_table_with_a_filter_failing_on_a_row() {
+        let store = Arc::new(TableStore::new());
+        store.insert(row![2]);
+        store.insert(row![-1]);
+        store.insert(row![4]);
+
+        let table_scan = TableScan::with_filter(store, EvenFilter);
+        let rows = table_scan.iter().collect::<Vec<_>>();
+
+        assert_eq!(vec![Ok(row![2]), Err(-1), Ok(row![4])], rows);
+    }
+
+    #[test]
+    fn scan_table_with_ids_and_a_filter_failing_on_a_row() {
+        let store = Arc::new(TableStore::new());
+        let row_id1 = store.insert(row![2]);
+        store.insert(row![3]);
+        store.insert(row![-1]);
+        let row_id4 = store.insert(row![4]);
+
+        let table_scan = TableScan::with_filter(store, EvenFilter);
+        let rows = table_scan.iter_with_ids().collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![Ok((row_id1, row![2])), Err(-1), Ok((row_id4, row![4]))],
+            rows
+        );
+    }
+
+    #[test]
+    fn filter_in_parallel_produces_the_rows_of_a_sequential_scan() {
+        let store = Arc::new(TableStore::new());
+        for id in 0..1000 {
+            store.insert(row![id]);
+        }
+
+        let table_scan = TableScan::with_filter(store, EvenFilter);
+        let sequential = table_scan.iter().collect::<Result<Vec<_>, _>>().unwrap();
+
+        for parallelism in [1, 3, 4, 2000] {
+            assert_eq!(
+                sequential,
+                table_scan.filter_in_parallel(parallelism).unwrap()
+            );
+        }
+        assert_eq!(500, sequential.len());
+    }
+
+    #[test]
+    fn filter_in_parallel_returns_the_error_of_the_first_failing_row() {
+        let store = Arc::new(TableStore::new());
+        for id in [2, -3, 4, 6, -7, 8] {
+            store.insert(row![id]);
+        }
+
+        let table_scan = TableScan::with_filter(store, EvenFilter);
+
+        for parallelism in [1, 2, 3, 6] {
+            assert_eq!(Err(-3), table_scan.filter_in_parallel(parallelism));
+        }
+    }
+
+    #[test]
+    fn filter_in_parallel_over_an_empty_table() {
+        let store = Arc::new(TableStore::new());
+        let table_scan = TableScan::new(store);
+
+        assert!(table_scan.filter_in_parallel(4).unwrap().is_empty());
+    }
 }
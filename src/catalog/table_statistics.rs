@@ -0,0 +1,143 @@
+use crate::storage::row::Row;
+use crate::types::column_value::ColumnValue;
+use std::collections::HashSet;
+
+/// Statistics of the rows of a table: the row count and the statistics of each of its columns.
+///
+/// The statistics are meant for the optimizer (e.g., to estimate the size of a table), and
+/// describe the rows of the table at the time they were computed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableStatistics {
+    row_count: usize,
+    columns: Vec<ColumnStatistics>,
+}
+
+/// Statistics of the values of a column.
+///
+/// `Null` values are not taken into account: a column holding only `Null` values has
+/// no minimum, no maximum, and no distinct values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnStatistics {
+    min: Option<ColumnValue>,
+    max: Option<ColumnValue>,
+    distinct_count: usize,
+}
+
+impl TableStatistics {
+    /// Computes the statistics of the given rows of a table with the given number of columns.
+    pub(crate) fn compute(column_count: usize, rows: impl Iterator<Item = Row>) -> Self {
+        let mut row_count = 0;
+        let mut distinct_values = vec![HashSet::new(); column_count];
+        for row in rows {
+            row_count += 1;
+            for (values, value) in distinct_values.iter_mut().zip(row.column_values()) {
+                if !value.is_null() {
+                    values.insert(value.clone());
+                }
+            }
+        }
+
+        let columns = distinct_values
+            .into_iter()
+            .map(|values| ColumnStatistics {
+                min: values.iter().min().cloned(),
+                max: values.iter().max().cloned(),
+                distinct_count: values.len(),
+            })
+            .collect();
+
+        Self { row_count, columns }
+    }
+
+    /// Returns the number of rows in the table.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Returns the statistics of the column at the given position (in the schema of the table).
+    pub fn column_statistics(&self, position: usize) -> Option<&ColumnStatistics> {
+        self.columns.get(position)
+    }
+}
+
+impl ColumnStatistics {
+    /// Returns the smallest (non-null) value of the column.
+    pub fn min(&self) -> Option<&ColumnValue> {
+        self.min.as_ref()
+    }
+
+    /// Returns the largest (non-null) value of the column.
+    pub fn max(&self) -> Option<&ColumnValue> {
+        self.max.as_ref()
+    }
+
+    /// Returns the number of distinct (non-null) values of the column.
+    pub fn distinct_count(&self) -> usize {
+        self.distinct_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::row;
+    use crate::rows;
+
+    #[test]
+    fn statistics_of_rows() {
+        let statistics = TableStatistics::compute(
+            2,
+            rows![[3, "relop"], [1, "query"], [3, "relop"]].into_iter(),
+        );
+
+        assert_eq!(3, statistics.row_count());
+
+        let id = statistics.column_statistics(0).unwrap();
+        assert_eq!(Some(&ColumnValue::int(1)), id.min());
+        assert_eq!(Some(&ColumnValue::int(3)), id.max());
+        assert_eq!(2, id.distinct_count());
+
+        let name = statistics.column_statistics(1).unwrap();
+        assert_eq!(Some(&ColumnValue::text("query")), name.min());
+        assert_eq!(Some(&ColumnValue::text("relop")), name.max());
+        assert_eq!(2, name.distinct_count());
+    }
+
+    #[test]
+    fn statistics_of_rows_with_nulls() {
+        let statistics = TableStatistics::compute(
+            1,
+            vec![
+                row![ColumnValue::null()],
+                row![10],
+                row![ColumnValue::null()],
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(3, statistics.row_count());
+
+        let column = statistics.column_statistics(0).unwrap();
+        assert_eq!(Some(&ColumnValue::int(10)), column.min());
+        assert_eq!(Some(&ColumnValue::int(10)), column.max());
+        assert_eq!(1, column.distinct_count());
+    }
+
+    #[test]
+    fn statistics_without_rows() {
+        let statistics = TableStatistics::compute(1, std::iter::empty());
+
+        assert_eq!(0, statistics.row_count());
+
+        let column = statistics.column_statistics(0).unwrap();
+        assert_eq!(None, column.min());
+        assert_eq!(None, column.max());
+        assert_eq!(0, column.distinct_count());
+    }
+
+    #[test]
+    fn statistics_of_a_non_existent_column() {
+        let statistics = TableStatistics::compute(1, std::iter::empty());
+        assert!(statistics.column_statistics(1).is_none());
+    }
+}
@@ -1,8 +1,9 @@
-use crate::catalog::error::{CatalogError, InsertError};
+use crate::catalog::error::{CatalogError, InsertError, LoadCsvError};
 use crate::query::executor::error::ExecutionError;
 use crate::query::lexer::error::LexError;
 use crate::query::parser::error::ParseError;
 use crate::query::plan::error::PlanningError;
+use crate::types::error::ConversionError;
 
 /// Represents the various errors that can occur when using the `Relop` client.
 #[derive(Debug)]
@@ -11,12 +12,81 @@ pub enum ClientError {
     Catalog(CatalogError),
     /// Errors related to data insertion (e.g., type mismatch, duplicate key).
     Insert(InsertError),
+    /// Errors related to loading rows from CSV (e.g., a field of the wrong type).
+    LoadCsv(LoadCsvError),
     /// Errors related to lexical analysis of the query string.
     Lex(LexError),
     /// Errors related to parsing the query tokens into an AST.
-    Parse(ParseError),
+    Parse {
+        /// The parse error.
+        error: ParseError,
+        /// The line in the query where parsing failed.
+        line: usize,
+        /// The column in the query where parsing failed.
+        column: usize,
+    },
     /// Errors related to executing the logical plan.
     Execution(ExecutionError),
     /// Errors related to logical planning.
     Plan(PlanningError),
+    /// Errors related to converting the rows of a query result into Rust values (via
+    /// `Relop::execute_typed`).
+    FromRow(FromRowError),
+    /// Error when a query expected to produce rows (e.g., via `Relop::execute_typed`) does not
+    /// (e.g., an insert).
+    NotAResultSet,
+    /// Errors of a statement of a script (executed via `Relop::execute_all`).
+    Statement {
+        /// The (0-based) index of the failing statement in the script.
+        index: usize,
+        /// The error of the statement.
+        error: Box<ClientError>,
+    },
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Lex(error) => write!(f, "{}", error),
+            ClientError::Parse {
+                error,
+                line,
+                column,
+            } => write!(f, "{:?} at line {}, column {}", error, line, column),
+            ClientError::Execution(error) => write!(f, "{}", error),
+            ClientError::Statement { index, error } => write!(f, "statement {}: {}", index, error),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/// Represents errors that occur while converting a row of a query result into a Rust value
+/// (see [`FromRow`](crate::client::from_row::FromRow)).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FromRowError {
+    /// The row has no visible column of the name.
+    UnknownColumn(String),
+    /// The unqualified column name matches more than one column of the row.
+    AmbiguousColumn(String),
+    /// The number of columns of the row differs from the number of values being converted
+    /// into (e.g., the arity of a tuple).
+    ColumnCountMismatch {
+        /// The number of values being converted into.
+        expected: usize,
+        /// The number of columns of the row.
+        actual: usize,
+    },
+    /// The value of a column does not convert into the Rust type.
+    Conversion {
+        /// The name of the column.
+        column: String,
+        /// The error of the conversion.
+        error: ConversionError,
+    },
+}
+
+impl std::fmt::Display for FromRowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
 }
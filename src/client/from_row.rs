@@ -0,0 +1,286 @@
+use crate::client::error::FromRowError;
+use crate::schema::error::SchemaError;
+use crate::storage::row_view::RowView;
+use crate::types::column_value::ColumnValue;
+use crate::types::date::Date;
+use crate::types::error::ConversionError;
+
+/// Converts a row of a query result into a Rust value, so that [`Relop::execute_typed`]
+/// produces the rows of a query as values of the type.
+///
+/// `FromRow` is implemented for tuples (of up to 6 elements) of [`FromColumnValue`] types, which
+/// read the columns positionally. A struct implements it by reading the columns by name with
+/// [`column`].
+///
+/// [`Relop::execute_typed`]: crate::client::Relop::execute_typed
+///
+/// # Examples
+///
+/// ```
+/// use relop::catalog::Catalog;
+/// use relop::client::error::FromRowError;
+/// use relop::client::from_row::{column, FromRow};
+/// use relop::client::Relop;
+/// use relop::storage::row_view::RowView;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Employee {
+///     id: i64,
+///     name: Option<String>,
+/// }
+///
+/// impl FromRow for Employee {
+///     fn from_row(row_view: &RowView) -> Result<Self, FromRowError> {
+///         Ok(Employee {
+///             id: column(row_view, "id")?,
+///             name: column(row_view, "name")?,
+///         })
+///     }
+/// }
+///
+/// let relop = Relop::new(Catalog::new());
+/// relop.execute("create table employees (id int, name text)").unwrap();
+/// relop.execute("insert into employees values (1, 'relop')").unwrap();
+///
+/// let employees = relop
+///     .execute_typed::<Employee>("select id, name from employees")
+///     .unwrap();
+/// assert_eq!(
+///     vec![Employee { id: 1, name: Some("relop".to_string()) }],
+///     employees
+/// );
+/// ```
+pub trait FromRow: Sized {
+    /// Converts the visible columns of the row into a value.
+    fn from_row(row_view: &RowView) -> Result<Self, FromRowError>;
+}
+
+/// Converts the value of a column into a Rust value.
+///
+/// It is implemented for `i64`, `String`, `bool` and `Date`, which reject `Null` values, and for
+/// `Option` of these, which reads `Null` as `None`.
+pub trait FromColumnValue: Sized {
+    /// Converts the value of a column into a Rust value.
+    fn from_column_value(value: &ColumnValue) -> Result<Self, ConversionError>;
+}
+
+impl FromColumnValue for i64 {
+    fn from_column_value(value: &ColumnValue) -> Result<Self, ConversionError> {
+        i64::try_from(value)
+    }
+}
+
+impl FromColumnValue for String {
+    fn from_column_value(value: &ColumnValue) -> Result<Self, ConversionError> {
+        String::try_from(value)
+    }
+}
+
+impl FromColumnValue for bool {
+    fn from_column_value(value: &ColumnValue) -> Result<Self, ConversionError> {
+        bool::try_from(value)
+    }
+}
+
+impl FromColumnValue for Date {
+    fn from_column_value(value: &ColumnValue) -> Result<Self, ConversionError> {
+        Date::try_from(value)
+    }
+}
+
+impl<T: FromColumnValue> FromColumnValue for Option<T> {
+    fn from_column_value(value: &ColumnValue) -> Result<Self, ConversionError> {
+        if value.is_null() {
+            return Ok(None);
+        }
+        T::from_column_value(value).map(Some)
+    }
+}
+
+/// Reads the value of a column of the row by name, converted into a Rust value.
+///
+/// # Returns
+///
+/// * `Ok(T)` if the column exists and its value converts into `T`.
+/// * `Err(FromRowError::UnknownColumn)` if the column is not part of the row.
+/// * `Err(FromRowError::AmbiguousColumn)` if the unqualified column name matches multiple columns.
+/// * `Err(FromRowError::Conversion)` if the value does not convert into `T`.
+pub fn column<T: FromColumnValue>(
+    row_view: &RowView,
+    column_name: &str,
+) -> Result<T, FromRowError> {
+    let value = row_view
+        .column_value_by(column_name)
+        .map_err(|error| match error {
+            SchemaError::AmbiguousColumnName(column_name) => {
+                FromRowError::AmbiguousColumn(column_name)
+            }
+            _ => FromRowError::UnknownColumn(column_name.to_string()),
+        })?
+        .ok_or_else(|| FromRowError::UnknownColumn(column_name.to_string()))?;
+
+    T::from_column_value(value).map_err(|error| FromRowError::Conversion {
+        column: column_name.to_string(),
+        error,
+    })
+}
+
+/// Reads the value of a column of the row by its (0-based) position, converted into a Rust value.
+fn column_at<T: FromColumnValue>(row_view: &RowView, index: usize) -> Result<T, FromRowError> {
+    let column_name = || {
+        row_view
+            .column_name_at(index)
+            .unwrap_or_default()
+            .to_string()
+    };
+    let value = row_view
+        .column_value_at(index)
+        .map_err(|_| FromRowError::UnknownColumn(column_name()))?;
+
+    T::from_column_value(value).map_err(|error| FromRowError::Conversion {
+        column: column_name(),
+        error,
+    })
+}
+
+macro_rules! impl_from_row_for_tuple {
+    ($arity:expr; $($element:ident => $index:tt),+) => {
+        impl<$($element: FromColumnValue),+> FromRow for ($($element,)+) {
+            fn from_row(row_view: &RowView) -> Result<Self, FromRowError> {
+                if row_view.len() != $arity {
+                    return Err(FromRowError::ColumnCountMismatch {
+                        expected: $arity,
+                        actual: row_view.len(),
+                    });
+                }
+                Ok(($(column_at::<$element>(row_view, $index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(1; A => 0);
+impl_from_row_for_tuple!(2; A => 0, B => 1);
+impl_from_row_for_tuple!(3; A => 0, B => 1, C => 2);
+impl_from_row_for_tuple!(4; A => 0, B => 1, C => 2, D => 3);
+impl_from_row_for_tuple!(5; A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_from_row_for_tuple!(6; A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::executor::result_set::ResultSet;
+    use crate::query::executor::values_result_set::ValuesResultSet;
+    use crate::schema;
+    use crate::schema::Schema;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use std::sync::Arc;
+
+    fn employee(id: ColumnValue, name: ColumnValue) -> ValuesResultSet {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        ValuesResultSet::new(vec![Row::filled(vec![id, name])], Arc::new(schema))
+    }
+
+    #[test]
+    fn convert_a_row_into_a_tuple() {
+        let result_set = employee(ColumnValue::int(1), ColumnValue::text("relop"));
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        let employee = <(i64, String)>::from_row(&row_view).unwrap();
+        assert_eq!((1, "relop".to_string()), employee);
+    }
+
+    #[test]
+    fn convert_a_null_value_into_none() {
+        let result_set = employee(ColumnValue::int(1), ColumnValue::null());
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        let employee = <(i64, Option<String>)>::from_row(&row_view).unwrap();
+        assert_eq!((1, None), employee);
+    }
+
+    #[test]
+    fn attempt_to_convert_a_null_value_into_a_non_optional_value() {
+        let result_set = employee(ColumnValue::int(1), ColumnValue::null());
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        assert_eq!(
+            Err(FromRowError::Conversion {
+                column: "name".to_string(),
+                error: ConversionError::NullValue {
+                    expected: ColumnType::Text
+                },
+            }),
+            <(i64, String)>::from_row(&row_view)
+        );
+    }
+
+    #[test]
+    fn attempt_to_convert_a_row_into_a_tuple_of_a_different_arity() {
+        let result_set = employee(ColumnValue::int(1), ColumnValue::text("relop"));
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        assert_eq!(
+            Err(FromRowError::ColumnCountMismatch {
+                expected: 3,
+                actual: 2
+            }),
+            <(i64, String, bool)>::from_row(&row_view)
+        );
+    }
+
+    #[test]
+    fn attempt_to_convert_a_value_of_a_different_type() {
+        let result_set = employee(ColumnValue::int(1), ColumnValue::text("relop"));
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        assert_eq!(
+            Err(FromRowError::Conversion {
+                column: "id".to_string(),
+                error: ConversionError::TypeMismatch {
+                    expected: ColumnType::Text,
+                    actual: ColumnType::Int
+                },
+            }),
+            <(String, String)>::from_row(&row_view)
+        );
+    }
+
+    #[test]
+    fn read_a_column_by_name() {
+        let result_set = employee(ColumnValue::int(1), ColumnValue::text("relop"));
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        assert_eq!(Ok("relop".to_string()), column::<String>(&row_view, "name"));
+    }
+
+    #[test]
+    fn attempt_to_read_an_unknown_column_by_name() {
+        let result_set = employee(ColumnValue::int(1), ColumnValue::text("relop"));
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        assert_eq!(
+            Err(FromRowError::UnknownColumn("city".to_string())),
+            column::<String>(&row_view, "city")
+        );
+    }
+
+    #[test]
+    fn attempt_to_read_an_ambiguous_column_by_name() {
+        let schema = Schema::new().with_computed_columns(&[
+            ("employees.id", ColumnType::Int),
+            ("departments.id", ColumnType::Int),
+        ]);
+        let result_set = ValuesResultSet::new(
+            vec![Row::filled(vec![ColumnValue::int(1), ColumnValue::int(2)])],
+            Arc::new(schema),
+        );
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        assert_eq!(
+            Err(FromRowError::AmbiguousColumn("id".to_string())),
+            column::<i64>(&row_view, "id")
+        );
+    }
+}
@@ -5,20 +5,29 @@
 //! provides methods for table creation, data insertion, and query execution.
 
 pub mod error;
+pub mod from_row;
 
+pub use crate::query::executor::limits::{ExceededLimit, ExecutionLimits};
 pub use crate::query::executor::result::QueryResult;
+use std::io::Read;
 use std::sync::Arc;
 
+use crate::catalog::table_statistics::TableStatistics;
 use crate::catalog::Catalog;
 use crate::client::error::ClientError;
+use crate::client::from_row::FromRow;
 use crate::query::executor::Executor;
+use crate::query::lexer::token::TokenStream;
 use crate::query::lexer::Lexer;
+use crate::query::optimizer::Optimizer;
+use crate::query::parser::ordering_key::OrderingDirection;
 use crate::query::parser::Parser;
-use crate::query::plan::LogicalPlanner;
+use crate::query::plan::{LogicalPlan, LogicalPlanner};
 use crate::schema::Schema;
 use crate::storage::batch::Batch;
 use crate::storage::row::Row;
 use crate::storage::table_store::RowId;
+use crate::types::column_value::ColumnValue;
 
 /// The main client interface for the relational operator library.
 ///
@@ -29,6 +38,11 @@ use crate::storage::table_store::RowId;
 /// - Executing SQL queries through the full query processing pipeline
 pub struct Relop {
     catalog: Arc<Catalog>,
+    scan_parallelism: usize,
+    case_insensitive_identifiers: bool,
+    descending_default_ordering: bool,
+    max_token_length: Option<usize>,
+    execution_limits: ExecutionLimits,
 }
 
 impl Relop {
@@ -48,7 +62,152 @@ impl Relop {
     /// let relop = Relop::new(catalog);
     /// ```
     pub fn new(catalog: Arc<Catalog>) -> Relop {
-        Self { catalog }
+        Self {
+            catalog,
+            scan_parallelism: 1,
+            case_insensitive_identifiers: false,
+            descending_default_ordering: false,
+            max_token_length: None,
+            execution_limits: ExecutionLimits::new(),
+        }
+    }
+
+    /// Sets the degree of parallelism of the filtered table scans: the number of threads the
+    /// (pushed-down) filter of a table scan is evaluated across.
+    ///
+    /// The rows of the table are partitioned into chunks, one per thread, and the matching rows
+    /// are produced in the same order as the ones of a sequential scan, so the results are the
+    /// same for any degree of parallelism. A parallel scan reads all the rows of the table before
+    /// producing any, so a scan with a (pushed-down) limit stays sequential.
+    ///
+    /// The default is `1`, a sequential scan; `0` is treated as `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `scan_parallelism` - The number of threads a filtered table scan uses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    ///
+    /// let relop = Relop::new(Catalog::new()).with_scan_parallelism(4);
+    /// ```
+    pub fn with_scan_parallelism(mut self, scan_parallelism: usize) -> Relop {
+        self.scan_parallelism = scan_parallelism.max(1);
+        self
+    }
+
+    /// Makes the identifiers of the queries case-insensitive: the unquoted identifiers (table
+    /// names, column names and aliases) are folded to lowercase, so `SELECT Name FROM Employees`
+    /// reads the table `employees`. Quoted identifiers (e.g., `"Employees"`) are kept as written.
+    ///
+    /// Keywords are case-insensitive either way. Column names are matched ignoring ASCII case
+    /// either way (see [`Schema::column_position`]), so the option matters for the table names,
+    /// which are otherwise matched exactly, and for the names of the aliased columns in the result.
+    /// With the option, the tables are expected to be created with lowercase names.
+    ///
+    /// The default is case-sensitive identifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new()).with_case_insensitive_identifiers();
+    /// relop
+    ///     .create_table("employees", schema!["name" => ColumnType::Text].unwrap())
+    ///     .unwrap();
+    ///
+    /// assert!(relop.execute("SELECT Name FROM Employees").is_ok());
+    /// ```
+    pub fn with_case_insensitive_identifiers(mut self) -> Relop {
+        self.case_insensitive_identifiers = true;
+        self
+    }
+
+    /// Orders the keys of `ORDER BY` given without `ASC` or `DESC` in descending order (with
+    /// `NULL`s first, as for `DESC`), for teams whose convention is to list the latest first.
+    ///
+    /// An explicit `ASC` or `DESC` still applies. The default is ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::{rows, schema};
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new()).with_descending_default_ordering();
+    /// relop
+    ///     .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+    ///     .unwrap();
+    /// relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+    ///
+    /// let query_result = relop.execute("SELECT id FROM employees ORDER BY id").unwrap();
+    /// let result_set = query_result.result_set().unwrap();
+    /// let mut rows = result_set.iterator().unwrap();
+    ///
+    /// let row_view = rows.next().unwrap().unwrap();
+    /// assert_eq!(2, row_view.column_value_by("id").unwrap().unwrap().int_value().unwrap());
+    /// ```
+    pub fn with_descending_default_ordering(mut self) -> Relop {
+        self.descending_default_ordering = true;
+        self
+    }
+
+    /// Sets the maximum number of characters of an identifier or a string literal in a query,
+    /// so that a pathologically long token fails early with `LexError::TokenTooLong`.
+    ///
+    /// The default is 64K characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::error::ClientError;
+    /// use relop::client::Relop;
+    ///
+    /// let relop = Relop::new(Catalog::new()).with_max_token_length(8);
+    ///
+    /// assert!(matches!(
+    ///     relop.execute("SELECT 'a long string'"),
+    ///     Err(ClientError::Lex(_))
+    /// ));
+    /// ```
+    pub fn with_max_token_length(mut self, max_token_length: usize) -> Relop {
+        self.max_token_length = Some(max_token_length);
+        self
+    }
+
+    /// Sets the limits every select query is executed within (see [`ExecutionLimits`]), so that
+    /// a runaway query (e.g., a Cartesian join of large tables) fails instead of hanging.
+    ///
+    /// A query exceeding a limit fails while its rows are iterated, with
+    /// `ExecutionError::Budget`. The default is no limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `execution_limits` - The limits of the select queries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::{ExecutionLimits, Relop};
+    /// use std::time::Duration;
+    ///
+    /// let relop = Relop::new(Catalog::new())
+    ///     .with_execution_limits(ExecutionLimits::new().with_timeout(Duration::from_secs(5)));
+    /// ```
+    pub fn with_execution_limits(mut self, execution_limits: ExecutionLimits) -> Relop {
+        self.execution_limits = execution_limits;
+        self
     }
 
     /// Creates a new table with the given name and schema.
@@ -94,6 +253,210 @@ impl Relop {
             .map_err(ClientError::Catalog)
     }
 
+    /// Drops the table with the given name, along with its rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table to drop.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the table was dropped successfully, or a [`ClientError::Catalog`]
+    /// if an error occurred.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The table doesn't exist (wrapped in [`ClientError::Catalog`])
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// let schema = Schema::new()
+    ///     .add_column("id", ColumnType::Int)
+    ///     .unwrap();
+    ///
+    /// relop.create_table("employees", schema).unwrap();
+    /// relop.drop_table("employees").unwrap();
+    /// ```
+    pub fn drop_table(&self, table_name: &str) -> Result<(), ClientError> {
+        self.catalog
+            .drop_table(table_name)
+            .map_err(ClientError::Catalog)
+    }
+
+    /// Returns `true` if a table with the given name exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// if !relop.table_exists("employees") {
+    ///     relop.create_table("employees", schema!["id" => ColumnType::Int].unwrap()).unwrap();
+    /// }
+    /// assert!(relop.table_exists("employees"));
+    /// ```
+    pub fn table_exists(&self, table_name: &str) -> bool {
+        self.catalog.table_exists(table_name)
+    }
+
+    /// Returns the names of all the tables, sorted alphabetically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// relop.create_table("employees", schema!["id" => ColumnType::Int].unwrap()).unwrap();
+    /// relop.create_table("departments", schema!["id" => ColumnType::Int].unwrap()).unwrap();
+    ///
+    /// assert_eq!(vec!["departments", "employees"], relop.table_names());
+    /// ```
+    pub fn table_names(&self) -> Vec<String> {
+        self.catalog.show_tables()
+    }
+
+    /// Creates an index on the specified column of the specified table.
+    ///
+    /// A query filtering the table on an equality between the column and a constant
+    /// (e.g., `where department_id = 10`) reads only the rows having that value, instead of
+    /// scanning the whole table. The index is maintained as rows are inserted, updated and deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    /// * `column_name` - The name of the column to index.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The table or the column doesn't exist (wrapped in [`ClientError::Catalog`])
+    /// - The column is already indexed (wrapped in [`ClientError::Catalog`])
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::rows;
+    /// use relop::schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// let schema = schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap();
+    /// relop.create_table("employees", schema).unwrap();
+    /// relop.insert_all_into("employees", rows![[1, 10], [2, 20]]).unwrap();
+    ///
+    /// relop.create_index("employees", "department_id").unwrap();
+    ///
+    /// let query_result = relop.execute("select id from employees where department_id = 20").unwrap();
+    /// assert_eq!(1, query_result.result_set().unwrap().iterator().unwrap().count());
+    /// ```
+    pub fn create_index(&self, table_name: &str, column_name: &str) -> Result<(), ClientError> {
+        self.catalog
+            .create_index(table_name, column_name)
+            .map_err(ClientError::Catalog)
+    }
+
+    /// Returns the statistics of the rows of the specified table: the row count and, per column,
+    /// the minimum, the maximum and the number of distinct (non-null) values.
+    ///
+    /// The statistics are computed on the first request after the rows of the table change.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The table doesn't exist (wrapped in [`ClientError::Catalog`])
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::row;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// let schema = Schema::new().add_column("id", ColumnType::Int).unwrap();
+    /// relop.create_table("employees", schema).unwrap();
+    /// relop.insert_into("employees", row![10]).unwrap();
+    /// relop.insert_into("employees", row![20]).unwrap();
+    ///
+    /// let statistics = relop.table_statistics("employees").unwrap();
+    /// assert_eq!(2, statistics.row_count());
+    /// assert_eq!(Some(&ColumnValue::int(20)), statistics.column_statistics(0).unwrap().max());
+    /// ```
+    pub fn table_statistics(&self, table_name: &str) -> Result<Arc<TableStatistics>, ClientError> {
+        self.catalog
+            .table_statistics(table_name)
+            .map_err(ClientError::Catalog)
+    }
+
+    /// Removes all the rows from the specified table, keeping its schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table to truncate.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the table was truncated successfully, or a [`ClientError::Catalog`]
+    /// if an error occurred.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The table doesn't exist (wrapped in [`ClientError::Catalog`])
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::row;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// let schema = Schema::new()
+    ///     .add_column("id", ColumnType::Int)
+    ///     .unwrap();
+    ///
+    /// relop.create_table("employees", schema).unwrap();
+    /// relop.insert_into("employees", row![1]).unwrap();
+    /// relop.truncate_table("employees").unwrap();
+    /// ```
+    pub fn truncate_table(&self, table_name: &str) -> Result<(), ClientError> {
+        self.catalog
+            .truncate_table(table_name)
+            .map_err(ClientError::Catalog)
+    }
+
     /// Inserts a single row into the specified table.
     ///
     /// # Arguments
@@ -113,6 +476,8 @@ impl Relop {
     /// - The table doesn't exist (wrapped in [`ClientError::Insert`])
     /// - The row's column count doesn't match the table schema (wrapped in [`ClientError::Insert`])
     /// - The row's column types don't match the table schema (wrapped in [`ClientError::Insert`])
+    /// - The row's primary key is `Null` or already present in the table (wrapped in [`ClientError::Insert`])
+    /// - The row's value in a uniquely-constrained column is already present in the table (wrapped in [`ClientError::Insert`])
     ///
     /// # Examples
     ///
@@ -160,6 +525,8 @@ impl Relop {
     /// - The table doesn't exist (wrapped in [`ClientError::Insert`])
     /// - Any row's column count doesn't match the table schema (wrapped in [`ClientError::Insert`])
     /// - Any row's column types don't match the table schema (wrapped in [`ClientError::Insert`])
+    /// - Any row's primary key is `Null`, already present in the table, or repeated among the rows
+    ///   (wrapped in [`ClientError::Insert`])
     ///
     /// # Examples
     ///
@@ -195,49 +562,39 @@ impl Relop {
             .map_err(ClientError::Insert)
     }
 
-    /// Executes a SQL query string through the full query processing pipeline.
-    ///
-    /// This method processes a SQL query through multiple stages:
-    /// 1. **Lexical Analysis**: The query string is tokenized by the `Lexer`
-    /// 2. **Parsing**: Tokens are parsed into an Abstract Syntax Tree (AST) by the `Parser`
-    /// 3. **Logical Planning**: The AST is converted into a logical plan by the `LogicalPlanner`
-    /// 4. **Execution**: The logical plan is executed by the `Executor`, which returns a [`QueryResult`]
+    /// Inserts the rows of an iterator into the specified table, one by one, as they are pulled.
     ///
-    /// The processing pipeline follows this flow: `Lexer` → `Parser` → `LogicalPlanner` → `Executor`
+    /// Unlike [`Relop::insert_all_into`], the rows are not collected first, which keeps the memory
+    /// of large loads low; on the other hand, the insert is not atomic.
     ///
     /// # Arguments
     ///
-    /// * `query` - The SQL query string to execute.
+    /// * `table_name` - The name of the table to insert into.
+    /// * `rows` - An iterator over the rows to insert. Each row must match the table's schema in
+    ///   terms of column count and types.
     ///
     /// # Returns
     ///
-    /// Returns `Ok(QueryResult)` containing the query results, or a [`ClientError`] if an error
-    /// occurred during any stage of processing.
+    /// Returns `Ok(usize)` containing the number of inserted rows, or a [`ClientError::Insert`]
+    /// if an error occurred.
     ///
     /// # Errors
     ///
     /// This method will return an error if:
-    /// - The query contains invalid characters or syntax that cannot be lexed (wrapped in [`ClientError::Lex`])
-    /// - The query syntax is invalid or unsupported (wrapped in [`ClientError::Parse`])
-    /// - An error occurs during query execution, such as referencing a non-existent table
-    ///   (wrapped in [`ClientError::Execution`])
-    ///
-    /// # Supported Queries
-    ///
-    /// Currently, supports the following query types:
-    /// - `show tables` - Lists all tables in the catalog
-    /// - `describe table <name>` - Shows the schema of a specific table
-    /// - `select * from table <name>` - Gets the result-set from a specific table
+    /// - The table doesn't exist (wrapped in [`ClientError::Insert`])
+    /// - A row fails to insert, for any of the reasons of [`Relop::insert_into`] (wrapped in
+    ///   [`InsertError::Row`](crate::catalog::error::InsertError::Row) with the (0-based) index
+    ///   of the row). The rows before it remain inserted, and the rows after it are not pulled.
     ///
     /// # Examples
     ///
-    /// Listing all tables:
-    ///
     /// ```
     /// use relop::catalog::Catalog;
     /// use relop::client::Relop;
     /// use relop::schema::Schema;
+    /// use relop::storage::row::Row;
     /// use relop::types::column_type::ColumnType;
+    /// use relop::types::column_value::ColumnValue;
     ///
     /// let relop = Relop::new(Catalog::new());
     /// let schema = Schema::new()
@@ -246,18 +603,149 @@ impl Relop {
     ///
     /// relop.create_table("employees", schema).unwrap();
     ///
-    /// let result = relop.execute("show tables").unwrap();
-    /// let tables = result.all_tables().unwrap();
-    /// assert_eq!(&vec!["employees".to_string()], tables);
+    /// let rows = (1..=1000).map(|id| Row::single(ColumnValue::int(id)));
+    /// assert_eq!(1000, relop.insert_stream("employees", rows).unwrap());
     /// ```
+    pub fn insert_stream(
+        &self,
+        table_name: &str,
+        rows: impl Iterator<Item = Row>,
+    ) -> Result<usize, ClientError> {
+        self.catalog
+            .insert_stream(table_name, rows)
+            .map_err(ClientError::Insert)
+    }
+
+    /// Imports rows from CSV into the specified table.
     ///
-    /// Describing a table:
+    /// Fields are separated by commas and may be enclosed in double quotes (as in RFC 4180).
+    /// Each field is parsed as the type of its column (`Int`, `Text` or `Bool`), and an empty
+    /// field is imported as `Null`. Either all the rows are imported, or none of them.
     ///
-    /// ```
-    /// use relop::catalog::Catalog;
-    /// use relop::client::Relop;
-    /// use relop::schema::Schema;
-    /// use relop::types::column_type::ColumnType;
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table to import the rows into.
+    /// * `reader` - The source of the CSV input.
+    /// * `has_header` - Whether the first record is a header naming the columns of the table.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<RowId>` containing the IDs of all inserted rows, or a
+    /// [`ClientError::LoadCsv`] if an error occurred.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The table doesn't exist, or the input cannot be read
+    /// - The header does not name the columns of the table, in order
+    /// - A row does not have one field per column, or a field cannot be parsed as the type of
+    ///   its column (the error carries the number of the row)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// let schema = Schema::new()
+    ///     .add_column("id", ColumnType::Int)
+    ///     .unwrap()
+    ///     .add_column("name", ColumnType::Text)
+    ///     .unwrap();
+    ///
+    /// relop.create_table("employees", schema).unwrap();
+    ///
+    /// let csv = "id,name\n1,relop\n2,query\n";
+    /// let row_ids = relop.import_csv("employees", csv.as_bytes(), true).unwrap();
+    /// assert_eq!(2, row_ids.len());
+    /// ```
+    pub fn import_csv(
+        &self,
+        table_name: &str,
+        reader: impl Read,
+        has_header: bool,
+    ) -> Result<Vec<RowId>, ClientError> {
+        self.catalog
+            .load_csv(table_name, reader, has_header)
+            .map_err(ClientError::LoadCsv)
+    }
+
+    /// Executes a SQL query string through the full query processing pipeline.
+    ///
+    /// This method processes a SQL query through multiple stages:
+    /// 1. **Lexical Analysis**: The query string is tokenized by the `Lexer`
+    /// 2. **Parsing**: Tokens are parsed into an Abstract Syntax Tree (AST) by the `Parser`
+    /// 3. **Logical Planning**: The AST is converted into a logical plan by the `LogicalPlanner`
+    /// 4. **Execution**: The logical plan is executed by the `Executor`, which returns a [`QueryResult`]
+    ///
+    /// The processing pipeline follows this flow: `Lexer` → `Parser` → `LogicalPlanner` → `Executor`
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The SQL query string to execute.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(QueryResult)` containing the query results, or a [`ClientError`] if an error
+    /// occurred during any stage of processing.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The query contains invalid characters or syntax that cannot be lexed (wrapped in [`ClientError::Lex`])
+    /// - The query syntax is invalid or unsupported (wrapped in [`ClientError::Parse`])
+    /// - An error occurs during query execution, such as referencing a non-existent table
+    ///   (wrapped in [`ClientError::Execution`])
+    ///
+    /// # Supported Queries
+    ///
+    /// Currently, supports the following query types:
+    /// - `show tables` - Lists all tables in the catalog
+    /// - `describe table <name>` - Shows the schema of a specific table
+    /// - `select * from table <name>` - Gets the result-set from a specific table
+    /// - `select ... union [all] | intersect | except select ...` - Combines the result-sets of two queries
+    /// - `select ... where <column> = (select ...)` - Filters by the single value of a subquery
+    /// - `select ... where <column> [not] in (select ...)` - Filters by the values of a subquery
+    /// - `select ... where [not] exists (select ...)` - Filters by the existence of the rows of a (correlated) subquery
+    /// - `create table <name> (<column> <type> [primary key], ..., [primary key (<column>, ...)])` - Creates a table (types: `int`, `text`, `bool`, `date`), with an optional primary key
+    /// - `drop table <name>` - Drops a specific table
+    /// - `insert into <name> [(columns)] values (...), ...` - Inserts rows into a specific table
+    /// - `values (...), ...` - Gets the result-set of the given rows (columns: `column1`, `column2`, ...)
+    /// - `delete from <name> [where ...]` - Deletes the (matching) rows from a specific table
+    /// - `update <name> set <column> = <value>, ... [where ...]` - Updates the (matching) rows of a specific table
+    ///
+    /// # Examples
+    ///
+    /// Listing all tables:
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// let schema = Schema::new()
+    ///     .add_column("id", ColumnType::Int)
+    ///     .unwrap();
+    ///
+    /// relop.create_table("employees", schema).unwrap();
+    ///
+    /// let result = relop.execute("show tables").unwrap();
+    /// let tables = result.all_tables().unwrap();
+    /// assert_eq!(&vec!["employees".to_string()], tables);
+    /// ```
+    ///
+    /// Describing a table:
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
     ///
     /// let relop = Relop::new(Catalog::new());
     /// let schema = Schema::new()
@@ -269,6 +757,7 @@ impl Relop {
     /// let result = relop.execute("describe table employees").unwrap();
     /// let table = result.table_descriptor().unwrap();
     /// assert_eq!("employees", table.name());
+    /// assert_eq!(vec![ColumnType::Int], table.column_types());
     /// ```
     ///
     /// Selecting from a table
@@ -300,20 +789,306 @@ impl Relop {
     ///  assert_eq!(&ColumnValue::int(1), row_view.column_value_by("id").unwrap().unwrap());
     /// ```
     pub fn execute(&self, query: &str) -> Result<QueryResult, ClientError> {
+        self.execute_with_params(query, Vec::new())
+    }
+
+    /// Executes a SQL query with placeholders (`?`), binding them to the given parameters in
+    /// order, instead of concatenating the values into the query.
+    ///
+    /// A placeholder can be used wherever a value can (e.g., in `where`, `insert` or `update`).
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The SQL query string to execute.
+    /// * `params` - The values of the placeholders, in the order they appear in the query.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QueryResult)` - The result of the query execution.
+    /// * `Err(ClientError::Plan(PlanningError::ParameterCountMismatch))` - If the number of
+    ///   parameters differs from the number of placeholders.
+    /// * `Err(ClientError)` - If the query fails at any other stage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// relop.execute("create table employees (id int, name text)").unwrap();
+    /// relop
+    ///     .execute_with_params(
+    ///         "insert into employees values (?, ?)",
+    ///         vec![ColumnValue::int(1), ColumnValue::text("relop")],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let query_result = relop
+    ///     .execute_with_params("select name from employees where id = ?", vec![ColumnValue::int(1)])
+    ///     .unwrap();
+    /// let result_set = query_result.result_set().unwrap();
+    /// let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+    /// assert_eq!(&ColumnValue::text("relop"), row_view.column_value_by("name").unwrap().unwrap());
+    /// ```
+    pub fn execute_with_params(
+        &self,
+        query: &str,
+        params: Vec<ColumnValue>,
+    ) -> Result<QueryResult, ClientError> {
+        let plan = self.plan(query, &params)?;
+        let optimized_plan = Optimizer::new(self.catalog.clone()).optimize(plan);
+        self.execute_plan(optimized_plan)
+    }
+
+    /// Executes a SQL query, converting each row of its result into a `T` (see [`FromRow`]),
+    /// instead of reading the columns of every row by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The SQL query string to execute.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<T>)` - The rows of the result, in order.
+    /// * `Err(ClientError::NotAResultSet)` - If the query does not produce rows (e.g., an insert).
+    /// * `Err(ClientError::FromRow)` - If a row does not convert into a `T`.
+    /// * `Err(ClientError)` - If the query fails at any other stage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// relop.execute("create table employees (id int, name text)").unwrap();
+    /// relop.execute("insert into employees values (1, 'relop'), (2, 'query')").unwrap();
+    ///
+    /// let employees = relop
+    ///     .execute_typed::<(i64, String)>("select id, name from employees order by id")
+    ///     .unwrap();
+    /// assert_eq!(vec![(1, "relop".to_string()), (2, "query".to_string())], employees);
+    /// ```
+    pub fn execute_typed<T: FromRow>(&self, query: &str) -> Result<Vec<T>, ClientError> {
+        let query_result = self.execute(query)?;
+        let result_set = query_result
+            .result_set()
+            .ok_or(ClientError::NotAResultSet)?;
+
+        let rows = result_set
+            .iterator()
+            .map_err(ClientError::Execution)?
+            .map(|row_view| {
+                let row_view = row_view.map_err(ClientError::Execution)?;
+                T::from_row(&row_view).map_err(ClientError::FromRow)
+            })
+            .collect();
+        rows
+    }
+
+    /// Executes a SQL query within the given limits, instead of the ones set via
+    /// [`Relop::with_execution_limits`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The SQL query string to execute.
+    /// * `execution_limits` - The limits of the query.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QueryResult)` - The result of the query execution; iterating its rows fails with
+    ///   `ExecutionError::Budget` once a limit is exceeded.
+    /// * `Err(ClientError)` - If the query fails at any stage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::{ExecutionLimits, Relop};
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// relop.execute("create table employees (id int)").unwrap();
+    /// relop.execute("insert into employees values (1), (2), (3)").unwrap();
+    ///
+    /// let query_result = relop
+    ///     .execute_with_limits(
+    ///         "select * from employees e1 cross join employees e2",
+    ///         ExecutionLimits::new().with_max_rows_scanned(5),
+    ///     )
+    ///     .unwrap();
+    /// let result_set = query_result.result_set().unwrap();
+    /// assert!(result_set.iterator().unwrap().any(|row| row.is_err()));
+    /// ```
+    pub fn execute_with_limits(
+        &self,
+        query: &str,
+        execution_limits: ExecutionLimits,
+    ) -> Result<QueryResult, ClientError> {
+        let plan = self.plan(query, &[])?;
+        let optimized_plan = Optimizer::new(self.catalog.clone()).optimize(plan);
+        self.execute_plan_within(optimized_plan, execution_limits)
+    }
+
+    /// Executes a SQL query without applying the optimizer rules (e.g., predicate or limit pushdown).
+    ///
+    /// This is meant for debugging: the results must be the same as the ones of [`Relop::execute`],
+    /// only the plan used to produce them differs.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The SQL query string to execute.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QueryResult)` - The result of the query execution.
+    /// * `Err(ClientError)` - If the query fails at any stage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// let schema = Schema::new().add_column("id", ColumnType::Int).unwrap();
+    /// relop.create_table("employees", schema).unwrap();
+    ///
+    /// let query_result = relop
+    ///     .execute_unoptimized("select * from employees where id = 1")
+    ///     .unwrap();
+    /// assert!(query_result.result_set().is_some());
+    /// ```
+    pub fn execute_unoptimized(&self, query: &str) -> Result<QueryResult, ClientError> {
+        let plan = self.plan(query, &[])?;
+        self.execute_plan(plan)
+    }
+
+    /// Executes a script of semicolon-separated SQL statements, in order, returning one
+    /// `QueryResult` per statement.
+    ///
+    /// Each statement is planned after the previous one is executed, so a statement can use
+    /// the tables created by the ones before it. Execution stops at the first failing statement;
+    /// the statements before it remain executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - The SQL statements to execute.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<QueryResult>)` - The results of the statements, in order.
+    /// * `Err(ClientError::Lex)` - If the script fails to lex.
+    /// * `Err(ClientError::Statement)` - If a statement fails, along with its (0-based) index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// let results = relop
+    ///     .execute_all(
+    ///         "create table employees (id int, name text);
+    ///          insert into employees values (1, 'relop'), (2, 'query');
+    ///          select * from employees;",
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(3, results.len());
+    /// assert_eq!(Some(2), results[1].rows_inserted());
+    /// assert!(results[2].result_set().is_some());
+    /// ```
+    pub fn execute_all(&self, script: &str) -> Result<Vec<QueryResult>, ClientError> {
+        let tokens = self.lex(script)?;
+        tokens
+            .split_statements()
+            .into_iter()
+            .enumerate()
+            .map(|(index, statement)| {
+                self.plan_tokens(statement, &[])
+                    .map(|plan| Optimizer::new(self.catalog.clone()).optimize(plan))
+                    .and_then(|plan| self.execute_plan(plan))
+                    .map_err(|error| ClientError::Statement {
+                        index,
+                        error: Box::new(error),
+                    })
+            })
+            .collect()
+    }
+
+    fn lex(&self, query: &str) -> Result<TokenStream, ClientError> {
         let mut lexer = Lexer::new_with_default_keywords(query);
-        let tokens = lexer.lex().map_err(ClientError::Lex)?;
+        if self.case_insensitive_identifiers {
+            lexer = lexer.with_case_insensitive_identifiers();
+        }
+        if let Some(max_token_length) = self.max_token_length {
+            lexer = lexer.with_max_token_length(max_token_length);
+        }
+        lexer.lex().map_err(ClientError::Lex)
+    }
+
+    fn plan(&self, query: &str, parameters: &[ColumnValue]) -> Result<LogicalPlan, ClientError> {
+        let tokens = self.lex(query)?;
+        self.plan_tokens(tokens, parameters)
+    }
 
+    fn plan_tokens(
+        &self,
+        tokens: TokenStream,
+        parameters: &[ColumnValue],
+    ) -> Result<LogicalPlan, ClientError> {
         let mut parser = Parser::new(tokens);
-        let ast = parser.parse().map_err(ClientError::Parse)?;
+        if self.descending_default_ordering {
+            parser = parser.with_default_ordering_direction(OrderingDirection::Descending);
+        }
+        let ast = parser.parse().map_err(|error| {
+            let (line, column) = parser.position();
+            ClientError::Parse {
+                error,
+                line,
+                column,
+            }
+        })?;
 
         let planner = LogicalPlanner::new(self.catalog.clone());
-        let plan = planner.plan(ast).map_err(ClientError::Plan)?;
-        let optimized_plan = crate::query::optimizer::Optimizer::new().optimize(plan);
+        planner
+            .plan_with_parameters(ast, parameters)
+            .map_err(ClientError::Plan)
+    }
+
+    fn execute_plan(&self, plan: LogicalPlan) -> Result<QueryResult, ClientError> {
+        self.execute_plan_within(plan, self.execution_limits)
+    }
+
+    fn execute_plan_within(
+        &self,
+        plan: LogicalPlan,
+        execution_limits: ExecutionLimits,
+    ) -> Result<QueryResult, ClientError> {
+        let executor = Executor::new(&self.catalog)
+            .with_scan_parallelism(self.scan_parallelism)
+            .with_limits(execution_limits);
+        executor.execute(plan).map_err(ClientError::Execution)
+    }
+}
 
-        let executor = Executor::new(&self.catalog);
-        executor
-            .execute(optimized_plan)
-            .map_err(ClientError::Execution)
+#[cfg(test)]
+mod test_fixtures {
+    use super::*;
+
+    /// Creates a `Relop` with the given tables, each holding the given rows.
+    pub(super) fn relop_with_tables(tables: Vec<(&str, Schema, Vec<Row>)>) -> Relop {
+        let relop = Relop::new(Catalog::new());
+        for (table_name, schema, rows) in tables {
+            relop.create_table(table_name, schema).unwrap();
+            relop.insert_all_into(table_name, rows).unwrap();
+        }
+        relop
     }
 }
 
@@ -329,6 +1104,7 @@ mod tests {
     use crate::rows;
     use crate::test_utils::insert_rows;
     use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
     use crate::{assert_next_row, schema};
 
     #[test]
@@ -408,6 +1184,27 @@ mod tests {
         assert_eq!(&vec!["employees"], table_names);
     }
 
+    #[test]
+    fn execute_show_tables_in_sorted_order() {
+        let relop = Relop::new(Catalog::new());
+        for table_name in ["roles", "employees", "projects", "departments"] {
+            relop
+                .create_table(table_name, schema!["id" => ColumnType::Int].unwrap())
+                .unwrap();
+        }
+
+        let query_result = relop.execute("show tables").unwrap();
+
+        assert_eq!(
+            &vec!["departments", "employees", "projects", "roles"],
+            query_result.all_tables().unwrap()
+        );
+        assert_eq!(
+            "departments\nemployees\nprojects\nroles\n",
+            query_result.to_string()
+        );
+    }
+
     #[test]
     fn execute_describe_table() {
         let relop = Relop::new(Catalog::new());
@@ -430,8 +1227,67 @@ mod tests {
         let query_result = relop.execute("show");
         assert!(matches!(
             query_result,
-            Err(ClientError::Parse(ParseError::UnexpectedToken{expected, found})) if expected == "tables" && found.is_empty()
+            Err(ClientError::Parse{error: ParseError::UnexpectedToken{expected, found}, line: 1, column: 5}) if expected == "tables" && found.is_empty()
+        ));
+    }
+
+    #[test]
+    fn execute_select_with_a_parse_error_reports_the_position() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop.execute("select id, name\nform employees");
+        let Err(error) = query_result else {
+            panic!("expected an error");
+        };
+        assert!(matches!(
+            &error,
+            ClientError::Parse{error: ParseError::UnexpectedToken{expected, found}, line: 2, column: 1} if expected == "from" && found == "form"
+        ));
+        assert_eq!(
+            "UnexpectedToken { expected: \"from\", found: \"form\" } at line 2, column 1",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn execute_select_with_a_lex_error_reports_the_position() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop.execute("select * from employees where id = #1");
+        let Err(error) = query_result else {
+            panic!("expected an error");
+        };
+        assert!(matches!(
+            &error,
+            ClientError::Lex(LexError::UnexpectedCharacter {
+                character: '#',
+                line: 1,
+                column: 36
+            })
         ));
+        assert_eq!(
+            "unexpected character '#' at line 1, column 36",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn execute_select_with_an_execution_error_reports_the_error() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+
+        let query_result =
+            relop.execute("select * from employees where id = (select id from employees)");
+        let Err(error) = query_result else {
+            panic!("expected an error");
+        };
+        assert_eq!(
+            "scalar subquery produced more than one row",
+            error.to_string()
+        );
     }
 
     #[test]
@@ -441,7 +1297,7 @@ mod tests {
         let query_result = relop.execute("show \\");
         assert!(matches!(
             query_result,
-            Err(ClientError::Lex(LexError::UnexpectedCharacter(ch))) if ch == '\\'
+            Err(ClientError::Lex(LexError::UnexpectedCharacter { character, .. })) if character == '\\'
         ));
     }
 
@@ -619,6 +1475,52 @@ mod tests {
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
+    #[test]
+    fn execute_select_with_where_clause_on_a_column_not_in_projection() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        insert_rows(
+            &relop.catalog,
+            "employees",
+            rows![[1, "relop"], [2, "query"]],
+        );
+        let query_result = relop
+            .execute("select id from employees where name = 'query'")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, ! "name");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_where_clause_on_non_existing_column() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        insert_rows(
+            &relop.catalog,
+            "employees",
+            rows![[1, "relop"], [2, "query"]],
+        );
+        let query_result = relop.execute("select id from employees where unknown = 'query'");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Execution(ExecutionError::UnknownColumn(column_name))) if column_name == "unknown"
+        ));
+    }
+
     #[test]
     fn execute_select_star_with_like_clause_matching() {
         let relop = Relop::new(Catalog::new());
@@ -635,7 +1537,7 @@ mod tests {
         );
 
         let query_result = relop
-            .execute("select * from employees where name like '^rel.*' order by id")
+            .execute("select * from employees where name like 'rel%' order by id")
             .unwrap();
 
         let result_set = query_result.result_set().unwrap();
@@ -662,11 +1564,165 @@ mod tests {
         );
 
         let query_result = relop
-            .execute("select * from employees where name like '^nomatch.*'")
+            .execute("select * from employees where name like 'nomatch%'")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_star_with_ilike_clause_matching() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        insert_rows(
+            &relop.catalog,
+            "employees",
+            rows![[1, "relop"], [2, "query"], [3, "Relational"]],
+        );
+
+        let query_result = relop
+            .execute("select * from employees where name ilike 'REL%' order by id")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "Relational");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_star_with_ilike_clause_not_matching() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        insert_rows(
+            &relop.catalog,
+            "employees",
+            rows![[1, "relop"], [2, "query"]],
+        );
+
+        let query_result = relop
+            .execute("select * from employees where name ilike 'NOMATCH%'")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_star_with_not_like_clause_matching() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        insert_rows(
+            &relop.catalog,
+            "employees",
+            rows![[1, "relop"], [2, "query"], [3, "relational"]],
+        );
+
+        let query_result = relop
+            .execute("select * from employees where name not like 'rel%' order by id")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_star_with_not_like_clause_not_matching() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        insert_rows(
+            &relop.catalog,
+            "employees",
+            rows![[1, "relop"], [2, "relational"]],
+        );
+
+        let query_result = relop
+            .execute("select * from employees where name not like 'rel%'")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_star_with_like_clause_with_single_character_wildcard() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        insert_rows(
+            &relop.catalog,
+            "employees",
+            rows![[1, "relop"], [2, "rlop"], [3, "rulop"]],
+        );
+
+        let query_result = relop
+            .execute("select * from employees where name like 'r_lop' order by id")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "rulop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_star_with_like_clause_with_escaped_wildcard() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "discounts",
+            schema!["id" => ColumnType::Int, "label" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        insert_rows(
+            &relop.catalog,
+            "discounts",
+            rows![[1, "50% off"], [2, "500 off"]],
+        );
+
+        let query_result = relop
+            .execute(r"select * from discounts where label like '50\% off'")
             .unwrap();
 
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "label" => "50% off");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
@@ -988,308 +2044,227 @@ mod tests {
         assert_next_row!(row_iterator.as_mut(), "emp.name" => "query");
         assert_no_more_rows!(row_iterator.as_mut());
     }
-}
-
-#[cfg(test)]
-mod conjunction_tests {
-    use super::*;
-    use crate::rows;
-    use crate::types::column_type::ColumnType;
-    use crate::{assert_next_row, assert_no_more_rows, schema};
 
     #[test]
-    fn execute_select_with_and_and_or() {
+    fn execute_select_with_null_values() {
         let relop = Relop::new(Catalog::new());
-        relop
-            .create_table(
-                "employees",
-                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
-            )
-            .unwrap();
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
 
         relop
-            .insert_all_into(
+            .insert_into(
                 "employees",
-                rows![
-                    [1, "Alice", "London"],
-                    [2, "Bob", "Paris"],
-                    [3, "Charlie", "London"]
-                ],
+                Row::filled(vec![ColumnValue::int(1), ColumnValue::null()]),
             )
             .unwrap();
 
-        let query_result = relop
-            .execute("select * from employees where city = 'London' and id = 1 or city = 'Paris' order by id")
-            .unwrap();
+        let query_result = relop.execute("select * from employees").unwrap();
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "Alice");
-        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "Bob");
+        let row_view = row_iterator.next().unwrap().unwrap();
+        assert!(!row_view.is_null("id").unwrap());
+        assert!(row_view.is_null("name").unwrap());
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_precedence_and_or_1() {
-        // A or B and C => A or (B and C)
+    fn execute_select_with_where_clause_on_a_null_value() {
         let relop = Relop::new(Catalog::new());
-        relop
-            .create_table(
-                "employees",
-                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
-            )
-            .unwrap();
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
 
         relop
             .insert_all_into(
                 "employees",
-                rows![
-                    [1, "Alice", "London"],
-                    [2, "Bob", "Paris"],
-                    [3, "Charlie", "London"]
+                vec![
+                    Row::filled(vec![ColumnValue::int(1), ColumnValue::null()]),
+                    row![2, "relop"],
                 ],
             )
             .unwrap();
 
-        // id = 1 or (name = 'Bob' and city = 'Paris')
         let query_result = relop
-            .execute("select * from employees where id = 1 or name = 'Bob' and city = 'Paris' order by id")
+            .execute("select * from employees where name = 'relop'")
             .unwrap();
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "id" => 1);
-        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "relop");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_precedence_and_or_2() {
-        // A and B or C => (A and B) or C
+    fn execute_select_with_where_clause_against_a_negative_number() {
         let relop = Relop::new(Catalog::new());
-        relop
-            .create_table(
-                "employees",
-                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
-            )
-            .unwrap();
+        let result = relop.create_table(
+            "accounts",
+            schema!["id" => ColumnType::Int, "balance" => ColumnType::Int].unwrap(),
+        );
+        assert!(result.is_ok());
 
         relop
-            .insert_all_into(
-                "employees",
-                rows![
-                    [1, "Alice", "London"],
-                    [2, "Bob", "Paris"],
-                    [3, "Charlie", "London"]
-                ],
-            )
+            .insert_all_into("accounts", rows![[1, -250], [2, -100], [3, -0], [4, 75]])
             .unwrap();
 
-        // (id = 1 and city = 'London') or name = 'Bob'
         let query_result = relop
-            .execute("select * from employees where id = 1 and city = 'London' or name = 'Bob' order by id")
+            .execute("select id from accounts where balance > -100")
             .unwrap();
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "id" => 1);
-        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 4);
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_trailing_or_error() {
+    fn execute_select_with_comments() {
         let relop = Relop::new(Catalog::new());
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
         relop
-            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .insert_all_into("employees", rows![[1, "relop"], [2, "query"]])
             .unwrap();
 
-        let query_result = relop.execute("select * from employees where id = 1 or");
-        assert!(matches!(
-            query_result,
-            Err(ClientError::Parse(
-                crate::query::parser::error::ParseError::UnexpectedToken { .. }
-            ))
-        ));
-    }
-
-    #[test]
-    fn execute_select_with_missing_clause_after_or_error() {
-        let relop = Relop::new(Catalog::new());
-        relop
-            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+        let query_result = relop
+            .execute(
+                "-- employees named relop\nselect name /* only the name */ from employees where id = 1;",
+            )
             .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        let query_result = relop.execute("select * from employees where id = 1 or ;");
-        assert!(matches!(
-            query_result,
-            Err(ClientError::Parse(
-                crate::query::parser::error::ParseError::UnexpectedToken { .. }
-            ))
-        ));
+        assert_next_row!(row_iterator.as_mut(), "name" => "relop");
+        assert_no_more_rows!(row_iterator.as_mut());
     }
-}
-
-#[cfg(test)]
-mod parentheses_tests {
-    use crate::catalog::Catalog;
-    use crate::client::Relop;
-    use crate::types::column_type::ColumnType;
-    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
 
     #[test]
-    fn execute_select_with_parentheses_1() {
+    fn execute_select_with_quoted_identifiers() {
         let relop = Relop::new(Catalog::new());
-        relop
-            .create_table(
-                "employees",
-                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
-            )
-            .unwrap();
+        let result = relop.create_table(
+            "orders",
+            schema!["id" => ColumnType::Int, "order" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
 
         relop
-            .insert_all_into(
-                "employees",
-                rows![
-                    [1, "Alice", "London"],
-                    [2, "Bob", "Paris"],
-                    [3, "Charlie", "London"]
-                ],
-            )
+            .insert_all_into("orders", rows![[1, "books"], [2, "pens"]])
             .unwrap();
 
         let query_result = relop
-            .execute("select * from employees where (name = 'Alice' or name = 'Bob') and city = 'London'")
+            .execute("select \"order\" from orders where orders.\"id\" = 2")
             .unwrap();
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "Alice");
+        assert_next_row!(row_iterator.as_mut(), "order" => "pens");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_parentheses_2() {
+    fn execute_select_with_where_clause_on_a_string_with_an_escaped_quote() {
         let relop = Relop::new(Catalog::new());
-        relop
-            .create_table(
-                "employees",
-                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
-            )
-            .unwrap();
+        let result = relop.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
 
         relop
-            .insert_all_into(
-                "employees",
-                rows![
-                    [1, "Alice", "London"],
-                    [2, "Bob", "Paris"],
-                    [3, "Charlie", "London"]
-                ],
-            )
+            .insert_all_into("employees", rows![[1, "O'Brien"], [2, "OBrien"]])
             .unwrap();
 
         let query_result = relop
-            .execute("select * from employees where (name = 'Alice' or name = 'Bob') and (city = 'London' or city = 'Paris') order by id")
+            .execute("select id from employees where name = 'O''Brien'")
             .unwrap();
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "Alice");
-        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "Bob");
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_nested_parentheses() {
+    fn execute_select_with_an_unterminated_comment() {
         let relop = Relop::new(Catalog::new());
-        relop
-            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
-            .unwrap();
 
-        relop.insert_all_into("employees", rows![[1]]).unwrap();
-
-        let query_result = relop
-            .execute("select * from employees where ((id = 1))")
-            .unwrap();
-        let result_set = query_result.result_set().unwrap();
-        let mut row_iterator = result_set.iterator().unwrap();
-
-        assert_next_row!(row_iterator.as_mut(), "id" => 1);
-        assert_no_more_rows!(row_iterator.as_mut());
+        let query_result = relop.execute("select * from employees /* all the employees");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Lex(LexError::UnterminatedComment { .. }))
+        ));
     }
 }
 
 #[cfg(test)]
-mod join_tests {
-    use super::*;
-    use crate::assert_no_more_rows;
-    use crate::row;
-    use crate::rows;
+mod projection_alias_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
     use crate::types::column_type::ColumnType;
-    use crate::{assert_next_row, schema};
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
 
     #[test]
-    fn execute_select_with_join() {
+    fn execute_select_with_column_aliases() {
         let relop = Relop::new(Catalog::new());
-        relop
-            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
-            .unwrap();
-
         relop
             .create_table(
-                "departments",
+                "employees",
                 schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
             )
             .unwrap();
-
-        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
         relop
-            .insert_all_into("departments", rows![[1, "Engineering"], [3, "Marketing"]])
+            .insert_all_into("employees", rows![[1, "relop"], [2, "query"]])
             .unwrap();
 
         let query_result = relop
-            .execute("select * from employees join departments on employees.id = departments.id")
+            .execute("select id as employee_id, name as full_name from employees where id = 1")
             .unwrap();
-
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1, "departments.id" => 1, "departments.name" => "Engineering");
+        assert_next_row!(row_iterator.as_mut(), "employee_id" => 1, "full_name" => "relop", ! "id", ! "name");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_multi_table_join() {
+    fn execute_select_with_column_alias_and_unaliased_column() {
         let relop = Relop::new(Catalog::new());
         relop
-            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
-            .unwrap();
-        relop
-            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
-            .unwrap();
-        relop
-            .create_table("locations", schema!["id" => ColumnType::Int].unwrap())
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
             .unwrap();
-
-        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
         relop
-            .insert_all_into("departments", rows![[1], [3]])
+            .insert_all_into("employees", rows![[1, "relop"]])
             .unwrap();
-        relop.insert_all_into("locations", rows![[1], [4]]).unwrap();
 
         let query_result = relop
-            .execute("select employees.id from employees join departments on employees.id = departments.id join locations on departments.id = locations.id")
+            .execute("select id as employee_id, name from employees")
             .unwrap();
         let result_set = query_result.result_set().unwrap();
-        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        let column_names = result_set.schema().column_names();
+        assert_eq!(vec!["employee_id", "employees.name"], column_names[..2]);
+
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "employee_id" => 1, "name" => "relop", "employees.name" => "relop");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_self_join_and_aliases() {
+    fn execute_select_with_column_alias_and_order_by_alias() {
         let relop = Relop::new(Catalog::new());
         relop
             .create_table(
@@ -1298,24 +2273,22 @@ mod join_tests {
             )
             .unwrap();
         relop
-            .insert_all_into("employees", rows![[1, "Relop"], [2, "Query"]])
+            .insert_all_into("employees", rows![[1, "relop"], [2, "query"]])
             .unwrap();
 
         let query_result = relop
-            .execute(
-                "select e1.name, e2.name from employees as e1 join employees as e2 on e1.id = e2.id order by e1.id",
-            )
+            .execute("select id as employee_id, name from employees order by employee_id desc")
             .unwrap();
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "e1.name" => "Relop", "e2.name" => "Relop");
-        assert_next_row!(row_iterator.as_mut(), "e1.name" => "Query", "e2.name" => "Query");
+        assert_next_row!(row_iterator.as_mut(), "employee_id" => 2, "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "employee_id" => 1, "name" => "relop");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_join_and_projection() {
+    fn execute_select_with_column_alias_and_order_by_original_column() {
         let relop = Relop::new(Catalog::new());
         relop
             .create_table(
@@ -1324,34 +2297,25 @@ mod join_tests {
             )
             .unwrap();
         relop
-            .create_table(
-                "departments",
-                schema!["id" => ColumnType::Int, "dept_name" => ColumnType::Text].unwrap(),
-            )
-            .unwrap();
-
-        relop.insert_into("employees", row![1, "Alice"]).unwrap();
-        relop
-            .insert_into("departments", row![1, "Engineering"])
+            .insert_all_into("employees", rows![[1, "relop"], [2, "query"]])
             .unwrap();
 
         let query_result = relop
-            .execute("select employees.name, departments.dept_name from employees join departments on employees.id = departments.id")
+            .execute("select id as employee_id from employees order by id desc")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.name" => "Alice", "departments.dept_name" => "Engineering");
+        assert_next_row!(row_iterator.as_mut(), "employee_id" => 2, ! "id");
+        assert_next_row!(row_iterator.as_mut(), "employee_id" => 1, ! "id");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_join_and_where() {
+    fn execute_select_with_column_aliases_in_join() {
         let relop = Relop::new(Catalog::new());
         relop
-            .create_table(
-                "employees",
-                schema!["id" => ColumnType::Int, "dept_id" => ColumnType::Int].unwrap(),
-            )
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
             .unwrap();
         relop
             .create_table(
@@ -1359,275 +2323,6601 @@ mod join_tests {
                 schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
             )
             .unwrap();
-
-        relop
-            .insert_all_into("employees", rows![[1, 10], [2, 20]])
-            .unwrap();
+        relop.insert_all_into("employees", rows![[1]]).unwrap();
         relop
-            .insert_all_into("departments", rows![[10, "Sales"], [20, "HR"]])
+            .insert_all_into("departments", rows![[1, "Engineering"]])
             .unwrap();
 
         let query_result = relop
-            .execute("select departments.name from employees join departments on employees.dept_id = departments.id where employees.id = 2")
+            .execute("select employees.id as id, departments.name as department from employees join departments on employees.id = departments.id")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "departments.name" => "HR");
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "department" => "Engineering");
         assert_no_more_rows!(row_iterator.as_mut());
     }
+}
+
+#[cfg(test)]
+mod conjunction_tests {
+    use super::*;
+    use crate::rows;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, schema};
 
     #[test]
-    fn execute_select_with_join_and_order_by() {
+    fn execute_select_with_and_and_or() {
         let relop = Relop::new(Catalog::new());
-        relop
-            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
-            .unwrap();
         relop
             .create_table(
-                "departments",
-                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
             )
             .unwrap();
 
-        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
         relop
-            .insert_all_into("departments", rows![[1, "Dev"], [2, "Ops"]])
+            .insert_all_into(
+                "employees",
+                rows![
+                    [1, "Alice", "London"],
+                    [2, "Bob", "Paris"],
+                    [3, "Charlie", "London"]
+                ],
+            )
             .unwrap();
 
         let query_result = relop
-            .execute("select departments.name from employees join departments on employees.id = departments.id order by departments.name DESC")
+            .execute("select * from employees where city = 'London' and id = 1 or city = 'Paris' order by id")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "departments.name" => "Ops");
-        assert_next_row!(row_iterator.as_mut(), "departments.name" => "Dev");
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "Alice");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "Bob");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_join_on_with_or() {
+    fn execute_select_with_precedence_and_or_1() {
+        // A or B and C => A or (B and C)
         let relop = Relop::new(Catalog::new());
         relop
             .create_table(
                 "employees",
-                schema!["id" => ColumnType::Int, "active" => ColumnType::Int].unwrap(),
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
             )
             .unwrap();
-        relop
-            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
-            .unwrap();
 
         relop
-            .insert_all_into("employees", rows![[1, 0], [2, 1]])
-            .unwrap();
-        relop
-            .insert_all_into("departments", rows![[1], [3]])
+            .insert_all_into(
+                "employees",
+                rows![
+                    [1, "Alice", "London"],
+                    [2, "Bob", "Paris"],
+                    [3, "Charlie", "London"]
+                ],
+            )
             .unwrap();
 
+        // id = 1 or (name = 'Bob' and city = 'Paris')
         let query_result = relop
-            .execute("select employees.id from employees join departments on employees.id = departments.id OR employees.active = 1")
+            .execute("select * from employees where id = 1 or name = 'Bob' and city = 'Paris' order by id")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_join_and_where_or() {
+    fn execute_select_with_precedence_and_or_2() {
+        // A and B or C => (A and B) or C
         let relop = Relop::new(Catalog::new());
         relop
             .create_table(
                 "employees",
-                schema!["id" => ColumnType::Int, "active" => ColumnType::Int].unwrap(),
-            )
-            .unwrap();
-        relop
-            .create_table(
-                "departments",
-                schema!["id" => ColumnType::Int, "location" => ColumnType::Text].unwrap(),
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
             )
             .unwrap();
 
         relop
-            .insert_all_into("employees", rows![[1, 1], [2, 0]])
-            .unwrap();
-        relop
-            .insert_all_into("departments", rows![[1, "NY"], [2, "SF"]])
+            .insert_all_into(
+                "employees",
+                rows![
+                    [1, "Alice", "London"],
+                    [2, "Bob", "Paris"],
+                    [3, "Charlie", "London"]
+                ],
+            )
             .unwrap();
 
+        // (id = 1 and city = 'London') or name = 'Bob'
         let query_result = relop
-            .execute("select employees.id from employees join departments on employees.id = departments.id where employees.active = 1 OR departments.location = 'SF'")
+            .execute("select * from employees where id = 1 and city = 'London' or name = 'Bob' order by id")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_join_on_mixing_and_or() {
+    fn execute_select_with_trailing_or_error() {
         let relop = Relop::new(Catalog::new());
         relop
-            .create_table(
-                "employees",
-                schema!["id" => ColumnType::Int, "active" => ColumnType::Int, "dept_id" => ColumnType::Int].unwrap(),
-            )
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
             .unwrap();
+
+        let query_result = relop.execute("select * from employees where id = 1 or");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Parse {
+                error: crate::query::parser::error::ParseError::UnexpectedToken { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn execute_select_with_missing_clause_after_or_error() {
+        let relop = Relop::new(Catalog::new());
         relop
-            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
             .unwrap();
 
+        let query_result = relop.execute("select * from employees where id = 1 or ;");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Parse {
+                error: crate::query::parser::error::ParseError::UnexpectedToken { .. },
+                ..
+            })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod parentheses_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    #[test]
+    fn execute_select_with_parentheses_1() {
+        let relop = Relop::new(Catalog::new());
         relop
-            .insert_all_into("employees", rows![[1, 1, 10], [2, 0, 20], [3, 1, 10]])
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
+            )
             .unwrap();
+
         relop
-            .insert_all_into("departments", rows![[10], [20]])
+            .insert_all_into(
+                "employees",
+                rows![
+                    [1, "Alice", "London"],
+                    [2, "Bob", "Paris"],
+                    [3, "Charlie", "London"]
+                ],
+            )
             .unwrap();
 
         let query_result = relop
-            .execute("select employees.id from employees join departments on employees.id = departments.id AND employees.active = 1 OR employees.dept_id = departments.id")
+            .execute("select * from employees where (name = 'Alice' or name = 'Bob') and city = 'London'")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "Alice");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_join_where_mixing_and_or() {
+    fn execute_select_with_parentheses_2() {
         let relop = Relop::new(Catalog::new());
         relop
             .create_table(
                 "employees",
-                schema!["id" => ColumnType::Int, "active" => ColumnType::Int, "dept_id" => ColumnType::Int].unwrap(),
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
             )
             .unwrap();
+
         relop
-            .create_table(
-                "departments",
-                schema!["id" => ColumnType::Int, "loc" => ColumnType::Text].unwrap(),
+            .insert_all_into(
+                "employees",
+                rows![
+                    [1, "Alice", "London"],
+                    [2, "Bob", "Paris"],
+                    [3, "Charlie", "London"]
+                ],
             )
             .unwrap();
 
-        relop
-            .insert_all_into("employees", rows![[1, 1, 10], [2, 0, 20], [3, 1, 10]])
+        let query_result = relop
+            .execute("select * from employees where (name = 'Alice' or name = 'Bob') and (city = 'London' or city = 'Paris') order by id")
             .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "Alice");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "Bob");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_nested_parentheses() {
+        let relop = Relop::new(Catalog::new());
         relop
-            .insert_all_into("departments", rows![[10, "NY"], [20, "SF"]])
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
             .unwrap();
 
+        relop.insert_all_into("employees", rows![[1]]).unwrap();
+
         let query_result = relop
-            .execute("select employees.id from employees join departments on employees.dept_id = departments.id where employees.active = 1 AND departments.loc = 'NY' OR employees.id = 2")
+            .execute("select * from employees where ((id = 1))")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
         assert_no_more_rows!(row_iterator.as_mut());
     }
+}
+
+#[cfg(test)]
+mod not_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
 
     #[test]
-    fn execute_select_with_join_on_with_and() {
+    fn execute_select_with_not() {
         let relop = Relop::new(Catalog::new());
         relop
             .create_table(
                 "employees",
-                schema!["id" => ColumnType::Int, "active" => ColumnType::Int].unwrap(),
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
             )
             .unwrap();
-        relop
-            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
-            .unwrap();
 
         relop
-            .insert_all_into("employees", rows![[1, 1], [2, 0]])
-            .unwrap();
-        relop
-            .insert_all_into("departments", rows![[1], [2]])
+            .insert_all_into(
+                "employees",
+                rows![
+                    [1, "Alice", "London"],
+                    [2, "Bob", "Paris"],
+                    [3, "Charlie", "London"]
+                ],
+            )
             .unwrap();
 
         let query_result = relop
-            .execute("select employees.id from employees join departments on employees.id = departments.id and employees.active = 1")
+            .execute("select * from employees where not city = 'London'")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "Bob");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_join_on_and_where() {
+    fn execute_select_with_not_binding_tighter_than_and() {
         let relop = Relop::new(Catalog::new());
         relop
             .create_table(
                 "employees",
-                schema!["id" => ColumnType::Int, "active" => ColumnType::Int].unwrap(),
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
             )
             .unwrap();
+
         relop
-            .create_table(
-                "departments",
-                schema!["id" => ColumnType::Int, "loc" => ColumnType::Text].unwrap(),
+            .insert_all_into(
+                "employees",
+                rows![
+                    [1, "Alice", "London"],
+                    [2, "Bob", "Paris"],
+                    [3, "Charlie", "London"]
+                ],
             )
             .unwrap();
 
+        let query_result = relop
+            .execute("select * from employees where not name = 'Alice' and city = 'London'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "Charlie");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_not_on_parentheses() {
+        let relop = Relop::new(Catalog::new());
         relop
-            .insert_all_into("employees", rows![[1, 1], [2, 1]])
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
+            )
             .unwrap();
+
         relop
-            .insert_all_into("departments", rows![[1, "NY"], [2, "SF"]])
+            .insert_all_into(
+                "employees",
+                rows![
+                    [1, "Alice", "London"],
+                    [2, "Bob", "Paris"],
+                    [3, "Charlie", "London"]
+                ],
+            )
             .unwrap();
 
         let query_result = relop
-            .execute("select employees.id from employees join departments on employees.id = departments.id and employees.active = 1 where departments.loc = 'SF'")
+            .execute("select * from employees where not (name = 'Alice' or city = 'Paris')")
             .unwrap();
-        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "Charlie");
         assert_no_more_rows!(row_iterator.as_mut());
     }
 
     #[test]
-    fn execute_select_with_join_and_parentheses_in_where() {
+    fn execute_select_with_not_on_null_values() {
         let relop = Relop::new(Catalog::new());
         relop
             .create_table(
                 "employees",
-                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "dept_id" => ColumnType::Int].unwrap(),
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop
+            .insert_all_into(
+                "employees",
+                rows![[1, "Alice", "London"], [2, "Bob", "Paris"]],
+            )
+            .unwrap();
+        relop
+            .insert_into(
+                "employees",
+                Row::filled(vec![
+                    ColumnValue::int(3),
+                    ColumnValue::text("Charlie"),
+                    ColumnValue::null(),
+                ]),
             )
             .unwrap();
+
+        let queries = [
+            "select * from employees where not city = 'London'",
+            "select * from employees where not (city != 'Paris')",
+            "select * from employees where not (city = 'London' and id > 0)",
+            "select * from employees where not city in (select city from employees where id = 1)",
+            "select * from employees where not city like 'L%'",
+        ];
+        for query in queries {
+            let query_result = relop.execute(query).unwrap();
+            let result_set = query_result.result_set().unwrap();
+            let mut row_iterator = result_set.iterator().unwrap();
+
+            assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "Bob");
+            assert_no_more_rows!(row_iterator.as_mut());
+        }
+    }
+
+    #[test]
+    fn execute_select_with_not_on_an_or_known_to_hold_for_null_values() {
+        let relop = Relop::new(Catalog::new());
         relop
             .create_table(
-                "departments",
-                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text].unwrap(),
             )
             .unwrap();
 
         relop
-            .insert_all_into(
+            .insert_into(
                 "employees",
-                rows![[1, "Alice", 10], [2, "Bob", 10], [3, "Charlie", 20]],
+                Row::filled(vec![
+                    ColumnValue::int(1),
+                    ColumnValue::text("Alice"),
+                    ColumnValue::null(),
+                ]),
             )
             .unwrap();
+
+        let query_result = relop
+            .execute("select * from employees where not (city = 'London' or id = 2)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+
+        let query_result = relop
+            .execute("select * from employees where not (city = 'London' and id = 2)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "Alice");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod between_tests {
+    use crate::catalog::Catalog;
+    use crate::client::error::ClientError;
+    use crate::client::Relop;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    #[test]
+    fn execute_select_with_between() {
+        let relop = Relop::new(Catalog::new());
         relop
-            .insert_all_into("departments", rows![[10, "Engineering"], [20, "Sales"]])
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[5], [10], [15], [20], [25]])
             .unwrap();
 
         let query_result = relop
-            .execute("select employees.name from employees join departments on employees.dept_id = departments.id where (employees.name = 'Alice' or employees.name = 'Bob') and departments.name = 'Engineering' order by employees.name")
+            .execute("select * from employees where id between 10 and 20 order by id")
             .unwrap();
         let result_set = query_result.result_set().unwrap();
         let mut row_iterator = result_set.iterator().unwrap();
 
-        assert_next_row!(row_iterator.as_mut(), "employees.name" => "Alice");
-        assert_next_row!(row_iterator.as_mut(), "employees.name" => "Bob");
+        assert_next_row!(row_iterator.as_mut(), "id" => 10);
+        assert_next_row!(row_iterator.as_mut(), "id" => 15);
+        assert_next_row!(row_iterator.as_mut(), "id" => 20);
         assert_no_more_rows!(row_iterator.as_mut());
     }
+
+    #[test]
+    fn execute_select_with_between_and_conjunction() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "city" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop
+            .insert_all_into(
+                "employees",
+                rows![[5, "London"], [10, "Paris"], [15, "London"], [20, "London"]],
+            )
+            .unwrap();
+
+        let query_result = relop
+            .execute("select * from employees where id between 10 and 20 and city = 'London' order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 15, "city" => "London");
+        assert_next_row!(row_iterator.as_mut(), "id" => 20, "city" => "London");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_between_and_disjunction() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1], [5], [10], [15], [30]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select * from employees where id = 1 or id between 10 and 20 or id = 30 order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 10);
+        assert_next_row!(row_iterator.as_mut(), "id" => 15);
+        assert_next_row!(row_iterator.as_mut(), "id" => 30);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_between_of_equal_bounds() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[5], [10], [15]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select * from employees where id between 10 and 10")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 10);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_between_of_reversed_bounds() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        let query_result = relop.execute("select * from employees where id between 20 and 10");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Plan(PlanningError::InvalidBetweenRange { low, high })) if low == "20" && high == "10"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod join_tests {
+    use super::*;
+    use crate::assert_no_more_rows;
+    use crate::query::executor::error::ExecutionError;
+    use crate::query::parser::error::ParseError;
+    use crate::query::plan::error::PlanningError;
+    use crate::row;
+    use crate::rows;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, schema};
+
+    #[test]
+    fn execute_select_with_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+        relop
+            .insert_all_into("departments", rows![[1, "Engineering"], [3, "Marketing"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select * from employees join departments on employees.id = departments.id")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1, "departments.id" => 1, "departments.name" => "Engineering");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_multi_table_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table("locations", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+        relop
+            .insert_all_into("departments", rows![[1], [3]])
+            .unwrap();
+        relop.insert_all_into("locations", rows![[1], [4]]).unwrap();
+
+        let query_result = relop
+            .execute("select employees.id from employees join departments on employees.id = departments.id join locations on departments.id = locations.id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_self_join_and_aliases() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .insert_all_into("employees", rows![[1, "Relop"], [2, "Query"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute(
+                "select e1.name, e2.name from employees as e1 join employees as e2 on e1.id = e2.id order by e1.id",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "e1.name" => "Relop", "e2.name" => "Relop");
+        assert_next_row!(row_iterator.as_mut(), "e1.name" => "Query", "e2.name" => "Query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_and_projection() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "dept_name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop.insert_into("employees", row![1, "Alice"]).unwrap();
+        relop
+            .insert_into("departments", row![1, "Engineering"])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select employees.name, departments.dept_name from employees join departments on employees.id = departments.id")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.name" => "Alice", "departments.dept_name" => "Engineering");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_qualified_star_with_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "dept_name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop.insert_into("employees", row![1, "Alice"]).unwrap();
+        relop
+            .insert_into("departments", row![1, "Engineering"])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select e.* from employees as e join departments on e.id = departments.id")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "e.id" => 1, "e.name" => "Alice", ! "departments.id", ! "departments.dept_name");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_qualified_star_with_unknown_alias() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        let result = relop.execute(
+            "select locations.* from employees join departments on employees.id = departments.id",
+        );
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::TableAliasNotFound(ref qualifier))) if qualifier == "locations"
+        ));
+    }
+
+    #[test]
+    fn execute_select_with_join_and_where() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "dept_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1, 10], [2, 20]])
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[10, "Sales"], [20, "HR"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select departments.name from employees join departments on employees.dept_id = departments.id where employees.id = 2")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "departments.name" => "HR");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_and_order_by() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+        relop
+            .insert_all_into("departments", rows![[1, "Dev"], [2, "Ops"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select departments.name from employees join departments on employees.id = departments.id order by departments.name DESC")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "departments.name" => "Ops");
+        assert_next_row!(row_iterator.as_mut(), "departments.name" => "Dev");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_on_with_or() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "active" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1, 0], [2, 1]])
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[1], [3]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select employees.id from employees join departments on employees.id = departments.id OR employees.active = 1")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_and_where_or() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "active" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "location" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1, 1], [2, 0]])
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[1, "NY"], [2, "SF"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select employees.id from employees join departments on employees.id = departments.id where employees.active = 1 OR departments.location = 'SF'")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_on_mixing_and_or() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "active" => ColumnType::Int, "dept_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1, 1, 10], [2, 0, 20], [3, 1, 10]])
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[10], [20]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select employees.id from employees join departments on employees.id = departments.id AND employees.active = 1 OR employees.dept_id = departments.id")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_where_mixing_and_or() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "active" => ColumnType::Int, "dept_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "loc" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1, 1, 10], [2, 0, 20], [3, 1, 10]])
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[10, "NY"], [20, "SF"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select employees.id from employees join departments on employees.dept_id = departments.id where employees.active = 1 AND departments.loc = 'NY' OR employees.id = 2")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_on_with_and() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "active" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1, 1], [2, 0]])
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[1], [2]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select employees.id from employees join departments on employees.id = departments.id and employees.active = 1")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_on_and_where() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "active" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "loc" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1, 1], [2, 1]])
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[1, "NY"], [2, "SF"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select employees.id from employees join departments on employees.id = departments.id and employees.active = 1 where departments.loc = 'SF'")
+            .unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_join_and_parentheses_in_where() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "dept_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop
+            .insert_all_into(
+                "employees",
+                rows![[1, "Alice", 10], [2, "Bob", 10], [3, "Charlie", 20]],
+            )
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[10, "Engineering"], [20, "Sales"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select employees.name from employees join departments on employees.dept_id = departments.id where (employees.name = 'Alice' or employees.name = 'Bob') and departments.name = 'Engineering' order by employees.name")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.name" => "Alice");
+        assert_next_row!(row_iterator.as_mut(), "employees.name" => "Bob");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_right_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+        relop
+            .insert_all_into("departments", rows![[3, "Engineering"], [4, "Marketing"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute(
+                "select * from employees right join departments on employees.id = departments.id",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 3, "departments.name" => "Engineering");
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 4, "departments.name" => "Marketing");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_full_outer_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+        relop
+            .insert_all_into("departments", rows![[3, "Engineering"], [4, "Marketing"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select * from employees full outer join departments on employees.id = departments.id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1, "departments.id" => ColumnValue::null());
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2, "departments.id" => ColumnValue::null());
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 4);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_left_join_and_where_clause_on_the_right_table() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+        relop
+            .insert_all_into("departments", rows![[1, "Engineering"], [2, "Marketing"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select * from employees left join departments on employees.id = departments.id where departments.name = 'Engineering'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1, "departments.name" => "Engineering");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_cross_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table("departments", schema!["name" => ColumnType::Text].unwrap())
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+        relop
+            .insert_all_into("departments", rows![["Engineering"], ["Marketing"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select * from employees cross join departments where employees.id = 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2, "departments.name" => "Engineering");
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2, "departments.name" => "Marketing");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_comma_separated_tables_as_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        relop
+            .insert_all_into("employees", rows![[1, 10], [2, 20], [3, 10]])
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[10, "Engineering"], [20, "Marketing"]])
+            .unwrap();
+
+        let rows_of = |query: &str| {
+            let query_result = relop.execute(query).unwrap();
+            let result_set = query_result.result_set().unwrap();
+            let rows = result_set
+                .iterator()
+                .unwrap()
+                .map(|row_view| {
+                    let row_view = row_view.unwrap();
+                    (
+                        row_view
+                            .column_value_by("e.id")
+                            .unwrap()
+                            .unwrap()
+                            .int_value()
+                            .unwrap(),
+                        row_view
+                            .column_value_by("d.name")
+                            .unwrap()
+                            .unwrap()
+                            .text_value()
+                            .unwrap()
+                            .to_string(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            rows
+        };
+
+        let comma_join_rows = rows_of(
+            "select e.id, d.name from employees e, departments d where e.department_id = d.id order by e.id",
+        );
+        let join_rows = rows_of(
+            "select e.id, d.name from employees e join departments d on e.department_id = d.id order by e.id",
+        );
+
+        assert_eq!(
+            vec![
+                (1, "Engineering".to_string()),
+                (2, "Marketing".to_string()),
+                (3, "Engineering".to_string()),
+            ],
+            comma_join_rows
+        );
+        assert_eq!(join_rows, comma_join_rows);
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_ambiguous_column_in_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        let result =
+            relop.execute("select name from employees e join departments d on e.id = d.id");
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::AmbiguousColumn(column_name))) if column_name == "name"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_where_clause_on_ambiguous_column_in_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "city" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "city" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop.insert_into("employees", row![1, "London"]).unwrap();
+        relop.insert_into("departments", row![1, "Paris"]).unwrap();
+
+        let result = relop.execute(
+            "select * from employees join departments on employees.id = departments.id where city = 'London'",
+        );
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::AmbiguousColumn(column_name))) if column_name == "city"
+        ));
+    }
+
+    #[test]
+    fn execute_select_with_where_clause_on_qualified_column_sharing_a_name_in_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "city" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "city" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop.insert_into("employees", row![1, "London"]).unwrap();
+        relop.insert_into("employees", row![2, "Paris"]).unwrap();
+        relop.insert_into("departments", row![1, "Paris"]).unwrap();
+        relop.insert_into("departments", row![2, "London"]).unwrap();
+
+        let query_result = relop
+            .execute("select e.id, d.city from employees e join departments d on e.id = d.id where d.city = 'London'")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "e.id" => 2, "d.city" => "London");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_qualified_columns_sharing_a_name_in_join() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop.insert_into("employees", row![1, "relop"]).unwrap();
+        relop
+            .insert_into("departments", row![1, "Engineering"])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select e.name, d.name from employees e join departments d on e.id = d.id")
+            .unwrap();
+
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "e.name" => "relop", "d.name" => "Engineering");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_join_on_condition_referencing_a_single_table() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "manager_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        let result = relop.execute(
+            "select * from employees join departments on employees.id = employees.manager_id",
+        );
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::InvalidJoinCondition(condition)))
+                if condition == "employees.id = employees.manager_id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_cross_join_and_on_clause() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        let result = relop.execute(
+            "select * from employees cross join departments on employees.id = departments.id",
+        );
+        assert!(matches!(
+            result,
+            Err(ClientError::Parse {
+                error: ParseError::OnClauseInCrossJoin,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn execute_select_with_inner_join_and_empty_right_table() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+
+        let query_result = relop
+            .execute("select * from employees join departments on employees.id > departments.id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_left_join_and_empty_right_table() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+
+        let query_result = relop
+            .execute(
+                "select * from employees left join departments on employees.id > departments.id",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1, "departments.id" => ColumnValue::null());
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2, "departments.id" => ColumnValue::null());
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod optimization_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{rows, schema};
+
+    fn collect_values(relop: &Relop, query: &str, optimized: bool) -> Vec<Vec<ColumnValue>> {
+        let query_result = if optimized {
+            relop.execute(query).unwrap()
+        } else {
+            relop.execute_unoptimized(query).unwrap()
+        };
+        let result_set = query_result.result_set().unwrap();
+        let column_count = result_set.schema().column_count();
+
+        result_set
+            .iterator()
+            .unwrap()
+            .map(|row_view| {
+                let row_view = row_view.unwrap();
+                (0..column_count)
+                    .map(|position| row_view.column_value_at_unchecked(position).clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn employees_and_departments() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[1, "relop"], [2, "query"], [3, "plan"], [4, "scan"]],
+            ),
+            (
+                "departments",
+                schema!["employee_id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[1, "Engineering"], [3, "Marketing"], [4, "Sales"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn execute_select_with_where_clause_optimized_and_unoptimized() {
+        let relop = employees_and_departments();
+        let query = "select * from employees where id = 2";
+
+        let optimized = collect_values(&relop, query, true);
+        let unoptimized = collect_values(&relop, query, false);
+
+        assert_eq!(1, optimized.len());
+        assert_eq!(optimized, unoptimized);
+    }
+
+    #[test]
+    fn execute_select_with_limit_optimized_and_unoptimized() {
+        let relop = employees_and_departments();
+        let query = "select name from employees limit 2";
+
+        let optimized = collect_values(&relop, query, true);
+        let unoptimized = collect_values(&relop, query, false);
+
+        assert_eq!(2, optimized.len());
+        assert_eq!(optimized, unoptimized);
+    }
+
+    #[test]
+    fn execute_select_with_join_where_clause_and_order_by_optimized_and_unoptimized() {
+        let relop = employees_and_departments();
+        let query = "select employees.name, departments.name from employees join departments on employees.id = departments.employee_id where employees.id > 1 order by employees.id desc limit 1";
+
+        let optimized = collect_values(&relop, query, true);
+        let unoptimized = collect_values(&relop, query, false);
+
+        assert_eq!(1, optimized.len());
+        assert_eq!(optimized, unoptimized);
+    }
+}
+
+#[cfg(test)]
+mod explain_tests {
+    use super::*;
+    use crate::types::column_type::ColumnType;
+    use crate::{rows, schema};
+
+    #[test]
+    fn execute_explain_select_with_pushed_down_filter() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+
+        let query_result = relop
+            .execute("explain select name from employees where id = 1")
+            .unwrap();
+
+        assert_eq!(
+            Some("Projection (columns: name)\n  Scan (table: employees, filter: id = 1)\n"),
+            query_result.plan()
+        );
+    }
+
+    #[test]
+    fn execute_explain_select_with_pushed_down_limit() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        let query_result = relop
+            .execute("explain select * from employees order by id limit 3")
+            .unwrap();
+
+        assert_eq!(
+            Some("Sort (keys: id ASC, limit: 3)\n  Scan (table: employees)\n"),
+            query_result.plan()
+        );
+    }
+
+    #[test]
+    fn execute_unoptimized_explain_select() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        let query_result = relop
+            .execute_unoptimized("explain select * from employees where id = 1")
+            .unwrap();
+
+        assert_eq!(
+            Some("Filter (predicate: id = 1)\n  Scan (table: employees)\n"),
+            query_result.plan()
+        );
+    }
+
+    #[test]
+    fn execute_explain_does_not_execute_the_query() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+
+        let query_result = relop.execute("explain select * from employees").unwrap();
+
+        assert!(query_result.result_set().is_none());
+        assert_eq!(Some("Scan (table: employees)\n"), query_result.plan());
+    }
+}
+
+#[cfg(test)]
+mod bool_tests {
+    use super::*;
+    use crate::catalog::error::InsertError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::schema::error::SchemaError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, row, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "active" => ColumnType::Bool].unwrap(),
+            rows![[1, true], [2, false], [3, true]],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_where_clause_on_bool_column() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select id from employees where active = false")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_order_by_bool_column() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select * from employees order by active, id desc")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "active" => false);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "active" => true);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "active" => true);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_insert_bool_into_int_column() {
+        let relop = employees();
+
+        let result = relop.insert_into("employees", row![true, true]);
+        assert!(matches!(
+            result,
+            Err(ClientError::Insert(InsertError::Schema(SchemaError::ColumnTypeMismatch { column, expected, actual })))
+                if column == "id" && expected == ColumnType::Int && actual == ColumnType::Bool
+        ));
+    }
+
+    #[test]
+    fn attempt_to_insert_int_into_bool_column() {
+        let relop = employees();
+
+        let result = relop.insert_into("employees", row![4, 1]);
+        assert!(matches!(
+            result,
+            Err(ClientError::Insert(InsertError::Schema(SchemaError::ColumnTypeMismatch { column, expected, actual })))
+                if column == "active" && expected == ColumnType::Bool && actual == ColumnType::Int
+        ));
+    }
+}
+
+#[cfg(test)]
+mod insert_tests {
+    use super::*;
+    use crate::catalog::error::{CatalogError, InsertError};
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::executor::error::ExecutionError;
+    use crate::schema::error::SchemaError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            vec![],
+        )])
+    }
+
+    #[test]
+    fn execute_insert_and_select() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("insert into employees (id, name) values (1, 'relop'), (2, 'query')")
+            .unwrap();
+        assert_eq!(Some(2), query_result.rows_inserted());
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_insert_without_columns() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("insert into employees values (1, 'O''Brien');")
+            .unwrap();
+        assert_eq!(Some(1), query_result.rows_inserted());
+
+        let query_result = relop
+            .execute("select name from employees where id = 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "O'Brien");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_with_a_type_mismatch() {
+        let relop = employees();
+
+        let query_result = relop.execute("insert into employees values ('one', 'relop')");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Execution(ExecutionError::Insert(InsertError::Schema(
+                SchemaError::ColumnTypeMismatch { column, expected: ColumnType::Int, actual: ColumnType::Text }
+            )))) if column == "id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_into_a_non_existent_table() {
+        let relop = employees();
+
+        let query_result = relop.execute("insert into departments values (1)");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Execution(ExecutionError::Catalog(
+                CatalogError::TableDoesNotExist(table_name)
+            ))) if table_name == "departments"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod delete_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            rows![[1, "relop"], [2, "query"], [3, "rust"]],
+        )])
+    }
+
+    #[test]
+    fn execute_delete_with_where_clause() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("delete from employees where id = 1 or name = 'rust'")
+            .unwrap();
+        assert_eq!(Some(2), query_result.rows_affected());
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_delete_without_where_clause() {
+        let relop = employees();
+
+        let query_result = relop.execute("delete from employees;").unwrap();
+        assert_eq!(Some(3), query_result.rows_affected());
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_delete_matching_no_rows() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("delete from employees where id > 10")
+            .unwrap();
+        assert_eq!(Some(0), query_result.rows_affected());
+    }
+
+    #[test]
+    fn attempt_to_execute_delete_with_a_type_mismatch() {
+        let relop = employees();
+
+        let query_result = relop.execute("delete from employees where id = 'one'");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Execution(
+                ExecutionError::TypeMismatchInComparison
+            ))
+        ));
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "rust");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod update_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::executor::error::ExecutionError;
+    use crate::schema::error::SchemaError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            rows![[1, "relop"], [2, "query"], [3, "rust"]],
+        )])
+    }
+
+    #[test]
+    fn execute_update_with_where_clause() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("update employees set name = 'x' where id = 1")
+            .unwrap();
+        assert_eq!(Some(1), query_result.rows_affected());
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "x");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "rust");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_update_with_where_clause_matching_multiple_rows() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("update employees set name = 'x', id = 0 where id >= 2;")
+            .unwrap();
+        assert_eq!(Some(2), query_result.rows_affected());
+
+        let query_result = relop
+            .execute("select id from employees where name = 'x'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 0);
+        assert_next_row!(row_iterator.as_mut(), "id" => 0);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_update_matching_no_rows() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("update employees set name = 'x' where id > 10")
+            .unwrap();
+        assert_eq!(Some(0), query_result.rows_affected());
+    }
+
+    #[test]
+    fn attempt_to_execute_update_with_a_type_mismatch() {
+        let relop = employees();
+
+        let query_result = relop.execute("update employees set id = 'one'");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Execution(ExecutionError::Schema(
+                SchemaError::ColumnTypeMismatch { column, .. }
+            ))) if column == "id"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod table_lifecycle_tests {
+    use super::*;
+    use crate::catalog::error::CatalogError;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows};
+
+    #[test]
+    fn execute_create_table_and_insert() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop
+            .execute("create table employees (id int, name text)")
+            .unwrap();
+        assert_eq!(Some("employees"), query_result.created_table());
+
+        let query_result = relop.execute("describe table employees").unwrap();
+        let table = query_result.table_descriptor().unwrap();
+        assert_eq!(vec!["id", "name"], table.column_names());
+        assert_eq!(
+            vec![ColumnType::Int, ColumnType::Text],
+            table.column_types()
+        );
+
+        relop
+            .execute("insert into employees values (1, 'relop')")
+            .unwrap();
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_create_table_with_a_bool_column() {
+        let relop = Relop::new(Catalog::new());
+
+        relop
+            .execute("CREATE TABLE flags (name TEXT, enabled BOOL);")
+            .unwrap();
+
+        let query_result = relop.execute("describe table flags").unwrap();
+        let table = query_result.table_descriptor().unwrap();
+        assert_eq!(
+            vec![ColumnType::Text, ColumnType::Bool],
+            table.column_types()
+        );
+    }
+
+    #[test]
+    fn attempt_to_execute_create_table_for_an_existing_table() {
+        let relop = Relop::new(Catalog::new());
+        relop.execute("create table employees (id int)").unwrap();
+
+        let query_result = relop.execute("create table employees (id int)");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Execution(ExecutionError::Catalog(
+                CatalogError::TableAlreadyExists(table_name)
+            ))) if table_name == "employees"
+        ));
+    }
+
+    #[test]
+    fn execute_drop_table() {
+        let relop = Relop::new(Catalog::new());
+        relop.execute("create table employees (id int)").unwrap();
+        relop.execute("create table departments (id int)").unwrap();
+
+        let query_result = relop.execute("drop table employees").unwrap();
+        assert_eq!(Some("employees"), query_result.dropped_table());
+
+        let query_result = relop.execute("show tables").unwrap();
+        assert_eq!(
+            &vec!["departments".to_string()],
+            query_result.all_tables().unwrap()
+        );
+    }
+
+    #[test]
+    fn attempt_to_execute_drop_table_for_a_non_existent_table() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop.execute("drop table employees");
+        assert!(matches!(
+            query_result,
+            Err(ClientError::Execution(ExecutionError::Catalog(
+                CatalogError::TableDoesNotExist(table_name)
+            ))) if table_name == "employees"
+        ));
+    }
+
+    #[test]
+    fn drop_table() {
+        let relop = Relop::new(Catalog::new());
+        relop.execute("create table employees (id int)").unwrap();
+
+        relop.drop_table("employees").unwrap();
+
+        let query_result = relop.execute("show tables").unwrap();
+        assert!(query_result.all_tables().unwrap().is_empty());
+    }
+
+    #[test]
+    fn attempt_to_drop_a_non_existent_table() {
+        let relop = Relop::new(Catalog::new());
+
+        let result = relop.drop_table("employees");
+        assert!(matches!(
+            result,
+            Err(ClientError::Catalog(CatalogError::TableDoesNotExist(table_name))) if table_name == "employees"
+        ));
+    }
+
+    #[test]
+    fn truncate_table() {
+        let relop = Relop::new(Catalog::new());
+        relop.execute("create table employees (id int)").unwrap();
+        relop
+            .execute("insert into employees values (1), (2)")
+            .unwrap();
+
+        relop.truncate_table("employees").unwrap();
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_no_more_rows!(row_iterator.as_mut());
+
+        let query_result = relop.execute("describe table employees").unwrap();
+        let table = query_result.table_descriptor().unwrap();
+        assert_eq!(vec!["id"], table.column_names());
+    }
+
+    #[test]
+    fn attempt_to_truncate_a_non_existent_table() {
+        let relop = Relop::new(Catalog::new());
+
+        let result = relop.truncate_table("employees");
+        assert!(matches!(
+            result,
+            Err(ClientError::Catalog(CatalogError::TableDoesNotExist(table_name))) if table_name == "employees"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod count_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_type::ColumnType;
+    use crate::{rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            rows![[1, "relop"], [2, "query"], [3, "rust"]],
+        )])
+    }
+
+    #[test]
+    fn count_rows_of_select_star() {
+        let relop = employees();
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(3, result_set.count().unwrap());
+    }
+
+    #[test]
+    fn count_rows_of_select_with_limit() {
+        let relop = employees();
+
+        let query_result = relop.execute("select * from employees limit 2").unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(2, result_set.count().unwrap());
+    }
+
+    #[test]
+    fn count_rows_of_select_with_where_clause_and_order_by() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select name from employees where id >= 2 order by name")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(2, result_set.count().unwrap());
+    }
+
+    #[test]
+    fn count_rows_of_an_empty_result() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select * from employees where id > 10")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(0, result_set.count().unwrap());
+    }
+
+    #[test]
+    fn count_rows_and_iterate_again() {
+        let relop = employees();
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(3, result_set.count().unwrap());
+        assert_eq!(3, result_set.iterator().unwrap().count());
+    }
+
+    #[test]
+    fn attempt_to_count_rows_with_an_error_in_iteration() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute_unoptimized("select * from employees where id = 'one'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert!(matches!(
+            result_set.count(),
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod collect_rows_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{row, rows, schema};
+
+    fn employees_and_departments() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "department_id" => ColumnType::Int].unwrap(),
+                rows![[1, "relop", 10], [2, "query", 20], [3, "rust", 30]],
+            ),
+            (
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[10, "Engineering"], [20, "Marketing"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn collect_rows_of_select_star() {
+        let relop = employees_and_departments();
+
+        let query_result = relop.execute("select * from departments").unwrap();
+        let rows = query_result.result_set().unwrap().collect_rows().unwrap();
+
+        assert_eq!(rows![[10, "Engineering"], [20, "Marketing"]], rows);
+    }
+
+    #[test]
+    fn collect_rows_of_select_with_projection_and_alias() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select name as employee, id from employees where id < 3")
+            .unwrap();
+        let rows = query_result.result_set().unwrap().collect_rows().unwrap();
+
+        assert_eq!(rows![["relop", 1], ["query", 2]], rows);
+    }
+
+    #[test]
+    fn collect_rows_of_select_with_join() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select employees.name, departments.name from employees join departments on employees.department_id = departments.id")
+            .unwrap();
+        let rows = query_result.result_set().unwrap().collect_rows().unwrap();
+
+        assert_eq!(
+            rows![["relop", "Engineering"], ["query", "Marketing"]],
+            rows
+        );
+    }
+
+    #[test]
+    fn collect_rows_of_select_with_nested_loop_join() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select e.id, d.id from employees as e join departments as d on e.department_id > d.id")
+            .unwrap();
+        let rows = query_result.result_set().unwrap().collect_rows().unwrap();
+
+        assert_eq!(rows![[2, 10], [3, 10], [3, 20]], rows);
+    }
+
+    #[test]
+    fn collect_rows_of_select_with_left_join() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select e.id, d.name from employees as e left join departments as d on e.department_id = d.id")
+            .unwrap();
+        let rows = query_result.result_set().unwrap().collect_rows().unwrap();
+
+        assert_eq!(
+            vec![
+                row![1, "Engineering"],
+                row![2, "Marketing"],
+                Row::filled(vec![ColumnValue::int(3), ColumnValue::null()]),
+            ],
+            rows
+        );
+    }
+
+    #[test]
+    fn collect_rows_of_select_with_order_by_and_limit() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select name from employees order by name desc limit 2")
+            .unwrap();
+        let rows = query_result.result_set().unwrap().collect_rows().unwrap();
+
+        assert_eq!(rows![["rust"], ["relop"]], rows);
+    }
+
+    #[test]
+    fn collect_rows_of_an_empty_result() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select * from employees where id > 10")
+            .unwrap();
+        let rows = query_result.result_set().unwrap().collect_rows().unwrap();
+
+        assert!(rows.is_empty());
+    }
+
+    #[test]
+    fn rows_of_select_with_iterator_adapters() {
+        let relop = employees_and_departments();
+
+        let query_result = relop.execute("select id, name from employees").unwrap();
+        let rows = query_result
+            .result_set()
+            .unwrap()
+            .rows()
+            .unwrap()
+            .filter(|row| {
+                row.as_ref()
+                    .map(|row| row.column_value_at(0) != Some(&ColumnValue::int(2)))
+                    .unwrap_or(true)
+            })
+            .take(2)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(rows![[1, "relop"], [3, "rust"]], rows);
+    }
+
+    #[test]
+    fn rows_of_select_are_produced_on_demand() {
+        let relop = employees_and_departments();
+
+        let query_result = relop.execute("select name from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut rows = result_set.rows().unwrap();
+
+        assert_eq!(row!["relop"], rows.next().unwrap().unwrap());
+        assert_eq!(row!["query"], rows.next().unwrap().unwrap());
+        assert_eq!(row!["rust"], rows.next().unwrap().unwrap());
+        assert!(rows.next().is_none());
+    }
+
+    #[test]
+    fn rows_of_select_outlive_the_iterator() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute(
+                "select d.name from employees as e join departments as d on e.department_id = d.id",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let names = result_set
+            .rows()
+            .unwrap()
+            .map(|row| row.map(|row| row.column_value_at(0).cloned()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                Some(ColumnValue::text("Engineering")),
+                Some(ColumnValue::text("Marketing"))
+            ],
+            names
+        );
+    }
+
+    #[test]
+    fn attempt_to_iterate_rows_of_select_with_a_type_mismatch() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select * from employees where name > 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut rows = result_set.rows().unwrap();
+
+        assert!(matches!(
+            rows.next(),
+            Some(Err(
+                crate::query::executor::error::ExecutionError::TypeMismatchInComparison
+            ))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod display_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::types::column_type::ColumnType;
+    use crate::{rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "active" => ColumnType::Bool].unwrap(),
+            rows![[1, "relop", true], [20, "query", false]],
+        )])
+    }
+
+    #[test]
+    fn display_select_star() {
+        let relop = employees();
+        let query_result = relop.execute("select * from employees").unwrap();
+
+        assert_eq!(
+            "+--------------+----------------+------------------+\n\
+             | employees.id | employees.name | employees.active |\n\
+             +--------------+----------------+------------------+\n\
+             |            1 | relop          | true             |\n\
+             |           20 | query          | false            |\n\
+             +--------------+----------------+------------------+\n",
+            query_result.to_string()
+        );
+    }
+
+    #[test]
+    fn display_select_with_projection_and_alias() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select name as employee, id from employees order by id desc")
+            .unwrap();
+
+        assert_eq!(
+            "+----------+--------------+\n\
+             | employee | employees.id |\n\
+             +----------+--------------+\n\
+             | query    |           20 |\n\
+             | relop    |            1 |\n\
+             +----------+--------------+\n",
+            query_result.to_string()
+        );
+    }
+
+    #[test]
+    fn display_select_with_left_join_and_nulls() {
+        let relop = employees();
+        relop
+            .create_table(
+                "departments",
+                schema!["employee_id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[1, "Engineering"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select e.id, d.name from employees as e left join departments as d on e.id = d.employee_id")
+            .unwrap();
+
+        assert_eq!(
+            "+------+-------------+\n\
+             | e.id | d.name      |\n\
+             +------+-------------+\n\
+             |    1 | Engineering |\n\
+             |   20 | NULL        |\n\
+             +------+-------------+\n",
+            query_result.to_string()
+        );
+    }
+
+    #[test]
+    fn display_select_with_no_rows() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where id > 100")
+            .unwrap();
+
+        assert_eq!(
+            "+--------------+\n\
+             | employees.id |\n\
+             +--------------+\n",
+            query_result.to_string()
+        );
+    }
+
+    #[test]
+    fn display_select_with_an_error_in_iteration() {
+        let relop = employees();
+        let query_result = relop
+            .execute_unoptimized("select id from employees where name = 1")
+            .unwrap();
+
+        assert_eq!(
+            "error: type mismatch in comparison\n",
+            query_result.to_string()
+        );
+    }
+
+    #[test]
+    fn display_select_with_a_division_by_zero_in_iteration() {
+        let relop = employees();
+        let query_result = relop.execute("select id / 0 from employees").unwrap();
+
+        assert_eq!("error: division by zero\n", query_result.to_string());
+    }
+
+    #[test]
+    fn display_show_tables() {
+        let relop = employees();
+        let query_result = relop.execute("show tables").unwrap();
+
+        assert_eq!("employees\n", query_result.to_string());
+    }
+}
+
+#[cfg(test)]
+mod csv_export_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::executor::error::ExecutionError;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{row, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "active" => ColumnType::Bool].unwrap(),
+            vec![
+                row![1, "relop", true],
+                row![2, "Doe, John", false],
+                row![3, "say \"hi\"", true],
+                Row::filled(vec![
+                    ColumnValue::int(4),
+                    ColumnValue::null(),
+                    ColumnValue::null(),
+                ]),
+            ],
+        )])
+    }
+
+    #[test]
+    fn export_select_star_as_csv() {
+        let relop = employees();
+        let query_result = relop.execute("select * from employees").unwrap();
+
+        assert_eq!(
+            "employees.id,employees.name,employees.active\n\
+             1,relop,true\n\
+             2,\"Doe, John\",false\n\
+             3,\"say \"\"hi\"\"\",true\n\
+             4,,\n",
+            query_result.to_csv().unwrap()
+        );
+    }
+
+    #[test]
+    fn export_select_with_projection_and_alias_as_csv() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select name as \"full, name\" from employees where id = 2")
+            .unwrap();
+
+        assert_eq!(
+            "\"full, name\"\n\"Doe, John\"\n",
+            query_result.to_csv().unwrap()
+        );
+    }
+
+    #[test]
+    fn export_empty_result_as_csv() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where id > 10")
+            .unwrap();
+
+        assert_eq!("employees.id\n", query_result.to_csv().unwrap());
+    }
+
+    #[test]
+    fn attempt_to_export_as_csv_with_an_error_in_iteration() {
+        let relop = employees();
+        let query_result = relop
+            .execute_unoptimized("select id from employees where name = 1")
+            .unwrap();
+
+        assert!(matches!(
+            query_result.to_csv(),
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod csv_import_tests {
+    use super::*;
+    use crate::catalog::error::{CatalogError, LoadCsvError};
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, row, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "active" => ColumnType::Bool].unwrap(),
+            vec![],
+        )])
+    }
+
+    #[test]
+    fn import_csv_and_select() {
+        let relop = employees();
+        let csv = "id,name,active\n1,relop,true\n2,\"Doe, John\",false\n";
+        let row_ids = relop.import_csv("employees", csv.as_bytes(), true).unwrap();
+        assert_eq!(2, row_ids.len());
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "employees.name" => "relop", "employees.active" => true);
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "employees.name" => "Doe, John", "employees.active" => false);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn import_exported_csv() {
+        let relop = employees();
+        relop
+            .insert_all_into(
+                "employees",
+                vec![row![1, "say \"hi\"", true], row![2, "multi\nline", false]],
+            )
+            .unwrap();
+        let csv = relop
+            .execute("select * from employees")
+            .unwrap()
+            .to_csv()
+            .unwrap();
+
+        relop.truncate_table("employees").unwrap();
+        relop.import_csv("employees", csv.as_bytes(), true).unwrap();
+
+        assert_eq!(
+            csv,
+            relop
+                .execute("select * from employees")
+                .unwrap()
+                .to_csv()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn attempt_to_import_csv_with_an_invalid_value() {
+        let relop = employees();
+        let csv = "1,relop,true\ntwo,query,false\n";
+        let result = relop.import_csv("employees", csv.as_bytes(), false);
+
+        assert!(matches!(
+            result,
+            Err(ClientError::LoadCsv(LoadCsvError::InvalidValue { row, column, .. }))
+                if row == 2 && column == "id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_import_csv_into_non_existent_table() {
+        let relop = Relop::new(Catalog::new());
+        let result = relop.import_csv("employees", "1\n".as_bytes(), false);
+
+        assert!(matches!(
+            result,
+            Err(ClientError::LoadCsv(LoadCsvError::Catalog(CatalogError::TableDoesNotExist(table_name))))
+                if table_name == "employees"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod order_by_ordinal_tests {
+    use crate::catalog::Catalog;
+    use crate::client::error::ClientError;
+    use crate::client::Relop;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    #[test]
+    fn execute_select_with_order_by_ordinal() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .insert_all_into(
+                "employees",
+                rows![[1, "relop"], [3, "query"], [2, "operator"]],
+            )
+            .unwrap();
+
+        let query_result = relop
+            .execute("select name, id from employees order by 2 desc")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "query", "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "name" => "operator", "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "name" => "relop", "id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_order_by_ordinal_of_aliased_column() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .insert_all_into("employees", rows![[1, "relop"], [2, "query"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select id as employee_id, name from employees order by 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employee_id" => 2, "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "employee_id" => 1, "name" => "relop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_order_by_ordinal_out_of_range() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        let result = relop.execute("select id from employees order by 3");
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::OrderByOrdinalOutOfRange { ordinal, column_count }))
+                if ordinal == 3 && column_count == 1
+        ));
+    }
+}
+
+#[cfg(test)]
+mod nulls_ordering_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows, row, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "rank" => ColumnType::Int].unwrap(),
+            vec![
+                row![1, 20],
+                Row::filled(vec![ColumnValue::int(2), ColumnValue::null()]),
+                row![3, 10],
+            ],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_order_by_ascending_places_nulls_last_by_default() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees order by rank")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_order_by_descending_places_nulls_first_by_default() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees order by rank desc")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_order_by_ascending_nulls_first() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees order by rank asc nulls first")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_order_by_descending_nulls_last() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees order by rank desc nulls last")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_order_by_nulls_first_and_limit() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees order by rank nulls first limit 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod arithmetic_tests {
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema![
+                "id" => ColumnType::Int,
+                "name" => ColumnType::Text,
+                "salary" => ColumnType::Int,
+                "bonus" => ColumnType::Int
+            ]
+            .unwrap(),
+            rows![
+                [1, "relop", 900, 200],
+                [2, "query", 700, 100],
+                [3, "operator", 1000, 0]
+            ],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_addition_in_where_clause() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where salary + bonus > 1000")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_arithmetic_precedence_in_where_clause() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where salary - bonus * 2 = 500 or id * (2 + 1) = 9")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_arithmetic_on_both_sides_of_comparison() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where salary / 100 = bonus / 100 + 7")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_delete_with_arithmetic_in_where_clause() {
+        let relop = employees();
+        let query_result = relop
+            .execute("delete from employees where salary + bonus >= 1000")
+            .unwrap();
+
+        assert_eq!(Some(2), query_result.rows_affected());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_division_by_zero() {
+        let relop = employees();
+        let query_result = relop
+            .execute_unoptimized("select id from employees where salary / bonus > 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let result = result_set
+            .iterator()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>();
+
+        assert!(matches!(result, Err(ExecutionError::DivisionByZero)));
+    }
+
+    #[test]
+    fn attempt_to_execute_delete_with_division_by_zero() {
+        let relop = employees();
+        let result = relop.execute("delete from employees where salary / bonus > 1");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::DivisionByZero))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_arithmetic_on_text() {
+        let relop = employees();
+        let query_result = relop
+            .execute_unoptimized("select id from employees where name + 1 > 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let result = result_set
+            .iterator()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>();
+
+        assert!(matches!(
+            result,
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod computed_column_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema![
+                "id" => ColumnType::Int,
+                "name" => ColumnType::Text,
+                "salary" => ColumnType::Int
+            ]
+            .unwrap(),
+            rows![[1, "relop", 900], [2, "query", 700]],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_a_plain_and_a_computed_column() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id, salary * 12 as annual from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "annual" => 10800, ! "salary");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "annual" => 8400, ! "salary");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_a_computed_column_in_where_and_order_by() {
+        let relop = employees();
+        let query_result = relop
+            .execute(
+                "select name, (salary + 100) / 2 as half from employees where id > 0 order by half",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "query", "half" => 400);
+        assert_next_row!(row_iterator.as_mut(), "name" => "relop", "half" => 500);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn computed_column_names() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id, salary * 12 as annual from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(
+            vec!["employees.id".to_string(), "annual".to_string()],
+            result_set.column_names()
+        );
+    }
+
+    #[test]
+    fn execute_select_with_a_constant_and_a_plain_column() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select 1 as one, name from employees order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "one" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "one" => 1, "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_constant_columns_of_each_type() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id, 'active' as status, true as enabled from employees where id = 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "status" => "active", "enabled" => true);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn constant_column_names() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select 'relop' as engine, id from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(
+            vec!["engine".to_string(), "employees.id".to_string()],
+            result_set.column_names()
+        );
+    }
+
+    #[test]
+    fn execute_select_with_a_constant_column_without_alias() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select 1, name from employees order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(
+            vec!["1".to_string(), "employees.name".to_string()],
+            result_set.column_names()
+        );
+
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "1" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "1" => 1, "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_a_computed_column_over_text() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id, name * 2 as doubled from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let result = result_set
+            .iterator()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>();
+
+        assert!(matches!(
+            result,
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod string_function_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            rows![[1, "Relop"], [2, "Query Engine"]],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_string_functions_in_projection() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id, upper(name) as upper_name, lower(name) as lower_name, length(name) as name_length from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "upper_name" => "RELOP", "lower_name" => "relop", "name_length" => 5);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "upper_name" => "QUERY ENGINE", "lower_name" => "query engine", "name_length" => 12);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_string_functions_without_alias() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select upper(name), length(name) from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "upper(name)" => "RELOP", "length(name)" => 5);
+        assert_next_row!(row_iterator.as_mut(), "upper(name)" => "QUERY ENGINE", "length(name)" => 12);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_string_functions_in_where_clause() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where upper(name) = 'RELOP' or length(name) > 10")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_substr() {
+        let relop = employees();
+        let query_result = relop
+            .execute(
+                "select substr(name, 1, 3) as prefix, substr(name, 7) as suffix from employees",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "prefix" => "Rel", "suffix" => "");
+        assert_next_row!(row_iterator.as_mut(), "prefix" => "Que", "suffix" => "Engine");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_substr_without_alias() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select substr(name, 1, 3) from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(vec!["substr(name, 1, 3)"], result_set.column_names());
+
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "substr(name, 1, 3)" => "Rel");
+        assert_next_row!(row_iterator.as_mut(), "substr(name, 1, 3)" => "Que");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_substr_in_where_clause() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where substr(name, 1, 5) = 'Query'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_string_function_over_int() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select upper(id) as upper_id from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let result = result_set
+            .iterator()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>();
+
+        assert!(matches!(
+            result,
+            Err(ExecutionError::InvalidFunctionArgument { function, expected })
+                if function == "upper" && expected == ColumnType::Text
+        ));
+    }
+}
+
+#[cfg(test)]
+mod union_tests {
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees_and_managers() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[1, "relop"], [2, "query"]],
+            ),
+            (
+                "managers",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[2, "query"], [3, "plan"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn execute_union() {
+        let relop = employees_and_managers();
+        let query_result = relop
+            .execute("select id, name from employees union select id, name from managers")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "plan");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_union_all() {
+        let relop = employees_and_managers();
+        let query_result = relop
+            .execute("select name from employees union all select name from managers")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(
+            vec!["employees.name".to_string()],
+            result_set.column_names()
+        );
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "name" => "plan");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_union_with_order_by_and_limit_of_the_combined_result() {
+        let relop = employees_and_managers();
+        let query_result = relop
+            .execute("select id from employees where id > 1 union all select id from managers order by id desc limit 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_union_with_order_by_an_alias_of_the_first_query() {
+        let relop = employees_and_managers();
+        let query_result = relop
+            .execute("select id as member_id, name from employees union select id, name from managers order by member_id desc")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(
+            vec!["member_id", "employees.name"],
+            result_set.column_names()
+        );
+
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "member_id" => 3, "name" => "plan");
+        assert_next_row!(row_iterator.as_mut(), "member_id" => 2, "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "member_id" => 1, "name" => "relop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_union_with_different_column_counts() {
+        let relop = employees_and_managers();
+        let result = relop.execute("select id, name from employees union select id from managers");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(
+                PlanningError::SetOperationColumnCountMismatch { left: 2, right: 1 }
+            ))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_union_with_different_column_types() {
+        let relop = employees_and_managers();
+        let result = relop.execute("select id from employees union select name from managers");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(
+                PlanningError::SetOperationColumnTypeMismatch {
+                    position: 1,
+                    left: ColumnType::Int,
+                    right: ColumnType::Text
+                }
+            ))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod intersect_and_except_tests {
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees_and_managers() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[1, "relop"], [2, "query"], [2, "query"], [3, "plan"]],
+            ),
+            (
+                "managers",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[2, "query"], [3, "plan"], [4, "scan"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn execute_intersect() {
+        let relop = employees_and_managers();
+        let query_result = relop
+            .execute("select id, name from employees intersect select id, name from managers")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "plan");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_intersect_of_disjoint_queries() {
+        let relop = employees_and_managers();
+        let query_result = relop
+            .execute("select id from employees where id = 1 intersect select id from managers")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_except() {
+        let relop = employees_and_managers();
+        let query_result = relop
+            .execute("select id, name from managers except select id, name from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 4, "name" => "scan");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_except_of_disjoint_queries() {
+        let relop = employees_and_managers();
+        let query_result = relop
+            .execute(
+                "select id from employees except select id from managers where id = 4 order by id",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_intersect_with_different_column_types() {
+        let relop = employees_and_managers();
+        let result = relop.execute("select id from employees intersect select name from managers");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(
+                PlanningError::SetOperationColumnTypeMismatch {
+                    position: 1,
+                    left: ColumnType::Int,
+                    right: ColumnType::Text
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_except_with_different_column_counts() {
+        let relop = employees_and_managers();
+        let result = relop.execute("select id from employees except select id, name from managers");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(
+                PlanningError::SetOperationColumnCountMismatch { left: 1, right: 2 }
+            ))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod scalar_subquery_tests {
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees_and_departments() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "department_id" => ColumnType::Int].unwrap(),
+                rows![[1, "relop", 10], [2, "query", 20], [3, "plan", 10]],
+            ),
+            (
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[10, "storage"], [20, "engine"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn execute_select_with_scalar_subquery() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select id from employees where department_id = (select id from departments where name = 'storage')")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_scalar_subquery_in_arithmetic() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select name from employees where department_id = (select id from departments where name = 'storage') + 10")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_scalar_subquery_producing_null() {
+        let relop = employees_and_departments();
+        relop
+            .execute("insert into departments (id) values (30)")
+            .unwrap();
+
+        let query_result = relop
+            .execute("select id from employees where name = (select name from departments where id = 30)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_delete_with_scalar_subquery() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("delete from employees where department_id = (select id from departments where name = 'engine')")
+            .unwrap();
+
+        assert_eq!(Some(1), query_result.rows_affected());
+    }
+
+    #[test]
+    fn attempt_to_execute_scalar_subquery_without_rows() {
+        let relop = employees_and_departments();
+        let result = relop.execute(
+            "select id from employees where department_id = (select id from departments where name = 'network')",
+        );
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(
+                ExecutionError::ScalarSubqueryWithoutRows
+            ))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_scalar_subquery_with_multiple_rows() {
+        let relop = employees_and_departments();
+        let result = relop
+            .execute("select id from employees where department_id = (select id from departments)");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(
+                ExecutionError::ScalarSubqueryWithMultipleRows
+            ))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_scalar_subquery_selecting_multiple_columns() {
+        let relop = employees_and_departments();
+        let result = relop.execute(
+            "select id from employees where department_id = (select id, name from departments)",
+        );
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(
+                PlanningError::SubqueryColumnCountMismatch(2)
+            ))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod in_subquery_tests {
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees_and_departments() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+                rows![[1, 10], [2, 20], [3, 30], [4, 10]],
+            ),
+            (
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[10, "storage"], [20, "engine"], [40, "network"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn execute_select_with_in_subquery() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select id from employees where department_id in (select id from departments)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 4);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_in_subquery_without_rows() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select id from employees where department_id in (select id from departments where name = 'sales')")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_not_in_subquery() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select id from employees where department_id not in (select id from departments where id < 30)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_not_in_subquery_producing_null() {
+        let relop = employees_and_departments();
+        relop
+            .execute("insert into employees (id) values (5)")
+            .unwrap();
+
+        let query_result = relop
+            .execute(
+                "select id from departments where id not in (select department_id from employees)",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_delete_with_in_subquery() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("delete from employees where department_id in (select id from departments where name = 'storage')")
+            .unwrap();
+
+        assert_eq!(Some(2), query_result.rows_affected());
+    }
+
+    #[test]
+    fn attempt_to_execute_in_subquery_selecting_multiple_columns() {
+        let relop = employees_and_departments();
+        let result = relop.execute(
+            "select id from employees where department_id in (select id, name from departments)",
+        );
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(
+                PlanningError::SubqueryColumnCountMismatch(2)
+            ))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_in_subquery_selecting_a_column_of_other_type() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute_unoptimized(
+                "select id from employees where department_id in (select name from departments)",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert!(matches!(
+            row_iterator.next(),
+            Some(Err(ExecutionError::TypeMismatchInComparison))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod parallel_scan_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
+    use crate::query::executor::result::QueryResult;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows, row, schema};
+
+    fn employees(scan_parallelism: usize) -> Relop {
+        let relop = Relop::new(Catalog::new()).with_scan_parallelism(scan_parallelism);
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        let rows = (1..=1000)
+            .map(|id| row![id, format!("employee-{}", id % 7)])
+            .collect::<Vec<Row>>();
+        relop.insert_all_into("employees", rows).unwrap();
+        relop
+    }
+
+    fn all_values(query_result: QueryResult) -> Vec<Vec<ColumnValue>> {
+        let result_set = query_result.result_set().unwrap();
+        result_set
+            .iterator()
+            .unwrap()
+            .map(|row_view| {
+                let row_view = row_view.unwrap();
+                vec![
+                    row_view.column_value_by("id").unwrap().unwrap().clone(),
+                    row_view.column_value_by("name").unwrap().unwrap().clone(),
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parallel_scan_produces_the_rows_of_a_sequential_scan() {
+        let query = "select id, name from employees where name = 'employee-3' or id < 10";
+
+        let sequential = all_values(employees(1).execute(query).unwrap());
+        for scan_parallelism in [2, 3, 8] {
+            let parallel = all_values(employees(scan_parallelism).execute(query).unwrap());
+            assert_eq!(sequential, parallel);
+        }
+        assert_eq!(151, sequential.len());
+    }
+
+    #[test]
+    fn parallel_scan_with_a_selective_filter() {
+        let relop = employees(4);
+        let query_result = relop
+            .execute("select id from employees where id > 997")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 998);
+        assert_next_row!(row_iterator.as_mut(), "id" => 999);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1000);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn parallel_scan_with_a_limit() {
+        let relop = employees(4);
+        let query_result = relop
+            .execute("select id from employees where id > 10 limit 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 11);
+        assert_next_row!(row_iterator.as_mut(), "id" => 12);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn zero_scan_parallelism_scans_sequentially() {
+        let relop = employees(0);
+        let query_result = relop
+            .execute("select id from employees where id = 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod constant_folding_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int].unwrap(),
+            rows![[1], [5], [7]],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_always_true_conjunct() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where 1 = 1 and id = 5")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 5);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_always_false_predicate() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where 1 = 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_unoptimized_select_with_always_false_predicate() {
+        let relop = employees();
+        let query_result = relop
+            .execute_unoptimized("select id from employees where 1 = 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_unoptimized_select_with_always_true_predicate() {
+        let relop = employees();
+        let query_result = relop
+            .execute_unoptimized("select id from employees where 1 = 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(3, result_set.count().unwrap());
+    }
+
+    #[test]
+    fn execute_select_with_always_true_disjunct() {
+        let relop = employees();
+        let query_result = relop
+            .execute("select id from employees where id = 5 or 2 > 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 5);
+        assert_next_row!(row_iterator.as_mut(), "id" => 7);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn explain_select_with_always_true_conjunct() {
+        let relop = employees();
+        let query_result = relop
+            .execute("explain select id from employees where 1 = 1 and id = 5")
+            .unwrap();
+
+        assert_eq!(
+            Some("Projection (columns: id)\n  Scan (table: employees, filter: id = 5)\n"),
+            query_result.plan()
+        );
+    }
+
+    #[test]
+    fn explain_select_with_always_false_predicate() {
+        let relop = employees();
+        let query_result = relop
+            .execute("explain select id from employees where id = 5 and 1 = 2")
+            .unwrap();
+
+        assert_eq!(
+            Some("Projection (columns: id)\n  Limit (count: 0)\n    Scan (table: employees, limit: 0)\n"),
+            query_result.plan()
+        );
+    }
+}
+
+#[cfg(test)]
+mod join_reordering_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::executor::result::QueryResult;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{row, rows, schema};
+
+    fn departments_and_employees() -> Relop {
+        relop_with_tables(vec![
+            (
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[1, "storage"], [2, "engine"]],
+            ),
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+                (1..=20).map(|id| row![id, id % 3 + 1]).collect(),
+            ),
+        ])
+    }
+
+    fn sorted_values(query_result: QueryResult) -> Vec<Vec<ColumnValue>> {
+        let result_set = query_result.result_set().unwrap();
+        let mut values = result_set
+            .iterator()
+            .unwrap()
+            .map(|row_view| row_view.unwrap().to_row().column_values().to_vec())
+            .collect::<Vec<_>>();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn explain_join_building_on_the_smaller_table() {
+        let relop = departments_and_employees();
+        let query_result = relop
+            .execute("explain select * from departments join employees on departments.id = employees.department_id")
+            .unwrap();
+
+        assert_eq!(
+            Some("Projection (columns: departments.id, departments.name, employees.id, employees.department_id)\n  Join (type: INNER, on: departments.id = employees.department_id)\n    Scan (table: employees)\n    Scan (table: departments)\n"),
+            query_result.plan()
+        );
+    }
+
+    #[test]
+    fn reordered_join_produces_the_rows_of_the_original_join() {
+        let relop = departments_and_employees();
+        let query =
+            "select * from departments join employees on departments.id = employees.department_id";
+
+        let reordered = sorted_values(relop.execute(query).unwrap());
+        let original = sorted_values(relop.execute_unoptimized(query).unwrap());
+
+        assert_eq!(original, reordered);
+        assert_eq!(13, reordered.len());
+        assert_eq!(4, reordered[0].len());
+    }
+}
+
+#[cfg(test)]
+mod exists_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees_and_departments() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+                rows![[1, 10], [2, 20], [3, 30], [4, 10]],
+            ),
+            (
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[10, "storage"], [20, "engine"], [40, "network"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn execute_select_with_correlated_exists() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select name from departments where exists (select id from employees where employees.department_id = departments.id)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "storage");
+        assert_next_row!(row_iterator.as_mut(), "name" => "engine");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_correlated_not_exists() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select name from departments where not exists (select id from employees where employees.department_id = departments.id)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "network");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_correlated_exists_selecting_a_constant() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select * from departments where exists (select 1 from employees where employees.department_id = departments.id)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 10, "name" => "storage");
+        assert_next_row!(row_iterator.as_mut(), "id" => 20, "name" => "engine");
+        assert_no_more_rows!(row_iterator.as_mut());
+
+        let query_result = relop
+            .execute("select * from departments where not exists (select 1 from employees where employees.department_id = departments.id)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 40, "name" => "network");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_correlated_exists_over_table_alias() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select d.name from departments as d where exists (select id from employees where department_id = d.id and id > 1)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "d.name" => "storage");
+        assert_next_row!(row_iterator.as_mut(), "d.name" => "engine");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_correlated_exists_and_other_clauses() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select name from departments where id > 10 and exists (select id from employees where employees.department_id = departments.id)")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "engine");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_uncorrelated_exists() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select id from employees where exists (select id from departments where name = 'engine')")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 4);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_uncorrelated_exists_without_rows() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("select id from employees where exists (select id from departments where name = 'sales')")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_delete_with_correlated_not_exists() {
+        let relop = employees_and_departments();
+        let query_result = relop
+            .execute("delete from employees where not exists (select id from departments where departments.id = employees.department_id)")
+            .unwrap();
+
+        assert_eq!(Some(1), query_result.rows_affected());
+    }
+}
+
+#[cfg(test)]
+mod persistence_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{row, rows, schema};
+
+    fn values(relop: &Relop, query: &str) -> Vec<Vec<ColumnValue>> {
+        let query_result = relop.execute(query).unwrap();
+        let result_set = query_result.result_set().unwrap();
+        result_set
+            .iterator()
+            .unwrap()
+            .map(|row_view| row_view.unwrap().to_row().column_values().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn select_from_a_loaded_catalog() {
+        let path = std::env::temp_dir().join("relop_select_from_a_loaded_catalog");
+        let catalog = Catalog::new();
+        let relop = Relop::new(catalog.clone());
+        relop
+            .create_table(
+                "employees",
+                schema![
+                    "id" => ColumnType::Int,
+                    "name" => ColumnType::Text,
+                    "active" => ColumnType::Bool
+                ]
+                .unwrap(),
+            )
+            .unwrap();
+        relop
+            .create_table("departments", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .insert_all_into(
+                "employees",
+                vec![
+                    row![1, "relop", true],
+                    row![-2, "", false],
+                    Row::filled(vec![
+                        ColumnValue::int(3),
+                        ColumnValue::null(),
+                        ColumnValue::null(),
+                    ]),
+                ],
+            )
+            .unwrap();
+        relop
+            .insert_all_into("departments", rows![[10], [20]])
+            .unwrap();
+
+        catalog.save(&path).unwrap();
+        let loaded = Relop::new(Catalog::load(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        for query in ["select * from employees", "select * from departments"] {
+            assert_eq!(values(&relop, query), values(&loaded, query));
+        }
+        assert_eq!(3, values(&loaded, "select * from employees").len());
+    }
+
+    #[test]
+    fn insert_into_a_loaded_catalog() {
+        let path = std::env::temp_dir().join("relop_insert_into_a_loaded_catalog");
+        let catalog = Catalog::new();
+        let relop = Relop::new(catalog.clone());
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop.insert_all_into("employees", rows![[1], [2]]).unwrap();
+
+        catalog.save(&path).unwrap();
+        let loaded = Relop::new(Catalog::load(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let row_id = loaded.insert_into("employees", row![3]).unwrap();
+        assert_eq!(3, row_id);
+        assert_eq!(
+            vec![
+                vec![ColumnValue::int(1)],
+                vec![ColumnValue::int(2)],
+                vec![ColumnValue::int(3)]
+            ],
+            values(&loaded, "select * from employees")
+        );
+    }
+}
+
+#[cfg(test)]
+mod primary_key_tests {
+    use crate::catalog::error::InsertError;
+    use crate::catalog::Catalog;
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::schema::primary_key::PrimaryKey;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows, row, rows, schema};
+
+    fn employees_with_composite_primary_key() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema![
+                "department_id" => ColumnType::Int,
+                "id" => ColumnType::Int,
+                "name" => ColumnType::Text
+            ]
+            .unwrap()
+            .add_primary_key(PrimaryKey::composite(["department_id", "id"]))
+            .unwrap(),
+            vec![],
+        )])
+    }
+
+    #[test]
+    fn insert_rows_with_the_same_single_column_value_but_different_composite_keys() {
+        let relop = employees_with_composite_primary_key();
+        relop
+            .insert_all_into(
+                "employees",
+                rows![[1, 10, "relop"], [2, 10, "query"], [1, 20, "engine"]],
+            )
+            .unwrap();
+
+        let query_result = relop
+            .execute("select name from employees where id = 10 order by department_id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_a_duplicate_composite_key() {
+        let relop = employees_with_composite_primary_key();
+        relop
+            .insert_into("employees", row![1, 10, "relop"])
+            .unwrap();
+
+        let result = relop.insert_into("employees", row![1, 10, "query"]);
+        assert!(matches!(
+            result,
+            Err(ClientError::Insert(InsertError::DuplicatePrimaryKey(key))) if key.values() == [ColumnValue::int(1), ColumnValue::int(10)]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_a_duplicate_composite_key_via_sql() {
+        let relop = employees_with_composite_primary_key();
+        relop
+            .execute("insert into employees values (1, 10, 'relop')")
+            .unwrap();
+
+        let result = relop.execute("insert into employees values (1, 10, 'query')");
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::Insert(
+                InsertError::DuplicatePrimaryKey(_)
+            )))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_update_row_to_a_duplicate_composite_key() {
+        let relop = employees_with_composite_primary_key();
+        relop
+            .insert_all_into("employees", rows![[1, 10, "relop"], [1, 20, "query"]])
+            .unwrap();
+
+        let result = relop.execute("update employees set id = 20 where id = 10");
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::Update(
+                InsertError::DuplicatePrimaryKey(_)
+            )))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_a_null_primary_key_column() {
+        let relop = employees_with_composite_primary_key();
+
+        let result = relop.insert_into("employees", row![1, ColumnValue::null(), "relop"]);
+        assert!(matches!(
+            result,
+            Err(ClientError::Insert(InsertError::NullPrimaryKey(column))) if column == "id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_a_duplicate_key_into_a_table_created_via_sql() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .execute("create table employees (id int primary key, name text)")
+            .unwrap();
+        relop
+            .execute("insert into employees values (1, 'relop')")
+            .unwrap();
+
+        let result = relop.execute("insert into employees values (1, 'query')");
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::Insert(
+                InsertError::DuplicatePrimaryKey(key)
+            ))) if key.values() == [ColumnValue::int(1)]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_a_duplicate_composite_key_into_a_table_created_via_sql() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .execute(
+                "create table employees (department_id int, id int, name text, primary key (department_id, id))",
+            )
+            .unwrap();
+        relop
+            .execute("insert into employees values (1, 10, 'relop'), (2, 10, 'query')")
+            .unwrap();
+
+        let result = relop.execute("insert into employees values (1, 10, 'engine')");
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::Insert(
+                InsertError::DuplicatePrimaryKey(key)
+            ))) if key.values() == [ColumnValue::int(1), ColumnValue::int(10)]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_insert_row_with_a_null_key_into_a_table_created_via_sql() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .execute(
+                "create table employees (department_id int, id int, name text, primary key (department_id, id))",
+            )
+            .unwrap();
+
+        let result =
+            relop.execute("insert into employees (department_id, name) values (1, 'relop')");
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::Insert(
+                InsertError::NullPrimaryKey(column)
+            ))) if column == "id"
+        ));
+    }
+
+    #[test]
+    fn insert_row_with_a_duplicate_composite_key_into_a_loaded_catalog() {
+        let path = std::env::temp_dir().join("relop_primary_key_in_a_loaded_catalog");
+        let catalog = Catalog::new();
+        let relop = Relop::new(catalog.clone());
+        relop
+            .create_table(
+                "employees",
+                schema!["department_id" => ColumnType::Int, "id" => ColumnType::Int]
+                    .unwrap()
+                    .add_primary_key(PrimaryKey::composite(["department_id", "id"]))
+                    .unwrap(),
+            )
+            .unwrap();
+        relop.insert_into("employees", row![1, 10]).unwrap();
+
+        catalog.save(&path).unwrap();
+        let loaded = Relop::new(Catalog::load(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let result = loaded.insert_into("employees", row![1, 10]);
+        assert!(matches!(
+            result,
+            Err(ClientError::Insert(InsertError::DuplicatePrimaryKey(_)))
+        ));
+        assert!(loaded.insert_into("employees", row![2, 10]).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod unique_constraint_tests {
+    use crate::catalog::error::InsertError;
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::schema::unique_constraint::UniqueConstraint;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows, row, rows, schema};
+
+    fn employees_with_unique_email() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "email" => ColumnType::Text]
+                .unwrap()
+                .add_unique_constraint(UniqueConstraint::new("email"))
+                .unwrap(),
+            vec![],
+        )])
+    }
+
+    #[test]
+    fn insert_distinct_values_into_a_uniquely_constrained_column() {
+        let relop = employees_with_unique_email();
+        relop
+            .insert_all_into("employees", rows![[1, "a@relop"], [2, "b@relop"]])
+            .unwrap();
+
+        let query_result = relop
+            .execute("select email from employees order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "email" => "a@relop");
+        assert_next_row!(row_iterator.as_mut(), "email" => "b@relop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_insert_a_duplicate_value_into_a_uniquely_constrained_column() {
+        let relop = employees_with_unique_email();
+        relop.insert_into("employees", row![1, "a@relop"]).unwrap();
+
+        let result = relop.insert_into("employees", row![2, "a@relop"]);
+        assert!(matches!(
+            result,
+            Err(ClientError::Insert(InsertError::DuplicateUniqueValue { column, value }))
+                if column == "email" && value == ColumnValue::text("a@relop")
+        ));
+    }
+
+    #[test]
+    fn attempt_to_insert_a_duplicate_value_into_a_uniquely_constrained_column_via_sql() {
+        let relop = employees_with_unique_email();
+        relop
+            .execute("insert into employees values (1, 'a@relop')")
+            .unwrap();
+
+        let result = relop.execute("insert into employees values (2, 'a@relop')");
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::Insert(
+                InsertError::DuplicateUniqueValue { .. }
+            )))
+        ));
+    }
+
+    #[test]
+    fn insert_null_values_into_a_uniquely_constrained_column() {
+        let relop = employees_with_unique_email();
+
+        relop
+            .insert_into("employees", row![1, ColumnValue::null()])
+            .unwrap();
+        relop
+            .insert_into("employees", row![2, ColumnValue::null()])
+            .unwrap();
+    }
+
+    #[test]
+    fn attempt_to_update_a_uniquely_constrained_column_to_a_duplicate_value() {
+        let relop = employees_with_unique_email();
+        relop
+            .insert_all_into("employees", rows![[1, "a@relop"], [2, "b@relop"]])
+            .unwrap();
+
+        let result = relop.execute("update employees set email = 'a@relop' where id = 2");
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::Update(
+                InsertError::DuplicateUniqueValue { .. }
+            )))
+        ));
+    }
+
+    #[test]
+    fn insert_the_value_of_a_deleted_row_into_a_uniquely_constrained_column() {
+        let relop = employees_with_unique_email();
+        relop.insert_into("employees", row![1, "a@relop"]).unwrap();
+        relop.execute("delete from employees where id = 1").unwrap();
+
+        assert!(relop.insert_into("employees", row![2, "a@relop"]).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod index_tests {
+    use crate::catalog::error::CatalogError;
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::schema::primary_key::PrimaryKey;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{row, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema![
+                "id" => ColumnType::Int,
+                "department_id" => ColumnType::Int,
+                "name" => ColumnType::Text
+            ]
+            .unwrap(),
+            (1..=30)
+                .map(|id| row![id, id % 4, format!("employee-{}", id % 7)])
+                .collect(),
+        )])
+    }
+
+    fn values(relop: &Relop, query: &str) -> Vec<Vec<ColumnValue>> {
+        let query_result = relop.execute(query).unwrap();
+        let result_set = query_result.result_set().unwrap();
+        result_set
+            .iterator()
+            .unwrap()
+            .map(|row_view| row_view.unwrap().to_row().column_values().to_vec())
+            .collect()
+    }
+
+    const QUERIES: [&str; 6] = [
+        "select * from employees where department_id = 2",
+        "select id from employees where 3 = department_id",
+        "select id from employees where department_id = 1 and id > 10",
+        "select e.id from employees as e where e.department_id = 0 and e.name = 'employee-1'",
+        "select id from employees where department_id = 2 limit 3",
+        "select id from employees where department_id = 9",
+    ];
+
+    #[test]
+    fn index_lookups_match_a_full_scan() {
+        let scanned = employees();
+        let indexed = employees();
+        indexed.create_index("employees", "department_id").unwrap();
+        indexed.create_index("employees", "name").unwrap();
+
+        for query in QUERIES {
+            assert_eq!(
+                values(&scanned, query),
+                values(&indexed, query),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn index_lookups_match_a_full_scan_after_updates_and_deletes() {
+        let scanned = employees();
+        let indexed = employees();
+        indexed.create_index("employees", "department_id").unwrap();
+
+        for relop in [&scanned, &indexed] {
+            relop
+                .execute("update employees set department_id = 2 where id < 5")
+                .unwrap();
+            relop
+                .execute("delete from employees where department_id = 1 and id > 20")
+                .unwrap();
+            relop.insert_into("employees", row![31, 2, "new"]).unwrap();
+        }
+
+        for query in QUERIES {
+            assert_eq!(
+                values(&scanned, query),
+                values(&indexed, query),
+                "{}",
+                query
+            );
+        }
+    }
+
+    fn employees_with_primary_key() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema![
+                "id" => ColumnType::Int,
+                "department_id" => ColumnType::Int,
+                "name" => ColumnType::Text
+            ]
+            .unwrap()
+            .add_primary_key(PrimaryKey::single("id"))
+            .unwrap(),
+            (1..=30)
+                .map(|id| row![id, id % 4, format!("employee-{}", id % 7)])
+                .collect(),
+        )])
+    }
+
+    const PRIMARY_KEY_QUERIES: [&str; 6] = [
+        "select * from employees where id = 2",
+        "select name from employees where 7 = id",
+        "select id from employees where id = 5 and department_id = 1",
+        "select id from employees where id = 5 and department_id = 2",
+        "select e.name from employees as e where e.id = 12",
+        "select id from employees where id = 99",
+    ];
+
+    #[test]
+    fn primary_key_lookups_match_a_full_scan() {
+        let scanned = employees();
+        let indexed = employees_with_primary_key();
+
+        for query in PRIMARY_KEY_QUERIES {
+            assert_eq!(
+                values(&scanned, query),
+                values(&indexed, query),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn primary_key_lookups_match_a_full_scan_after_updates_and_deletes() {
+        let scanned = employees();
+        let indexed = employees_with_primary_key();
+
+        for relop in [&scanned, &indexed] {
+            relop
+                .execute("update employees set id = 99 where id = 3")
+                .unwrap();
+            relop.execute("delete from employees where id = 5").unwrap();
+            relop
+                .insert_into("employees", row![2000, 2, "new"])
+                .unwrap();
+        }
+
+        for query in PRIMARY_KEY_QUERIES
+            .iter()
+            .chain(&["select name from employees where id = 3"])
+        {
+            assert_eq!(
+                values(&scanned, query),
+                values(&indexed, query),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn attempt_to_create_index_on_non_existent_column() {
+        let relop = employees();
+        let result = relop.create_index("employees", "salary");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Catalog(CatalogError::ColumnDoesNotExist(column_name))) if column_name == "salary"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod auto_increment_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::schema::column::Column;
+    use crate::schema::primary_key::PrimaryKey;
+    use crate::schema::Schema;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows, row};
+
+    fn employees_with_auto_increment_id() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            Schema::new()
+                .add_column_definition(
+                    Column::new("id", ColumnType::Int)
+                        .not_null()
+                        .auto_increment(),
+                )
+                .unwrap()
+                .add_column("name", ColumnType::Text)
+                .unwrap()
+                .add_primary_key(PrimaryKey::single("id"))
+                .unwrap(),
+            vec![],
+        )])
+    }
+
+    #[test]
+    fn insert_rows_receiving_increasing_ids() {
+        let relop = employees_with_auto_increment_id();
+
+        let query_result = relop
+            .execute("insert into employees (name) values ('relop'), ('query'), ('plan')")
+            .unwrap();
+        assert_eq!(Some(3), query_result.rows_inserted());
+        assert_eq!(Some(&[1, 2, 3][..]), query_result.generated_ids());
+
+        let query_result = relop
+            .execute("insert into employees (name) values ('join')")
+            .unwrap();
+        assert_eq!(Some(&[4][..]), query_result.generated_ids());
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "id" => 3, "name" => "plan");
+        assert_next_row!(row_iterator.as_mut(), "id" => 4, "name" => "join");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn do_not_reuse_ids_after_deletes() {
+        let relop = employees_with_auto_increment_id();
+        relop
+            .execute("insert into employees (name) values ('relop'), ('query')")
+            .unwrap();
+        relop.execute("delete from employees").unwrap();
+
+        let query_result = relop
+            .execute("insert into employees (name) values ('plan')")
+            .unwrap();
+
+        assert_eq!(Some(&[3][..]), query_result.generated_ids());
+    }
+
+    #[test]
+    fn insert_row_with_an_explicit_id_moves_the_counter_past_it() {
+        let relop = employees_with_auto_increment_id();
+        relop.insert_into("employees", row![10, "relop"]).unwrap();
+
+        relop
+            .insert_into(
+                "employees",
+                Row::filled(vec![ColumnValue::null(), ColumnValue::text("query")]),
+            )
+            .unwrap();
+
+        let query_result = relop
+            .execute("select id from employees where name = 'query'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 11);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod date_tests {
+    use crate::catalog::Catalog;
+    use crate::client::error::ClientError;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::query::parser::error::ParseError;
+    use crate::{assert_next_row, assert_no_more_rows};
+
+    fn employees_with_joining_dates() -> Relop {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .execute("create table employees (id int, joined_on date)")
+            .unwrap();
+        relop
+            .execute(
+                "insert into employees values (1, '2024-03-10'), (2, '2023-11-02'), (3, '2024-01-15')",
+            )
+            .unwrap();
+        relop
+            .execute("insert into employees (id) values (4)")
+            .unwrap();
+        relop
+    }
+
+    #[test]
+    fn select_with_equality_on_a_date() {
+        let relop = employees_with_joining_dates();
+
+        let query_result = relop
+            .execute("select id from employees where joined_on = '2024-01-15'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn select_with_range_on_dates() {
+        let relop = employees_with_joining_dates();
+
+        let query_result = relop
+            .execute("select id from employees where joined_on >= date '2024-01-01' order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn select_with_between_dates() {
+        let relop = employees_with_joining_dates();
+
+        let query_result = relop
+            .execute(
+                "select id from employees where joined_on between '2023-12-01' and '2024-02-29'",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn select_ordered_by_date() {
+        let relop = employees_with_joining_dates();
+
+        let query_result = relop
+            .execute("select id from employees order by joined_on desc nulls last")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 4);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_insert_an_invalid_date() {
+        let relop = employees_with_joining_dates();
+
+        let result = relop.execute("insert into employees values (5, '2024-02-30')");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::InvalidDate(text))) if text == "2024-02-30"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_select_with_an_invalid_date_literal() {
+        let relop = employees_with_joining_dates();
+
+        let result = relop.execute("select id from employees where joined_on = date '2024-13-01'");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Parse { error: ParseError::InvalidDate(text), .. }) if text == "2024-13-01"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod values_tests {
+    use crate::catalog::Catalog;
+    use crate::client::error::ClientError;
+    use crate::client::Relop;
+    use crate::query::plan::error::PlanningError;
+    use crate::{assert_next_row, assert_no_more_rows};
+
+    #[test]
+    fn execute_values() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop.execute("values (1, 'relop'), (2, 'query')").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "column1" => 1, "column2" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "column1" => 2, "column2" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_values_with_a_single_row() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop.execute("values (true);").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "column1" => true);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_values_with_different_row_lengths() {
+        let relop = Relop::new(Catalog::new());
+
+        let result = relop.execute("values (1, 'relop'), (2)");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(
+                PlanningError::ValuesColumnCountMismatch {
+                    expected: 2,
+                    actual: 1
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_values_with_different_column_types() {
+        let relop = Relop::new(Catalog::new());
+
+        let result = relop.execute("values (1), ('relop')");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::ValuesColumnTypeMismatch {
+                position: 1,
+                expected: crate::types::column_type::ColumnType::Int,
+                actual: crate::types::column_type::ColumnType::Text
+            }))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod execute_all_tests {
+    use crate::catalog::error::CatalogError;
+    use crate::catalog::Catalog;
+    use crate::client::error::ClientError;
+    use crate::client::Relop;
+    use crate::query::parser::error::ParseError;
+    use crate::query::plan::error::PlanningError;
+    use crate::{assert_next_row, assert_no_more_rows};
+
+    #[test]
+    fn execute_all_statements_of_a_script() {
+        let relop = Relop::new(Catalog::new());
+
+        let results = relop
+            .execute_all(
+                "create table employees (id int, name text);
+                 insert into employees values (1, 'relop'), (2, 'query');
+                 delete from employees where id = 2;
+                 select * from employees",
+            )
+            .unwrap();
+
+        assert_eq!(4, results.len());
+        assert_eq!(Some("employees"), results[0].created_table());
+        assert_eq!(Some(2), results[1].rows_inserted());
+        assert_eq!(Some(1), results[2].rows_affected());
+
+        let result_set = results[3].result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_all_with_semicolons_in_strings_and_empty_statements() {
+        let relop = Relop::new(Catalog::new());
+
+        let results = relop
+            .execute_all(
+                "create table employees (id int, name text);;
+                 insert into employees values (1, 'a;b');",
+            )
+            .unwrap();
+        assert_eq!(2, results.len());
+
+        let query_result = relop.execute("select name from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "name" => "a;b");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_all_with_an_empty_script() {
+        let relop = Relop::new(Catalog::new());
+
+        assert!(relop.execute_all("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn attempt_to_execute_all_with_a_failing_statement() {
+        let relop = Relop::new(Catalog::new());
+
+        let result = relop.execute_all(
+            "create table employees (id int);
+             insert into employees values (1);
+             select * from departments;
+             insert into employees values (2);",
+        );
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Statement { index: 2, error })
+                if matches!(*error, ClientError::Plan(PlanningError::Catalog(CatalogError::TableDoesNotExist(ref table_name))) if table_name == "departments")
+        ));
+        let query_result = relop.execute("select * from employees").unwrap();
+        assert_eq!(1, query_result.result_set().unwrap().count().unwrap());
+    }
+
+    #[test]
+    fn attempt_to_execute_all_with_an_unparsable_statement() {
+        let relop = Relop::new(Catalog::new());
+
+        let result = relop.execute_all("show tables;\nselect * form employees;");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Statement { index: 1, error })
+                if matches!(*error, ClientError::Parse { error: ParseError::UnexpectedToken { .. }, line: 2, .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod parameter_tests {
+    use crate::client::error::ClientError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows};
+    use crate::{rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            rows![[1, "relop"], [2, "query"], [3, "plan"]],
+        )])
+    }
+
+    #[test]
+    fn select_with_an_int_parameter() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute_with_params(
+                "select name from employees where id = ?",
+                vec![ColumnValue::int(2)],
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn select_with_a_text_parameter() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute_with_params(
+                "select id from employees where name = ?",
+                vec![ColumnValue::text("plan")],
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn select_with_a_parameter_in_a_subquery() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute_with_params(
+                "select id from employees where id > ? and id in (select id from employees where name != ?) order by id",
+                vec![ColumnValue::int(1), ColumnValue::text("query")],
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn insert_and_update_with_parameters() {
+        let relop = employees();
+
+        relop
+            .execute_with_params(
+                "insert into employees values (?, ?)",
+                vec![ColumnValue::int(4), ColumnValue::text("index")],
+            )
+            .unwrap();
+        relop
+            .execute_with_params(
+                "update employees set name = ? where id = ?",
+                vec![ColumnValue::text("scan"), ColumnValue::int(4)],
+            )
+            .unwrap();
+
+        let query_result = relop
+            .execute("select name from employees where id = 4")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "scan");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_with_fewer_parameters_than_placeholders() {
+        let relop = employees();
+
+        let result = relop.execute_with_params(
+            "select * from employees where id = ? and name = ?",
+            vec![ColumnValue::int(1)],
+        );
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::ParameterCountMismatch {
+                expected: 2,
+                actual: 1
+            }))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_a_query_with_placeholders_without_parameters() {
+        let relop = employees();
+
+        let result = relop.execute("select * from employees where id = ?");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::ParameterCountMismatch {
+                expected: 1,
+                actual: 0
+            }))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod rows_affected_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::schema;
+    use crate::types::column_type::ColumnType;
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            vec![],
+        )])
+    }
+
+    #[test]
+    fn rows_affected_by_a_multi_row_insert() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("insert into employees values (1, 'relop'), (2, 'query'), (3, 'plan')")
+            .unwrap();
+
+        assert_eq!(Some(3), query_result.rows_affected());
+    }
+
+    #[test]
+    fn rows_affected_by_a_filtered_delete() {
+        let relop = employees();
+        relop
+            .execute("insert into employees values (1, 'relop'), (2, 'query'), (3, 'plan')")
+            .unwrap();
+
+        let query_result = relop
+            .execute("delete from employees where id >= 2")
+            .unwrap();
+
+        assert_eq!(Some(2), query_result.rows_affected());
+    }
+
+    #[test]
+    fn rows_affected_by_a_filtered_update() {
+        let relop = employees();
+        relop
+            .execute("insert into employees values (1, 'relop'), (2, 'query')")
+            .unwrap();
+
+        let query_result = relop
+            .execute("update employees set name = 'scan' where id = 1")
+            .unwrap();
+
+        assert_eq!(Some(1), query_result.rows_affected());
+    }
+
+    #[test]
+    fn no_rows_affected_by_a_select() {
+        let relop = employees();
+
+        let query_result = relop.execute("select * from employees").unwrap();
+
+        assert!(query_result.rows_affected().is_none());
+        assert!(query_result.result_set().is_some());
+    }
+}
+
+#[cfg(test)]
+mod to_row_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::rows;
+    use crate::schema;
+    use crate::types::column_type::ColumnType;
+
+    fn employees_and_departments() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "department_id" => ColumnType::Int].unwrap(),
+                rows![[1, "relop", 10], [2, "query", 20]],
+            ),
+            (
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[10, "storage"], [20, "planning"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn to_row_of_a_projected_result() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select name, id from employees order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let rows = result_set
+            .iterator()
+            .unwrap()
+            .map(|row_view| row_view.unwrap().to_row())
+            .collect::<Vec<_>>();
+
+        assert_eq!(rows![["relop", 1], ["query", 2]], rows);
+    }
+
+    #[test]
+    fn to_row_matches_the_values_by_column_name() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select employees.name, departments.name from employees join departments on employees.department_id = departments.id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        for row_view in result_set.iterator().unwrap() {
+            let row_view = row_view.unwrap();
+            let row = row_view.to_row();
+
+            assert_eq!(2, row.column_values().len());
+            assert_eq!(
+                row_view.column_value_by("employees.name").unwrap(),
+                row.column_value_at(0)
+            );
+            assert_eq!(
+                row_view.column_value_by("departments.name").unwrap(),
+                row.column_value_at(1)
+            );
+        }
+    }
+
+    #[test]
+    fn to_row_of_a_joined_result() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select * from employees join departments on employees.department_id = departments.id order by employees.id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let rows = result_set
+            .iterator()
+            .unwrap()
+            .map(|row_view| row_view.unwrap().to_row())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows![
+                [1, "relop", 10, 10, "storage"],
+                [2, "query", 20, 20, "planning"]
+            ],
+            rows
+        );
+    }
+}
+
+#[cfg(test)]
+mod positional_access_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_value::ColumnValue;
+
+    #[test]
+    fn column_values_by_index_of_a_projected_result() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .execute("create table employees (id int, name text, city text)")
+            .unwrap();
+        relop
+            .execute("insert into employees values (1, 'relop', 'pune')")
+            .unwrap();
+
+        let query_result = relop.execute("select city, id from employees").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        assert_eq!(2, row_view.len());
+        assert_eq!(
+            &ColumnValue::text("pune"),
+            row_view.column_value_at(0).unwrap()
+        );
+        assert_eq!(&ColumnValue::int(1), row_view.column_value_at(1).unwrap());
+        assert!(matches!(
+            row_view.column_value_at(2),
+            Err(ExecutionError::ColumnIndexOutOfBounds(2))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod implicit_alias_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
+    use crate::{assert_next_row, assert_no_more_rows};
+
+    #[test]
+    fn join_with_implicit_table_aliases() {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .execute("create table employees (id int, department_id int)")
+            .unwrap();
+        relop
+            .execute("create table departments (id int, name text)")
+            .unwrap();
+        relop
+            .execute("insert into employees values (1, 10), (2, 20)")
+            .unwrap();
+        relop
+            .execute("insert into departments values (10, 'storage'), (20, 'planning')")
+            .unwrap();
+
+        let query_result = relop
+            .execute("select e.id, d.name from employees e join departments d on e.department_id = d.id where d.name = 'planning'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "e.id" => 2, "d.name" => "planning");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod insert_stream_tests {
+    use super::*;
+    use crate::catalog::error::InsertError;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::schema::error::SchemaError;
+    use crate::types::column_type::ColumnType;
+    use crate::{row, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            vec![],
+        )])
+    }
+
+    #[test]
+    fn insert_stream_of_rows() {
+        let relop = employees();
+
+        let count = relop
+            .insert_stream(
+                "employees",
+                (1..=100).map(|id| row![id, format!("employee-{}", id)]),
+            )
+            .unwrap();
+        assert_eq!(100, count);
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        assert_eq!(100, query_result.result_set().unwrap().count().unwrap());
+    }
+
+    #[test]
+    fn attempt_to_insert_stream_with_an_early_type_error() {
+        let relop = employees();
+        let mut pulled = 0;
+        let rows = rows![[1, "relop"], ["two", "query"], [3, "operator"]]
+            .into_iter()
+            .inspect(|_| pulled += 1);
+
+        let result = relop.insert_stream("employees", rows);
+        assert!(matches!(
+            result,
+            Err(ClientError::Insert(InsertError::Row { index: 1, ref error }))
+                if matches!(error.as_ref(), InsertError::Schema(SchemaError::ColumnTypeMismatch { column, .. }) if column == "id")
+        ));
+        assert_eq!(2, pulled);
+
+        let query_result = relop.execute("select * from employees").unwrap();
+        assert_eq!(1, query_result.result_set().unwrap().count().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod order_by_column_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees_and_departments() -> Relop {
+        relop_with_tables(vec![
+            (
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+                rows![[1, "relop"], [2, "query"]],
+            ),
+            (
+                "departments",
+                schema!["id" => ColumnType::Int, "title" => ColumnType::Text].unwrap(),
+                rows![[1, "Engineering"], [2, "Design"]],
+            ),
+        ])
+    }
+
+    #[test]
+    fn execute_select_with_order_by_a_column_not_projected() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select name from employees order by id desc")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "query");
+        assert_next_row!(row_iterator.as_mut(), "name" => "relop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_order_by_an_unknown_column() {
+        let relop = employees_and_departments();
+
+        let result = relop.execute("select * from employees order by salary");
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::ColumnNotFound(column_name))) if column_name == "salary"
+        ));
+    }
+
+    #[test]
+    fn execute_select_with_order_by_a_projected_column_ambiguous_in_join() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute(
+                "select employees.id from employees join departments on employees.id = departments.id order by id desc",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "employees.id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_order_by_a_qualified_projection_column_ambiguous_in_join() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute(
+                "select departments.* from employees join departments on employees.id = departments.id order by id desc",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "departments.id" => 2, "title" => "Design");
+        assert_next_row!(row_iterator.as_mut(), "departments.id" => 1, "title" => "Engineering");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_order_by_an_ambiguous_column_in_join() {
+        let relop = employees_and_departments();
+
+        let result = relop.execute(
+            "select * from employees join departments on employees.id = departments.id order by id",
+        );
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::AmbiguousColumn(column_name))) if column_name == "id"
+        ));
+    }
+
+    #[test]
+    fn execute_select_with_order_by_a_qualified_column_in_join() {
+        let relop = employees_and_departments();
+
+        let query_result = relop
+            .execute("select e.name, d.title from employees e join departments d on e.id = d.id order by d.title")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "e.name" => "query", "d.title" => "Design");
+        assert_next_row!(row_iterator.as_mut(), "e.name" => "relop", "d.title" => "Engineering");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod distinct_on_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "city" => ColumnType::Text, "name" => ColumnType::Text, "salary" => ColumnType::Int]
+                .unwrap(),
+            rows![
+                [1, "Pune", "Neha", 300],
+                [2, "Delhi", "Rahul", 200],
+                [3, "Pune", "Zoya", 500],
+                [4, "Delhi", "Amit", 200],
+                [5, "Mumbai", "Ravi", 100]
+            ],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_distinct_on_a_single_column() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select distinct on (city) city, name from employees order by city, name")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "city" => "Delhi", "name" => "Amit");
+        assert_next_row!(row_iterator.as_mut(), "city" => "Mumbai", "name" => "Ravi");
+        assert_next_row!(row_iterator.as_mut(), "city" => "Pune", "name" => "Neha");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_distinct_on_keeping_the_highest_salary_of_each_city() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute(
+                "select distinct on (city) city, name, salary from employees order by city, salary desc",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "city" => "Delhi", "name" => "Rahul", "salary" => 200);
+        assert_next_row!(row_iterator.as_mut(), "city" => "Mumbai", "name" => "Ravi", "salary" => 100);
+        assert_next_row!(row_iterator.as_mut(), "city" => "Pune", "name" => "Zoya", "salary" => 500);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_distinct_on_multiple_columns() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select distinct on (city, salary) city, salary, name from employees order by city, salary, name")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "city" => "Delhi", "salary" => 200, "name" => "Amit");
+        assert_next_row!(row_iterator.as_mut(), "city" => "Mumbai", "salary" => 100, "name" => "Ravi");
+        assert_next_row!(row_iterator.as_mut(), "city" => "Pune", "salary" => 300, "name" => "Neha");
+        assert_next_row!(row_iterator.as_mut(), "city" => "Pune", "salary" => 500, "name" => "Zoya");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_distinct_on_a_column_not_projected() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select distinct on (city) name from employees order by city, id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "Rahul");
+        assert_next_row!(row_iterator.as_mut(), "name" => "Ravi");
+        assert_next_row!(row_iterator.as_mut(), "name" => "Neha");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_distinct_on_and_limit() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute(
+                "select distinct on (city) city, name from employees order by city, name limit 2",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "city" => "Delhi", "name" => "Amit");
+        assert_next_row!(row_iterator.as_mut(), "city" => "Mumbai", "name" => "Ravi");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_distinct_on_without_order_by() {
+        let relop = employees();
+
+        let result = relop.execute("select distinct on (city) city, name from employees");
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::DistinctOnNotMatchingOrderBy(columns))) if columns == vec!["city".to_string()]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_distinct_on_not_leading_the_order_by() {
+        let relop = employees();
+
+        let result = relop
+            .execute("select distinct on (city) city, name from employees order by name, city");
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::DistinctOnNotMatchingOrderBy(columns))) if columns == vec!["city".to_string()]
+        ));
+    }
+}
+
+#[cfg(test)]
+mod identifier_case_tests {
+    use super::*;
+    use crate::catalog::error::CatalogError;
+    use crate::query::plan::error::PlanningError;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees(relop: Relop) -> Relop {
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .insert_all_into("employees", rows![[1, "relop"], [2, "query"]])
+            .unwrap();
+        relop
+    }
+
+    #[test]
+    fn execute_select_with_columns_in_any_case_by_default() {
+        let relop = employees(Relop::new(Catalog::new()));
+
+        let query_result = relop
+            .execute("SELECT Name FROM employees WHERE ID = 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "relop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_a_table_in_another_case_by_default() {
+        let relop = employees(Relop::new(Catalog::new()));
+
+        let result = relop.execute("SELECT Name FROM Employees");
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::Catalog(CatalogError::TableDoesNotExist(table_name)))) if table_name == "Employees"
+        ));
+    }
+
+    #[test]
+    fn execute_select_with_case_insensitive_identifiers() {
+        let relop = employees(Relop::new(Catalog::new()).with_case_insensitive_identifiers());
+
+        let query_result = relop
+            .execute("SELECT E.Name FROM Employees E WHERE E.Id = 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "e.name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_resolving_identifiers_consistently_in_any_case() {
+        let relop = employees(Relop::new(Catalog::new()).with_case_insensitive_identifiers());
+
+        for query in [
+            "select name from employees order by id",
+            "SELECT Name FROM Employees ORDER BY Id",
+            "SELECT NAME FROM EMPLOYEES ORDER BY ID",
+        ] {
+            let query_result = relop.execute(query).unwrap();
+            let result_set = query_result.result_set().unwrap();
+            let mut row_iterator = result_set.iterator().unwrap();
+
+            assert_next_row!(row_iterator.as_mut(), "name" => "relop");
+            assert_next_row!(row_iterator.as_mut(), "name" => "query");
+            assert_no_more_rows!(row_iterator.as_mut());
+        }
+    }
+
+    #[test]
+    fn fold_column_aliases_with_case_insensitive_identifiers() {
+        let relop = employees(Relop::new(Catalog::new()).with_case_insensitive_identifiers());
+
+        let query_result = relop
+            .execute("SELECT Name AS EmployeeName FROM Employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(vec!["employeename".to_string()], result_set.column_names());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_a_quoted_table_in_another_case() {
+        let relop = employees(Relop::new(Catalog::new()).with_case_insensitive_identifiers());
+
+        let result = relop.execute("SELECT Name FROM \"Employees\"");
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::Catalog(CatalogError::TableDoesNotExist(table_name)))) if table_name == "Employees"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod execution_limits_tests {
+    use super::*;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_type::ColumnType;
+    use crate::{rows, schema};
+    use std::time::Duration;
+
+    fn employees(relop: Relop) -> Relop {
+        relop
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        relop
+            .insert_all_into("employees", rows![[1], [2], [3], [4]])
+            .unwrap();
+        relop
+    }
+
+    #[test]
+    fn execute_select_within_the_limits() {
+        let relop = employees(Relop::new(Catalog::new()));
+
+        let query_result = relop
+            .execute_with_limits(
+                "select * from employees",
+                ExecutionLimits::new().with_max_rows_scanned(4),
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(4, result_set.count().unwrap());
+    }
+
+    #[test]
+    fn attempt_to_execute_a_cross_join_beyond_the_maximum_rows_scanned() {
+        let relop = employees(Relop::new(Catalog::new()));
+
+        let query_result = relop
+            .execute_with_limits(
+                "select * from employees e1 cross join employees e2",
+                ExecutionLimits::new().with_max_rows_scanned(5),
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert!(matches!(
+            result_set.count(),
+            Err(ExecutionError::Budget(ExceededLimit::MaxRowsScanned(5)))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_a_cross_join_beyond_the_timeout() {
+        let relop = employees(Relop::new(Catalog::new()));
+
+        let query_result = relop
+            .execute_with_limits(
+                "select * from employees e1 cross join employees e2",
+                ExecutionLimits::new().with_timeout(Duration::ZERO),
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert!(matches!(
+            result_set.count(),
+            Err(ExecutionError::Budget(ExceededLimit::Timeout(
+                Duration::ZERO
+            )))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_a_cross_join_beyond_the_limits_of_relop() {
+        let relop = employees(
+            Relop::new(Catalog::new())
+                .with_execution_limits(ExecutionLimits::new().with_max_rows_scanned(5)),
+        );
+
+        let query_result = relop
+            .execute("select * from employees e1 cross join employees e2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert!(matches!(
+            result_set.count(),
+            Err(ExecutionError::Budget(ExceededLimit::MaxRowsScanned(5)))
+        ));
+    }
+
+    #[test]
+    fn execute_with_limits_overriding_the_limits_of_relop() {
+        let relop = employees(
+            Relop::new(Catalog::new())
+                .with_execution_limits(ExecutionLimits::new().with_max_rows_scanned(1)),
+        );
+
+        let query_result = relop
+            .execute_with_limits(
+                "select * from employees e1 cross join employees e2",
+                ExecutionLimits::new(),
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(16, result_set.count().unwrap());
+    }
+
+    #[test]
+    fn iterate_the_result_repeatedly_within_the_limits() {
+        let relop = employees(Relop::new(Catalog::new()));
+
+        let query_result = relop
+            .execute_with_limits(
+                "select * from employees where id > 2",
+                ExecutionLimits::new().with_max_rows_scanned(4),
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert_eq!(2, result_set.count().unwrap());
+        assert_eq!(2, result_set.count().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod type_mismatch_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::executor::error::ExecutionError;
+    use crate::types::column_type::ColumnType;
+    use crate::{rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            rows![[1, "relop"], [2, "query"]],
+        )])
+    }
+
+    fn assert_type_mismatch(relop: &Relop, query: &str) {
+        let query_result = relop.execute(query).unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert!(
+            matches!(
+                result_set.count(),
+                Err(ExecutionError::TypeMismatchInComparison)
+            ),
+            "expected a type mismatch for {}",
+            query
+        );
+    }
+
+    #[test]
+    fn attempt_to_compare_an_int_column_with_text() {
+        assert_type_mismatch(&employees(), "select * from employees where id = 'abc'");
+    }
+
+    #[test]
+    fn attempt_to_compare_text_with_an_int_column() {
+        assert_type_mismatch(&employees(), "select * from employees where 'abc' = id");
+    }
+
+    #[test]
+    fn attempt_to_compare_a_text_column_with_an_int() {
+        assert_type_mismatch(&employees(), "select * from employees where name > 1");
+    }
+
+    #[test]
+    fn attempt_to_compare_an_int_with_a_text_column() {
+        assert_type_mismatch(&employees(), "select * from employees where 1 <= name");
+    }
+
+    #[test]
+    fn attempt_to_compare_an_int_column_with_text_in_a_disjunction() {
+        assert_type_mismatch(
+            &employees(),
+            "select * from employees where id = 'abc' or id = 1",
+        );
+    }
+
+    #[test]
+    fn attempt_to_compare_an_indexed_int_column_with_text() {
+        let relop = employees();
+        relop.create_index("employees", "id").unwrap();
+
+        assert_type_mismatch(&relop, "select * from employees where id = 'abc'");
+    }
+
+    #[test]
+    fn attempt_to_compare_an_int_column_with_text_in_a_parallel_scan() {
+        let relop = employees().with_scan_parallelism(2);
+
+        assert_type_mismatch(&relop, "select * from employees where id = 'abc'");
+    }
+
+    #[test]
+    fn attempt_to_compare_an_int_column_with_text_in_an_unoptimized_filter() {
+        let relop = employees();
+        let query_result = relop
+            .execute_unoptimized("select * from employees where id = 'abc'")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+
+        assert!(matches!(
+            result_set.count(),
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod column_value_conversion_tests {
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::client::Relop;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::types::error::ConversionError;
+    use crate::{row, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            vec![
+                row![1, "relop"],
+                Row::filled(vec![ColumnValue::int(2), ColumnValue::null()]),
+            ],
+        )])
+    }
+
+    #[test]
+    fn read_values_of_a_select_with_try_into() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select id, name from employees where id = 1")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        let id: i64 = row_view
+            .column_value_by("id")
+            .unwrap()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let name: String = row_view
+            .column_value_by("name")
+            .unwrap()
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(1, id);
+        assert_eq!("relop", name);
+    }
+
+    #[test]
+    fn attempt_to_read_a_null_value_of_a_select_with_try_into() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select name from employees where id = 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let row_view = result_set.iterator().unwrap().next().unwrap().unwrap();
+
+        let name: Result<String, _> = row_view
+            .column_value_by("name")
+            .unwrap()
+            .unwrap()
+            .try_into();
+        assert_eq!(
+            Err(ConversionError::NullValue {
+                expected: ColumnType::Text
+            }),
+            name
+        );
+    }
+}
+
+#[cfg(test)]
+mod quoted_table_name_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
+    use crate::{assert_next_row, assert_no_more_rows, rows};
+
+    fn orders_and_select() -> Relop {
+        let relop = Relop::new(Catalog::new());
+        relop
+            .execute("create table \"order\" (id int, \"select\" text)")
+            .unwrap();
+        relop
+            .execute("create table \"select\" (id int, \"from\" int)")
+            .unwrap();
+        relop
+            .execute("insert into \"order\" values (1, 'relop'), (2, 'query')")
+            .unwrap();
+        relop
+            .execute("insert into \"select\" values (10, 2)")
+            .unwrap();
+        relop
+    }
+
+    #[test]
+    fn select_from_a_quoted_keyword_table_name() {
+        let relop = orders_and_select();
+
+        let query_result = relop
+            .execute("select \"order\".\"select\" from \"order\" where \"order\".id = 1")
+            .unwrap();
+        let rows = query_result.result_set().unwrap().collect_rows().unwrap();
+
+        assert_eq!(rows![["relop"]], rows);
+    }
+
+    #[test]
+    fn join_quoted_keyword_table_names() {
+        let relop = orders_and_select();
+
+        let query_result = relop
+            .execute(
+                "select * from \"order\" join \"select\" on \"order\".id = \"select\".\"from\"",
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "order.id" => 2, "select.id" => 10);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn describe_a_quoted_keyword_table_name() {
+        let relop = orders_and_select();
+
+        let query_result = relop.execute("describe table \"select\"").unwrap();
+
+        assert_eq!("select", query_result.table_descriptor().unwrap().name());
+    }
+}
+
+#[cfg(test)]
+mod table_names_tests {
+    use crate::catalog::Catalog;
+    use crate::client::Relop;
+    use crate::schema;
+    use crate::types::column_type::ColumnType;
+
+    fn relop_with_tables(table_names: &[&str]) -> Relop {
+        let relop = Relop::new(Catalog::new());
+        for table_name in table_names {
+            relop
+                .create_table(*table_name, schema!["id" => ColumnType::Int].unwrap())
+                .unwrap();
+        }
+        relop
+    }
+
+    #[test]
+    fn table_exists() {
+        let relop = relop_with_tables(&["employees"]);
+
+        assert!(relop.table_exists("employees"));
+    }
+
+    #[test]
+    fn table_does_not_exist() {
+        let relop = relop_with_tables(&["employees"]);
+
+        assert!(!relop.table_exists("departments"));
+        assert!(!relop.table_exists("Employees"));
+    }
+
+    #[test]
+    fn table_does_not_exist_after_drop() {
+        let relop = relop_with_tables(&["employees"]);
+        relop.drop_table("employees").unwrap();
+
+        assert!(!relop.table_exists("employees"));
+    }
+
+    #[test]
+    fn table_names_are_sorted() {
+        let relop = relop_with_tables(&["projects", "employees", "roles", "departments"]);
+
+        assert_eq!(
+            vec!["departments", "employees", "projects", "roles"],
+            relop.table_names()
+        );
+        assert_eq!(relop.table_names(), relop.table_names());
+    }
+
+    #[test]
+    fn table_names_of_an_empty_catalog() {
+        let relop = relop_with_tables(&[]);
+
+        assert!(relop.table_names().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod aggregate_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::query::plan::error::PlanningError;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "city" => ColumnType::Text].unwrap(),
+            rows![
+                [1, "Pune"],
+                [2, "Delhi"],
+                [3, "Pune"],
+                [4, "Delhi"],
+                [5, "Mumbai"]
+            ],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_count_and_count_distinct() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select count(city), count(distinct city) from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "count(city)" => 5, "count(distinct city)" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_count_distinct_of_filtered_rows() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select count(*) as total, count(distinct city) as cities from employees where id > 2")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "total" => 3, "cities" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_count_distinct_skipping_nulls() {
+        let relop = employees();
+        relop
+            .insert_into(
+                "employees",
+                Row::filled(vec![ColumnValue::int(6), ColumnValue::null()]),
+            )
+            .unwrap();
+
+        let query_result = relop
+            .execute("select count(*), count(city), count(distinct city) from employees")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "count(*)" => 6, "count(city)" => 5, "count(distinct city)" => 3);
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_count_distinct_of_an_unknown_column() {
+        let relop = employees();
+
+        let result = relop.execute("select count(distinct unknown) from employees");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Plan(PlanningError::ColumnNotFound(column))) if column == "unknown"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod distinct_from_tests {
+    use super::*;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::row;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+    use crate::{assert_next_row, assert_no_more_rows, schema};
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "manager_id" => ColumnType::Int, "mentor_id" => ColumnType::Int]
+                .unwrap(),
+            vec![
+                Row::filled(vec![ColumnValue::int(1), ColumnValue::null(), ColumnValue::null()]),
+                Row::filled(vec![ColumnValue::int(2), ColumnValue::int(1), ColumnValue::null()]),
+                row![3, 1, 1],
+                row![4, 1, 2],
+            ],
+        )])
+    }
+
+    #[test]
+    fn execute_select_with_is_not_distinct_from() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select id from employees where manager_id is not distinct from mentor_id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_is_distinct_from() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select id from employees where manager_id is distinct from mentor_id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 4);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_equality_not_matching_nulls() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute("select id from employees where manager_id = mentor_id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_is_not_distinct_from_a_null_parameter() {
+        let relop = employees();
+
+        let query_result = relop
+            .execute_with_params(
+                "select id from employees where mentor_id is not distinct from ?",
+                vec![ColumnValue::null()],
+            )
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod default_ordering_direction_tests {
+    use super::*;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+
+    fn employees(relop: Relop) -> Relop {
+        relop
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        relop
+            .insert_all_into("employees", rows![[2, "Rahul"], [1, "Neha"], [3, "Amit"]])
+            .unwrap();
+        relop
+    }
+
+    #[test]
+    fn execute_select_with_order_by_in_ascending_order_by_default() {
+        let relop = employees(Relop::new(Catalog::new()));
+
+        let query_result = relop
+            .execute("select id from employees order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_order_by_in_the_descending_default_ordering() {
+        let relop = employees(Relop::new(Catalog::new()).with_descending_default_ordering());
+
+        let query_result = relop
+            .execute("select id from employees order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_with_explicit_asc_overriding_the_descending_default_ordering() {
+        let relop = employees(Relop::new(Catalog::new()).with_descending_default_ordering());
+
+        let query_result = relop
+            .execute("select id from employees order by id asc")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_next_row!(row_iterator.as_mut(), "id" => 3);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod select_without_from_tests {
+    use super::*;
+    use crate::query::executor::error::ExecutionError;
+    use crate::{assert_next_row, assert_no_more_rows};
+
+    #[test]
+    fn execute_select_of_a_constant_expression_without_from() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop.execute("select 1 + 1 as two").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "two" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_select_of_a_scalar_function_without_from() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop
+            .execute("select upper('hi') as greeting, length('relop') as size")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "greeting" => "HI", "size" => 5);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_union_of_selects_without_from() {
+        let relop = Relop::new(Catalog::new());
+
+        let query_result = relop
+            .execute("select 2 as id union select 1 as id order by id")
+            .unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_next_row!(row_iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_of_a_column_without_from() {
+        let relop = Relop::new(Catalog::new());
+
+        let result = relop.execute("select id");
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Execution(ExecutionError::UnknownColumn(column))) if column == "id"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod max_token_length_tests {
+    use super::*;
+    use crate::query::lexer::error::LexError;
+    use crate::{assert_next_row, assert_no_more_rows};
+
+    #[test]
+    fn execute_select_with_a_string_literal_of_the_max_token_length() {
+        let relop = Relop::new(Catalog::new()).with_max_token_length(6);
+
+        let query_result = relop.execute("select 'abcdef' as name").unwrap();
+        let result_set = query_result.result_set().unwrap();
+        let mut row_iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(row_iterator.as_mut(), "name" => "abcdef");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_select_with_a_string_literal_longer_than_the_max_token_length() {
+        let relop = Relop::new(Catalog::new()).with_max_token_length(6);
+
+        let result = relop.execute("select 'abcdefg' as name");
+        assert!(matches!(
+            result,
+            Err(ClientError::Lex(LexError::TokenTooLong {
+                max_length: 6,
+                line: 1,
+                column: 8
+            }))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod typed_execution_tests {
+    use super::*;
+    use crate::client::error::FromRowError;
+    use crate::client::from_row::column;
+    use crate::client::test_fixtures::relop_with_tables;
+    use crate::storage::row_view::RowView;
+    use crate::types::column_type::ColumnType;
+    use crate::types::error::ConversionError;
+    use crate::{row, schema};
+
+    #[derive(Debug, PartialEq)]
+    struct Employee {
+        id: i64,
+        name: String,
+        city: Option<String>,
+    }
+
+    impl FromRow for Employee {
+        fn from_row(row_view: &RowView) -> Result<Self, FromRowError> {
+            Ok(Employee {
+                id: column(row_view, "id")?,
+                name: column(row_view, "name")?,
+                city: column(row_view, "city")?,
+            })
+        }
+    }
+
+    fn employees() -> Relop {
+        relop_with_tables(vec![(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text]
+                .unwrap(),
+            vec![
+                row![1, "relop", "Pune"],
+                Row::filled(vec![
+                    ColumnValue::int(2),
+                    ColumnValue::text("query"),
+                    ColumnValue::null(),
+                ]),
+            ],
+        )])
+    }
+
+    #[test]
+    fn execute_typed_into_tuples() {
+        let relop = employees();
+
+        let employees = relop
+            .execute_typed::<(i64, String)>("select id, name from employees order by id")
+            .unwrap();
+
+        assert_eq!(
+            vec![(1, "relop".to_string()), (2, "query".to_string())],
+            employees
+        );
+    }
+
+    #[test]
+    fn execute_typed_into_a_struct() {
+        let relop = employees();
+
+        let employees = relop
+            .execute_typed::<Employee>("select city, name, id from employees order by id")
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                Employee {
+                    id: 1,
+                    name: "relop".to_string(),
+                    city: Some("Pune".to_string()),
+                },
+                Employee {
+                    id: 2,
+                    name: "query".to_string(),
+                    city: None,
+                },
+            ],
+            employees
+        );
+    }
+
+    #[test]
+    fn execute_typed_without_rows() {
+        let relop = employees();
+
+        let employees = relop
+            .execute_typed::<(i64,)>("select id from employees where id > 2")
+            .unwrap();
+
+        assert!(employees.is_empty());
+    }
+
+    #[test]
+    fn attempt_to_execute_typed_into_a_struct_with_a_column_not_selected() {
+        let relop = employees();
+
+        let result = relop.execute_typed::<Employee>("select id, name from employees");
+        assert!(matches!(
+            result,
+            Err(ClientError::FromRow(FromRowError::UnknownColumn(column))) if column == "city"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_typed_with_a_null_value_into_a_non_optional_value() {
+        let relop = employees();
+
+        let result = relop.execute_typed::<(i64, String)>("select id, city from employees");
+        assert!(matches!(
+            result,
+            Err(ClientError::FromRow(FromRowError::Conversion {
+                column,
+                error: ConversionError::NullValue {
+                    expected: ColumnType::Text
+                },
+            })) if column == "employees.city"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_typed_for_a_query_without_rows() {
+        let relop = employees();
+
+        let result =
+            relop.execute_typed::<(i64,)>("insert into employees values (3, 'rows', 'Delhi')");
+        assert!(matches!(result, Err(ClientError::NotAResultSet)));
+    }
 }
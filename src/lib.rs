@@ -4,6 +4,6 @@ pub mod macros;
 pub(crate) mod query;
 pub mod schema;
 pub mod storage;
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
 pub mod types;
@@ -10,11 +10,12 @@
 /// use relop::storage::row::Row;
 /// use relop::types::column_value::ColumnValue;
 ///
-/// let row = row![1, "text"];
+/// let row = row![1, "text", true];
 ///
 /// let expected = Row::filled(vec![
 ///     ColumnValue::int(1),
-///     ColumnValue::text("text")
+///     ColumnValue::text("text"),
+///     ColumnValue::bool(true)
 /// ]);
 /// assert_eq!(row, expected);
 ///
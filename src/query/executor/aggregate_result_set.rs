@@ -0,0 +1,241 @@
+use crate::query::executor::error::ExecutionError;
+use crate::query::executor::result_set::{ResultSet, RowViewResult};
+use crate::query::parser::projection::Aggregate;
+use crate::schema::Schema;
+use crate::storage::row::Row;
+use crate::storage::row_view::RowView;
+use crate::types::column_type::ColumnType;
+use crate::types::column_value::ColumnValue;
+use std::collections::HashSet;
+
+/// A `ResultSet` implementation that aggregates all the rows of the inner `ResultSet` into a
+/// single row, with one `Int` column per aggregate (e.g., `count(*), count(distinct city)`).
+///
+/// `count(*)` counts the rows, `count(column)` counts the non-null values of the column, and
+/// `count(distinct column)` counts the distinct non-null values of the column, tracking the
+/// values seen in a `HashSet`.
+///
+/// # Note
+///
+/// The rows of the inner result set are consumed before the aggregated row is yielded.
+pub struct AggregateResultSet {
+    inner: Box<dyn ResultSet>,
+    counters: Vec<Counter>,
+    schema: Schema,
+    visible_positions: Vec<usize>,
+}
+
+/// What an aggregate counts: the rows, or the (distinct) non-null values at a position.
+struct Counter {
+    position: Option<usize>,
+    distinct: bool,
+}
+
+impl AggregateResultSet {
+    /// Creates a new `AggregateResultSet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The source `ResultSet`.
+    /// * `aggregates` - The aggregates, one per column of the result.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AggregateResultSet)` if all the aggregated columns exist in the source schema.
+    /// * `Err(ExecutionError::UnknownColumn)` if any column is not found.
+    pub(crate) fn new(
+        inner: Box<dyn ResultSet>,
+        aggregates: &[Aggregate],
+    ) -> Result<AggregateResultSet, ExecutionError> {
+        let counters = aggregates
+            .iter()
+            .map(|aggregate| {
+                let position = aggregate
+                    .column()
+                    .map(|column| {
+                        inner
+                            .schema()
+                            .column_position(column)
+                            .map_err(ExecutionError::from)?
+                            .ok_or_else(|| ExecutionError::UnknownColumn(column.to_string()))
+                    })
+                    .transpose()?;
+                Ok(Counter {
+                    position,
+                    distinct: aggregate.is_distinct(),
+                })
+            })
+            .collect::<Result<Vec<_>, ExecutionError>>()?;
+
+        let output_names = aggregates
+            .iter()
+            .map(|aggregate| aggregate.output_name())
+            .collect::<Vec<_>>();
+        let columns = output_names
+            .iter()
+            .map(|name| (name.as_str(), ColumnType::Int))
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            inner,
+            counters,
+            schema: Schema::new().with_computed_columns(&columns),
+            visible_positions: (0..aggregates.len()).collect(),
+        })
+    }
+}
+
+impl ResultSet for AggregateResultSet {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        let mut counts = vec![0usize; self.counters.len()];
+        let mut distinct_values: Vec<HashSet<ColumnValue>> =
+            vec![HashSet::new(); self.counters.len()];
+
+        for row_view_result in self.inner.iterator()? {
+            let row_view = row_view_result?;
+            for (index, counter) in self.counters.iter().enumerate() {
+                let Some(position) = counter.position else {
+                    counts[index] += 1;
+                    continue;
+                };
+                let value = row_view.column_value_at_unchecked(position);
+                if value.is_null() {
+                    continue;
+                }
+                if counter.distinct {
+                    distinct_values[index].insert(value.clone());
+                } else {
+                    counts[index] += 1;
+                }
+            }
+        }
+
+        let values = self
+            .counters
+            .iter()
+            .enumerate()
+            .map(|(index, counter)| {
+                let count = if counter.distinct {
+                    distinct_values[index].len()
+                } else {
+                    counts[index]
+                };
+                ColumnValue::int(count as i64)
+            })
+            .collect();
+        let row_view = RowView::new(Row::filled(values), &self.schema, &self.visible_positions);
+        Ok(Box::new(std::iter::once(Ok(row_view))))
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::executor::test_utils::ErrorResultSet;
+    use crate::query::executor::values_result_set::ValuesResultSet;
+    use crate::{assert_next_row, assert_no_more_rows, row, schema};
+    use std::sync::Arc;
+
+    fn names_and_cities(rows: Vec<Row>) -> Box<dyn ResultSet> {
+        let schema = schema!["name" => ColumnType::Text, "city" => ColumnType::Text].unwrap();
+        Box::new(ValuesResultSet::new(rows, Arc::new(schema)))
+    }
+
+    #[test]
+    fn count_the_rows_and_the_distinct_values() {
+        let inner = names_and_cities(vec![
+            row!["Amit", "Delhi"],
+            row!["Rahul", "Delhi"],
+            row!["Neha", "Pune"],
+        ]);
+        let aggregate_result_set = AggregateResultSet::new(
+            inner,
+            &[
+                Aggregate::count_all(),
+                Aggregate::count("city"),
+                Aggregate::count_distinct("city").with_alias("cities"),
+            ],
+        )
+        .unwrap();
+
+        let mut iterator = aggregate_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "count(*)" => 3, "count(city)" => 3, "cities" => 2);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn count_skips_null_values() {
+        let inner = names_and_cities(vec![
+            row!["Amit", "Delhi"],
+            Row::filled(vec![ColumnValue::text("Rahul"), ColumnValue::null()]),
+            Row::filled(vec![ColumnValue::text("Neha"), ColumnValue::null()]),
+        ]);
+        let aggregate_result_set = AggregateResultSet::new(
+            inner,
+            &[
+                Aggregate::count_all(),
+                Aggregate::count("city"),
+                Aggregate::count_distinct("city"),
+            ],
+        )
+        .unwrap();
+
+        let mut iterator = aggregate_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "count(*)" => 3, "count(city)" => 1, "count(distinct city)" => 1);
+    }
+
+    #[test]
+    fn count_over_no_rows() {
+        let aggregate_result_set =
+            AggregateResultSet::new(names_and_cities(vec![]), &[Aggregate::count_all()]).unwrap();
+
+        let mut iterator = aggregate_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "count(*)" => 0);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn aggregate_result_set_supports_multiple_iterations() {
+        let inner = names_and_cities(vec![row!["Amit", "Delhi"], row!["Rahul", "Delhi"]]);
+        let aggregate_result_set =
+            AggregateResultSet::new(inner, &[Aggregate::count_distinct("city")]).unwrap();
+
+        let mut iterator = aggregate_result_set.iterator().unwrap();
+        assert_next_row!(iterator.as_mut(), "count(distinct city)" => 1);
+
+        let mut iterator = aggregate_result_set.iterator().unwrap();
+        assert_next_row!(iterator.as_mut(), "count(distinct city)" => 1);
+    }
+
+    #[test]
+    fn attempt_to_create_aggregate_result_set_with_unknown_column() {
+        let result =
+            AggregateResultSet::new(names_and_cities(vec![]), &[Aggregate::count("unknown")]);
+
+        assert!(matches!(
+            result,
+            Err(ExecutionError::UnknownColumn(column)) if column == "unknown"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_aggregate_with_an_error_in_the_inner_result_set() {
+        let error_result_set = ErrorResultSet {
+            schema: Arc::new(Schema::new()),
+        };
+        let aggregate_result_set =
+            AggregateResultSet::new(Box::new(error_result_set), &[Aggregate::count_all()]).unwrap();
+
+        assert!(matches!(
+            aggregate_result_set.iterator(),
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+}
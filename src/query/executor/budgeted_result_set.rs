@@ -0,0 +1,121 @@
+use crate::query::executor::error::ExecutionError;
+use crate::query::executor::limits::Budget;
+use crate::query::executor::result_set::{ResultSet, RowViewResult};
+use crate::schema::Schema;
+use std::sync::Arc;
+
+/// A `ResultSet` implementation that keeps a query within its `Budget`.
+///
+/// A charging `BudgetedResultSet` wraps a table scan, and charges each of its rows to the budget,
+/// failing the iteration with `ExecutionError::Budget` once a limit is exceeded. A restarting
+/// `BudgetedResultSet` wraps the result of the query, and restarts the budget whenever its rows
+/// are iterated, so that every iteration gets the whole budget.
+pub struct BudgetedResultSet {
+    inner: Box<dyn ResultSet>,
+    budget: Arc<Budget>,
+    restarting: bool,
+}
+
+impl BudgetedResultSet {
+    /// Creates a `BudgetedResultSet` which charges each row of `inner` to the budget.
+    pub(crate) fn charging(inner: Box<dyn ResultSet>, budget: Arc<Budget>) -> Self {
+        Self {
+            inner,
+            budget,
+            restarting: false,
+        }
+    }
+
+    /// Creates a `BudgetedResultSet` which restarts the budget whenever `inner` is iterated.
+    pub(crate) fn restarting(inner: Box<dyn ResultSet>, budget: Arc<Budget>) -> Self {
+        Self {
+            inner,
+            budget,
+            restarting: true,
+        }
+    }
+}
+
+impl ResultSet for BudgetedResultSet {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        if self.restarting {
+            self.budget.restart();
+            return self.inner.iterator();
+        }
+        let inner_iterator = self.inner.iterator()?;
+        Ok(Box::new(inner_iterator.map(move |row_view_result| {
+            row_view_result.and_then(|row_view| {
+                self.budget.charge_row()?;
+                Ok(row_view)
+            })
+        })))
+    }
+
+    fn schema(&self) -> &Schema {
+        self.inner.schema()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.inner.column_names()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::executor::limits::{ExceededLimit, ExecutionLimits};
+    use crate::query::executor::values_result_set::ValuesResultSet;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, row, schema};
+
+    fn ids(budget: &Arc<Budget>) -> Box<dyn ResultSet> {
+        let schema = schema!["id" => ColumnType::Int].unwrap();
+        let values_result_set =
+            ValuesResultSet::new(vec![row![1], row![2], row![3]], Arc::new(schema));
+        Box::new(BudgetedResultSet::charging(
+            Box::new(values_result_set),
+            budget.clone(),
+        ))
+    }
+
+    #[test]
+    fn produce_rows_within_the_budget() {
+        let budget = Arc::new(Budget::new(ExecutionLimits::new().with_max_rows_scanned(3)));
+
+        assert_eq!(3, ids(&budget).count().unwrap());
+    }
+
+    #[test]
+    fn attempt_to_produce_rows_beyond_the_budget() {
+        let budget = Arc::new(Budget::new(ExecutionLimits::new().with_max_rows_scanned(2)));
+        let result_set = ids(&budget);
+        let mut iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1);
+        assert_next_row!(iterator.as_mut(), "id" => 2);
+        assert!(matches!(
+            iterator.next(),
+            Some(Err(ExecutionError::Budget(ExceededLimit::MaxRowsScanned(
+                2
+            ))))
+        ));
+    }
+
+    #[test]
+    fn restart_the_budget_for_every_iteration() {
+        let budget = Arc::new(Budget::new(ExecutionLimits::new().with_max_rows_scanned(3)));
+        let result_set = BudgetedResultSet::restarting(ids(&budget), budget.clone());
+
+        assert_eq!(3, result_set.count().unwrap());
+        assert_eq!(3, result_set.count().unwrap());
+    }
+
+    #[test]
+    fn attempt_to_iterate_twice_without_restarting_the_budget() {
+        let budget = Arc::new(Budget::new(ExecutionLimits::new().with_max_rows_scanned(3)));
+        let result_set = ids(&budget);
+
+        assert_eq!(3, result_set.count().unwrap());
+        assert!(matches!(result_set.count(), Err(ExecutionError::Budget(_))));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::query::executor::error::ExecutionError;
+use crate::query::executor::result_set::{ResultSet, RowViewResult};
+use crate::schema::Schema;
+use crate::types::column_value::ColumnValue;
+
+/// A `ResultSet` implementation that keeps the first row of each distinct key (`DISTINCT ON`).
+///
+/// `DistinctOnResultSet` expects the rows of the inner `ResultSet` to be ordered by the key
+/// columns, so that the rows sharing a key are adjacent. It yields a row whenever its key differs
+/// from the key of the previous row. `Null` values are considered equal to each other.
+pub struct DistinctOnResultSet {
+    inner: Box<dyn ResultSet>,
+    key_positions: Vec<usize>,
+}
+
+impl DistinctOnResultSet {
+    /// Creates a new `DistinctOnResultSet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The source `ResultSet`, ordered by the key columns.
+    /// * `columns` - The names of the columns which form the key.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DistinctOnResultSet)` if all specified columns exist in the source schema.
+    /// * `Err(ExecutionError::UnknownColumn)` if any column is not found.
+    pub(crate) fn new(
+        inner: Box<dyn ResultSet>,
+        columns: &[String],
+    ) -> Result<DistinctOnResultSet, ExecutionError> {
+        let key_positions = columns
+            .iter()
+            .map(|column| {
+                inner
+                    .schema()
+                    .column_position(column)
+                    .map_err(ExecutionError::from)?
+                    .ok_or_else(|| ExecutionError::UnknownColumn(column.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            inner,
+            key_positions,
+        })
+    }
+}
+
+impl ResultSet for DistinctOnResultSet {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        let inner_iterator = self.inner.iterator()?;
+        let mut previous_key: Option<Vec<ColumnValue>> = None;
+
+        Ok(Box::new(inner_iterator.filter(move |row_view_result| {
+            let Ok(row_view) = row_view_result else {
+                return true;
+            };
+            let key = self
+                .key_positions
+                .iter()
+                .map(|position| row_view.column_value_at_unchecked(*position).clone())
+                .collect::<Vec<_>>();
+            if previous_key.as_ref() == Some(&key) {
+                return false;
+            }
+            previous_key = Some(key);
+            true
+        })))
+    }
+
+    fn schema(&self) -> &Schema {
+        self.inner.schema()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.inner.column_names()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::executor::values_result_set::ValuesResultSet;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, row, schema};
+    use std::sync::Arc;
+
+    fn cities_and_names(rows: Vec<crate::storage::row::Row>) -> Box<dyn ResultSet> {
+        let schema = schema!["city" => ColumnType::Text, "name" => ColumnType::Text].unwrap();
+        Box::new(ValuesResultSet::new(rows, Arc::new(schema)))
+    }
+
+    #[test]
+    fn keep_the_first_row_of_each_key() {
+        let inner = cities_and_names(vec![
+            row!["Delhi", "Amit"],
+            row!["Delhi", "Rahul"],
+            row!["Pune", "Neha"],
+            row!["Pune", "Zoya"],
+        ]);
+        let distinct_on_result_set =
+            DistinctOnResultSet::new(inner, &["city".to_string()]).unwrap();
+
+        let mut iterator = distinct_on_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "city" => "Delhi", "name" => "Amit");
+        assert_next_row!(iterator.as_mut(), "city" => "Pune", "name" => "Neha");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn keep_the_first_row_of_each_multi_column_key() {
+        let inner = cities_and_names(vec![
+            row!["Delhi", "Amit"],
+            row!["Delhi", "Amit"],
+            row!["Delhi", "Rahul"],
+            row!["Pune", "Amit"],
+        ]);
+        let distinct_on_result_set =
+            DistinctOnResultSet::new(inner, &["city".to_string(), "name".to_string()]).unwrap();
+
+        assert_eq!(3, distinct_on_result_set.count().unwrap());
+    }
+
+    #[test]
+    fn emit_a_key_again_when_it_is_not_adjacent() {
+        let inner = cities_and_names(vec![
+            row!["Delhi", "Amit"],
+            row!["Pune", "Neha"],
+            row!["Delhi", "Rahul"],
+        ]);
+        let distinct_on_result_set =
+            DistinctOnResultSet::new(inner, &["city".to_string()]).unwrap();
+
+        assert_eq!(3, distinct_on_result_set.count().unwrap());
+    }
+
+    #[test]
+    fn distinct_on_result_set_supports_multiple_iterations() {
+        let inner = cities_and_names(vec![row!["Delhi", "Amit"], row!["Delhi", "Rahul"]]);
+        let distinct_on_result_set =
+            DistinctOnResultSet::new(inner, &["city".to_string()]).unwrap();
+
+        assert_eq!(1, distinct_on_result_set.count().unwrap());
+        assert_eq!(1, distinct_on_result_set.count().unwrap());
+    }
+
+    #[test]
+    fn attempt_to_create_distinct_on_result_set_with_unknown_column() {
+        let inner = cities_and_names(vec![]);
+        let result = DistinctOnResultSet::new(inner, &["unknown".to_string()]);
+
+        assert!(matches!(
+            result,
+            Err(ExecutionError::UnknownColumn(column)) if column == "unknown"
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::catalog::error::CatalogError;
+use crate::catalog::error::{CatalogError, InsertError};
 use crate::storage::error::RowViewComparatorError;
 
 /// Represents errors that can occur during query execution.
@@ -6,18 +6,94 @@ use crate::storage::error::RowViewComparatorError;
 pub enum ExecutionError {
     /// Errors related to catalog operations during execution (e.g., table lookup).
     Catalog(CatalogError),
-    /// Error related unknown column during select query execution with projection.
+    /// Error related unknown column during select query execution (e.g., in a projection or a filter).
     UnknownColumn(String),
     /// Error related to mismatch types during execution of comparison operations.
     TypeMismatchInComparison,
     /// Errors related to schema validation during execution.
     Schema(crate::schema::error::SchemaError),
+    /// Error when an unqualified column name (e.g., `name`) matches a column of more than one
+    /// joined table; qualifying it (e.g., `employees.name`) resolves the ambiguity.
+    AmbiguousColumn(String),
     /// Error when a raw Row scan encounters an unbound ColumnReference.
     UnboundColumn(String),
     /// Error when a ColumnIndex is out of bounds for a Row.
     ColumnIndexOutOfBounds(usize),
     /// Errors that occur during query planning (e.g., binding predicates).
     Planning(crate::query::plan::error::PlanningError),
+    /// Errors related to inserting rows (e.g., a value not matching the column type).
+    Insert(InsertError),
+    /// Errors related to updating rows (e.g., an updated primary key already present in the table).
+    Update(InsertError),
+    /// Error when the right-hand side of a division is zero.
+    DivisionByZero,
+    /// Error when the result of an arithmetic operation does not fit in an `Int`.
+    ArithmeticOverflow,
+    /// Error when a scalar function is applied to an argument of an unsupported type.
+    InvalidFunctionArgument {
+        /// The name of the function.
+        function: String,
+        /// The type of argument the function expects.
+        expected: crate::types::column_type::ColumnType,
+    },
+    /// Error when a subquery is used where it is not evaluated ahead of the rows (e.g., in a projection).
+    UnevaluatedSubquery,
+    /// Error when a parameter placeholder (`?`) is left without a value.
+    UnboundParameter(usize),
+    /// Error when a subquery used as a value produces no rows.
+    ScalarSubqueryWithoutRows,
+    /// Error when a subquery used as a value produces more than one row.
+    ScalarSubqueryWithMultipleRows,
+    /// Error when a qualified projection (e.g., `employees.*`) refers to a table or alias that is not in the source.
+    TableAliasNotFound(String),
+    /// Error when an insert omits a column which has no default value and does not accept `Null`.
+    MissingColumnValue(String),
+    /// Error when a text compared with (or assigned to) a date is not a valid date.
+    InvalidDate(String),
+    /// Error when a query exceeds a limit of its `ExecutionLimits` (e.g., the rows scanned by a
+    /// Cartesian join), containing the exceeded limit.
+    Budget(crate::query::executor::limits::ExceededLimit),
+}
+
+impl std::fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecutionError::UnknownColumn(column) => write!(f, "unknown column {}", column),
+            ExecutionError::TypeMismatchInComparison => write!(f, "type mismatch in comparison"),
+            ExecutionError::Schema(error) => write!(f, "{}", error),
+            ExecutionError::AmbiguousColumn(column) => write!(f, "ambiguous column {}", column),
+            ExecutionError::DivisionByZero => write!(f, "division by zero"),
+            ExecutionError::ArithmeticOverflow => write!(f, "arithmetic overflow"),
+            ExecutionError::InvalidFunctionArgument { function, expected } => write!(
+                f,
+                "function {} expects an argument of type {}",
+                function, expected
+            ),
+            ExecutionError::UnboundParameter(index) => {
+                write!(f, "parameter {} has no value", index)
+            }
+            ExecutionError::ScalarSubqueryWithoutRows => {
+                write!(f, "scalar subquery produced no rows")
+            }
+            ExecutionError::ScalarSubqueryWithMultipleRows => {
+                write!(f, "scalar subquery produced more than one row")
+            }
+            ExecutionError::TableAliasNotFound(table) => {
+                write!(f, "table or alias {} not found", table)
+            }
+            ExecutionError::MissingColumnValue(column) => {
+                write!(f, "missing value for column {}", column)
+            }
+            ExecutionError::InvalidDate(text) => write!(f, "invalid date '{}'", text),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl From<std::convert::Infallible> for ExecutionError {
+    fn from(error: std::convert::Infallible) -> Self {
+        match error {}
+    }
 }
 
 impl From<RowViewComparatorError> for ExecutionError {
@@ -30,12 +106,25 @@ impl From<RowViewComparatorError> for ExecutionError {
 
 impl From<crate::schema::error::SchemaError> for ExecutionError {
     fn from(error: crate::schema::error::SchemaError) -> Self {
-        ExecutionError::Schema(error)
+        match error {
+            crate::schema::error::SchemaError::AmbiguousColumnName(column_name) => {
+                ExecutionError::AmbiguousColumn(column_name)
+            }
+            error => ExecutionError::Schema(error),
+        }
     }
 }
 
 impl From<crate::query::plan::error::PlanningError> for ExecutionError {
     fn from(error: crate::query::plan::error::PlanningError) -> Self {
-        ExecutionError::Planning(error)
+        match error {
+            crate::query::plan::error::PlanningError::ColumnNotFound(column_name) => {
+                ExecutionError::UnknownColumn(column_name)
+            }
+            crate::query::plan::error::PlanningError::AmbiguousColumn(column_name) => {
+                ExecutionError::AmbiguousColumn(column_name)
+            }
+            error => ExecutionError::Planning(error),
+        }
     }
 }
@@ -0,0 +1,115 @@
+use crate::query::executor::error::ExecutionError;
+use crate::query::executor::result_set::{visible_schema, ResultSet, RowViewResult};
+use crate::schema::Schema;
+use crate::storage::row::Row;
+use crate::storage::row_view::RowView;
+use std::collections::HashSet;
+
+/// A `ResultSet` implementation that produces the rows of the left `ResultSet` which are not
+/// produced by the right `ResultSet` (`EXCEPT`).
+///
+/// When the iterator is created, the right side is read completely into a hash set of rows. Every
+/// left row is then looked up in the set, and is produced only if it is not found; the row is added
+/// to the set when it is produced, so that each distinct row is produced once.
+///
+/// Only the visible columns of both sides are compared, and the columns are named after the left side.
+/// Rows are produced in the order of the left side.
+pub struct ExceptResultSet {
+    left: Box<dyn ResultSet>,
+    right: Box<dyn ResultSet>,
+    schema: Schema,
+    visible_positions: Vec<usize>,
+}
+
+impl ExceptResultSet {
+    /// Creates a new `ExceptResultSet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The `ResultSet` whose rows are produced, it names the columns.
+    /// * `right` - The `ResultSet` whose rows are excluded from the left rows.
+    pub(crate) fn new(left: Box<dyn ResultSet>, right: Box<dyn ResultSet>) -> Self {
+        let schema = visible_schema(left.as_ref());
+        let visible_positions = (0..schema.column_count()).collect();
+        Self {
+            left,
+            right,
+            schema,
+            visible_positions,
+        }
+    }
+}
+
+impl ResultSet for ExceptResultSet {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        let mut excluded_rows = self
+            .right
+            .iterator()?
+            .map(|row_view_result| row_view_result.map(|row_view| row_view.to_row()))
+            .collect::<Result<HashSet<Row>, _>>()?;
+
+        let left_rows = self
+            .left
+            .iterator()?
+            .map(|row_view_result| row_view_result.map(|row_view| row_view.to_row()));
+
+        Ok(Box::new(
+            left_rows
+                .filter(move |row_result| match row_result {
+                    Ok(row) => excluded_rows.insert(row.clone()),
+                    Err(_) => true,
+                })
+                .map(move |row_result| {
+                    row_result.map(|row| RowView::new(row, &self.schema, &self.visible_positions))
+                }),
+        ))
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::executor::test_utils::scan_result_set;
+    use crate::{assert_next_row, assert_no_more_rows, rows};
+
+    #[test]
+    fn except_result_set_with_overlapping_inputs() {
+        let left = scan_result_set("employees", rows![[1, "relop"], [2, "query"], [3, "plan"]]);
+        let right = scan_result_set("managers", rows![[3, "plan"], [1, "relop"], [4, "scan"]]);
+
+        let except_result_set = ExceptResultSet::new(left, right);
+        let mut iterator = except_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "employees.name" => "query");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn except_result_set_removes_duplicates() {
+        let left = scan_result_set("employees", rows![[1, "relop"], [2, "query"], [1, "relop"]]);
+        let right = scan_result_set("managers", rows![[2, "query"]]);
+
+        let except_result_set = ExceptResultSet::new(left, right);
+        let mut iterator = except_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn except_result_set_with_disjoint_inputs() {
+        let left = scan_result_set("employees", rows![[1, "relop"], [2, "query"]]);
+        let right = scan_result_set("managers", rows![[1, "query"], [2, "relop"]]);
+
+        let except_result_set = ExceptResultSet::new(left, right);
+        let mut iterator = except_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+}
@@ -7,6 +7,10 @@ use crate::schema::Schema;
 ///
 /// `FilterResultSet` wraps another `ResultSet` and only yields rows that satisfy
 /// the given `Predicate`.
+///
+/// A predicate which does not depend on the row (e.g., `1 = 2`) is evaluated once: when it is
+/// false, no row is pulled from the inner `ResultSet`, and when it is true, the rows of the inner
+/// `ResultSet` are yielded as they are.
 pub struct FilterResultSet {
     inner: Box<dyn ResultSet>,
     predicate: Predicate,
@@ -25,7 +29,12 @@ impl FilterResultSet {
 }
 
 impl ResultSet for FilterResultSet {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        match self.predicate.constant_value() {
+            Some(false) => return Ok(Box::new(std::iter::empty())),
+            Some(true) => return self.inner.iterator(),
+            None => {}
+        }
         let inner_iterator = self.inner.iterator()?;
         let result = inner_iterator.filter_map(move |row_view_result| match row_view_result {
             Ok(row_view) => match self.predicate.matches(&row_view) {
@@ -41,6 +50,10 @@ impl ResultSet for FilterResultSet {
     fn schema(&self) -> &Schema {
         self.inner.schema()
     }
+
+    fn column_names(&self) -> Vec<String> {
+        self.inner.column_names()
+    }
 }
 
 #[cfg(test)]
@@ -51,7 +64,7 @@ mod tests {
     use std::sync::Arc;
 
     use super::*;
-    use crate::query::executor::test_utils::ErrorResultSet;
+    use crate::query::executor::test_utils::{ErrorResultSet, InitErrorResultSet};
     use crate::query::parser::ast::Literal;
     use crate::query::plan::predicate::LogicalOperator;
     use crate::storage::table_store::TableStore;
@@ -234,6 +247,61 @@ mod tests {
         assert_no_more_rows!(iterator.as_mut());
     }
 
+    #[test]
+    fn filter_result_set_with_always_false_literal_comparison_pulls_no_rows() {
+        let schema = Arc::new(schema!["id" => ColumnType::Int].unwrap());
+        let result_set = Box::new(InitErrorResultSet { schema });
+
+        let predicate =
+            Predicate::comparison(Literal::Int(1), LogicalOperator::Eq, Literal::Int(2));
+        let filter_result_set = FilterResultSet::new(result_set, predicate);
+        let mut iterator = filter_result_set.iterator().unwrap();
+
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn filter_result_set_with_always_false_conjunct_pulls_no_rows() {
+        let schema = Arc::new(schema!["id" => ColumnType::Int].unwrap());
+        let result_set = Box::new(InitErrorResultSet { schema });
+
+        let predicate = Predicate::And(vec![
+            Predicate::comparison(Literal::Int(1), LogicalOperator::Eq, Literal::Int(2)),
+            Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(1),
+            ),
+        ]);
+        let filter_result_set = FilterResultSet::new(result_set, predicate);
+        let mut iterator = filter_result_set.iterator().unwrap();
+
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn filter_result_set_with_failing_literal_comparison_reports_the_error_for_rows() {
+        let table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let table_store = TableStore::new();
+        table_store.insert(row![1]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let predicate = Predicate::comparison(
+            Literal::Int(1),
+            LogicalOperator::Eq,
+            Literal::Text("one".to_string()),
+        );
+        let filter_result_set = FilterResultSet::new(result_set, predicate);
+        let mut iterator = filter_result_set.iterator().unwrap();
+
+        assert!(matches!(
+            iterator.next(),
+            Some(Err(ExecutionError::TypeMismatchInComparison))
+        ));
+    }
+
     #[test]
     fn filter_result_set_with_error() {
         let schema = schema!["id" => ColumnType::Int].unwrap();
@@ -0,0 +1,523 @@
+use crate::query::executor::error::ExecutionError;
+use crate::query::executor::result_set::{ResultSet, RowViewResult};
+use crate::query::parser::ast::JoinType;
+use crate::query::plan::predicate::Predicate;
+use crate::schema::Schema;
+use crate::storage::row_view::RowView;
+use crate::types::column_value::ColumnValue;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A `ResultSet` implementation that performs a hash join between two `ResultSet`s.
+///
+/// A hash join is used for equi-joins, where the `on` predicate is a single equality between
+/// one column of the left side and one column of the right side (e.g., `employees.id = departments.id`).
+///
+/// ### Execution Flow:
+///
+/// 1. **Build**: when the iterator is created, the right side is read completely and its rows are
+///    grouped in a hash table keyed by the value of the right join column.
+/// 2. **Probe**: every left row looks up the value of its left join column in the hash table, and is
+///    merged with each of the right rows stored under that value.
+///
+/// The join costs `O(n + m)` instead of the `O(n * m)` of a `NestedLoopJoinResultSet`, at the price
+/// of keeping all the right rows in memory for the duration of the iteration.
+///
+/// Rows are produced in the same order as a `NestedLoopJoinResultSet`: left rows in their order, and
+/// for each left row, the matching right rows in their order. A `Null` key never matches, like a
+/// comparison involving `Null`.
+///
+/// ### Join Types
+///
+/// Only `Inner` and `Left` joins are supported (see [`HashJoinResultSet::supports`]).
+/// For a `Left` join, left rows without a match are padded with nulls on the right.
+pub struct HashJoinResultSet {
+    left: Box<dyn ResultSet>,
+    right: Box<dyn ResultSet>,
+    left_key_position: usize,
+    right_key_position: usize,
+    join_type: JoinType,
+    merged_schema: Schema,
+    visible_positions: Arc<Vec<usize>>,
+}
+
+impl HashJoinResultSet {
+    /// Creates a new `HashJoinResultSet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The left (probe) side of the join.
+    /// * `right` - The right (build) side of the join.
+    /// * `left_key_position` - The position of the join column in the left schema.
+    /// * `right_key_position` - The position of the join column in the right schema.
+    /// * `join_type` - The type of the join, either `Inner` or `Left`.
+    pub(crate) fn new(
+        left: Box<dyn ResultSet>,
+        right: Box<dyn ResultSet>,
+        left_key_position: usize,
+        right_key_position: usize,
+        join_type: JoinType,
+    ) -> Self {
+        debug_assert!(Self::supports(join_type));
+
+        let merged_schema = left
+            .schema()
+            .merge_with_prefixes(None, right.schema(), None);
+        let visible_positions = Arc::new((0..merged_schema.column_count()).collect());
+        Self {
+            left,
+            right,
+            left_key_position,
+            right_key_position,
+            join_type,
+            merged_schema,
+            visible_positions,
+        }
+    }
+
+    /// Returns `true` if the join type can be executed as a hash join.
+    pub(crate) fn supports(join_type: JoinType) -> bool {
+        matches!(join_type, JoinType::Inner | JoinType::Left)
+    }
+
+    /// Returns the positions of the join columns in the left and the right schema,
+    /// if the `on` predicate is an equi-join predicate.
+    ///
+    /// The predicate must be a single equality between two columns, one of which resolves
+    /// only in the left schema and the other only in the right schema, in either order.
+    /// Both columns must have the same type, so that the hash join never hides a type mismatch
+    /// which the nested loop join would report.
+    pub(crate) fn key_positions(
+        on: &Predicate,
+        left_schema: &Schema,
+        right_schema: &Schema,
+    ) -> Option<(usize, usize)> {
+        let (lhs, rhs) = on.equality_column_names()?;
+
+        let key_positions = Self::positions_of(lhs, rhs, left_schema, right_schema)
+            .or_else(|| Self::positions_of(rhs, lhs, left_schema, right_schema))?;
+
+        let (left_key_position, right_key_position) = key_positions;
+        let same_column_types = left_schema.column_type_at(left_key_position)
+            == right_schema.column_type_at(right_key_position);
+
+        same_column_types.then_some(key_positions)
+    }
+
+    fn positions_of(
+        left_column_name: &str,
+        right_column_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+    ) -> Option<(usize, usize)> {
+        let only_in = |column_name: &str, schema: &Schema, other: &Schema| {
+            if other.has_column(column_name) {
+                return None;
+            }
+            schema.column_position(column_name).ok().flatten()
+        };
+        let left_key_position = only_in(left_column_name, left_schema, right_schema)?;
+        let right_key_position = only_in(right_column_name, right_schema, left_schema)?;
+        Some((left_key_position, right_key_position))
+    }
+}
+
+impl ResultSet for HashJoinResultSet {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        let mut hash_table: HashMap<ColumnValue, Vec<RowView<'_>>> = HashMap::new();
+        for right_row_view in self.right.iterator()? {
+            let right_row_view = right_row_view?;
+            let key = right_row_view.column_value_at_unchecked(self.right_key_position);
+            if key.is_null() {
+                continue;
+            }
+            hash_table
+                .entry(key.clone())
+                .or_default()
+                .push(right_row_view);
+        }
+
+        let left_iterator = self.left.iterator()?;
+        Ok(Box::new(HashJoinIterator {
+            left_iterator,
+            hash_table,
+            left_key_position: self.left_key_position,
+            join_type: self.join_type,
+            right_column_count: self.right.schema().column_count(),
+            merged_schema: &self.merged_schema,
+            visible_positions: &self.visible_positions,
+            current_left_row_view: None,
+            current_match_position: 0,
+        }))
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.merged_schema
+    }
+}
+
+/// An iterator that probes the hash table built from the right side with every left row.
+struct HashJoinIterator<'a> {
+    left_iterator: Box<dyn Iterator<Item = RowViewResult<'a>> + 'a>,
+    hash_table: HashMap<ColumnValue, Vec<RowView<'a>>>,
+    left_key_position: usize,
+    join_type: JoinType,
+    right_column_count: usize,
+    merged_schema: &'a Schema,
+    visible_positions: &'a [usize],
+    current_left_row_view: Option<RowView<'a>>,
+    current_match_position: usize,
+}
+
+impl<'a> Iterator for HashJoinIterator<'a> {
+    type Item = RowViewResult<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(left_row_view) = self.current_left_row_view.as_ref() {
+                let key = left_row_view.column_value_at_unchecked(self.left_key_position);
+                let matches = self.hash_table.get(key);
+                if let Some(right_row_view) =
+                    matches.and_then(|matches| matches.get(self.current_match_position))
+                {
+                    self.current_match_position += 1;
+                    return Some(Ok(RowView::new(
+                        left_row_view.merge(right_row_view),
+                        self.merged_schema,
+                        self.visible_positions,
+                    )));
+                }
+                let left_row_view = self.current_left_row_view.take().unwrap();
+                if self.join_type == JoinType::Left && self.current_match_position == 0 {
+                    return Some(Ok(RowView::new(
+                        left_row_view.pad_with_nulls(0, self.right_column_count),
+                        self.merged_schema,
+                        self.visible_positions,
+                    )));
+                }
+            }
+
+            match self.left_iterator.next()? {
+                Ok(left_row_view) => {
+                    self.current_left_row_view = Some(left_row_view);
+                    self.current_match_position = 0;
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog::table::Table;
+    use crate::catalog::table_scan::TableScan;
+    use crate::query::executor::nested_loop_join_result_set::NestedLoopJoinResultSet;
+    use crate::query::executor::scan_result_set::ScanResultsSet;
+    use crate::storage::row::Row;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::query::executor::test_utils::{ErrorResultSet, InitErrorResultSet};
+    use crate::query::parser::ast::Literal;
+    use crate::query::plan::predicate::LogicalOperator;
+    use crate::storage::table_store::TableStore;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, row, rows, schema};
+
+    fn employees_and_departments() -> (Box<dyn ResultSet>, Box<dyn ResultSet>) {
+        let employees_table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let employees_store = TableStore::new();
+        employees_store.insert_all(rows![[1, "Alice"], [2, "Bob"], [3, "Carol"]]);
+
+        let departments_table = Table::new(
+            "departments",
+            schema!["employee_id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let departments_store = TableStore::new();
+        departments_store.insert_all(rows![
+            [1, "Engineering"],
+            [3, "Marketing"],
+            [1, "Research"],
+            [4, "Sales"]
+        ]);
+
+        (
+            Box::new(ScanResultsSet::new(
+                TableScan::new(Arc::new(employees_store)),
+                Arc::new(employees_table),
+                None,
+            )),
+            Box::new(ScanResultsSet::new(
+                TableScan::new(Arc::new(departments_store)),
+                Arc::new(departments_table),
+                None,
+            )),
+        )
+    }
+
+    fn on_employee_id_equals_department_employee_id() -> Predicate {
+        Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::ColumnReference("departments.employee_id".to_string()),
+        )
+    }
+
+    fn collect_rows(result_set: &dyn ResultSet) -> Vec<Row> {
+        let column_count = result_set.schema().column_count();
+        result_set
+            .iterator()
+            .unwrap()
+            .map(|row_view| {
+                let row_view = row_view.unwrap();
+                Row::filled(
+                    (0..column_count)
+                        .map(|position| row_view.column_value_at_unchecked(position).clone())
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hash_join_result_sets() {
+        let (employees, departments) = employees_and_departments();
+        let join_result_set = HashJoinResultSet::new(employees, departments, 0, 0, JoinType::Inner);
+
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.name" => "Engineering");
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.name" => "Research");
+        assert_next_row!(iterator.as_mut(), "employees.id" => 3, "departments.name" => "Marketing");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn left_hash_join_result_sets() {
+        let (employees, departments) = employees_and_departments();
+        let join_result_set = HashJoinResultSet::new(employees, departments, 0, 0, JoinType::Left);
+
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.name" => "Engineering");
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.name" => "Research");
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "employees.name" => "Bob", "departments.name" => ColumnValue::null());
+        assert_next_row!(iterator.as_mut(), "employees.id" => 3, "departments.name" => "Marketing");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn hash_join_result_sets_with_null_keys() {
+        let employees_table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let employees_store = TableStore::new();
+        employees_store.insert_all(vec![Row::filled(vec![ColumnValue::null()]), row![1]]);
+
+        let departments_table =
+            Table::new("departments", schema!["id" => ColumnType::Int].unwrap());
+        let departments_store = TableStore::new();
+        departments_store.insert_all(vec![Row::filled(vec![ColumnValue::null()]), row![1]]);
+
+        let join_result_set = HashJoinResultSet::new(
+            Box::new(ScanResultsSet::new(
+                TableScan::new(Arc::new(employees_store)),
+                Arc::new(employees_table),
+                None,
+            )),
+            Box::new(ScanResultsSet::new(
+                TableScan::new(Arc::new(departments_store)),
+                Arc::new(departments_table),
+                None,
+            )),
+            0,
+            0,
+            JoinType::Inner,
+        );
+
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => 1);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn hash_join_matches_nested_loop_join() {
+        for join_type in [JoinType::Inner, JoinType::Left] {
+            let employees_table = Arc::new(Table::new(
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+            ));
+            let employees_store = Arc::new(TableStore::new());
+            employees_store.insert_all((0..500).map(|id| row![id, (id * 7) % 120]).collect());
+
+            let departments_table = Arc::new(Table::new(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            ));
+            let departments_store = Arc::new(TableStore::new());
+            departments_store.insert_all(
+                (0..150)
+                    .map(|id| row![id % 100, format!("department-{}", id)])
+                    .collect(),
+            );
+
+            let employees = || -> Box<dyn ResultSet> {
+                Box::new(ScanResultsSet::new(
+                    TableScan::new(employees_store.clone()),
+                    employees_table.clone(),
+                    None,
+                ))
+            };
+            let departments = || -> Box<dyn ResultSet> {
+                Box::new(ScanResultsSet::new(
+                    TableScan::new(departments_store.clone()),
+                    departments_table.clone(),
+                    None,
+                ))
+            };
+
+            let on = Predicate::comparison(
+                Literal::ColumnReference("employees.department_id".to_string()),
+                LogicalOperator::Eq,
+                Literal::ColumnReference("departments.id".to_string()),
+            );
+            let (left_key_position, right_key_position) =
+                HashJoinResultSet::key_positions(&on, employees().schema(), departments().schema())
+                    .unwrap();
+
+            let nested_loop_join =
+                NestedLoopJoinResultSet::new(employees(), departments(), Some(on), join_type);
+            let hash_join = HashJoinResultSet::new(
+                employees(),
+                departments(),
+                left_key_position,
+                right_key_position,
+                join_type,
+            );
+
+            let nested_loop_rows = collect_rows(&nested_loop_join);
+            let hash_rows = collect_rows(&hash_join);
+
+            assert!(!hash_rows.is_empty());
+            assert_eq!(nested_loop_rows, hash_rows);
+        }
+    }
+
+    #[test]
+    fn key_positions_for_equi_join_predicate() {
+        let (employees, departments) = employees_and_departments();
+        let key_positions = HashJoinResultSet::key_positions(
+            &on_employee_id_equals_department_employee_id(),
+            employees.schema(),
+            departments.schema(),
+        );
+        assert_eq!(Some((0, 0)), key_positions);
+    }
+
+    #[test]
+    fn key_positions_for_equi_join_predicate_with_right_column_on_lhs() {
+        let (employees, departments) = employees_and_departments();
+        let on = Predicate::comparison(
+            Literal::ColumnReference("departments.name".to_string()),
+            LogicalOperator::Eq,
+            Literal::ColumnReference("employees.name".to_string()),
+        );
+        let key_positions =
+            HashJoinResultSet::key_positions(&on, employees.schema(), departments.schema());
+        assert_eq!(Some((1, 1)), key_positions);
+    }
+
+    #[test]
+    fn no_key_positions_for_ambiguous_column() {
+        let (employees, departments) = employees_and_departments();
+        let on = Predicate::comparison(
+            Literal::ColumnReference("name".to_string()),
+            LogicalOperator::Eq,
+            Literal::ColumnReference("departments.employee_id".to_string()),
+        );
+        let key_positions =
+            HashJoinResultSet::key_positions(&on, employees.schema(), departments.schema());
+        assert!(key_positions.is_none());
+    }
+
+    #[test]
+    fn no_key_positions_for_columns_of_different_types() {
+        let (employees, departments) = employees_and_departments();
+        let on = Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::ColumnReference("departments.name".to_string()),
+        );
+        let key_positions =
+            HashJoinResultSet::key_positions(&on, employees.schema(), departments.schema());
+        assert!(key_positions.is_none());
+    }
+
+    #[test]
+    fn no_key_positions_for_columns_of_the_same_side() {
+        let (employees, departments) = employees_and_departments();
+        let on = Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::ColumnReference("employees.name".to_string()),
+        );
+        let key_positions =
+            HashJoinResultSet::key_positions(&on, employees.schema(), departments.schema());
+        assert!(key_positions.is_none());
+    }
+
+    #[test]
+    fn hash_join_result_set_with_error_in_left_iterator() {
+        let (_, departments) = employees_and_departments();
+        let join_result_set = HashJoinResultSet::new(
+            Box::new(ErrorResultSet {
+                schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            }),
+            departments,
+            0,
+            0,
+            JoinType::Inner,
+        );
+
+        let mut iterator = join_result_set.iterator().unwrap();
+        assert!(matches!(
+            iterator.next(),
+            Some(Err(ExecutionError::TypeMismatchInComparison))
+        ));
+    }
+
+    #[test]
+    fn hash_join_result_set_with_error_in_right_iterator() {
+        let (employees, _) = employees_and_departments();
+        let join_result_set = HashJoinResultSet::new(
+            employees,
+            Box::new(InitErrorResultSet {
+                schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            }),
+            0,
+            0,
+            JoinType::Inner,
+        );
+
+        assert!(join_result_set.iterator().is_err());
+    }
+
+    #[test]
+    fn column_names_of_hash_joined_result_sets() {
+        let (employees, departments) = employees_and_departments();
+        let join_result_set = HashJoinResultSet::new(employees, departments, 0, 0, JoinType::Inner);
+
+        assert_eq!(
+            vec![
+                "employees.id",
+                "employees.name",
+                "departments.employee_id",
+                "departments.name"
+            ],
+            join_result_set.column_names()
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::catalog::table::Table;
+use crate::catalog::table_entry::TableEntry;
+use crate::query::executor::error::ExecutionError;
+use crate::query::executor::result_set::{ResultSet, RowViewResult};
+use crate::schema::Schema;
+use crate::storage::row_filter::RowFilter;
+use crate::storage::row_view::RowView;
+use crate::storage::table_store::RowId;
+use std::sync::Arc;
+
+/// A `ResultSet` implementation that reads the rows of a table found in an index, instead of
+/// scanning the entire table.
+///
+/// `IndexScanResultSet` holds the `RowId`s looked up in the index (for an equality on the indexed
+/// column), and fetches the rows on demand. The filter is evaluated on each fetched row, so the
+/// rows produced are the ones a `ScanResultsSet` with the same filter would produce, in the same
+/// order (the order of the `RowId`s).
+///
+/// An optional limit (pushed down by the optimizer) stops the scan once that many rows
+/// have been produced.
+pub struct IndexScanResultSet<F: RowFilter> {
+    table_entry: Arc<TableEntry>,
+    row_ids: Vec<RowId>,
+    filter: F,
+    visible_positions: Arc<Vec<usize>>,
+    prefixed_schema: Schema,
+    limit: Option<usize>,
+}
+
+impl<F: RowFilter> IndexScanResultSet<F> {
+    /// Creates a new `IndexScanResultSet` for the given table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_entry` - The owner of the table data.
+    /// * `row_ids` - The `RowId`s looked up in the index, in ascending order.
+    /// * `filter` - The filter of the scan.
+    /// * `table` - The metadata of the table (schema, etc.).
+    /// * `alias` - The optional alias for the table.
+    pub(crate) fn new(
+        table_entry: Arc<TableEntry>,
+        row_ids: Vec<RowId>,
+        filter: F,
+        table: Arc<Table>,
+        alias: Option<String>,
+    ) -> Self {
+        let base_schema = table.schema_ref();
+        let column_positions = (0..base_schema.column_count()).collect();
+        let prefix = alias.unwrap_or_else(|| table.name().to_string());
+        let prefixed_schema = base_schema.with_prefix(&prefix);
+
+        Self {
+            table_entry,
+            row_ids,
+            filter,
+            visible_positions: Arc::new(column_positions),
+            prefixed_schema,
+            limit: None,
+        }
+    }
+
+    /// Sets the maximum number of rows produced by the scan.
+    ///
+    /// The limit applies after the filter.
+    pub(crate) fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl<F: RowFilter + 'static> ResultSet for IndexScanResultSet<F>
+where
+    ExecutionError: From<F::Error>,
+{
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        // A row deleted after the index lookup is skipped.
+        let rows = self
+            .row_ids
+            .iter()
+            .filter_map(|row_id| self.table_entry.get(*row_id))
+            .filter_map(|row| match self.filter.matches(&row) {
+                Ok(true) => Some(Ok(row)),
+                Ok(false) => None,
+                Err(error) => Some(Err(ExecutionError::from(error))),
+            })
+            .take(self.limit.unwrap_or(usize::MAX));
+        Ok(Box::new(rows.map(move |row| {
+            Ok(RowView::new(
+                row?,
+                &self.prefixed_schema,
+                &self.visible_positions,
+            ))
+        })))
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.prefixed_schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::row::Row;
+    use crate::storage::row_filter::NoFilter;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, rows, schema};
+    use std::convert::Infallible;
+
+    fn employees() -> (Arc<TableEntry>, Arc<Table>) {
+        let table_entry = TableEntry::new(Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+        ));
+        table_entry
+            .insert_all(rows![[1, 10], [2, 20], [3, 10], [4, 10]].into())
+            .unwrap();
+        let table = table_entry.table();
+        (table_entry, table)
+    }
+
+    #[test]
+    fn index_scan_result_set() {
+        let (table_entry, table) = employees();
+        let result_set = IndexScanResultSet::new(table_entry, vec![1, 3], NoFilter, table, None);
+
+        let mut iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1, "department_id" => 10);
+        assert_next_row!(iterator.as_mut(), "id" => 3, "department_id" => 10);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn index_scan_result_set_with_a_filter() {
+        struct IdOver2;
+        impl RowFilter for IdOver2 {
+            type Error = Infallible;
+
+            fn matches(&self, row: &Row) -> Result<bool, Infallible> {
+                Ok(row.column_value_at(0).unwrap().int_value().unwrap() > 2)
+            }
+        }
+
+        let (table_entry, table) = employees();
+        let result_set =
+            IndexScanResultSet::new(table_entry, vec![1, 3, 4], IdOver2, table, Some("e".into()));
+
+        let mut iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "e.id" => 3);
+        assert_next_row!(iterator.as_mut(), "e.id" => 4);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn index_scan_result_set_with_a_limit() {
+        let (table_entry, table) = employees();
+        let result_set = IndexScanResultSet::new(table_entry, vec![1, 3, 4], NoFilter, table, None)
+            .with_limit(Some(1));
+
+        let mut iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn index_scan_result_set_skipping_deleted_rows() {
+        let (table_entry, table) = employees();
+        table_entry.delete_all(&[3]);
+        let result_set = IndexScanResultSet::new(table_entry, vec![1, 3, 4], NoFilter, table, None);
+
+        let mut iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1);
+        assert_next_row!(iterator.as_mut(), "id" => 4);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+}
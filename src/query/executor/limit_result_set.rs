@@ -24,7 +24,7 @@ impl LimitResultSet {
 }
 
 impl ResultSet for LimitResultSet {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
         let inner_iterator = self.inner.iterator()?;
         Ok(Box::new(inner_iterator.take(self.limit)))
     }
@@ -32,11 +32,16 @@ impl ResultSet for LimitResultSet {
     fn schema(&self) -> &Schema {
         self.inner.schema()
     }
+
+    fn column_names(&self) -> Vec<String> {
+        self.inner.column_names()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::query::executor::project_result_set::ProjectResultSet;
+    use crate::query::parser::projection::ProjectedColumn;
 
     use crate::catalog::table::Table;
     use crate::catalog::table_scan::TableScan;
@@ -98,7 +103,8 @@ mod tests {
 
         let table_scan = TableScan::new(Arc::new(table_store));
         let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
-        let projected_result_set = ProjectResultSet::new(result_set, &["id"]).unwrap();
+        let projected_result_set =
+            ProjectResultSet::new(result_set, &[ProjectedColumn::new("id")]).unwrap();
 
         let limit_result_set = LimitResultSet::new(Box::new(projected_result_set), 1);
         let mut iterator = limit_result_set.iterator().unwrap();
@@ -106,4 +112,22 @@ mod tests {
         assert_next_row!(iterator.as_mut(), "id" => 1, ! "name");
         assert_no_more_rows!(iterator.as_mut());
     }
+
+    #[test]
+    fn column_names_of_limit_result_set_over_a_projection() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_scan = TableScan::new(Arc::new(TableStore::new()));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+        let projected_result_set = Box::new(
+            ProjectResultSet::new(result_set, &[ProjectedColumn::aliased("id", "employee_id")])
+                .unwrap(),
+        );
+
+        let limit_result_set = LimitResultSet::new(projected_result_set, 1);
+
+        assert_eq!(vec!["employee_id"], limit_result_set.column_names());
+    }
 }
@@ -0,0 +1,175 @@
+use crate::query::executor::error::ExecutionError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The limits on the execution of a query, which protect against runaway queries (e.g., a
+/// Cartesian join of large tables).
+///
+/// - The maximum number of rows scanned counts the rows produced by the table scans; a table
+///   scanned more than once (e.g., both sides of a self join) counts every time.
+/// - The timeout is measured from the start of the iteration over the rows of the result.
+///
+/// The limits are checked as the rows are pulled, and apply to every iteration of the result
+/// separately. A query exceeding a limit fails with `ExecutionError::Budget`.
+///
+/// # Examples
+///
+/// ```
+/// use relop::client::ExecutionLimits;
+/// use std::time::Duration;
+///
+/// let limits = ExecutionLimits::new()
+///     .with_max_rows_scanned(10_000)
+///     .with_timeout(Duration::from_secs(1));
+/// assert_eq!(Some(10_000), limits.max_rows_scanned());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionLimits {
+    max_rows_scanned: Option<usize>,
+    timeout: Option<Duration>,
+}
+
+impl ExecutionLimits {
+    /// Creates `ExecutionLimits` without any limit.
+    pub fn new() -> ExecutionLimits {
+        Self::default()
+    }
+
+    /// Limits the number of rows the table scans of a query can produce.
+    pub fn with_max_rows_scanned(mut self, max_rows_scanned: usize) -> ExecutionLimits {
+        self.max_rows_scanned = Some(max_rows_scanned);
+        self
+    }
+
+    /// Limits the time the rows of a query can take to be produced.
+    pub fn with_timeout(mut self, timeout: Duration) -> ExecutionLimits {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the maximum number of rows scanned, if limited.
+    pub fn max_rows_scanned(&self) -> Option<usize> {
+        self.max_rows_scanned
+    }
+
+    /// Returns the timeout, if limited.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns `true` if there is no limit.
+    pub(crate) fn is_unlimited(&self) -> bool {
+        self.max_rows_scanned.is_none() && self.timeout.is_none()
+    }
+}
+
+/// The limit of `ExecutionLimits` which a query exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExceededLimit {
+    /// The table scans produced more rows than the maximum, containing the maximum.
+    MaxRowsScanned(usize),
+    /// The rows took longer than the timeout to be produced, containing the timeout.
+    Timeout(Duration),
+}
+
+/// The budget of a query execution: the rows scanned and the time elapsed so far, checked
+/// against the `ExecutionLimits`.
+pub(crate) struct Budget {
+    limits: ExecutionLimits,
+    rows_scanned: AtomicUsize,
+    started_at: Mutex<Instant>,
+}
+
+impl Budget {
+    /// Creates a `Budget` for the given limits, starting now.
+    pub(crate) fn new(limits: ExecutionLimits) -> Self {
+        Self {
+            limits,
+            rows_scanned: AtomicUsize::new(0),
+            started_at: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Restarts the budget: no rows scanned, and the time measured from now.
+    pub(crate) fn restart(&self) {
+        self.rows_scanned.store(0, Ordering::SeqCst);
+        *self.started_at.lock().unwrap() = Instant::now();
+    }
+
+    /// Charges a scanned row to the budget, returning `ExecutionError::Budget` if a limit is
+    /// exceeded.
+    pub(crate) fn charge_row(&self) -> Result<(), ExecutionError> {
+        let rows_scanned = self.rows_scanned.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(max_rows_scanned) = self.limits.max_rows_scanned {
+            if rows_scanned > max_rows_scanned {
+                return Err(ExecutionError::Budget(ExceededLimit::MaxRowsScanned(
+                    max_rows_scanned,
+                )));
+            }
+        }
+        if let Some(timeout) = self.limits.timeout {
+            if self.started_at.lock().unwrap().elapsed() > timeout {
+                return Err(ExecutionError::Budget(ExceededLimit::Timeout(timeout)));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlimited_execution_limits() {
+        assert!(ExecutionLimits::new().is_unlimited());
+        assert!(!ExecutionLimits::new()
+            .with_max_rows_scanned(1)
+            .is_unlimited());
+        assert!(!ExecutionLimits::new()
+            .with_timeout(Duration::from_secs(1))
+            .is_unlimited());
+    }
+
+    #[test]
+    fn charge_rows_within_the_budget() {
+        let budget = Budget::new(ExecutionLimits::new().with_max_rows_scanned(2));
+
+        assert!(budget.charge_row().is_ok());
+        assert!(budget.charge_row().is_ok());
+    }
+
+    #[test]
+    fn attempt_to_charge_rows_beyond_the_maximum() {
+        let budget = Budget::new(ExecutionLimits::new().with_max_rows_scanned(1));
+
+        assert!(budget.charge_row().is_ok());
+        assert!(matches!(
+            budget.charge_row(),
+            Err(ExecutionError::Budget(ExceededLimit::MaxRowsScanned(1)))
+        ));
+    }
+
+    #[test]
+    fn charge_rows_again_after_restart() {
+        let budget = Budget::new(ExecutionLimits::new().with_max_rows_scanned(1));
+
+        assert!(budget.charge_row().is_ok());
+        budget.restart();
+        assert!(budget.charge_row().is_ok());
+    }
+
+    #[test]
+    fn attempt_to_charge_a_row_after_the_timeout() {
+        let budget = Budget::new(ExecutionLimits::new().with_timeout(Duration::ZERO));
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert!(matches!(
+            budget.charge_row(),
+            Err(ExecutionError::Budget(ExceededLimit::Timeout(
+                Duration::ZERO
+            )))
+        ));
+    }
+}
@@ -1,36 +1,91 @@
+pub(crate) mod aggregate_result_set;
+pub(crate) mod budgeted_result_set;
+pub(crate) mod distinct_on_result_set;
 pub mod error;
+pub(crate) mod except_result_set;
 pub(crate) mod filter_result_set;
+pub(crate) mod hash_join_result_set;
+pub(crate) mod index_scan_result_set;
+pub(crate) mod intersect_result_set;
 pub(crate) mod limit_result_set;
+pub mod limits;
 pub(crate) mod nested_loop_join_result_set;
 pub(crate) mod ordering_result_set;
 pub(crate) mod project_result_set;
 pub mod result;
 pub mod result_set;
 pub(crate) mod scan_result_set;
+pub(crate) mod union_result_set;
+pub(crate) mod values_result_set;
 
 #[cfg(test)]
 pub(crate) mod test_utils;
 
+use crate::catalog::table::Table;
+use crate::catalog::table_entry::TableEntry;
 use crate::catalog::Catalog;
 use crate::query::executor::error::ExecutionError;
+use crate::query::executor::limits::{Budget, ExecutionLimits};
 use crate::query::executor::result::QueryResult;
+use crate::query::parser::ast::Literal;
+use crate::query::plan::predicate::{
+    CorrelatedSubquery, LogicalClause, MembershipValues, Predicate,
+};
 use crate::query::plan::LogicalPlan;
+use crate::schema::error::SchemaError;
+use crate::schema::primary_key::PrimaryKey;
+use crate::schema::Schema;
+use crate::storage::row::Row;
+use crate::storage::row_view::RowView;
+use crate::storage::table_store::RowId;
+use crate::types::column_type::ColumnType;
+use crate::types::column_value::ColumnValue;
+use crate::types::date::Date;
+use aggregate_result_set::AggregateResultSet;
+use budgeted_result_set::BudgetedResultSet;
+use distinct_on_result_set::DistinctOnResultSet;
+use except_result_set::ExceptResultSet;
 use filter_result_set::FilterResultSet;
+use hash_join_result_set::HashJoinResultSet;
+use index_scan_result_set::IndexScanResultSet;
+use intersect_result_set::IntersectResultSet;
 use limit_result_set::LimitResultSet;
 use nested_loop_join_result_set::NestedLoopJoinResultSet;
 use ordering_result_set::OrderingResultSet;
 use project_result_set::ProjectResultSet;
 use scan_result_set::ScanResultsSet;
+use std::collections::HashSet;
+use std::sync::Arc;
+use union_result_set::UnionResultSet;
+use values_result_set::ValuesResultSet;
 
 /// Executes logical plans against the catalog.
 pub(crate) struct Executor<'a> {
-    catalog: &'a Catalog,
+    catalog: &'a Arc<Catalog>,
+    scan_parallelism: usize,
+    budget: Option<Arc<Budget>>,
 }
 
 impl<'a> Executor<'a> {
-    /// Creates a new `Executor` with the given catalog.
-    pub(crate) fn new(catalog: &'a Catalog) -> Self {
-        Self { catalog }
+    /// Creates a new `Executor` with the given catalog, scanning the tables sequentially.
+    pub(crate) fn new(catalog: &'a Arc<Catalog>) -> Self {
+        Self {
+            catalog,
+            scan_parallelism: 1,
+            budget: None,
+        }
+    }
+
+    /// Sets the number of threads the filter of a table scan is evaluated across.
+    pub(crate) fn with_scan_parallelism(mut self, scan_parallelism: usize) -> Self {
+        self.scan_parallelism = scan_parallelism;
+        self
+    }
+
+    /// Sets the limits the select queries are executed within.
+    pub(crate) fn with_limits(mut self, limits: ExecutionLimits) -> Self {
+        self.budget = (!limits.is_unlimited()).then(|| Arc::new(Budget::new(limits)));
+        self
     }
 
     /// Executes the given logical plan and returns the result.
@@ -47,13 +102,466 @@ impl<'a> Executor<'a> {
 
                 Ok(QueryResult::TableDescription(table))
             }
+            LogicalPlan::Explain(plan) => Ok(QueryResult::Plan(plan.to_string())),
+            LogicalPlan::Insert {
+                table_name,
+                columns,
+                rows,
+            } => {
+                let (count, generated_ids) = self.execute_insert(&table_name, columns, rows)?;
+                Ok(QueryResult::RowsInserted {
+                    count,
+                    generated_ids,
+                })
+            }
+            LogicalPlan::Delete { table_name, filter } => {
+                let deleted = self.execute_delete(&table_name, filter)?;
+                Ok(QueryResult::RowsAffected(deleted))
+            }
+            LogicalPlan::CreateTable {
+                table_name,
+                columns,
+                primary_key,
+            } => {
+                let mut schema = columns
+                    .into_iter()
+                    .try_fold(Schema::new(), |schema, (column, column_type)| {
+                        schema.add_column(&column, column_type)
+                    })?;
+                if let Some(primary_key) = primary_key {
+                    schema = schema.add_primary_key(PrimaryKey::composite(primary_key))?;
+                }
+                self.catalog
+                    .create_table(&table_name, schema)
+                    .map_err(ExecutionError::Catalog)?;
+
+                Ok(QueryResult::TableCreated(table_name))
+            }
+            LogicalPlan::DropTable { table_name } => {
+                self.catalog
+                    .drop_table(&table_name)
+                    .map_err(ExecutionError::Catalog)?;
+
+                Ok(QueryResult::TableDropped(table_name))
+            }
+            LogicalPlan::Update {
+                table_name,
+                assignments,
+                filter,
+            } => {
+                let updated = self.execute_update(&table_name, assignments, filter)?;
+                Ok(QueryResult::RowsAffected(updated))
+            }
             _ => {
                 let result_set = self.execute_select(logical_plan)?;
+                let result_set = match &self.budget {
+                    Some(budget) => {
+                        Box::new(BudgetedResultSet::restarting(result_set, budget.clone()))
+                    }
+                    None => result_set,
+                };
                 Ok(QueryResult::ResultSet(result_set))
             }
         }
     }
 
+    /// Inserts the rows of values into the table and returns the number of inserted rows, along
+    /// with the values of the auto-increment column of the inserted rows (if the table has one).
+    ///
+    /// Values are placed at the positions of the given columns (all the columns of the table,
+    /// in order, if none are given). A column without a value is filled with its default value,
+    /// or `NULL` if it has none; a column which does not accept `NULL` and has no default value
+    /// must be given a value. An auto-increment column without a value receives the next
+    /// sequential value. The rows are type-checked against the schema of the table before
+    /// any of them is inserted.
+    fn execute_insert(
+        &self,
+        table_name: &str,
+        columns: Option<Vec<String>>,
+        rows: Vec<Vec<Literal>>,
+    ) -> Result<(usize, Vec<i64>), ExecutionError> {
+        let schema = self
+            .catalog
+            .schema_for(table_name)
+            .map_err(ExecutionError::Catalog)?;
+
+        let positions = match columns {
+            Some(columns) => {
+                let mut positions = Vec::with_capacity(columns.len());
+                for column in columns {
+                    let position = schema
+                        .column_position(&column)?
+                        .ok_or(ExecutionError::UnknownColumn(column.clone()))?;
+                    if positions.contains(&position) {
+                        return Err(ExecutionError::Schema(SchemaError::DuplicateColumnName(
+                            column,
+                        )));
+                    }
+                    positions.push(position);
+                }
+                positions
+            }
+            None => (0..schema.column_count()).collect(),
+        };
+
+        let mut omitted_values = Vec::with_capacity(schema.column_count());
+        for (position, column) in schema.columns().iter().enumerate() {
+            if positions.contains(&position) || column.is_auto_increment() {
+                omitted_values.push(ColumnValue::Null);
+                continue;
+            }
+            match column.default_value() {
+                Some(default_value) => omitted_values.push(default_value.clone()),
+                None if column.is_nullable() => omitted_values.push(ColumnValue::Null),
+                None => {
+                    return Err(ExecutionError::MissingColumnValue(
+                        column.name().to_string(),
+                    ))
+                }
+            }
+        }
+
+        let rows = rows
+            .into_iter()
+            .map(|values| {
+                if values.len() != positions.len() {
+                    return Err(ExecutionError::Schema(SchemaError::ColumnCountMismatch {
+                        expected: positions.len(),
+                        actual: values.len(),
+                    }));
+                }
+                let mut column_values = omitted_values.clone();
+                for (position, value) in positions.iter().zip(values) {
+                    column_values[*position] =
+                        Self::coerce(Self::column_value(value)?, schema.column_type_at(*position))?;
+                }
+                Ok(Row::filled(column_values))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let row_ids = self
+            .catalog
+            .insert_all_into(table_name, rows)
+            .map_err(ExecutionError::Insert)?;
+
+        let generated_ids = match schema.auto_increment_column_position() {
+            Some(position) => {
+                let (table_entry, _) = self
+                    .catalog
+                    .scan(table_name)
+                    .map_err(ExecutionError::Catalog)?;
+                row_ids
+                    .iter()
+                    .filter_map(|row_id| table_entry.get(*row_id))
+                    .filter_map(|row| match row.column_value_at(position) {
+                        Some(ColumnValue::Int(value)) => Some(*value),
+                        _ => None,
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+        Ok((row_ids.len(), generated_ids))
+    }
+
+    /// Deletes the rows of the table matching the filter (all the rows without it) and returns
+    /// the number of deleted rows.
+    ///
+    /// The `RowId`s of the matching rows are collected first, and deleted after the scan.
+    fn execute_delete(
+        &self,
+        table_name: &str,
+        filter: Option<Predicate>,
+    ) -> Result<usize, ExecutionError> {
+        let row_ids = self
+            .matching_rows(table_name, filter)?
+            .into_iter()
+            .map(|(row_id, _)| row_id)
+            .collect::<Vec<_>>();
+
+        self.catalog
+            .delete_from(table_name, &row_ids)
+            .map_err(ExecutionError::Catalog)
+    }
+
+    /// Updates the rows of the table matching the filter (all the rows without it) and returns
+    /// the number of updated rows.
+    ///
+    /// The assignments are type-checked against the schema of the table before the scan, and
+    /// the matching rows are collected first (evaluating the filter on their old values), and
+    /// replaced after the scan.
+    fn execute_update(
+        &self,
+        table_name: &str,
+        assignments: Vec<(String, Literal)>,
+        filter: Option<Predicate>,
+    ) -> Result<usize, ExecutionError> {
+        let schema = self
+            .catalog
+            .schema_for(table_name)
+            .map_err(ExecutionError::Catalog)?;
+
+        let mut values_by_position: Vec<(usize, ColumnValue)> =
+            Vec::with_capacity(assignments.len());
+        for (column, literal) in assignments {
+            let position = schema
+                .column_position(&column)?
+                .ok_or(ExecutionError::UnknownColumn(column.clone()))?;
+            if values_by_position
+                .iter()
+                .any(|(existing, _)| *existing == position)
+            {
+                return Err(ExecutionError::Schema(SchemaError::DuplicateColumnName(
+                    column,
+                )));
+            }
+            let value = Self::coerce(
+                Self::column_value(literal)?,
+                schema.column_type_at(position),
+            )?;
+            if let (Some(expected), Some(actual)) =
+                (schema.column_type_at(position), value.column_type())
+            {
+                if *expected != actual {
+                    return Err(ExecutionError::Schema(SchemaError::ColumnTypeMismatch {
+                        column,
+                        expected: expected.clone(),
+                        actual,
+                    }));
+                }
+            }
+            values_by_position.push((position, value));
+        }
+
+        let rows = self
+            .matching_rows(table_name, filter)?
+            .into_iter()
+            .map(|(row_id, row)| {
+                let mut column_values = row.column_values().to_vec();
+                for (position, value) in &values_by_position {
+                    column_values[*position] = value.clone();
+                }
+                (row_id, Row::filled(column_values))
+            })
+            .collect::<Vec<_>>();
+
+        self.catalog
+            .update_in(table_name, rows)
+            .map_err(ExecutionError::Update)
+    }
+
+    /// Returns the rows of the table (along with their `RowId`s) matching the filter, all the
+    /// rows without it.
+    ///
+    /// The filter is evaluated exactly as a `select` evaluates its `where` clause, and an error
+    /// in evaluating it (e.g., a type mismatch) is returned rather than treated as no match.
+    fn matching_rows(
+        &self,
+        table_name: &str,
+        filter: Option<Predicate>,
+    ) -> Result<Vec<(RowId, Row)>, ExecutionError> {
+        let (table_entry, table) = self
+            .catalog
+            .scan(table_name)
+            .map_err(ExecutionError::Catalog)?;
+
+        match filter {
+            Some(predicate) => {
+                let prefixed_schema = table.schema_ref().with_prefix(table.name());
+                let bound_predicate = self
+                    .evaluate_subqueries(predicate)?
+                    .bind(&prefixed_schema)?;
+                table_entry
+                    .scan_with_filter(bound_predicate)
+                    .iter_with_ids()
+                    .collect()
+            }
+            None => Ok(table_entry
+                .scan()
+                .iter_with_ids()
+                .map(|entry| entry.unwrap_or_else(|infallible| match infallible {}))
+                .collect()),
+        }
+    }
+
+    /// Returns whether the given plan produces at least one row, stopping at the first row.
+    pub(crate) fn has_rows(&self, plan: LogicalPlan) -> Result<bool, ExecutionError> {
+        let result_set = self.execute_select(plan)?;
+        let mut row_iterator = result_set.iterator()?;
+        match row_iterator.next() {
+            Some(row_view) => row_view.map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Looks up the rows which may match the predicate in an index of the table, if the predicate
+    /// requires an equality between each primary key column and a constant (e.g., `id = 2`), or
+    /// between an indexed column and a constant.
+    ///
+    /// The primary key index is preferred, as it finds at most one row. Returns the `RowId`s of
+    /// the rows having the constants in the columns, or `None` if neither index applies (the
+    /// table is then scanned).
+    fn index_lookup(
+        predicate: &Predicate,
+        table_entry: &TableEntry,
+        table: &Table,
+        prefixed_schema: &Schema,
+    ) -> Option<Vec<RowId>> {
+        let values_by_column = predicate
+            .equality_constants()
+            .into_iter()
+            .filter_map(|(column_name, value)| {
+                let position = prefixed_schema.column_position(column_name).ok()??;
+                let column = &table.schema_ref().columns()[position];
+                column
+                    .column_type()
+                    .accepts(&value)
+                    .then(|| (column.name(), value))
+            })
+            .collect::<Vec<_>>();
+
+        table_entry
+            .primary_key_lookup(&values_by_column)
+            .or_else(|| {
+                values_by_column
+                    .iter()
+                    .find_map(|(column_name, value)| table_entry.index_lookup(column_name, value))
+            })
+    }
+
+    /// Replaces the (planned) subqueries in the predicate with their values, so that each
+    /// subquery is executed once, before any row is filtered.
+    ///
+    /// A correlated subquery (`EXISTS`) depends on the row being filtered, so it is only made
+    /// executable here, and is executed once per filtered row.
+    fn evaluate_subqueries(&self, predicate: Predicate) -> Result<Predicate, ExecutionError> {
+        predicate.try_map_clauses(&mut |clause| match clause {
+            LogicalClause::Exists {
+                subquery: CorrelatedSubquery::Planned { plan, correlations },
+            } => Ok(LogicalClause::Exists {
+                subquery: CorrelatedSubquery::Executable {
+                    plan,
+                    correlations,
+                    catalog: Arc::clone(self.catalog),
+                },
+            }),
+            LogicalClause::In {
+                lhs,
+                values: MembershipValues::PlannedSubquery(plan),
+                negated,
+            } => Ok(LogicalClause::In {
+                lhs: self.evaluate_subquery(lhs)?,
+                values: MembershipValues::Values(self.evaluate_membership_values(*plan)?),
+                negated,
+            }),
+            clause => clause.try_map_literals(&mut |literal| self.evaluate_subquery(literal)),
+        })
+    }
+
+    /// Executes the subquery of a membership clause (`IN`) and collects its distinct values.
+    fn evaluate_membership_values(
+        &self,
+        plan: LogicalPlan,
+    ) -> Result<HashSet<ColumnValue>, ExecutionError> {
+        let result_set = self.execute_select(plan)?;
+        let values = result_set
+            .iterator()?
+            .map(|row_view| Self::first_visible_value(row_view?))
+            .collect::<Result<HashSet<_>, _>>()?;
+        Ok(values)
+    }
+
+    /// Returns the value of the first visible column of the row, the single column selected by
+    /// a subquery.
+    fn first_visible_value(row_view: RowView) -> Result<ColumnValue, ExecutionError> {
+        row_view
+            .to_row()
+            .column_value_at(0)
+            .cloned()
+            .ok_or(ExecutionError::ColumnIndexOutOfBounds(0))
+    }
+
+    /// Replaces the subqueries in the literal (including the ones in the operands of an
+    /// arithmetic expression and in the arguments of a function call) with their values.
+    ///
+    /// A subquery is used as a single value, so it must produce exactly one row.
+    fn evaluate_subquery(&self, literal: Literal) -> Result<Literal, ExecutionError> {
+        match literal {
+            Literal::PlannedSubquery(plan) => {
+                let result_set = self.execute_select(*plan)?;
+                let mut row_iterator = result_set.iterator()?;
+                let row_view = row_iterator
+                    .next()
+                    .ok_or(ExecutionError::ScalarSubqueryWithoutRows)??;
+                let value = Self::first_visible_value(row_view)?;
+                if row_iterator.next().is_some() {
+                    return Err(ExecutionError::ScalarSubqueryWithMultipleRows);
+                }
+                Ok(Literal::from(value))
+            }
+            Literal::Arithmetic { lhs, operator, rhs } => Ok(Literal::arithmetic(
+                self.evaluate_subquery(*lhs)?,
+                operator,
+                self.evaluate_subquery(*rhs)?,
+            )),
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => Ok(Literal::function_call(
+                function,
+                arguments
+                    .into_iter()
+                    .map(|argument| self.evaluate_subquery(argument))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            _ => Ok(literal),
+        }
+    }
+
+    /// Reads a text given for a `Date` column as a date (e.g., `'2024-01-15'`), returning an
+    /// error if it is not one. Other values are returned as they are.
+    fn coerce(
+        value: ColumnValue,
+        column_type: Option<&ColumnType>,
+    ) -> Result<ColumnValue, ExecutionError> {
+        match (value, column_type) {
+            (ColumnValue::Text(text), Some(ColumnType::Date)) => Date::parse(&text)
+                .map(ColumnValue::Date)
+                .ok_or(ExecutionError::InvalidDate(text)),
+            (value, _) => Ok(value),
+        }
+    }
+
+    fn column_value(literal: Literal) -> Result<ColumnValue, ExecutionError> {
+        match literal {
+            Literal::Int(value) => Ok(ColumnValue::Int(value)),
+            Literal::Text(value) => Ok(ColumnValue::Text(value)),
+            Literal::Bool(value) => Ok(ColumnValue::Bool(value)),
+            Literal::Date(value) => Ok(ColumnValue::Date(value)),
+            Literal::Null => Ok(ColumnValue::Null),
+            Literal::ColumnReference(column_name) => {
+                Err(ExecutionError::UnboundColumn(column_name))
+            }
+            Literal::ColumnIndex(index) => Err(ExecutionError::ColumnIndexOutOfBounds(index)),
+            Literal::Arithmetic { lhs, operator, rhs } => {
+                operator.apply(&Self::column_value(*lhs)?, &Self::column_value(*rhs)?)
+            }
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => function.apply(
+                &arguments
+                    .into_iter()
+                    .map(Self::column_value)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Literal::Parameter(index) => Err(ExecutionError::UnboundParameter(index)),
+            Literal::Subquery(_) | Literal::PlannedSubquery(_) => {
+                Err(ExecutionError::UnevaluatedSubquery)
+            }
+        }
+    }
+
     /// Executes the logical plan for select queries and returns the result.
     fn execute_select(
         &self,
@@ -65,6 +573,7 @@ impl<'a> Executor<'a> {
                 alias,
                 filter,
                 schema: _,
+                limit,
             } => {
                 let (table_entry, table) = self
                     .catalog
@@ -75,28 +584,83 @@ impl<'a> Executor<'a> {
                     Some(predicate) => {
                         let prefix = alias.clone().unwrap_or_else(|| table.name().to_string());
                         let prefixed_schema = table.schema_ref().with_prefix(&prefix);
-                        let bound_predicate = predicate.bind(&prefixed_schema)?;
-
-                        Box::new(ScanResultsSet::new(
-                            table_entry.scan_with_filter(bound_predicate),
-                            table,
-                            alias,
-                        ))
+                        let index_lookup =
+                            Self::index_lookup(&predicate, &table_entry, &table, &prefixed_schema);
+                        let bound_predicate = self
+                            .evaluate_subqueries(predicate)?
+                            .bind(&prefixed_schema)?;
+
+                        match index_lookup {
+                            Some(row_ids) => Box::new(
+                                IndexScanResultSet::new(
+                                    table_entry,
+                                    row_ids,
+                                    bound_predicate,
+                                    table,
+                                    alias,
+                                )
+                                .with_limit(limit),
+                            ),
+                            None => Box::new(
+                                ScanResultsSet::new(
+                                    table_entry.scan_with_filter(bound_predicate),
+                                    table,
+                                    alias,
+                                )
+                                .with_limit(limit)
+                                .with_parallelism(self.scan_parallelism),
+                            ),
+                        }
                     }
                     None => {
                         let table_scan = table_entry.scan();
-                        Box::new(ScanResultsSet::new(table_scan, table, alias))
+                        Box::new(ScanResultsSet::new(table_scan, table, alias).with_limit(limit))
                     }
                 };
-                Ok(result_set)
+                match &self.budget {
+                    Some(budget) => Ok(Box::new(BudgetedResultSet::charging(
+                        result_set,
+                        budget.clone(),
+                    ))),
+                    None => Ok(result_set),
+                }
             }
-            LogicalPlan::Join { left, right, on } => {
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                join_type,
+            } => {
                 let left_result_set = self.execute_select(*left)?;
                 let right_result_set = self.execute_select(*right)?;
+                let on = on
+                    .map(|predicate| self.evaluate_subqueries(predicate))
+                    .transpose()?;
+
+                let key_positions = on
+                    .as_ref()
+                    .filter(|_| HashJoinResultSet::supports(join_type))
+                    .and_then(|on| {
+                        HashJoinResultSet::key_positions(
+                            on,
+                            left_result_set.schema(),
+                            right_result_set.schema(),
+                        )
+                    });
+                if let Some((left_key_position, right_key_position)) = key_positions {
+                    return Ok(Box::new(HashJoinResultSet::new(
+                        left_result_set,
+                        right_result_set,
+                        left_key_position,
+                        right_key_position,
+                        join_type,
+                    )));
+                }
                 Ok(Box::new(NestedLoopJoinResultSet::new(
                     left_result_set,
                     right_result_set,
                     on,
+                    join_type,
                 )))
             }
             LogicalPlan::Filter {
@@ -104,6 +668,7 @@ impl<'a> Executor<'a> {
                 predicate,
             } => {
                 let result_set = self.execute_select(*base)?;
+                let predicate = self.evaluate_subqueries(predicate)?;
                 Ok(Box::new(FilterResultSet::new(result_set, predicate)))
             }
             LogicalPlan::Projection {
@@ -114,6 +679,14 @@ impl<'a> Executor<'a> {
                 let project_result_set = ProjectResultSet::new(result_set, &columns[..])?;
                 Ok(Box::new(project_result_set))
             }
+            LogicalPlan::QualifiedProjection {
+                base_plan: base,
+                qualifier,
+            } => {
+                let result_set = self.execute_select(*base)?;
+                let project_result_set = ProjectResultSet::qualified(result_set, &qualifier)?;
+                Ok(Box::new(project_result_set))
+            }
             LogicalPlan::Sort {
                 base_plan: base,
                 ordering_keys,
@@ -123,6 +696,20 @@ impl<'a> Executor<'a> {
                 let ordering_result_set = OrderingResultSet::new(result_set, ordering_keys, limit);
                 Ok(Box::new(ordering_result_set))
             }
+            LogicalPlan::DistinctOn {
+                base_plan: base,
+                columns,
+            } => {
+                let result_set = self.execute_select(*base)?;
+                Ok(Box::new(DistinctOnResultSet::new(result_set, &columns)?))
+            }
+            LogicalPlan::Aggregate {
+                base_plan: base,
+                aggregates,
+            } => {
+                let result_set = self.execute_select(*base)?;
+                Ok(Box::new(AggregateResultSet::new(result_set, &aggregates)?))
+            }
             LogicalPlan::Limit {
                 base_plan: base,
                 count,
@@ -130,6 +717,48 @@ impl<'a> Executor<'a> {
                 let result_set = self.execute_select(*base)?;
                 Ok(Box::new(LimitResultSet::new(result_set, count)))
             }
+            LogicalPlan::Union { all, left, right } => {
+                let left_result_set = self.execute_select(*left)?;
+                let right_result_set = self.execute_select(*right)?;
+                Ok(Box::new(UnionResultSet::new(
+                    left_result_set,
+                    right_result_set,
+                    all,
+                )))
+            }
+            LogicalPlan::Intersect { left, right } => {
+                let left_result_set = self.execute_select(*left)?;
+                let right_result_set = self.execute_select(*right)?;
+                Ok(Box::new(IntersectResultSet::new(
+                    left_result_set,
+                    right_result_set,
+                )))
+            }
+            LogicalPlan::Except { left, right } => {
+                let left_result_set = self.execute_select(*left)?;
+                let right_result_set = self.execute_select(*right)?;
+                Ok(Box::new(ExceptResultSet::new(
+                    left_result_set,
+                    right_result_set,
+                )))
+            }
+            LogicalPlan::Values { rows, schema } => {
+                let rows = rows
+                    .into_iter()
+                    .map(|values| {
+                        values
+                            .into_iter()
+                            .map(Self::column_value)
+                            .collect::<Result<Vec<_>, _>>()
+                            .map(Row::filled)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Box::new(ValuesResultSet::new(rows, schema)))
+            }
+            LogicalPlan::SingleRow => Ok(Box::new(ValuesResultSet::new(
+                vec![Row::filled(Vec::new())],
+                Arc::new(Schema::new()),
+            ))),
             _ => panic!("should not be here"),
         }
     }
@@ -138,13 +767,151 @@ impl<'a> Executor<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::catalog::error::CatalogError;
-    use crate::query::parser::ast::Literal;
-    use crate::query::plan::predicate::{LogicalOperator, Predicate};
+    use crate::catalog::error::{CatalogError, InsertError};
+    use crate::query::parser::ast::JoinType;
+    use crate::query::plan::predicate::LogicalOperator;
+    use crate::schema::column::Column;
+    use crate::schema::Schema;
     use crate::test_utils::{insert_row, insert_rows};
     use crate::types::column_type::ColumnType;
     use crate::{asc, assert_next_row, assert_no_more_rows, desc, row, rows, schema};
 
+    #[test]
+    fn index_lookup_for_equality_with_constant_on_indexed_column() {
+        let catalog = Catalog::new();
+        catalog
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        insert_rows(&catalog, "employees", rows![[1, 10], [2, 20], [3, 10]]);
+        catalog.create_index("employees", "department_id").unwrap();
+
+        let (table_entry, table) = catalog.scan("employees").unwrap();
+        let prefixed_schema = table.schema_ref().with_prefix("e");
+        let predicate = Predicate::And(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("e.id".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(1),
+            ),
+            Predicate::comparison(
+                Literal::ColumnReference("e.department_id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(10),
+            ),
+        ]);
+
+        assert_eq!(
+            Some(vec![1, 3]),
+            Executor::index_lookup(&predicate, &table_entry, &table, &prefixed_schema)
+        );
+    }
+
+    #[test]
+    fn index_lookup_for_equality_with_constant_on_primary_key() {
+        let catalog = Catalog::new();
+        catalog
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int]
+                    .unwrap()
+                    .add_primary_key(PrimaryKey::single("id"))
+                    .unwrap(),
+            )
+            .unwrap();
+        insert_rows(&catalog, "employees", rows![[1, 10], [2, 20], [3, 10]]);
+
+        let (table_entry, table) = catalog.scan("employees").unwrap();
+        let prefixed_schema = table.schema_ref().with_prefix("employees");
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Int(2),
+        );
+
+        assert_eq!(
+            Some(vec![2]),
+            Executor::index_lookup(&predicate, &table_entry, &table, &prefixed_schema)
+        );
+    }
+
+    #[test]
+    fn index_lookup_for_equality_with_constant_on_primary_key_without_a_matching_row() {
+        let catalog = Catalog::new();
+        catalog
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int]
+                    .unwrap()
+                    .add_primary_key(PrimaryKey::single("id"))
+                    .unwrap(),
+            )
+            .unwrap();
+        insert_rows(&catalog, "employees", rows![[1]]);
+
+        let (table_entry, table) = catalog.scan("employees").unwrap();
+        let prefixed_schema = table.schema_ref().with_prefix("employees");
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Int(5),
+        );
+
+        assert_eq!(
+            Some(vec![]),
+            Executor::index_lookup(&predicate, &table_entry, &table, &prefixed_schema)
+        );
+    }
+
+    #[test]
+    fn no_index_lookup_for_equality_on_a_part_of_a_composite_primary_key() {
+        let catalog = Catalog::new();
+        catalog
+            .create_table(
+                "employees",
+                schema!["department_id" => ColumnType::Int, "id" => ColumnType::Int]
+                    .unwrap()
+                    .add_primary_key(PrimaryKey::composite(["department_id", "id"]))
+                    .unwrap(),
+            )
+            .unwrap();
+        insert_rows(&catalog, "employees", rows![[10, 1], [20, 1]]);
+
+        let (table_entry, table) = catalog.scan("employees").unwrap();
+        let prefixed_schema = table.schema_ref().with_prefix("employees");
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Int(1),
+        );
+
+        assert!(
+            Executor::index_lookup(&predicate, &table_entry, &table, &prefixed_schema).is_none()
+        );
+    }
+
+    #[test]
+    fn no_index_lookup_for_equality_on_non_indexed_column() {
+        let catalog = Catalog::new();
+        catalog
+            .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+
+        let (table_entry, table) = catalog.scan("employees").unwrap();
+        let prefixed_schema = table.schema_ref().with_prefix("employees");
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Int(1),
+        );
+
+        assert!(
+            Executor::index_lookup(&predicate, &table_entry, &table, &prefixed_schema).is_none()
+        );
+    }
+
     #[test]
     fn execute_show_tables() {
         let catalog = Catalog::new();
@@ -509,6 +1276,7 @@ mod tests {
                 alias: Some("e".to_string()),
                 filter: None,
                 schema: std::sync::Arc::new(crate::schema::Schema::new()),
+                limit: None,
             })
             .unwrap();
 
@@ -544,6 +1312,7 @@ mod tests {
                 left: LogicalPlan::scan("employees").boxed(),
                 right: LogicalPlan::scan("departments").boxed(),
                 on: None,
+                join_type: JoinType::Inner,
             })
             .unwrap();
 
@@ -579,6 +1348,7 @@ mod tests {
                 alias: Some("e".to_string()),
                 filter: None,
                 schema: std::sync::Arc::new(crate::schema::Schema::new()),
+                limit: None,
             }
             .boxed(),
             right: LogicalPlan::Scan {
@@ -586,6 +1356,7 @@ mod tests {
                 alias: Some("d".to_string()),
                 filter: None,
                 schema: std::sync::Arc::new(crate::schema::Schema::new()),
+                limit: None,
             }
             .boxed(),
             on: Some(Predicate::comparison(
@@ -593,6 +1364,7 @@ mod tests {
                 LogicalOperator::Eq,
                 Literal::ColumnReference("d.id".to_string()),
             )),
+            join_type: JoinType::Inner,
         };
 
         let outer_join = LogicalPlan::Join {
@@ -602,6 +1374,7 @@ mod tests {
                 alias: Some("l".to_string()),
                 filter: None,
                 schema: std::sync::Arc::new(crate::schema::Schema::new()),
+                limit: None,
             }
             .boxed(),
             on: Some(Predicate::comparison(
@@ -609,6 +1382,7 @@ mod tests {
                 LogicalOperator::Eq,
                 Literal::ColumnReference("l.id".to_string()),
             )),
+            join_type: JoinType::Inner,
         };
 
         let query_result = executor.execute(outer_join).unwrap();
@@ -632,6 +1406,7 @@ mod tests {
                 alias: Some("emp1".to_string()),
                 filter: None,
                 schema: std::sync::Arc::new(crate::schema::Schema::new()),
+                limit: None,
             }
             .boxed(),
             right: LogicalPlan::Scan {
@@ -639,6 +1414,7 @@ mod tests {
                 alias: Some("emp2".to_string()),
                 filter: None,
                 schema: std::sync::Arc::new(crate::schema::Schema::new()),
+                limit: None,
             }
             .boxed(),
             on: Some(Predicate::comparison(
@@ -646,6 +1422,7 @@ mod tests {
                 LogicalOperator::Eq,
                 Literal::ColumnReference("emp2.id".to_string()),
             )),
+            join_type: JoinType::Inner,
         };
 
         let query_result = executor.execute(join_plan).unwrap();
@@ -654,4 +1431,628 @@ mod tests {
         assert_next_row!(row_iterator.as_mut(), "emp1.id" => 2, "emp2.id" => 2);
         assert_no_more_rows!(row_iterator.as_mut());
     }
+
+    #[test]
+    fn execute_insert() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor
+            .execute(LogicalPlan::Insert {
+                table_name: "employees".to_string(),
+                columns: None,
+                rows: vec![
+                    vec![Literal::Int(1), Literal::Text("relop".to_string())],
+                    vec![Literal::Int(2), Literal::Text("query".to_string())],
+                ],
+            })
+            .unwrap();
+        assert_eq!(Some(2), query_result.rows_inserted());
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_insert_with_columns_in_a_different_order_and_a_missing_column() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "active" => ColumnType::Bool].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor
+            .execute(LogicalPlan::Insert {
+                table_name: "employees".to_string(),
+                columns: Some(vec!["name".to_string(), "id".to_string()]),
+                rows: vec![vec![Literal::Text("relop".to_string()), Literal::Int(1)]],
+            })
+            .unwrap();
+        assert_eq!(Some(1), query_result.rows_inserted());
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+
+        let row_view = row_iterator.next().unwrap().unwrap();
+        assert_eq!(
+            Some(&ColumnValue::int(1)),
+            row_view.column_value_by("id").unwrap()
+        );
+        assert_eq!(
+            Some(&ColumnValue::text("relop")),
+            row_view.column_value_by("name").unwrap()
+        );
+        assert!(row_view.is_null("active").unwrap());
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    fn employees_with_defaults(catalog: &Catalog) {
+        let schema = Schema::new()
+            .add_column_definition(Column::new("id", ColumnType::Int).not_null())
+            .unwrap()
+            .add_column_definition(
+                Column::new("name", ColumnType::Text).with_default(ColumnValue::text("unknown")),
+            )
+            .unwrap()
+            .add_column_definition(
+                Column::new("active", ColumnType::Bool)
+                    .not_null()
+                    .with_default(ColumnValue::bool(true)),
+            )
+            .unwrap();
+        catalog.create_table("employees", schema).unwrap();
+    }
+
+    #[test]
+    fn execute_insert_filling_omitted_columns_with_default_values() {
+        let catalog = Catalog::new();
+        employees_with_defaults(&catalog);
+
+        let executor = Executor::new(&catalog);
+        executor
+            .execute(LogicalPlan::Insert {
+                table_name: "employees".to_string(),
+                columns: Some(vec!["id".to_string()]),
+                rows: vec![vec![Literal::Int(1)]],
+            })
+            .unwrap();
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "unknown", "active" => true);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_insert_overriding_default_values() {
+        let catalog = Catalog::new();
+        employees_with_defaults(&catalog);
+
+        let executor = Executor::new(&catalog);
+        executor
+            .execute(LogicalPlan::Insert {
+                table_name: "employees".to_string(),
+                columns: Some(vec![
+                    "id".to_string(),
+                    "name".to_string(),
+                    "active".to_string(),
+                ]),
+                rows: vec![vec![
+                    Literal::Int(1),
+                    Literal::Text("relop".to_string()),
+                    Literal::Bool(false),
+                ]],
+            })
+            .unwrap();
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop", "active" => false);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_omitting_a_required_column() {
+        let catalog = Catalog::new();
+        employees_with_defaults(&catalog);
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Insert {
+            table_name: "employees".to_string(),
+            columns: Some(vec!["name".to_string()]),
+            rows: vec![vec![Literal::Text("relop".to_string())]],
+        });
+
+        assert!(
+            matches!(query_result, Err(ExecutionError::MissingColumnValue(column)) if column == "id")
+        );
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_with_null_in_a_not_null_column() {
+        let catalog = Catalog::new();
+        employees_with_defaults(&catalog);
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Insert {
+            table_name: "employees".to_string(),
+            columns: Some(vec!["id".to_string()]),
+            rows: vec![vec![Literal::Null]],
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Insert(InsertError::Schema(SchemaError::NullValueInNotNullColumn(column)))) if column == "id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_with_a_type_mismatch() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Insert {
+            table_name: "employees".to_string(),
+            columns: None,
+            rows: vec![
+                vec![Literal::Int(1)],
+                vec![Literal::Text("two".to_string())],
+            ],
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Insert(InsertError::Schema(SchemaError::ColumnTypeMismatch { column, .. }))) if column == "id"
+        ));
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_with_a_value_count_mismatch() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Insert {
+            table_name: "employees".to_string(),
+            columns: None,
+            rows: vec![vec![Literal::Int(1)]],
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Schema(SchemaError::ColumnCountMismatch {
+                expected: 2,
+                actual: 1
+            }))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_with_an_unknown_column() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Insert {
+            table_name: "employees".to_string(),
+            columns: Some(vec!["salary".to_string()]),
+            rows: vec![vec![Literal::Int(1)]],
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::UnknownColumn(column)) if column == "salary"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_with_a_duplicate_column() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Insert {
+            table_name: "employees".to_string(),
+            columns: Some(vec!["id".to_string(), "id".to_string()]),
+            rows: vec![vec![Literal::Int(1), Literal::Int(2)]],
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Schema(SchemaError::DuplicateColumnName(column))) if column == "id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_insert_into_a_non_existent_table() {
+        let catalog = Catalog::new();
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Insert {
+            table_name: "employees".to_string(),
+            columns: None,
+            rows: vec![vec![Literal::Int(1)]],
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Catalog(CatalogError::TableDoesNotExist(table_name))) if table_name == "employees"
+        ));
+    }
+
+    #[test]
+    fn execute_delete_with_filter() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+        insert_rows(
+            &catalog,
+            "employees",
+            rows![[1, "relop"], [2, "query"], [3, "rust"]],
+        );
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor
+            .execute(LogicalPlan::Delete {
+                table_name: "employees".to_string(),
+                filter: Some(Predicate::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    LogicalOperator::Greater,
+                    Literal::Int(1),
+                )),
+            })
+            .unwrap();
+        assert_eq!(Some(2), query_result.rows_affected());
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_delete_without_filter() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+        insert_rows(&catalog, "employees", rows![[1], [2]]);
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor
+            .execute(LogicalPlan::Delete {
+                table_name: "employees".to_string(),
+                filter: None,
+            })
+            .unwrap();
+        assert_eq!(Some(2), query_result.rows_affected());
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_delete_with_filter_on_an_unknown_column() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+        insert_rows(&catalog, "employees", rows![[1]]);
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Delete {
+            table_name: "employees".to_string(),
+            filter: Some(Predicate::comparison(
+                Literal::ColumnReference("salary".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(1),
+            )),
+        });
+        assert!(query_result.is_err());
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 1);
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_delete_from_a_non_existent_table() {
+        let catalog = Catalog::new();
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Delete {
+            table_name: "employees".to_string(),
+            filter: None,
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Catalog(CatalogError::TableDoesNotExist(table_name))) if table_name == "employees"
+        ));
+    }
+
+    #[test]
+    fn execute_update_with_filter() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+        insert_rows(&catalog, "employees", rows![[1, "relop"], [2, "query"]]);
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor
+            .execute(LogicalPlan::Update {
+                table_name: "employees".to_string(),
+                assignments: vec![("name".to_string(), Literal::Text("rust".to_string()))],
+                filter: Some(Predicate::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    LogicalOperator::Eq,
+                    Literal::Int(2),
+                )),
+            })
+            .unwrap();
+        assert_eq!(Some(1), query_result.rows_affected());
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(row_iterator.as_mut(), "id" => 2, "name" => "rust");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn execute_update_of_the_filtered_column_without_filter() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        assert!(result.is_ok());
+        insert_rows(&catalog, "employees", rows![[1, "relop"], [2, "query"]]);
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor
+            .execute(LogicalPlan::Update {
+                table_name: "employees".to_string(),
+                assignments: vec![
+                    ("id".to_string(), Literal::Int(10)),
+                    ("name".to_string(), Literal::Text("rust".to_string())),
+                ],
+                filter: None,
+            })
+            .unwrap();
+        assert_eq!(Some(2), query_result.rows_affected());
+
+        let query_result = executor.execute(LogicalPlan::scan("employees")).unwrap();
+        let mut row_iterator = query_result.result_set().unwrap().iterator().unwrap();
+        assert_next_row!(row_iterator.as_mut(), "id" => 10, "name" => "rust");
+        assert_next_row!(row_iterator.as_mut(), "id" => 10, "name" => "rust");
+        assert_no_more_rows!(row_iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_execute_update_with_a_type_mismatch() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+        insert_rows(&catalog, "employees", rows![[1]]);
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Update {
+            table_name: "employees".to_string(),
+            assignments: vec![("id".to_string(), Literal::Text("one".to_string()))],
+            filter: None,
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Schema(SchemaError::ColumnTypeMismatch { column, expected: ColumnType::Int, actual: ColumnType::Text })) if column == "id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_update_of_an_unknown_column() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Update {
+            table_name: "employees".to_string(),
+            assignments: vec![("salary".to_string(), Literal::Int(1))],
+            filter: None,
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::UnknownColumn(column)) if column == "salary"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_execute_update_of_a_column_twice() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::Update {
+            table_name: "employees".to_string(),
+            assignments: vec![
+                ("id".to_string(), Literal::Int(1)),
+                ("id".to_string(), Literal::Int(2)),
+            ],
+            filter: None,
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Schema(SchemaError::DuplicateColumnName(column))) if column == "id"
+        ));
+    }
+
+    #[test]
+    fn execute_create_table() {
+        let catalog = Catalog::new();
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor
+            .execute(LogicalPlan::CreateTable {
+                table_name: "employees".to_string(),
+                columns: vec![
+                    ("id".to_string(), ColumnType::Int),
+                    ("name".to_string(), ColumnType::Text),
+                ],
+                primary_key: None,
+            })
+            .unwrap();
+        assert_eq!(Some("employees"), query_result.created_table());
+
+        let schema = catalog.schema_for("employees").unwrap();
+        assert_eq!(
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            *schema
+        );
+    }
+
+    #[test]
+    fn execute_create_table_with_a_primary_key() {
+        let catalog = Catalog::new();
+
+        let executor = Executor::new(&catalog);
+        executor
+            .execute(LogicalPlan::CreateTable {
+                table_name: "employees".to_string(),
+                columns: vec![
+                    ("department_id".to_string(), ColumnType::Int),
+                    ("id".to_string(), ColumnType::Int),
+                ],
+                primary_key: Some(vec!["department_id".to_string(), "id".to_string()]),
+            })
+            .unwrap();
+
+        let schema = catalog.schema_for("employees").unwrap();
+        assert_eq!(
+            Some(&PrimaryKey::composite(["department_id", "id"])),
+            schema.primary_key()
+        );
+    }
+
+    #[test]
+    fn attempt_to_execute_create_table_with_a_primary_key_on_an_unknown_column() {
+        let catalog = Catalog::new();
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::CreateTable {
+            table_name: "employees".to_string(),
+            columns: vec![("id".to_string(), ColumnType::Int)],
+            primary_key: Some(vec!["code".to_string()]),
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Schema(SchemaError::PrimaryKeyColumnNotFound(column))) if column == "code"
+        ));
+        assert!(catalog.schema_for("employees").is_err());
+    }
+
+    #[test]
+    fn attempt_to_execute_create_table_with_a_duplicate_column() {
+        let catalog = Catalog::new();
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::CreateTable {
+            table_name: "employees".to_string(),
+            columns: vec![
+                ("id".to_string(), ColumnType::Int),
+                ("id".to_string(), ColumnType::Text),
+            ],
+            primary_key: None,
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Schema(SchemaError::DuplicateColumnName(column))) if column == "id"
+        ));
+        assert!(catalog.schema_for("employees").is_err());
+    }
+
+    #[test]
+    fn attempt_to_execute_create_table_for_an_existing_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::CreateTable {
+            table_name: "employees".to_string(),
+            columns: vec![("name".to_string(), ColumnType::Text)],
+            primary_key: None,
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Catalog(CatalogError::TableAlreadyExists(table_name))) if table_name == "employees"
+        ));
+    }
+
+    #[test]
+    fn execute_drop_table() {
+        let catalog = Catalog::new();
+        let result = catalog.create_table("employees", schema!["id" => ColumnType::Int].unwrap());
+        assert!(result.is_ok());
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor
+            .execute(LogicalPlan::DropTable {
+                table_name: "employees".to_string(),
+            })
+            .unwrap();
+        assert_eq!(Some("employees"), query_result.dropped_table());
+
+        let query_result = executor.execute(LogicalPlan::show_tables()).unwrap();
+        assert!(query_result.all_tables().unwrap().is_empty());
+    }
+
+    #[test]
+    fn attempt_to_execute_drop_table_for_a_non_existent_table() {
+        let catalog = Catalog::new();
+
+        let executor = Executor::new(&catalog);
+        let query_result = executor.execute(LogicalPlan::DropTable {
+            table_name: "employees".to_string(),
+        });
+
+        assert!(matches!(
+            query_result,
+            Err(ExecutionError::Catalog(CatalogError::TableDoesNotExist(table_name))) if table_name == "employees"
+        ));
+    }
 }
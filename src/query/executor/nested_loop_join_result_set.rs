@@ -1,5 +1,6 @@
 use crate::query::executor::error::ExecutionError;
 use crate::query::executor::result_set::{ResultSet, RowViewResult};
+use crate::query::parser::ast::JoinType;
 use crate::query::plan::predicate::Predicate;
 use crate::schema::Schema;
 use crate::storage::row_view::RowView;
@@ -14,23 +15,48 @@ use std::sync::Arc;
 /// ```text
 ///            [Outer Join Iterator]
 ///               /            \
-///      left_iterator: Pulls  right_result_set: Resets and iterates
-///      rows from Inner Join  for every left row.
+///      left_iterator: Pulls  right_result_set: Materialized once,
+///      rows from Inner Join  iterated for every left row.
 ///             /
 ///     [Inner Join Iterator]
 ///        /            \
-///   left: Pulls from A  right: Resets/Iterates for B
+///   left: Pulls from A  right: Materialized once from B
 /// ```
 ///
 /// 1. The **Outer Join Iterator** calls `next()` on its `left_iterator` (the Inner Join).
-/// 2. The **Inner Join Iterator** pulls a row from `A`, resets `B`, and returns the first `A+B` row.
-/// 3. The **Outer Join Iterator** receives `A+B`, resets `C`, and combines `A+B` with each row of `C`.
+/// 2. The **Inner Join Iterator** materializes `B`, pulls a row from `A`, and returns the first `A+B` row.
+/// 3. The **Outer Join Iterator** receives `A+B`, materializes `C` (once), and combines `A+B` with each row of `C`.
 /// 4. This process repeats, effectively creating a 3-level deep nested loop without the outer
 ///    nodes needing to know the internal structure of their children.
+///
+/// ### Join Types
+///
+/// - `Inner`: only merged rows satisfying the `on` predicate are returned.
+/// - `Cross`: every left row is combined with every right row (the `on` predicate is always `None`).
+/// - `Left`: every left row is returned; left rows without a match are padded with nulls on the right.
+/// - `Right`: the iteration order is swapped, the right side drives the loop and the left side is
+///   iterated for every right row. Right rows without a match are padded with nulls on the left.
+///   The merged rows keep the `left + right` column order.
+/// - `FullOuter`: behaves like `Left`, and additionally tracks which right rows were matched across
+///   the whole left scan. Once the left side is exhausted, the right rows are iterated one more time and
+///   the unmatched ones are returned, padded with nulls on the left.
+///
+/// ### Memory
+///
+/// The side iterated for every row of the other side (the right side, or the left side of a `Right`
+/// join) is materialized once per iteration of the join, so that its nested filters and joins are not
+/// recomputed for every row of the driving side. The memory therefore grows linearly with the number
+/// of rows on that side; `FullOuter` additionally keeps one flag per right row.
+///
+/// An `Inner` or a `Cross` join with an empty right side produces no rows, so the right side is
+/// materialized before the left side is iterated; if it is empty, the left side is not iterated at
+/// all. The outer joins materialize it on the first driving row, and still iterate the left side,
+/// because their left rows are returned padded with nulls.
 pub struct NestedLoopJoinResultSet {
     left: Box<dyn ResultSet>,
     right: Box<dyn ResultSet>,
     on: Option<Predicate>,
+    join_type: JoinType,
     merged_schema: Schema,
     visible_positions: Arc<Vec<usize>>,
 }
@@ -40,6 +66,7 @@ impl NestedLoopJoinResultSet {
         left: Box<dyn ResultSet>,
         right: Box<dyn ResultSet>,
         on: Option<Predicate>,
+        join_type: JoinType,
     ) -> Self {
         let merged_schema = left
             .schema()
@@ -49,6 +76,7 @@ impl NestedLoopJoinResultSet {
             left,
             right,
             on,
+            join_type,
             merged_schema,
             visible_positions,
         }
@@ -56,15 +84,37 @@ impl NestedLoopJoinResultSet {
 }
 
 impl ResultSet for NestedLoopJoinResultSet {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
-        let left_iterator = self.left.iterator()?;
-        Ok(Box::new(JoinIterator::new(
-            left_iterator,
-            self.right.as_ref(),
-            self.on.as_ref(),
-            &self.merged_schema,
-            &self.visible_positions,
-        )))
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        let (outer, inner) = match self.join_type {
+            JoinType::Right => (self.right.as_ref(), self.left.as_ref()),
+            _ => (self.left.as_ref(), self.right.as_ref()),
+        };
+        let inner_row_views = if matches!(self.join_type, JoinType::Inner | JoinType::Cross) {
+            match materialize(inner) {
+                Ok(inner_row_views) if inner_row_views.is_empty() => {
+                    return Ok(Box::new(std::iter::empty()))
+                }
+                Ok(inner_row_views) => Some(inner_row_views),
+                Err(err) => return Ok(Box::new(std::iter::once(Err(err)))),
+            }
+        } else {
+            None
+        };
+
+        let outer_iterator = outer.iterator()?;
+        Ok(Box::new(
+            JoinIterator::new(
+                outer_iterator,
+                inner,
+                self.on.as_ref(),
+                self.join_type,
+                outer.schema().column_count(),
+                inner.schema().column_count(),
+                &self.merged_schema,
+                &self.visible_positions,
+            )
+            .with_inner_row_views(inner_row_views),
+        ))
     }
 
     fn schema(&self) -> &Schema {
@@ -72,34 +122,125 @@ impl ResultSet for NestedLoopJoinResultSet {
     }
 }
 
+/// Collects all the row views of the given `ResultSet`, failing on the first error.
+fn materialize(result_set: &dyn ResultSet) -> Result<Vec<RowView<'_>>, ExecutionError> {
+    result_set.iterator()?.collect()
+}
+
 /// An iterator that performs a nested loop join between two iterators.
+///
+/// The outer side drives the loop and the materialized rows of the inner side are iterated for
+/// every outer row. For a `Right` join the outer side is the right result set, for all other join
+/// types it is the left one.
 struct JoinIterator<'a> {
-    left_iterator: Box<dyn Iterator<Item = RowViewResult<'a>> + 'a>,
-    right_result_set: &'a dyn ResultSet,
+    outer_iterator: Box<dyn Iterator<Item = RowViewResult<'a>> + 'a>,
+    inner_result_set: &'a dyn ResultSet,
     on: Option<&'a Predicate>,
+    join_type: JoinType,
+    outer_column_count: usize,
+    inner_column_count: usize,
     merged_schema: &'a Schema,
     visible_positions: &'a [usize],
-    current_left_row_view: Option<RowView<'a>>,
-    current_right_iterator: Option<Box<dyn Iterator<Item = RowViewResult<'a>> + 'a>>,
+    inner_row_views: Option<Vec<RowView<'a>>>,
+    current_outer_row_view: Option<RowView<'a>>,
+    current_outer_matched: bool,
+    current_inner_position: usize,
+    matched_inner_positions: Vec<bool>,
+    outer_exhausted: bool,
+    unmatched_inner_position: usize,
 }
 
 impl<'a> JoinIterator<'a> {
+    #[allow(clippy::too_many_arguments)]
     fn new(
-        left_iterator: Box<dyn Iterator<Item = RowViewResult<'a>> + 'a>,
-        right_result_set: &'a dyn ResultSet,
+        outer_iterator: Box<dyn Iterator<Item = RowViewResult<'a>> + 'a>,
+        inner_result_set: &'a dyn ResultSet,
         on: Option<&'a Predicate>,
+        join_type: JoinType,
+        outer_column_count: usize,
+        inner_column_count: usize,
         merged_schema: &'a Schema,
         visible_positions: &'a [usize],
     ) -> Self {
         Self {
-            left_iterator,
-            right_result_set,
+            outer_iterator,
+            inner_result_set,
             on,
+            join_type,
+            outer_column_count,
+            inner_column_count,
             merged_schema,
             visible_positions,
-            current_left_row_view: None,
-            current_right_iterator: None,
+            inner_row_views: None,
+            current_outer_row_view: None,
+            current_outer_matched: false,
+            current_inner_position: 0,
+            matched_inner_positions: Vec::new(),
+            outer_exhausted: false,
+            unmatched_inner_position: 0,
+        }
+    }
+
+    /// Sets the already materialized inner row views, which are otherwise materialized on the
+    /// first outer row.
+    fn with_inner_row_views(mut self, inner_row_views: Option<Vec<RowView<'a>>>) -> Self {
+        if let Some(inner_row_views) = inner_row_views {
+            self.set_inner_row_views(inner_row_views);
+        }
+        self
+    }
+
+    fn set_inner_row_views(&mut self, inner_row_views: Vec<RowView<'a>>) {
+        if self.join_type == JoinType::FullOuter {
+            self.matched_inner_positions = vec![false; inner_row_views.len()];
+        }
+        self.inner_row_views = Some(inner_row_views);
+    }
+
+    /// Materializes the inner row views, unless they already are.
+    fn materialize_inner(&mut self) -> Result<(), ExecutionError> {
+        if self.inner_row_views.is_none() {
+            let inner_row_views = materialize(self.inner_result_set)?;
+            self.set_inner_row_views(inner_row_views);
         }
+        Ok(())
+    }
+
+    /// Merges the outer and inner row views, keeping the `left + right` column order.
+    fn merge(&self, outer_row_view: &RowView, inner_row_view: &RowView) -> RowView<'a> {
+        let merged_row = match self.join_type {
+            JoinType::Right => inner_row_view.merge(outer_row_view),
+            _ => outer_row_view.merge(inner_row_view),
+        };
+        RowView::new(merged_row, self.merged_schema, self.visible_positions)
+    }
+
+    /// Pads an outer row view that found no match with nulls for the inner columns.
+    fn pad_outer(&self, outer_row_view: &RowView) -> RowView<'a> {
+        let padded_row = match self.join_type {
+            JoinType::Right => outer_row_view.pad_with_nulls(self.inner_column_count, 0),
+            _ => outer_row_view.pad_with_nulls(0, self.inner_column_count),
+        };
+        RowView::new(padded_row, self.merged_schema, self.visible_positions)
+    }
+
+    /// Pads an inner row view that found no match with nulls for the outer columns.
+    fn pad_inner(&self, inner_row_view: &RowView) -> RowView<'a> {
+        let padded_row = inner_row_view.pad_with_nulls(self.outer_column_count, 0);
+        RowView::new(padded_row, self.merged_schema, self.visible_positions)
+    }
+
+    /// Returns the next unmatched inner row view, once the outer side is exhausted (`FullOuter` only).
+    fn next_unmatched_inner(&mut self) -> Option<RowViewResult<'a>> {
+        let inner_row_views = self.inner_row_views.as_ref()?;
+        while let Some(inner_row_view) = inner_row_views.get(self.unmatched_inner_position) {
+            let matched = self.matched_inner_positions[self.unmatched_inner_position];
+            self.unmatched_inner_position += 1;
+            if !matched {
+                return Some(Ok(self.pad_inner(inner_row_view)));
+            }
+        }
+        None
     }
 }
 
@@ -108,41 +249,58 @@ impl<'a> Iterator for JoinIterator<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if self.current_left_row_view.is_none() {
-                match self.left_iterator.next() {
-                    Some(Ok(left_row_view)) => {
-                        self.current_left_row_view = Some(left_row_view);
-                        match self.right_result_set.iterator() {
-                            Ok(iterator) => self.current_right_iterator = Some(iterator),
-                            Err(err) => return Some(Err(err)),
+            if self.outer_exhausted {
+                return self.next_unmatched_inner();
+            }
+            if self.current_outer_row_view.is_none() {
+                match self.outer_iterator.next() {
+                    Some(Ok(outer_row_view)) => {
+                        if let Err(err) = self.materialize_inner() {
+                            return Some(Err(err));
                         }
+                        self.current_outer_row_view = Some(outer_row_view);
+                        self.current_outer_matched = false;
+                        self.current_inner_position = 0;
                     }
                     Some(Err(err)) => return Some(Err(err)),
-                    None => return None,
+                    None => {
+                        if self.join_type != JoinType::FullOuter {
+                            return None;
+                        }
+                        if let Err(err) = self.materialize_inner() {
+                            return Some(Err(err));
+                        }
+                        self.outer_exhausted = true;
+                        continue;
+                    }
                 }
             }
 
-            if let Some(ref mut right_iterator) = self.current_right_iterator {
-                match right_iterator.next() {
-                    Some(Ok(right_row_view)) => {
-                        let left_row_view = self.current_left_row_view.as_ref().unwrap();
-                        let merged_row = left_row_view.merge(&right_row_view);
-                        let merged_row_view =
-                            RowView::new(merged_row, self.merged_schema, self.visible_positions);
-
-                        if let Some(predicate) = self.on {
-                            match predicate.matches(&merged_row_view) {
-                                Ok(true) => return Some(Ok(merged_row_view)),
-                                Ok(false) => continue,
-                                Err(err) => return Some(Err(err)),
-                            }
+            let position = self.current_inner_position;
+            let outer_row_view = self.current_outer_row_view.as_ref().unwrap();
+            let inner_row_view = self.inner_row_views.as_ref().unwrap().get(position);
+            match inner_row_view {
+                Some(inner_row_view) => {
+                    self.current_inner_position += 1;
+                    let merged_row_view = self.merge(outer_row_view, inner_row_view);
+
+                    if let Some(predicate) = self.on {
+                        match predicate.matches(&merged_row_view) {
+                            Ok(true) => {}
+                            Ok(false) => continue,
+                            Err(err) => return Some(Err(err)),
                         }
-                        return Some(Ok(merged_row_view));
                     }
-                    Some(Err(err)) => return Some(Err(err)),
-                    None => {
-                        self.current_left_row_view = None;
-                        self.current_right_iterator = None;
+                    self.current_outer_matched = true;
+                    if self.join_type == JoinType::FullOuter {
+                        self.matched_inner_positions[position] = true;
+                    }
+                    return Some(Ok(merged_row_view));
+                }
+                None => {
+                    let outer_row_view = self.current_outer_row_view.take().unwrap();
+                    if self.join_type.is_outer() && !self.current_outer_matched {
+                        return Some(Ok(self.pad_outer(&outer_row_view)));
                     }
                 }
             }
@@ -158,14 +316,17 @@ mod tests {
     use std::sync::Arc;
 
     use super::*;
+    use crate::query::executor::filter_result_set::FilterResultSet;
     use crate::query::executor::test_utils::{
-        ErrorResultSet, InitErrorResultSet, JoinResetErrorResultSet,
+        CountingResultSet, ErrorResultSet, InitErrorResultSet, JoinResetErrorResultSet,
     };
     use crate::query::parser::ast::Literal;
     use crate::query::plan::predicate::LogicalOperator;
     use crate::storage::table_store::TableStore;
     use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
     use crate::{assert_next_row, assert_no_more_rows, row, rows, schema};
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn join_result_sets_cross_product() {
@@ -192,8 +353,12 @@ mod tests {
             None,
         ));
 
-        let join_result_set =
-            NestedLoopJoinResultSet::new(employees_result_set, departments_result_set, None);
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees_result_set,
+            departments_result_set,
+            None,
+            JoinType::Inner,
+        );
         let mut iterator = join_result_set.iterator().unwrap();
 
         assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.name" => "Engineering");
@@ -236,8 +401,12 @@ mod tests {
             Literal::ColumnReference("departments.id".to_string()),
         );
 
-        let join_result_set =
-            NestedLoopJoinResultSet::new(employees_result_set, departments_result_set, Some(on));
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees_result_set,
+            departments_result_set,
+            Some(on),
+            JoinType::Inner,
+        );
         let mut iterator = join_result_set.iterator().unwrap();
 
         assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => 1, "departments.name" => "Headquarters");
@@ -277,6 +446,7 @@ mod tests {
             employees_result_set,
             departments_result_set,
             Some(inner_on),
+            JoinType::Inner,
         ));
 
         let locations_table = Table::new("locations", schema!["id" => ColumnType::Int].unwrap());
@@ -295,8 +465,12 @@ mod tests {
             Literal::ColumnReference("loc.id".to_string()),
         );
 
-        let join_result_set =
-            NestedLoopJoinResultSet::new(inner_join, locations_result_set, Some(outer_on));
+        let join_result_set = NestedLoopJoinResultSet::new(
+            inner_join,
+            locations_result_set,
+            Some(outer_on),
+            JoinType::Inner,
+        );
         let mut iterator = join_result_set.iterator().unwrap();
 
         assert_next_row!(iterator.as_mut(), "emp.id" => 1, "dept.id" => 1, "loc.id" => 1);
@@ -329,8 +503,12 @@ mod tests {
             Literal::ColumnReference("emp2.id".to_string()),
         );
 
-        let join_result_set =
-            NestedLoopJoinResultSet::new(employees1_result_set, employees2_result_set, Some(on));
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees1_result_set,
+            employees2_result_set,
+            Some(on),
+            JoinType::Inner,
+        );
         let mut iterator = join_result_set.iterator().unwrap();
 
         assert_next_row!(iterator.as_mut(), "emp1.id" => 101, "emp2.id" => 101);
@@ -345,13 +523,15 @@ mod tests {
         let schema = table.schema();
 
         let left = Box::new(ErrorResultSet { schema });
+        let right_store = TableStore::new();
+        right_store.insert(row![1]);
         let right = Box::new(ScanResultsSet::new(
-            TableScan::new(Arc::new(TableStore::new())),
+            TableScan::new(Arc::new(right_store)),
             table,
             None,
         ));
 
-        let join = NestedLoopJoinResultSet::new(left, right, None);
+        let join = NestedLoopJoinResultSet::new(left, right, None, JoinType::Inner);
         let mut iterator = join.iterator().unwrap();
 
         assert!(matches!(
@@ -376,7 +556,7 @@ mod tests {
             schema: right_schema,
         });
 
-        let join = NestedLoopJoinResultSet::new(left, right, None);
+        let join = NestedLoopJoinResultSet::new(left, right, None, JoinType::Inner);
         let mut iterator = join.iterator().unwrap();
 
         // Right iterator.next() returns Err
@@ -388,7 +568,7 @@ mod tests {
     }
 
     #[test]
-    fn join_result_set_with_error_in_right_iterator_reset() {
+    fn join_result_set_does_not_reset_the_right_iterator() {
         let left_schema = schema!["id" => ColumnType::Int].unwrap();
         let left_store = TableStore::new();
         left_store.insert_all(rows![[1], [2]]);
@@ -406,18 +586,13 @@ mod tests {
             call_count: std::sync::atomic::AtomicUsize::new(0),
         });
 
-        // Cross join (no predicate)
-        let join = NestedLoopJoinResultSet::new(left, right, None);
+        // Cross join (no predicate), the right side fails if it is iterated more than once
+        let join = NestedLoopJoinResultSet::new(left, right, None, JoinType::Inner);
         let mut iterator = join.iterator().unwrap();
 
-        let first = iterator.next().unwrap();
-        assert!(first.is_ok());
-
-        let second = iterator.next().unwrap();
-        assert!(matches!(
-            second,
-            Err(ExecutionError::TypeMismatchInComparison)
-        ));
+        assert!(iterator.next().unwrap().is_ok());
+        assert!(iterator.next().unwrap().is_ok());
+        assert_no_more_rows!(iterator.as_mut());
     }
 
     #[test]
@@ -435,7 +610,7 @@ mod tests {
         ));
         let right = Box::new(InitErrorResultSet { schema });
 
-        let join = NestedLoopJoinResultSet::new(left, right, None);
+        let join = NestedLoopJoinResultSet::new(left, right, None, JoinType::Inner);
         let mut iterator = join.iterator().unwrap();
 
         assert!(matches!(
@@ -479,7 +654,7 @@ mod tests {
             Literal::Text("error".to_string()),
         );
 
-        let join = NestedLoopJoinResultSet::new(left, right, Some(on));
+        let join = NestedLoopJoinResultSet::new(left, right, Some(on), JoinType::Inner);
         let mut iterator = join.iterator().unwrap();
 
         assert!(matches!(
@@ -487,4 +662,334 @@ mod tests {
             Some(Err(ExecutionError::TypeMismatchInComparison))
         ));
     }
+
+    fn employees_and_departments() -> (Box<dyn ResultSet>, Box<dyn ResultSet>) {
+        let employees_table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let employees_store = TableStore::new();
+        employees_store.insert_all(rows![[1, "relop"], [2, "query"]]);
+
+        let employees_result_set = Box::new(ScanResultsSet::new(
+            TableScan::new(Arc::new(employees_store)),
+            Arc::new(employees_table),
+            None,
+        ));
+
+        let departments_table = Table::new(
+            "departments",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let departments_store = TableStore::new();
+        departments_store.insert_all(rows![[1, "Headquarters"], [3, "Remote"]]);
+
+        let departments_result_set = Box::new(ScanResultsSet::new(
+            TableScan::new(Arc::new(departments_store)),
+            Arc::new(departments_table),
+            None,
+        ));
+        (employees_result_set, departments_result_set)
+    }
+
+    fn on_employee_id_equals_department_id() -> Predicate {
+        Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::ColumnReference("departments.id".to_string()),
+        )
+    }
+
+    #[test]
+    fn left_join_result_sets() {
+        let (employees, departments) = employees_and_departments();
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees,
+            departments,
+            Some(on_employee_id_equals_department_id()),
+            JoinType::Left,
+        );
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => 1, "departments.name" => "Headquarters");
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "departments.id" => ColumnValue::null(), "departments.name" => ColumnValue::null());
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn right_join_result_sets() {
+        let (employees, departments) = employees_and_departments();
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees,
+            departments,
+            Some(on_employee_id_equals_department_id()),
+            JoinType::Right,
+        );
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "employees.name" => "relop", "departments.id" => 1);
+        assert_next_row!(iterator.as_mut(), "employees.id" => ColumnValue::null(), "employees.name" => ColumnValue::null(), "departments.id" => 3, "departments.name" => "Remote");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn full_outer_join_result_sets() {
+        let (employees, departments) = employees_and_departments();
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees,
+            departments,
+            Some(on_employee_id_equals_department_id()),
+            JoinType::FullOuter,
+        );
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => 1);
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "departments.id" => ColumnValue::null());
+        assert_next_row!(iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 3);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn full_outer_join_result_sets_with_non_overlapping_keys() {
+        let (employees, departments) = employees_and_departments();
+        let on = Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Int(100),
+        );
+        let join_result_set =
+            NestedLoopJoinResultSet::new(employees, departments, Some(on), JoinType::FullOuter);
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => ColumnValue::null());
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "departments.id" => ColumnValue::null());
+        assert_next_row!(iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 1);
+        assert_next_row!(iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 3);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn right_join_result_sets_with_non_overlapping_keys() {
+        let (employees, departments) = employees_and_departments();
+        let on = Predicate::comparison(
+            Literal::ColumnReference("departments.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Int(100),
+        );
+        let join_result_set =
+            NestedLoopJoinResultSet::new(employees, departments, Some(on), JoinType::Right);
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 1);
+        assert_next_row!(iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 3);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn full_outer_join_result_set_with_empty_left() {
+        let employees_table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let employees_result_set = Box::new(ScanResultsSet::new(
+            TableScan::new(Arc::new(TableStore::new())),
+            Arc::new(employees_table),
+            None,
+        ));
+        let (_, departments) = employees_and_departments();
+
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees_result_set,
+            departments,
+            Some(on_employee_id_equals_department_id()),
+            JoinType::FullOuter,
+        );
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 1);
+        assert_next_row!(iterator.as_mut(), "employees.id" => ColumnValue::null(), "departments.id" => 3);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    fn empty_departments() -> Box<dyn ResultSet> {
+        let departments_table =
+            Table::new("departments", schema!["id" => ColumnType::Int].unwrap());
+        Box::new(ScanResultsSet::new(
+            TableScan::new(Arc::new(TableStore::new())),
+            Arc::new(departments_table),
+            None,
+        ))
+    }
+
+    #[test]
+    fn inner_join_with_empty_right_does_not_iterate_left() {
+        let employees = Box::new(InitErrorResultSet {
+            schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+        });
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees,
+            empty_departments(),
+            Some(on_employee_id_equals_department_id()),
+            JoinType::Inner,
+        );
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn cross_join_with_empty_right_does_not_iterate_left() {
+        let employees = Box::new(InitErrorResultSet {
+            schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+        });
+        let join_result_set =
+            NestedLoopJoinResultSet::new(employees, empty_departments(), None, JoinType::Cross);
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn left_join_with_empty_right() {
+        let (employees, _) = employees_and_departments();
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees,
+            empty_departments(),
+            Some(on_employee_id_equals_department_id()),
+            JoinType::Left,
+        );
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => ColumnValue::null());
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "departments.id" => ColumnValue::null());
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn inner_join_reuses_the_peeked_right_iterator() {
+        let (employees, departments) = employees_and_departments();
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees,
+            departments,
+            Some(on_employee_id_equals_department_id()),
+            JoinType::Inner,
+        );
+
+        assert_eq!(1, join_result_set.count().unwrap());
+        assert_eq!(1, join_result_set.count().unwrap());
+    }
+
+    fn counted_departments_with_id_above(id: i64) -> (Box<dyn ResultSet>, Arc<AtomicUsize>) {
+        let (_, departments) = employees_and_departments();
+        let filtered_departments = Box::new(FilterResultSet::new(
+            departments,
+            Predicate::comparison(
+                Literal::ColumnReference("departments.id".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(id),
+            ),
+        ));
+        let iterations = Arc::new(AtomicUsize::new(0));
+        let counted_departments = Box::new(CountingResultSet {
+            inner: filtered_departments,
+            iterations: iterations.clone(),
+        });
+        (counted_departments, iterations)
+    }
+
+    #[test]
+    fn inner_join_iterates_the_filtered_right_side_once() {
+        let (employees, _) = employees_and_departments();
+        let (departments, iterations) = counted_departments_with_id_above(0);
+        let join_result_set =
+            NestedLoopJoinResultSet::new(employees, departments, None, JoinType::Cross);
+
+        assert_eq!(4, join_result_set.count().unwrap());
+        assert_eq!(1, iterations.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn left_join_iterates_the_filtered_right_side_once() {
+        let (employees, _) = employees_and_departments();
+        let (departments, iterations) = counted_departments_with_id_above(1);
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees,
+            departments,
+            Some(on_employee_id_equals_department_id()),
+            JoinType::Left,
+        );
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => ColumnValue::null());
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "departments.id" => ColumnValue::null());
+        assert_no_more_rows!(iterator.as_mut());
+        assert_eq!(1, iterations.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn full_outer_join_iterates_the_filtered_right_side_once() {
+        let (employees, _) = employees_and_departments();
+        let (departments, iterations) = counted_departments_with_id_above(0);
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees,
+            departments,
+            Some(on_employee_id_equals_department_id()),
+            JoinType::FullOuter,
+        );
+
+        assert_eq!(3, join_result_set.count().unwrap());
+        assert_eq!(1, iterations.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn join_result_set_materializes_the_right_side_for_every_iteration() {
+        let (employees, _) = employees_and_departments();
+        let (departments, iterations) = counted_departments_with_id_above(0);
+        let join_result_set =
+            NestedLoopJoinResultSet::new(employees, departments, None, JoinType::Cross);
+
+        assert_eq!(4, join_result_set.count().unwrap());
+        assert_eq!(4, join_result_set.count().unwrap());
+        assert_eq!(2, iterations.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn cross_join_result_sets() {
+        let (employees, departments) = employees_and_departments();
+        let join_result_set =
+            NestedLoopJoinResultSet::new(employees, departments, None, JoinType::Cross);
+        let mut iterator = join_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => 1);
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "departments.id" => 3);
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "departments.id" => 1);
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "departments.id" => 3);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn column_names_of_joined_result_sets() {
+        let employees_table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let employees_result_set = Box::new(ScanResultsSet::new(
+            TableScan::new(Arc::new(TableStore::new())),
+            Arc::new(employees_table),
+            Some("e".to_string()),
+        ));
+        let departments_table =
+            Table::new("departments", schema!["name" => ColumnType::Text].unwrap());
+        let departments_result_set = Box::new(ScanResultsSet::new(
+            TableScan::new(Arc::new(TableStore::new())),
+            Arc::new(departments_table),
+            None,
+        ));
+
+        let join_result_set = NestedLoopJoinResultSet::new(
+            employees_result_set,
+            departments_result_set,
+            None,
+            JoinType::Inner,
+        );
+
+        assert_eq!(
+            vec!["e.id", "departments.name"],
+            join_result_set.column_names()
+        );
+    }
 }
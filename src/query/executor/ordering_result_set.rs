@@ -13,6 +13,10 @@ use crate::storage::row_view::{RowView, RowViewComparator};
 ///
 /// This implementation performs an **in-memory sort**, meaning it buffers all rows
 /// from the inner result set before yielding the first row.
+///
+/// The sort is **stable**: rows with equal ordering keys are yielded in the order in which the
+/// inner result set yields them (e.g., in insertion order for a table scan), with or without a
+/// limit. This keeps pagination with `limit` reproducible.
 pub struct OrderingResultSet {
     inner: Box<dyn ResultSet>,
     ordering_keys: Vec<OrderingKey>,
@@ -40,7 +44,7 @@ impl OrderingResultSet {
 }
 
 impl ResultSet for OrderingResultSet {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
         let comparator = RowViewComparator::new(self.schema(), &self.ordering_keys)?;
         let iterator = self.inner.iterator()?;
 
@@ -49,14 +53,17 @@ impl ResultSet for OrderingResultSet {
                 return Ok(Box::new(std::iter::empty()));
             }
 
+            // Rows with equal ordering keys are ordered by their sequence in the inner
+            // result set, so that the heap keeps (and yields) the earliest of them.
             struct ComparableRowView<'comparator, 'row_view> {
                 row: RowView<'row_view>,
+                sequence: usize,
                 comparator: &'comparator RowViewComparator<'comparator>,
             }
 
             impl PartialEq for ComparableRowView<'_, '_> {
                 fn eq(&self, other: &Self) -> bool {
-                    self.comparator.compare(&self.row, &other.row) == std::cmp::Ordering::Equal
+                    self.cmp(other) == std::cmp::Ordering::Equal
                 }
             }
 
@@ -70,16 +77,19 @@ impl ResultSet for OrderingResultSet {
 
             impl Ord for ComparableRowView<'_, '_> {
                 fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-                    self.comparator.compare(&self.row, &other.row)
+                    self.comparator
+                        .compare(&self.row, &other.row)
+                        .then(self.sequence.cmp(&other.sequence))
                 }
             }
 
             let mut max_heap = std::collections::BinaryHeap::with_capacity(limit + 1);
-            for result in iterator {
+            for (sequence, result) in iterator.enumerate() {
                 match result {
                     Ok(row_view) => {
                         max_heap.push(ComparableRowView {
                             row: row_view,
+                            sequence,
                             comparator: &comparator,
                         });
                         if max_heap.len() > limit {
@@ -106,6 +116,7 @@ impl ResultSet for OrderingResultSet {
                 }
             }
 
+            // `sort_by` is stable, rows with equal ordering keys keep their relative order.
             rows.sort_by(|left, right| comparator.compare(left, right));
             Ok(Box::new(rows.into_iter().map(Ok)))
         }
@@ -114,6 +125,10 @@ impl ResultSet for OrderingResultSet {
     fn schema(&self) -> &Schema {
         self.inner.schema()
     }
+
+    fn column_names(&self) -> Vec<String> {
+        self.inner.column_names()
+    }
 }
 
 #[cfg(test)]
@@ -235,6 +250,68 @@ mod tests {
         assert_no_more_rows!(iterator.as_mut());
     }
 
+    #[test]
+    fn ordering_result_set_preserves_insertion_order_for_equal_keys() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "rank" => ColumnType::Int, "name" => ColumnType::Text]
+                .unwrap(),
+        );
+        let table_store = TableStore::new();
+        table_store.insert_all(rows![
+            [2, 10, "relop"],
+            [1, 10, "query"],
+            [2, 10, "operator"],
+            [1, 10, "planner"],
+            [2, 10, "executor"]
+        ]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let ordering_keys = vec![asc!("id"), asc!("rank")];
+        let ordering_result_set = OrderingResultSet::new(result_set, ordering_keys, None);
+        let mut iterator = ordering_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1, "rank" => 10, "name" => "query");
+        assert_next_row!(iterator.as_mut(), "id" => 1, "rank" => 10, "name" => "planner");
+        assert_next_row!(iterator.as_mut(), "id" => 2, "rank" => 10, "name" => "relop");
+        assert_next_row!(iterator.as_mut(), "id" => 2, "rank" => 10, "name" => "operator");
+        assert_next_row!(iterator.as_mut(), "id" => 2, "rank" => 10, "name" => "executor");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn ordering_result_set_with_pushed_down_limit_preserves_insertion_order_for_equal_keys() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "rank" => ColumnType::Int, "name" => ColumnType::Text]
+                .unwrap(),
+        );
+        let table_store = TableStore::new();
+        table_store.insert_all(rows![
+            [2, 10, "relop"],
+            [1, 10, "query"],
+            [2, 10, "operator"],
+            [1, 10, "planner"],
+            [2, 10, "executor"],
+            [2, 10, "optimizer"]
+        ]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let ordering_keys = vec![asc!("id"), asc!("rank")];
+        let ordering_result_set = OrderingResultSet::new(result_set, ordering_keys, Some(4));
+        let mut iterator = ordering_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1, "rank" => 10, "name" => "query");
+        assert_next_row!(iterator.as_mut(), "id" => 1, "rank" => 10, "name" => "planner");
+        assert_next_row!(iterator.as_mut(), "id" => 2, "rank" => 10, "name" => "relop");
+        assert_next_row!(iterator.as_mut(), "id" => 2, "rank" => 10, "name" => "operator");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
     #[test]
     fn ordering_result_set_with_unknown_column_fails() {
         let table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
@@ -268,4 +345,26 @@ mod tests {
             Err(ExecutionError::TypeMismatchInComparison)
         ));
     }
+
+    #[test]
+    fn column_names_of_ordering_result_set_over_a_projection() {
+        use crate::query::executor::project_result_set::ProjectResultSet;
+        use crate::query::parser::projection::ProjectedColumn;
+
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_scan = TableScan::new(Arc::new(TableStore::new()));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+        let projected_result_set = Box::new(
+            ProjectResultSet::new(result_set, &[ProjectedColumn::aliased("name", "full_name")])
+                .unwrap(),
+        );
+
+        let ordering_result_set =
+            OrderingResultSet::new(projected_result_set, vec![asc!("id")], None);
+
+        assert_eq!(vec!["full_name"], ordering_result_set.column_names());
+    }
 }
@@ -1,62 +1,178 @@
 use crate::query::executor::error::ExecutionError;
 use crate::query::executor::result_set::{ResultSet, RowViewResult};
+use crate::query::parser::ast::Literal;
+use crate::query::parser::projection::ProjectedColumn;
+use crate::query::plan::predicate::ValueResolver;
 use crate::schema::Schema;
+use crate::storage::row::Row;
+use crate::storage::row_view::RowView;
 
 /// A `ResultSet` implementation that applies a projection (column selection)
 /// to an underlying `ResultSet`.
 ///
-/// `ProjectResultSet` wraps another `ResultSet` and filters the columns visible
-/// in the produced `RowView`s.
+/// `ProjectResultSet` wraps another `ResultSet` and produces `RowView`s in which only
+/// the projected columns are visible. Aliased columns are exposed under their alias,
+/// unaliased columns keep their original names.
+///
+/// Each produced row holds the projected values first, followed by the remaining
+/// input values which are not visible. Keeping them allows operators above the projection
+/// (e.g. `ORDER BY`) to refer to columns which are not part of the projection,
+/// including the original name of an aliased column. Input columns whose name would
+/// clash with an alias are not kept, so that the alias always resolves unambiguously.
 pub struct ProjectResultSet {
     inner: Box<dyn ResultSet>,
+    positions: Vec<usize>,
+    input_column_count: usize,
+    expressions: Vec<Literal>,
+    projected_schema: Schema,
     visible_positions: Vec<usize>,
 }
 
 impl ProjectResultSet {
     /// Creates a new `ProjectResultSet`.
     ///
+    /// Computed columns are evaluated for each input row, and placed after the input values,
+    /// so that each projected column is identified by its position in the extended row.
+    ///
     /// # Arguments
     ///
     /// * `inner` - The source `ResultSet` to project from.
-    /// * `columns` - The list of column names to include in the projection.
+    /// * `columns` - The list of columns (with optional aliases) to include in the projection.
     ///
     /// # Returns
     ///
     /// * `Ok(ProjectResultSet)` if all specified columns exist in the source schema.
     /// * `Err(ExecutionError::UnknownColumn)` if any column is not found.
-    pub(crate) fn new<T: AsRef<str>>(
+    pub(crate) fn new(
         inner: Box<dyn ResultSet>,
-        columns: &[T],
+        columns: &[ProjectedColumn],
     ) -> Result<ProjectResultSet, ExecutionError> {
         let schema = inner.schema();
 
-        let positions = columns
+        let mut expressions = Vec::new();
+        let mut computed_columns = Vec::new();
+        let mut projected_positions = Vec::with_capacity(columns.len());
+        for column in columns {
+            if let (Some(expression), Some(alias)) = (column.expression(), column.alias()) {
+                projected_positions.push((schema.column_count() + expressions.len(), None));
+                let column_type = expression
+                    .value_type()
+                    .ok_or_else(|| ExecutionError::UnknownColumn(column.name().to_string()))?;
+                expressions.push(expression.clone());
+                computed_columns.push((alias, column_type));
+                continue;
+            }
+            let position = Row::column_position(schema, column.name())?;
+            projected_positions.push((position, column.alias()));
+        }
+
+        let visible_positions = (0..projected_positions.len()).collect();
+
+        let shadowed_positions = columns
             .iter()
-            .map(|column_name| {
-                schema
-                    .column_position(column_name.as_ref())
-                    .map_err(ExecutionError::Schema)?
-                    .ok_or_else(|| ExecutionError::UnknownColumn(column_name.as_ref().to_string()))
+            .filter_map(|column| column.alias())
+            .flat_map(|alias| schema.matching_column_positions(alias))
+            .collect::<Vec<_>>();
+
+        let hidden_positions = (0..schema.column_count())
+            .filter(|position| {
+                !projected_positions.contains(&(*position, None))
+                    && !shadowed_positions.contains(position)
             })
-            .collect::<Result<Vec<_>, _>>()?;
+            .map(|position| (position, None))
+            .collect::<Vec<_>>();
+        projected_positions.extend(hidden_positions);
+
+        let projected_schema = schema
+            .with_computed_columns(&computed_columns)
+            .project_positions(&projected_positions);
+        let positions = projected_positions
+            .into_iter()
+            .map(|(position, _)| position)
+            .collect();
 
+        let input_column_count = schema.column_count();
         Ok(ProjectResultSet {
             inner,
-            visible_positions: positions,
+            positions,
+            input_column_count,
+            expressions,
+            projected_schema,
+            visible_positions,
         })
     }
+
+    /// Creates a new `ProjectResultSet` projecting all the columns of a single table or alias
+    /// (e.g., `employees.*`).
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The source `ResultSet` to project from.
+    /// * `qualifier` - The table name or alias whose columns are projected.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProjectResultSet)` if the source schema has columns qualified with `qualifier`.
+    /// * `Err(ExecutionError::TableAliasNotFound)` otherwise.
+    pub(crate) fn qualified(
+        inner: Box<dyn ResultSet>,
+        qualifier: &str,
+    ) -> Result<ProjectResultSet, ExecutionError> {
+        let columns = inner
+            .schema()
+            .column_names_with_prefix(qualifier)
+            .into_iter()
+            .map(ProjectedColumn::new)
+            .collect::<Vec<_>>();
+
+        if columns.is_empty() {
+            return Err(ExecutionError::TableAliasNotFound(qualifier.to_string()));
+        }
+        Self::new(inner, &columns)
+    }
 }
 
 impl ResultSet for ProjectResultSet {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
         let inner_iterator = self.inner.iterator()?;
         Ok(Box::new(inner_iterator.map(move |row_view_result| {
-            row_view_result.map(|row_view| row_view.project(&self.visible_positions))
+            row_view_result.and_then(|row_view| {
+                let computed_values = self
+                    .expressions
+                    .iter()
+                    .map(|expression| row_view.resolve(expression))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let values = self
+                    .positions
+                    .iter()
+                    .map(
+                        |&position| match position.checked_sub(self.input_column_count) {
+                            Some(index) => computed_values[index].clone(),
+                            None => row_view.column_value_at_unchecked(position).clone(),
+                        },
+                    )
+                    .collect();
+                Ok(RowView::new(
+                    Row::filled(values),
+                    &self.projected_schema,
+                    &self.visible_positions,
+                ))
+            })
         })))
     }
 
     fn schema(&self) -> &Schema {
-        self.inner.schema()
+        &self.projected_schema
+    }
+
+    /// Returns the names of the projected columns only, the columns kept for the operators
+    /// above the projection are not visible.
+    fn column_names(&self) -> Vec<String> {
+        self.visible_positions
+            .iter()
+            .filter_map(|position| self.projected_schema.columns().get(*position))
+            .map(|column| column.name().to_string())
+            .collect()
     }
 }
 
@@ -69,11 +185,12 @@ mod tests {
 
     use super::*;
     use crate::query::executor::filter_result_set::FilterResultSet;
-    use crate::query::parser::ast::Literal;
+    use crate::query::executor::nested_loop_join_result_set::NestedLoopJoinResultSet;
+    use crate::query::parser::ast::{ArithmeticOperator, JoinType};
     use crate::query::plan::predicate::{LogicalOperator, Predicate};
     use crate::storage::table_store::TableStore;
     use crate::types::column_type::ColumnType;
-    use crate::{assert_next_row, assert_no_more_rows, row, schema};
+    use crate::{assert_next_row, assert_no_more_rows, row, rows, schema};
 
     #[test]
     fn projected_result_set() {
@@ -87,7 +204,8 @@ mod tests {
         let table_scan = TableScan::new(Arc::new(table_store));
         let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
 
-        let projected_result_set = ProjectResultSet::new(result_set, &["name"]).unwrap();
+        let projected_result_set =
+            ProjectResultSet::new(result_set, &[ProjectedColumn::new("name")]).unwrap();
         let mut iterator = projected_result_set.iterator().unwrap();
 
         assert_next_row!(iterator.as_mut(), "name" => "relop", ! "id");
@@ -113,7 +231,8 @@ mod tests {
                 Literal::Int(1),
             ),
         ));
-        let projected_result_set = ProjectResultSet::new(filter_result_set, &["name"]).unwrap();
+        let projected_result_set =
+            ProjectResultSet::new(filter_result_set, &[ProjectedColumn::new("name")]).unwrap();
 
         let mut iterator = projected_result_set.iterator().unwrap();
 
@@ -130,12 +249,46 @@ mod tests {
         let table_scan = TableScan::new(Arc::new(table_store));
         let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
 
-        let result = ProjectResultSet::new(result_set, &["name"]);
+        let result = ProjectResultSet::new(result_set, &[ProjectedColumn::new("name")]);
         assert!(
             matches!(result, Err(ExecutionError::UnknownColumn(column_name)) if column_name == "name"),
         );
     }
 
+    #[test]
+    fn qualified_projected_result_set() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_store = TableStore::new();
+        table_store.insert(row![1, "relop"]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let projected_result_set = ProjectResultSet::qualified(result_set, "employees").unwrap();
+        let mut iterator = projected_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "employees.name" => "relop");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_get_qualified_projected_result_set_with_non_existent_qualifier() {
+        let table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let table_store = TableStore::new();
+        table_store.insert(row![1]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let result = ProjectResultSet::qualified(result_set, "departments");
+        assert!(
+            matches!(result, Err(ExecutionError::TableAliasNotFound(qualifier)) if qualifier == "departments"),
+        );
+    }
+
     #[test]
     fn project_result_set_with_ambiguous_column_fails() {
         let table_store = TableStore::new();
@@ -150,12 +303,261 @@ mod tests {
         let table = Table::new("combined", schema);
         let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
 
-        let columns = vec!["id".to_string()];
+        let columns = vec![ProjectedColumn::new("id")];
         let project_result_set = ProjectResultSet::new(result_set, &columns);
 
         assert!(matches!(
             project_result_set,
-            Err(ExecutionError::Schema(schema::error::SchemaError::AmbiguousColumnName(ref column_name))) if column_name == "id"
+            Err(ExecutionError::AmbiguousColumn(ref column_name)) if column_name == "id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_project_ambiguous_column_over_join() {
+        let project_result_set = ProjectResultSet::new(
+            employees_join_departments(),
+            &[ProjectedColumn::new("name")],
+        );
+
+        assert!(matches!(
+            project_result_set,
+            Err(ExecutionError::AmbiguousColumn(ref column_name)) if column_name == "name"
+        ));
+    }
+
+    #[test]
+    fn project_qualified_columns_over_join() {
+        let project_result_set = ProjectResultSet::new(
+            employees_join_departments(),
+            &[
+                ProjectedColumn::new("employees.name"),
+                ProjectedColumn::new("departments.name"),
+            ],
+        )
+        .unwrap();
+
+        let mut iterator = project_result_set.iterator().unwrap();
+        assert_next_row!(iterator.as_mut(), "employees.name" => "relop", "departments.name" => "engineering");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    fn employees_join_departments() -> Box<dyn ResultSet> {
+        let employees_store = TableStore::new();
+        employees_store.insert(row![1, "relop"]);
+        let employees = Box::new(ScanResultsSet::new(
+            TableScan::new(Arc::new(employees_store)),
+            Arc::new(Table::new(
+                "employees",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )),
+            None,
         ));
+
+        let departments_store = TableStore::new();
+        departments_store.insert(row![1, "engineering"]);
+        let departments = Box::new(ScanResultsSet::new(
+            TableScan::new(Arc::new(departments_store)),
+            Arc::new(Table::new(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )),
+            None,
+        ));
+
+        Box::new(NestedLoopJoinResultSet::new(
+            employees,
+            departments,
+            None,
+            JoinType::Inner,
+        ))
+    }
+
+    #[test]
+    fn projected_result_set_with_alias() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_store = TableStore::new();
+        table_store.insert(row![1, "relop"]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let projected_result_set = ProjectResultSet::new(
+            result_set,
+            &[
+                ProjectedColumn::aliased("id", "employee_id"),
+                ProjectedColumn::new("name"),
+            ],
+        )
+        .unwrap();
+        let mut iterator = projected_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employee_id" => 1, "name" => "relop", ! "id");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn projected_result_set_schema_reflects_alias() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_scan = TableScan::new(Arc::new(TableStore::new()));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let projected_result_set = ProjectResultSet::new(
+            result_set,
+            &[
+                ProjectedColumn::aliased("name", "full_name"),
+                ProjectedColumn::new("id"),
+            ],
+        )
+        .unwrap();
+
+        let column_names = projected_result_set.schema().column_names();
+        assert_eq!("full_name", column_names[0]);
+        assert_eq!("employees.id", column_names[1]);
+    }
+
+    #[test]
+    fn projected_result_set_with_alias_shadowing_another_column() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_store = TableStore::new();
+        table_store.insert(row![1, "relop"]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let projected_result_set =
+            ProjectResultSet::new(result_set, &[ProjectedColumn::aliased("id", "name")]).unwrap();
+        let mut iterator = projected_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "name" => 1, ! "id");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn projected_result_set_with_computed_column() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "salary" => ColumnType::Int].unwrap(),
+        );
+        let table_store = TableStore::new();
+        table_store.insert(row![1, 900]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let projected_result_set = ProjectResultSet::new(
+            result_set,
+            &[
+                ProjectedColumn::new("id"),
+                ProjectedColumn::computed(
+                    Literal::arithmetic(
+                        Literal::ColumnReference("salary".to_string()),
+                        ArithmeticOperator::Mul,
+                        Literal::Int(12),
+                    ),
+                    "annual",
+                ),
+            ],
+        )
+        .unwrap();
+        let mut iterator = projected_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1, "annual" => 10800, ! "salary");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn projected_result_set_with_constant_column() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_store = TableStore::new();
+        table_store.insert_all(rows![[1, "relop"], [2, "query"]]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let projected_result_set = ProjectResultSet::new(
+            result_set,
+            &[
+                ProjectedColumn::computed(Literal::Int(1), "one"),
+                ProjectedColumn::new("name"),
+            ],
+        )
+        .unwrap();
+        let mut iterator = projected_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "one" => 1, "name" => "relop", ! "id");
+        assert_next_row!(iterator.as_mut(), "one" => 1, "name" => "query", ! "id");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn attempt_to_iterate_projected_result_set_with_computed_column_over_text() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_store = TableStore::new();
+        table_store.insert(row![1, "relop"]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let projected_result_set = ProjectResultSet::new(
+            result_set,
+            &[ProjectedColumn::computed(
+                Literal::arithmetic(
+                    Literal::ColumnReference("name".to_string()),
+                    ArithmeticOperator::Add,
+                    Literal::Int(1),
+                ),
+                "next",
+            )],
+        )
+        .unwrap();
+        let result = projected_result_set
+            .iterator()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>();
+
+        assert!(matches!(
+            result,
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
+    #[test]
+    fn column_names_of_projected_result_set_with_alias() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text, "city" => ColumnType::Text]
+                .unwrap(),
+        );
+        let table_scan = TableScan::new(Arc::new(TableStore::new()));
+        let result_set = Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None));
+
+        let projected_result_set = ProjectResultSet::new(
+            result_set,
+            &[
+                ProjectedColumn::aliased("name", "full_name"),
+                ProjectedColumn::new("id"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec!["full_name", "employees.id"],
+            projected_result_set.column_names()
+        );
     }
 }
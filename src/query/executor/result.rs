@@ -1,19 +1,38 @@
 use crate::catalog::table::Table;
+use crate::query::executor::error::ExecutionError;
 use crate::query::executor::result_set::ResultSet;
+use crate::types::column_value::ColumnValue;
 use std::sync::Arc;
 
 /// Represents the result of a query execution.
 pub enum QueryResult {
-    /// Result of a `SHOW TABLES` query, containing a list of table names.
+    /// Result of a `SHOW TABLES` query, containing the table names sorted alphabetically.
     TableList(Vec<String>),
     /// Result of a `DESCRIBE TABLE` query, containing the table's schema information.
     TableDescription(Arc<Table>),
     /// Result of a `SELECT *` query without where clause.
     ResultSet(Box<dyn ResultSet>),
+    /// Result of an `EXPLAIN` query, containing the rendered (optimized) logical plan.
+    Plan(String),
+    /// Result of an `INSERT` query, containing the number of inserted rows and the values of the
+    /// auto-increment column of the inserted rows (empty if the table has none).
+    RowsInserted {
+        /// The number of inserted rows.
+        count: usize,
+        /// The values of the auto-increment column of the inserted rows, in the order of the rows.
+        generated_ids: Vec<i64>,
+    },
+    /// Result of an `UPDATE` or a `DELETE` query, containing the number of updated (or deleted)
+    /// rows.
+    RowsAffected(usize),
+    /// Result of a `CREATE TABLE` query, containing the name of the created table.
+    TableCreated(String),
+    /// Result of a `DROP TABLE` query, containing the name of the dropped table.
+    TableDropped(String),
 }
 
 impl QueryResult {
-    /// Returns the list of tables if the result is a `TableList`.
+    /// Returns the list of tables if the result is a `TableList`, sorted alphabetically.
     ///
     /// # Returns
     ///
@@ -51,6 +70,299 @@ impl QueryResult {
             _ => None,
         }
     }
+
+    /// Returns the rendered logical plan if the result is a `Plan`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` - If the result is a `Plan`.
+    /// * `None` - Otherwise.
+    pub fn plan(&self) -> Option<&str> {
+        match self {
+            QueryResult::Plan(plan) => Some(plan),
+            _ => None,
+        }
+    }
+
+    /// Exports the rows of a `ResultSet` as CSV: a header row of column names, followed by a
+    /// line per row, with the fields separated by commas.
+    ///
+    /// Fields containing a comma, a double quote or a line break are enclosed in double quotes,
+    /// with the double quotes inside them doubled (as in RFC 4180); `Null` is exported as an
+    /// empty field. Lines end with `\n`.
+    ///
+    /// This runs the query (see [`ResultSet::iterator`]), and returns the first error
+    /// encountered while running it. The results other than `ResultSet` have no rows, and are
+    /// exported as an empty string.
+    pub fn to_csv(&self) -> Result<String, ExecutionError> {
+        let result_set = match self {
+            QueryResult::ResultSet(result_set) => result_set,
+            _ => return Ok(String::new()),
+        };
+
+        let mut csv = String::new();
+        let header = result_set
+            .column_names()
+            .iter()
+            .map(|column_name| csv_field(column_name))
+            .collect::<Vec<_>>();
+        csv.push_str(&header.join(","));
+        csv.push('\n');
+
+        for row_view in result_set.iterator()? {
+            let row = row_view?.to_row();
+            let fields = row
+                .column_values()
+                .iter()
+                .map(|value| match value {
+                    ColumnValue::Int(value) => value.to_string(),
+                    ColumnValue::Text(value) => csv_field(value),
+                    ColumnValue::Bool(value) => value.to_string(),
+                    ColumnValue::Date(value) => value.to_string(),
+                    ColumnValue::Null => String::new(),
+                })
+                .collect::<Vec<_>>();
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        Ok(csv)
+    }
+
+    /// Returns the number of inserted rows if the result is `RowsInserted`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - If the result is `RowsInserted`.
+    /// * `None` - Otherwise.
+    pub fn rows_inserted(&self) -> Option<usize> {
+        match self {
+            QueryResult::RowsInserted { count, .. } => Some(*count),
+            _ => None,
+        }
+    }
+
+    /// Returns the values of the auto-increment column of the inserted rows if the result is
+    /// `RowsInserted`, in the order of the rows (empty if the table has no auto-increment column).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&[i64])` - If the result is `RowsInserted`.
+    /// * `None` - Otherwise.
+    pub fn generated_ids(&self) -> Option<&[i64]> {
+        match self {
+            QueryResult::RowsInserted { generated_ids, .. } => Some(generated_ids),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of rows inserted, updated or deleted if the result is `RowsAffected`
+    /// or `RowsInserted`, giving a uniform way to check the result of a mutation.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - If the result is `RowsAffected` or `RowsInserted`.
+    /// * `None` - Otherwise.
+    pub fn rows_affected(&self) -> Option<usize> {
+        match self {
+            QueryResult::RowsAffected(count) | QueryResult::RowsInserted { count, .. } => {
+                Some(*count)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the created table if the result is `TableCreated`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` - If the result is `TableCreated`.
+    /// * `None` - Otherwise.
+    pub fn created_table(&self) -> Option<&str> {
+        match self {
+            QueryResult::TableCreated(table_name) => Some(table_name),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the dropped table if the result is `TableDropped`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` - If the result is `TableDropped`.
+    /// * `None` - Otherwise.
+    pub fn dropped_table(&self) -> Option<&str> {
+        match self {
+            QueryResult::TableDropped(table_name) => Some(table_name),
+            _ => None,
+        }
+    }
+}
+
+/// Renders the result for a REPL: a bordered table with a header row for a `ResultSet` (and for
+/// the columns of a `TableDescription`), one table name per line for a `TableList`, and a short
+/// message for the other results.
+///
+/// ```text
+/// +--------------+----------------+
+/// | employees.id | employees.name |
+/// +--------------+----------------+
+/// |            1 | relop          |
+/// +--------------+----------------+
+/// ```
+///
+/// Rendering a `ResultSet` runs the query (see [`ResultSet::iterator`]); an error while running it
+/// is rendered in place of the table.
+impl std::fmt::Display for QueryResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryResult::TableList(tables) => {
+                for table in tables {
+                    writeln!(f, "{}", table)?;
+                }
+                Ok(())
+            }
+            QueryResult::TableDescription(table) => {
+                let rows = table
+                    .column_names()
+                    .into_iter()
+                    .zip(table.column_types())
+                    .zip(table.column_nullability())
+                    .map(|((column_name, column_type), nullable)| {
+                        vec![
+                            Cell::left(column_name.to_string()),
+                            Cell::left(column_type.to_string()),
+                            Cell::left(if nullable { "yes" } else { "no" }.to_string()),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                writeln!(f, "table: {}", table.name())?;
+                write_table(
+                    f,
+                    &[
+                        "column".to_string(),
+                        "type".to_string(),
+                        "nullable".to_string(),
+                    ],
+                    &rows,
+                )
+            }
+            QueryResult::ResultSet(result_set) => {
+                let iterator = match result_set.iterator() {
+                    Ok(iterator) => iterator,
+                    Err(error) => return writeln!(f, "error: {}", error),
+                };
+                let mut rows = Vec::new();
+                for row_view in iterator {
+                    match row_view {
+                        Ok(row_view) => rows.push(
+                            row_view
+                                .to_row()
+                                .column_values()
+                                .iter()
+                                .map(Cell::from)
+                                .collect::<Vec<_>>(),
+                        ),
+                        Err(error) => return writeln!(f, "error: {}", error),
+                    }
+                }
+                write_table(f, &result_set.column_names(), &rows)
+            }
+            QueryResult::Plan(plan) => write!(f, "{}", plan),
+            QueryResult::RowsInserted { count, .. } => writeln!(f, "{} row(s) inserted", count),
+            QueryResult::RowsAffected(count) => writeln!(f, "{} row(s) affected", count),
+            QueryResult::TableCreated(table_name) => writeln!(f, "table {} created", table_name),
+            QueryResult::TableDropped(table_name) => writeln!(f, "table {} dropped", table_name),
+        }
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// A rendered value of a table cell, numbers are right-aligned and everything else is
+/// left-aligned.
+struct Cell {
+    text: String,
+    right_aligned: bool,
+}
+
+impl Cell {
+    fn left(text: String) -> Self {
+        Self {
+            text,
+            right_aligned: false,
+        }
+    }
+}
+
+impl From<&ColumnValue> for Cell {
+    fn from(value: &ColumnValue) -> Self {
+        match value {
+            ColumnValue::Int(value) => Cell {
+                text: value.to_string(),
+                right_aligned: true,
+            },
+            ColumnValue::Text(value) => Cell::left(value.clone()),
+            ColumnValue::Bool(value) => Cell::left(value.to_string()),
+            ColumnValue::Date(value) => Cell::left(value.to_string()),
+            ColumnValue::Null => Cell::left("NULL".to_string()),
+        }
+    }
+}
+
+fn write_table(
+    f: &mut std::fmt::Formatter<'_>,
+    headers: &[String],
+    rows: &[Vec<Cell>],
+) -> std::fmt::Result {
+    let mut widths = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.text.chars().count());
+        }
+    }
+
+    let border = widths
+        .iter()
+        .map(|width| "-".repeat(width + 2))
+        .collect::<Vec<_>>()
+        .join("+");
+    let border = format!("+{}+", border);
+
+    writeln!(f, "{}", border)?;
+    let header_cells = headers
+        .iter()
+        .zip(&widths)
+        .map(|(header, width)| format!(" {:<width$} ", header, width = width))
+        .collect::<Vec<_>>();
+    writeln!(f, "|{}|", header_cells.join("|"))?;
+    writeln!(f, "{}", border)?;
+    for row in rows {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                if cell.right_aligned {
+                    format!(" {:>width$} ", cell.text, width = width)
+                } else {
+                    format!(" {:<width$} ", cell.text, width = width)
+                }
+            })
+            .collect::<Vec<_>>();
+        writeln!(f, "|{}|", cells.join("|"))?;
+    }
+    if !rows.is_empty() {
+        writeln!(f, "{}", border)?;
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -59,6 +371,7 @@ mod tests {
     use crate::catalog::table::Table;
     use crate::query::executor::result_set::{ResultSet, RowViewResult};
     use crate::schema;
+    use crate::schema::column::Column;
     use crate::schema::Schema;
     use crate::types::column_type::ColumnType;
     use std::sync::Arc;
@@ -69,7 +382,7 @@ mod tests {
         fn iterator(
             &self,
         ) -> Result<
-            Box<dyn Iterator<Item = RowViewResult> + '_>,
+            Box<dyn Iterator<Item = RowViewResult<'_>> + '_>,
             crate::query::executor::error::ExecutionError,
         > {
             unimplemented!()
@@ -112,4 +425,152 @@ mod tests {
         assert!(result.all_tables().is_none());
         assert!(result.table_descriptor().is_none());
     }
+
+    #[test]
+    fn query_result_plan() {
+        let result = QueryResult::Plan("Scan (table: employees)".to_string());
+
+        assert_eq!(result.plan(), Some("Scan (table: employees)"));
+        assert!(result.result_set().is_none());
+        assert!(result.all_tables().is_none());
+        assert!(result.table_descriptor().is_none());
+    }
+
+    #[test]
+    fn query_result_rows_inserted() {
+        let result = QueryResult::RowsInserted {
+            count: 2,
+            generated_ids: vec![],
+        };
+
+        assert_eq!(result.rows_inserted(), Some(2));
+        assert_eq!(result.rows_affected(), Some(2));
+        assert!(result.result_set().is_none());
+        assert!(result.plan().is_none());
+    }
+
+    #[test]
+    fn query_result_rows_affected() {
+        let result = QueryResult::RowsAffected(3);
+
+        assert_eq!(result.rows_affected(), Some(3));
+        assert!(result.rows_inserted().is_none());
+        assert!(result.result_set().is_none());
+    }
+
+    #[test]
+    fn query_result_without_rows_affected() {
+        let result = QueryResult::TableCreated("employees".to_string());
+
+        assert!(result.rows_affected().is_none());
+    }
+
+    #[test]
+    fn query_result_table_created() {
+        let result = QueryResult::TableCreated("employees".to_string());
+
+        assert_eq!(result.created_table(), Some("employees"));
+        assert!(result.table_descriptor().is_none());
+        assert!(result.result_set().is_none());
+    }
+
+    #[test]
+    fn csv_field_without_special_characters() {
+        assert_eq!("relop", csv_field("relop"));
+    }
+
+    #[test]
+    fn csv_field_with_comma_quote_and_line_break() {
+        assert_eq!("\"a,b\"", csv_field("a,b"));
+        assert_eq!("\"O\"\"Brien\"", csv_field("O\"Brien"));
+        assert_eq!("\"line\nbreak\"", csv_field("line\nbreak"));
+    }
+
+    #[test]
+    fn csv_of_a_result_without_rows() {
+        let result = QueryResult::RowsInserted {
+            count: 2,
+            generated_ids: vec![],
+        };
+
+        assert_eq!("", result.to_csv().unwrap());
+    }
+
+    #[test]
+    fn display_table_list() {
+        let result =
+            QueryResult::TableList(vec!["employees".to_string(), "departments".to_string()]);
+
+        assert_eq!("employees\ndepartments\n", result.to_string());
+    }
+
+    #[test]
+    fn display_table_description() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let result = QueryResult::TableDescription(Arc::new(Table::new("employees", schema)));
+
+        assert_eq!(
+            "table: employees\n\
+             +--------+------+----------+\n\
+             | column | type | nullable |\n\
+             +--------+------+----------+\n\
+             | id     | int  | yes      |\n\
+             | name   | text | yes      |\n\
+             +--------+------+----------+\n",
+            result.to_string()
+        );
+    }
+
+    #[test]
+    fn display_table_description_with_a_not_null_column() {
+        let schema = Schema::new()
+            .add_column_definition(Column::new("id", ColumnType::Int).not_null())
+            .unwrap()
+            .add_column("joined", ColumnType::Date)
+            .unwrap();
+        let result = QueryResult::TableDescription(Arc::new(Table::new("employees", schema)));
+
+        assert_eq!(
+            "table: employees\n\
+             +--------+------+----------+\n\
+             | column | type | nullable |\n\
+             +--------+------+----------+\n\
+             | id     | int  | no       |\n\
+             | joined | date | yes      |\n\
+             +--------+------+----------+\n",
+            result.to_string()
+        );
+    }
+
+    #[test]
+    fn display_counts_and_table_names() {
+        assert_eq!(
+            "2 row(s) inserted\n",
+            QueryResult::RowsInserted {
+                count: 2,
+                generated_ids: vec![],
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "1 row(s) affected\n",
+            QueryResult::RowsAffected(1).to_string()
+        );
+        assert_eq!(
+            "table employees created\n",
+            QueryResult::TableCreated("employees".to_string()).to_string()
+        );
+        assert_eq!(
+            "table employees dropped\n",
+            QueryResult::TableDropped("employees".to_string()).to_string()
+        );
+    }
+
+    #[test]
+    fn query_result_table_dropped() {
+        let result = QueryResult::TableDropped("employees".to_string());
+
+        assert_eq!(result.dropped_table(), Some("employees"));
+        assert!(result.created_table().is_none());
+    }
 }
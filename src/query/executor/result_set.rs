@@ -1,5 +1,6 @@
 use crate::query::executor::error::ExecutionError;
 use crate::schema::Schema;
+use crate::storage::row::Row;
 use crate::storage::row_view::RowView;
 
 /// Represents the result of a query, providing access to the rows and column values.
@@ -24,9 +25,83 @@ use crate::storage::row_view::RowView;
 pub trait ResultSet {
     // Return a boxed iterator that yields Result<RowView, ...>
     // The iterator is bound by the lifetime of &self
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError>;
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError>;
 
     fn schema(&self) -> &Schema;
+
+    /// Returns the names of the visible columns of the result, in order.
+    ///
+    /// The names include the table prefixes (e.g., `employees.id`) and the projection aliases.
+    /// By default, all the columns of the schema are visible.
+    fn column_names(&self) -> Vec<String> {
+        self.schema()
+            .column_names()
+            .into_iter()
+            .map(|column_name| column_name.to_string())
+            .collect()
+    }
+
+    /// Returns the number of rows in the result set.
+    ///
+    /// This drives a fresh iterator to exhaustion, so the query is run (e.g., the table is
+    /// scanned, the rows are sorted) once for counting, and once more for every later
+    /// iteration. The limit of the query, if any, is applied (`select * from employees limit 2`
+    /// counts at most 2 rows).
+    ///
+    /// Returns the first error encountered while iterating.
+    fn count(&self) -> Result<usize, ExecutionError> {
+        let mut count = 0;
+        for row_view in self.iterator()? {
+            row_view?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Returns an iterator over the rows of the result set as owned `Row`s.
+    ///
+    /// Unlike [`ResultSet::collect_rows`], the rows are produced on demand, so the iterator
+    /// composes with the standard iterator adapters (e.g., `filter`, `take`) without
+    /// materializing the whole result. Each `Row` holds only the visible column values of a
+    /// result row, and does not borrow from the result set.
+    fn rows(
+        &self,
+    ) -> Result<Box<dyn Iterator<Item = Result<Row, ExecutionError>> + '_>, ExecutionError> {
+        Ok(Box::new(self.iterator()?.map(|row_view| {
+            row_view.map(|row_view| row_view.to_row())
+        })))
+    }
+
+    /// Materializes all the rows of the result set into owned `Row`s.
+    ///
+    /// Each `Row` holds only the visible column values of a result row, in the order of the
+    /// columns of the result (e.g., the projected columns in the order of projection, or the
+    /// columns of the left side of a join followed by the ones of the right side).
+    ///
+    /// Returns the first error encountered while iterating.
+    fn collect_rows(&self) -> Result<Vec<Row>, ExecutionError> {
+        self.rows()?.collect()
+    }
+}
+
+/// Returns the schema of the visible columns of the result set, in order.
+///
+/// The schema of some result sets (e.g., a projection) also holds columns which are not visible,
+/// kept for the operators above them.
+pub(crate) fn visible_schema(result_set: &dyn ResultSet) -> Schema {
+    let schema = result_set.schema();
+    let positions = result_set
+        .column_names()
+        .iter()
+        .filter_map(|column_name| {
+            schema
+                .columns()
+                .iter()
+                .position(|column| column.name() == column_name)
+        })
+        .map(|position| (position, None))
+        .collect::<Vec<_>>();
+    schema.project_positions(&positions)
 }
 
 /// Represents the result for an individual RowView.
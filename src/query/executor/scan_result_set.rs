@@ -11,10 +11,18 @@ use std::sync::Arc;
 ///
 /// `ScanResultsSet` holds a reference to the table data via `TableScan` (the owner)
 /// and produces iterators that view all rows in the table.
+///
+/// An optional limit (pushed down by the optimizer) stops the scan once that many rows
+/// have been produced, so the remaining rows of the table are never read.
+///
+/// With a parallelism of more than one (and no limit), the filter is evaluated across that many
+/// threads, and the matching rows are produced in the same order as the ones of a sequential scan.
 pub struct ScanResultsSet<F: RowFilter = NoFilter> {
     table_scan: TableScan<F>,
     visible_positions: Arc<Vec<usize>>,
     prefixed_schema: Schema,
+    limit: Option<usize>,
+    parallelism: usize,
 }
 
 impl<F: RowFilter> ScanResultsSet<F> {
@@ -35,17 +43,53 @@ impl<F: RowFilter> ScanResultsSet<F> {
             table_scan,
             visible_positions: Arc::new(column_positions),
             prefixed_schema,
+            limit: None,
+            parallelism: 1,
         }
     }
+
+    /// Sets the maximum number of rows produced by the scan.
+    ///
+    /// The limit applies after the filter of the `TableScan` (if any).
+    pub(crate) fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Sets the number of threads the filter of the `TableScan` is evaluated across.
+    ///
+    /// A parallel scan reads all the rows of the table before producing any, so it is not used
+    /// with a limit, which stops a sequential scan early.
+    pub(crate) fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
 }
 
-impl<F: RowFilter + 'static> ResultSet for ScanResultsSet<F> {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
+impl<F: RowFilter + 'static> ResultSet for ScanResultsSet<F>
+where
+    ExecutionError: From<F::Error>,
+{
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        if self.parallelism > 1 && self.limit.is_none() {
+            let rows = self.table_scan.filter_in_parallel(self.parallelism)?;
+            return Ok(Box::new(rows.into_iter().map(move |row| {
+                Ok(RowView::new(
+                    row,
+                    &self.prefixed_schema,
+                    &self.visible_positions,
+                ))
+            })));
+        }
         // We call .iter() on TableScan, which returns a TableIterator.
         // We map that iterator to RowView.
-        Ok(Box::new(self.table_scan.iter().map(move |row| {
+        let rows = self
+            .table_scan
+            .iter()
+            .take(self.limit.unwrap_or(usize::MAX));
+        Ok(Box::new(rows.map(move |row| {
             Ok(RowView::new(
-                row,
+                row?,
                 &self.prefixed_schema,
                 &self.visible_positions,
             ))
@@ -62,6 +106,7 @@ mod tests {
     use crate::catalog::table::Table;
     use crate::catalog::table_scan::TableScan;
     use crate::query::executor::scan_result_set::ScanResultsSet;
+    use std::convert::Infallible;
     use std::sync::Arc;
 
     use super::*;
@@ -69,6 +114,7 @@ mod tests {
     use crate::storage::table_store::TableStore;
     use crate::types::column_type::ColumnType;
     use crate::{assert_next_row, assert_no_more_rows, row, schema};
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn scan_result_set() {
@@ -100,8 +146,10 @@ mod tests {
 
         struct MatchingRelopFilter;
         impl RowFilter for MatchingRelopFilter {
-            fn matches(&self, row: &Row) -> bool {
-                row.column_value_at(1).unwrap().text_value().unwrap() == "relop"
+            type Error = Infallible;
+
+            fn matches(&self, row: &Row) -> Result<bool, Infallible> {
+                Ok(row.column_value_at(1).unwrap().text_value().unwrap() == "relop")
             }
         }
 
@@ -114,6 +162,124 @@ mod tests {
         assert_no_more_rows!(iterator.as_mut());
     }
 
+    #[test]
+    fn scan_result_set_with_a_limit() {
+        let table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let table_store = TableStore::new();
+        table_store.insert(row![1]);
+        table_store.insert(row![2]);
+        table_store.insert(row![3]);
+
+        let table_scan = TableScan::new(Arc::new(table_store));
+        let result_set = ScanResultsSet::new(table_scan, Arc::new(table), None).with_limit(Some(2));
+
+        let mut iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1);
+        assert_next_row!(iterator.as_mut(), "id" => 2);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn scan_result_set_with_a_limit_reads_only_the_limited_rows() {
+        let table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let table_store = TableStore::new();
+        table_store.insert(row![1]);
+        table_store.insert(row![2]);
+        table_store.insert(row![3]);
+
+        struct CountingFilter {
+            rows_read: Arc<AtomicUsize>,
+        }
+        impl RowFilter for CountingFilter {
+            type Error = Infallible;
+
+            fn matches(&self, _row: &Row) -> Result<bool, Infallible> {
+                self.rows_read.fetch_add(1, Ordering::SeqCst);
+                Ok(true)
+            }
+        }
+
+        let rows_read = Arc::new(AtomicUsize::new(0));
+        let table_scan = TableScan::with_filter(
+            Arc::new(table_store),
+            CountingFilter {
+                rows_read: rows_read.clone(),
+            },
+        );
+        let result_set = ScanResultsSet::new(table_scan, Arc::new(table), None).with_limit(Some(1));
+
+        let rows = result_set.iterator().unwrap().count();
+
+        assert_eq!(1, rows);
+        assert_eq!(1, rows_read.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn parallel_scan_result_set_with_a_filter() {
+        let table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let table_store = TableStore::new();
+        for id in 1..=6 {
+            table_store.insert(row![id]);
+        }
+
+        struct OddFilter;
+        impl RowFilter for OddFilter {
+            type Error = Infallible;
+
+            fn matches(&self, row: &Row) -> Result<bool, Infallible> {
+                Ok(row.column_value_at(0).unwrap().int_value().unwrap() % 2 == 1)
+            }
+        }
+
+        let table_scan = TableScan::with_filter(Arc::new(table_store), OddFilter);
+        let result_set = ScanResultsSet::new(table_scan, Arc::new(table), None).with_parallelism(4);
+
+        let mut iterator = result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1);
+        assert_next_row!(iterator.as_mut(), "id" => 3);
+        assert_next_row!(iterator.as_mut(), "id" => 5);
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn parallel_scan_result_set_with_a_limit_reads_only_the_limited_rows() {
+        let table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
+        let table_store = TableStore::new();
+        table_store.insert(row![1]);
+        table_store.insert(row![2]);
+        table_store.insert(row![3]);
+
+        struct CountingFilter {
+            rows_read: Arc<AtomicUsize>,
+        }
+        impl RowFilter for CountingFilter {
+            type Error = Infallible;
+
+            fn matches(&self, _row: &Row) -> Result<bool, Infallible> {
+                self.rows_read.fetch_add(1, Ordering::SeqCst);
+                Ok(true)
+            }
+        }
+
+        let rows_read = Arc::new(AtomicUsize::new(0));
+        let table_scan = TableScan::with_filter(
+            Arc::new(table_store),
+            CountingFilter {
+                rows_read: rows_read.clone(),
+            },
+        );
+        let result_set = ScanResultsSet::new(table_scan, Arc::new(table), None)
+            .with_limit(Some(1))
+            .with_parallelism(4);
+
+        let rows = result_set.iterator().unwrap().count();
+
+        assert_eq!(1, rows);
+        assert_eq!(1, rows_read.load(Ordering::SeqCst));
+    }
+
     #[test]
     fn attempt_to_get_result_set_with_non_existent_column() {
         let table = Table::new("employees", schema!["id" => ColumnType::Int].unwrap());
@@ -155,4 +321,16 @@ mod tests {
 
         assert_eq!(result_set.schema().column_names(), vec!["e.id", "e.name"]);
     }
+
+    #[test]
+    fn column_names_with_alias() {
+        let table = Table::new(
+            "employees",
+            schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+        );
+        let table_scan = TableScan::new(Arc::new(TableStore::new()));
+        let result_set = ScanResultsSet::new(table_scan, Arc::new(table), Some("e".to_string()));
+
+        assert_eq!(vec!["e.id", "e.name"], result_set.column_names());
+    }
 }
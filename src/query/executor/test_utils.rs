@@ -1,16 +1,36 @@
+use crate::catalog::table::Table;
+use crate::catalog::table_scan::TableScan;
 use crate::query::executor::error::ExecutionError;
 use crate::query::executor::result_set::{ResultSet, RowViewResult};
-use crate::row;
+use crate::query::executor::scan_result_set::ScanResultsSet;
 use crate::schema::Schema;
+use crate::storage::row::Row;
 use crate::storage::row_view::RowView;
+use crate::storage::table_store::TableStore;
+use crate::types::column_type::ColumnType;
+use crate::{row, schema};
 use std::sync::Arc;
 
+/// Creates a `ResultSet` scanning the given rows of a table with the columns `id` (int) and
+/// `name` (text).
+pub fn scan_result_set(table_name: &str, rows: Vec<Row>) -> Box<dyn ResultSet> {
+    let table = Table::new(
+        table_name,
+        schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+    );
+    let table_store = TableStore::new();
+    table_store.insert_all(rows);
+
+    let table_scan = TableScan::new(Arc::new(table_store));
+    Box::new(ScanResultsSet::new(table_scan, Arc::new(table), None))
+}
+
 pub struct ErrorResultSet {
     pub schema: Arc<Schema>,
 }
 
 impl ResultSet for ErrorResultSet {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
         Ok(Box::new(std::iter::once(Err(
             ExecutionError::TypeMismatchInComparison,
         ))))
@@ -26,7 +46,7 @@ pub struct InitErrorResultSet {
 }
 
 impl ResultSet for InitErrorResultSet {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
         Err(ExecutionError::TypeMismatchInComparison)
     }
 
@@ -35,6 +55,24 @@ impl ResultSet for InitErrorResultSet {
     }
 }
 
+/// A `ResultSet` wrapper counting how many times its rows are iterated.
+pub struct CountingResultSet {
+    pub inner: Box<dyn ResultSet>,
+    pub iterations: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl ResultSet for CountingResultSet {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        self.iterations
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        self.inner.iterator()
+    }
+
+    fn schema(&self) -> &Schema {
+        self.inner.schema()
+    }
+}
+
 pub struct JoinResetErrorResultSet {
     pub schema: Arc<Schema>,
     pub visible_positions: Arc<Vec<usize>>,
@@ -42,7 +80,7 @@ pub struct JoinResetErrorResultSet {
 }
 
 impl ResultSet for JoinResetErrorResultSet {
-    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult> + '_>, ExecutionError> {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
         let count = self
             .call_count
             .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
@@ -0,0 +1,125 @@
+use crate::query::executor::error::ExecutionError;
+use crate::query::executor::result_set::{visible_schema, ResultSet, RowViewResult};
+use crate::schema::Schema;
+use crate::storage::row_view::RowView;
+use std::collections::HashSet;
+
+/// A `ResultSet` implementation that combines the rows of two `ResultSet`s (`UNION`).
+///
+/// The rows of the left side are produced first, followed by the rows of the right side.
+/// Only the visible columns of both sides are kept, and the columns are named after the left side.
+///
+/// With `all` (`UNION ALL`), the rows are concatenated. Otherwise (`UNION`), a row is produced only
+/// the first time it is seen; this keeps all the distinct rows in memory for the duration of the
+/// iteration.
+pub struct UnionResultSet {
+    left: Box<dyn ResultSet>,
+    right: Box<dyn ResultSet>,
+    all: bool,
+    schema: Schema,
+    visible_positions: Vec<usize>,
+}
+
+impl UnionResultSet {
+    /// Creates a new `UnionResultSet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The `ResultSet` whose rows are produced first, it names the columns.
+    /// * `right` - The `ResultSet` whose rows are produced after the left ones.
+    /// * `all` - Whether duplicate rows are kept.
+    pub(crate) fn new(left: Box<dyn ResultSet>, right: Box<dyn ResultSet>, all: bool) -> Self {
+        let schema = visible_schema(left.as_ref());
+        let visible_positions = (0..schema.column_count()).collect();
+        Self {
+            left,
+            right,
+            all,
+            schema,
+            visible_positions,
+        }
+    }
+}
+
+impl ResultSet for UnionResultSet {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        let rows = self
+            .left
+            .iterator()?
+            .chain(self.right.iterator()?)
+            .map(|row_view_result| row_view_result.map(|row_view| row_view.to_row()));
+
+        let mut seen = HashSet::new();
+        Ok(Box::new(
+            rows.filter(move |row_result| match row_result {
+                Ok(row) => self.all || seen.insert(row.clone()),
+                Err(_) => true,
+            })
+            .map(move |row_result| {
+                row_result.map(|row| RowView::new(row, &self.schema, &self.visible_positions))
+            }),
+        ))
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::executor::project_result_set::ProjectResultSet;
+    use crate::query::executor::test_utils::scan_result_set;
+    use crate::query::parser::projection::ProjectedColumn;
+    use crate::{assert_next_row, assert_no_more_rows, row, rows};
+
+    #[test]
+    fn union_all_result_set() {
+        let left = scan_result_set("employees", rows![[1, "relop"], [2, "query"]]);
+        let right = scan_result_set("managers", rows![[1, "relop"]]);
+
+        let union_result_set = UnionResultSet::new(left, right, true);
+        let mut iterator = union_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "employees.name" => "relop");
+        assert_next_row!(iterator.as_mut(), "employees.id" => 2, "employees.name" => "query");
+        assert_next_row!(iterator.as_mut(), "employees.id" => 1, "employees.name" => "relop");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn union_result_set_removes_duplicates() {
+        let left = scan_result_set("employees", rows![[1, "relop"], [2, "query"], [1, "relop"]]);
+        let right = scan_result_set("managers", rows![[2, "query"], [3, "plan"]]);
+
+        let union_result_set = UnionResultSet::new(left, right, false);
+        let mut iterator = union_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "id" => 1, "name" => "relop");
+        assert_next_row!(iterator.as_mut(), "id" => 2, "name" => "query");
+        assert_next_row!(iterator.as_mut(), "id" => 3, "name" => "plan");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn union_result_set_keeps_only_visible_columns() {
+        let left = ProjectResultSet::new(
+            scan_result_set("employees", vec![row![1, "relop"]]),
+            &[ProjectedColumn::new("name")],
+        )
+        .unwrap();
+        let right = ProjectResultSet::new(
+            scan_result_set("managers", vec![row![2, "relop"]]),
+            &[ProjectedColumn::new("name")],
+        )
+        .unwrap();
+
+        let union_result_set = UnionResultSet::new(Box::new(left), Box::new(right), false);
+
+        assert_eq!(vec!["employees.name"], union_result_set.column_names());
+        let mut iterator = union_result_set.iterator().unwrap();
+        assert_next_row!(iterator.as_mut(), "name" => "relop", ! "id");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+}
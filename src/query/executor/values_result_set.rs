@@ -0,0 +1,86 @@
+use crate::query::executor::error::ExecutionError;
+use crate::query::executor::result_set::{ResultSet, RowViewResult};
+use crate::schema::Schema;
+use crate::storage::row::Row;
+use crate::storage::row_view::RowView;
+use std::sync::Arc;
+
+/// A `ResultSet` implementation that produces literal rows (`VALUES (1, 'a'), (2, 'b')`),
+/// without reading a table.
+///
+/// The columns are named `column1`, `column2`, etc., after their positions.
+pub struct ValuesResultSet {
+    rows: Vec<Row>,
+    schema: Arc<Schema>,
+    visible_positions: Vec<usize>,
+}
+
+impl ValuesResultSet {
+    /// Creates a new `ValuesResultSet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows to produce, in order.
+    /// * `schema` - The schema of the rows.
+    pub(crate) fn new(rows: Vec<Row>, schema: Arc<Schema>) -> Self {
+        let visible_positions = (0..schema.column_count()).collect();
+        Self {
+            rows,
+            schema,
+            visible_positions,
+        }
+    }
+}
+
+impl ResultSet for ValuesResultSet {
+    fn iterator(&self) -> Result<Box<dyn Iterator<Item = RowViewResult<'_>> + '_>, ExecutionError> {
+        Ok(Box::new(self.rows.iter().map(move |row| {
+            Ok(RowView::new(
+                row.clone(),
+                &self.schema,
+                &self.visible_positions,
+            ))
+        })))
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::column_type::ColumnType;
+    use crate::{assert_next_row, assert_no_more_rows, row, schema};
+
+    #[test]
+    fn values_result_set() {
+        let schema = schema!["column1" => ColumnType::Int, "column2" => ColumnType::Text].unwrap();
+        let values_result_set =
+            ValuesResultSet::new(vec![row![1, "a"], row![2, "b"]], Arc::new(schema));
+
+        let mut iterator = values_result_set.iterator().unwrap();
+
+        assert_next_row!(iterator.as_mut(), "column1" => 1, "column2" => "a");
+        assert_next_row!(iterator.as_mut(), "column1" => 2, "column2" => "b");
+        assert_no_more_rows!(iterator.as_mut());
+    }
+
+    #[test]
+    fn values_result_set_supports_multiple_iterations() {
+        let schema = schema!["column1" => ColumnType::Int].unwrap();
+        let values_result_set = ValuesResultSet::new(vec![row![1]], Arc::new(schema));
+
+        assert_eq!(1, values_result_set.count().unwrap());
+        assert_eq!(1, values_result_set.count().unwrap());
+    }
+
+    #[test]
+    fn column_names_of_values_result_set() {
+        let schema = schema!["column1" => ColumnType::Int, "column2" => ColumnType::Text].unwrap();
+        let values_result_set = ValuesResultSet::new(vec![], Arc::new(schema));
+
+        assert_eq!(vec!["column1", "column2"], values_result_set.column_names());
+    }
+}
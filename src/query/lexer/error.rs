@@ -1,10 +1,103 @@
 /// Represents errors that can occur during lexical analysis.
+///
+/// Every variant carries the (1-based) `line` and `column` in the input where the error occurred.
 #[derive(Debug, PartialEq)]
 pub enum LexError {
     /// Indicates an unexpected character was encountered in the input.
-    UnexpectedCharacter(char),
+    UnexpectedCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The line of the character.
+        line: usize,
+        /// The column of the character.
+        column: usize,
+    },
     /// Indicates an unterminated string literal.
-    UnterminatedStringLiteral,
+    UnterminatedStringLiteral {
+        /// The line of the opening quote.
+        line: usize,
+        /// The column of the opening quote.
+        column: usize,
+    },
+    /// Indicates an unterminated double-quoted identifier.
+    UnterminatedQuotedIdentifier {
+        /// The line of the opening quote.
+        line: usize,
+        /// The column of the opening quote.
+        column: usize,
+    },
+    /// Indicates an unterminated block comment (`/*` without a closing `*/`).
+    UnterminatedComment {
+        /// The line of the opening `/*`.
+        line: usize,
+        /// The column of the opening `/*`.
+        column: usize,
+    },
+    /// Indicates an identifier or a string literal longer than the maximum token length.
+    TokenTooLong {
+        /// The maximum number of characters of a token.
+        max_length: usize,
+        /// The line where the token starts.
+        line: usize,
+        /// The column where the token starts.
+        column: usize,
+    },
     /// Indicates an unsupported operator.
-    UnsupportedOperator(char),
+    UnsupportedOperator {
+        /// The unsupported operator.
+        operator: char,
+        /// The line of the operator.
+        line: usize,
+        /// The column of the operator.
+        column: usize,
+    },
+}
+
+impl std::fmt::Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LexError::UnexpectedCharacter {
+                character,
+                line,
+                column,
+            } => write!(
+                f,
+                "unexpected character '{}' at line {}, column {}",
+                character, line, column
+            ),
+            LexError::UnterminatedStringLiteral { line, column } => write!(
+                f,
+                "unterminated string literal at line {}, column {}",
+                line, column
+            ),
+            LexError::UnterminatedQuotedIdentifier { line, column } => write!(
+                f,
+                "unterminated quoted identifier at line {}, column {}",
+                line, column
+            ),
+            LexError::UnterminatedComment { line, column } => write!(
+                f,
+                "unterminated comment at line {}, column {}",
+                line, column
+            ),
+            LexError::TokenTooLong {
+                max_length,
+                line,
+                column,
+            } => write!(
+                f,
+                "token longer than {} characters at line {}, column {}",
+                max_length, line, column
+            ),
+            LexError::UnsupportedOperator {
+                operator,
+                line,
+                column,
+            } => write!(
+                f,
+                "unsupported operator '{}' at line {}, column {}",
+                operator, line, column
+            ),
+        }
+    }
 }
@@ -10,8 +10,57 @@ impl Keywords {
     /// The default keywords include: "show", "tables", "describe", "table", "select", "from" etc.
     pub(crate) fn new_with_default_keywords() -> Keywords {
         Self::new_with_keywords(&[
-            "show", "tables", "describe", "table", "select", "from", "as", "where", "and", "or",
-            "join", "on", "like", "order", "by", "asc", "limit", "desc",
+            "show",
+            "tables",
+            "describe",
+            "table",
+            "select",
+            "from",
+            "as",
+            "where",
+            "and",
+            "or",
+            "join",
+            "on",
+            "like",
+            "ilike",
+            "order",
+            "by",
+            "asc",
+            "limit",
+            "desc",
+            "between",
+            "not",
+            "left",
+            "right",
+            "full",
+            "outer",
+            "cross",
+            "inner",
+            "explain",
+            "true",
+            "false",
+            "insert",
+            "into",
+            "values",
+            "delete",
+            "update",
+            "set",
+            "create",
+            "drop",
+            "nulls",
+            "first",
+            "last",
+            "union",
+            "all",
+            "intersect",
+            "except",
+            "in",
+            "exists",
+            "distinct",
+            "is",
+            "primary",
+            "key",
         ])
     }
 
@@ -7,14 +7,30 @@ use crate::query::lexer::error::LexError;
 use crate::query::lexer::keywords::Keywords;
 use crate::query::lexer::token::{Token, TokenStream, TokenType};
 
+/// The default maximum number of characters of an identifier or a string literal.
+pub(crate) const DEFAULT_MAX_TOKEN_LENGTH: usize = 64 * 1024;
+
 /// `Lexer` is responsible for lexical analysis of the input source string.
 /// It converts a sequence of characters into a sequence of tokens (`TokenStream`).
 ///
 /// It holds the input characters, current position, and a set of keywords for identification.
+/// The current position is also tracked as a (1-based) line and column, which are attached to
+/// every token and every `LexError`.
+///
+/// Keywords are always recognized regardless of their case. Identifiers are kept as written,
+/// unless the lexer is made case-insensitive (`with_case_insensitive_identifiers`), which folds
+/// the unquoted identifiers to lowercase.
+///
+/// Identifiers and string literals longer than the maximum token length (64K characters by
+/// default) are rejected, so that pathological input fails early.
 pub(crate) struct Lexer {
     input: Vec<char>,
     position: usize,
+    line: usize,
+    column: usize,
     keywords: Keywords,
+    case_insensitive_identifiers: bool,
+    max_token_length: usize,
 }
 
 impl Lexer {
@@ -37,48 +53,93 @@ impl Lexer {
         Self {
             input: source.chars().collect(),
             position: 0,
+            line: 1,
+            column: 1,
             keywords,
+            case_insensitive_identifiers: false,
+            max_token_length: DEFAULT_MAX_TOKEN_LENGTH,
         }
     }
 
+    /// Folds the unquoted identifiers (e.g., `Employees`, `E.Name`) to lowercase, so that they
+    /// refer to the same table or column regardless of how they are written.
+    ///
+    /// Quoted identifiers (e.g., `"Name"`) are kept as written.
+    pub(crate) fn with_case_insensitive_identifiers(mut self) -> Self {
+        self.case_insensitive_identifiers = true;
+        self
+    }
+
+    /// Sets the maximum number of characters of an identifier (including its qualifier) or a
+    /// string literal, beyond which lexing fails with `LexError::TokenTooLong`.
+    pub(crate) fn with_max_token_length(mut self, max_token_length: usize) -> Self {
+        self.max_token_length = max_token_length;
+        self
+    }
+
     /// Performs lexical analysis on the input and returns a `TokenStream`.
     ///
     /// It iterates through the input characters, recognizing tokens such as whitespace,
-    /// punctuation (semicolon, comma, star), identifiers, numbers, string literals, and keywords.
+    /// punctuation (semicolon, comma, star), arithmetic operators (`+`, `-`, `/`), identifiers,
+    /// numbers, string literals, and keywords.
+    /// Comments (`-- ...` till the end of the line, and `/* ... */`) are skipped like whitespace.
     ///
     /// # Returns
     ///
     /// * `Ok(TokenStream)` - A stream of tokens representing the input.
-    /// * `Err(LexError)` - If an unexpected character is encountered.
+    /// * `Err(LexError)` - If an unexpected character, an unterminated string literal, quoted
+    ///   identifier or block comment, or a token longer than the maximum token length is
+    ///   encountered.
     pub(crate) fn lex(&mut self) -> Result<TokenStream, LexError> {
         let mut stream = TokenStream::new();
         while let Some(char) = self.peek() {
-            match char {
-                ch if ch.is_whitespace() => self.eat(),
-                ';' => self.capture_token(&mut stream, Token::semicolon()),
-                '*' => self.capture_token(&mut stream, Token::star()),
-                ',' => self.capture_token(&mut stream, Token::comma()),
-                '(' => self.capture_token(&mut stream, Token::left_parentheses()),
-                ')' => self.capture_token(&mut stream, Token::right_parentheses()),
-                '\'' => stream.add(self.string()?),
-                '=' => self.capture_token(&mut stream, Token::equal()),
-                '>' | '<' | '!' => stream.add(self.comparison_operator()?),
-                ch if Self::looks_like_a_whole_number(ch) => stream.add(self.number()),
-                ch if Self::looks_like_an_identifier(ch) => {
-                    stream.add(self.identifier_or_keyword())
+            let (line, column) = (self.line, self.column);
+            let token = match char {
+                ch if ch.is_whitespace() => {
+                    self.eat();
+                    continue;
+                }
+                '-' if self.peek_next() == Some('-') => {
+                    self.line_comment();
+                    continue;
+                }
+                '/' if self.peek_next() == Some('*') => {
+                    self.block_comment()?;
+                    continue;
                 }
+                ';' => self.capture_token(Token::semicolon()),
+                '*' => self.capture_token(Token::star()),
+                ',' => self.capture_token(Token::comma()),
+                '?' => self.capture_token(Token::placeholder()),
+                '(' => self.capture_token(Token::left_parentheses()),
+                ')' => self.capture_token(Token::right_parentheses()),
+                '\'' => self.string()?,
+                '"' => self.quoted_identifier_maybe_qualified()?,
+                '=' => self.capture_token(Token::equal()),
+                '>' | '<' | '!' => self.comparison_operator()?,
+                '-' if self.starts_a_negative_number(&stream) => self.negative_number(),
+                '-' => self.capture_token(Token::minus()),
+                '+' => self.capture_token(Token::plus()),
+                '/' => self.capture_token(Token::slash()),
+                ch if Self::looks_like_a_whole_number(ch) => self.number(),
+                ch if Self::looks_like_an_identifier(ch) => self.identifier_or_keyword()?,
                 _ => {
-                    return Err(LexError::UnexpectedCharacter(char));
+                    return Err(LexError::UnexpectedCharacter {
+                        character: char,
+                        line,
+                        column,
+                    });
                 }
-            }
+            };
+            stream.add(token.at(line, column));
         }
-        stream.add(Token::end_of_stream());
+        stream.add(Token::end_of_stream().at(self.line, self.column));
         Ok(stream)
     }
 
-    fn capture_token(&mut self, stream: &mut TokenStream, token: Token) {
-        stream.add(token);
+    fn capture_token(&mut self, token: Token) -> Token {
         self.eat();
+        token
     }
 
     fn eat(&mut self) {
@@ -87,8 +148,14 @@ impl Lexer {
 
     fn advance(&mut self) -> Option<char> {
         let char = self.peek();
-        if char.is_some() {
+        if let Some(ch) = char {
             self.position += 1;
+            if ch == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
         }
         char
     }
@@ -97,39 +164,159 @@ impl Lexer {
         self.input.get(self.position).copied()
     }
 
-    fn identifier_or_keyword(&mut self) -> Token {
+    fn peek_next(&self) -> Option<char> {
+        self.input.get(self.position + 1).copied()
+    }
+
+    fn line_comment(&mut self) {
+        while let Some(ch) = self.advance() {
+            if ch == '\n' {
+                break;
+            }
+        }
+    }
+
+    fn block_comment(&mut self) -> Result<(), LexError> {
+        let (line, column) = (self.line, self.column);
+        self.eat();
+        self.eat();
+
+        while let Some(ch) = self.advance() {
+            if ch == '*' && self.peek() == Some('/') {
+                self.eat();
+                return Ok(());
+            }
+        }
+        Err(LexError::UnterminatedComment { line, column })
+    }
+
+    fn identifier_or_keyword(&mut self) -> Result<Token, LexError> {
+        let (line, column) = (self.line, self.column);
         let mut lexeme = String::new();
 
         while let Some(ch) = self.peek() {
             if Self::looks_like_an_identifier(ch) || ch == '.' {
                 let _ = self.advance();
                 lexeme.push(ch);
+                self.ensure_within_max_token_length(lexeme.len(), line, column)?;
             } else {
                 break;
             }
         }
 
         let is_keyword = self.keywords.contains(lexeme.as_str());
+        if !is_keyword && self.case_insensitive_identifiers {
+            lexeme.make_ascii_lowercase();
+        }
+
+        if lexeme.ends_with('.') && self.peek() == Some('"') {
+            let quoted = self.quoted_identifier()?;
+            lexeme.push_str(quoted.lexeme());
+            self.ensure_within_max_token_length(lexeme.chars().count(), line, column)?;
+            return Ok(Token::new(lexeme, TokenType::Identifier));
+        }
+
         if is_keyword {
-            Token::new(lexeme, TokenType::Keyword)
+            Ok(Token::new(lexeme, TokenType::Keyword))
         } else {
-            Token::new(lexeme, TokenType::Identifier)
+            Ok(Token::new(lexeme, TokenType::Identifier))
         }
     }
 
+    /// Reads a double-quoted identifier, along with the column qualified by it if it is followed
+    /// by a `.` (e.g., `"order".id` or `"order"."select"`), as a single identifier.
+    fn quoted_identifier_maybe_qualified(&mut self) -> Result<Token, LexError> {
+        let (line, column) = (self.line, self.column);
+        let quoted = self.quoted_identifier()?;
+        if self.peek() != Some('.') {
+            return Ok(quoted);
+        }
+        let mut lexeme = quoted.lexeme().to_string();
+        lexeme.push('.');
+        self.eat();
+
+        if self.peek() == Some('"') {
+            lexeme.push_str(self.quoted_identifier()?.lexeme());
+            self.ensure_within_max_token_length(lexeme.chars().count(), line, column)?;
+            return Ok(Token::new(lexeme, TokenType::Identifier));
+        }
+        let mut column_name = String::new();
+        while let Some(ch) = self.peek() {
+            if !Self::looks_like_an_identifier(ch) {
+                break;
+            }
+            self.eat();
+            column_name.push(ch);
+            self.ensure_within_max_token_length(column_name.len(), line, column)?;
+        }
+        if self.case_insensitive_identifiers {
+            column_name.make_ascii_lowercase();
+        }
+        lexeme.push_str(&column_name);
+        self.ensure_within_max_token_length(lexeme.chars().count(), line, column)?;
+        Ok(Token::new(lexeme, TokenType::Identifier))
+    }
+
+    /// Reads a double-quoted identifier verbatim; it is never classified as a keyword.
+    /// A doubled quote (`""`) inside the identifier escapes to a single `"`.
+    fn quoted_identifier(&mut self) -> Result<Token, LexError> {
+        let (line, column) = (self.line, self.column);
+        let mut lexeme = String::new();
+        let mut length = 0;
+        self.eat();
+
+        while let Some(ch) = self.advance() {
+            if ch == '"' {
+                if self.peek() != Some('"') {
+                    return Ok(Token::new(lexeme, TokenType::Identifier));
+                }
+                self.eat();
+            }
+            lexeme.push(ch);
+            length += 1;
+            self.ensure_within_max_token_length(length, line, column)?;
+        }
+        Err(LexError::UnterminatedQuotedIdentifier { line, column })
+    }
+
+    /// Reads a single-quoted string literal. A doubled quote (`''`) inside the literal escapes
+    /// to a single `'`.
     fn string(&mut self) -> Result<Token, LexError> {
+        let (line, column) = (self.line, self.column);
         let mut lexeme = String::new();
+        let mut length = 0;
         self.eat();
 
-        while let Some(ch) = self.peek() {
+        while let Some(ch) = self.advance() {
             if ch == '\'' {
+                if self.peek() != Some('\'') {
+                    return Ok(Token::new(lexeme, TokenType::StringLiteral));
+                }
                 self.eat();
-                return Ok(Token::new(lexeme, TokenType::StringLiteral));
             }
             lexeme.push(ch);
-            let _ = self.advance();
+            length += 1;
+            self.ensure_within_max_token_length(length, line, column)?;
+        }
+        Err(LexError::UnterminatedStringLiteral { line, column })
+    }
+
+    /// Fails with `LexError::TokenTooLong` if a token (starting at `line` and `column`) of the
+    /// given length is longer than the maximum token length.
+    fn ensure_within_max_token_length(
+        &self,
+        length: usize,
+        line: usize,
+        column: usize,
+    ) -> Result<(), LexError> {
+        if length > self.max_token_length {
+            return Err(LexError::TokenTooLong {
+                max_length: self.max_token_length,
+                line,
+                column,
+            });
         }
-        Err(LexError::UnterminatedStringLiteral)
+        Ok(())
     }
 
     fn number(&mut self) -> Token {
@@ -146,7 +333,33 @@ impl Lexer {
         Token::new(lexeme, TokenType::WholeNumber)
     }
 
+    /// A `-` starts a negative number if it is immediately followed by a digit, and it does not
+    /// follow an operand (a number, a string, an identifier, a boolean or a closing parentheses),
+    /// after which it would be a subtraction.
+    fn starts_a_negative_number(&self, stream: &TokenStream) -> bool {
+        let followed_by_digit = self
+            .peek_next()
+            .is_some_and(Self::looks_like_a_whole_number);
+
+        let follows_an_operand = stream.last().is_some_and(|token| {
+            token.is_a_whole_number()
+                || token.is_string_literal()
+                || token.is_identifier()
+                || token.is_right_parentheses()
+                || token.is_keyword("true")
+                || token.is_keyword("false")
+        });
+        followed_by_digit && !follows_an_operand
+    }
+
+    fn negative_number(&mut self) -> Token {
+        self.eat();
+        let number = self.number();
+        Token::new(format!("-{}", number.lexeme()), TokenType::WholeNumber)
+    }
+
     fn comparison_operator(&mut self) -> Result<Token, LexError> {
+        let (line, column) = (self.line, self.column);
         //SAFETY: current character is always present when this method is called.
         //So, peek() will always return a non-none character.
         //Hence, unwrap() is safe here.
@@ -174,7 +387,11 @@ impl Lexer {
                     self.eat();
                     return Ok(Token::not_equal());
                 }
-                Err(LexError::UnsupportedOperator(current_ch))
+                Err(LexError::UnsupportedOperator {
+                    operator: current_ch,
+                    line,
+                    column,
+                })
             }
             _ => panic!("unsupported comparison operator"),
         }
@@ -307,7 +524,10 @@ mod tests {
         let result =
             Lexer::new_with_default_keywords("SELECT * FROM employees where name = 'alice").lex();
 
-        assert!(matches!(result, Err(LexError::UnterminatedStringLiteral)));
+        assert!(matches!(
+            result,
+            Err(LexError::UnterminatedStringLiteral { .. })
+        ));
     }
 
     #[test]
@@ -504,7 +724,7 @@ mod tests {
             Lexer::new_with_default_keywords("select * from employees where id ! 10").lex();
         assert!(matches!(
             result,
-            Err(LexError::UnsupportedOperator(ch)) if ch == '!'
+            Err(LexError::UnsupportedOperator { operator, .. }) if operator == '!'
         ));
     }
 
@@ -567,10 +787,10 @@ mod tests {
 
     #[test]
     fn unrecognized_character() {
-        let result = Lexer::new_with_default_keywords("select +").lex();
+        let result = Lexer::new_with_default_keywords("select #").lex();
         assert!(matches!(
             result,
-            Err(LexError::UnexpectedCharacter(ch)) if ch == '+'
+            Err(LexError::UnexpectedCharacter { character, .. }) if character == '#'
         ));
     }
 
@@ -615,7 +835,488 @@ mod tests {
         let result = Lexer::new_with_default_keywords("select * from employees limit 120.34").lex();
         assert!(matches!(
             result,
-            Err(LexError::UnexpectedCharacter(ch)) if ch == '.'
+            Err(LexError::UnexpectedCharacter { character, .. }) if character == '.'
         ))
     }
+
+    #[test]
+    fn lex_select_with_where_clause_with_negative_number() {
+        assert_lex!(
+            "select * from employees where balance > -100",
+            [
+                (TokenType::Keyword, "select"),
+                (TokenType::Star, "*"),
+                (TokenType::Keyword, "from"),
+                (TokenType::Identifier, "employees"),
+                (TokenType::Keyword, "where"),
+                (TokenType::Identifier, "balance"),
+                (TokenType::Greater, ">"),
+                (TokenType::WholeNumber, "-100"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_negative_one() {
+        assert_lex!(
+            "-1",
+            [(TokenType::WholeNumber, "-1"), (TokenType::EndOfStream, ""),]
+        )
+    }
+
+    #[test]
+    fn lex_negative_zero() {
+        assert_lex!(
+            "-0",
+            [(TokenType::WholeNumber, "-0"), (TokenType::EndOfStream, ""),]
+        )
+    }
+
+    #[test]
+    fn lex_a_lone_minus() {
+        assert_lex!(
+            "id = -",
+            [
+                (TokenType::Identifier, "id"),
+                (TokenType::Equal, "="),
+                (TokenType::Minus, "-"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_a_minus_after_an_operand() {
+        assert_lex!(
+            "id -1",
+            [
+                (TokenType::Identifier, "id"),
+                (TokenType::Minus, "-"),
+                (TokenType::WholeNumber, "1"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_arithmetic_operators() {
+        assert_lex!(
+            "salary + bonus * 2 - 10 / -5",
+            [
+                (TokenType::Identifier, "salary"),
+                (TokenType::Plus, "+"),
+                (TokenType::Identifier, "bonus"),
+                (TokenType::Star, "*"),
+                (TokenType::WholeNumber, "2"),
+                (TokenType::Minus, "-"),
+                (TokenType::WholeNumber, "10"),
+                (TokenType::Slash, "/"),
+                (TokenType::WholeNumber, "-5"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_placeholders() {
+        assert_lex!(
+            "id = ? and name=?",
+            [
+                (TokenType::Identifier, "id"),
+                (TokenType::Equal, "="),
+                (TokenType::Placeholder, "?"),
+                (TokenType::Keyword, "and"),
+                (TokenType::Identifier, "name"),
+                (TokenType::Equal, "="),
+                (TokenType::Placeholder, "?"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_with_a_line_comment_at_the_start() {
+        assert_lex!(
+            "-- all the employees\nselect * from employees",
+            [
+                (TokenType::Keyword, "select"),
+                (TokenType::Star, "*"),
+                (TokenType::Keyword, "from"),
+                (TokenType::Identifier, "employees"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_with_a_block_comment_at_the_start() {
+        assert_lex!(
+            "/* all the\nemployees */select * from employees",
+            [
+                (TokenType::Keyword, "select"),
+                (TokenType::Star, "*"),
+                (TokenType::Keyword, "from"),
+                (TokenType::Identifier, "employees"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_with_a_line_comment_mid_query() {
+        assert_lex!(
+            "select id -- the identifier\nfrom employees -- the table",
+            [
+                (TokenType::Keyword, "select"),
+                (TokenType::Identifier, "id"),
+                (TokenType::Keyword, "from"),
+                (TokenType::Identifier, "employees"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_with_a_block_comment_mid_query() {
+        assert_lex!(
+            "select id/* the\n* identifier **/from employees where id > /* negative */ -1",
+            [
+                (TokenType::Keyword, "select"),
+                (TokenType::Identifier, "id"),
+                (TokenType::Keyword, "from"),
+                (TokenType::Identifier, "employees"),
+                (TokenType::Keyword, "where"),
+                (TokenType::Identifier, "id"),
+                (TokenType::Greater, ">"),
+                (TokenType::WholeNumber, "-1"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_with_an_unterminated_block_comment() {
+        let result = Lexer::new_with_default_keywords("select * from employees /* all *").lex();
+        assert!(matches!(result, Err(LexError::UnterminatedComment { .. })));
+    }
+
+    #[test]
+    fn lex_select_with_a_quoted_identifier() {
+        assert_lex!(
+            "select \"order\", \"from\" from employees",
+            [
+                (TokenType::Keyword, "select"),
+                (TokenType::Identifier, "order"),
+                (TokenType::Comma, ","),
+                (TokenType::Identifier, "from"),
+                (TokenType::Keyword, "from"),
+                (TokenType::Identifier, "employees"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_a_quoted_identifier_with_spaces_and_escaped_quotes() {
+        assert_lex!(
+            "\"the \"\"quoted\"\" id\"",
+            [
+                (TokenType::Identifier, "the \"quoted\" id"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_a_qualified_quoted_identifier() {
+        assert_lex!(
+            "orders.\"order\" = 1",
+            [
+                (TokenType::Identifier, "orders.order"),
+                (TokenType::Equal, "="),
+                (TokenType::WholeNumber, "1"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_a_column_qualified_by_a_quoted_table_name() {
+        assert_lex!(
+            "\"order\".id = 1",
+            [
+                (TokenType::Identifier, "order.id"),
+                (TokenType::Equal, "="),
+                (TokenType::WholeNumber, "1"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_a_quoted_column_qualified_by_a_quoted_table_name() {
+        assert_lex!(
+            "select \"order\".\"select\" from \"order\"",
+            [
+                (TokenType::Keyword, "select"),
+                (TokenType::Identifier, "order.select"),
+                (TokenType::Keyword, "from"),
+                (TokenType::Identifier, "order"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_case_insensitive_column_qualified_by_a_quoted_table_name() {
+        let tokens = Lexer::new_with_default_keywords("select \"Order\".Id from \"Order\"")
+            .with_case_insensitive_identifiers()
+            .lex()
+            .unwrap();
+
+        assert_eq!("Order.id", tokens.token_at(1).unwrap().lexeme());
+        assert_eq!("Order", tokens.token_at(3).unwrap().lexeme());
+    }
+
+    #[test]
+    fn lex_identifiers_as_written_by_default() {
+        assert_lex!(
+            "SELECT Name FROM Employees",
+            [
+                (TokenType::Keyword, "SELECT"),
+                (TokenType::Identifier, "Name"),
+                (TokenType::Keyword, "FROM"),
+                (TokenType::Identifier, "Employees"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_case_insensitive_identifiers() {
+        let tokens = Lexer::new_with_default_keywords("SELECT E.Name FROM Employees E")
+            .with_case_insensitive_identifiers()
+            .lex()
+            .unwrap();
+
+        let lexemes = (0..tokens.len())
+            .map(|index| tokens.token_at(index).unwrap().lexeme().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["SELECT", "e.name", "FROM", "employees", "e", ""],
+            lexemes
+        );
+    }
+
+    #[test]
+    fn lex_case_insensitive_identifiers_keeping_quoted_identifiers_as_written() {
+        let tokens =
+            Lexer::new_with_default_keywords("select \"Name\", Orders.\"Order\" from Orders")
+                .with_case_insensitive_identifiers()
+                .lex()
+                .unwrap();
+
+        assert_eq!("Name", tokens.token_at(1).unwrap().lexeme());
+        assert_eq!("orders.Order", tokens.token_at(3).unwrap().lexeme());
+        assert_eq!("orders", tokens.token_at(5).unwrap().lexeme());
+    }
+
+    #[test]
+    fn lex_an_unterminated_quoted_identifier() {
+        let result = Lexer::new_with_default_keywords("select \"order from employees").lex();
+        assert!(matches!(
+            result,
+            Err(LexError::UnterminatedQuotedIdentifier { .. })
+        ));
+    }
+
+    #[test]
+    fn lex_an_unterminated_qualified_quoted_identifier() {
+        let result =
+            Lexer::new_with_default_keywords("select orders.\"order\"\" from orders").lex();
+        assert!(matches!(
+            result,
+            Err(LexError::UnterminatedQuotedIdentifier { .. })
+        ));
+    }
+
+    #[test]
+    fn lex_a_string_literal_with_an_escaped_quote_at_the_start() {
+        assert_lex!(
+            "'''relop'",
+            [
+                (TokenType::StringLiteral, "'relop"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_a_string_literal_with_an_escaped_quote_in_the_middle() {
+        assert_lex!(
+            "name = 'O''Brien'",
+            [
+                (TokenType::Identifier, "name"),
+                (TokenType::Equal, "="),
+                (TokenType::StringLiteral, "O'Brien"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_a_string_literal_with_an_escaped_quote_at_the_end() {
+        assert_lex!(
+            "'relop'''",
+            [
+                (TokenType::StringLiteral, "relop'"),
+                (TokenType::EndOfStream, ""),
+            ]
+        )
+    }
+
+    #[test]
+    fn lex_an_empty_string_literal() {
+        assert_lex!(
+            "''",
+            [(TokenType::StringLiteral, ""), (TokenType::EndOfStream, ""),]
+        )
+    }
+
+    #[test]
+    fn lex_an_unterminated_string_literal_ending_with_an_escaped_quote() {
+        let result = Lexer::new_with_default_keywords("name = 'O''").lex();
+        assert!(matches!(
+            result,
+            Err(LexError::UnterminatedStringLiteral { .. })
+        ));
+    }
+
+    #[test]
+    fn lex_with_token_positions() {
+        let stream = Lexer::new_with_default_keywords("select id\n  from employees")
+            .lex()
+            .unwrap();
+
+        let positions: Vec<(&str, usize, usize)> = (0..stream.len())
+            .map(|index| {
+                let token = stream.token_at(index).unwrap();
+                (token.lexeme(), token.line(), token.column())
+            })
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("select", 1, 1),
+                ("id", 1, 8),
+                ("from", 2, 3),
+                ("employees", 2, 8),
+                ("", 2, 17),
+            ],
+            positions
+        );
+    }
+
+    #[test]
+    fn lex_with_an_unexpected_character_on_the_second_line() {
+        let result =
+            Lexer::new_with_default_keywords("select *\nfrom employees where id = 1.0").lex();
+        assert_eq!(
+            Err(LexError::UnexpectedCharacter {
+                character: '.',
+                line: 2,
+                column: 28
+            }),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn lex_with_an_unterminated_string_literal_reports_the_opening_quote() {
+        let result =
+            Lexer::new_with_default_keywords("select * from employees where name = 'relop").lex();
+        assert_eq!(
+            Err(LexError::UnterminatedStringLiteral {
+                line: 1,
+                column: 38
+            }),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn lex_an_identifier_of_the_max_token_length() {
+        let stream = Lexer::new_with_default_keywords("select abcde")
+            .with_max_token_length(6)
+            .lex()
+            .unwrap();
+
+        assert_eq!("abcde", stream.token_at(1).unwrap().lexeme());
+    }
+
+    #[test]
+    fn attempt_to_lex_an_identifier_longer_than_the_max_token_length() {
+        let result = Lexer::new_with_default_keywords("select abcdefg")
+            .with_max_token_length(6)
+            .lex();
+        assert_eq!(
+            Err(LexError::TokenTooLong {
+                max_length: 6,
+                line: 1,
+                column: 8
+            }),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn lex_a_string_literal_of_the_max_token_length() {
+        let stream = Lexer::new_with_default_keywords("'it''s'")
+            .with_max_token_length(4)
+            .lex()
+            .unwrap();
+
+        assert_eq!("it's", stream.token_at(0).unwrap().lexeme());
+    }
+
+    #[test]
+    fn attempt_to_lex_a_string_literal_longer_than_the_max_token_length() {
+        let result = Lexer::new_with_default_keywords("select 'abcdefg'")
+            .with_max_token_length(6)
+            .lex();
+        assert_eq!(
+            Err(LexError::TokenTooLong {
+                max_length: 6,
+                line: 1,
+                column: 8
+            }),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn attempt_to_lex_a_qualified_quoted_identifier_longer_than_the_max_token_length() {
+        let result = Lexer::new_with_default_keywords("\"e\".\"name\"")
+            .with_max_token_length(5)
+            .lex();
+        assert_eq!(
+            Err(LexError::TokenTooLong {
+                max_length: 5,
+                line: 1,
+                column: 1
+            }),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn lex_a_long_string_literal_within_the_default_max_token_length() {
+        let literal = "a".repeat(DEFAULT_MAX_TOKEN_LENGTH);
+        let stream = Lexer::new_with_default_keywords(&format!("'{}'", literal))
+            .lex()
+            .unwrap();
+
+        assert_eq!(literal, stream.token_at(0).unwrap().lexeme());
+    }
 }
@@ -7,9 +7,13 @@ pub(crate) struct TokenStream {
 
 /// `Token` represents a single unit of meaning in the source code,
 /// such as an identifier, a keyword, or a punctuation mark.
+///
+/// It also carries the (1-based) line and column in the source where it starts.
 pub(crate) struct Token {
     lexeme: String,
     token_type: TokenType,
+    line: usize,
+    column: usize,
 }
 
 /// `TokenType` defines the various categories of tokens that can be recognized.
@@ -45,19 +49,36 @@ pub(crate) enum TokenType {
     Lesser,
     /// Not equal operator `!=`.
     NotEqual,
+    /// Plus operator `+`.
+    Plus,
+    /// Minus operator `-`.
+    Minus,
+    /// Slash operator `/`, used for division (an asterisk `*` is used for multiplication).
+    Slash,
+    /// A placeholder `?`, standing for a parameter supplied along with the query.
+    Placeholder,
     /// Indicates the end of the token stream.
     EndOfStream,
 }
 
 impl Token {
-    /// Creates a new `Token` with the given lexeme and type.
+    /// Creates a new `Token` with the given lexeme and type, positioned at line 1, column 1.
     pub(crate) fn new<S: Into<String>>(lexeme: S, token_type: TokenType) -> Token {
         Token {
             lexeme: lexeme.into(),
             token_type,
+            line: 1,
+            column: 1,
         }
     }
 
+    /// Positions the token at the given line and column of the source.
+    pub(crate) fn at(mut self, line: usize, column: usize) -> Token {
+        self.line = line;
+        self.column = column;
+        self
+    }
+
     /// Creates a token representing the end of the input stream.
     pub(crate) fn end_of_stream() -> Token {
         Token::new("", TokenType::EndOfStream)
@@ -103,6 +124,26 @@ impl Token {
         Token::new("!=", TokenType::NotEqual)
     }
 
+    /// Creates a plus token `+`.
+    pub(crate) fn plus() -> Token {
+        Token::new("+", TokenType::Plus)
+    }
+
+    /// Creates a minus token `-`.
+    pub(crate) fn minus() -> Token {
+        Token::new("-", TokenType::Minus)
+    }
+
+    /// Creates a slash token `/`.
+    pub(crate) fn slash() -> Token {
+        Token::new("/", TokenType::Slash)
+    }
+
+    /// Creates a placeholder token `?`.
+    pub(crate) fn placeholder() -> Token {
+        Token::new("?", TokenType::Placeholder)
+    }
+
     /// Creates a comma token `,`.
     pub(crate) fn comma() -> Token {
         Token::new(",", TokenType::Comma)
@@ -153,6 +194,11 @@ impl Token {
         self.lexeme == ")" && self.token_type == TokenType::RightParentheses
     }
 
+    /// Checks if the token is a placeholder `?`.
+    pub(crate) fn is_placeholder(&self) -> bool {
+        self.token_type == TokenType::Placeholder
+    }
+
     /// Checks if the token represents the end of the stream.
     pub(crate) fn is_end_of_stream(&self) -> bool {
         self.token_type == TokenType::EndOfStream
@@ -184,6 +230,16 @@ impl Token {
     pub(crate) fn token_type(&self) -> TokenType {
         self.token_type
     }
+
+    /// Returns the line in the source where the token starts.
+    pub(crate) fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column in the source where the token starts.
+    pub(crate) fn column(&self) -> usize {
+        self.column
+    }
 }
 
 impl TokenStream {
@@ -202,10 +258,51 @@ impl TokenStream {
         self.tokens.get(index)
     }
 
+    /// Retrieves the last token added to the stream.
+    pub(crate) fn last(&self) -> Option<&Token> {
+        self.tokens.last()
+    }
+
     /// Creates a cursor for iterating over the tokens in this stream.
     pub(crate) fn cursor(self) -> TokenCursor {
         TokenCursor::new(self)
     }
+
+    /// Splits the stream into one stream per statement, on the semicolons outside parentheses.
+    ///
+    /// Each statement keeps its terminating semicolon and ends with an end-of-stream token
+    /// (positioned right after the statement). Empty statements (e.g., `;;`) are left out.
+    pub(crate) fn split_statements(self) -> Vec<TokenStream> {
+        let mut statements = Vec::new();
+        let mut current = TokenStream::new();
+        let mut depth = 0usize;
+
+        for token in self.tokens {
+            if token.is_end_of_stream() {
+                if !current.tokens.is_empty() {
+                    current.add(token);
+                    statements.push(current);
+                }
+                break;
+            }
+            if token.is_left_parentheses() {
+                depth += 1;
+            } else if token.is_right_parentheses() {
+                depth = depth.saturating_sub(1);
+            }
+            let ends_statement = depth == 0 && token.is_semicolon();
+            let (line, column) = (token.line(), token.column() + token.lexeme().len());
+            if ends_statement && current.tokens.is_empty() {
+                continue;
+            }
+            current.add(token);
+            if ends_statement {
+                current.add(Token::end_of_stream().at(line, column));
+                statements.push(std::mem::replace(&mut current, TokenStream::new()));
+            }
+        }
+        statements
+    }
 }
 
 #[cfg(test)]
@@ -249,6 +346,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn split_statements_on_semicolons() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("show", TokenType::Keyword).at(1, 1));
+        stream.add(Token::new("tables", TokenType::Keyword).at(1, 6));
+        stream.add(Token::semicolon().at(1, 12));
+        stream.add(Token::semicolon().at(1, 13));
+        stream.add(Token::new("show", TokenType::Keyword).at(2, 1));
+        stream.add(Token::new("tables", TokenType::Keyword).at(2, 6));
+        stream.add(Token::end_of_stream().at(2, 12));
+
+        let statements = stream.split_statements();
+
+        assert_eq!(2, statements.len());
+        assert_eq!(4, statements[0].len());
+        assert_eq!(
+            TokenType::Semicolon,
+            statements[0].token_at(2).unwrap().token_type
+        );
+        let end_of_stream = statements[0].token_at(3).unwrap();
+        assert!(end_of_stream.is_end_of_stream());
+        assert_eq!((1, 13), (end_of_stream.line(), end_of_stream.column()));
+        assert_eq!(3, statements[1].len());
+        assert!(statements[1].token_at(2).unwrap().is_end_of_stream());
+    }
+
+    #[test]
+    fn do_not_split_statements_on_semicolons_inside_parentheses() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::semicolon());
+        stream.add(Token::right_parentheses());
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let statements = stream.split_statements();
+
+        assert_eq!(1, statements.len());
+        assert_eq!(6, statements[0].len());
+    }
+
+    #[test]
+    fn split_statements_of_an_empty_stream() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        assert!(stream.split_statements().is_empty());
+    }
+
     #[test]
     fn attempt_to_get_token_at_index_beyond_available_tokens() {
         let mut stream = TokenStream::new();
@@ -277,6 +425,21 @@ mod token_tests {
         assert_eq!(TokenType::Star, token.token_type());
     }
 
+    #[test]
+    fn arithmetic_operator_tokens() {
+        assert_eq!(TokenType::Plus, Token::plus().token_type());
+        assert_eq!(TokenType::Minus, Token::minus().token_type());
+        assert_eq!(TokenType::Slash, Token::slash().token_type());
+        assert_eq!("/", Token::slash().lexeme());
+    }
+
+    #[test]
+    fn placeholder_token() {
+        let token = Token::placeholder();
+        assert_eq!("?", token.lexeme());
+        assert!(token.is_placeholder());
+    }
+
     #[test]
     fn comma_token() {
         let token = Token::comma();
@@ -1,16 +1,25 @@
 use crate::query::lexer::token::{Token, TokenStream};
+use std::cell::Cell;
 
 /// `TokenCursor` allows for traversing a `TokenStream` one token at a time.
 /// It maintains an index to the current token position.
+///
+/// It also remembers the furthest token that was returned (by `next` or `peek`), which is the
+/// token a parse error is reported at.
 pub(crate) struct TokenCursor {
     stream: TokenStream,
     index: usize,
+    furthest_seen: Cell<Option<usize>>,
 }
 
 impl TokenCursor {
     /// Creates a new `TokenCursor` for the given `TokenStream`.
     pub(crate) fn new(stream: TokenStream) -> TokenCursor {
-        TokenCursor { stream, index: 0 }
+        TokenCursor {
+            stream,
+            index: 0,
+            furthest_seen: Cell::new(None),
+        }
     }
 
     /// Returns the current token and advances the cursor to the next position.
@@ -18,16 +27,43 @@ impl TokenCursor {
     /// Returns `Some(Token)` if a token exists at the current position, or `None` if
     /// the end of the stream has been reached.
     pub(crate) fn next(&mut self) -> Option<&Token> {
-        let token = self.stream.token_at(self.index);
-        if token.is_some() {
+        let index = self.index;
+        if self.stream.token_at(index).is_some() {
+            self.see(index);
             self.index += 1;
         }
-        token
+        self.stream.token_at(index)
     }
 
     /// Returns the current token without advancing the cursor.
     pub(crate) fn peek(&self) -> Option<&Token> {
-        self.stream.token_at(self.index)
+        let token = self.stream.token_at(self.index);
+        if token.is_some() {
+            self.see(self.index);
+        }
+        token
+    }
+
+    /// Returns the token after the current one without advancing the cursor.
+    pub(crate) fn peek_second(&self) -> Option<&Token> {
+        let token = self.stream.token_at(self.index + 1);
+        if token.is_some() {
+            self.see(self.index + 1);
+        }
+        token
+    }
+
+    /// Returns the furthest token returned so far by `next` or `peek`.
+    pub(crate) fn furthest_seen(&self) -> Option<&Token> {
+        self.furthest_seen
+            .get()
+            .and_then(|index| self.stream.token_at(index))
+    }
+
+    fn see(&self, index: usize) {
+        if self.furthest_seen.get().is_none_or(|seen| index > seen) {
+            self.furthest_seen.set(Some(index));
+        }
     }
 }
 
@@ -89,4 +125,22 @@ mod tests {
         assert_eq!(TokenType::Keyword, token.token_type());
         assert_eq!("show", token.lexeme());
     }
+
+    #[test]
+    fn furthest_seen_token() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("show", TokenType::Keyword));
+        stream.add(Token::new("tables", TokenType::Keyword).at(1, 6));
+
+        let mut cursor = TokenCursor::new(stream);
+        assert!(cursor.furthest_seen().is_none());
+
+        cursor.next();
+        assert_eq!("show", cursor.furthest_seen().unwrap().lexeme());
+
+        cursor.peek();
+        let token = cursor.furthest_seen().unwrap();
+        assert_eq!("tables", token.lexeme());
+        assert_eq!(6, token.column());
+    }
 }
@@ -0,0 +1,206 @@
+use crate::query::optimizer::OptimizerRule;
+use crate::query::plan::predicate::{ConstantResolver, LogicalClause, Predicate};
+use crate::query::plan::LogicalPlan;
+
+/// An optimizer rule that evaluates the comparisons between constants (e.g., `1 = 1`) in the
+/// predicate of a `Filter` at plan time:
+///
+/// - an always-true conjunct of an `AND` is dropped (`1 = 1 AND id = 5` is `id = 5`), and an
+///   always-false conjunct makes the whole `AND` always false.
+/// - an always-true disjunct of an `OR` makes the whole `OR` always true, and an always-false
+///   disjunct is dropped.
+/// - `NOT` negates an always-true or an always-false predicate.
+///
+/// A `Filter` whose predicate is always true is removed, and a `Filter` whose predicate is always
+/// false is replaced by a `Limit` of zero rows, which produces no rows without reading the table.
+///
+/// A comparison which fails to evaluate (e.g., `1 = 'one'` or `1 / 0 = 1`) is kept, so that the
+/// error is reported when the query is executed.
+pub(crate) struct ConstantFoldingRule;
+
+impl OptimizerRule for ConstantFoldingRule {
+    fn optimize(&self, plan: LogicalPlan) -> LogicalPlan {
+        let plan = plan.map_children(|child| self.optimize(child));
+
+        match plan {
+            LogicalPlan::Filter {
+                base_plan,
+                predicate,
+            } => match fold(predicate) {
+                Folded::Constant(true) => *base_plan,
+                Folded::Constant(false) => LogicalPlan::Limit {
+                    base_plan,
+                    count: 0,
+                },
+                Folded::Predicate(predicate) => LogicalPlan::Filter {
+                    base_plan,
+                    predicate,
+                },
+            },
+            _ => plan,
+        }
+    }
+}
+
+/// The result of folding a predicate: either a constant, or the (simplified) predicate.
+enum Folded {
+    Constant(bool),
+    Predicate(Predicate),
+}
+
+fn fold(predicate: Predicate) -> Folded {
+    match predicate {
+        Predicate::Single(clause) => fold_clause(clause),
+        Predicate::And(predicates) => fold_all(predicates, false, Predicate::And),
+        Predicate::Or(predicates) => fold_all(predicates, true, Predicate::Or),
+        Predicate::Not(predicate) => match fold(*predicate) {
+            Folded::Constant(value) => Folded::Constant(!value),
+            Folded::Predicate(predicate) => Folded::Predicate(Predicate::Not(Box::new(predicate))),
+        },
+    }
+}
+
+/// Folds the predicates of an `AND` (whose `deciding` value is `false`) or an `OR` (whose
+/// `deciding` value is `true`): a predicate folded into the deciding value decides the whole
+/// predicate, and a predicate folded into the other value is dropped.
+fn fold_all(
+    predicates: Vec<Predicate>,
+    deciding: bool,
+    combine: fn(Vec<Predicate>) -> Predicate,
+) -> Folded {
+    let mut remaining = Vec::with_capacity(predicates.len());
+    for predicate in predicates {
+        match fold(predicate) {
+            Folded::Constant(value) if value == deciding => return Folded::Constant(deciding),
+            Folded::Constant(_) => {}
+            Folded::Predicate(predicate) => remaining.push(predicate),
+        }
+    }
+    match remaining.len() {
+        0 => Folded::Constant(!deciding),
+        1 => Folded::Predicate(remaining.remove(0)),
+        _ => Folded::Predicate(combine(remaining)),
+    }
+}
+
+fn fold_clause(clause: LogicalClause) -> Folded {
+    if let LogicalClause::Comparison { lhs, operator, rhs } = &clause {
+        if let Ok(value) = operator.apply(lhs, rhs, &ConstantResolver) {
+            return Folded::Constant(value);
+        }
+    }
+    Folded::Predicate(Predicate::Single(clause))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::parser::ast::Literal;
+    use crate::query::plan::predicate::LogicalOperator;
+
+    fn constant_comparison(lhs: i64, rhs: i64) -> Predicate {
+        Predicate::comparison(Literal::Int(lhs), LogicalOperator::Eq, Literal::Int(rhs))
+    }
+
+    fn id_equals(value: i64) -> Predicate {
+        Predicate::comparison(
+            Literal::ColumnReference("id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Int(value),
+        )
+    }
+
+    fn filter(predicate: Predicate) -> LogicalPlan {
+        LogicalPlan::scan("employees").filter(predicate)
+    }
+
+    #[test]
+    fn remove_always_true_filter() {
+        let plan = ConstantFoldingRule.optimize(filter(constant_comparison(1, 1)));
+        assert_eq!(LogicalPlan::scan("employees"), plan);
+    }
+
+    #[test]
+    fn replace_always_false_filter_with_empty_result() {
+        let plan = ConstantFoldingRule.optimize(filter(constant_comparison(1, 2)));
+        assert_eq!(LogicalPlan::scan("employees").limit(0), plan);
+    }
+
+    #[test]
+    fn drop_always_true_conjunct() {
+        let plan = ConstantFoldingRule.optimize(filter(Predicate::And(vec![
+            constant_comparison(1, 1),
+            id_equals(5),
+        ])));
+        assert_eq!(filter(id_equals(5)), plan);
+    }
+
+    #[test]
+    fn always_false_conjunct_makes_the_filter_always_false() {
+        let plan = ConstantFoldingRule.optimize(filter(Predicate::And(vec![
+            id_equals(5),
+            constant_comparison(1, 2),
+        ])));
+        assert_eq!(LogicalPlan::scan("employees").limit(0), plan);
+    }
+
+    #[test]
+    fn always_true_disjunct_removes_the_filter() {
+        let plan = ConstantFoldingRule.optimize(filter(Predicate::Or(vec![
+            id_equals(5),
+            constant_comparison(1, 1),
+        ])));
+        assert_eq!(LogicalPlan::scan("employees"), plan);
+    }
+
+    #[test]
+    fn drop_always_false_disjunct() {
+        let plan = ConstantFoldingRule.optimize(filter(Predicate::Or(vec![
+            constant_comparison(1, 2),
+            id_equals(5),
+            id_equals(6),
+        ])));
+        assert_eq!(
+            filter(Predicate::Or(vec![id_equals(5), id_equals(6)])),
+            plan
+        );
+    }
+
+    #[test]
+    fn negate_always_false_predicate() {
+        let plan = ConstantFoldingRule
+            .optimize(filter(Predicate::Not(Box::new(constant_comparison(1, 2)))));
+        assert_eq!(LogicalPlan::scan("employees"), plan);
+    }
+
+    #[test]
+    fn fold_constant_arithmetic() {
+        let plan = ConstantFoldingRule.optimize(filter(Predicate::comparison(
+            Literal::arithmetic(
+                Literal::Int(1),
+                crate::query::parser::ast::ArithmeticOperator::Add,
+                Literal::Int(1),
+            ),
+            LogicalOperator::Eq,
+            Literal::Int(2),
+        )));
+        assert_eq!(LogicalPlan::scan("employees"), plan);
+    }
+
+    #[test]
+    fn keep_comparison_failing_to_evaluate() {
+        let predicate = Predicate::comparison(
+            Literal::Int(1),
+            LogicalOperator::Eq,
+            Literal::Text("one".to_string()),
+        );
+        let plan = ConstantFoldingRule.optimize(filter(predicate.clone()));
+        assert_eq!(filter(predicate), plan);
+    }
+
+    #[test]
+    fn keep_filter_without_constant_comparisons() {
+        let plan = ConstantFoldingRule.optimize(filter(id_equals(5)));
+        assert_eq!(filter(id_equals(5)), plan);
+    }
+}
@@ -0,0 +1,305 @@
+use crate::catalog::Catalog;
+use crate::query::optimizer::OptimizerRule;
+use crate::query::parser::ast::JoinType;
+use crate::query::parser::projection::ProjectedColumn;
+use crate::query::plan::LogicalPlan;
+
+/// An optimizer rule that swaps the sides of inner equi-joins, so that the hash join builds its
+/// hash table on the smaller relation.
+///
+/// A hash join reads its right side completely into a hash table, and probes it with the rows
+/// of its left side. The number of rows of each side is estimated from the row counts of the
+/// tables (see `Catalog::table_statistics`), and the sides are swapped when the right side is
+/// estimated to be larger than the left side:
+///
+/// ```text
+///          [Join (e.department_id = d.id)]             [Projection (e.id, e.department_id, d.id)]
+///               /              \                                       |
+///    [Scan (d: 3 rows)]  [Scan (e: 1000 rows)]   =>     [Join (e.department_id = d.id)]
+///                                                            /                \
+///                                                  [Scan (e: 1000 rows)]  [Scan (d: 3 rows)]
+/// ```
+///
+/// A `Projection` restores the original order of the columns, so only the order of the rows
+/// changes (which is unspecified without `ORDER BY`).
+///
+/// The rule only applies to a chain of joins in which every join is an inner join: inner joins
+/// are commutative, outer joins are left untouched. The sides are also left untouched when the
+/// columns of the join can not be remapped by name (e.g., a self-join without aliases).
+///
+/// The estimated number of rows of a join is the larger of the estimates of its sides (as for a
+/// join on a key), and a filter is assumed to keep all the rows.
+pub(crate) struct JoinReorderingRule;
+
+impl OptimizerRule for JoinReorderingRule {
+    /// Without the statistics of the tables, the plan is left unchanged.
+    fn optimize(&self, plan: LogicalPlan) -> LogicalPlan {
+        plan
+    }
+
+    fn optimize_with_catalog(&self, plan: LogicalPlan, catalog: &Catalog) -> LogicalPlan {
+        let plan = plan.map_children(|child| self.optimize_with_catalog(child, catalog));
+
+        match plan {
+            LogicalPlan::Join {
+                left,
+                right,
+                on: Some(on),
+                join_type: JoinType::Inner,
+            } if on.equality_column_names().is_some()
+                && is_inner_join_chain(&left)
+                && is_inner_join_chain(&right) =>
+            {
+                let swap = match (
+                    estimated_row_count(&left, catalog),
+                    estimated_row_count(&right, catalog),
+                ) {
+                    (Some(left_rows), Some(right_rows)) => right_rows > left_rows,
+                    _ => false,
+                };
+                let join = LogicalPlan::Join {
+                    left,
+                    right,
+                    on: Some(on),
+                    join_type: JoinType::Inner,
+                };
+                if swap {
+                    swap_sides(join)
+                } else {
+                    join
+                }
+            }
+            _ => plan,
+        }
+    }
+}
+
+/// Returns `true` if the plan is not a join, or a join whose joins (including itself) are all
+/// inner joins.
+fn is_inner_join_chain(plan: &LogicalPlan) -> bool {
+    match plan {
+        LogicalPlan::Join {
+            left,
+            right,
+            join_type,
+            ..
+        } => {
+            *join_type == JoinType::Inner && is_inner_join_chain(left) && is_inner_join_chain(right)
+        }
+        LogicalPlan::Projection { base_plan, .. } | LogicalPlan::Filter { base_plan, .. } => {
+            is_inner_join_chain(base_plan)
+        }
+        _ => true,
+    }
+}
+
+/// Estimates the number of rows produced by the plan, from the row counts of the tables.
+fn estimated_row_count(plan: &LogicalPlan, catalog: &Catalog) -> Option<usize> {
+    match plan {
+        LogicalPlan::Scan {
+            table_name, limit, ..
+        } => {
+            let row_count = catalog.table_statistics(table_name).ok()?.row_count();
+            Some(limit.map_or(row_count, |limit| limit.min(row_count)))
+        }
+        LogicalPlan::Join { left, right, .. } => {
+            Some(estimated_row_count(left, catalog)?.max(estimated_row_count(right, catalog)?))
+        }
+        LogicalPlan::Limit { base_plan, count } => {
+            Some(estimated_row_count(base_plan, catalog)?.min(*count))
+        }
+        LogicalPlan::Filter { base_plan, .. }
+        | LogicalPlan::Projection { base_plan, .. }
+        | LogicalPlan::QualifiedProjection { base_plan, .. }
+        | LogicalPlan::Sort { base_plan, .. } => estimated_row_count(base_plan, catalog),
+        _ => None,
+    }
+}
+
+/// Swaps the sides of the join, and projects the columns of the swapped join in their original
+/// order. The join is returned unchanged if its columns can not be projected by name.
+fn swap_sides(join: LogicalPlan) -> LogicalPlan {
+    let Some(schema) = join.schema() else {
+        return join;
+    };
+    let LogicalPlan::Join {
+        left,
+        right,
+        on,
+        join_type,
+    } = join
+    else {
+        return join;
+    };
+
+    let column_names = schema.column_names();
+    let remappable = column_names
+        .iter()
+        .enumerate()
+        .all(|(position, column_name)| {
+            matches!(schema.column_position(column_name), Ok(Some(found)) if found == position)
+        });
+
+    if !remappable {
+        return LogicalPlan::Join {
+            left,
+            right,
+            on,
+            join_type,
+        };
+    }
+    LogicalPlan::Projection {
+        base_plan: LogicalPlan::Join {
+            left: right,
+            right: left,
+            on,
+            join_type,
+        }
+        .boxed(),
+        columns: column_names.into_iter().map(ProjectedColumn::new).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::lexer::Lexer;
+    use crate::query::parser::Parser;
+    use crate::query::plan::LogicalPlanner;
+    use crate::schema;
+    use crate::storage::row::Row;
+    use crate::types::column_type::ColumnType;
+    use crate::{row, rows};
+    use std::sync::Arc;
+
+    fn catalog_with_departments_and_employees() -> Arc<Catalog> {
+        let catalog = Catalog::new();
+        catalog
+            .create_table(
+                "departments",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
+        catalog
+            .create_table(
+                "employees",
+                schema!["id" => ColumnType::Int, "department_id" => ColumnType::Int].unwrap(),
+            )
+            .unwrap();
+        catalog
+            .insert_all_into("departments", rows![[1, "storage"], [2, "engine"]])
+            .unwrap();
+        let employees = (1..=10)
+            .map(|id| row![id, id % 2 + 1])
+            .collect::<Vec<Row>>();
+        catalog.insert_all_into("employees", employees).unwrap();
+        catalog
+    }
+
+    fn plan(catalog: &Arc<Catalog>, query: &str) -> LogicalPlan {
+        let tokens = Lexer::new_with_default_keywords(query).lex().unwrap();
+        let ast = Parser::new(tokens).parse().unwrap();
+        LogicalPlanner::new(catalog.clone()).plan(ast).unwrap()
+    }
+
+    fn build_side_table(plan: &LogicalPlan) -> Option<&str> {
+        match plan {
+            LogicalPlan::Join { right, .. } => match right.as_ref() {
+                LogicalPlan::Scan { table_name, .. } => Some(table_name),
+                _ => None,
+            },
+            LogicalPlan::Projection { base_plan, .. } => build_side_table(base_plan),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn build_on_the_smaller_table() {
+        let catalog = catalog_with_departments_and_employees();
+        let plan = plan(
+            &catalog,
+            "select * from departments join employees on departments.id = employees.department_id",
+        );
+
+        let optimized = JoinReorderingRule.optimize_with_catalog(plan, &catalog);
+
+        assert_eq!(Some("departments"), build_side_table(&optimized));
+        assert_eq!(
+            vec![
+                "departments.id",
+                "departments.name",
+                "employees.id",
+                "employees.department_id"
+            ],
+            optimized.schema().unwrap().column_names()
+        );
+    }
+
+    #[test]
+    fn keep_the_smaller_table_as_the_build_side() {
+        let catalog = catalog_with_departments_and_employees();
+        let plan = plan(
+            &catalog,
+            "select * from employees join departments on departments.id = employees.department_id",
+        );
+
+        let optimized = JoinReorderingRule.optimize_with_catalog(plan.clone(), &catalog);
+
+        assert_eq!(plan, optimized);
+    }
+
+    #[test]
+    fn do_not_reorder_a_left_join() {
+        let catalog = catalog_with_departments_and_employees();
+        let plan = plan(
+            &catalog,
+            "select * from departments left join employees on departments.id = employees.department_id",
+        );
+
+        let optimized = JoinReorderingRule.optimize_with_catalog(plan.clone(), &catalog);
+
+        assert_eq!(plan, optimized);
+    }
+
+    #[test]
+    fn do_not_reorder_a_join_over_a_left_join() {
+        let catalog = catalog_with_departments_and_employees();
+        catalog
+            .create_table("roles", schema!["id" => ColumnType::Int].unwrap())
+            .unwrap();
+        let plan = plan(
+            &catalog,
+            "select * from roles left join departments on roles.id = departments.id join employees on departments.id = employees.department_id",
+        );
+
+        let optimized = JoinReorderingRule.optimize_with_catalog(plan.clone(), &catalog);
+
+        assert_eq!(plan, optimized);
+    }
+
+    #[test]
+    fn do_not_reorder_a_self_join_without_aliases() {
+        let catalog = catalog_with_departments_and_employees();
+        let plan = plan(
+            &catalog,
+            "select * from departments join employees on departments.id = employees.department_id join employees on employees.id = departments.id",
+        );
+
+        let optimized = JoinReorderingRule.optimize_with_catalog(plan.clone(), &catalog);
+
+        assert!(matches!(optimized, LogicalPlan::Join { .. }));
+    }
+
+    #[test]
+    fn do_not_reorder_without_statistics() {
+        let catalog = catalog_with_departments_and_employees();
+        let plan = plan(
+            &catalog,
+            "select * from departments join employees on departments.id = employees.department_id",
+        );
+
+        let optimized = JoinReorderingRule.optimize(plan.clone());
+
+        assert_eq!(plan, optimized);
+    }
+}
@@ -1,9 +1,16 @@
 use crate::query::optimizer::OptimizerRule;
 use crate::query::plan::LogicalPlan;
 
-/// An optimizer rule that pushes a `Limit` operation down into a `Sort` operation
-/// if the `Limit` immediately encloses the `Sort`. This allows the execution engine
-/// to perform an efficiently bounded Top-K sort instead of a full sort.
+/// An optimizer rule that pushes a `Limit` operation down:
+///
+/// - into a `Sort` operation if the `Limit` immediately encloses the `Sort`. This allows the execution engine
+///   to perform an efficiently bounded Top-K sort instead of a full sort.
+/// - into a `Scan` operation if only projections are between the `Limit` and the `Scan`. The `Scan` records
+///   the limit as a hint and stops reading the table early. The `Limit` is kept on top of the plan.
+///
+/// A limit is never pushed through a `Filter`, a `Join` or a `Sort`, as that would change the results.
+/// A filter which is already pushed down into the `Scan` is applied before the limit, so it is safe to
+/// push the limit into a filtered `Scan`.
 pub(crate) struct LimitPushdownRule;
 
 impl OptimizerRule for LimitPushdownRule {
@@ -26,10 +33,10 @@ impl OptimizerRule for LimitPushdownRule {
                         limit: Some(count),
                     }
                 } else {
-                    // Not a Sort node, just rebuild the Limit
+                    // Not a Sort node, rebuild the Limit and try to push it into a Scan
                     LogicalPlan::Limit {
                         count,
-                        base_plan: Box::new(optimized_base_plan),
+                        base_plan: Box::new(push_into_scan(optimized_base_plan, count)),
                     }
                 }
             }
@@ -39,10 +46,43 @@ impl OptimizerRule for LimitPushdownRule {
     }
 }
 
+/// Records the limit on the `Scan` reachable from the given plan through projections only.
+fn push_into_scan(plan: LogicalPlan, count: usize) -> LogicalPlan {
+    match plan {
+        LogicalPlan::Scan {
+            table_name,
+            alias,
+            filter,
+            schema,
+            limit,
+        } => LogicalPlan::Scan {
+            table_name,
+            alias,
+            filter,
+            schema,
+            limit: Some(limit.map_or(count, |limit| limit.min(count))),
+        },
+        LogicalPlan::Projection { base_plan, columns } => LogicalPlan::Projection {
+            base_plan: Box::new(push_into_scan(*base_plan, count)),
+            columns,
+        },
+        LogicalPlan::QualifiedProjection {
+            base_plan,
+            qualifier,
+        } => LogicalPlan::QualifiedProjection {
+            base_plan: Box::new(push_into_scan(*base_plan, count)),
+            qualifier,
+        },
+        _ => plan,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::asc;
+    use crate::query::parser::ast::Literal;
+    use crate::query::plan::predicate::{LogicalOperator, Predicate};
     use crate::query::plan::LogicalPlan;
 
     #[test]
@@ -52,6 +92,7 @@ mod tests {
             alias: None,
             filter: None,
             schema: std::sync::Arc::new(crate::schema::Schema::new()),
+            limit: None,
         };
 
         let sort_plan = table_scan.order_by(vec![asc!("id")]);
@@ -69,4 +110,80 @@ mod tests {
             optimized
         );
     }
+
+    #[test]
+    fn push_down_limit_to_scan() {
+        let limit = LogicalPlan::scan("employees").limit(1);
+
+        let rule = LimitPushdownRule;
+        let optimized = rule.optimize(limit);
+
+        assert!(
+            matches!(optimized, LogicalPlan::Limit { count: 1, ref base_plan }
+                if matches!(base_plan.as_ref(), LogicalPlan::Scan { limit: Some(1), .. })),
+            "Expected Limit over Scan with limit Some(1), got {:?}",
+            optimized
+        );
+    }
+
+    #[test]
+    fn push_down_limit_to_scan_through_projection() {
+        let limit = LogicalPlan::scan("employees").project(vec!["id"]).limit(2);
+
+        let rule = LimitPushdownRule;
+        let optimized = rule.optimize(limit);
+
+        let LogicalPlan::Limit { base_plan, .. } = optimized else {
+            panic!("Expected Limit node");
+        };
+        let LogicalPlan::Projection { base_plan, .. } = *base_plan else {
+            panic!("Expected Projection node");
+        };
+        assert!(matches!(
+            *base_plan,
+            LogicalPlan::Scan { limit: Some(2), .. }
+        ));
+    }
+
+    #[test]
+    fn do_not_push_down_limit_through_filter() {
+        let limit = LogicalPlan::scan("employees")
+            .filter(Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(1),
+            ))
+            .limit(1);
+
+        let rule = LimitPushdownRule;
+        let optimized = rule.optimize(limit);
+
+        let LogicalPlan::Limit { base_plan, .. } = optimized else {
+            panic!("Expected Limit node");
+        };
+        let LogicalPlan::Filter { base_plan, .. } = *base_plan else {
+            panic!("Expected Filter node");
+        };
+        assert!(matches!(*base_plan, LogicalPlan::Scan { limit: None, .. }));
+    }
+
+    #[test]
+    fn do_not_push_down_limit_through_sort_into_scan() {
+        let limit = LogicalPlan::scan("employees")
+            .order_by(vec![asc!("id")])
+            .limit(3);
+
+        let rule = LimitPushdownRule;
+        let optimized = rule.optimize(limit);
+
+        let LogicalPlan::Sort {
+            base_plan,
+            limit: Some(3),
+            ..
+        } = optimized
+        else {
+            panic!("Expected Sort node with a limit");
+        };
+        assert!(matches!(*base_plan, LogicalPlan::Scan { limit: None, .. }));
+    }
 }
@@ -1,31 +1,55 @@
+pub(crate) mod constant_folding;
+pub(crate) mod join_reordering;
 pub(crate) mod limit_pushdown;
 pub(crate) mod predicate_pushdown;
 
+use crate::catalog::Catalog;
+use crate::query::optimizer::constant_folding::ConstantFoldingRule;
+use crate::query::optimizer::join_reordering::JoinReorderingRule;
 use crate::query::optimizer::limit_pushdown::LimitPushdownRule;
 use crate::query::optimizer::predicate_pushdown::PredicatePushdownRule;
 use crate::query::plan::LogicalPlan;
+use std::sync::Arc;
 
 /// A trait for rules that optimize a `LogicalPlan`.
 pub(crate) trait OptimizerRule {
     /// Applies the optimization rule to the given plan.
     fn optimize(&self, plan: LogicalPlan) -> LogicalPlan;
+
+    /// Applies the optimization rule to the given plan, with the catalog available for the
+    /// statistics of the tables (see `Catalog::table_statistics`).
+    ///
+    /// Rules which do not depend on the statistics only implement `optimize`.
+    fn optimize_with_catalog(&self, plan: LogicalPlan, _catalog: &Catalog) -> LogicalPlan {
+        self.optimize(plan)
+    }
 }
 
 /// The query optimizer that applies a set of rules to a `LogicalPlan`.
 pub(crate) struct Optimizer {
     rules: Vec<Box<dyn OptimizerRule>>,
+    catalog: Arc<Catalog>,
 }
 
 impl Optimizer {
-    /// Creates a new `Optimizer` with the default set of rules.
-    pub(crate) fn new() -> Self {
+    /// Creates a new `Optimizer` with the default set of rules, which can use the statistics
+    /// of the tables in the given catalog.
+    pub(crate) fn new(catalog: Arc<Catalog>) -> Self {
         Self {
-            rules: vec![Box::new(PredicatePushdownRule), Box::new(LimitPushdownRule)],
+            rules: vec![
+                Box::new(ConstantFoldingRule),
+                Box::new(PredicatePushdownRule),
+                Box::new(JoinReorderingRule),
+                Box::new(LimitPushdownRule),
+            ],
+            catalog,
         }
     }
 
     /// Optimized the given plan by applying all rules.
     pub(crate) fn optimize(&self, plan: LogicalPlan) -> LogicalPlan {
-        self.rules.iter().fold(plan, |acc, rule| rule.optimize(acc))
+        self.rules.iter().fold(plan, |acc, rule| {
+            rule.optimize_with_catalog(acc, &self.catalog)
+        })
     }
 }
@@ -1,6 +1,8 @@
 use crate::query::optimizer::OptimizerRule;
+use crate::query::parser::ast::JoinType;
 use crate::query::plan::predicate::Predicate;
 use crate::query::plan::LogicalPlan;
+use crate::schema::Schema;
 
 /// A rule that pushes `Filter` nodes down into `Scan` nodes.
 pub(crate) struct PredicatePushdownRule;
@@ -48,9 +50,14 @@ impl OptimizerRule for PredicatePushdownRule {
                 base_plan,
                 predicate,
             } => match *base_plan {
-                LogicalPlan::Join { left, right, on } => {
+                LogicalPlan::Join {
+                    left,
+                    right,
+                    on,
+                    join_type,
+                } => {
                     let (pushed_left, pushed_right, remaining) =
-                        try_push_down(predicate, &left, &right);
+                        try_push_down(predicate, &left, &right, join_type);
 
                     let new_left = if let Some(left_predicate) = pushed_left {
                         self.optimize(LogicalPlan::Filter {
@@ -74,6 +81,7 @@ impl OptimizerRule for PredicatePushdownRule {
                         left: Box::new(new_left),
                         right: Box::new(new_right),
                         on,
+                        join_type,
                     };
 
                     if let Some(remaining_predicate) = remaining {
@@ -85,11 +93,13 @@ impl OptimizerRule for PredicatePushdownRule {
                         optimized_join
                     }
                 }
+                // A filter is never pushed into a limited scan, the limit would apply before the filter.
                 LogicalPlan::Scan {
                     table_name,
                     alias,
                     filter: existing,
                     schema,
+                    limit: None,
                 } => {
                     let combined_filter = match existing {
                         Some(existing_filter) => Predicate::And(vec![existing_filter, predicate]),
@@ -100,6 +110,7 @@ impl OptimizerRule for PredicatePushdownRule {
                         alias,
                         filter: Some(combined_filter),
                         schema,
+                        limit: None,
                     }
                 }
                 _ => LogicalPlan::Filter {
@@ -114,10 +125,19 @@ impl OptimizerRule for PredicatePushdownRule {
 
 /// Attempts to push parts of an AND-separated predicate down to the left and right children.
 /// Returns a tuple of `(Option<Left Predicate>, Option<Right Predicate>, Option<Unpushable Predicate>)`.
+///
+/// Predicates are never pushed into a side that the join pads with nulls (e.g. the right side of a
+/// left join), because filtering that side before the join would turn filtered-out rows into
+/// null-padded rows instead of removing them.
+///
+/// A predicate referring to an unqualified column of both sides (e.g. `city` when both tables
+/// have a `city` column) is not pushed either, because the column would be bound to the side it
+/// is pushed into, while it is ambiguous in the joined rows.
 fn try_push_down(
     predicate: Predicate,
     left_plan: &LogicalPlan,
     right_plan: &LogicalPlan,
+    join_type: JoinType,
 ) -> (Option<Predicate>, Option<Predicate>, Option<Predicate>) {
     let left_schema_optional = left_plan.schema();
     let right_schema_optional = right_plan.schema();
@@ -138,8 +158,12 @@ fn try_push_down(
     let mut unpushable_predicates = Vec::new();
 
     for pred in predicates {
-        let belongs_to_left = pred.belongs_to(&left_schema);
-        let belongs_to_right = pred.belongs_to(&right_schema);
+        if refers_to_columns_of_both(&pred, &left_schema, &right_schema) {
+            unpushable_predicates.push(pred);
+            continue;
+        }
+        let belongs_to_left = !join_type.pads_left_with_nulls() && pred.belongs_to(&left_schema);
+        let belongs_to_right = !join_type.pads_right_with_nulls() && pred.belongs_to(&right_schema);
         if belongs_to_left {
             left_predicates.push(pred);
         } else if belongs_to_right {
@@ -156,6 +180,14 @@ fn try_push_down(
     )
 }
 
+/// Returns `true` if a column referenced by the predicate matches a column of both schemas.
+fn refers_to_columns_of_both(predicate: &Predicate, left: &Schema, right: &Schema) -> bool {
+    predicate
+        .referenced_column_names()
+        .iter()
+        .any(|column_name| left.has_column(column_name) && right.has_column(column_name))
+}
+
 /// Combines a list of predicates into a single `Predicate::And`, or returns `None` if the list is empty.
 fn combine_predicates(mut predicates: Vec<Predicate>) -> Option<Predicate> {
     match predicates.len() {
@@ -191,6 +223,7 @@ mod tests {
                 Literal::Int(1),
             )),
             schema: std::sync::Arc::new(crate::schema::Schema::new()),
+            limit: None,
         };
 
         assert_eq!(optimized_plan, expected_plan);
@@ -219,8 +252,9 @@ mod tests {
                     Literal::Int(1),
                 )),
                 schema: std::sync::Arc::new(crate::schema::Schema::new()),
+                limit: None,
             }),
-            columns: vec!["id".to_string()],
+            columns: vec![crate::query::parser::projection::ProjectedColumn::new("id")],
         };
 
         assert_eq!(optimized_plan, expected_plan);
@@ -259,6 +293,7 @@ mod tests {
                 ),
             ])),
             schema: std::sync::Arc::new(crate::schema::Schema::new()),
+            limit: None,
         };
         assert_eq!(optimized_plan, expected_plan);
     }
@@ -274,6 +309,7 @@ mod tests {
             alias: Some("e".to_string()),
             filter: None,
             schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
         };
 
         let departments_plan = LogicalPlan::Scan {
@@ -281,6 +317,7 @@ mod tests {
             alias: Some("d".to_string()),
             filter: None,
             schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
         };
 
         let plan = employees_plan
@@ -311,6 +348,7 @@ mod tests {
                     Literal::Int(10),
                 )),
                 schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                limit: None,
             }),
             right: Box::new(LogicalPlan::Scan {
                 table_name: "departments".to_string(),
@@ -321,8 +359,10 @@ mod tests {
                     Literal::Int(5),
                 )),
                 schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                limit: None,
             }),
             on: None,
+            join_type: JoinType::Inner,
         };
 
         assert_eq!(optimized_plan, expected_plan);
@@ -339,6 +379,7 @@ mod tests {
             alias: Some("e".to_string()),
             filter: None,
             schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
         }
         .join(
             LogicalPlan::Scan {
@@ -346,6 +387,7 @@ mod tests {
                 alias: Some("d".to_string()),
                 filter: None,
                 schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                limit: None,
             },
             None,
         )
@@ -377,14 +419,17 @@ mod tests {
                         Literal::Int(10),
                     )),
                     schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                    limit: None,
                 }),
                 right: Box::new(LogicalPlan::Scan {
                     table_name: "departments".to_string(),
                     alias: Some("d".to_string()),
                     filter: None,
                     schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                    limit: None,
                 }),
                 on: None,
+                join_type: JoinType::Inner,
             }),
             predicate: Predicate::comparison(
                 Literal::ColumnReference("e.id".to_string()),
@@ -407,6 +452,7 @@ mod tests {
             alias: Some("e".to_string()),
             filter: None,
             schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
         };
 
         let departments_plan = LogicalPlan::Scan {
@@ -414,6 +460,7 @@ mod tests {
             alias: Some("d".to_string()),
             filter: None,
             schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
         };
 
         let locations_plan = LogicalPlan::Scan {
@@ -421,6 +468,7 @@ mod tests {
             alias: Some("l".to_string()),
             filter: None,
             schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
         };
 
         let plan = employees_plan
@@ -462,14 +510,17 @@ mod tests {
                             Literal::Int(10),
                         )),
                         schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                        limit: None,
                     }),
                     right: Box::new(LogicalPlan::Scan {
                         table_name: "departments".to_string(),
                         alias: Some("d".to_string()),
                         filter: None,
                         schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                        limit: None,
                     }),
                     on: None,
+                    join_type: JoinType::Inner,
                 }),
                 predicate: Predicate::comparison(
                     Literal::ColumnReference("e.id".to_string()),
@@ -486,10 +537,177 @@ mod tests {
                     Literal::Int(5),
                 )),
                 schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                limit: None,
             }),
             on: None,
+            join_type: JoinType::Inner,
+        };
+
+        assert_eq!(optimized_plan, expected_plan);
+    }
+
+    #[test]
+    fn push_down_filter_through_left_join_only_to_the_left() {
+        use crate::schema;
+        use crate::types::column_type::ColumnType;
+        use std::sync::Arc;
+
+        let employees_plan = LogicalPlan::Scan {
+            table_name: "employees".to_string(),
+            alias: Some("e".to_string()),
+            filter: None,
+            schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
+        };
+
+        let departments_plan = LogicalPlan::Scan {
+            table_name: "departments".to_string(),
+            alias: Some("d".to_string()),
+            filter: None,
+            schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
+        };
+
+        let plan = employees_plan
+            .join_of_type(departments_plan, None, JoinType::Left)
+            .filter(Predicate::And(vec![
+                Predicate::comparison(
+                    Literal::ColumnReference("e.id".to_string()),
+                    LogicalOperator::Greater,
+                    Literal::Int(10),
+                ),
+                Predicate::comparison(
+                    Literal::ColumnReference("d.id".to_string()),
+                    LogicalOperator::Eq,
+                    Literal::Int(5),
+                ),
+            ]));
+
+        let optimizer = PredicatePushdownRule;
+        let optimized_plan = optimizer.optimize(plan);
+
+        let expected_plan = LogicalPlan::Filter {
+            base_plan: Box::new(LogicalPlan::Join {
+                left: Box::new(LogicalPlan::Scan {
+                    table_name: "employees".to_string(),
+                    alias: Some("e".to_string()),
+                    filter: Some(Predicate::comparison(
+                        Literal::ColumnReference("e.id".to_string()),
+                        LogicalOperator::Greater,
+                        Literal::Int(10),
+                    )),
+                    schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                    limit: None,
+                }),
+                right: Box::new(LogicalPlan::Scan {
+                    table_name: "departments".to_string(),
+                    alias: Some("d".to_string()),
+                    filter: None,
+                    schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+                    limit: None,
+                }),
+                on: None,
+                join_type: JoinType::Left,
+            }),
+            predicate: Predicate::comparison(
+                Literal::ColumnReference("d.id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(5),
+            ),
         };
 
         assert_eq!(optimized_plan, expected_plan);
     }
+
+    #[test]
+    fn do_not_push_down_filter_through_full_outer_join() {
+        use crate::schema;
+        use crate::types::column_type::ColumnType;
+        use std::sync::Arc;
+
+        let employees_plan = LogicalPlan::Scan {
+            table_name: "employees".to_string(),
+            alias: Some("e".to_string()),
+            filter: None,
+            schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
+        };
+
+        let departments_plan = LogicalPlan::Scan {
+            table_name: "departments".to_string(),
+            alias: Some("d".to_string()),
+            filter: None,
+            schema: Arc::new(schema!["id" => ColumnType::Int].unwrap()),
+            limit: None,
+        };
+
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("e.id".to_string()),
+            LogicalOperator::Greater,
+            Literal::Int(10),
+        );
+        let plan = employees_plan
+            .join_of_type(departments_plan, None, JoinType::FullOuter)
+            .filter(predicate);
+
+        let optimizer = PredicatePushdownRule;
+        let optimized_plan = optimizer.optimize(plan);
+
+        assert!(matches!(
+            optimized_plan,
+            LogicalPlan::Filter { base_plan, .. }
+            if matches!(
+                base_plan.as_ref(),
+                LogicalPlan::Join { left, right, join_type: JoinType::FullOuter, .. }
+                if matches!(left.as_ref(), LogicalPlan::Scan { filter: None, .. })
+                && matches!(right.as_ref(), LogicalPlan::Scan { filter: None, .. })
+            )
+        ));
+    }
+
+    #[test]
+    fn do_not_push_down_filter_on_an_unqualified_column_of_both_sides_of_join() {
+        use crate::schema;
+        use crate::types::column_type::ColumnType;
+        use std::sync::Arc;
+
+        let employees_plan = LogicalPlan::Scan {
+            table_name: "employees".to_string(),
+            alias: Some("e".to_string()),
+            filter: None,
+            schema: Arc::new(schema!["id" => ColumnType::Int, "city" => ColumnType::Text].unwrap()),
+            limit: None,
+        };
+
+        let departments_plan = LogicalPlan::Scan {
+            table_name: "departments".to_string(),
+            alias: Some("d".to_string()),
+            filter: None,
+            schema: Arc::new(schema!["id" => ColumnType::Int, "city" => ColumnType::Text].unwrap()),
+            limit: None,
+        };
+
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("city".to_string()),
+            LogicalOperator::Eq,
+            Literal::Text("Paris".to_string()),
+        );
+        let plan = employees_plan
+            .join(departments_plan, None)
+            .filter(predicate);
+
+        let optimizer = PredicatePushdownRule;
+        let optimized_plan = optimizer.optimize(plan);
+
+        assert!(matches!(
+            optimized_plan,
+            LogicalPlan::Filter { base_plan, .. }
+            if matches!(
+                base_plan.as_ref(),
+                LogicalPlan::Join { left, right, .. }
+                if matches!(left.as_ref(), LogicalPlan::Scan { filter: None, .. })
+                && matches!(right.as_ref(), LogicalPlan::Scan { filter: None, .. })
+            )
+        ));
+    }
 }
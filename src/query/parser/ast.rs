@@ -1,10 +1,19 @@
+use crate::query::executor::error::ExecutionError;
 use crate::query::lexer::token::{Token, TokenType};
 use crate::query::parser::error::ParseError;
 use crate::query::parser::ordering_key::OrderingKey;
 use crate::query::parser::projection::Projection;
+use crate::query::plan::LogicalPlan;
+use crate::types::column_type::ColumnType;
+use crate::types::column_value::ColumnValue;
+use crate::types::date::Date;
 
 /// `Ast` represents the Abstract Syntax Tree for SQL statements.
-#[derive(Debug)]
+///
+/// An `Ast` is short-lived (built by the parser and consumed by the planner), so
+/// the size difference between `Select` and the other variants is accepted.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum Ast {
     /// Represents a `SHOW TABLES` statement.
     ShowTables,
@@ -19,6 +28,9 @@ pub(crate) enum Ast {
         source: TableSource,
         /// The projection (columns or all) to select.
         projection: Projection,
+        /// The columns of `DISTINCT ON`, keeping the first row (in the ORDER BY order) of each
+        /// distinct combination of their values.
+        distinct_on: Option<Vec<String>>,
         /// The WHERE filter criteria.
         where_clause: Option<WhereClause>,
         /// The ORDER BY clause, defining the columns and directions used to order rows.
@@ -26,9 +38,202 @@ pub(crate) enum Ast {
         /// The LIMIT (max records) to return.
         limit: Option<usize>,
     },
+    /// Represents a set operation (e.g. `UNION`) combining the results of two queries.
+    SetOperation {
+        /// The set operator.
+        operator: SetOperator,
+        /// The left-hand query.
+        left: Box<Ast>,
+        /// The right-hand query.
+        right: Box<Ast>,
+        /// The ORDER BY clause, applied to the combined result.
+        order_by: Option<Vec<OrderingKey>>,
+        /// The LIMIT (max records) to return from the combined result.
+        limit: Option<usize>,
+    },
+    /// Represents an `EXPLAIN` statement, wrapping the statement whose plan is explained.
+    Explain(Box<Ast>),
+    /// Represents an `INSERT INTO ... VALUES ...` statement.
+    Insert {
+        /// The name of the table to insert into.
+        table_name: String,
+        /// The optional list of columns the values are given for.
+        columns: Option<Vec<String>>,
+        /// The rows of values to insert.
+        rows: Vec<Vec<Literal>>,
+    },
+    /// Represents a `VALUES (...), ...` query, producing the given rows without a table.
+    Values(Vec<Vec<Literal>>),
+    /// Represents a `DELETE FROM` statement.
+    Delete {
+        /// The name of the table to delete from.
+        table_name: String,
+        /// The optional WHERE clause, all the rows are deleted without it.
+        where_clause: Option<WhereClause>,
+    },
+    /// Represents a `CREATE TABLE` statement.
+    CreateTable {
+        /// The name of the table to create.
+        name: String,
+        /// The columns of the table, along with their types.
+        columns: Vec<(String, ColumnType)>,
+        /// The columns of the optional primary key (`primary key (id)`), in order.
+        primary_key: Option<Vec<String>>,
+    },
+    /// Represents a `DROP TABLE` statement.
+    DropTable {
+        /// The name of the table to drop.
+        table_name: String,
+    },
+    /// Represents an `UPDATE ... SET ...` statement.
+    Update {
+        /// The name of the table to update.
+        table_name: String,
+        /// The columns to update, along with their new values.
+        assignments: Vec<(String, Literal)>,
+        /// The optional WHERE clause, all the rows are updated without it.
+        where_clause: Option<WhereClause>,
+    },
+}
+
+impl Ast {
+    /// Sets the ORDER BY and LIMIT clauses of a `Select` or a `SetOperation`, other statements
+    /// are returned unchanged.
+    pub(crate) fn with_order_by_and_limit(
+        self,
+        order_by: Option<Vec<OrderingKey>>,
+        limit: Option<usize>,
+    ) -> Self {
+        match self {
+            Ast::Select {
+                source,
+                projection,
+                distinct_on,
+                where_clause,
+                ..
+            } => Ast::Select {
+                source,
+                projection,
+                distinct_on,
+                where_clause,
+                order_by,
+                limit,
+            },
+            Ast::SetOperation {
+                operator,
+                left,
+                right,
+                ..
+            } => Ast::SetOperation {
+                operator,
+                left,
+                right,
+                order_by,
+                limit,
+            },
+            _ => self,
+        }
+    }
+
+    /// Transforms every literal of the statement, including the literals of its subqueries,
+    /// with the given function.
+    ///
+    /// The operands of arithmetic expressions and function calls are transformed before the
+    /// expression itself.
+    pub(crate) fn map_literals<F: FnMut(Literal) -> Literal>(self, transform: &mut F) -> Ast {
+        let map_rows = |rows: Vec<Vec<Literal>>, transform: &mut F| {
+            rows.into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|literal| literal.map(transform))
+                        .collect()
+                })
+                .collect()
+        };
+        let map_where_clause = |where_clause: Option<WhereClause>, transform: &mut F| {
+            where_clause.map(|where_clause| WhereClause(where_clause.0.map_literals(transform)))
+        };
+        match self {
+            Ast::Select {
+                source,
+                projection,
+                distinct_on,
+                where_clause,
+                order_by,
+                limit,
+            } => Ast::Select {
+                source: source.map_literals(transform),
+                projection: projection.map_literals(transform),
+                distinct_on,
+                where_clause: map_where_clause(where_clause, transform),
+                order_by,
+                limit,
+            },
+            Ast::SetOperation {
+                operator,
+                left,
+                right,
+                order_by,
+                limit,
+            } => Ast::SetOperation {
+                operator,
+                left: Box::new(left.map_literals(transform)),
+                right: Box::new(right.map_literals(transform)),
+                order_by,
+                limit,
+            },
+            Ast::Explain(ast) => Ast::Explain(Box::new(ast.map_literals(transform))),
+            Ast::Insert {
+                table_name,
+                columns,
+                rows,
+            } => Ast::Insert {
+                table_name,
+                columns,
+                rows: map_rows(rows, transform),
+            },
+            Ast::Values(rows) => Ast::Values(map_rows(rows, transform)),
+            Ast::Delete {
+                table_name,
+                where_clause,
+            } => Ast::Delete {
+                table_name,
+                where_clause: map_where_clause(where_clause, transform),
+            },
+            Ast::Update {
+                table_name,
+                assignments,
+                where_clause,
+            } => Ast::Update {
+                table_name,
+                assignments: assignments
+                    .into_iter()
+                    .map(|(column_name, literal)| (column_name, literal.map(transform)))
+                    .collect(),
+                where_clause: map_where_clause(where_clause, transform),
+            },
+            Ast::ShowTables
+            | Ast::DescribeTable { .. }
+            | Ast::CreateTable { .. }
+            | Ast::DropTable { .. } => self,
+        }
+    }
+}
+
+/// `SetOperator` defines the operators combining the results of two queries.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum SetOperator {
+    /// `UNION`, the rows of both queries without duplicates.
+    Union,
+    /// `UNION ALL`, the rows of both queries, including duplicates.
+    UnionAll,
+    /// `INTERSECT`, the rows of the left query which are also in the right query, without duplicates.
+    Intersect,
+    /// `EXCEPT`, the rows of the left query which are not in the right query, without duplicates.
+    Except,
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum TableSource {
     Table {
         name: String,
@@ -38,10 +243,77 @@ pub(crate) enum TableSource {
         left: Box<TableSource>,
         right: Box<TableSource>,
         on: Option<Expression>,
+        join_type: JoinType,
     },
+    /// The source of a `SELECT` without `FROM` (e.g., `select 1 + 1 as two`): a single row
+    /// without columns, against which the projection is evaluated.
+    SingleRow,
+}
+
+/// `JoinType` defines how rows without a match on the other side of a join are treated.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum JoinType {
+    /// Only rows with a match on both sides are returned.
+    Inner,
+    /// All left rows are returned, padding the right side with nulls when there is no match.
+    Left,
+    /// All right rows are returned, padding the left side with nulls when there is no match.
+    Right,
+    /// All rows from both sides are returned, padding the other side with nulls when there is no match.
+    FullOuter,
+    /// Every left row is combined with every right row, without any condition.
+    Cross,
+}
+
+impl JoinType {
+    /// Returns `true` if rows without a match on the other side are returned, padded with nulls.
+    pub(crate) fn is_outer(&self) -> bool {
+        self.pads_left_with_nulls() || self.pads_right_with_nulls()
+    }
+
+    /// Returns `true` if left columns may be null-padded (the left side is not preserved).
+    pub(crate) fn pads_left_with_nulls(&self) -> bool {
+        matches!(self, JoinType::Right | JoinType::FullOuter)
+    }
+
+    /// Returns `true` if right columns may be null-padded (the right side is not preserved).
+    pub(crate) fn pads_right_with_nulls(&self) -> bool {
+        matches!(self, JoinType::Left | JoinType::FullOuter)
+    }
+}
+
+impl std::fmt::Display for JoinType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join_type = match self {
+            JoinType::Inner => "INNER",
+            JoinType::Left => "LEFT",
+            JoinType::Right => "RIGHT",
+            JoinType::FullOuter => "FULL OUTER",
+            JoinType::Cross => "CROSS",
+        };
+        write!(f, "{}", join_type)
+    }
 }
 
 impl TableSource {
+    /// Transforms every literal of the join conditions with the given function.
+    fn map_literals<F: FnMut(Literal) -> Literal>(self, transform: &mut F) -> TableSource {
+        match self {
+            TableSource::Join {
+                left,
+                right,
+                on,
+                join_type,
+            } => TableSource::Join {
+                left: Box::new(left.map_literals(transform)),
+                right: Box::new(right.map_literals(transform)),
+                on: on.map(|on| on.map_literals(transform)),
+                join_type,
+            },
+            TableSource::Table { .. } | TableSource::SingleRow => self,
+        }
+    }
+
     /// Creates a new `TableSource` with table name.
     pub(crate) fn table(name: &str) -> Self {
         TableSource::Table {
@@ -60,18 +332,19 @@ impl TableSource {
 }
 
 /// `WhereClause` represents the filtering criteria in a SELECT statement.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) struct WhereClause(pub(crate) Expression);
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum Expression {
     Single(Clause),
     And(Vec<Expression>),
     Or(Vec<Expression>),
     Grouped(Box<Expression>),
+    Not(Box<Expression>),
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum Clause {
     /// A comparison expression (e.g., `id = 1`, `age > 25`, `1 = 1`).
     Comparison {
@@ -82,16 +355,66 @@ pub(crate) enum Clause {
         /// The right-hand side literal.
         rhs: Literal,
     },
-    /// A LIKE expression (e.g., `name like 'John%'`).
+    /// A LIKE expression (e.g., `name like 'John%'`), or a NOT LIKE expression when negated.
+    /// An ILIKE expression (e.g., `name ilike 'JOHN%'`) matches case-insensitively.
     Like {
         /// The column name to match.
         column_name: String,
         /// The literal pattern to match against (e.g., "John%").
         literal: Literal,
+        /// Whether the match is negated (`not like`).
+        negated: bool,
+        /// Whether the match ignores case (`ilike`).
+        case_insensitive: bool,
+    },
+    /// A BETWEEN expression (e.g., `id between 10 and 20`), inclusive on both ends.
+    Between {
+        /// The column name to check.
+        column_name: String,
+        /// The lower bound (inclusive).
+        low: Literal,
+        /// The upper bound (inclusive).
+        high: Literal,
+    },
+    /// An IN expression over the values of a subquery (e.g., `department_id in (select id from departments)`),
+    /// or a NOT IN expression when negated.
+    In {
+        /// The operand to look up among the values.
+        lhs: Literal,
+        /// The query producing the values, it must select exactly one column.
+        subquery: Box<Ast>,
+        /// Whether the membership is negated (`not in`).
+        negated: bool,
+    },
+    /// An existence clause (e.g., `exists (select id from employees)`).
+    Exists {
+        /// The query whose rows are checked for existence, it may refer to the outer columns.
+        subquery: Box<Ast>,
     },
 }
 
 impl Expression {
+    /// Transforms every literal of the expression with the given function.
+    fn map_literals<F: FnMut(Literal) -> Literal>(self, transform: &mut F) -> Expression {
+        let map_all = |expressions: Vec<Expression>, transform: &mut F| {
+            expressions
+                .into_iter()
+                .map(|expression| expression.map_literals(transform))
+                .collect()
+        };
+        match self {
+            Expression::Single(clause) => Expression::Single(clause.map_literals(transform)),
+            Expression::And(expressions) => Expression::And(map_all(expressions, transform)),
+            Expression::Or(expressions) => Expression::Or(map_all(expressions, transform)),
+            Expression::Grouped(expression) => {
+                Expression::Grouped(Box::new(expression.map_literals(transform)))
+            }
+            Expression::Not(expression) => {
+                Expression::Not(Box::new(expression.map_literals(transform)))
+            }
+        }
+    }
+
     /// Creates a new `Expression::Single` variant.
     pub fn single(clause: Clause) -> Self {
         Expression::Single(clause)
@@ -111,9 +434,57 @@ impl Expression {
     pub fn grouped(expression: Expression) -> Self {
         Expression::Grouped(Box::new(expression))
     }
+
+    /// Creates a new `Expression::Not` variant.
+    pub fn not(expression: Expression) -> Self {
+        Expression::Not(Box::new(expression))
+    }
 }
 
 impl Clause {
+    /// Transforms every literal of the clause with the given function.
+    fn map_literals<F: FnMut(Literal) -> Literal>(self, transform: &mut F) -> Clause {
+        match self {
+            Clause::Comparison { lhs, operator, rhs } => Clause::Comparison {
+                lhs: lhs.map(transform),
+                operator,
+                rhs: rhs.map(transform),
+            },
+            Clause::Like {
+                column_name,
+                literal,
+                negated,
+                case_insensitive,
+            } => Clause::Like {
+                column_name,
+                literal: literal.map(transform),
+                negated,
+                case_insensitive,
+            },
+            Clause::Between {
+                column_name,
+                low,
+                high,
+            } => Clause::Between {
+                column_name,
+                low: low.map(transform),
+                high: high.map(transform),
+            },
+            Clause::In {
+                lhs,
+                subquery,
+                negated,
+            } => Clause::In {
+                lhs: lhs.map(transform),
+                subquery: Box::new(subquery.map_literals(transform)),
+                negated,
+            },
+            Clause::Exists { subquery } => Clause::Exists {
+                subquery: Box::new(subquery.map_literals(transform)),
+            },
+        }
+    }
+
     /// Creates a new `Clause::Comparison` variant.
     ///
     /// # Arguments
@@ -125,6 +496,50 @@ impl Clause {
         Clause::Comparison { lhs, operator, rhs }
     }
 
+    /// Creates a new `Clause::Between` variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - The name of the column to check.
+    /// * `low` - The inclusive lower bound.
+    /// * `high` - The inclusive upper bound.
+    pub fn between(column_name: &str, low: Literal, high: Literal) -> Self {
+        Clause::Between {
+            column_name: column_name.to_string(),
+            low,
+            high,
+        }
+    }
+
+    /// Creates a new `Clause::In` variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The operand to look up among the values.
+    /// * `subquery` - The query producing the values.
+    /// * `negated` - Whether the membership is negated (`not in`).
+    pub fn in_subquery(lhs: Literal, subquery: Ast, negated: bool) -> Self {
+        Clause::In {
+            lhs,
+            subquery: Box::new(subquery),
+            negated,
+        }
+    }
+
+    /// Creates a new `Clause::Exists` variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `subquery` - The query whose rows are checked for existence.
+    pub fn exists(subquery: Ast) -> Self {
+        Clause::Exists {
+            subquery: Box::new(subquery),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Clause {
     /// Creates a new `Clause::Like` variant.
     ///
     /// # Arguments
@@ -135,6 +550,38 @@ impl Clause {
         Clause::Like {
             column_name: column_name.to_string(),
             literal,
+            negated: false,
+            case_insensitive: false,
+        }
+    }
+
+    /// Creates a new negated `Clause::Like` variant (`not like`).
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - The name of the column to match.
+    /// * `literal` - The literal pattern to match against.
+    pub fn not_like(column_name: &str, literal: Literal) -> Self {
+        Clause::Like {
+            column_name: column_name.to_string(),
+            literal,
+            negated: true,
+            case_insensitive: false,
+        }
+    }
+
+    /// Creates a new case-insensitive `Clause::Like` variant (`ilike`).
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - The name of the column to match.
+    /// * `literal` - The literal pattern to match against.
+    pub fn ilike(column_name: &str, literal: Literal) -> Self {
+        Clause::Like {
+            column_name: column_name.to_string(),
+            literal,
+            negated: false,
+            case_insensitive: true,
         }
     }
 }
@@ -159,10 +606,20 @@ impl WhereClause {
     pub fn like(column_name: &str, literal: Literal) -> Self {
         WhereClause(Expression::single(Clause::like(column_name, literal)))
     }
+
+    /// Creates a new `WhereClause` with an ILIKE criteria.
+    pub fn ilike(column_name: &str, literal: Literal) -> Self {
+        WhereClause(Expression::single(Clause::ilike(column_name, literal)))
+    }
+
+    /// Creates a new `WhereClause` with a NOT LIKE criteria.
+    pub fn not_like(column_name: &str, literal: Literal) -> Self {
+        WhereClause(Expression::single(Clause::not_like(column_name, literal)))
+    }
 }
 
 /// `BinaryOperator` defines the binary operators supported in a WHERE clause.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum BinaryOperator {
     /// Equal to `=`.
     Eq,
@@ -178,6 +635,12 @@ pub(crate) enum BinaryOperator {
     NotEq,
     /// Like
     Like,
+    /// Case-insensitive like
+    ILike,
+    /// Null-safe inequality `IS DISTINCT FROM`.
+    IsDistinctFrom,
+    /// Null-safe equality `IS NOT DISTINCT FROM`.
+    IsNotDistinctFrom,
 }
 
 impl BinaryOperator {
@@ -194,6 +657,7 @@ impl BinaryOperator {
             TokenType::LesserEqual => Ok(BinaryOperator::LesserEq),
             TokenType::NotEqual => Ok(BinaryOperator::NotEq),
             _ if token.is_keyword("like") => Ok(BinaryOperator::Like),
+            _ if token.is_keyword("ilike") => Ok(BinaryOperator::ILike),
             _ => Err(ParseError::UnexpectedToken {
                 expected: "operator".to_string(),
                 found: token.lexeme().to_string(),
@@ -202,26 +666,345 @@ impl BinaryOperator {
     }
 }
 
+/// `ArithmeticOperator` defines the arithmetic operators supported in expressions.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ArithmeticOperator {
+    /// Addition `+`.
+    Add,
+    /// Subtraction `-`.
+    Sub,
+    /// Multiplication `*`.
+    Mul,
+    /// Division `/`, truncating towards zero.
+    Div,
+}
+
+impl ArithmeticOperator {
+    /// Applies the operator to the given values.
+    ///
+    /// Only `Int` values are supported, and `Null` on either side results in `Null`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ColumnValue)` - The result of the operation.
+    /// * `Err(ExecutionError::TypeMismatchInComparison)` - If either value is not numeric.
+    /// * `Err(ExecutionError::DivisionByZero)` - If the right-hand side of a division is zero.
+    /// * `Err(ExecutionError::ArithmeticOverflow)` - If the result does not fit in an `Int`.
+    pub(crate) fn apply(
+        &self,
+        lhs: &ColumnValue,
+        rhs: &ColumnValue,
+    ) -> Result<ColumnValue, ExecutionError> {
+        match (lhs, rhs) {
+            (ColumnValue::Null, _) | (_, ColumnValue::Null) => Ok(ColumnValue::Null),
+            (ColumnValue::Int(lhs), ColumnValue::Int(rhs)) => {
+                let result = match self {
+                    ArithmeticOperator::Add => lhs.checked_add(*rhs),
+                    ArithmeticOperator::Sub => lhs.checked_sub(*rhs),
+                    ArithmeticOperator::Mul => lhs.checked_mul(*rhs),
+                    ArithmeticOperator::Div if *rhs == 0 => {
+                        return Err(ExecutionError::DivisionByZero)
+                    }
+                    ArithmeticOperator::Div => lhs.checked_div(*rhs),
+                };
+                result
+                    .map(ColumnValue::Int)
+                    .ok_or(ExecutionError::ArithmeticOverflow)
+            }
+            _ => Err(ExecutionError::TypeMismatchInComparison),
+        }
+    }
+}
+
+impl std::fmt::Display for ArithmeticOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self {
+            ArithmeticOperator::Add => "+",
+            ArithmeticOperator::Sub => "-",
+            ArithmeticOperator::Mul => "*",
+            ArithmeticOperator::Div => "/",
+        };
+        write!(f, "{}", operator)
+    }
+}
+
+/// `ScalarFunction` defines the scalar functions supported in expressions.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ScalarFunction {
+    /// `upper(text)`, converts the text to uppercase.
+    Upper,
+    /// `lower(text)`, converts the text to lowercase.
+    Lower,
+    /// `length(text)`, returns the number of characters in the text.
+    Length,
+    /// `substr(text, start [, length])`, returns the characters of the text from the (1-based)
+    /// `start` position, up to `length` characters.
+    Substr,
+}
+
+impl ScalarFunction {
+    /// Returns the function with the given name (ignoring case), if any.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "upper" => Some(ScalarFunction::Upper),
+            "lower" => Some(ScalarFunction::Lower),
+            "length" => Some(ScalarFunction::Length),
+            "substr" => Some(ScalarFunction::Substr),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the function accepts the given number of arguments.
+    pub(crate) fn accepts_argument_count(&self, count: usize) -> bool {
+        match self {
+            ScalarFunction::Substr => count == 2 || count == 3,
+            _ => count == 1,
+        }
+    }
+
+    /// Returns the type of the value the function evaluates to.
+    pub(crate) fn return_type(&self) -> ColumnType {
+        match self {
+            ScalarFunction::Upper | ScalarFunction::Lower | ScalarFunction::Substr => {
+                ColumnType::Text
+            }
+            ScalarFunction::Length => ColumnType::Int,
+        }
+    }
+
+    /// Applies the function to the given argument values.
+    ///
+    /// A `Null` argument results in `Null`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ColumnValue)` - The result of the function.
+    /// * `Err(ExecutionError::InvalidFunctionArgument)` - If the text argument is not a text, or
+    ///   the position arguments of `substr` are not integers.
+    pub(crate) fn apply(&self, arguments: &[ColumnValue]) -> Result<ColumnValue, ExecutionError> {
+        if arguments
+            .iter()
+            .any(|argument| matches!(argument, ColumnValue::Null))
+        {
+            return Ok(ColumnValue::Null);
+        }
+        match (self, arguments) {
+            (ScalarFunction::Upper, [ColumnValue::Text(text)]) => {
+                Ok(ColumnValue::Text(text.to_uppercase()))
+            }
+            (ScalarFunction::Lower, [ColumnValue::Text(text)]) => {
+                Ok(ColumnValue::Text(text.to_lowercase()))
+            }
+            (ScalarFunction::Length, [ColumnValue::Text(text)]) => {
+                Ok(ColumnValue::Int(text.chars().count() as i64))
+            }
+            (ScalarFunction::Substr, _) => self.substr(arguments),
+            _ => Err(ExecutionError::InvalidFunctionArgument {
+                function: self.to_string(),
+                expected: ColumnType::Text,
+            }),
+        }
+    }
+
+    /// Returns the characters of the text from the (1-based) start position, up to the given
+    /// length.
+    ///
+    /// Positions before the first character are counted but yield nothing, and the result is
+    /// truncated at the end of the text (as in PostgreSQL), so that out-of-range arguments
+    /// produce an empty or truncated text.
+    fn substr(&self, arguments: &[ColumnValue]) -> Result<ColumnValue, ExecutionError> {
+        let invalid_argument = |expected| ExecutionError::InvalidFunctionArgument {
+            function: self.to_string(),
+            expected,
+        };
+        let ColumnValue::Text(text) = &arguments[0] else {
+            return Err(invalid_argument(ColumnType::Text));
+        };
+        let ColumnValue::Int(start) = arguments[1] else {
+            return Err(invalid_argument(ColumnType::Int));
+        };
+        let end = match arguments.get(2) {
+            Some(ColumnValue::Int(length)) => start.saturating_add((*length).max(0)),
+            Some(_) => return Err(invalid_argument(ColumnType::Int)),
+            None => i64::MAX,
+        };
+
+        let skip = usize::try_from(start.max(1) - 1).unwrap_or(usize::MAX);
+        let take = usize::try_from(end.saturating_sub(start.max(1)).max(0)).unwrap_or(usize::MAX);
+        Ok(ColumnValue::Text(
+            text.chars().skip(skip).take(take).collect(),
+        ))
+    }
+}
+
+impl std::fmt::Display for ScalarFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ScalarFunction::Upper => "upper",
+            ScalarFunction::Lower => "lower",
+            ScalarFunction::Length => "length",
+            ScalarFunction::Substr => "substr",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 /// `Literal` represents a concrete value used in expressions.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum Literal {
     /// An integer literal.
     Int(i64),
     /// A text string literal.
     Text(String),
+    /// A boolean literal (`true` or `false`).
+    Bool(bool),
+    /// A date literal (e.g. `date '2024-01-15'`).
+    Date(Date),
+    /// The absence of a value (e.g. the value produced by a subquery for a `NULL` column).
+    Null,
     /// A column reference (e.g. `last_name` in `first_name = last_name` or `employees.first_name`).
     ColumnReference(String),
     /// A pre-resolved column index used for high-performance scans.
     ColumnIndex(usize),
+    /// A (0-based) positional parameter, written as `?` and bound to its value before planning.
+    Parameter(usize),
+    /// An arithmetic expression over two operands (e.g. `salary + bonus`).
+    Arithmetic {
+        /// The left-hand side operand.
+        lhs: Box<Literal>,
+        /// The arithmetic operator.
+        operator: ArithmeticOperator,
+        /// The right-hand side operand.
+        rhs: Box<Literal>,
+    },
+    /// A scalar function applied to its arguments (e.g. `upper(name)`).
+    FunctionCall {
+        /// The function being applied.
+        function: ScalarFunction,
+        /// The arguments of the function.
+        arguments: Vec<Literal>,
+    },
+    /// A parenthesized query producing a single value (e.g. `(select id from departments)`).
+    Subquery(Box<Ast>),
+    /// A subquery planned by the planner, evaluated by the executor before the rows are filtered.
+    PlannedSubquery(Box<LogicalPlan>),
+}
+
+impl std::fmt::Display for Literal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Literal::Int(value) => write!(f, "{}", value),
+            Literal::Text(value) => write!(f, "'{}'", value),
+            Literal::Bool(value) => write!(f, "{}", value),
+            Literal::Date(value) => write!(f, "date '{}'", value),
+            Literal::Null => write!(f, "null"),
+            Literal::ColumnReference(column_name) => write!(f, "{}", column_name),
+            Literal::ColumnIndex(index) => write!(f, "#{}", index),
+            Literal::Parameter(_) => write!(f, "?"),
+            Literal::Arithmetic { lhs, operator, rhs } => {
+                let operand = |literal: &Literal| match literal {
+                    Literal::Arithmetic { .. } => format!("({})", literal),
+                    _ => literal.to_string(),
+                };
+                write!(f, "{} {} {}", operand(lhs), operator, operand(rhs))
+            }
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.to_string())
+                    .collect::<Vec<_>>();
+                write!(f, "{}({})", function, arguments.join(", "))
+            }
+            Literal::Subquery(_) | Literal::PlannedSubquery(_) => write!(f, "(subquery)"),
+        }
+    }
+}
+
+impl From<ColumnValue> for Literal {
+    fn from(value: ColumnValue) -> Self {
+        match value {
+            ColumnValue::Int(value) => Literal::Int(value),
+            ColumnValue::Text(value) => Literal::Text(value),
+            ColumnValue::Bool(value) => Literal::Bool(value),
+            ColumnValue::Date(value) => Literal::Date(value),
+            ColumnValue::Null => Literal::Null,
+        }
+    }
 }
 
 impl Literal {
+    /// Creates a new `Literal::Arithmetic` variant.
+    pub(crate) fn arithmetic(lhs: Literal, operator: ArithmeticOperator, rhs: Literal) -> Self {
+        Literal::Arithmetic {
+            lhs: Box::new(lhs),
+            operator,
+            rhs: Box::new(rhs),
+        }
+    }
+
+    /// Creates a new `Literal::FunctionCall` variant.
+    pub(crate) fn function_call(function: ScalarFunction, arguments: Vec<Literal>) -> Self {
+        Literal::FunctionCall {
+            function,
+            arguments,
+        }
+    }
+
+    /// Transforms the literal with the given function, after transforming its operands,
+    /// arguments and the literals of its subquery.
+    pub(crate) fn map<F: FnMut(Literal) -> Literal>(self, transform: &mut F) -> Literal {
+        let literal = match self {
+            Literal::Arithmetic { lhs, operator, rhs } => {
+                Literal::arithmetic(lhs.map(transform), operator, rhs.map(transform))
+            }
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => Literal::function_call(
+                function,
+                arguments
+                    .into_iter()
+                    .map(|argument| argument.map(transform))
+                    .collect(),
+            ),
+            Literal::Subquery(subquery) => {
+                Literal::Subquery(Box::new(subquery.map_literals(transform)))
+            }
+            literal => literal,
+        };
+        transform(literal)
+    }
+
+    /// Returns the type of the value the literal evaluates to, if it is known without a schema.
+    ///
+    /// Column references (and indexes) are typed by the schema they refer to, and `null` and
+    /// subqueries have no type of their own, so they return `None`.
+    pub(crate) fn value_type(&self) -> Option<ColumnType> {
+        match self {
+            Literal::Int(_) | Literal::Arithmetic { .. } => Some(ColumnType::Int),
+            Literal::Text(_) => Some(ColumnType::Text),
+            Literal::Bool(_) => Some(ColumnType::Bool),
+            Literal::Date(_) => Some(ColumnType::Date),
+            Literal::FunctionCall { function, .. } => Some(function.return_type()),
+            Literal::Null
+            | Literal::ColumnReference(_)
+            | Literal::ColumnIndex(_)
+            | Literal::Parameter(_)
+            | Literal::Subquery(_)
+            | Literal::PlannedSubquery(_) => None,
+        }
+    }
+
     /// Converts a `Token` into a `Literal`.
     ///
     /// # Returns
     ///
     /// * `Ok(Literal::Text)` - If the token is a string literal.
-    /// * `Ok(Literal::Int)` - If the token is a whole number.
+    /// * `Ok(Literal::Int)` - If the token is a whole number (possibly negative).
+    /// * `Ok(Literal::Bool)` - If the token is the `true` or `false` keyword.
     /// * `Ok(Literal::ColumnReference)` - If the token is an identifier.
     /// * `Err(ParseError::NumericLiteralOutOfRange)` - If the number is too large (should theoretically be handled by lexer, but good for safety).
     /// * `Err(ParseError::UnexpectedToken)` - If the token is not a literal.
@@ -237,6 +1020,9 @@ impl Literal {
 
             return Ok(Literal::Int(value));
         }
+        if token.is_keyword("true") || token.is_keyword("false") {
+            return Ok(Literal::Bool(token.is_keyword("true")));
+        }
         if token.is_identifier() {
             return Ok(Literal::ColumnReference(token.lexeme().to_string()));
         }
@@ -310,6 +1096,15 @@ mod operator_tests {
         assert_eq!(BinaryOperator::from_token(&token), Ok(BinaryOperator::Like));
     }
 
+    #[test]
+    fn from_token_ilike() {
+        let token = Token::new("ILIKE", TokenType::Keyword);
+        assert_eq!(
+            BinaryOperator::from_token(&token),
+            Ok(BinaryOperator::ILike)
+        );
+    }
+
     #[test]
     fn from_token_semicolon() {
         let token = Token::semicolon();
@@ -322,12 +1117,291 @@ mod operator_tests {
     }
 }
 
+#[cfg(test)]
+mod arithmetic_operator_tests {
+    use crate::query::executor::error::ExecutionError;
+    use crate::query::parser::ast::{ArithmeticOperator, Literal};
+    use crate::types::column_value::ColumnValue;
+
+    #[test]
+    fn apply_arithmetic_operators() {
+        let (lhs, rhs) = (ColumnValue::int(7), ColumnValue::int(2));
+        assert_eq!(
+            ColumnValue::int(9),
+            ArithmeticOperator::Add.apply(&lhs, &rhs).unwrap()
+        );
+        assert_eq!(
+            ColumnValue::int(5),
+            ArithmeticOperator::Sub.apply(&lhs, &rhs).unwrap()
+        );
+        assert_eq!(
+            ColumnValue::int(14),
+            ArithmeticOperator::Mul.apply(&lhs, &rhs).unwrap()
+        );
+        assert_eq!(
+            ColumnValue::int(3),
+            ArithmeticOperator::Div.apply(&lhs, &rhs).unwrap()
+        );
+    }
+
+    #[test]
+    fn apply_arithmetic_operator_with_null() {
+        let result = ArithmeticOperator::Add.apply(&ColumnValue::null(), &ColumnValue::int(2));
+        assert_eq!(ColumnValue::null(), result.unwrap());
+    }
+
+    #[test]
+    fn attempt_to_divide_by_zero() {
+        let result = ArithmeticOperator::Div.apply(&ColumnValue::int(2), &ColumnValue::int(0));
+        assert!(matches!(result, Err(ExecutionError::DivisionByZero)));
+    }
+
+    #[test]
+    fn attempt_to_apply_arithmetic_operator_with_overflow() {
+        let result =
+            ArithmeticOperator::Mul.apply(&ColumnValue::int(i64::MAX), &ColumnValue::int(2));
+        assert!(matches!(result, Err(ExecutionError::ArithmeticOverflow)));
+    }
+
+    #[test]
+    fn attempt_to_apply_arithmetic_operator_on_text() {
+        let result =
+            ArithmeticOperator::Add.apply(&ColumnValue::text("relop"), &ColumnValue::int(2));
+        assert!(matches!(
+            result,
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
+    #[test]
+    fn display_arithmetic_literal() {
+        let literal = Literal::arithmetic(
+            Literal::ColumnReference("salary".to_string()),
+            ArithmeticOperator::Mul,
+            Literal::arithmetic(
+                Literal::Int(1),
+                ArithmeticOperator::Add,
+                Literal::ColumnReference("raise".to_string()),
+            ),
+        );
+        assert_eq!("salary * (1 + raise)", literal.to_string());
+    }
+}
+
+#[cfg(test)]
+mod scalar_function_tests {
+    use crate::query::executor::error::ExecutionError;
+    use crate::query::parser::ast::{Literal, ScalarFunction};
+    use crate::types::column_type::ColumnType;
+    use crate::types::column_value::ColumnValue;
+
+    #[test]
+    fn scalar_function_from_name() {
+        assert_eq!(
+            Some(ScalarFunction::Upper),
+            ScalarFunction::from_name("UPPER")
+        );
+        assert_eq!(
+            Some(ScalarFunction::Lower),
+            ScalarFunction::from_name("lower")
+        );
+        assert_eq!(
+            Some(ScalarFunction::Length),
+            ScalarFunction::from_name("Length")
+        );
+        assert_eq!(None, ScalarFunction::from_name("trim"));
+    }
+
+    #[test]
+    fn apply_string_functions() {
+        let arguments = [ColumnValue::text("Relop")];
+        assert_eq!(
+            ColumnValue::text("RELOP"),
+            ScalarFunction::Upper.apply(&arguments).unwrap()
+        );
+        assert_eq!(
+            ColumnValue::text("relop"),
+            ScalarFunction::Lower.apply(&arguments).unwrap()
+        );
+        assert_eq!(
+            ColumnValue::int(5),
+            ScalarFunction::Length.apply(&arguments).unwrap()
+        );
+    }
+
+    #[test]
+    fn apply_length_counting_characters() {
+        let arguments = [ColumnValue::text("héllo")];
+        assert_eq!(
+            ColumnValue::int(5),
+            ScalarFunction::Length.apply(&arguments).unwrap()
+        );
+    }
+
+    #[test]
+    fn apply_string_function_to_null() {
+        assert_eq!(
+            ColumnValue::Null,
+            ScalarFunction::Upper.apply(&[ColumnValue::Null]).unwrap()
+        );
+    }
+
+    #[test]
+    fn attempt_to_apply_string_function_to_int() {
+        let result = ScalarFunction::Lower.apply(&[ColumnValue::int(1)]);
+        assert!(matches!(
+            result,
+            Err(ExecutionError::InvalidFunctionArgument { function, expected })
+                if function == "lower" && expected == ColumnType::Text
+        ));
+    }
+
+    fn substr(arguments: &[ColumnValue]) -> ColumnValue {
+        ScalarFunction::Substr.apply(arguments).unwrap()
+    }
+
+    #[test]
+    fn apply_substr() {
+        let text = ColumnValue::text("relop");
+        assert_eq!(
+            ColumnValue::text("rel"),
+            substr(&[text.clone(), ColumnValue::int(1), ColumnValue::int(3)])
+        );
+        assert_eq!(
+            ColumnValue::text("lop"),
+            substr(&[text, ColumnValue::int(3)])
+        );
+    }
+
+    #[test]
+    fn apply_substr_with_start_beyond_length() {
+        let text = ColumnValue::text("relop");
+        assert_eq!(
+            ColumnValue::text(""),
+            substr(&[text.clone(), ColumnValue::int(10), ColumnValue::int(2)])
+        );
+        assert_eq!(ColumnValue::text(""), substr(&[text, ColumnValue::int(6)]));
+    }
+
+    #[test]
+    fn apply_substr_with_length_exceeding_remaining_characters() {
+        let text = ColumnValue::text("relop");
+        assert_eq!(
+            ColumnValue::text("op"),
+            substr(&[text.clone(), ColumnValue::int(4), ColumnValue::int(10)])
+        );
+        assert_eq!(
+            ColumnValue::text("relop"),
+            substr(&[text, ColumnValue::int(1), ColumnValue::int(i64::MAX)])
+        );
+    }
+
+    #[test]
+    fn apply_substr_with_start_before_first_character() {
+        let text = ColumnValue::text("relop");
+        assert_eq!(
+            ColumnValue::text("r"),
+            substr(&[text.clone(), ColumnValue::int(0), ColumnValue::int(2)])
+        );
+        assert_eq!(
+            ColumnValue::text(""),
+            substr(&[text.clone(), ColumnValue::int(-5), ColumnValue::int(3)])
+        );
+        assert_eq!(
+            ColumnValue::text("relop"),
+            substr(&[text, ColumnValue::int(i64::MIN)])
+        );
+    }
+
+    #[test]
+    fn apply_substr_with_negative_length() {
+        let text = ColumnValue::text("relop");
+        assert_eq!(
+            ColumnValue::text(""),
+            substr(&[text, ColumnValue::int(2), ColumnValue::int(-1)])
+        );
+    }
+
+    #[test]
+    fn apply_substr_counting_characters() {
+        let text = ColumnValue::text("héllo");
+        assert_eq!(
+            ColumnValue::text("él"),
+            substr(&[text, ColumnValue::int(2), ColumnValue::int(2)])
+        );
+    }
+
+    #[test]
+    fn attempt_to_apply_substr_to_int() {
+        let result = ScalarFunction::Substr.apply(&[ColumnValue::int(1), ColumnValue::int(1)]);
+        assert!(matches!(
+            result,
+            Err(ExecutionError::InvalidFunctionArgument { function, expected })
+                if function == "substr" && expected == ColumnType::Text
+        ));
+    }
+
+    #[test]
+    fn attempt_to_apply_substr_with_text_start() {
+        let result =
+            ScalarFunction::Substr.apply(&[ColumnValue::text("relop"), ColumnValue::text("1")]);
+        assert!(matches!(
+            result,
+            Err(ExecutionError::InvalidFunctionArgument { function, expected })
+                if function == "substr" && expected == ColumnType::Int
+        ));
+    }
+
+    #[test]
+    fn substr_accepts_two_or_three_arguments() {
+        assert!(!ScalarFunction::Substr.accepts_argument_count(1));
+        assert!(ScalarFunction::Substr.accepts_argument_count(2));
+        assert!(ScalarFunction::Substr.accepts_argument_count(3));
+        assert!(!ScalarFunction::Substr.accepts_argument_count(4));
+    }
+
+    #[test]
+    fn display_function_call() {
+        let literal = Literal::function_call(
+            ScalarFunction::Upper,
+            vec![Literal::ColumnReference("name".to_string())],
+        );
+        assert_eq!("upper(name)", literal.to_string());
+    }
+
+    #[test]
+    fn value_type_of_function_call() {
+        let literal = Literal::function_call(
+            ScalarFunction::Length,
+            vec![Literal::ColumnReference("name".to_string())],
+        );
+        assert_eq!(Some(ColumnType::Int), literal.value_type());
+        assert_eq!(
+            None,
+            Literal::ColumnReference("name".to_string()).value_type()
+        );
+    }
+}
+
 #[cfg(test)]
 mod literal_tests {
     use crate::query::lexer::token::{Token, TokenType};
     use crate::query::parser::ast::Literal;
     use crate::query::parser::error::ParseError;
 
+    #[test]
+    fn from_column_value() {
+        use crate::types::column_value::ColumnValue;
+
+        assert_eq!(Literal::Int(1), Literal::from(ColumnValue::int(1)));
+        assert_eq!(
+            Literal::Text("relop".to_string()),
+            Literal::from(ColumnValue::text("relop"))
+        );
+        assert_eq!(Literal::Bool(true), Literal::from(ColumnValue::bool(true)));
+        assert_eq!(Literal::Null, Literal::from(ColumnValue::null()));
+    }
+
     #[test]
     fn from_token_string_literal() {
         let token = Token::new("relop", TokenType::StringLiteral);
@@ -342,6 +1416,29 @@ mod literal_tests {
         assert!(matches!(literal, Literal::Int(val) if val == 42));
     }
 
+    #[test]
+    fn from_token_negative_integer_literal() {
+        let token = Token::new("-1", TokenType::WholeNumber);
+        let literal = Literal::from_token(&token).unwrap();
+        assert_eq!(Literal::Int(-1), literal);
+    }
+
+    #[test]
+    fn from_token_negative_zero_literal() {
+        let token = Token::new("-0", TokenType::WholeNumber);
+        let literal = Literal::from_token(&token).unwrap();
+        assert_eq!(Literal::Int(0), literal);
+    }
+
+    #[test]
+    fn from_token_bool_literals() {
+        let literal = Literal::from_token(&Token::new("true", TokenType::Keyword)).unwrap();
+        assert_eq!(Literal::Bool(true), literal);
+
+        let literal = Literal::from_token(&Token::new("FALSE", TokenType::Keyword)).unwrap();
+        assert_eq!(Literal::Bool(false), literal);
+    }
+
     #[test]
     fn from_token_invalid_literal() {
         let token = Token::new("select", TokenType::Keyword);
@@ -361,6 +1458,16 @@ mod literal_tests {
             Err(ParseError::NumericLiteralOutOfRange(value)) if value == "9999999999999999999999"
         ));
     }
+
+    #[test]
+    fn from_token_negative_integer_literal_out_of_range() {
+        let token = Token::new("-9999999999999999999999", TokenType::WholeNumber);
+        let result = Literal::from_token(&token);
+        assert!(matches!(
+            result,
+            Err(ParseError::NumericLiteralOutOfRange(value)) if value == "-9999999999999999999999"
+        ));
+    }
 }
 #[cfg(test)]
 mod where_clause_tests {
@@ -393,6 +1500,8 @@ mod where_clause_tests {
             WhereClause(Expression::single(Clause::Like {
                 column_name: "name".to_string(),
                 literal: Literal::Text("John%".to_string()),
+                negated: false,
+                case_insensitive: false,
             }))
         );
     }
@@ -427,6 +1536,36 @@ mod clause_tests {
             Clause::Like {
                 column_name: "name".to_string(),
                 literal: Literal::Text("John%".to_string()),
+                negated: false,
+                case_insensitive: false,
+            }
+        );
+    }
+
+    #[test]
+    fn create_not_like_clause() {
+        let clause = Clause::not_like("name", Literal::Text("John%".to_string()));
+        assert_eq!(
+            clause,
+            Clause::Like {
+                column_name: "name".to_string(),
+                literal: Literal::Text("John%".to_string()),
+                negated: true,
+                case_insensitive: false,
+            }
+        );
+    }
+
+    #[test]
+    fn create_ilike_clause() {
+        let clause = Clause::ilike("name", Literal::Text("JOHN%".to_string()));
+        assert_eq!(
+            clause,
+            Clause::Like {
+                column_name: "name".to_string(),
+                literal: Literal::Text("JOHN%".to_string()),
+                negated: false,
+                case_insensitive: true,
             }
         );
     }
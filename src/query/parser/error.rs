@@ -27,4 +27,20 @@ pub enum ParseError {
     UnexpectedEndOfInput,
     /// Indicates that the input has exceeded the range of numeric literal.
     NumericLiteralOutOfRange(String),
+    /// Indicates that an `ON` clause was given for a `CROSS JOIN`.
+    OnClauseInCrossJoin,
+    /// Indicates that an unknown function was called.
+    UnknownFunction(String),
+    /// Indicates that a function was called with an unsupported number of arguments.
+    FunctionArgumentCountMismatch {
+        /// The name of the function.
+        function: String,
+        /// The number of arguments given.
+        actual: usize,
+    },
+    /// Indicates that a date literal is not a valid date (written as `YYYY-MM-DD`).
+    InvalidDate(String),
+    /// Indicates that a `CREATE TABLE` declares more than one primary key, containing the name
+    /// of the table.
+    MultiplePrimaryKeys(String),
 }
@@ -5,14 +5,21 @@ pub(crate) mod projection;
 
 use crate::query::lexer::token::{Token, TokenStream, TokenType};
 use crate::query::lexer::token_cursor::TokenCursor;
-use crate::query::parser::ast::{Ast, BinaryOperator, Clause, Expression, Literal, WhereClause};
+use crate::query::parser::ast::{
+    ArithmeticOperator, Ast, BinaryOperator, Clause, Expression, JoinType, Literal, ScalarFunction,
+    SetOperator, WhereClause,
+};
 use crate::query::parser::error::ParseError;
-use crate::query::parser::ordering_key::{OrderingDirection, OrderingKey};
-use crate::query::parser::projection::Projection;
+use crate::query::parser::ordering_key::{NullsOrdering, OrderingDirection, OrderingKey};
+use crate::query::parser::projection::{Aggregate, ProjectedColumn, Projection};
+use crate::types::column_type::ColumnType;
+use crate::types::date::Date;
 
 /// `Parser` is responsible for parsing a stream of tokens into an Abstract Syntax Tree (AST).
 pub(crate) struct Parser {
     cursor: TokenCursor,
+    parameter_count: usize,
+    default_ordering_direction: OrderingDirection,
 }
 
 impl Parser {
@@ -20,9 +27,21 @@ impl Parser {
     pub(crate) fn new(stream: TokenStream) -> Parser {
         Self {
             cursor: stream.cursor(),
+            parameter_count: 0,
+            default_ordering_direction: OrderingDirection::Ascending,
         }
     }
 
+    /// Sets the direction of the ordering keys given without `ASC` or `DESC` (ascending by
+    /// default).
+    pub(crate) fn with_default_ordering_direction(
+        mut self,
+        direction: OrderingDirection,
+    ) -> Parser {
+        self.default_ordering_direction = direction;
+        self
+    }
+
     /// Parses the token stream into an `Ast`.
     ///
     /// The grammar is available in `docs/grammar.ebnf`.
@@ -37,6 +56,15 @@ impl Parser {
         Ok(ast)
     }
 
+    /// Returns the line and column of the furthest token the parser has looked at.
+    ///
+    /// After `parse` fails, this is the position where parsing failed.
+    pub(crate) fn position(&self) -> (usize, usize) {
+        self.cursor
+            .furthest_seen()
+            .map_or((1, 1), |token| (token.line(), token.column()))
+    }
+
     fn parse_statement(&mut self) -> Result<Ast, ParseError> {
         match self.cursor.peek() {
             Some(token) => {
@@ -46,9 +74,24 @@ impl Parser {
                     self.parse_describe_table()
                 } else if token.matches(TokenType::Keyword, "select") {
                     self.parse_select()
+                } else if token.matches(TokenType::Keyword, "explain") {
+                    self.parse_explain()
+                } else if token.matches(TokenType::Keyword, "insert") {
+                    self.parse_insert()
+                } else if token.matches(TokenType::Keyword, "delete") {
+                    self.parse_delete()
+                } else if token.matches(TokenType::Keyword, "update") {
+                    self.parse_update()
+                } else if token.matches(TokenType::Keyword, "values") {
+                    self.parse_values()
+                } else if token.matches(TokenType::Keyword, "create") {
+                    self.parse_create_table()
+                } else if token.matches(TokenType::Keyword, "drop") {
+                    self.parse_drop_table()
                 } else {
                     Err(ParseError::UnsupportedToken {
-                        expected: "show | describe | select".to_string(),
+                        expected: "show | describe | select | explain | insert | values | delete | update | create | drop"
+                            .to_string(),
                         found: token.lexeme().to_string(),
                     })
                 }
@@ -77,24 +120,366 @@ impl Parser {
     }
 
     fn parse_select(&mut self) -> Result<Ast, ParseError> {
+        let query = self.parse_query()?;
+        let _ = self.eat_if(|token| token.is_semicolon());
+
+        Ok(query)
+    }
+
+    /// Parses a `SELECT` along with its set operations, ORDER BY and LIMIT clauses, without the
+    /// trailing semicolon (a subquery is closed by a parenthesis instead).
+    fn parse_query(&mut self) -> Result<Ast, ParseError> {
+        let mut query = self.parse_select_core()?;
+        while let Some(operator) = self.maybe_set_operator() {
+            let right = self.parse_select_core()?;
+            query = Ast::SetOperation {
+                operator,
+                left: Box::new(query),
+                right: Box::new(right),
+                order_by: None,
+                limit: None,
+            };
+        }
+        let order_by = self.maybe_order_by()?;
+        let limit = self.maybe_limit()?;
+
+        Ok(query.with_order_by_and_limit(order_by, limit))
+    }
+
+    /// Parses a `SELECT` without the ORDER BY and LIMIT clauses, which apply to the result of
+    /// a set operation (if any) as a whole.
+    fn parse_select_core(&mut self) -> Result<Ast, ParseError> {
         self.expect_keyword("select")?;
+        let distinct_on = self.maybe_distinct_on()?;
         let projection = self.expect_projection()?;
+        if self.is_at_end_of_select_core() {
+            return Ok(Ast::Select {
+                source: ast::TableSource::SingleRow,
+                projection,
+                distinct_on,
+                where_clause: None,
+                order_by: None,
+                limit: None,
+            });
+        }
         self.expect_keyword("from")?;
         let source = self.expect_table_source()?;
         let where_clause = self.maybe_where_clause()?;
-        let order_by = self.maybe_order_by()?;
-        let limit = self.maybe_limit()?;
-        let _ = self.eat_if(|token| token.is_semicolon());
 
         Ok(Ast::Select {
             source,
             projection,
+            distinct_on,
+            where_clause,
+            order_by: None,
+            limit: None,
+        })
+    }
+
+    /// Returns `true` if the projection of a `SELECT` is followed by the end of the query, a set
+    /// operator, `ORDER BY` or `LIMIT`, instead of `FROM` (e.g., `select 1 + 1 as two`).
+    fn is_at_end_of_select_core(&self) -> bool {
+        self.cursor.peek().is_none_or(|token| {
+            token.is_end_of_stream()
+                || token.is_semicolon()
+                || token.is_right_parentheses()
+                || ["union", "intersect", "except", "order", "limit"]
+                    .iter()
+                    .any(|keyword| token.is_keyword(keyword))
+        })
+    }
+
+    /// Parses the parenthesized columns of `DISTINCT ON`, e.g., `distinct on (city, name)`.
+    fn maybe_distinct_on(&mut self) -> Result<Option<Vec<String>>, ParseError> {
+        if !self.eat_if(|token| token.is_keyword("distinct")) {
+            return Ok(None);
+        }
+        self.expect_keyword("on")?;
+        self.expect_left_parentheses()?;
+        let mut columns = vec![self.expect_identifier()?];
+        while self.eat_if(|token| token.is_comma()) {
+            columns.push(self.expect_identifier()?);
+        }
+        self.expect_right_parentheses()?;
+        Ok(Some(columns))
+    }
+
+    fn maybe_set_operator(&mut self) -> Option<SetOperator> {
+        if self.eat_if(|token| token.is_keyword("union")) {
+            if self.eat_if(|token| token.is_keyword("all")) {
+                return Some(SetOperator::UnionAll);
+            }
+            return Some(SetOperator::Union);
+        }
+        if self.eat_if(|token| token.is_keyword("intersect")) {
+            return Some(SetOperator::Intersect);
+        }
+        if self.eat_if(|token| token.is_keyword("except")) {
+            return Some(SetOperator::Except);
+        }
+        None
+    }
+
+    fn parse_explain(&mut self) -> Result<Ast, ParseError> {
+        self.expect_keyword("explain")?;
+        match self.cursor.peek() {
+            Some(token) if token.matches(TokenType::Keyword, "select") => {
+                Ok(Ast::Explain(Box::new(self.parse_select()?)))
+            }
+            Some(token) => Err(ParseError::UnexpectedToken {
+                expected: "select".to_string(),
+                found: token.lexeme().to_string(),
+            }),
+            None => Err(ParseError::UnexpectedEndOfInput),
+        }
+    }
+
+    fn parse_insert(&mut self) -> Result<Ast, ParseError> {
+        self.expect_keyword("insert")?;
+        self.expect_keyword("into")?;
+        let table_name = self.expect_identifier()?;
+        let columns = self.maybe_insert_columns()?;
+        let rows = self.expect_rows_of_values()?;
+        let _ = self.eat_if(|token| token.is_semicolon());
+
+        Ok(Ast::Insert {
+            table_name,
+            columns,
+            rows,
+        })
+    }
+
+    fn parse_values(&mut self) -> Result<Ast, ParseError> {
+        let rows = self.expect_rows_of_values()?;
+        let _ = self.eat_if(|token| token.is_semicolon());
+
+        Ok(Ast::Values(rows))
+    }
+
+    /// Parses `VALUES` followed by one or more comma-separated rows of values
+    /// (e.g. `values (1, 'relop'), (2, 'query')`).
+    fn expect_rows_of_values(&mut self) -> Result<Vec<Vec<Literal>>, ParseError> {
+        self.expect_keyword("values")?;
+
+        let mut rows = vec![self.expect_values()?];
+        while self.eat_if(|token| token.is_comma()) {
+            rows.push(self.expect_values()?);
+        }
+        Ok(rows)
+    }
+
+    fn parse_delete(&mut self) -> Result<Ast, ParseError> {
+        self.expect_keyword("delete")?;
+        self.expect_keyword("from")?;
+        let table_name = self.expect_identifier()?;
+        let where_clause = self.maybe_where_clause()?;
+        let _ = self.eat_if(|token| token.is_semicolon());
+
+        Ok(Ast::Delete {
+            table_name,
+            where_clause,
+        })
+    }
+
+    fn parse_create_table(&mut self) -> Result<Ast, ParseError> {
+        self.expect_keyword("create")?;
+        self.expect_keyword("table")?;
+        let name = self.expect_identifier()?;
+
+        self.expect_left_parentheses()?;
+        let mut columns = Vec::new();
+        let mut primary_key = None;
+        loop {
+            let element_primary_key = if self.eat_if(|token| token.is_keyword("primary")) {
+                self.expect_keyword("key")?;
+                Some(self.expect_identifiers_in_parentheses()?)
+            } else {
+                let (column_name, column_type) = self.expect_column_definition()?;
+                let is_primary_key = self.maybe_primary_key_suffix()?;
+                columns.push((column_name.clone(), column_type));
+                is_primary_key.then(|| vec![column_name])
+            };
+            if let Some(element_primary_key) = element_primary_key {
+                if primary_key.replace(element_primary_key).is_some() {
+                    return Err(ParseError::MultiplePrimaryKeys(name));
+                }
+            }
+            if !self.eat_if(|token| token.is_comma()) {
+                break;
+            }
+        }
+        self.expect_right_parentheses()?;
+        let _ = self.eat_if(|token| token.is_semicolon());
+
+        Ok(Ast::CreateTable {
+            name,
+            columns,
+            primary_key,
+        })
+    }
+
+    /// Parses the optional `primary key` suffix of a column definition (e.g., `id int primary key`).
+    fn maybe_primary_key_suffix(&mut self) -> Result<bool, ParseError> {
+        if !self.eat_if(|token| token.is_keyword("primary")) {
+            return Ok(false);
+        }
+        self.expect_keyword("key")?;
+        Ok(true)
+    }
+
+    /// Parses a parenthesized, comma-separated list of identifiers, e.g., `(department_id, id)`.
+    fn expect_identifiers_in_parentheses(&mut self) -> Result<Vec<String>, ParseError> {
+        self.expect_left_parentheses()?;
+        let mut identifiers = vec![self.expect_identifier()?];
+        while self.eat_if(|token| token.is_comma()) {
+            identifiers.push(self.expect_identifier()?);
+        }
+        self.expect_right_parentheses()?;
+        Ok(identifiers)
+    }
+
+    fn parse_drop_table(&mut self) -> Result<Ast, ParseError> {
+        self.expect_keyword("drop")?;
+        self.expect_keyword("table")?;
+        let table_name = self.expect_identifier()?;
+        let _ = self.eat_if(|token| token.is_semicolon());
+
+        Ok(Ast::DropTable { table_name })
+    }
+
+    fn expect_column_definition(&mut self) -> Result<(String, ColumnType), ParseError> {
+        let column_name = self.expect_identifier()?;
+        let column_type = match self.cursor.next() {
+            Some(token) if token.is_identifier() => {
+                match token.lexeme().to_ascii_lowercase().as_str() {
+                    "int" => ColumnType::Int,
+                    "text" => ColumnType::Text,
+                    "bool" => ColumnType::Bool,
+                    "date" => ColumnType::Date,
+                    _ => {
+                        return Err(ParseError::UnexpectedToken {
+                            expected: "int | text | bool | date".to_string(),
+                            found: token.lexeme().to_string(),
+                        })
+                    }
+                }
+            }
+            Some(token) => {
+                return Err(ParseError::UnexpectedToken {
+                    expected: "int | text | bool | date".to_string(),
+                    found: token.lexeme().to_string(),
+                })
+            }
+            None => return Err(ParseError::UnexpectedEndOfInput),
+        };
+        Ok((column_name, column_type))
+    }
+
+    fn parse_update(&mut self) -> Result<Ast, ParseError> {
+        self.expect_keyword("update")?;
+        let table_name = self.expect_identifier()?;
+        self.expect_keyword("set")?;
+
+        let mut assignments = vec![self.expect_assignment()?];
+        while self.eat_if(|token| token.is_comma()) {
+            assignments.push(self.expect_assignment()?);
+        }
+        let where_clause = self.maybe_where_clause()?;
+        let _ = self.eat_if(|token| token.is_semicolon());
+
+        Ok(Ast::Update {
+            table_name,
+            assignments,
             where_clause,
-            order_by,
-            limit,
         })
     }
 
+    fn expect_assignment(&mut self) -> Result<(String, Literal), ParseError> {
+        let column_name = self.expect_identifier()?;
+        match self.cursor.next() {
+            Some(token) if token.token_type() == TokenType::Equal => {}
+            Some(token) => {
+                return Err(ParseError::UnexpectedToken {
+                    expected: "=".to_string(),
+                    found: token.lexeme().to_string(),
+                })
+            }
+            None => return Err(ParseError::UnexpectedEndOfInput),
+        }
+        Ok((column_name, self.expect_value()?))
+    }
+
+    fn maybe_insert_columns(&mut self) -> Result<Option<Vec<String>>, ParseError> {
+        if !self.eat_if(|token| token.is_left_parentheses()) {
+            return Ok(None);
+        }
+        let mut columns = vec![self.expect_identifier()?];
+        while self.eat_if(|token| token.is_comma()) {
+            columns.push(self.expect_identifier()?);
+        }
+        self.expect_right_parentheses()?;
+        Ok(Some(columns))
+    }
+
+    /// Parses a parenthesized row of values, e.g., `(1, 'relop')`.
+    fn expect_values(&mut self) -> Result<Vec<Literal>, ParseError> {
+        self.expect_left_parentheses()?;
+        let mut values = vec![self.expect_value()?];
+        while self.eat_if(|token| token.is_comma()) {
+            values.push(self.expect_value()?);
+        }
+        self.expect_right_parentheses()?;
+        Ok(values)
+    }
+
+    /// Parses a single value: a literal that is not a column reference.
+    fn expect_value(&mut self) -> Result<Literal, ParseError> {
+        if let Some(parameter) = self.maybe_parameter() {
+            return Ok(parameter);
+        }
+        if let Some(date) = self.maybe_date_literal()? {
+            return Ok(date);
+        }
+        match self.cursor.next() {
+            Some(token) if !token.is_identifier() => match Literal::from_token(token) {
+                Err(ParseError::UnexpectedToken { found, .. }) => {
+                    Err(ParseError::UnexpectedToken {
+                        expected: "value".to_string(),
+                        found,
+                    })
+                }
+                result => result,
+            },
+            Some(token) => Err(ParseError::UnexpectedToken {
+                expected: "value".to_string(),
+                found: token.lexeme().to_string(),
+            }),
+            None => Err(ParseError::UnexpectedEndOfInput),
+        }
+    }
+
+    fn expect_left_parentheses(&mut self) -> Result<(), ParseError> {
+        match self.cursor.next() {
+            Some(token) if token.is_left_parentheses() => Ok(()),
+            Some(token) => Err(ParseError::UnexpectedToken {
+                expected: "(".to_string(),
+                found: token.lexeme().to_string(),
+            }),
+            None => Err(ParseError::UnexpectedEndOfInput),
+        }
+    }
+
+    fn expect_right_parentheses(&mut self) -> Result<(), ParseError> {
+        match self.cursor.next() {
+            Some(token) if token.is_right_parentheses() => Ok(()),
+            Some(token) => Err(ParseError::UnexpectedToken {
+                expected: ")".to_string(),
+                found: token.lexeme().to_string(),
+            }),
+            None => Err(ParseError::UnexpectedEndOfInput),
+        }
+    }
+
     fn expect_keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
         match self.cursor.next() {
             Some(token) if token.matches(TokenType::Keyword, keyword) => Ok(()),
@@ -121,47 +506,144 @@ impl Parser {
         if self.eat_if(|token| token.is_star()) {
             return Ok(Projection::All);
         }
+        if let Some(qualifier) = self.maybe_qualifier() {
+            self.expect_star()?;
+            return Ok(Projection::QualifiedAll(qualifier));
+        }
+        if self.is_at_aggregate() {
+            return Ok(Projection::Aggregates(self.expect_aggregates()?));
+        }
         let columns = self.expect_columns()?;
         Ok(Projection::Columns(columns))
     }
 
-    fn expect_columns(&mut self) -> Result<Vec<String>, ParseError> {
-        let mut columns = Vec::new();
+    fn maybe_qualifier(&mut self) -> Option<String> {
+        let token = self.cursor.peek()?;
+        if !token.is_identifier() {
+            return None;
+        }
+        let qualifier = token.lexeme().strip_suffix('.')?.to_string();
+        self.cursor.next();
+        Some(qualifier)
+    }
+
+    fn expect_star(&mut self) -> Result<(), ParseError> {
+        match self.cursor.next() {
+            Some(token) if token.is_star() => Ok(()),
+            Some(token) => Err(ParseError::UnexpectedToken {
+                expected: "*".to_string(),
+                found: token.lexeme().to_string(),
+            }),
+            None => Err(ParseError::UnexpectedEndOfInput),
+        }
+    }
+
+    fn is_at_aggregate(&self) -> bool {
+        let is_count = self.cursor.peek().is_some_and(|token| {
+            token.is_identifier() && token.lexeme().eq_ignore_ascii_case("count")
+        });
+        is_count
+            && self
+                .cursor
+                .peek_second()
+                .is_some_and(|token| token.is_left_parentheses())
+    }
+
+    /// Parses the aggregates of a projection, e.g., `count(*), count(distinct city) as cities`.
+    ///
+    /// Without `GROUP BY`, the aggregates apply to all the rows, so they can not be mixed with
+    /// columns.
+    fn expect_aggregates(&mut self) -> Result<Vec<Aggregate>, ParseError> {
+        let mut aggregates = vec![self.expect_aggregate()?];
+        while self.eat_if(|token| token.is_comma()) {
+            aggregates.push(self.expect_aggregate()?);
+        }
+        Ok(aggregates)
+    }
 
-        let first = match self.cursor.next() {
-            Some(token) if token.is_identifier() => token.lexeme().to_string(),
+    fn expect_aggregate(&mut self) -> Result<Aggregate, ParseError> {
+        match self.cursor.next() {
+            Some(token)
+                if token.is_identifier() && token.lexeme().eq_ignore_ascii_case("count") => {}
             Some(token) => {
                 return Err(ParseError::UnexpectedToken {
-                    expected: "identifier".to_string(),
+                    expected: "count".to_string(),
                     found: token.lexeme().to_string(),
-                });
+                })
             }
             None => return Err(ParseError::UnexpectedEndOfInput),
+        }
+        self.expect_left_parentheses()?;
+        let aggregate = if self.eat_if(|token| token.is_star()) {
+            Aggregate::count_all()
+        } else if self.eat_if(|token| token.is_keyword("distinct")) {
+            Aggregate::count_distinct(self.expect_identifier()?)
+        } else {
+            Aggregate::count(self.expect_identifier()?)
         };
-        columns.push(first);
+        self.expect_right_parentheses()?;
 
+        match self.maybe_alias()? {
+            Some(alias) => Ok(aggregate.with_alias(alias)),
+            None => Ok(aggregate),
+        }
+    }
+
+    fn expect_columns(&mut self) -> Result<Vec<ProjectedColumn>, ParseError> {
+        let mut columns = vec![self.expect_projected_column()?];
         while self.eat_if(|token| token.is_comma()) {
-            let column = self.expect_identifier()?;
-            columns.push(column);
+            columns.push(self.expect_projected_column()?);
         }
         Ok(columns)
     }
 
+    fn expect_projected_column(&mut self) -> Result<ProjectedColumn, ParseError> {
+        let operand = self.expect_operand()?;
+        let alias = self.maybe_alias()?;
+        match (operand, alias) {
+            (Literal::ColumnReference(column), Some(alias)) => {
+                Ok(ProjectedColumn::aliased(column, alias))
+            }
+            (Literal::ColumnReference(column), None) => Ok(ProjectedColumn::new(column)),
+            // Like an aggregate (e.g., `count(distinct city)`), a computed column without an
+            // alias is named by its text (e.g., `upper(name)`, `salary + 1` or `1`).
+            (
+                expression @ (Literal::Arithmetic { .. }
+                | Literal::FunctionCall { .. }
+                | Literal::Int(_)
+                | Literal::Text(_)
+                | Literal::Bool(_)
+                | Literal::Date(_)),
+                alias,
+            ) => {
+                let alias = alias.unwrap_or_else(|| expression.to_string());
+                Ok(ProjectedColumn::computed(expression, alias))
+            }
+            (literal, _) => Err(ParseError::UnexpectedToken {
+                expected: "column or expression".to_string(),
+                found: literal.to_string(),
+            }),
+        }
+    }
+
     fn expect_table_source(&mut self) -> Result<ast::TableSource, ParseError> {
         let left_table = self.expect_identifier()?;
-        let left_alias = self.maybe_alias()?;
+        let left_alias = self.maybe_table_alias()?;
         let mut source = if let Some(alias_name) = left_alias {
             ast::TableSource::table_with_alias(&left_table, &alias_name)
         } else {
             ast::TableSource::table(&left_table)
         };
 
-        while self.eat_if(|token| token.is_keyword("join")) {
+        while let Some(join_type) = self.maybe_join()? {
             let right_table = self.expect_identifier()?;
-            let right_alias = self.maybe_alias()?;
+            let right_alias = self.maybe_table_alias()?;
             let mut on = None;
 
             if self.eat_if(|token| token.is_keyword("on")) {
+                if join_type == JoinType::Cross {
+                    return Err(ParseError::OnClauseInCrossJoin);
+                }
                 let expression = self.expect_expression()?;
                 on = Some(expression);
             }
@@ -174,11 +656,40 @@ impl Parser {
                 left: Box::new(source),
                 right: Box::new(right_source),
                 on,
+                join_type,
             };
         }
         Ok(source)
     }
 
+    fn maybe_join(&mut self) -> Result<Option<JoinType>, ParseError> {
+        if self.eat_if(|token| token.is_comma()) {
+            return Ok(Some(JoinType::Cross));
+        }
+        if self.eat_if(|token| token.is_keyword("join")) {
+            return Ok(Some(JoinType::Inner));
+        }
+        if self.eat_if(|token| token.is_keyword("inner")) {
+            self.expect_keyword("join")?;
+            return Ok(Some(JoinType::Inner));
+        }
+        let join_type = if self.eat_if(|token| token.is_keyword("left")) {
+            JoinType::Left
+        } else if self.eat_if(|token| token.is_keyword("right")) {
+            JoinType::Right
+        } else if self.eat_if(|token| token.is_keyword("full")) {
+            JoinType::FullOuter
+        } else if self.eat_if(|token| token.is_keyword("cross")) {
+            self.expect_keyword("join")?;
+            return Ok(Some(JoinType::Cross));
+        } else {
+            return Ok(None);
+        };
+        self.eat_if(|token| token.is_keyword("outer"));
+        self.expect_keyword("join")?;
+        Ok(Some(join_type))
+    }
+
     fn maybe_alias(&mut self) -> Result<Option<String>, ParseError> {
         if self.eat_if(|token| token.is_keyword("as")) {
             return Ok(Some(self.expect_identifier()?));
@@ -186,6 +697,25 @@ impl Parser {
         Ok(None)
     }
 
+    /// Parses the alias of a table, given either with `as` (`employees as e`) or directly after
+    /// the table name (`employees e`).
+    ///
+    /// Reserved words (e.g., `join`, `on`, `where`, `order`) are lexed as keywords, not
+    /// identifiers, so they are never taken as an implicit alias.
+    fn maybe_table_alias(&mut self) -> Result<Option<String>, ParseError> {
+        if let Some(alias) = self.maybe_alias()? {
+            return Ok(Some(alias));
+        }
+        if self
+            .cursor
+            .peek()
+            .is_some_and(|token| token.is_identifier())
+        {
+            return Ok(Some(self.expect_identifier()?));
+        }
+        Ok(None)
+    }
+
     fn maybe_where_clause(&mut self) -> Result<Option<WhereClause>, ParseError> {
         let is_where_clause = self.eat_if(|token| token.is_keyword("where"));
         if is_where_clause {
@@ -231,6 +761,13 @@ impl Parser {
     }
 
     fn expect_primary_expression(&mut self) -> Result<Expression, ParseError> {
+        if self.eat_if(|token| token.is_keyword("not")) {
+            // `not` binds tighter than `and`/`or`: `not a and b` is `(not a) and b`.
+            return Ok(Expression::not(self.expect_primary_expression()?));
+        }
+        if self.eat_if(|token| token.is_keyword("exists")) {
+            return Ok(Expression::single(self.expect_exists()?));
+        }
         if self.eat_if(|token| token.is_left_parentheses()) {
             let expr = self.expect_expression()?;
             if !self.eat_if(|token| token.is_right_parentheses()) {
@@ -250,63 +787,301 @@ impl Parser {
     }
 
     fn expect_clause(&mut self) -> Result<Clause, ParseError> {
-        let lhs = self.expect_literal()?;
+        let lhs = self.expect_operand()?;
+        if self.eat_if(|token| token.is_keyword("between")) {
+            return self.expect_between(lhs);
+        }
+        if self.eat_if(|token| token.is_keyword("in")) {
+            return self.expect_in(lhs, false);
+        }
+        if self.eat_if(|token| token.is_keyword("is")) {
+            return self.expect_distinct_from(lhs);
+        }
+        if self.eat_if(|token| token.is_keyword("not")) {
+            if self.eat_if(|token| token.is_keyword("in")) {
+                return self.expect_in(lhs, true);
+            }
+            let case_insensitive = self.eat_if(|token| token.is_keyword("ilike"));
+            if !case_insensitive {
+                self.expect_keyword("like")?;
+            }
+            return self.expect_like(lhs, true, case_insensitive);
+        }
         let operator = self.expect_operator()?;
 
         match operator {
-            BinaryOperator::Like => {
-                if let Literal::ColumnReference(column_name) = lhs {
-                    let rhs = self.expect_literal()?;
-                    Ok(Clause::like(&column_name, rhs))
-                } else {
-                    Err(ParseError::UnexpectedToken {
-                        expected: "column name".to_string(),
-                        found: format!("{:?}", lhs),
-                    })
-                }
-            }
+            BinaryOperator::Like => self.expect_like(lhs, false, false),
+            BinaryOperator::ILike => self.expect_like(lhs, false, true),
             _ => {
-                let rhs = self.expect_literal()?;
+                let rhs = self.expect_operand()?;
                 Ok(Clause::comparison(lhs, operator, rhs))
             }
         }
     }
 
-    fn expect_operator(&mut self) -> Result<BinaryOperator, ParseError> {
-        match self.cursor.next() {
-            Some(token) => BinaryOperator::from_token(token),
-            None => Err(ParseError::UnexpectedEndOfInput),
-        }
+    /// Parses the null-safe comparison following `IS`, which is `IS DISTINCT FROM`, or
+    /// `IS NOT DISTINCT FROM` when negated.
+    fn expect_distinct_from(&mut self, lhs: Literal) -> Result<Clause, ParseError> {
+        let negated = self.eat_if(|token| token.is_keyword("not"));
+        self.expect_keyword("distinct")?;
+        self.expect_keyword("from")?;
+        let rhs = self.expect_operand()?;
+
+        let operator = if negated {
+            BinaryOperator::IsNotDistinctFrom
+        } else {
+            BinaryOperator::IsDistinctFrom
+        };
+        Ok(Clause::comparison(lhs, operator, rhs))
     }
 
-    fn expect_literal(&mut self) -> Result<Literal, ParseError> {
-        match self.cursor.next() {
-            Some(token) => Literal::from_token(token),
-            None => Err(ParseError::UnexpectedEndOfInput),
+    /// Parses an operand of a comparison, which is a literal or an arithmetic expression over
+    /// literals (e.g., `salary + bonus * 2`).
+    ///
+    /// `*` and `/` bind tighter than `+` and `-`, operators of the same precedence are
+    /// left-associative, and parentheses can be used for grouping after an operator
+    /// (e.g., `salary * (1 + raise)`).
+    fn expect_operand(&mut self) -> Result<Literal, ParseError> {
+        let mut lhs = self.expect_term()?;
+        while let Some(operator) = self.maybe_arithmetic_operator(|token_type| {
+            matches!(token_type, TokenType::Plus | TokenType::Minus)
+        }) {
+            let rhs = self.expect_term()?;
+            lhs = Literal::arithmetic(lhs, operator, rhs);
         }
+        Ok(lhs)
     }
 
-    fn maybe_order_by(&mut self) -> Result<Option<Vec<OrderingKey>>, ParseError> {
-        let is_order = self.eat_if(|token| token.is_keyword("order"));
-        if is_order {
-            let mut ordering_keys = Vec::new();
-            self.expect_keyword("by")?;
-
-            let ordering_key = self.expect_ordering_key()?;
-            ordering_keys.push(ordering_key);
-
-            while self.eat_if(|token| token.is_comma()) {
-                let ordering_key = self.expect_ordering_key()?;
-                ordering_keys.push(ordering_key);
-            }
-            return Ok(Some(ordering_keys));
+    fn expect_term(&mut self) -> Result<Literal, ParseError> {
+        let mut lhs = self.expect_factor()?;
+        while let Some(operator) = self.maybe_arithmetic_operator(|token_type| {
+            matches!(token_type, TokenType::Star | TokenType::Slash)
+        }) {
+            let rhs = self.expect_factor()?;
+            lhs = Literal::arithmetic(lhs, operator, rhs);
         }
-        Ok(None)
+        Ok(lhs)
     }
 
-    fn expect_ordering_key(&mut self) -> Result<OrderingKey, ParseError> {
-        let column_name = self.expect_identifier()?;
-        Ok(OrderingKey::new(column_name, self.ordering_direction()))
+    fn expect_factor(&mut self) -> Result<Literal, ParseError> {
+        if self.eat_if(|token| token.is_left_parentheses()) {
+            if self
+                .cursor
+                .peek()
+                .is_some_and(|token| token.is_keyword("select"))
+            {
+                let subquery = self.parse_query()?;
+                self.expect_right_parentheses()?;
+                return Ok(Literal::Subquery(Box::new(subquery)));
+            }
+            let operand = self.expect_operand()?;
+            self.expect_right_parentheses()?;
+            return Ok(operand);
+        }
+        let literal = self.expect_literal()?;
+        match literal {
+            Literal::ColumnReference(name) if self.eat_if(|token| token.is_left_parentheses()) => {
+                self.expect_function_call(&name)
+            }
+            _ => Ok(literal),
+        }
+    }
+
+    fn expect_function_call(&mut self, name: &str) -> Result<Literal, ParseError> {
+        let function = ScalarFunction::from_name(name)
+            .ok_or_else(|| ParseError::UnknownFunction(name.to_string()))?;
+
+        let mut arguments = vec![self.expect_operand()?];
+        while self.eat_if(|token| token.is_comma()) {
+            arguments.push(self.expect_operand()?);
+        }
+        self.expect_right_parentheses()?;
+
+        if !function.accepts_argument_count(arguments.len()) {
+            return Err(ParseError::FunctionArgumentCountMismatch {
+                function: function.to_string(),
+                actual: arguments.len(),
+            });
+        }
+        Ok(Literal::function_call(function, arguments))
+    }
+
+    fn maybe_arithmetic_operator<F: Fn(TokenType) -> bool>(
+        &mut self,
+        accepts: F,
+    ) -> Option<ArithmeticOperator> {
+        let operator = match self.cursor.peek()?.token_type() {
+            token_type if !accepts(token_type) => return None,
+            TokenType::Plus => ArithmeticOperator::Add,
+            TokenType::Minus => ArithmeticOperator::Sub,
+            TokenType::Star => ArithmeticOperator::Mul,
+            TokenType::Slash => ArithmeticOperator::Div,
+            _ => return None,
+        };
+        self.cursor.next();
+        Some(operator)
+    }
+
+    /// Parses the pattern of a `LIKE` clause, which is `NOT LIKE` when negated and `ILIKE`
+    /// when case-insensitive.
+    fn expect_like(
+        &mut self,
+        lhs: Literal,
+        negated: bool,
+        case_insensitive: bool,
+    ) -> Result<Clause, ParseError> {
+        if let Literal::ColumnReference(column_name) = lhs {
+            let literal = self.expect_literal()?;
+            Ok(Clause::Like {
+                column_name,
+                literal,
+                negated,
+                case_insensitive,
+            })
+        } else {
+            Err(ParseError::UnexpectedToken {
+                expected: "column name".to_string(),
+                found: format!("{:?}", lhs),
+            })
+        }
+    }
+
+    /// Parses the bounds of a `BETWEEN` clause.
+    ///
+    /// The `and` separating the bounds is consumed here, so it is never
+    /// treated as a conjunction by `expect_and_expression`.
+    fn expect_between(&mut self, lhs: Literal) -> Result<Clause, ParseError> {
+        if let Literal::ColumnReference(column_name) = lhs {
+            let low = self.expect_literal()?;
+            self.expect_keyword("and")?;
+            let high = self.expect_literal()?;
+            Ok(Clause::between(&column_name, low, high))
+        } else {
+            Err(ParseError::UnexpectedToken {
+                expected: "column name".to_string(),
+                found: format!("{:?}", lhs),
+            })
+        }
+    }
+
+    /// Parses the parenthesized subquery of an `IN` clause, which is `NOT IN` when negated.
+    fn expect_in(&mut self, lhs: Literal, negated: bool) -> Result<Clause, ParseError> {
+        self.expect_left_parentheses()?;
+        let subquery = self.parse_query()?;
+        self.expect_right_parentheses()?;
+        Ok(Clause::in_subquery(lhs, subquery, negated))
+    }
+
+    fn expect_exists(&mut self) -> Result<Clause, ParseError> {
+        self.expect_left_parentheses()?;
+        let subquery = self.parse_query()?;
+        self.expect_right_parentheses()?;
+        Ok(Clause::exists(subquery))
+    }
+
+    fn expect_operator(&mut self) -> Result<BinaryOperator, ParseError> {
+        match self.cursor.next() {
+            Some(token) => BinaryOperator::from_token(token),
+            None => Err(ParseError::UnexpectedEndOfInput),
+        }
+    }
+
+    fn expect_literal(&mut self) -> Result<Literal, ParseError> {
+        if let Some(parameter) = self.maybe_parameter() {
+            return Ok(parameter);
+        }
+        if let Some(date) = self.maybe_date_literal()? {
+            return Ok(date);
+        }
+        match self.cursor.next() {
+            Some(token) => Literal::from_token(token),
+            None => Err(ParseError::UnexpectedEndOfInput),
+        }
+    }
+
+    /// Parses a placeholder `?` into a parameter, if the next token is one.
+    ///
+    /// Parameters are numbered from 0, in the order their placeholders appear in the query.
+    fn maybe_parameter(&mut self) -> Option<Literal> {
+        if !self.eat_if(|token| token.is_placeholder()) {
+            return None;
+        }
+        let parameter = Literal::Parameter(self.parameter_count);
+        self.parameter_count += 1;
+        Some(parameter)
+    }
+
+    /// Parses a date literal, e.g., `date '2024-01-15'`, if the next tokens are one.
+    ///
+    /// `date` is not a keyword (so it can still name a column): it starts a date literal only
+    /// when it is followed by a string literal.
+    fn maybe_date_literal(&mut self) -> Result<Option<Literal>, ParseError> {
+        let is_date_literal = self.cursor.peek().is_some_and(|token| {
+            token.is_identifier() && token.lexeme().eq_ignore_ascii_case("date")
+        }) && self
+            .cursor
+            .peek_second()
+            .is_some_and(|token| token.is_string_literal());
+        if !is_date_literal {
+            return Ok(None);
+        }
+        self.cursor.next();
+        //SAFETY: the token after `date` is a string literal.
+        let text = self.cursor.next().unwrap().lexeme().to_string();
+        match Date::parse(&text) {
+            Some(date) => Ok(Some(Literal::Date(date))),
+            None => Err(ParseError::InvalidDate(text)),
+        }
+    }
+
+    fn maybe_order_by(&mut self) -> Result<Option<Vec<OrderingKey>>, ParseError> {
+        let is_order = self.eat_if(|token| token.is_keyword("order"));
+        if is_order {
+            let mut ordering_keys = Vec::new();
+            self.expect_keyword("by")?;
+
+            let ordering_key = self.expect_ordering_key()?;
+            ordering_keys.push(ordering_key);
+
+            while self.eat_if(|token| token.is_comma()) {
+                let ordering_key = self.expect_ordering_key()?;
+                ordering_keys.push(ordering_key);
+            }
+            return Ok(Some(ordering_keys));
+        }
+        Ok(None)
+    }
+
+    fn expect_ordering_key(&mut self) -> Result<OrderingKey, ParseError> {
+        if let Some(token) = self.cursor.peek() {
+            if token.is_a_whole_number() {
+                let lexeme = token.lexeme().to_string();
+                self.cursor.next();
+                let ordinal = lexeme
+                    .parse::<usize>()
+                    .map_err(|_| ParseError::NumericLiteralOutOfRange(lexeme))?;
+                let key = OrderingKey::by_ordinal(ordinal, self.ordering_direction());
+                return self.maybe_nulls_ordering(key);
+            }
+        }
+        let column_name = self.expect_identifier()?;
+        let key = OrderingKey::new(column_name, self.ordering_direction());
+        self.maybe_nulls_ordering(key)
+    }
+
+    fn maybe_nulls_ordering(&mut self, key: OrderingKey) -> Result<OrderingKey, ParseError> {
+        if !self.eat_if(|token| token.is_keyword("nulls")) {
+            return Ok(key);
+        }
+        match self.cursor.next() {
+            Some(token) if token.is_keyword("first") => Ok(key.with_nulls(NullsOrdering::First)),
+            Some(token) if token.is_keyword("last") => Ok(key.with_nulls(NullsOrdering::Last)),
+            Some(token) => Err(ParseError::UnexpectedToken {
+                expected: "first | last".to_string(),
+                found: token.lexeme().to_string(),
+            }),
+            None => Err(ParseError::UnexpectedEndOfInput),
+        }
     }
 
     fn ordering_direction(&mut self) -> OrderingDirection {
@@ -315,7 +1090,7 @@ impl Parser {
         } else if self.eat_if(|token| token.is_keyword("desc")) {
             OrderingDirection::Descending
         } else {
-            OrderingDirection::Ascending
+            self.default_ordering_direction.clone()
         }
     }
 
@@ -416,7 +1191,7 @@ mod show_tables_tests {
         let result = parser.parse();
 
         assert!(
-            matches!(result, Err(ParseError::UnsupportedToken {expected, found}) if expected == "show | describe | select" && found == "unsupported")
+            matches!(result, Err(ParseError::UnsupportedToken {expected, found}) if expected == "show | describe | select | explain | insert | values | delete | update | create | drop" && found == "unsupported")
         );
     }
 
@@ -821,7 +1596,7 @@ mod select_projection_tests {
         let ast = parser.parse().unwrap();
 
         assert!(matches!(ast, Ast::Select { source, projection, .. }
-                if source == ast::TableSource::table("employees") && projection == Projection::Columns(vec!["name".to_string(), "id".to_string()])));
+                if source == ast::TableSource::table("employees") && projection == Projection::Columns(vec![ProjectedColumn::new("name"), ProjectedColumn::new("id")])));
     }
 
     #[test]
@@ -837,7 +1612,7 @@ mod select_projection_tests {
         let ast = parser.parse().unwrap();
 
         assert!(matches!(ast, Ast::Select { source, projection, .. }
-                if source == ast::TableSource::table("employees") && projection == Projection::Columns(vec!["name".to_string()])));
+                if source == ast::TableSource::table("employees") && projection == Projection::Columns(vec![ProjectedColumn::new("name")])));
     }
 
     #[test]
@@ -856,7 +1631,83 @@ mod select_projection_tests {
         let ast = parser.parse().unwrap();
 
         assert!(matches!(ast, Ast::Select { source, projection, .. }
-                if source == ast::TableSource::table("employees") && projection == Projection::Columns(vec!["name".to_string(), "id".to_string()])));
+                if source == ast::TableSource::table("employees") && projection == Projection::Columns(vec![ProjectedColumn::new("name"), ProjectedColumn::new("id")])));
+    }
+
+    #[test]
+    fn parse_select_projection_with_aliases() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("employee_id", TokenType::Identifier));
+        stream.add(Token::new(",", TokenType::Comma));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Select { source, projection, .. }
+                if source == ast::TableSource::table("employees")
+                && projection == Projection::Columns(vec![ProjectedColumn::aliased("id", "employee_id"), ProjectedColumn::new("name")])));
+    }
+
+    #[test]
+    fn parse_select_qualified_star() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("employees.", TokenType::Identifier));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Select { source, projection, .. }
+                if source == ast::TableSource::table("employees")
+                && projection == Projection::QualifiedAll("employees".to_string())));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_qualified_star_with_missing_star() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("employees.", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken{expected, found}) if expected == "*" && found == "from")
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_select_projection_with_missing_alias() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found })
+            if expected == "identifier" && found == "from"
+        ));
     }
 
     #[test]
@@ -1025,57 +1876,75 @@ mod select_where_with_single_comparison_tests {
     }
 
     #[test]
-    fn parse_select_with_where_single_comparison_and_semicolon() {
+    fn parse_select_with_where_comparison_against_negative_number() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::new("where", TokenType::Keyword));
-        stream.add(Token::new("name", TokenType::Identifier));
-        stream.add(Token::new("like", TokenType::Keyword));
-        stream.add(Token::new("rel%", TokenType::StringLiteral));
-        stream.add(Token::semicolon());
+        stream.add(Token::new("balance", TokenType::Identifier));
+        stream.add(Token::new(">", TokenType::Greater));
+        stream.add(Token::new("-100", TokenType::WholeNumber));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
         let ast = parser.parse().unwrap();
 
-        assert!(
-            matches!(ast, Ast::Select { source, projection, where_clause, .. }
-                if source == ast::TableSource::table("employees") &&
-                    projection == Projection::All &&
-                    matches!(&where_clause, Some(ref wc)
-                         if *wc == WhereClause::like(
-                             "name",
-                             Literal::Text("rel%".to_string())
-                         )
-                    )
-            )
-        );
+        assert!(matches!(ast, Ast::Select { where_clause, .. }
+            if where_clause == Some(WhereClause::comparison(
+                Literal::ColumnReference("balance".to_string()),
+                BinaryOperator::Greater,
+                Literal::Int(-100)
+            ))
+        ));
     }
 
     #[test]
-    fn attempt_to_parse_with_no_tokens() {
-        let stream = TokenStream::new();
+    fn position_of_a_parse_error() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword).at(1, 1));
+        stream.add(Token::new("*", TokenType::Star).at(1, 8));
+        stream.add(Token::new("form", TokenType::Identifier).at(2, 1));
+        stream.add(Token::new("employees", TokenType::Identifier).at(2, 6));
+        stream.add(Token::end_of_stream().at(2, 15));
 
         let mut parser = Parser::new(stream);
         let result = parser.parse();
 
-        assert!(matches!(result, Err(ParseError::NoTokens)));
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "from" && found == "form"
+        ));
+        assert_eq!((2, 1), parser.position());
     }
 
     #[test]
-    fn attempt_to_parse_select_with_where_but_missing_identifier_after_where() {
+    fn position_of_a_parse_error_at_the_end_of_input() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword).at(1, 1));
+        stream.add(Token::new("*", TokenType::Star).at(1, 8));
+        stream.add(Token::new("from", TokenType::Keyword).at(1, 10));
+        stream.add(Token::end_of_stream().at(1, 14));
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(result.is_err());
+        assert_eq!((1, 14), parser.position());
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_where_but_negative_literal_out_of_range() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::new("where", TokenType::Keyword));
-        stream.add(Token::new("=", TokenType::Equal));
-        stream.add(Token::new("relop", TokenType::StringLiteral));
-        stream.add(Token::semicolon());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new(">", TokenType::Greater));
+        stream.add(Token::new("-999999999999999999999", TokenType::WholeNumber));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
@@ -1083,29 +1952,42 @@ mod select_where_with_single_comparison_tests {
 
         assert!(matches!(
             result,
-            Err(ParseError::UnexpectedToken {
-                expected,
-                found,
-            }) if expected == "identifier" && found == "=" ));
+            Err(ParseError::NumericLiteralOutOfRange(value)) if value == "-999999999999999999999" ));
     }
 
     #[test]
-    fn attempt_to_parse_select_with_where_but_no_tokens_after_where() {
+    fn parse_select_with_where_single_comparison_and_semicolon() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("like", TokenType::Keyword));
+        stream.add(Token::new("rel%", TokenType::StringLiteral));
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let result = parser.parse();
+        let ast = parser.parse().unwrap();
 
-        assert!(matches!(result, Err(ParseError::UnexpectedEndOfInput)));
+        assert!(
+            matches!(ast, Ast::Select { source, projection, where_clause, .. }
+                if source == ast::TableSource::table("employees") &&
+                    projection == Projection::All &&
+                    matches!(&where_clause, Some(ref wc)
+                         if *wc == WhereClause::like(
+                             "name",
+                             Literal::Text("rel%".to_string())
+                         )
+                    )
+            )
+        );
     }
 
     #[test]
-    fn attempt_to_parse_select_with_where_but_missing_operator() {
+    fn parse_select_with_where_not_like() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
@@ -1113,23 +1995,24 @@ mod select_where_with_single_comparison_tests {
         stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::new("where", TokenType::Keyword));
         stream.add(Token::new("name", TokenType::Identifier));
-        stream.add(Token::new("relop", TokenType::StringLiteral));
-        stream.add(Token::semicolon());
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("like", TokenType::Keyword));
+        stream.add(Token::new("rel%", TokenType::StringLiteral));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let result = parser.parse();
+        let ast = parser.parse().unwrap();
 
-        assert!(matches!(
-            result,
-            Err(ParseError::UnexpectedToken {
-                expected,
-                found,
-            }) if expected == "operator" && found == "relop" ));
+        assert!(matches!(ast, Ast::Select { where_clause, .. }
+            if where_clause == Some(WhereClause::not_like(
+                "name",
+                Literal::Text("rel%".to_string())
+            ))
+        ));
     }
 
     #[test]
-    fn attempt_to_parse_select_with_where_but_no_tokens_after_where_column_name() {
+    fn parse_select_with_where_ilike() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
@@ -1137,32 +2020,181 @@ mod select_where_with_single_comparison_tests {
         stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::new("where", TokenType::Keyword));
         stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("ilike", TokenType::Keyword));
+        stream.add(Token::new("^REL.*", TokenType::StringLiteral));
+        stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let result = parser.parse();
+        let ast = parser.parse().unwrap();
 
-        assert!(matches!(result, Err(ParseError::UnexpectedEndOfInput)));
+        assert!(matches!(ast, Ast::Select { where_clause, .. }
+            if where_clause == Some(WhereClause::ilike(
+                "name",
+                Literal::Text("^REL.*".to_string())
+            ))
+        ));
     }
 
     #[test]
-    fn attempt_to_parse_select_with_where_but_missing_literal() {
+    fn parse_select_with_where_not_ilike() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::new("where", TokenType::Keyword));
-        stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::new(">", TokenType::Greater));
-        stream.add(Token::new("select", TokenType::Keyword));
-        stream.add(Token::semicolon());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("ilike", TokenType::Keyword));
+        stream.add(Token::new("^REL.*", TokenType::StringLiteral));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let result = parser.parse();
+        let ast = parser.parse().unwrap();
 
-        assert!(matches!(
-            result,
+        assert!(
+            matches!(ast, Ast::Select { where_clause: Some(WhereClause(Expression::Single(clause))), .. }
+                if clause == Clause::Like {
+                    column_name: "name".to_string(),
+                    literal: Literal::Text("^REL.*".to_string()),
+                    negated: true,
+                    case_insensitive: true,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_where_with_not_followed_by_an_operator() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "like" && found == "="
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_with_no_tokens() {
+        let stream = TokenStream::new();
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(result, Err(ParseError::NoTokens)));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_where_but_missing_identifier_after_where() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken {
+                expected,
+                found,
+            }) if expected == "identifier" && found == "=" ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_where_but_no_tokens_after_where() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(result, Err(ParseError::UnexpectedEndOfInput)));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_where_but_missing_operator() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken {
+                expected,
+                found,
+            }) if expected == "operator" && found == "relop" ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_where_but_no_tokens_after_where_column_name() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(result, Err(ParseError::UnexpectedEndOfInput)));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_where_but_missing_literal() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new(">", TokenType::Greater));
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
             Err(ParseError::UnexpectedToken {
                 expected,
                 found,
@@ -1295,6 +2327,141 @@ mod select_where_with_and_tests {
         );
     }
 
+    #[test]
+    fn parse_select_with_where_with_between_and_comparison() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("between", TokenType::Keyword));
+        stream.add(Token::new("10", TokenType::WholeNumber));
+        stream.add(Token::new("and", TokenType::Keyword));
+        stream.add(Token::new("20", TokenType::WholeNumber));
+        stream.add(Token::new("and", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Select { where_clause, .. }
+            if matches!(&where_clause, Some(WhereClause(Expression::And(expressions)))
+                if expressions.len() == 2 &&
+                expressions[0] == Expression::single(Clause::between(
+                    "id",
+                    Literal::Int(10),
+                    Literal::Int(20)
+                )) &&
+                expressions[1] == Expression::single(Clause::comparison(
+                    Literal::ColumnReference("name".to_string()),
+                    BinaryOperator::Eq,
+                    Literal::Text("relop".to_string())
+                ))
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_where_with_between_dates() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("joined_on", TokenType::Identifier));
+        stream.add(Token::new("between", TokenType::Keyword));
+        stream.add(Token::new("DATE", TokenType::Identifier));
+        stream.add(Token::new("2024-01-01", TokenType::StringLiteral));
+        stream.add(Token::new("and", TokenType::Keyword));
+        stream.add(Token::new("date", TokenType::Identifier));
+        stream.add(Token::new("2024-12-31", TokenType::StringLiteral));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Select { where_clause, .. }
+            if where_clause == Some(WhereClause(Expression::single(Clause::between(
+                "joined_on",
+                Literal::Date(Date::new(2024, 1, 1).unwrap()),
+                Literal::Date(Date::new(2024, 12, 31).unwrap())
+            ))))
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_where_comparing_a_column_named_date() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("date", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("2024-01-15", TokenType::StringLiteral));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Select { where_clause, .. }
+            if where_clause == Some(WhereClause(Expression::single(Clause::comparison(
+                Literal::ColumnReference("date".to_string()),
+                BinaryOperator::Eq,
+                Literal::Text("2024-01-15".to_string())
+            ))))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_an_invalid_date_literal() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("joined_on", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("date", TokenType::Identifier));
+        stream.add(Token::new("2024-02-30", TokenType::StringLiteral));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(result, Err(ParseError::InvalidDate(date)) if date == "2024-02-30"));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_where_with_between_missing_and() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("between", TokenType::Keyword));
+        stream.add(Token::new("10", TokenType::WholeNumber));
+        stream.add(Token::new("20", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken { expected, found }) if expected == "and" && found == "20")
+        );
+    }
+
     #[test]
     fn attempt_to_parse_select_with_where_with_invalid_like() {
         let mut stream = TokenStream::new();
@@ -1626,7 +2793,7 @@ mod select_order_by_tests {
         assert!(
             matches!(ast, Ast::Select { source, projection, order_by, .. }
                     if source == ast::TableSource::table("employees")
-                        && projection == Projection::Columns(vec!["id".to_string()])
+                        && projection == Projection::Columns(vec![ProjectedColumn::new("id")])
                         && order_by == Some(vec![asc!("id")])
             )
         )
@@ -1651,65 +2818,147 @@ mod select_order_by_tests {
         assert!(
             matches!(ast, Ast::Select { source, projection, order_by, .. }
                     if source == ast::TableSource::table("employees")
-                        && projection == Projection::Columns(vec!["id".to_string()])
+                        && projection == Projection::Columns(vec![ProjectedColumn::new("id")])
                         && order_by == Some(vec![desc!("id")])
             )
         )
     }
 
     #[test]
-    fn parse_select_with_order_by_ascending_with_semicolon() {
+    fn parse_select_with_order_by_ordinal() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::comma());
         stream.add(Token::new("id", TokenType::Identifier));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::new("order", TokenType::Keyword));
         stream.add(Token::new("by", TokenType::Keyword));
-        stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::new("asc", TokenType::Keyword));
-        stream.add(Token::semicolon());
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::new("desc", TokenType::Keyword));
+        stream.add(Token::comma());
+        stream.add(Token::new("name", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
         let ast = parser.parse().unwrap();
 
-        assert!(
-            matches!(ast, Ast::Select { source, projection, order_by, .. }
-                    if source == ast::TableSource::table("employees")
-                        && projection == Projection::Columns(vec!["id".to_string()])
-                        && order_by == Some(vec![asc!("id")])
-            )
-        )
+        assert!(matches!(ast, Ast::Select { order_by, .. }
+                if order_by == Some(vec![
+                    OrderingKey::by_ordinal(2, OrderingDirection::Descending),
+                    asc!("name")
+                ])
+        ))
     }
 
     #[test]
-    fn attempt_to_parse_invalid_select_with_missing_comma_between_order_by_columns() {
+    fn parse_select_with_order_by_nulls_ordering() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
-        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("id", TokenType::Identifier));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::new("order", TokenType::Keyword));
         stream.add(Token::new("by", TokenType::Keyword));
         stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("nulls", TokenType::Keyword));
+        stream.add(Token::new("first", TokenType::Keyword));
+        stream.add(Token::comma());
         stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("desc", TokenType::Keyword));
+        stream.add(Token::new("nulls", TokenType::Keyword));
+        stream.add(Token::new("last", TokenType::Keyword));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let result = parser.parse();
+        let ast = parser.parse().unwrap();
 
-        assert!(
-            matches!(result, Err(ParseError::UnexpectedToken{expected, found}) if expected == "end of stream" && found == "name" )
-        );
+        assert!(matches!(ast, Ast::Select { order_by, .. }
+                if order_by == Some(vec![
+                    asc!("id").with_nulls(NullsOrdering::First),
+                    desc!("name").with_nulls(NullsOrdering::Last)
+                ])
+        ))
     }
 
     #[test]
-    fn attempt_to_parse_with_no_tokens() {
-        let stream = TokenStream::new();
-
-        let mut parser = Parser::new(stream);
-        let result = parser.parse();
+    fn attempt_to_parse_select_with_order_by_nulls_without_placement() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("nulls", TokenType::Keyword));
+        stream.add(Token::new("limit", TokenType::Keyword));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found })
+                if expected == "first | last" && found == "limit"
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_order_by_ascending_with_semicolon() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("asc", TokenType::Keyword));
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(
+            matches!(ast, Ast::Select { source, projection, order_by, .. }
+                    if source == ast::TableSource::table("employees")
+                        && projection == Projection::Columns(vec![ProjectedColumn::new("id")])
+                        && order_by == Some(vec![asc!("id")])
+            )
+        )
+    }
+
+    #[test]
+    fn attempt_to_parse_invalid_select_with_missing_comma_between_order_by_columns() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken{expected, found}) if expected == "end of stream" && found == "name" )
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_with_no_tokens() {
+        let stream = TokenStream::new();
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
 
         assert!(matches!(result, Err(ParseError::NoTokens)));
     }
@@ -1732,6 +2981,52 @@ mod select_order_by_tests {
             matches!(result, Err(ParseError::UnexpectedToken{expected, found}) if expected == "by" && found == "id" )
         );
     }
+
+    #[test]
+    fn parse_select_with_order_by_in_the_default_ordering_direction() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser =
+            Parser::new(stream).with_default_ordering_direction(OrderingDirection::Descending);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Select { order_by, .. }
+                if order_by == Some(vec![
+                    desc!("id"),
+                    OrderingKey::by_ordinal(2, OrderingDirection::Descending)
+                ])
+        ))
+    }
+
+    #[test]
+    fn parse_select_with_order_by_overriding_the_default_ordering_direction() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("asc", TokenType::Keyword));
+        stream.add(Token::end_of_stream());
+
+        let mut parser =
+            Parser::new(stream).with_default_ordering_direction(OrderingDirection::Descending);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Select { order_by, .. } if order_by == Some(vec![asc!("id")])));
+    }
 }
 
 #[cfg(test)]
@@ -1756,9 +3051,9 @@ mod select_tests_with_limit {
         let ast = parser.parse().unwrap();
 
         assert!(matches!(ast,
-            Ast::Select { source, projection, where_clause: _, order_by: _, limit }
+            Ast::Select { source, projection, distinct_on: _, where_clause: _, order_by: _, limit }
                 if source == ast::TableSource::table("employees")
-                    && projection == Projection::Columns(vec!["name".to_string(), "id".to_string()])
+                    && projection == Projection::Columns(vec![ProjectedColumn::new("name"), ProjectedColumn::new("id")])
                     && limit == Some(10)
         ));
     }
@@ -1781,9 +3076,9 @@ mod select_tests_with_limit {
         let ast = parser.parse().unwrap();
 
         assert!(matches!(ast,
-            Ast::Select { source, projection, where_clause: _, order_by: _, limit }
+            Ast::Select { source, projection, distinct_on: _, where_clause: _, order_by: _, limit }
                 if source == ast::TableSource::table("employees")
-                    && projection == Projection::Columns(vec!["name".to_string(), "id".to_string()])
+                    && projection == Projection::Columns(vec![ProjectedColumn::new("name"), ProjectedColumn::new("id")])
                     && limit == Some(10)
         ));
     }
@@ -1870,102 +3165,69 @@ mod select_tests_with_limit {
 }
 
 #[cfg(test)]
-mod column_reference_tests {
+mod select_distinct_on_tests {
     use super::*;
     use crate::query::lexer::token::Token;
-    use crate::query::parser::ast::{Ast, BinaryOperator, Clause, Expression, Literal};
+
+    fn select_distinct_on_prefix(stream: &mut TokenStream) {
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("distinct", TokenType::Keyword));
+    }
 
     #[test]
-    fn parse_select_with_column_to_column_comparison() {
+    fn parse_select_with_distinct_on_a_single_column() {
         let mut stream = TokenStream::new();
-        stream.add(Token::new("select", TokenType::Keyword));
+        select_distinct_on_prefix(&mut stream);
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("(", TokenType::LeftParentheses));
+        stream.add(Token::new("city", TokenType::Identifier));
+        stream.add(Token::new(")", TokenType::RightParentheses));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("where", TokenType::Keyword));
-        stream.add(Token::new("first_name", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("last_name", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
         let ast = parser.parse().unwrap();
 
-        assert!(
-            matches!(ast, Ast::Select { ref source, ref where_clause, .. }
-                if matches!(source, ast::TableSource::Table { ref name, .. } if name == "employees")
-                && matches!(where_clause, Some(WhereClause(Expression::Single(Clause::Comparison { ref lhs, ref operator, ref rhs })))
-                    if matches!(lhs, Literal::ColumnReference(ref name) if name == "first_name")
-                    && *operator == BinaryOperator::Eq
-                    && matches!(rhs, Literal::ColumnReference(ref name) if name == "last_name")
-                )
-            )
-        );
+        assert!(matches!(ast,
+            Ast::Select { projection, distinct_on, .. }
+                if projection == Projection::All && distinct_on == Some(vec!["city".to_string()])
+        ));
     }
-}
-
-#[cfg(test)]
-mod select_join_tests {
-    use super::*;
-    use crate::query::lexer::token::Token;
-    use crate::query::parser::ast::{
-        Ast, BinaryOperator, Clause, Expression, Literal, TableSource,
-    };
 
     #[test]
-    fn parse_select_with_join() {
+    fn parse_select_with_distinct_on_multiple_columns() {
         let mut stream = TokenStream::new();
-        stream.add(Token::new("select", TokenType::Keyword));
-        stream.add(Token::new("*", TokenType::Star));
+        select_distinct_on_prefix(&mut stream);
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("(", TokenType::LeftParentheses));
+        stream.add(Token::new("city", TokenType::Identifier));
+        stream.add(Token::new(",", TokenType::Comma));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new(")", TokenType::RightParentheses));
+        stream.add(Token::new("name", TokenType::Identifier));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("join", TokenType::Keyword));
-        stream.add(Token::new("departments", TokenType::Identifier));
-        stream.add(Token::new("on", TokenType::Keyword));
-        stream.add(Token::new("employees.id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("departments.employee_id", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
         let ast = parser.parse().unwrap();
 
-        assert!(matches!(
-            ast,
-            Ast::Select { ref source, .. }
-            if matches!(
-                source,
-                TableSource::Join { left, right, on }
-                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
-                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
-                && matches!(
-                    on,
-                    Some(Expression::Single(Clause::Comparison { lhs, operator, rhs }))
-                    if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employees.id")
-                    && *operator == BinaryOperator::Eq
-                    && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "departments.employee_id")
-                )
-            )
+        assert!(matches!(ast,
+            Ast::Select { projection, distinct_on, .. }
+                if projection == Projection::Columns(vec![ProjectedColumn::new("name")])
+                    && distinct_on == Some(vec!["city".to_string(), "name".to_string()])
         ));
     }
 
     #[test]
-    fn parse_select_with_join_multiple_conditions_in_on() {
+    fn parse_select_without_distinct_on() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("join", TokenType::Keyword));
-        stream.add(Token::new("departments", TokenType::Identifier));
-        stream.add(Token::new("on", TokenType::Keyword));
-        stream.add(Token::new("employee_id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("department_id", TokenType::Identifier));
-        stream.add(Token::new("and", TokenType::Keyword));
-        stream.add(Token::new("status", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("ACTIVE", TokenType::StringLiteral));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
@@ -1973,158 +3235,104 @@ mod select_join_tests {
 
         assert!(matches!(
             ast,
-            Ast::Select { ref source, .. }
-            if matches!(
-                source,
-                TableSource::Join { left, right, on }
-                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
-                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
-                && matches!(
-                    on,
-                    Some(Expression::And(expressions))
-                    if expressions.len() == 2
-                    && matches!(
-                        &expressions[0],
-                        Expression::Single(Clause::Comparison { lhs, operator, rhs })
-                        if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employee_id")
-                        && *operator == BinaryOperator::Eq
-                        && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "department_id")
-                    )
-                    && matches!(
-                        &expressions[1],
-                        Expression::Single(Clause::Comparison { lhs, operator, rhs })
-                        if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "status")
-                        && *operator == BinaryOperator::Eq
-                        && matches!(rhs, Literal::Text(column_name) if column_name == "ACTIVE")
-                    )
-                )
-            )
+            Ast::Select {
+                distinct_on: None,
+                ..
+            }
         ));
     }
 
     #[test]
-    fn parse_select_with_join_but_no_on_clause() {
+    fn attempt_to_parse_distinct_without_on() {
         let mut stream = TokenStream::new();
-        stream.add(Token::new("select", TokenType::Keyword));
-        stream.add(Token::new("*", TokenType::Star));
+        select_distinct_on_prefix(&mut stream);
+        stream.add(Token::new("city", TokenType::Identifier));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("join", TokenType::Keyword));
-        stream.add(Token::new("departments", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let ast = parser.parse().unwrap();
+        let result = parser.parse();
 
         assert!(matches!(
-            ast,
-            Ast::Select { ref source, .. }
-            if matches!(
-                source,
-                TableSource::Join { left, right, on }
-                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
-                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
-                && on.is_none()
-            )
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "on" && found == "city"
         ));
     }
 
     #[test]
-    fn attempt_to_parse_select_with_join_but_missing_right_table() {
+    fn attempt_to_parse_distinct_on_without_columns() {
         let mut stream = TokenStream::new();
-        stream.add(Token::new("select", TokenType::Keyword));
+        select_distinct_on_prefix(&mut stream);
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("(", TokenType::LeftParentheses));
+        stream.add(Token::new(")", TokenType::RightParentheses));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("join", TokenType::Keyword));
-        stream.add(Token::new("on", TokenType::Keyword));
-        stream.add(Token::new("employee_id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("department_id", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
         let result = parser.parse();
 
-        assert!(matches!(
-            result,
-            Err(ParseError::UnexpectedToken { expected, found })
-            if expected == "identifier" && found == "on"
-        ));
+        assert!(matches!(result, Err(ParseError::UnexpectedToken { .. })));
     }
+}
+
+#[cfg(test)]
+mod column_reference_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+    use crate::query::parser::ast::{Ast, BinaryOperator, Clause, Expression, Literal};
 
     #[test]
-    fn parse_select_with_multiple_joins() {
+    fn parse_select_with_column_to_column_comparison() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("join", TokenType::Keyword));
-        stream.add(Token::new("departments", TokenType::Identifier));
-        stream.add(Token::new("on", TokenType::Keyword));
-        stream.add(Token::new("employee_id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("department_id", TokenType::Identifier));
-        stream.add(Token::new("join", TokenType::Keyword));
-        stream.add(Token::new("roles", TokenType::Identifier));
-        stream.add(Token::new("on", TokenType::Keyword));
-        stream.add(Token::new("role_id", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("first_name", TokenType::Identifier));
         stream.add(Token::equal());
-        stream.add(Token::new("id", TokenType::Identifier));
-
+        stream.add(Token::new("last_name", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
         let ast = parser.parse().unwrap();
 
-        assert!(matches!(
-            ast,
-            Ast::Select { ref source, .. }
-            if matches!(
-                source,
-                TableSource::Join { left: left_outer, right: right_outer, on: on_outer }
-                if matches!(
-                    left_outer.as_ref(),
-                    TableSource::Join { left: left_inner, right: right_inner, on: on_inner }
-                    if matches!(left_inner.as_ref(), TableSource::Table { name, .. } if name == "employees")
-                    && matches!(right_inner.as_ref(), TableSource::Table { name, .. } if name == "departments")
-                    && matches!(
-                        on_inner,
-                        Some(Expression::Single(Clause::Comparison { lhs, operator, rhs }))
-                        if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employee_id")
-                        && *operator == BinaryOperator::Eq
-                        && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "department_id")
-                    )
-                )
-                && matches!(right_outer.as_ref(), TableSource::Table { name, .. } if name == "roles")
-                && matches!(
-                    on_outer,
-                    Some(Expression::Single(Clause::Comparison { lhs, operator, rhs }))
-                    if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "role_id")
+        assert!(
+            matches!(ast, Ast::Select { ref source, ref where_clause, .. }
+                if matches!(source, ast::TableSource::Table { ref name, .. } if name == "employees")
+                && matches!(where_clause, Some(WhereClause(Expression::Single(Clause::Comparison { ref lhs, ref operator, ref rhs })))
+                    if matches!(lhs, Literal::ColumnReference(ref name) if name == "first_name")
                     && *operator == BinaryOperator::Eq
-                    && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "id")
+                    && matches!(rhs, Literal::ColumnReference(ref name) if name == "last_name")
                 )
             )
-        ));
+        );
     }
 }
 
 #[cfg(test)]
-mod select_with_alias_tests {
+mod arithmetic_tests {
     use super::*;
     use crate::query::lexer::token::Token;
-    use crate::query::parser::ast::{Ast, TableSource};
+    use crate::query::parser::ast::{ArithmeticOperator, Ast, BinaryOperator, Literal};
 
     #[test]
-    fn parse_select_with_table_alias() {
+    fn parse_select_with_arithmetic_in_comparison() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("as", TokenType::Keyword));
-        stream.add(Token::new("e", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("salary", TokenType::Identifier));
+        stream.add(Token::plus());
+        stream.add(Token::new("bonus", TokenType::Identifier));
+        stream.add(Token::greater());
+        stream.add(Token::new("1000", TokenType::WholeNumber));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
@@ -2132,52 +3340,85 @@ mod select_with_alias_tests {
 
         assert!(matches!(
             ast,
-            Ast::Select { ref source, .. }
-            if matches!(source, TableSource::Table { ref name, ref alias } if name == "employees" && alias.as_deref() == Some("e"))
+            Ast::Select { where_clause, .. }
+                if where_clause == Some(WhereClause::comparison(
+                    Literal::arithmetic(
+                        Literal::ColumnReference("salary".to_string()),
+                        ArithmeticOperator::Add,
+                        Literal::ColumnReference("bonus".to_string()),
+                    ),
+                    BinaryOperator::Greater,
+                    Literal::Int(1000),
+                ))
         ));
     }
 
     #[test]
-    fn parse_select_with_join_and_aliases() {
+    fn parse_select_with_arithmetic_precedence() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("as", TokenType::Keyword));
-        stream.add(Token::new("e", TokenType::Identifier));
-        stream.add(Token::new("join", TokenType::Keyword));
-        stream.add(Token::new("departments", TokenType::Identifier));
-        stream.add(Token::new("as", TokenType::Keyword));
-        stream.add(Token::new("d", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::minus());
+        stream.add(Token::new("salary", TokenType::Identifier));
+        stream.add(Token::star());
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::slash());
+        stream.add(Token::new("rank", TokenType::Identifier));
+        stream.add(Token::minus());
+        stream.add(Token::new("3", TokenType::WholeNumber));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
         let ast = parser.parse().unwrap();
 
+        let product = Literal::arithmetic(
+            Literal::arithmetic(
+                Literal::ColumnReference("salary".to_string()),
+                ArithmeticOperator::Mul,
+                Literal::Int(2),
+            ),
+            ArithmeticOperator::Div,
+            Literal::ColumnReference("rank".to_string()),
+        );
+        let rhs = Literal::arithmetic(
+            Literal::arithmetic(Literal::Int(1), ArithmeticOperator::Sub, product),
+            ArithmeticOperator::Sub,
+            Literal::Int(3),
+        );
         assert!(matches!(
             ast,
-            Ast::Select { ref source, .. }
-            if matches!(
-                source,
-                TableSource::Join { left, right, .. }
-                if matches!(left.as_ref(), TableSource::Table { name, alias } if name == "employees" && alias.as_deref() == Some("e"))
-                && matches!(right.as_ref(), TableSource::Table { name, alias } if name == "departments" && alias.as_deref() == Some("d"))
-            )
+            Ast::Select { where_clause, .. }
+                if where_clause == Some(WhereClause::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    BinaryOperator::Eq,
+                    rhs,
+                ))
         ));
     }
 
     #[test]
-    fn parse_select_with_join_left_alias_only() {
+    fn parse_select_with_parenthesized_arithmetic() {
         let mut stream = TokenStream::new();
         stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("*", TokenType::Star));
         stream.add(Token::new("from", TokenType::Keyword));
         stream.add(Token::new("employees", TokenType::Identifier));
-        stream.add(Token::new("as", TokenType::Keyword));
-        stream.add(Token::new("e", TokenType::Identifier));
-        stream.add(Token::new("join", TokenType::Keyword));
-        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("salary", TokenType::Identifier));
+        stream.add(Token::star());
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::plus());
+        stream.add(Token::new("raise", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::greater());
+        stream.add(Token::new("1000", TokenType::WholeNumber));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
@@ -2185,152 +3426,2652 @@ mod select_with_alias_tests {
 
         assert!(matches!(
             ast,
-            Ast::Select { ref source, .. }
-            if matches!(
-                source,
-                TableSource::Join { left, right, .. }
-                if matches!(left.as_ref(), TableSource::Table { name, alias } if name == "employees" && alias.as_deref() == Some("e"))
-                && matches!(right.as_ref(), TableSource::Table { name, alias } if name == "departments" && alias.is_none())
-            )
+            Ast::Select { where_clause, .. }
+                if where_clause == Some(WhereClause::comparison(
+                    Literal::arithmetic(
+                        Literal::ColumnReference("salary".to_string()),
+                        ArithmeticOperator::Mul,
+                        Literal::arithmetic(
+                            Literal::Int(1),
+                            ArithmeticOperator::Add,
+                            Literal::ColumnReference("raise".to_string()),
+                        ),
+                    ),
+                    BinaryOperator::Greater,
+                    Literal::Int(1000),
+                ))
         ));
     }
-}
 
-#[cfg(test)]
-mod parentheses_tests {
-    use super::*;
-    use crate::query::lexer::token::Token;
-    use crate::query::parser::ast::{Clause, Expression, Literal};
+    #[test]
+    fn attempt_to_parse_select_with_missing_arithmetic_operand() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("salary", TokenType::Identifier));
+        stream.add(Token::plus());
+        stream.add(Token::greater());
+        stream.add(Token::new("1000", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { found, .. }) if found == ">"
+        ));
+    }
 
     #[test]
-    fn parse_expression_with_parentheses() {
+    fn parse_select_with_a_computed_column() {
         let mut stream = TokenStream::new();
-        stream.add(Token::left_parentheses());
+        stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("1", TokenType::WholeNumber));
-        stream.add(Token::right_parentheses());
+        stream.add(Token::comma());
+        stream.add(Token::new("salary", TokenType::Identifier));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("12", TokenType::WholeNumber));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("annual", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let expr = parser.expect_expression().unwrap();
+        let ast = parser.parse().unwrap();
 
-        assert_eq!(
-            expr,
-            Expression::grouped(Expression::single(Clause::comparison(
-                Literal::ColumnReference("id".to_string()),
-                BinaryOperator::Eq,
-                Literal::Int(1)
-            )))
-        );
+        assert!(matches!(
+            ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Columns(vec![
+                    ProjectedColumn::new("id"),
+                    ProjectedColumn::computed(
+                        Literal::arithmetic(
+                            Literal::ColumnReference("salary".to_string()),
+                            ArithmeticOperator::Mul,
+                            Literal::Int(12),
+                        ),
+                        "annual",
+                    ),
+                ])
+        ));
     }
 
     #[test]
-    fn parse_complex_expression_with_parentheses() {
+    fn parse_select_with_a_computed_column_without_alias() {
         let mut stream = TokenStream::new();
-        stream.add(Token::left_parentheses());
-        stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("1", TokenType::WholeNumber));
-        stream.add(Token::new("or", TokenType::Keyword));
-        stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("2", TokenType::WholeNumber));
-        stream.add(Token::right_parentheses());
-        stream.add(Token::new("and", TokenType::Keyword));
-        stream.add(Token::new("active", TokenType::Identifier));
-        stream.add(Token::equal());
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("salary", TokenType::Identifier));
+        stream.add(Token::plus());
         stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let expr = parser.expect_expression().unwrap();
+        let ast = parser.parse().unwrap();
 
-        let expected = Expression::and(vec![
-            Expression::grouped(Expression::or(vec![
-                Expression::single(Clause::comparison(
-                    Literal::ColumnReference("id".to_string()),
-                    BinaryOperator::Eq,
-                    Literal::Int(1),
-                )),
-                Expression::single(Clause::comparison(
-                    Literal::ColumnReference("id".to_string()),
-                    BinaryOperator::Eq,
-                    Literal::Int(2),
-                )),
-            ])),
-            Expression::single(Clause::comparison(
-                Literal::ColumnReference("active".to_string()),
-                BinaryOperator::Eq,
-                Literal::Int(1),
-            )),
-        ]);
-        assert_eq!(expr, expected);
+        assert!(matches!(ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Columns(vec![ProjectedColumn::computed(
+                    Literal::arithmetic(
+                        Literal::ColumnReference("salary".to_string()),
+                        ArithmeticOperator::Add,
+                        Literal::Int(1),
+                    ),
+                    "salary + 1",
+                )])
+        ));
     }
 
     #[test]
-    fn parse_nested_parentheses() {
+    fn parse_select_with_a_literal_column() {
         let mut stream = TokenStream::new();
-        stream.add(Token::left_parentheses());
-        stream.add(Token::left_parentheses());
-        stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::equal());
+        stream.add(Token::new("select", TokenType::Keyword));
         stream.add(Token::new("1", TokenType::WholeNumber));
-        stream.add(Token::right_parentheses());
-        stream.add(Token::right_parentheses());
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("one", TokenType::Identifier));
+        stream.add(Token::new(",", TokenType::Comma));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let expr = parser.expect_expression().unwrap();
+        let ast = parser.parse().unwrap();
 
-        let expected =
-            Expression::grouped(Expression::grouped(Expression::single(Clause::comparison(
-                Literal::ColumnReference("id".to_string()),
-                BinaryOperator::Eq,
-                Literal::Int(1),
-            ))));
-        assert_eq!(expr, expected);
+        assert!(matches!(ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Columns(vec![
+                    ProjectedColumn::computed(Literal::Int(1), "one"),
+                    ProjectedColumn::new("name"),
+                ])
+        ));
     }
 
     #[test]
-    fn parse_expression_with_unclosed_parentheses_error() {
+    fn parse_select_with_a_literal_column_without_alias() {
         let mut stream = TokenStream::new();
-        stream.add(Token::left_parentheses());
-        stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let result = parser.expect_expression();
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Columns(vec![ProjectedColumn::computed(
+                    Literal::Text("relop".to_string()),
+                    "'relop'",
+                )])
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_a_null_column() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("null", TokenType::Keyword));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("nothing", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
 
         assert!(matches!(
             result,
-            Err(ParseError::UnexpectedToken { ref expected, ref found })
-            if expected == ")" && found.is_empty()
+            Err(ParseError::UnexpectedToken { found, .. }) if found == "null"
         ));
     }
+}
+
+#[cfg(test)]
+mod function_call_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+    use crate::query::parser::ast::{Ast, BinaryOperator, Literal, ScalarFunction};
 
     #[test]
-    fn parse_unclosed_parentheses_in_complex_expression() {
+    fn parse_select_with_function_call_in_projection() {
         let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("upper", TokenType::Identifier));
         stream.add(Token::left_parentheses());
-        stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("1", TokenType::WholeNumber));
-        stream.add(Token::new("or", TokenType::Keyword));
-        stream.add(Token::new("id", TokenType::Identifier));
-        stream.add(Token::equal());
-        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("upper_name", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
         stream.add(Token::end_of_stream());
 
         let mut parser = Parser::new(stream);
-        let result = parser.expect_expression();
+        let ast = parser.parse().unwrap();
 
         assert!(matches!(
-            result,
-            Err(ParseError::UnexpectedToken { ref expected, ref found })
-            if expected == ")" && found.is_empty()
+            ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Columns(vec![ProjectedColumn::computed(
+                    Literal::function_call(
+                        ScalarFunction::Upper,
+                        vec![Literal::ColumnReference("name".to_string())],
+                    ),
+                    "upper_name",
+                )])
         ));
     }
+
+    #[test]
+    fn parse_select_with_function_call_without_alias_in_projection() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("upper", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Columns(vec![ProjectedColumn::computed(
+                    Literal::function_call(
+                        ScalarFunction::Upper,
+                        vec![Literal::ColumnReference("name".to_string())],
+                    ),
+                    "upper(name)",
+                )])
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_function_call_in_where_clause() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("LENGTH", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("lower", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::right_parentheses());
+        stream.add(Token::greater());
+        stream.add(Token::new("3", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { where_clause, .. }
+                if where_clause == Some(WhereClause::comparison(
+                    Literal::function_call(
+                        ScalarFunction::Length,
+                        vec![Literal::function_call(
+                            ScalarFunction::Lower,
+                            vec![Literal::ColumnReference("name".to_string())],
+                        )],
+                    ),
+                    BinaryOperator::Greater,
+                    Literal::Int(3),
+                ))
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_substr() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("substr", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::comma());
+        stream.add(Token::new("3", TokenType::WholeNumber));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("prefix", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Columns(vec![ProjectedColumn::computed(
+                    Literal::function_call(
+                        ScalarFunction::Substr,
+                        vec![
+                            Literal::ColumnReference("name".to_string()),
+                            Literal::Int(1),
+                            Literal::Int(3),
+                        ],
+                    ),
+                    "prefix",
+                )])
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_substr_with_one_argument() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("substr", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("prefix", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::FunctionArgumentCountMismatch { function, actual })
+                if function == "substr" && actual == 1
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_unknown_function() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("trim", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("trimmed", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnknownFunction(function)) if function == "trim"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_function_call_with_too_many_arguments() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("upper", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("upper_name", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::FunctionArgumentCountMismatch { function, actual })
+                if function == "upper" && actual == 2
+        ));
+    }
+}
+
+#[cfg(test)]
+mod set_operation_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+    use crate::query::parser::ast::{Ast, SetOperator};
+
+    fn add_select_id_from(stream: &mut TokenStream, table_name: &str) {
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new(table_name, TokenType::Identifier));
+    }
+
+    fn is_select_id_from(ast: &Ast, table_name: &str) -> bool {
+        matches!(
+            ast,
+            Ast::Select { source, projection, order_by: None, limit: None, .. }
+                if *source == ast::TableSource::table(table_name)
+                    && *projection == Projection::Columns(vec![ProjectedColumn::new("id")])
+        )
+    }
+
+    #[test]
+    fn parse_union() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("union", TokenType::Keyword));
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::SetOperation { operator: SetOperator::Union, left, right, order_by: None, limit: None }
+                if is_select_id_from(&left, "employees") && is_select_id_from(&right, "departments")
+        ));
+    }
+
+    #[test]
+    fn parse_union_all_with_order_by_and_limit_of_the_combined_result() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("union", TokenType::Keyword));
+        stream.add(Token::new("all", TokenType::Keyword));
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("limit", TokenType::Keyword));
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::SetOperation { operator: SetOperator::UnionAll, right, order_by, limit: Some(2), .. }
+                if order_by == Some(vec![OrderingKey::ascending_by("id")])
+                    && is_select_id_from(&right, "departments")
+        ));
+    }
+
+    #[test]
+    fn parse_chained_unions_from_left_to_right() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("union", TokenType::Keyword));
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::new("union", TokenType::Keyword));
+        stream.add(Token::new("all", TokenType::Keyword));
+        add_select_id_from(&mut stream, "roles");
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::SetOperation { operator: SetOperator::UnionAll, left, .. }
+                if matches!(left.as_ref(), Ast::SetOperation { operator: SetOperator::Union, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_intersect() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("intersect", TokenType::Keyword));
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::SetOperation { operator: SetOperator::Intersect, left, right, .. }
+                if is_select_id_from(&left, "employees") && is_select_id_from(&right, "departments")
+        ));
+    }
+
+    #[test]
+    fn parse_except_after_union_from_left_to_right() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("union", TokenType::Keyword));
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::new("except", TokenType::Keyword));
+        add_select_id_from(&mut stream, "roles");
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::SetOperation { operator: SetOperator::Except, left, right, .. }
+                if matches!(left.as_ref(), Ast::SetOperation { operator: SetOperator::Union, .. })
+                    && is_select_id_from(&right, "roles")
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_union_after_order_by() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("union", TokenType::Keyword));
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found })
+                if expected == "end of stream" && found == "union"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod subquery_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+    use crate::query::parser::ast::{ArithmeticOperator, Ast, BinaryOperator, Literal};
+
+    fn add_select_id_from(stream: &mut TokenStream, table_name: &str) {
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new(table_name, TokenType::Identifier));
+    }
+
+    fn select_id_from(table_name: &str) -> Ast {
+        Ast::Select {
+            source: ast::TableSource::table(table_name),
+            projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+            distinct_on: None,
+            where_clause: None,
+            order_by: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn parse_select_with_scalar_subquery_in_comparison() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::left_parentheses());
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { where_clause, .. }
+                if where_clause == Some(WhereClause::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    BinaryOperator::Eq,
+                    Literal::Subquery(Box::new(select_id_from("departments"))),
+                ))
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_scalar_subquery_in_arithmetic() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::greater());
+        stream.add(Token::left_parentheses());
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::new("limit", TokenType::Keyword));
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::plus());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        let subquery = select_id_from("departments").with_order_by_and_limit(None, Some(1));
+        assert!(matches!(
+            ast,
+            Ast::Select { where_clause, .. }
+                if where_clause == Some(WhereClause::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    BinaryOperator::Greater,
+                    Literal::arithmetic(
+                        Literal::Subquery(Box::new(subquery)),
+                        ArithmeticOperator::Add,
+                        Literal::Int(1),
+                    ),
+                ))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_unclosed_subquery() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::left_parentheses());
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, .. }) if expected == ")"
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_in_subquery() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("in", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { where_clause: Some(WhereClause(Expression::Single(clause))), .. }
+                if clause == Clause::in_subquery(
+                    Literal::ColumnReference("id".to_string()),
+                    select_id_from("departments"),
+                    false,
+                )
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_not_in_subquery() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("in", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("and", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::greater());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { where_clause: Some(WhereClause(Expression::And(expressions))), .. }
+                if expressions[0] == Expression::single(Clause::in_subquery(
+                    Literal::ColumnReference("id".to_string()),
+                    select_id_from("departments"),
+                    true,
+                ))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_in_without_subquery() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("in", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "select" && found == "1"
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_exists() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("exists", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { where_clause: Some(WhereClause(Expression::Single(clause))), .. }
+                if clause == Clause::exists(select_id_from("departments"))
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_not_exists() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("exists", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { where_clause: Some(WhereClause(expression)), .. }
+                if expression == Expression::not(Expression::single(Clause::exists(select_id_from("departments"))))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_exists_without_parentheses() {
+        let mut stream = TokenStream::new();
+        add_select_id_from(&mut stream, "employees");
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("exists", TokenType::Keyword));
+        add_select_id_from(&mut stream, "departments");
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "(" && found == "select"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod select_join_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+    use crate::query::lexer::Lexer;
+    use crate::query::parser::ast::{
+        Ast, BinaryOperator, Clause, Expression, JoinType, Literal, TableSource,
+    };
+
+    #[test]
+    fn parse_select_with_join() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("employees.id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("departments.employee_id", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left, right, on, join_type: JoinType::Inner }
+                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
+                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
+                && matches!(
+                    on,
+                    Some(Expression::Single(Clause::Comparison { lhs, operator, rhs }))
+                    if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employees.id")
+                    && *operator == BinaryOperator::Eq
+                    && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "departments.employee_id")
+                )
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_join_multiple_conditions_in_on() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("employee_id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("department_id", TokenType::Identifier));
+        stream.add(Token::new("and", TokenType::Keyword));
+        stream.add(Token::new("status", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("ACTIVE", TokenType::StringLiteral));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left, right, on, join_type: JoinType::Inner }
+                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
+                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
+                && matches!(
+                    on,
+                    Some(Expression::And(expressions))
+                    if expressions.len() == 2
+                    && matches!(
+                        &expressions[0],
+                        Expression::Single(Clause::Comparison { lhs, operator, rhs })
+                        if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employee_id")
+                        && *operator == BinaryOperator::Eq
+                        && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "department_id")
+                    )
+                    && matches!(
+                        &expressions[1],
+                        Expression::Single(Clause::Comparison { lhs, operator, rhs })
+                        if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "status")
+                        && *operator == BinaryOperator::Eq
+                        && matches!(rhs, Literal::Text(column_name) if column_name == "ACTIVE")
+                    )
+                )
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_join_but_no_on_clause() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left, right, on, join_type: JoinType::Inner }
+                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
+                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
+                && on.is_none()
+            )
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_join_but_missing_right_table() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("employee_id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("department_id", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found })
+            if expected == "identifier" && found == "on"
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_multiple_joins() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("employee_id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("department_id", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("roles", TokenType::Identifier));
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("role_id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("id", TokenType::Identifier));
+
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left: left_outer, right: right_outer, on: on_outer, join_type: JoinType::Inner }
+                if matches!(
+                    left_outer.as_ref(),
+                    TableSource::Join { left: left_inner, right: right_inner, on: on_inner, join_type: JoinType::Inner }
+                    if matches!(left_inner.as_ref(), TableSource::Table { name, .. } if name == "employees")
+                    && matches!(right_inner.as_ref(), TableSource::Table { name, .. } if name == "departments")
+                    && matches!(
+                        on_inner,
+                        Some(Expression::Single(Clause::Comparison { lhs, operator, rhs }))
+                        if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employee_id")
+                        && *operator == BinaryOperator::Eq
+                        && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "department_id")
+                    )
+                )
+                && matches!(right_outer.as_ref(), TableSource::Table { name, .. } if name == "roles")
+                && matches!(
+                    on_outer,
+                    Some(Expression::Single(Clause::Comparison { lhs, operator, rhs }))
+                    if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "role_id")
+                    && *operator == BinaryOperator::Eq
+                    && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "id")
+                )
+            )
+        ));
+    }
+
+    fn parse_join_of_type(join_tokens: &[&str]) -> Result<Ast, ParseError> {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        for join_token in join_tokens {
+            stream.add(Token::new(*join_token, TokenType::Keyword));
+        }
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        parser.parse()
+    }
+
+    #[test]
+    fn parse_select_with_inner_join_as_join() {
+        let inner_join = parse_join_of_type(&["inner", "join"]).unwrap();
+        let join = parse_join_of_type(&["join"]).unwrap();
+
+        assert_eq!(join, inner_join);
+        assert!(matches!(
+            inner_join,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left, right, on, join_type: JoinType::Inner }
+                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
+                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
+                && on.is_none()
+            )
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_inner_outer_join() {
+        let result = parse_join_of_type(&["inner", "outer", "join"]);
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found })
+            if expected == "join" && found == "outer"
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_left_join() {
+        let ast = parse_join_of_type(&["left", "join"]).unwrap();
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(source, TableSource::Join { join_type: JoinType::Left, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_right_join() {
+        let ast = parse_join_of_type(&["right", "join"]).unwrap();
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(source, TableSource::Join { join_type: JoinType::Right, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_right_outer_join() {
+        let ast = parse_join_of_type(&["right", "outer", "join"]).unwrap();
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(source, TableSource::Join { join_type: JoinType::Right, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_full_outer_join() {
+        let ast = parse_join_of_type(&["full", "outer", "join"]).unwrap();
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left, right, join_type: JoinType::FullOuter, .. }
+                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
+                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_full_join() {
+        let ast = parse_join_of_type(&["full", "join"]).unwrap();
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(source, TableSource::Join { join_type: JoinType::FullOuter, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_cross_join() {
+        let ast = parse_join_of_type(&["cross", "join"]).unwrap();
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left, right, on, join_type: JoinType::Cross }
+                if matches!(left.as_ref(), TableSource::Table { name, .. } if name == "employees")
+                && matches!(right.as_ref(), TableSource::Table { name, .. } if name == "departments")
+                && on.is_none()
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_comma_separated_tables_as_cross_join() {
+        let parse = |query: &str| {
+            let stream = Lexer::new_with_default_keywords(query).lex().unwrap();
+            Parser::new(stream).parse().unwrap()
+        };
+
+        assert_eq!(
+            parse("select * from employees cross join departments where employees.id = departments.id"),
+            parse("select * from employees, departments where employees.id = departments.id")
+        );
+    }
+
+    #[test]
+    fn parse_select_with_three_comma_separated_tables_as_left_deep_cross_joins() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("e", TokenType::Identifier));
+        stream.add(Token::new(",", TokenType::Comma));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new(",", TokenType::Comma));
+        stream.add(Token::new("roles", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("e.id", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("departments.id", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, ref where_clause, .. }
+            if matches!(
+                source,
+                TableSource::Join { left: left_outer, right: right_outer, on: None, join_type: JoinType::Cross }
+                if matches!(
+                    left_outer.as_ref(),
+                    TableSource::Join { left: left_inner, right: right_inner, on: None, join_type: JoinType::Cross }
+                    if matches!(left_inner.as_ref(), TableSource::Table { name, alias: Some(alias) } if name == "employees" && alias == "e")
+                    && matches!(right_inner.as_ref(), TableSource::Table { name, .. } if name == "departments")
+                )
+                && matches!(right_outer.as_ref(), TableSource::Table { name, .. } if name == "roles")
+            )
+            && where_clause.is_some()
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_a_trailing_comma_after_tables() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new(",", TokenType::Comma));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        assert!(matches!(
+            parser.parse(),
+            Err(ParseError::UnexpectedToken { expected, .. }) if expected == "identifier"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_cross_outer_join() {
+        let result = parse_join_of_type(&["cross", "outer", "join"]);
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found })
+            if expected == "join" && found == "outer"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_cross_join_and_on_clause() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("cross", TokenType::Keyword));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("employee_id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("department_id", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(result, Err(ParseError::OnClauseInCrossJoin)));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_full_outer_but_missing_join() {
+        let result = parse_join_of_type(&["full", "outer"]);
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found })
+            if expected == "join" && found == "departments"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod select_with_alias_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+    use crate::query::parser::ast::{Ast, TableSource};
+
+    #[test]
+    fn parse_select_with_table_alias() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("e", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(source, TableSource::Table { ref name, ref alias } if name == "employees" && alias.as_deref() == Some("e"))
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_implicit_table_alias() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("e", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("e.id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, ref where_clause, .. }
+            if *source == TableSource::table_with_alias("employees", "e") && where_clause.is_some()
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_join_and_implicit_aliases() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("e", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("d", TokenType::Identifier));
+        stream.add(Token::new("on", TokenType::Keyword));
+        stream.add(Token::new("e.id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("d.id", TokenType::Identifier));
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("e.id", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, ref order_by, .. }
+            if matches!(source, TableSource::Join { left, right, on, .. }
+                if **left == TableSource::table_with_alias("employees", "e")
+                    && **right == TableSource::table_with_alias("departments", "d")
+                    && on.is_some())
+                && order_by.is_some()
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_join_and_mixed_aliases() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("e", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("d", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(source, TableSource::Join { left, right, .. }
+                if **left == TableSource::table_with_alias("employees", "e")
+                    && **right == TableSource::table_with_alias("departments", "d"))
+        ));
+    }
+
+    #[test]
+    fn parse_select_without_alias_before_join() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("limit", TokenType::Keyword));
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, limit: Some(1), .. }
+            if matches!(source, TableSource::Join { left, right, .. }
+                if **left == TableSource::table("employees")
+                    && **right == TableSource::table("departments"))
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_join_and_aliases() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("e", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("d", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left, right, .. }
+                if matches!(left.as_ref(), TableSource::Table { name, alias } if name == "employees" && alias.as_deref() == Some("e"))
+                && matches!(right.as_ref(), TableSource::Table { name, alias } if name == "departments" && alias.as_deref() == Some("d"))
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_join_left_alias_only() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("as", TokenType::Keyword));
+        stream.add(Token::new("e", TokenType::Identifier));
+        stream.add(Token::new("join", TokenType::Keyword));
+        stream.add(Token::new("departments", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(
+            ast,
+            Ast::Select { ref source, .. }
+            if matches!(
+                source,
+                TableSource::Join { left, right, .. }
+                if matches!(left.as_ref(), TableSource::Table { name, alias } if name == "employees" && alias.as_deref() == Some("e"))
+                && matches!(right.as_ref(), TableSource::Table { name, alias } if name == "departments" && alias.is_none())
+            )
+        ));
+    }
+}
+
+#[cfg(test)]
+mod parentheses_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+    use crate::query::parser::ast::{Clause, Expression, Literal};
+
+    #[test]
+    fn parse_expression_with_parentheses() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let expr = parser.expect_expression().unwrap();
+
+        assert_eq!(
+            expr,
+            Expression::grouped(Expression::single(Clause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1)
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_complex_expression_with_parentheses() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::new("or", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("and", TokenType::Keyword));
+        stream.add(Token::new("active", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let expr = parser.expect_expression().unwrap();
+
+        let expected = Expression::and(vec![
+            Expression::grouped(Expression::or(vec![
+                Expression::single(Clause::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    BinaryOperator::Eq,
+                    Literal::Int(1),
+                )),
+                Expression::single(Clause::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    BinaryOperator::Eq,
+                    Literal::Int(2),
+                )),
+            ])),
+            Expression::single(Clause::comparison(
+                Literal::ColumnReference("active".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1),
+            )),
+        ]);
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn parse_nested_parentheses() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::left_parentheses());
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let expr = parser.expect_expression().unwrap();
+
+        let expected =
+            Expression::grouped(Expression::grouped(Expression::single(Clause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1),
+            ))));
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn parse_expression_with_unclosed_parentheses_error() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.expect_expression();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { ref expected, ref found })
+            if expected == ")" && found.is_empty()
+        ));
+    }
+
+    #[test]
+    fn parse_unclosed_parentheses_in_complex_expression() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::new("or", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.expect_expression();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { ref expected, ref found })
+            if expected == ")" && found.is_empty()
+        ));
+    }
+}
+
+#[cfg(test)]
+mod not_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+    use crate::query::parser::ast::{Clause, Expression, Literal};
+
+    #[test]
+    fn parse_expression_with_not() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let expr = parser.expect_expression().unwrap();
+
+        assert_eq!(
+            expr,
+            Expression::not(Expression::single(Clause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1)
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_expression_with_not_binding_tighter_than_and() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::new("and", TokenType::Keyword));
+        stream.add(Token::new("active", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let expr = parser.expect_expression().unwrap();
+
+        let expected = Expression::and(vec![
+            Expression::not(Expression::single(Clause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1),
+            ))),
+            Expression::single(Clause::comparison(
+                Literal::ColumnReference("active".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1),
+            )),
+        ]);
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn parse_expression_with_not_on_parentheses() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::new("or", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let expr = parser.expect_expression().unwrap();
+
+        let expected = Expression::not(Expression::grouped(Expression::or(vec![
+            Expression::single(Clause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1),
+            )),
+            Expression::single(Clause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(2),
+            )),
+        ])));
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn parse_double_not() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("not", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::equal());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let expr = parser.expect_expression().unwrap();
+
+        assert_eq!(
+            expr,
+            Expression::not(Expression::not(Expression::single(Clause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1)
+            ))))
+        );
+    }
+}
+
+#[cfg(test)]
+mod explain_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+
+    #[test]
+    fn parse_explain_select() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("explain", TokenType::Keyword));
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Explain(inner)
+                if matches!(inner.as_ref(), Ast::Select { source, projection, .. }
+                    if *source == ast::TableSource::table("employees") && *projection == Projection::All)));
+    }
+
+    #[test]
+    fn attempt_to_parse_explain_show_tables() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("explain", TokenType::Keyword));
+        stream.add(Token::new("show", TokenType::Keyword));
+        stream.add(Token::new("tables", TokenType::Keyword));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken{expected, found}) if expected == "select" && found == "show")
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_explain_without_statement() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("explain", TokenType::Keyword));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, .. }) if expected == "select"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod insert_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+
+    #[test]
+    fn parse_insert_with_columns_and_multiple_rows() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("insert", TokenType::Keyword));
+        stream.add(Token::new("into", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::comma());
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::comma());
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::comma());
+        stream.add(Token::new("query", TokenType::StringLiteral));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Insert { table_name, columns, rows }
+        if table_name == "employees"
+            && columns == Some(vec!["id".to_string(), "name".to_string()])
+            && rows == vec![
+                vec![Literal::Int(1), Literal::Text("relop".to_string())],
+                vec![Literal::Int(2), Literal::Text("query".to_string())],
+            ]));
+    }
+
+    #[test]
+    fn parse_insert_without_columns() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("insert", TokenType::Keyword));
+        stream.add(Token::new("into", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("-1", TokenType::WholeNumber));
+        stream.add(Token::comma());
+        stream.add(Token::new("true", TokenType::Keyword));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Insert { table_name, columns, rows }
+            if table_name == "employees"
+                && columns.is_none()
+                && rows == vec![vec![Literal::Int(-1), Literal::Bool(true)]]));
+    }
+
+    #[test]
+    fn attempt_to_parse_insert_with_a_column_reference_as_value() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("insert", TokenType::Keyword));
+        stream.add(Token::new("into", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "value" && found == "id"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_insert_without_values() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("insert", TokenType::Keyword));
+        stream.add(Token::new("into", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, .. }) if expected == "("
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_insert_with_an_unclosed_row() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("insert", TokenType::Keyword));
+        stream.add(Token::new("into", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, .. }) if expected == ")"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_insert_without_into() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("insert", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "into" && found == "employees"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod delete_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+
+    #[test]
+    fn parse_delete_with_where_clause() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("delete", TokenType::Keyword));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Delete { table_name, where_clause }
+        if table_name == "employees"
+            && where_clause == Some(WhereClause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1),
+            ))));
+    }
+
+    #[test]
+    fn parse_delete_without_where_clause() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("delete", TokenType::Keyword));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(
+            matches!(ast, Ast::Delete { table_name, where_clause: None } if table_name == "employees")
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_delete_without_from() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("delete", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "from" && found == "employees"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_delete_with_order_by() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("delete", TokenType::Keyword));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("order", TokenType::Keyword));
+        stream.add(Token::new("by", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+mod update_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+
+    #[test]
+    fn parse_update_with_where_clause() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("update", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("set", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::comma());
+        stream.add(Token::new("age", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("30", TokenType::WholeNumber));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(
+            matches!(ast, Ast::Update { table_name, assignments, where_clause }
+            if table_name == "employees"
+                && assignments == vec![
+                    ("name".to_string(), Literal::Text("relop".to_string())),
+                    ("age".to_string(), Literal::Int(30)),
+                ]
+                && where_clause == Some(WhereClause::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    BinaryOperator::Eq,
+                    Literal::Int(1),
+                )))
+        );
+    }
+
+    #[test]
+    fn parse_update_without_where_clause() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("update", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("set", TokenType::Keyword));
+        stream.add(Token::new("active", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("false", TokenType::Keyword));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(
+            matches!(ast, Ast::Update { table_name, assignments, where_clause: None }
+            if table_name == "employees"
+                && assignments == vec![("active".to_string(), Literal::Bool(false))])
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_update_without_set() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("update", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "set" && found == "name"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_update_with_an_assignment_without_equal() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("update", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("set", TokenType::Keyword));
+        stream.add(Token::new("age", TokenType::Identifier));
+        stream.add(Token::new(">", TokenType::Greater));
+        stream.add(Token::new("30", TokenType::WholeNumber));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "=" && found == ">"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_update_with_a_column_reference_as_value() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("update", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("set", TokenType::Keyword));
+        stream.add(Token::new("age", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "value" && found == "id"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod create_and_drop_table_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+
+    #[test]
+    fn parse_create_table() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("create", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("INT", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("text", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("active", TokenType::Identifier));
+        stream.add(Token::new("bool", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(
+            matches!(ast, Ast::CreateTable { name, columns, primary_key }
+        if name == "employees"
+            && columns == vec![
+                ("id".to_string(), ColumnType::Int),
+                ("name".to_string(), ColumnType::Text),
+                ("active".to_string(), ColumnType::Bool),
+            ]
+            && primary_key.is_none())
+        );
+    }
+
+    #[test]
+    fn parse_create_table_with_a_primary_key_column() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("create", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("int", TokenType::Identifier));
+        stream.add(Token::new("primary", TokenType::Keyword));
+        stream.add(Token::new("key", TokenType::Keyword));
+        stream.add(Token::comma());
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("text", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(
+            matches!(ast, Ast::CreateTable { name, columns, primary_key }
+        if name == "employees"
+            && columns == vec![
+                ("id".to_string(), ColumnType::Int),
+                ("name".to_string(), ColumnType::Text),
+            ]
+            && primary_key == Some(vec!["id".to_string()]))
+        );
+    }
+
+    #[test]
+    fn parse_create_table_with_a_primary_key_constraint() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("create", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("department_id", TokenType::Identifier));
+        stream.add(Token::new("int", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("int", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("primary", TokenType::Keyword));
+        stream.add(Token::new("key", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("department_id", TokenType::Identifier));
+        stream.add(Token::comma());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(
+            matches!(ast, Ast::CreateTable { name, columns, primary_key }
+        if name == "employees"
+            && columns == vec![
+                ("department_id".to_string(), ColumnType::Int),
+                ("id".to_string(), ColumnType::Int),
+            ]
+            && primary_key == Some(vec!["department_id".to_string(), "id".to_string()]))
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_create_table_with_multiple_primary_keys() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("create", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("int", TokenType::Identifier));
+        stream.add(Token::new("primary", TokenType::Keyword));
+        stream.add(Token::new("key", TokenType::Keyword));
+        stream.add(Token::comma());
+        stream.add(Token::new("primary", TokenType::Keyword));
+        stream.add(Token::new("key", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::MultiplePrimaryKeys(table_name)) if table_name == "employees"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_create_table_with_primary_without_key() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("create", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("int", TokenType::Identifier));
+        stream.add(Token::new("primary", TokenType::Keyword));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "key" && found == ")"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_create_table_with_an_unknown_column_type() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("create", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("float", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "int | text | bool | date" && found == "float"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_create_table_without_columns() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("create", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "identifier" && found == ")"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_create_table_without_a_column_type() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("create", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, found }) if expected == "int | text | bool | date" && found == ")"
+        ));
+    }
+
+    #[test]
+    fn parse_drop_table() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("drop", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::DropTable { table_name } if table_name == "employees"));
+    }
+
+    #[test]
+    fn attempt_to_parse_drop_table_without_table_name() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("drop", TokenType::Keyword));
+        stream.add(Token::new("table", TokenType::Keyword));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+mod values_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+
+    #[test]
+    fn parse_values_with_multiple_rows() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("1", TokenType::WholeNumber));
+        stream.add(Token::comma());
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::comma());
+        stream.add(Token::left_parentheses());
+        stream.add(Token::new("2", TokenType::WholeNumber));
+        stream.add(Token::comma());
+        stream.add(Token::new("query", TokenType::StringLiteral));
+        stream.add(Token::right_parentheses());
+        stream.add(Token::semicolon());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert_eq!(
+            Ast::Values(vec![
+                vec![Literal::Int(1), Literal::Text("relop".to_string())],
+                vec![Literal::Int(2), Literal::Text("query".to_string())],
+            ]),
+            ast
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_values_without_a_row() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let result = parser.parse();
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnexpectedToken { expected, .. }) if expected == "("
+        ));
+    }
+}
+
+#[cfg(test)]
+mod parameter_tests {
+    use super::*;
+    use crate::query::lexer::token::Token;
+
+    #[test]
+    fn parse_select_with_parameters() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("select", TokenType::Keyword));
+        stream.add(Token::new("*", TokenType::Star));
+        stream.add(Token::new("from", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("where", TokenType::Keyword));
+        stream.add(Token::new("id", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::placeholder());
+        stream.add(Token::new("and", TokenType::Keyword));
+        stream.add(Token::new("name", TokenType::Identifier));
+        stream.add(Token::new("=", TokenType::Equal));
+        stream.add(Token::placeholder());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Select { where_clause, .. }
+            if where_clause == Some(WhereClause::and(vec![
+                Expression::single(Clause::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    BinaryOperator::Eq,
+                    Literal::Parameter(0),
+                )),
+                Expression::single(Clause::comparison(
+                    Literal::ColumnReference("name".to_string()),
+                    BinaryOperator::Eq,
+                    Literal::Parameter(1),
+                )),
+            ]))
+        ));
+    }
+
+    #[test]
+    fn parse_insert_with_parameters() {
+        let mut stream = TokenStream::new();
+        stream.add(Token::new("insert", TokenType::Keyword));
+        stream.add(Token::new("into", TokenType::Keyword));
+        stream.add(Token::new("employees", TokenType::Identifier));
+        stream.add(Token::new("values", TokenType::Keyword));
+        stream.add(Token::left_parentheses());
+        stream.add(Token::placeholder());
+        stream.add(Token::comma());
+        stream.add(Token::new("relop", TokenType::StringLiteral));
+        stream.add(Token::comma());
+        stream.add(Token::placeholder());
+        stream.add(Token::right_parentheses());
+        stream.add(Token::end_of_stream());
+
+        let mut parser = Parser::new(stream);
+        let ast = parser.parse().unwrap();
+
+        assert!(matches!(ast, Ast::Insert { rows, .. }
+            if rows == vec![vec![
+                Literal::Parameter(0),
+                Literal::Text("relop".to_string()),
+                Literal::Parameter(1),
+            ]]
+        ));
+    }
+}
+
+#[cfg(test)]
+mod quoted_table_name_tests {
+    use super::*;
+    use crate::query::lexer::Lexer;
+
+    fn parse(query: &str) -> Result<Ast, ParseError> {
+        let stream = Lexer::new_with_default_keywords(query).lex().unwrap();
+        Parser::new(stream).parse()
+    }
+
+    #[test]
+    fn parse_select_from_a_quoted_keyword_table_name() {
+        let ast = parse("select * from \"order\"").unwrap();
+
+        assert!(
+            matches!(ast, Ast::Select { source, .. } if source == ast::TableSource::table("order"))
+        );
+    }
+
+    #[test]
+    fn parse_describe_table_with_a_quoted_keyword_table_name() {
+        let ast = parse("describe table \"select\"").unwrap();
+
+        assert!(matches!(ast, Ast::DescribeTable { table_name } if table_name == "select"));
+    }
+
+    #[test]
+    fn parse_join_of_quoted_keyword_table_names_with_qualified_columns() {
+        let ast = parse(
+            "select \"order\".id from \"order\" join \"select\" on \"order\".id = \"select\".\"from\"",
+        )
+        .unwrap();
+
+        let Ast::Select {
+            source, projection, ..
+        } = ast
+        else {
+            panic!("expected a select");
+        };
+        assert_eq!(
+            Projection::Columns(vec![ProjectedColumn::new("order.id")]),
+            projection
+        );
+        assert!(
+            matches!(source, ast::TableSource::Join { left, right, on: Some(_), .. }
+            if *left == ast::TableSource::table("order") && *right == ast::TableSource::table("select"))
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_select_from_an_unquoted_keyword_table_name() {
+        let result = parse("select * from order");
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken { found, .. }) if found == "order")
+        );
+    }
+}
+
+#[cfg(test)]
+mod select_aggregate_tests {
+    use super::*;
+    use crate::query::lexer::Lexer;
+
+    fn parse(query: &str) -> Result<Ast, ParseError> {
+        let stream = Lexer::new_with_default_keywords(query).lex().unwrap();
+        Parser::new(stream).parse()
+    }
+
+    #[test]
+    fn parse_select_with_count_all() {
+        let ast = parse("select count(*) from employees").unwrap();
+
+        assert!(matches!(ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Aggregates(vec![Aggregate::count_all()])
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_count_and_count_distinct() {
+        let ast =
+            parse("select count(city), COUNT(distinct city) as cities from employees").unwrap();
+
+        assert!(matches!(ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Aggregates(vec![
+                    Aggregate::count("city"),
+                    Aggregate::count_distinct("city").with_alias("cities"),
+                ])
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_count_and_a_column() {
+        let result = parse("select count(*), name from employees");
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken { expected, found }) if expected == "count" && found == "name")
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_count_without_argument() {
+        let result = parse("select count() from employees");
+
+        assert!(matches!(result, Err(ParseError::UnexpectedToken { found, .. }) if found == ")"));
+    }
+
+    #[test]
+    fn parse_select_with_a_column_named_count() {
+        let ast = parse("select count from employees").unwrap();
+
+        assert!(matches!(ast,
+            Ast::Select { projection, .. }
+                if projection == Projection::Columns(vec![ProjectedColumn::new("count")])
+        ));
+    }
+}
+
+#[cfg(test)]
+mod distinct_from_tests {
+    use super::*;
+    use crate::query::lexer::Lexer;
+
+    fn parse(query: &str) -> Result<Ast, ParseError> {
+        let stream = Lexer::new_with_default_keywords(query).lex().unwrap();
+        Parser::new(stream).parse()
+    }
+
+    #[test]
+    fn parse_select_with_is_not_distinct_from() {
+        let ast = parse("select * from employees where manager_id is not distinct from mentor_id")
+            .unwrap();
+
+        assert!(matches!(ast,
+            Ast::Select { where_clause: Some(where_clause), .. }
+                if where_clause == WhereClause::comparison(
+                    Literal::ColumnReference("manager_id".to_string()),
+                    BinaryOperator::IsNotDistinctFrom,
+                    Literal::ColumnReference("mentor_id".to_string()),
+                )
+        ));
+    }
+
+    #[test]
+    fn parse_select_with_is_distinct_from() {
+        let ast = parse("select * from employees where manager_id IS DISTINCT FROM 1").unwrap();
+
+        assert!(matches!(ast,
+            Ast::Select { where_clause: Some(where_clause), .. }
+                if where_clause == WhereClause::comparison(
+                    Literal::ColumnReference("manager_id".to_string()),
+                    BinaryOperator::IsDistinctFrom,
+                    Literal::Int(1),
+                )
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_is_without_distinct_from() {
+        let result = parse("select * from employees where manager_id is 1");
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken { expected, found }) if expected == "distinct" && found == "1")
+        );
+    }
+
+    #[test]
+    fn attempt_to_parse_select_with_is_distinct_without_from() {
+        let result = parse("select * from employees where manager_id is distinct 1");
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken { expected, found }) if expected == "from" && found == "1")
+        );
+    }
+}
+
+#[cfg(test)]
+mod select_without_from_tests {
+    use super::*;
+    use crate::query::lexer::Lexer;
+
+    fn parse(query: &str) -> Result<Ast, ParseError> {
+        let stream = Lexer::new_with_default_keywords(query).lex().unwrap();
+        Parser::new(stream).parse()
+    }
+
+    #[test]
+    fn parse_select_without_from() {
+        let ast = parse("select 1 + 1 as two;").unwrap();
+
+        assert!(matches!(ast,
+            Ast::Select { source, projection, .. }
+                if source == ast::TableSource::SingleRow
+                    && projection == Projection::Columns(vec![ProjectedColumn::computed(
+                        Literal::arithmetic(Literal::Int(1), ArithmeticOperator::Add, Literal::Int(1)),
+                        "two",
+                    )])
+        ));
+    }
+
+    #[test]
+    fn parse_select_without_from_in_a_set_operation() {
+        let ast = parse("select 1 as one union select 2 as one order by one limit 1").unwrap();
+
+        assert!(matches!(ast,
+            Ast::SetOperation { left, right, limit: Some(1), .. }
+                if matches!(left.as_ref(), Ast::Select { source: ast::TableSource::SingleRow, .. })
+                    && matches!(right.as_ref(), Ast::Select { source: ast::TableSource::SingleRow, .. })
+        ));
+    }
+
+    #[test]
+    fn attempt_to_parse_select_without_from_with_where() {
+        let result = parse("select 1 as one where 1 = 1");
+
+        assert!(
+            matches!(result, Err(ParseError::UnexpectedToken { expected, found }) if expected == "from" && found == "where")
+        );
+    }
 }
@@ -1,16 +1,23 @@
 /// Represents a sort key in an `ORDER BY` clause.
 ///
-/// It specifies which column to sort by and the direction of the sort.
-#[derive(Debug, Eq, PartialEq)]
+/// It specifies which column to sort by, the direction of the sort and the placement of `Null`s.
+/// The column may be given
+/// by its (1-based) position in the projection (e.g., `order by 2`), such a key is resolved to
+/// the name of the column during planning.
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) struct OrderingKey {
     /// The name of the column to sort by.
     pub(crate) column: String,
     /// The direction of the sort (e.g., Ascending, Descending).
     pub(crate) direction: OrderingDirection,
+    /// Whether `Null`s are placed before or after the other values.
+    pub(crate) nulls: NullsOrdering,
+    /// The position of the column in the projection, if the key is given by position.
+    pub(crate) ordinal: Option<usize>,
 }
 
 /// Defines the direction of a sort order.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum OrderingDirection {
     /// Ascending order (lowest to highest).
     Ascending,
@@ -18,32 +25,92 @@ pub(crate) enum OrderingDirection {
     Descending,
 }
 
+/// Defines the placement of `Null`s in a sort order, irrespective of its direction.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(crate) enum NullsOrdering {
+    /// `Null`s are placed before the other values.
+    First,
+    /// `Null`s are placed after the other values.
+    Last,
+}
+
+impl NullsOrdering {
+    /// Returns the placement of `Null`s when `NULLS FIRST` or `NULLS LAST` is not given.
+    ///
+    /// `Null` sorts as if it were greater than every other value: `Null`s are placed last in
+    /// ascending order, and first in descending order.
+    pub(crate) fn default_for(direction: &OrderingDirection) -> Self {
+        match direction {
+            OrderingDirection::Ascending => NullsOrdering::Last,
+            OrderingDirection::Descending => NullsOrdering::First,
+        }
+    }
+}
+
 impl OrderingKey {
     /// Creates an `OrderingKey` for the specified column in the specified direction.
     pub(crate) fn new<C: Into<String>>(column_name: C, direction: OrderingDirection) -> Self {
         OrderingKey {
             column: column_name.into(),
+            nulls: NullsOrdering::default_for(&direction),
             direction,
+            ordinal: None,
+        }
+    }
+
+    /// Creates an `OrderingKey` for the column at the specified (1-based) position in the
+    /// projection, in the specified direction.
+    pub(crate) fn by_ordinal(ordinal: usize, direction: OrderingDirection) -> Self {
+        OrderingKey {
+            column: ordinal.to_string(),
+            nulls: NullsOrdering::default_for(&direction),
+            direction,
+            ordinal: Some(ordinal),
+        }
+    }
+
+    /// Places `Null`s as specified, instead of the default placement for the direction.
+    pub(crate) fn with_nulls(self, nulls: NullsOrdering) -> Self {
+        OrderingKey { nulls, ..self }
+    }
+
+    /// Resolves the key to the specified column, keeping its direction.
+    pub(crate) fn resolved_to<C: Into<String>>(self, column_name: C) -> Self {
+        OrderingKey {
+            column: column_name.into(),
+            ordinal: None,
+            ..self
         }
     }
 }
 
+impl std::fmt::Display for OrderingKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self.direction {
+            OrderingDirection::Ascending => "ASC",
+            OrderingDirection::Descending => "DESC",
+        };
+        write!(f, "{} {}", self.column, direction)?;
+        if self.nulls != NullsOrdering::default_for(&self.direction) {
+            match self.nulls {
+                NullsOrdering::First => write!(f, " NULLS FIRST")?,
+                NullsOrdering::Last => write!(f, " NULLS LAST")?,
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 impl OrderingKey {
     /// Creates an `OrderingKey` for the specified column in ascending order.
     pub(crate) fn ascending_by<C: Into<String>>(column_name: C) -> Self {
-        OrderingKey {
-            column: column_name.into(),
-            direction: OrderingDirection::Ascending,
-        }
+        OrderingKey::new(column_name, OrderingDirection::Ascending)
     }
 
     /// Creates an `OrderingKey` for the specified column in descending order.
     pub(crate) fn descending_by<C: Into<String>>(column_name: C) -> Self {
-        OrderingKey {
-            column: column_name.into(),
-            direction: OrderingDirection::Descending,
-        }
+        OrderingKey::new(column_name, OrderingDirection::Descending)
     }
 }
 
@@ -56,6 +123,7 @@ mod tests {
         let key = OrderingKey::ascending_by("id");
         assert_eq!(key.column, "id");
         assert_eq!(key.direction, OrderingDirection::Ascending);
+        assert_eq!(key.nulls, NullsOrdering::Last);
     }
 
     #[test]
@@ -63,5 +131,55 @@ mod tests {
         let key = OrderingKey::descending_by("rank");
         assert_eq!(key.column, "rank");
         assert_eq!(key.direction, OrderingDirection::Descending);
+        assert_eq!(key.nulls, NullsOrdering::First);
+    }
+
+    #[test]
+    fn with_nulls() {
+        let key = OrderingKey::ascending_by("id").with_nulls(NullsOrdering::First);
+        assert_eq!(key.direction, OrderingDirection::Ascending);
+        assert_eq!(key.nulls, NullsOrdering::First);
+    }
+
+    #[test]
+    fn by_ordinal() {
+        let key = OrderingKey::by_ordinal(2, OrderingDirection::Descending);
+        assert_eq!(key.column, "2");
+        assert_eq!(key.direction, OrderingDirection::Descending);
+        assert_eq!(key.ordinal, Some(2));
+    }
+
+    #[test]
+    fn resolve_key_by_ordinal() {
+        let key = OrderingKey::by_ordinal(2, OrderingDirection::Descending).resolved_to("name");
+        assert_eq!(OrderingKey::descending_by("name"), key);
+    }
+
+    #[test]
+    fn display_ordering_key() {
+        assert_eq!("id ASC", OrderingKey::ascending_by("id").to_string());
+        assert_eq!("name DESC", OrderingKey::descending_by("name").to_string());
+    }
+
+    #[test]
+    fn display_ordering_key_with_non_default_nulls_ordering() {
+        assert_eq!(
+            "id ASC NULLS FIRST",
+            OrderingKey::ascending_by("id")
+                .with_nulls(NullsOrdering::First)
+                .to_string()
+        );
+        assert_eq!(
+            "name DESC NULLS LAST",
+            OrderingKey::descending_by("name")
+                .with_nulls(NullsOrdering::Last)
+                .to_string()
+        );
+        assert_eq!(
+            "name DESC",
+            OrderingKey::descending_by("name")
+                .with_nulls(NullsOrdering::First)
+                .to_string()
+        );
     }
 }
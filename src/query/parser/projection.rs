@@ -1,8 +1,268 @@
+use crate::query::parser::ast::Literal;
+
 /// `Projection` represents the columns to be selected in a `SELECT` statement.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum Projection {
     /// Select all columns (`*`).
     All,
-    /// Select specific columns by name.
-    Columns(Vec<String>),
+    /// Select specific columns by name, each with an optional alias.
+    Columns(Vec<ProjectedColumn>),
+    /// Select all columns of a single table or alias (`employees.*`).
+    QualifiedAll(String),
+    /// Select aggregates over all the rows (e.g., `count(*), count(distinct city)`).
+    Aggregates(Vec<Aggregate>),
+}
+
+impl Projection {
+    /// Transforms the literals of the computed columns with the given function.
+    pub(crate) fn map_literals<F: FnMut(Literal) -> Literal>(self, transform: &mut F) -> Self {
+        match self {
+            Projection::Columns(columns) => Projection::Columns(
+                columns
+                    .into_iter()
+                    .map(|column| column.map_literals(transform))
+                    .collect(),
+            ),
+            Projection::All | Projection::QualifiedAll(_) | Projection::Aggregates(_) => self,
+        }
+    }
+}
+
+/// `ProjectedColumn` represents a single column in the projection list, with an optional alias
+/// (e.g., `id` or `id as employee_id`).
+///
+/// A computed column holds the expression to evaluate for each row (e.g., `salary * 12 as annual`),
+/// and always carries an alias.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct ProjectedColumn {
+    name: String,
+    alias: Option<String>,
+    expression: Option<Literal>,
+}
+
+impl ProjectedColumn {
+    /// Creates a new `ProjectedColumn` without an alias.
+    pub(crate) fn new<T: Into<String>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            alias: None,
+            expression: None,
+        }
+    }
+
+    /// Creates a new `ProjectedColumn` with an alias.
+    pub(crate) fn aliased<T: Into<String>, A: Into<String>>(name: T, alias: A) -> Self {
+        Self {
+            name: name.into(),
+            alias: Some(alias.into()),
+            expression: None,
+        }
+    }
+
+    /// Creates a new computed `ProjectedColumn`, named after its expression.
+    pub(crate) fn computed<A: Into<String>>(expression: Literal, alias: A) -> Self {
+        Self {
+            name: expression.to_string(),
+            alias: Some(alias.into()),
+            expression: Some(expression),
+        }
+    }
+
+    /// Transforms the literals of the expression of a computed column with the given function.
+    ///
+    /// The name of the column is left unchanged.
+    pub(crate) fn map_literals<F: FnMut(Literal) -> Literal>(self, transform: &mut F) -> Self {
+        Self {
+            expression: self.expression.map(|expression| expression.map(transform)),
+            ..self
+        }
+    }
+
+    /// Returns the name of the column being projected.
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the alias of the column, if any.
+    pub(crate) fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    /// Returns the expression of a computed column, if any.
+    pub(crate) fn expression(&self) -> Option<&Literal> {
+        self.expression.as_ref()
+    }
+}
+
+impl std::fmt::Display for ProjectedColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.alias {
+            Some(alias) => write!(f, "{} AS {}", self.name, alias),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// `Aggregate` represents a `count` aggregate in the projection list, with an optional alias
+/// (e.g., `count(*)`, `count(city)` or `count(distinct city) as cities`).
+///
+/// `count(*)` counts the rows, `count(column)` counts the non-null values of the column, and
+/// `count(distinct column)` counts the distinct non-null values of the column.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Aggregate {
+    column: Option<String>,
+    distinct: bool,
+    alias: Option<String>,
+}
+
+impl Aggregate {
+    /// Creates a `count(*)` aggregate.
+    pub(crate) fn count_all() -> Self {
+        Self {
+            column: None,
+            distinct: false,
+            alias: None,
+        }
+    }
+
+    /// Creates a `count(column)` aggregate.
+    pub(crate) fn count<T: Into<String>>(column: T) -> Self {
+        Self {
+            column: Some(column.into()),
+            distinct: false,
+            alias: None,
+        }
+    }
+
+    /// Creates a `count(distinct column)` aggregate.
+    pub(crate) fn count_distinct<T: Into<String>>(column: T) -> Self {
+        Self {
+            column: Some(column.into()),
+            distinct: true,
+            alias: None,
+        }
+    }
+
+    /// Sets the alias of the aggregate.
+    pub(crate) fn with_alias<A: Into<String>>(mut self, alias: A) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// Returns the column being counted, `None` for `count(*)`.
+    pub(crate) fn column(&self) -> Option<&str> {
+        self.column.as_deref()
+    }
+
+    /// Returns `true` if only the distinct values of the column are counted.
+    pub(crate) fn is_distinct(&self) -> bool {
+        self.distinct
+    }
+
+    /// Returns the alias of the aggregate, if any.
+    pub(crate) fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    /// Returns the name of the aggregate, as written without the alias (e.g., `count(distinct city)`).
+    pub(crate) fn name(&self) -> String {
+        match (&self.column, self.distinct) {
+            (None, _) => "count(*)".to_string(),
+            (Some(column), false) => format!("count({})", column),
+            (Some(column), true) => format!("count(distinct {})", column),
+        }
+    }
+
+    /// Returns the name of the column produced by the aggregate: its alias, or else its name.
+    pub(crate) fn output_name(&self) -> String {
+        self.alias()
+            .map_or_else(|| self.name(), |alias| alias.to_string())
+    }
+}
+
+impl std::fmt::Display for Aggregate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.alias() {
+            Some(alias) => write!(f, "{} AS {}", self.name(), alias),
+            None => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::parser::ast::ArithmeticOperator;
+
+    #[test]
+    fn projected_column_without_alias() {
+        let column = ProjectedColumn::new("id");
+        assert_eq!("id", column.name());
+        assert_eq!(None, column.alias());
+    }
+
+    #[test]
+    fn projected_column_with_alias() {
+        let column = ProjectedColumn::aliased("id", "employee_id");
+        assert_eq!("id", column.name());
+        assert_eq!(Some("employee_id"), column.alias());
+    }
+
+    #[test]
+    fn computed_projected_column() {
+        let column = ProjectedColumn::computed(
+            Literal::arithmetic(
+                Literal::ColumnReference("salary".to_string()),
+                ArithmeticOperator::Mul,
+                Literal::Int(12),
+            ),
+            "annual",
+        );
+        assert_eq!("salary * 12", column.name());
+        assert_eq!(Some("annual"), column.alias());
+        assert!(column.expression().is_some());
+    }
+
+    #[test]
+    fn display_projected_column() {
+        assert_eq!("id", ProjectedColumn::new("id").to_string());
+        assert_eq!(
+            "id AS employee_id",
+            ProjectedColumn::aliased("id", "employee_id").to_string()
+        );
+    }
+
+    #[test]
+    fn count_all_aggregate() {
+        let aggregate = Aggregate::count_all();
+        assert_eq!(None, aggregate.column());
+        assert!(!aggregate.is_distinct());
+        assert_eq!("count(*)", aggregate.output_name());
+    }
+
+    #[test]
+    fn count_distinct_aggregate() {
+        let aggregate = Aggregate::count_distinct("city");
+        assert_eq!(Some("city"), aggregate.column());
+        assert!(aggregate.is_distinct());
+        assert_eq!("count(distinct city)", aggregate.output_name());
+    }
+
+    #[test]
+    fn aggregate_with_alias() {
+        let aggregate = Aggregate::count("city").with_alias("cities");
+        assert_eq!(Some("cities"), aggregate.alias());
+        assert_eq!("cities", aggregate.output_name());
+    }
+
+    #[test]
+    fn display_aggregate() {
+        assert_eq!("count(city)", Aggregate::count("city").to_string());
+        assert_eq!(
+            "count(distinct city) AS cities",
+            Aggregate::count_distinct("city")
+                .with_alias("cities")
+                .to_string()
+        );
+    }
 }
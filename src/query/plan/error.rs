@@ -5,8 +5,79 @@ use regex::Error;
 pub enum PlanningError {
     /// Indicates that a provided regular expression in a LIKE clause is invalid.
     InvalidRegex(String),
+    /// Indicates that a `LIKE` pattern is invalid (ends with the escape character `\`).
+    InvalidLikePattern(String),
     /// Indicates that a column reference could not be resolved.
     ColumnNotFound(String),
+    /// Indicates that an unqualified column reference (e.g., in `WHERE` or `ORDER BY`) matches a column of
+    /// more than one joined table.
+    AmbiguousColumn(String),
+    /// Indicates that an `ORDER BY` ordinal is not the position of a projected column.
+    OrderByOrdinalOutOfRange {
+        /// The ordinal given in `ORDER BY`.
+        ordinal: usize,
+        /// The number of projected columns.
+        column_count: usize,
+    },
+    /// Indicates that the queries of a set operation (e.g., `UNION`) select a different number of columns.
+    SetOperationColumnCountMismatch {
+        /// The number of columns selected by the left query.
+        left: usize,
+        /// The number of columns selected by the right query.
+        right: usize,
+    },
+    /// Indicates that the queries of a set operation (e.g., `UNION`) select columns of different
+    /// types at the same position.
+    SetOperationColumnTypeMismatch {
+        /// The (1-based) position of the column.
+        position: usize,
+        /// The type of the column selected by the left query.
+        left: crate::types::column_type::ColumnType,
+        /// The type of the column selected by the right query.
+        right: crate::types::column_type::ColumnType,
+    },
+    /// Indicates that the columns of `DISTINCT ON` are not the leading columns of the ORDER BY
+    /// (or that there is no ORDER BY), containing the columns of `DISTINCT ON`.
+    DistinctOnNotMatchingOrderBy(Vec<String>),
+    /// Indicates that a subquery (used as a value, or as the values of `IN`) selects a number of
+    /// columns other than one.
+    SubqueryColumnCountMismatch(usize),
+    /// Indicates that a row of a `VALUES` query has a number of values other than the first row.
+    ValuesColumnCountMismatch {
+        /// The number of values of the first row.
+        expected: usize,
+        /// The number of values of the mismatching row.
+        actual: usize,
+    },
+    /// Indicates that the rows of a `VALUES` query have values of different types at the same
+    /// position.
+    ValuesColumnTypeMismatch {
+        /// The (1-based) position of the column.
+        position: usize,
+        /// The type of the first value at the position.
+        expected: crate::types::column_type::ColumnType,
+        /// The type of the mismatching value.
+        actual: crate::types::column_type::ColumnType,
+    },
+    /// Indicates that the `ON` condition of a join does not reference a column of each side of
+    /// the join (e.g., `on employees.id = employees.manager_id`), containing the condition.
+    InvalidJoinCondition(String),
+    /// Indicates that the lower bound of a `BETWEEN` is greater than its upper bound
+    /// (e.g., `id between 20 and 10`).
+    InvalidBetweenRange {
+        /// The lower bound.
+        low: String,
+        /// The upper bound.
+        high: String,
+    },
+    /// Indicates that the number of parameters supplied differs from the number of placeholders
+    /// (`?`) in the query.
+    ParameterCountMismatch {
+        /// The number of placeholders in the query.
+        expected: usize,
+        /// The number of parameters supplied.
+        actual: usize,
+    },
     /// Indicates a catalog-related error during planning (e.g., table not found).
     Catalog(crate::catalog::error::CatalogError),
 }
@@ -2,24 +2,71 @@ pub(crate) mod error;
 pub(crate) mod predicate;
 
 use crate::catalog::Catalog;
-use crate::query::parser::ast::{Ast, WhereClause};
+use crate::query::parser::ast::{Ast, Expression, JoinType, Literal, SetOperator, WhereClause};
 use crate::query::parser::ordering_key::OrderingKey;
-use crate::query::parser::projection::Projection;
+use crate::query::parser::projection::{Aggregate, ProjectedColumn, Projection};
 use crate::query::plan::error::PlanningError;
-use crate::query::plan::predicate::Predicate;
+use crate::query::plan::predicate::{
+    CorrelatedSubquery, Correlation, LogicalClause, MembershipValues, Predicate,
+};
+use crate::schema::error::SchemaError;
 use crate::schema::Schema;
+use crate::types::column_type::ColumnType;
+use crate::types::column_value::ColumnValue;
+use std::convert::Infallible;
 use std::sync::Arc;
 
 /// `LogicalPlan` represents the logical steps required to execute a query.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum LogicalPlan {
     /// Plan to show table names.
     ShowTables,
+    /// Plan to explain (render) the wrapped plan instead of executing it.
+    Explain(Box<LogicalPlan>),
     /// Plan to describe a table's schema.
     DescribeTable {
         /// Name of the table.
         table_name: String,
     },
+    /// Plan to insert rows of values into a table.
+    Insert {
+        /// The name of the table.
+        table_name: String,
+        /// The optional list of columns the values are given for, all the columns otherwise.
+        columns: Option<Vec<String>>,
+        /// The rows of values to insert.
+        rows: Vec<Vec<Literal>>,
+    },
+    /// Plan to delete the rows of a table.
+    Delete {
+        /// The name of the table.
+        table_name: String,
+        /// The optional filter, the rows matching it are deleted (all the rows without it).
+        filter: Option<Predicate>,
+    },
+    /// Plan to create a table.
+    CreateTable {
+        /// The name of the table.
+        table_name: String,
+        /// The columns of the table, along with their types.
+        columns: Vec<(String, ColumnType)>,
+        /// The columns of the optional primary key, in order.
+        primary_key: Option<Vec<String>>,
+    },
+    /// Plan to drop a table.
+    DropTable {
+        /// The name of the table.
+        table_name: String,
+    },
+    /// Plan to update the rows of a table.
+    Update {
+        /// The name of the table.
+        table_name: String,
+        /// The columns to update, along with their new values.
+        assignments: Vec<(String, Literal)>,
+        /// The optional filter, the rows matching it are updated (all the rows without it).
+        filter: Option<Predicate>,
+    },
     /// Plan to scan a table.
     Scan {
         /// The name of the table to scan.
@@ -30,7 +77,19 @@ pub(crate) enum LogicalPlan {
         filter: Option<Predicate>,
         /// The schema of the table.
         schema: Arc<Schema>,
+        /// The optional pushed-down limit, the scan stops after producing these many rows.
+        limit: Option<usize>,
+    },
+    /// Plan to produce literal rows of values (`VALUES (1, 'a'), (2, 'b')`), without a table.
+    Values {
+        /// The rows of values.
+        rows: Vec<Vec<Literal>>,
+        /// The schema of the rows, with the columns named `column1`, `column2`, etc.
+        schema: Arc<Schema>,
     },
+    /// Plan to produce a single row without columns, the source of a `SELECT` without `FROM`
+    /// (e.g., `select upper('hi') as greeting`).
+    SingleRow,
     /// Plan to perform a join between two tables.
     Join {
         /// The left-hand plan.
@@ -39,13 +98,22 @@ pub(crate) enum LogicalPlan {
         right: Box<LogicalPlan>,
         /// The optional ON condition over joined rows.
         on: Option<Predicate>,
+        /// The type of the join.
+        join_type: JoinType,
     },
     /// Plan to project specific columns from a base plan.
     Projection {
         /// The source plan.
         base_plan: Box<LogicalPlan>,
-        /// The columns to project.
-        columns: Vec<String>,
+        /// The columns to project, with their optional aliases.
+        columns: Vec<ProjectedColumn>,
+    },
+    /// Plan to project all the columns of a single table or alias from a base plan.
+    QualifiedProjection {
+        /// The source plan.
+        base_plan: Box<LogicalPlan>,
+        /// The table name or alias whose columns are projected.
+        qualifier: String,
     },
     Filter {
         /// The source plan.
@@ -60,6 +128,29 @@ pub(crate) enum LogicalPlan {
         /// The limit value.
         count: usize,
     },
+    /// Plan to combine the rows of two plans selecting the same number of columns, of the same types.
+    Union {
+        /// Whether duplicate rows are kept (`UNION ALL`), they are removed otherwise.
+        all: bool,
+        /// The left-hand plan, which names the columns of the result.
+        left: Box<LogicalPlan>,
+        /// The right-hand plan.
+        right: Box<LogicalPlan>,
+    },
+    /// Plan to produce the distinct rows of the left plan which are also produced by the right plan.
+    Intersect {
+        /// The left-hand plan, which names the columns of the result.
+        left: Box<LogicalPlan>,
+        /// The right-hand plan.
+        right: Box<LogicalPlan>,
+    },
+    /// Plan to produce the distinct rows of the left plan which are not produced by the right plan.
+    Except {
+        /// The left-hand plan, which names the columns of the result.
+        left: Box<LogicalPlan>,
+        /// The right-hand plan.
+        right: Box<LogicalPlan>,
+    },
     /// Plan to order the results.
     Sort {
         /// The source plan.
@@ -69,6 +160,21 @@ pub(crate) enum LogicalPlan {
         /// Top-K limit to push down, if any.
         limit: Option<usize>,
     },
+    /// Plan to keep the first row of each distinct combination of values of the given columns
+    /// (`DISTINCT ON`), from a base plan ordered by those columns first.
+    DistinctOn {
+        /// The source (sorted) plan.
+        base_plan: Box<LogicalPlan>,
+        /// The columns whose distinct values are kept.
+        columns: Vec<String>,
+    },
+    /// Plan to aggregate all the rows of the base plan into a single row (e.g., `count(*)`).
+    Aggregate {
+        /// The source plan.
+        base_plan: Box<LogicalPlan>,
+        /// The aggregates, one per column of the result.
+        aggregates: Vec<Aggregate>,
+    },
 }
 
 impl LogicalPlan {
@@ -83,15 +189,28 @@ impl LogicalPlan {
         F: FnMut(LogicalPlan) -> LogicalPlan,
     {
         match self {
-            LogicalPlan::Join { left, right, on } => LogicalPlan::Join {
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                join_type,
+            } => LogicalPlan::Join {
                 left: Box::new(transform(*left)),
                 right: Box::new(transform(*right)),
                 on,
+                join_type,
             },
             LogicalPlan::Projection { base_plan, columns } => LogicalPlan::Projection {
                 base_plan: Box::new(transform(*base_plan)),
                 columns,
             },
+            LogicalPlan::QualifiedProjection {
+                base_plan,
+                qualifier,
+            } => LogicalPlan::QualifiedProjection {
+                base_plan: Box::new(transform(*base_plan)),
+                qualifier,
+            },
             LogicalPlan::Filter {
                 base_plan,
                 predicate,
@@ -112,9 +231,41 @@ impl LogicalPlan {
                 ordering_keys,
                 limit,
             },
+            LogicalPlan::DistinctOn { base_plan, columns } => LogicalPlan::DistinctOn {
+                base_plan: Box::new(transform(*base_plan)),
+                columns,
+            },
+            LogicalPlan::Aggregate {
+                base_plan,
+                aggregates,
+            } => LogicalPlan::Aggregate {
+                base_plan: Box::new(transform(*base_plan)),
+                aggregates,
+            },
+            LogicalPlan::Union { all, left, right } => LogicalPlan::Union {
+                all,
+                left: Box::new(transform(*left)),
+                right: Box::new(transform(*right)),
+            },
+            LogicalPlan::Intersect { left, right } => LogicalPlan::Intersect {
+                left: Box::new(transform(*left)),
+                right: Box::new(transform(*right)),
+            },
+            LogicalPlan::Except { left, right } => LogicalPlan::Except {
+                left: Box::new(transform(*left)),
+                right: Box::new(transform(*right)),
+            },
+            LogicalPlan::Explain(plan) => LogicalPlan::Explain(Box::new(transform(*plan))),
             LogicalPlan::ShowTables
             | LogicalPlan::DescribeTable { .. }
-            | LogicalPlan::Scan { .. } => self,
+            | LogicalPlan::Insert { .. }
+            | LogicalPlan::Delete { .. }
+            | LogicalPlan::Update { .. }
+            | LogicalPlan::CreateTable { .. }
+            | LogicalPlan::DropTable { .. }
+            | LogicalPlan::Scan { .. }
+            | LogicalPlan::Values { .. }
+            | LogicalPlan::SingleRow => self,
         }
     }
 
@@ -130,6 +281,8 @@ impl LogicalPlan {
                 let prefix = alias.as_ref().unwrap_or(table_name);
                 Some(Arc::new(schema.with_prefix(prefix)))
             }
+            LogicalPlan::Values { schema, .. } => Some(schema.clone()),
+            LogicalPlan::SingleRow => Some(Arc::new(Schema::new())),
             LogicalPlan::Join { left, right, .. } => {
                 let left_schema = left.schema()?;
                 let right_schema = right.schema()?;
@@ -144,11 +297,353 @@ impl LogicalPlan {
                 let projected = base_schema.project(columns);
                 Some(Arc::new(projected))
             }
+            LogicalPlan::QualifiedProjection {
+                base_plan,
+                qualifier,
+            } => {
+                let base_schema = base_plan.schema()?;
+                let columns = base_schema
+                    .column_names_with_prefix(qualifier)
+                    .into_iter()
+                    .map(ProjectedColumn::new)
+                    .collect::<Vec<_>>();
+                Some(Arc::new(base_schema.project(&columns)))
+            }
+            LogicalPlan::Aggregate { aggregates, .. } => {
+                let output_names = aggregates
+                    .iter()
+                    .map(|aggregate| aggregate.output_name())
+                    .collect::<Vec<_>>();
+                let columns = output_names
+                    .iter()
+                    .map(|name| (name.as_str(), ColumnType::Int))
+                    .collect::<Vec<_>>();
+                Some(Arc::new(Schema::new().with_computed_columns(&columns)))
+            }
+            LogicalPlan::Union { left, .. }
+            | LogicalPlan::Intersect { left, .. }
+            | LogicalPlan::Except { left, .. } => left.schema(),
             LogicalPlan::Filter { base_plan, .. }
             | LogicalPlan::Sort { base_plan, .. }
+            | LogicalPlan::DistinctOn { base_plan, .. }
             | LogicalPlan::Limit { base_plan, .. } => base_plan.schema(),
 
-            LogicalPlan::ShowTables | LogicalPlan::DescribeTable { .. } => None,
+            LogicalPlan::ShowTables
+            | LogicalPlan::DescribeTable { .. }
+            | LogicalPlan::Insert { .. }
+            | LogicalPlan::Delete { .. }
+            | LogicalPlan::Update { .. }
+            | LogicalPlan::CreateTable { .. }
+            | LogicalPlan::DropTable { .. }
+            | LogicalPlan::Explain(_) => None,
+        }
+    }
+
+    /// Replaces the references to the outer columns (of a correlated subquery) in the filters
+    /// of this plan with the given values of those columns.
+    pub(crate) fn with_outer_values(self, outer_values: &[(String, ColumnValue)]) -> LogicalPlan {
+        let substitute = |predicate: Predicate| {
+            let Ok(predicate) = predicate.try_map_literals::<Infallible, _>(&mut |literal| {
+                Ok(Self::substitute_outer_values(literal, outer_values))
+            });
+            predicate
+        };
+        match self {
+            LogicalPlan::Filter {
+                base_plan,
+                predicate,
+            } => LogicalPlan::Filter {
+                base_plan: base_plan.with_outer_values(outer_values).boxed(),
+                predicate: substitute(predicate),
+            },
+            LogicalPlan::Scan {
+                table_name,
+                alias,
+                filter,
+                schema,
+                limit,
+            } => LogicalPlan::Scan {
+                table_name,
+                alias,
+                filter: filter.map(substitute),
+                schema,
+                limit,
+            },
+            plan => plan.map_children(|child| child.with_outer_values(outer_values)),
+        }
+    }
+
+    fn substitute_outer_values(
+        literal: Literal,
+        outer_values: &[(String, ColumnValue)],
+    ) -> Literal {
+        match literal {
+            Literal::ColumnReference(ref column_name) => outer_values
+                .iter()
+                .find(|(outer_column_name, _)| outer_column_name.eq_ignore_ascii_case(column_name))
+                .map(|(_, value)| Literal::from(value.clone()))
+                .unwrap_or(literal),
+            Literal::Arithmetic { lhs, operator, rhs } => Literal::arithmetic(
+                Self::substitute_outer_values(*lhs, outer_values),
+                operator,
+                Self::substitute_outer_values(*rhs, outer_values),
+            ),
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => Literal::function_call(
+                function,
+                arguments
+                    .into_iter()
+                    .map(|argument| Self::substitute_outer_values(argument, outer_values))
+                    .collect(),
+            ),
+            _ => literal,
+        }
+    }
+
+    /// Collects the column names referenced by the filters of this plan which are not columns
+    /// of the plan filtered, but are columns of the outer schema (of a correlated subquery).
+    fn outer_column_references(&self, outer_schema: &Schema, column_names: &mut Vec<String>) {
+        match self {
+            LogicalPlan::Filter {
+                base_plan,
+                predicate,
+            } => {
+                let inner_schema = base_plan.schema();
+                for column_name in predicate.referenced_column_names() {
+                    let is_inner = inner_schema.as_ref().is_some_and(|schema| {
+                        matches!(
+                            schema.column_position(column_name),
+                            Ok(Some(_)) | Err(SchemaError::AmbiguousColumnName(_))
+                        )
+                    });
+                    let is_outer = matches!(outer_schema.column_position(column_name), Ok(Some(_)));
+                    if !is_inner && is_outer && !column_names.contains(column_name) {
+                        column_names.push(column_name.clone());
+                    }
+                }
+                base_plan.outer_column_references(outer_schema, column_names);
+            }
+            LogicalPlan::Join { left, right, .. }
+            | LogicalPlan::Union { left, right, .. }
+            | LogicalPlan::Intersect { left, right }
+            | LogicalPlan::Except { left, right } => {
+                left.outer_column_references(outer_schema, column_names);
+                right.outer_column_references(outer_schema, column_names);
+            }
+            LogicalPlan::Projection { base_plan, .. }
+            | LogicalPlan::QualifiedProjection { base_plan, .. }
+            | LogicalPlan::Limit { base_plan, .. }
+            | LogicalPlan::Sort { base_plan, .. }
+            | LogicalPlan::DistinctOn { base_plan, .. }
+            | LogicalPlan::Aggregate { base_plan, .. } => {
+                base_plan.outer_column_references(outer_schema, column_names)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Renders the plan as an indented tree, one node per line, with the children of a node
+/// indented below it (e.g., for `EXPLAIN`):
+///
+/// ```text
+/// Limit (count: 1)
+///   Projection (columns: employees.name)
+///     Scan (table: employees, filter: employees.id = 1, limit: 1)
+/// ```
+impl std::fmt::Display for LogicalPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with_depth(f, 0)
+    }
+}
+
+impl LogicalPlan {
+    fn fmt_with_depth(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let indent = "  ".repeat(depth);
+        match self {
+            LogicalPlan::ShowTables => writeln!(f, "{}ShowTables", indent),
+            LogicalPlan::DescribeTable { table_name } => {
+                writeln!(f, "{}DescribeTable (table: {})", indent, table_name)
+            }
+            LogicalPlan::Explain(plan) => {
+                writeln!(f, "{}Explain", indent)?;
+                plan.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Insert {
+                table_name,
+                columns,
+                rows,
+            } => {
+                write!(f, "{}Insert (table: {}", indent, table_name)?;
+                if let Some(columns) = columns {
+                    write!(f, ", columns: {}", columns.join(", "))?;
+                }
+                writeln!(f, ", rows: {})", rows.len())
+            }
+            LogicalPlan::Values { rows, .. } => {
+                writeln!(f, "{}Values (rows: {})", indent, rows.len())
+            }
+            LogicalPlan::SingleRow => writeln!(f, "{}SingleRow", indent),
+            LogicalPlan::Delete { table_name, filter } => {
+                write!(f, "{}Delete (table: {}", indent, table_name)?;
+                if let Some(filter) = filter {
+                    write!(f, ", filter: {}", filter)?;
+                }
+                writeln!(f, ")")
+            }
+            LogicalPlan::CreateTable {
+                table_name,
+                columns,
+                primary_key,
+            } => {
+                let columns = columns
+                    .iter()
+                    .map(|(column, column_type)| format!("{} {}", column, column_type))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "{}CreateTable (table: {}, columns: {}",
+                    indent, table_name, columns
+                )?;
+                if let Some(primary_key) = primary_key {
+                    write!(f, ", primary key: ({})", primary_key.join(", "))?;
+                }
+                writeln!(f, ")")
+            }
+            LogicalPlan::DropTable { table_name } => {
+                writeln!(f, "{}DropTable (table: {})", indent, table_name)
+            }
+            LogicalPlan::Update {
+                table_name,
+                assignments,
+                filter,
+            } => {
+                let assignments = assignments
+                    .iter()
+                    .map(|(column, value)| format!("{} = {}", column, value))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "{}Update (table: {}, set: {}",
+                    indent, table_name, assignments
+                )?;
+                if let Some(filter) = filter {
+                    write!(f, ", filter: {}", filter)?;
+                }
+                writeln!(f, ")")
+            }
+            LogicalPlan::Scan {
+                table_name,
+                alias,
+                filter,
+                limit,
+                ..
+            } => {
+                write!(f, "{}Scan (table: {}", indent, table_name)?;
+                if let Some(alias) = alias {
+                    write!(f, ", alias: {}", alias)?;
+                }
+                if let Some(filter) = filter {
+                    write!(f, ", filter: {}", filter)?;
+                }
+                if let Some(limit) = limit {
+                    write!(f, ", limit: {}", limit)?;
+                }
+                writeln!(f, ")")
+            }
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                join_type,
+            } => {
+                write!(f, "{}Join (type: {}", indent, join_type)?;
+                if let Some(on) = on {
+                    write!(f, ", on: {}", on)?;
+                }
+                writeln!(f, ")")?;
+                left.fmt_with_depth(f, depth + 1)?;
+                right.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Projection { base_plan, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| column.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                writeln!(f, "{}Projection (columns: {})", indent, columns)?;
+                base_plan.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::QualifiedProjection {
+                base_plan,
+                qualifier,
+            } => {
+                writeln!(f, "{}Projection (columns: {}.*)", indent, qualifier)?;
+                base_plan.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Filter {
+                base_plan,
+                predicate,
+            } => {
+                writeln!(f, "{}Filter (predicate: {})", indent, predicate)?;
+                base_plan.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Limit { base_plan, count } => {
+                writeln!(f, "{}Limit (count: {})", indent, count)?;
+                base_plan.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Union { all, left, right } => {
+                writeln!(f, "{}Union (all: {})", indent, all)?;
+                left.fmt_with_depth(f, depth + 1)?;
+                right.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Intersect { left, right } => {
+                writeln!(f, "{}Intersect", indent)?;
+                left.fmt_with_depth(f, depth + 1)?;
+                right.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Except { left, right } => {
+                writeln!(f, "{}Except", indent)?;
+                left.fmt_with_depth(f, depth + 1)?;
+                right.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Sort {
+                base_plan,
+                ordering_keys,
+                limit,
+            } => {
+                let ordering_keys = ordering_keys
+                    .iter()
+                    .map(|ordering_key| ordering_key.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{}Sort (keys: {}", indent, ordering_keys)?;
+                if let Some(limit) = limit {
+                    write!(f, ", limit: {}", limit)?;
+                }
+                writeln!(f, ")")?;
+                base_plan.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::DistinctOn { base_plan, columns } => {
+                writeln!(f, "{}DistinctOn (columns: {})", indent, columns.join(", "))?;
+                base_plan.fmt_with_depth(f, depth + 1)
+            }
+            LogicalPlan::Aggregate {
+                base_plan,
+                aggregates,
+            } => {
+                let aggregates = aggregates
+                    .iter()
+                    .map(|aggregate| aggregate.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                writeln!(f, "{}Aggregate (aggregates: {})", indent, aggregates)?;
+                base_plan.fmt_with_depth(f, depth + 1)
+            }
         }
     }
 }
@@ -164,27 +659,227 @@ impl LogicalPlanner {
         Self { catalog }
     }
 
+    /// Binds the placeholders (`?`) of the given `Ast` to the parameters, in order, and converts
+    /// it into a `LogicalPlan`.
+    ///
+    /// Returns `PlanningError::ParameterCountMismatch` if the number of parameters differs from
+    /// the number of placeholders.
+    pub(crate) fn plan_with_parameters(
+        &self,
+        ast: Ast,
+        parameters: &[ColumnValue],
+    ) -> Result<LogicalPlan, PlanningError> {
+        let mut placeholder_count = 0;
+        let ast = ast.map_literals(&mut |literal| match literal {
+            Literal::Parameter(index) => {
+                placeholder_count = placeholder_count.max(index + 1);
+                parameters
+                    .get(index)
+                    .map_or(literal, |value| Literal::from(value.clone()))
+            }
+            literal => literal,
+        });
+        if placeholder_count != parameters.len() {
+            return Err(PlanningError::ParameterCountMismatch {
+                expected: placeholder_count,
+                actual: parameters.len(),
+            });
+        }
+        self.plan(ast)
+    }
+
     /// Converts a given `Ast` into a `LogicalPlan`.
+    ///
+    /// The clauses of a `SELECT` are planned (and evaluated) in the order: source (`FROM`, with
+    /// its joins), filter (`WHERE`), projection, sort (`ORDER BY`), `DISTINCT ON` and limit.
+    /// The filter is evaluated over the rows of the source before the projection, so it may
+    /// reference the columns of the source which are not projected
+    /// (e.g., `select id from employees where name = 'relop'`).
     pub(crate) fn plan(&self, ast: Ast) -> Result<LogicalPlan, PlanningError> {
         match ast {
             Ast::ShowTables => Ok(LogicalPlan::ShowTables),
             Ast::DescribeTable { table_name } => Ok(LogicalPlan::DescribeTable { table_name }),
+            Ast::Explain(ast) => Ok(LogicalPlan::Explain(self.plan(*ast)?.boxed())),
+            Ast::Insert {
+                table_name,
+                columns,
+                rows,
+            } => Ok(LogicalPlan::Insert {
+                table_name,
+                columns,
+                rows,
+            }),
+            Ast::Values(rows) => Self::plan_for_values(rows),
+            Ast::Delete {
+                table_name,
+                where_clause,
+            } => {
+                let filter = where_clause
+                    .map(|where_clause| {
+                        self.plan_predicate(where_clause.0, self.table_schema(&table_name).as_ref())
+                    })
+                    .transpose()?;
+                Ok(LogicalPlan::Delete { table_name, filter })
+            }
+            Ast::CreateTable {
+                name,
+                columns,
+                primary_key,
+            } => Ok(LogicalPlan::CreateTable {
+                table_name: name,
+                columns,
+                primary_key,
+            }),
+            Ast::DropTable { table_name } => Ok(LogicalPlan::DropTable { table_name }),
+            Ast::Update {
+                table_name,
+                assignments,
+                where_clause,
+            } => {
+                let filter = where_clause
+                    .map(|where_clause| {
+                        self.plan_predicate(where_clause.0, self.table_schema(&table_name).as_ref())
+                    })
+                    .transpose()?;
+                Ok(LogicalPlan::Update {
+                    table_name,
+                    assignments,
+                    filter,
+                })
+            }
             Ast::Select {
                 source,
                 projection,
+                distinct_on,
                 where_clause,
                 limit,
                 order_by,
             } => {
                 let base_plan = self.plan_for_source(source)?;
                 let base_plan = self.plan_for_filter(where_clause, base_plan)?;
-                let base_plan = self.plan_for_projection(projection, base_plan);
-                let base_plan = self.plan_for_sort(order_by, base_plan);
+                let base_plan = self.plan_for_projection(projection, base_plan)?;
+                let base_plan = self.plan_for_sort(order_by, base_plan)?;
+                let base_plan = self.plan_for_distinct_on(distinct_on, base_plan)?;
+                Ok(self.plan_for_limit(limit, base_plan))
+            }
+            Ast::SetOperation {
+                operator,
+                left,
+                right,
+                order_by,
+                limit,
+            } => {
+                let base_plan = self.plan_for_set_operation(operator, *left, *right)?;
+                let base_plan = self.plan_for_sort(order_by, base_plan)?;
                 Ok(self.plan_for_limit(limit, base_plan))
             }
         }
     }
 
+    /// Plans the literal rows of a `VALUES` query, ensuring that all the rows have the same
+    /// number of values, and that the values at the same position have the same type.
+    ///
+    /// The type of a column is the type of its first non-null value (`text` if all are null).
+    fn plan_for_values(rows: Vec<Vec<Literal>>) -> Result<LogicalPlan, PlanningError> {
+        let column_count = rows.first().map_or(0, |row| row.len());
+        let mut column_types: Vec<Option<ColumnType>> = vec![None; column_count];
+        for row in &rows {
+            if row.len() != column_count {
+                return Err(PlanningError::ValuesColumnCountMismatch {
+                    expected: column_count,
+                    actual: row.len(),
+                });
+            }
+            for (index, (literal, column_type)) in
+                row.iter().zip(column_types.iter_mut()).enumerate()
+            {
+                match (literal.value_type(), column_type.as_ref()) {
+                    (Some(actual), Some(expected)) if &actual != expected => {
+                        return Err(PlanningError::ValuesColumnTypeMismatch {
+                            position: index + 1,
+                            expected: expected.clone(),
+                            actual,
+                        });
+                    }
+                    (Some(actual), None) => *column_type = Some(actual),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut schema = Schema::new();
+        for (index, column_type) in column_types.into_iter().enumerate() {
+            schema = schema
+                .add_column(
+                    &format!("column{}", index + 1),
+                    column_type.unwrap_or(ColumnType::Text),
+                )
+                .expect("generated column names are unique");
+        }
+        Ok(LogicalPlan::Values {
+            rows,
+            schema: Arc::new(schema),
+        })
+    }
+
+    fn plan_for_set_operation(
+        &self,
+        operator: SetOperator,
+        left: Ast,
+        right: Ast,
+    ) -> Result<LogicalPlan, PlanningError> {
+        let left = self.plan(left)?;
+        let right = self.plan(right)?;
+        Self::ensure_compatible_schemas(&left, &right)?;
+
+        let (left, right) = (left.boxed(), right.boxed());
+        Ok(match operator {
+            SetOperator::Union => LogicalPlan::Union {
+                all: false,
+                left,
+                right,
+            },
+            SetOperator::UnionAll => LogicalPlan::Union {
+                all: true,
+                left,
+                right,
+            },
+            SetOperator::Intersect => LogicalPlan::Intersect { left, right },
+            SetOperator::Except => LogicalPlan::Except { left, right },
+        })
+    }
+
+    /// Ensures that both plans of a set operation select the same number of columns, and that
+    /// the columns at the same position have the same type.
+    fn ensure_compatible_schemas(
+        left: &LogicalPlan,
+        right: &LogicalPlan,
+    ) -> Result<(), PlanningError> {
+        let left_schema = left.schema().unwrap_or_default();
+        let right_schema = right.schema().unwrap_or_default();
+        if left_schema.column_count() != right_schema.column_count() {
+            return Err(PlanningError::SetOperationColumnCountMismatch {
+                left: left_schema.column_count(),
+                right: right_schema.column_count(),
+            });
+        }
+        for (index, (left_column, right_column)) in left_schema
+            .columns()
+            .iter()
+            .zip(right_schema.columns())
+            .enumerate()
+        {
+            if left_column.column_type() != right_column.column_type() {
+                return Err(PlanningError::SetOperationColumnTypeMismatch {
+                    position: index + 1,
+                    left: left_column.column_type().clone(),
+                    right: right_column.column_type().clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
     fn plan_for_source(
         &self,
         source: crate::query::parser::ast::TableSource,
@@ -201,14 +896,25 @@ impl LogicalPlanner {
                     alias,
                     filter: None,
                     schema,
+                    limit: None,
                 })
             }
-            crate::query::parser::ast::TableSource::Join { left, right, on } => {
+            crate::query::parser::ast::TableSource::SingleRow => Ok(LogicalPlan::SingleRow),
+            crate::query::parser::ast::TableSource::Join {
+                left,
+                right,
+                on,
+                join_type,
+            } => {
                 let left_plan = self.plan_for_source(*left)?;
                 let right_plan = self.plan_for_source(*right)?;
 
                 let on_predicate = match on {
-                    Some(expression) => Some(Predicate::try_from(expression)?),
+                    Some(expression) => {
+                        let predicate = self.plan_predicate(expression, None)?;
+                        Self::ensure_valid_join_condition(&predicate, &left_plan, &right_plan)?;
+                        Some(predicate)
+                    }
                     None => None,
                 };
 
@@ -216,18 +922,75 @@ impl LogicalPlanner {
                     left: left_plan.boxed(),
                     right: right_plan.boxed(),
                     on: on_predicate,
+                    join_type,
                 })
             }
         }
     }
 
-    fn plan_for_projection(&self, projection: Projection, base_plan: LogicalPlan) -> LogicalPlan {
+    /// Ensures that the `ON` condition of a join references at least one column of each side,
+    /// catching the accidental conditions over a single table.
+    fn ensure_valid_join_condition(
+        on: &Predicate,
+        left: &LogicalPlan,
+        right: &LogicalPlan,
+    ) -> Result<(), PlanningError> {
+        let left_schema = left.schema().unwrap_or_default();
+        let right_schema = right.schema().unwrap_or_default();
+        let referenced_column_names = on.referenced_column_names();
+        let references = |schema: &Schema| {
+            referenced_column_names
+                .iter()
+                .any(|column_name| schema.has_column(column_name))
+        };
+        if references(&left_schema) && references(&right_schema) {
+            return Ok(());
+        }
+        Err(PlanningError::InvalidJoinCondition(on.to_string()))
+    }
+
+    fn plan_for_projection(
+        &self,
+        projection: Projection,
+        base_plan: LogicalPlan,
+    ) -> Result<LogicalPlan, PlanningError> {
         match projection {
-            Projection::All => base_plan,
-            Projection::Columns(columns) => LogicalPlan::Projection {
+            Projection::All => Ok(base_plan),
+            Projection::Columns(columns) => Ok(LogicalPlan::Projection {
                 base_plan: base_plan.boxed(),
                 columns,
-            },
+            }),
+            Projection::QualifiedAll(qualifier) => Ok(LogicalPlan::QualifiedProjection {
+                base_plan: base_plan.boxed(),
+                qualifier,
+            }),
+            Projection::Aggregates(aggregates) => {
+                for column in aggregates.iter().filter_map(|aggregate| aggregate.column()) {
+                    Self::ensure_aggregated_column_exists(column, &base_plan)?;
+                }
+                Ok(LogicalPlan::Aggregate {
+                    base_plan: base_plan.boxed(),
+                    aggregates,
+                })
+            }
+        }
+    }
+
+    /// Ensures that the column of an aggregate (e.g., `count(city)`) is a single column of the
+    /// output of the `base_plan`.
+    fn ensure_aggregated_column_exists(
+        column: &str,
+        base_plan: &LogicalPlan,
+    ) -> Result<(), PlanningError> {
+        let Some(schema) = base_plan.schema() else {
+            return Ok(());
+        };
+        match schema.column_position(column) {
+            Ok(Some(_)) => Ok(()),
+            Err(SchemaError::AmbiguousColumnName(column_name)) => {
+                Err(PlanningError::AmbiguousColumn(column_name))
+            }
+            _ => Err(PlanningError::ColumnNotFound(column.to_string())),
         }
     }
 
@@ -237,34 +1000,252 @@ impl LogicalPlanner {
         base_plan: LogicalPlan,
     ) -> Result<LogicalPlan, PlanningError> {
         if let Some(clause) = where_clause {
+            let predicate = self.plan_predicate(clause.0, base_plan.schema().as_deref())?;
+            Self::ensure_unambiguous_columns(&predicate, &base_plan)?;
             return Ok(LogicalPlan::Filter {
                 base_plan: base_plan.boxed(),
-                predicate: Predicate::try_from(clause)?,
+                predicate,
             });
         }
         Ok(base_plan)
     }
 
-    fn plan_for_sort(
-        &self,
-        order_by: Option<Vec<OrderingKey>>,
-        base_plan: LogicalPlan,
-    ) -> LogicalPlan {
-        if let Some(keys) = order_by {
-            return LogicalPlan::Sort {
-                base_plan: base_plan.boxed(),
-                ordering_keys: keys,
-                limit: None,
-            };
+    /// Ensures that no column referenced by the `predicate` is an unqualified name matching a
+    /// column of more than one joined table of the `base_plan`.
+    ///
+    /// The filter may later be pushed down to one side of a join, where the name is no longer
+    /// ambiguous, so the check is made against the schema of the whole `base_plan`.
+    fn ensure_unambiguous_columns(
+        predicate: &Predicate,
+        base_plan: &LogicalPlan,
+    ) -> Result<(), PlanningError> {
+        let Some(schema) = base_plan.schema() else {
+            return Ok(());
+        };
+        for column_name in predicate.referenced_column_names() {
+            if let Err(SchemaError::AmbiguousColumnName(column_name)) =
+                schema.column_position(column_name)
+            {
+                return Err(PlanningError::AmbiguousColumn(column_name));
+            }
         }
-        base_plan
+        Ok(())
     }
 
-    fn plan_for_limit(&self, limit: Option<usize>, base_plan: LogicalPlan) -> LogicalPlan {
-        if let Some(value) = limit {
-            return LogicalPlan::Limit {
-                base_plan: base_plan.boxed(),
-                count: value,
+    /// Converts the expression into a `Predicate`, planning the subqueries it contains.
+    ///
+    /// The subquery of an existence clause (`EXISTS`) may refer to the columns of the outer
+    /// schema (the schema of the rows the predicate filters), these references are its correlations.
+    fn plan_predicate(
+        &self,
+        expression: Expression,
+        outer_schema: Option<&Schema>,
+    ) -> Result<Predicate, PlanningError> {
+        Predicate::try_from(expression)?.try_map_clauses(&mut |clause| match clause {
+            LogicalClause::Exists {
+                subquery: CorrelatedSubquery::Subquery(query),
+            } => {
+                let plan = self.plan(*query)?;
+                let mut column_names = Vec::new();
+                if let Some(outer_schema) = outer_schema {
+                    plan.outer_column_references(outer_schema, &mut column_names);
+                }
+                Ok(LogicalClause::Exists {
+                    subquery: CorrelatedSubquery::Planned {
+                        plan: plan.boxed(),
+                        correlations: column_names
+                            .iter()
+                            .map(|column_name| Correlation::new(column_name))
+                            .collect(),
+                    },
+                })
+            }
+            LogicalClause::In {
+                lhs,
+                values: MembershipValues::Subquery(query),
+                negated,
+            } => Ok(LogicalClause::In {
+                lhs: self.plan_subqueries(lhs)?,
+                values: MembershipValues::PlannedSubquery(self.plan_subquery(*query)?.boxed()),
+                negated,
+            }),
+            clause => clause.try_map_literals(&mut |literal| self.plan_subqueries(literal)),
+        })
+    }
+
+    /// Returns the schema of the table (with its columns prefixed by the table name), if it exists.
+    fn table_schema(&self, table_name: &str) -> Option<Schema> {
+        self.catalog
+            .schema_for(table_name)
+            .ok()
+            .map(|schema| schema.with_prefix(table_name))
+    }
+
+    /// Plans the subquery, which must select exactly one column.
+    fn plan_subquery(&self, query: Ast) -> Result<LogicalPlan, PlanningError> {
+        let plan = self.plan(query)?;
+        let column_count = plan.schema().map_or(0, |schema| schema.column_count());
+        if column_count != 1 {
+            return Err(PlanningError::SubqueryColumnCountMismatch(column_count));
+        }
+        Ok(plan)
+    }
+
+    /// Replaces the subqueries in the literal (including the ones in the operands of an
+    /// arithmetic expression and in the arguments of a function call) with their plans.
+    ///
+    fn plan_subqueries(&self, literal: Literal) -> Result<Literal, PlanningError> {
+        match literal {
+            Literal::Subquery(query) => Ok(Literal::PlannedSubquery(
+                self.plan_subquery(*query)?.boxed(),
+            )),
+            Literal::Arithmetic { lhs, operator, rhs } => Ok(Literal::arithmetic(
+                self.plan_subqueries(*lhs)?,
+                operator,
+                self.plan_subqueries(*rhs)?,
+            )),
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => Ok(Literal::function_call(
+                function,
+                arguments
+                    .into_iter()
+                    .map(|argument| self.plan_subqueries(argument))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            _ => Ok(literal),
+        }
+    }
+
+    fn plan_for_sort(
+        &self,
+        order_by: Option<Vec<OrderingKey>>,
+        base_plan: LogicalPlan,
+    ) -> Result<LogicalPlan, PlanningError> {
+        if let Some(keys) = order_by {
+            let ordering_keys = keys
+                .into_iter()
+                .map(|key| {
+                    let key = self.resolve_ordinal(key, &base_plan)?;
+                    Self::resolve_ordering_column(key, &base_plan)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            return Ok(LogicalPlan::Sort {
+                base_plan: base_plan.boxed(),
+                ordering_keys,
+                limit: None,
+            });
+        }
+        Ok(base_plan)
+    }
+
+    /// Plans `DISTINCT ON` over the (sorted) `base_plan`, ensuring that its columns are the
+    /// leading columns of the ORDER BY, in any order (e.g., `distinct on (city)` with
+    /// `order by city, name`), so that the rows sharing their values are adjacent.
+    fn plan_for_distinct_on(
+        &self,
+        distinct_on: Option<Vec<String>>,
+        base_plan: LogicalPlan,
+    ) -> Result<LogicalPlan, PlanningError> {
+        let Some(columns) = distinct_on else {
+            return Ok(base_plan);
+        };
+        let leading_ordering_columns = match &base_plan {
+            LogicalPlan::Sort { ordering_keys, .. } if ordering_keys.len() >= columns.len() => {
+                &ordering_keys[..columns.len()]
+            }
+            _ => return Err(PlanningError::DistinctOnNotMatchingOrderBy(columns)),
+        };
+        let all_leading = columns.iter().all(|column| {
+            leading_ordering_columns
+                .iter()
+                .any(|key| key.column.eq_ignore_ascii_case(column))
+        });
+        if !all_leading {
+            return Err(PlanningError::DistinctOnNotMatchingOrderBy(columns));
+        }
+        Ok(LogicalPlan::DistinctOn {
+            base_plan: base_plan.boxed(),
+            columns,
+        })
+    }
+
+    /// Resolves an ordering key given by position (e.g., `order by 2`) to the name of the
+    /// column at that (1-based) position in the output of the `base_plan`.
+    fn resolve_ordinal(
+        &self,
+        key: OrderingKey,
+        base_plan: &LogicalPlan,
+    ) -> Result<OrderingKey, PlanningError> {
+        let Some(ordinal) = key.ordinal else {
+            return Ok(key);
+        };
+        let schema = base_plan.schema().unwrap_or_default();
+        let column_count = schema.column_count();
+
+        if ordinal == 0 || ordinal > column_count {
+            return Err(PlanningError::OrderByOrdinalOutOfRange {
+                ordinal,
+                column_count,
+            });
+        }
+        Ok(key.resolved_to(schema.columns()[ordinal - 1].name()))
+    }
+
+    /// Ensures that the column of an ordering key is a single column of the output of the
+    /// `base_plan`, or, for a projection, a single column of its input (which is kept for sorting).
+    ///
+    /// A projected column whose (unqualified) name is ambiguous among the columns of the input
+    /// (e.g., `order by id` for `select t.id from t join u on t.id = u.id`) is resolved to the
+    /// name of the projected column, so that the sort finds it among the kept input columns.
+    fn resolve_ordering_column(
+        key: OrderingKey,
+        base_plan: &LogicalPlan,
+    ) -> Result<OrderingKey, PlanningError> {
+        let column_in = |plan: &LogicalPlan| {
+            let Some(schema) = plan.schema() else {
+                return Ok(Some(key.column.clone()));
+            };
+            match schema.column_position(&key.column) {
+                Ok(position) => {
+                    Ok(position.map(|position| schema.columns()[position].name().to_string()))
+                }
+                Err(SchemaError::AmbiguousColumnName(column_name)) => {
+                    Err(PlanningError::AmbiguousColumn(column_name))
+                }
+                Err(_) => Ok(None),
+            }
+        };
+        let found = match base_plan {
+            LogicalPlan::Projection {
+                base_plan: input, ..
+            }
+            | LogicalPlan::QualifiedProjection {
+                base_plan: input, ..
+            } => match column_in(base_plan)? {
+                Some(projected_column) => {
+                    if let Err(PlanningError::AmbiguousColumn(_)) = column_in(input) {
+                        return Ok(key.resolved_to(projected_column));
+                    }
+                    true
+                }
+                None => column_in(input)?.is_some(),
+            },
+            _ => column_in(base_plan)?.is_some(),
+        };
+        if found {
+            return Ok(key);
+        }
+        Err(PlanningError::ColumnNotFound(key.column.clone()))
+    }
+
+    fn plan_for_limit(&self, limit: Option<usize>, base_plan: LogicalPlan) -> LogicalPlan {
+        if let Some(value) = limit {
+            return LogicalPlan::Limit {
+                base_plan: base_plan.boxed(),
+                count: value,
             };
         }
         base_plan
@@ -292,6 +1273,7 @@ impl LogicalPlan {
             alias: None,
             filter: None,
             schema: Arc::new(Schema::new()),
+            limit: None,
         }
     }
 
@@ -299,7 +1281,7 @@ impl LogicalPlan {
     pub(crate) fn project<T: Into<String>>(self, columns: Vec<T>) -> Self {
         LogicalPlan::Projection {
             base_plan: self.boxed(),
-            columns: columns.into_iter().map(|column| column.into()).collect(),
+            columns: columns.into_iter().map(ProjectedColumn::new).collect(),
         }
     }
 
@@ -328,12 +1310,23 @@ impl LogicalPlan {
         }
     }
 
-    /// Creates a join plan.
+    /// Creates an inner join plan.
     pub(crate) fn join(self, right: LogicalPlan, on: Option<Predicate>) -> Self {
+        self.join_of_type(right, on, JoinType::Inner)
+    }
+
+    /// Creates a join plan with the given join type.
+    pub(crate) fn join_of_type(
+        self,
+        right: LogicalPlan,
+        on: Option<Predicate>,
+        join_type: JoinType,
+    ) -> Self {
         LogicalPlan::Join {
             left: self.boxed(),
             right: right.boxed(),
             on,
+            join_type,
         }
     }
 }
@@ -342,6 +1335,7 @@ impl LogicalPlan {
 mod tests {
     use super::*;
     use crate::query::parser::ast::{BinaryOperator, Literal};
+    use crate::query::parser::ordering_key::OrderingDirection;
     use crate::query::parser::projection::Projection;
     use crate::query::plan::predicate::LogicalOperator;
     use crate::types::column_type::ColumnType;
@@ -360,6 +1354,12 @@ mod tests {
         catalog
             .create_table("roles", schema!["id" => ColumnType::Int].unwrap())
             .unwrap();
+        catalog
+            .create_table(
+                "projects",
+                schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap(),
+            )
+            .unwrap();
 
         LogicalPlanner::new(catalog)
     }
@@ -383,12 +1383,31 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn logical_plan_for_explain() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Explain(Box::new(Ast::Select {
+                source: crate::query::parser::ast::TableSource::table("employees"),
+                projection: Projection::All,
+                distinct_on: None,
+                where_clause: None,
+                order_by: None,
+                limit: None,
+            })))
+            .unwrap();
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Explain(plan) if matches!(plan.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "employees")
+        ));
+    }
+
     #[test]
     fn logical_plan_for_select_all() {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -406,6 +1425,7 @@ mod tests {
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -413,7 +1433,7 @@ mod tests {
             .unwrap();
         assert!(matches!(
             logical_plan,
-            LogicalPlan::Scan { table_name, alias: _alias, filter: _filter, schema } if table_name == "employees"
+            LogicalPlan::Scan { table_name, alias: _alias, filter: _filter, schema, .. } if table_name == "employees"
             &&
             *schema == schema!["id" => ColumnType::Int].unwrap()
         ));
@@ -424,7 +1444,8 @@ mod tests {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
-                projection: Projection::Columns(vec!["id".to_string()]),
+                projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -432,7 +1453,7 @@ mod tests {
             .unwrap();
         assert!(matches!(
             logical_plan,
-            LogicalPlan::Projection {base_plan: _, columns } if columns.iter().eq(&["id"])
+            LogicalPlan::Projection {base_plan: _, columns } if *columns == vec![ProjectedColumn::new("id")]
         ));
     }
 
@@ -441,7 +1462,8 @@ mod tests {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
-                projection: Projection::Columns(vec!["id".to_string()]),
+                projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -460,6 +1482,7 @@ mod tests {
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: Some(WhereClause::comparison(
                     Literal::ColumnReference("age".to_string()),
                     BinaryOperator::Greater,
@@ -484,7 +1507,8 @@ mod tests {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
-                projection: Projection::Columns(vec![String::from("id")]),
+                projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+                distinct_on: None,
                 where_clause: Some(WhereClause::comparison(
                     Literal::ColumnReference("age".to_string()),
                     BinaryOperator::Greater,
@@ -497,7 +1521,7 @@ mod tests {
 
         assert!(matches!(
             logical_plan,
-            LogicalPlan::Projection {base_plan, columns} if columns == vec!["id"]
+            LogicalPlan::Projection {base_plan, columns} if columns == vec![ProjectedColumn::new("id")]
                 && matches!(
                 base_plan.as_ref(),
                 LogicalPlan::Filter { base_plan, predicate }
@@ -514,6 +1538,7 @@ mod tests {
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: Some(vec![asc!("id")]),
                 limit: None,
@@ -532,6 +1557,7 @@ mod tests {
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: Some(vec![desc!("id")]),
                 limit: None,
@@ -548,8 +1574,9 @@ mod tests {
     fn logical_plan_for_select_with_order_by_multiple_columns() {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
-                source: crate::query::parser::ast::TableSource::table("employees"),
+                source: crate::query::parser::ast::TableSource::table("projects"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: Some(vec![asc!("id"), desc!("name")]),
                 limit: None,
@@ -559,7 +1586,154 @@ mod tests {
             logical_plan,
             LogicalPlan::Sort {base_plan, ordering_keys, limit: _ }
                 if ordering_keys == vec![asc!("id"), desc!("name")] &&
-                    matches!(base_plan.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "employees") ));
+                    matches!(base_plan.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "projects") ));
+    }
+
+    #[test]
+    fn logical_plan_for_select_with_distinct_on() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Select {
+                source: crate::query::parser::ast::TableSource::table("projects"),
+                projection: Projection::All,
+                distinct_on: Some(vec!["name".to_string()]),
+                where_clause: None,
+                order_by: Some(vec![asc!("name"), desc!("id")]),
+                limit: Some(2),
+            })
+            .unwrap();
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Limit { base_plan, count: 2 }
+                if matches!(base_plan.as_ref(), LogicalPlan::DistinctOn { base_plan, columns }
+                    if *columns == vec!["name".to_string()] &&
+                        matches!(base_plan.as_ref(), LogicalPlan::Sort { .. }))
+        ));
+    }
+
+    #[test]
+    fn logical_plan_for_select_with_distinct_on_leading_columns_in_any_order() {
+        let logical_plan = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::table("projects"),
+            projection: Projection::All,
+            distinct_on: Some(vec!["name".to_string(), "id".to_string()]),
+            where_clause: None,
+            order_by: Some(vec![asc!("id"), asc!("name")]),
+            limit: None,
+        });
+        assert!(matches!(logical_plan, Ok(LogicalPlan::DistinctOn { .. })));
+    }
+
+    #[test]
+    fn attempt_to_plan_distinct_on_without_order_by() {
+        let result = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::table("projects"),
+            projection: Projection::All,
+            distinct_on: Some(vec!["name".to_string()]),
+            where_clause: None,
+            order_by: None,
+            limit: None,
+        });
+        assert!(matches!(
+            result,
+            Err(PlanningError::DistinctOnNotMatchingOrderBy(columns)) if columns == vec!["name".to_string()]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_distinct_on_not_leading_the_order_by() {
+        let result = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::table("projects"),
+            projection: Projection::All,
+            distinct_on: Some(vec!["name".to_string()]),
+            where_clause: None,
+            order_by: Some(vec![asc!("id"), asc!("name")]),
+            limit: None,
+        });
+        assert!(matches!(
+            result,
+            Err(PlanningError::DistinctOnNotMatchingOrderBy(columns)) if columns == vec!["name".to_string()]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_distinct_on_with_more_columns_than_the_order_by() {
+        let result = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::table("projects"),
+            projection: Projection::All,
+            distinct_on: Some(vec!["name".to_string(), "id".to_string()]),
+            where_clause: None,
+            order_by: Some(vec![asc!("name")]),
+            limit: None,
+        });
+        assert!(matches!(
+            result,
+            Err(PlanningError::DistinctOnNotMatchingOrderBy(_))
+        ));
+    }
+
+    #[test]
+    fn logical_plan_for_select_without_from() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Select {
+                source: crate::query::parser::ast::TableSource::SingleRow,
+                projection: Projection::Columns(vec![ProjectedColumn::computed(
+                    Literal::Int(1),
+                    "one",
+                )]),
+                distinct_on: None,
+                where_clause: None,
+                order_by: None,
+                limit: None,
+            })
+            .unwrap();
+
+        assert_eq!(vec!["one"], logical_plan.schema().unwrap().column_names());
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Projection { base_plan, .. } if matches!(base_plan.as_ref(), LogicalPlan::SingleRow)
+        ));
+    }
+
+    #[test]
+    fn logical_plan_for_select_with_aggregates() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Select {
+                source: crate::query::parser::ast::TableSource::table("projects"),
+                projection: Projection::Aggregates(vec![
+                    Aggregate::count_all(),
+                    Aggregate::count_distinct("name").with_alias("names"),
+                ]),
+                distinct_on: None,
+                where_clause: None,
+                order_by: None,
+                limit: None,
+            })
+            .unwrap();
+
+        let schema = logical_plan.schema().unwrap();
+        assert_eq!(vec!["count(*)", "names"], schema.column_names());
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Aggregate { base_plan, aggregates }
+                if aggregates.len() == 2 &&
+                    matches!(base_plan.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "projects")
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_an_aggregate_of_an_unknown_column() {
+        let result = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::table("projects"),
+            projection: Projection::Aggregates(vec![Aggregate::count("unknown")]),
+            distinct_on: None,
+            where_clause: None,
+            order_by: None,
+            limit: None,
+        });
+        assert!(matches!(
+            result,
+            Err(PlanningError::ColumnNotFound(column)) if column == "unknown"
+        ));
     }
 
     #[test]
@@ -568,6 +1742,7 @@ mod tests {
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: Some(10),
@@ -585,6 +1760,7 @@ mod tests {
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: Some(10),
@@ -601,7 +1777,8 @@ mod tests {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
-                projection: Projection::Columns(vec![String::from("id")]),
+                projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: Some(10),
@@ -618,7 +1795,8 @@ mod tests {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
-                projection: Projection::Columns(vec![String::from("id")]),
+                projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: Some(10),
@@ -628,7 +1806,7 @@ mod tests {
             logical_plan,
             LogicalPlan::Limit {base_plan, count: _ }
                 if matches!(base_plan.as_ref(), LogicalPlan::Projection { base_plan: _, columns }
-                if columns.iter().eq(&[String::from("id")]) )
+                if *columns == vec![ProjectedColumn::new("id")])
         ));
     }
 
@@ -637,7 +1815,8 @@ mod tests {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
-                projection: Projection::Columns(vec![String::from("id")]),
+                projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: Some(10),
@@ -655,8 +1834,9 @@ mod tests {
     fn logical_plan_for_select_with_order_by_and_limit() {
         let logical_plan = planner_for_tests()
             .plan(Ast::Select {
-                source: crate::query::parser::ast::TableSource::table("employees"),
+                source: crate::query::parser::ast::TableSource::table("projects"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: Some(vec![asc!("id"), desc!("name")]),
                 limit: Some(10),
@@ -667,11 +1847,191 @@ mod tests {
             LogicalPlan::Limit {base_plan, count}
                 if count == 10 && matches!(base_plan.as_ref(), LogicalPlan::Sort { base_plan, ordering_keys, limit: _ }
                     if *ordering_keys == vec![asc!("id"), desc!("name")] &&
-                        matches!(base_plan.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "employees")
+                        matches!(base_plan.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "projects")
             )
         ));
     }
 
+    #[test]
+    fn logical_plan_for_select_with_order_by_ordinal() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Select {
+                source: crate::query::parser::ast::TableSource::table("employees"),
+                projection: Projection::Columns(vec![ProjectedColumn::aliased(
+                    "id",
+                    "employee_id",
+                )]),
+                distinct_on: None,
+                where_clause: None,
+                order_by: Some(vec![OrderingKey::by_ordinal(
+                    1,
+                    OrderingDirection::Descending,
+                )]),
+                limit: None,
+            })
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Sort { base_plan, ordering_keys, limit: _ }
+                if ordering_keys == vec![desc!("employee_id")] &&
+                    matches!(base_plan.as_ref(), LogicalPlan::Projection { .. })
+        ));
+    }
+
+    #[test]
+    fn logical_plan_for_select_star_with_order_by_ordinal() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Select {
+                source: crate::query::parser::ast::TableSource::table("employees"),
+                projection: Projection::All,
+                distinct_on: None,
+                where_clause: None,
+                order_by: Some(vec![OrderingKey::by_ordinal(
+                    1,
+                    OrderingDirection::Ascending,
+                )]),
+                limit: None,
+            })
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Sort { ordering_keys, .. } if ordering_keys == vec![asc!("employees.id")]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_select_with_order_by_ordinal_out_of_range() {
+        for ordinal in [0, 2] {
+            let result = planner_for_tests().plan(Ast::Select {
+                source: crate::query::parser::ast::TableSource::table("employees"),
+                projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+                distinct_on: None,
+                where_clause: None,
+                order_by: Some(vec![OrderingKey::by_ordinal(
+                    ordinal,
+                    OrderingDirection::Ascending,
+                )]),
+                limit: None,
+            });
+
+            assert_eq!(
+                Err(PlanningError::OrderByOrdinalOutOfRange {
+                    ordinal,
+                    column_count: 1
+                }),
+                result.map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn logical_plan_for_select_with_order_by_a_column_not_projected() {
+        let logical_plan = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::table("projects"),
+            projection: Projection::Columns(vec![ProjectedColumn::new("name")]),
+            distinct_on: None,
+            where_clause: None,
+            order_by: Some(vec![desc!("id")]),
+            limit: None,
+        });
+
+        assert!(matches!(
+            logical_plan,
+            Ok(LogicalPlan::Sort { ordering_keys, .. }) if ordering_keys == vec![desc!("id")]
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_select_with_order_by_an_unknown_column() {
+        let result = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::table("employees"),
+            projection: Projection::All,
+            distinct_on: None,
+            where_clause: None,
+            order_by: Some(vec![asc!("unknown")]),
+            limit: None,
+        });
+
+        assert_eq!(
+            Err(PlanningError::ColumnNotFound("unknown".to_string())),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn attempt_to_plan_select_with_order_by_an_ambiguous_column_in_join() {
+        let result = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::Join {
+                left: Box::new(crate::query::parser::ast::TableSource::table("employees")),
+                right: Box::new(crate::query::parser::ast::TableSource::table("departments")),
+                on: None,
+                join_type: JoinType::Cross,
+            },
+            projection: Projection::All,
+            distinct_on: None,
+            where_clause: None,
+            order_by: Some(vec![asc!("id")]),
+            limit: None,
+        });
+
+        assert_eq!(
+            Err(PlanningError::AmbiguousColumn("id".to_string())),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn attempt_to_plan_select_with_where_clause_on_an_ambiguous_column_in_join() {
+        let result = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::Join {
+                left: Box::new(crate::query::parser::ast::TableSource::table("employees")),
+                right: Box::new(crate::query::parser::ast::TableSource::table("departments")),
+                on: None,
+                join_type: JoinType::Cross,
+            },
+            projection: Projection::All,
+            distinct_on: None,
+            where_clause: Some(WhereClause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Int(1),
+            )),
+            order_by: None,
+            limit: None,
+        });
+
+        assert_eq!(
+            Err(PlanningError::AmbiguousColumn("id".to_string())),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_select_with_order_by_a_projected_column_ambiguous_in_join() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Select {
+                source: crate::query::parser::ast::TableSource::Join {
+                    left: Box::new(crate::query::parser::ast::TableSource::table("employees")),
+                    right: Box::new(crate::query::parser::ast::TableSource::table("departments")),
+                    on: None,
+                    join_type: JoinType::Cross,
+                },
+                projection: Projection::Columns(vec![ProjectedColumn::new("employees.id")]),
+                distinct_on: None,
+                where_clause: None,
+                order_by: Some(vec![asc!("id")]),
+                limit: None,
+            })
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Sort { ordering_keys, .. } if ordering_keys == vec![asc!("employees.id")]
+        ));
+    }
+
     #[test]
     fn logical_plan_for_select_with_join() {
         use crate::query::parser::ast::Clause;
@@ -683,13 +2043,15 @@ mod tests {
                     right: Box::new(crate::query::parser::ast::TableSource::table("departments")),
                     on: Some(crate::query::parser::ast::Expression::Single(
                         Clause::Comparison {
-                            lhs: Literal::ColumnReference("employee_id".to_string()),
+                            lhs: Literal::ColumnReference("employees.id".to_string()),
                             operator: BinaryOperator::Eq,
-                            rhs: Literal::ColumnReference("department_id".to_string()),
+                            rhs: Literal::ColumnReference("departments.id".to_string()),
                         },
                     )),
+                    join_type: JoinType::Inner,
                 },
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -698,15 +2060,15 @@ mod tests {
 
         assert!(matches!(
             logical_plan,
-            LogicalPlan::Join { left, right, on }
+            LogicalPlan::Join { left, right, on, join_type: JoinType::Inner }
             if matches!(left.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "employees")
             && matches!(right.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "departments")
             && matches!(
                 on.as_ref().unwrap(),
                 Predicate::Single(predicate::LogicalClause::Comparison { lhs, operator, rhs })
-                if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employee_id")
+                if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employees.id")
                 && *operator == LogicalOperator::Eq
-                && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "department_id")
+                && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "departments.id")
             )
         ));
     }
@@ -722,13 +2084,15 @@ mod tests {
                     right: Box::new(crate::query::parser::ast::TableSource::table("departments")),
                     on: Some(crate::query::parser::ast::Expression::Single(
                         Clause::Comparison {
-                            lhs: Literal::ColumnReference("employee_id".to_string()),
+                            lhs: Literal::ColumnReference("employees.id".to_string()),
                             operator: BinaryOperator::Eq,
-                            rhs: Literal::ColumnReference("department_id".to_string()),
+                            rhs: Literal::ColumnReference("departments.id".to_string()),
                         },
                     )),
+                    join_type: JoinType::Inner,
                 },
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: Some(WhereClause(crate::query::parser::ast::Expression::Single(
                     Clause::Comparison {
                         lhs: Literal::ColumnReference("status".to_string()),
@@ -746,15 +2110,15 @@ mod tests {
             LogicalPlan::Filter { base_plan, predicate }
             if matches!(
                 base_plan.as_ref(),
-                LogicalPlan::Join { left, right, on }
+                LogicalPlan::Join { left, right, on, join_type: JoinType::Inner }
                 if matches!(left.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "employees")
                 && matches!(right.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "departments")
                 && matches!(
                     on.as_ref().unwrap(),
                     Predicate::Single(predicate::LogicalClause::Comparison { lhs, operator, rhs })
-                    if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employee_id")
+                    if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employees.id")
                     && *operator == LogicalOperator::Eq
-                    && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "department_id")
+                    && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "departments.id")
                 )
             )
             && matches!(
@@ -781,22 +2145,25 @@ mod tests {
                         )),
                         on: Some(crate::query::parser::ast::Expression::Single(
                             Clause::Comparison {
-                                lhs: Literal::ColumnReference("employee_id".to_string()),
+                                lhs: Literal::ColumnReference("employees.id".to_string()),
                                 operator: BinaryOperator::Eq,
-                                rhs: Literal::ColumnReference("department_id".to_string()),
+                                rhs: Literal::ColumnReference("departments.id".to_string()),
                             },
                         )),
+                        join_type: JoinType::Inner,
                     }),
                     right: Box::new(crate::query::parser::ast::TableSource::table("roles")),
                     on: Some(crate::query::parser::ast::Expression::Single(
                         Clause::Comparison {
-                            lhs: Literal::ColumnReference("role_id".to_string()),
+                            lhs: Literal::ColumnReference("roles.id".to_string()),
                             operator: BinaryOperator::Eq,
                             rhs: Literal::ColumnReference("id".to_string()),
                         },
                     )),
+                    join_type: JoinType::Inner,
                 },
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -808,30 +2175,28 @@ mod tests {
             LogicalPlan::Join {
                 left: left_outer,
                 right: right_outer,
-                on: on_outer
-            }
+                on: on_outer, join_type: JoinType::Inner }
             if matches!(
                 left_outer.as_ref(),
                 LogicalPlan::Join {
                     left: left_inner,
                     right: right_inner,
-                    on: on_inner
-                }
+                    on: on_inner, join_type: JoinType::Inner }
                 if matches!(left_inner.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "employees")
                 && matches!(right_inner.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "departments")
                 && matches!(
                     on_inner.as_ref().unwrap(),
                     Predicate::Single(predicate::LogicalClause::Comparison { lhs, operator, rhs })
-                    if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employee_id")
+                    if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "employees.id")
                     && *operator == LogicalOperator::Eq
-                    && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "department_id")
+                    && matches!(rhs, Literal::ColumnReference(column_name) if column_name == "departments.id")
                 )
             )
             && matches!(right_outer.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "roles")
             && matches!(
                 on_outer.as_ref().unwrap(),
                 Predicate::Single(predicate::LogicalClause::Comparison { lhs, operator, .. })
-                if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "role_id")
+                if matches!(lhs, Literal::ColumnReference(column_name) if column_name == "roles.id")
                 && *operator == LogicalOperator::Eq
             )
         ));
@@ -843,6 +2208,7 @@ mod tests {
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table_with_alias("employees", "e"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -873,11 +2239,13 @@ mod tests {
                         Clause::Comparison {
                             lhs: Literal::ColumnReference("e.id".to_string()),
                             operator: BinaryOperator::Eq,
-                            rhs: Literal::ColumnReference("d.employee_id".to_string()),
+                            rhs: Literal::ColumnReference("d.id".to_string()),
                         },
                     )),
+                    join_type: JoinType::Inner,
                 },
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -893,18 +2261,83 @@ mod tests {
     }
 
     #[test]
-    fn map_children_projection() {
-        let plan = LogicalPlan::scan("employees").project(vec!["id"]);
-        let transformed = plan.map_children(|logical_plan| match logical_plan {
-            LogicalPlan::Scan { table_name, .. } => {
-                LogicalPlan::scan(format!("{}_transformed", table_name))
-            }
+    fn logical_plan_for_select_with_join_on_condition_referencing_both_tables() {
+        use crate::query::parser::ast::Clause;
+
+        let logical_plan = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::Join {
+                left: Box::new(crate::query::parser::ast::TableSource::table("employees")),
+                right: Box::new(crate::query::parser::ast::TableSource::table("departments")),
+                on: Some(crate::query::parser::ast::Expression::And(vec![
+                    crate::query::parser::ast::Expression::Single(Clause::Comparison {
+                        lhs: Literal::ColumnReference("employees.id".to_string()),
+                        operator: BinaryOperator::Greater,
+                        rhs: Literal::Int(1),
+                    }),
+                    crate::query::parser::ast::Expression::Single(Clause::Comparison {
+                        lhs: Literal::ColumnReference("departments.id".to_string()),
+                        operator: BinaryOperator::Eq,
+                        rhs: Literal::ColumnReference("employees.id".to_string()),
+                    }),
+                ])),
+                join_type: JoinType::Inner,
+            },
+            projection: Projection::All,
+            distinct_on: None,
+            where_clause: None,
+            order_by: None,
+            limit: None,
+        });
+
+        assert!(matches!(
+            logical_plan,
+            Ok(LogicalPlan::Join { on: Some(_), .. })
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_select_with_join_on_condition_referencing_a_single_table() {
+        use crate::query::parser::ast::Clause;
+
+        let logical_plan = planner_for_tests().plan(Ast::Select {
+            source: crate::query::parser::ast::TableSource::Join {
+                left: Box::new(crate::query::parser::ast::TableSource::table("employees")),
+                right: Box::new(crate::query::parser::ast::TableSource::table("departments")),
+                on: Some(crate::query::parser::ast::Expression::Single(
+                    Clause::Comparison {
+                        lhs: Literal::ColumnReference("employees.id".to_string()),
+                        operator: BinaryOperator::Eq,
+                        rhs: Literal::Int(1),
+                    },
+                )),
+                join_type: JoinType::Inner,
+            },
+            projection: Projection::All,
+            distinct_on: None,
+            where_clause: None,
+            order_by: None,
+            limit: None,
+        });
+
+        assert!(matches!(
+            logical_plan,
+            Err(PlanningError::InvalidJoinCondition(condition)) if condition == "employees.id = 1"
+        ));
+    }
+
+    #[test]
+    fn map_children_projection() {
+        let plan = LogicalPlan::scan("employees").project(vec!["id"]);
+        let transformed = plan.map_children(|logical_plan| match logical_plan {
+            LogicalPlan::Scan { table_name, .. } => {
+                LogicalPlan::scan(format!("{}_transformed", table_name))
+            }
             _ => logical_plan,
         });
 
         let expected = LogicalPlan::Projection {
             base_plan: Box::new(LogicalPlan::scan("employees_transformed")),
-            columns: vec!["id".to_string()],
+            columns: vec![ProjectedColumn::new("id")],
         };
         assert_eq!(transformed, expected);
     }
@@ -923,6 +2356,7 @@ mod tests {
             left: Box::new(LogicalPlan::scan("employees_transformed")),
             right: Box::new(LogicalPlan::scan("departments_transformed")),
             on: None,
+            join_type: JoinType::Inner,
         };
         assert_eq!(transformed, expected);
     }
@@ -934,6 +2368,7 @@ mod tests {
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -951,7 +2386,8 @@ mod tests {
         let projection_plan = planner
             .plan(Ast::Select {
                 source: crate::query::parser::ast::TableSource::table("employees"),
-                projection: Projection::Columns(vec!["id".to_string()]),
+                projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -972,8 +2408,10 @@ mod tests {
                     left: Box::new(crate::query::parser::ast::TableSource::table("employees")),
                     right: Box::new(crate::query::parser::ast::TableSource::table("departments")),
                     on: None,
+                    join_type: JoinType::Inner,
                 },
                 projection: Projection::All,
+                distinct_on: None,
                 where_clause: None,
                 order_by: None,
                 limit: None,
@@ -1007,4 +2445,875 @@ mod tests {
         let schema = join_plan.schema();
         assert!(schema.is_none());
     }
+
+    #[test]
+    fn display_plan_for_select_with_where_and_limit() {
+        let plan = LogicalPlan::scan("employees")
+            .filter(Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(1),
+            ))
+            .project(vec!["id", "name"])
+            .limit(10);
+
+        assert_eq!(
+            "Limit (count: 10)\n  Projection (columns: id, name)\n    Filter (predicate: id = 1)\n      Scan (table: employees)\n",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn display_plan_for_join_with_sort() {
+        let plan = LogicalPlan::Scan {
+            table_name: "employees".to_string(),
+            alias: Some("e".to_string()),
+            filter: None,
+            schema: Arc::new(Schema::new()),
+            limit: None,
+        }
+        .join_of_type(
+            LogicalPlan::scan("departments"),
+            Some(Predicate::comparison(
+                Literal::ColumnReference("e.id".to_string()),
+                LogicalOperator::Eq,
+                Literal::ColumnReference("departments.id".to_string()),
+            )),
+            JoinType::Left,
+        )
+        .order_by(vec![desc!("e.id")]);
+
+        assert_eq!(
+            "Sort (keys: e.id DESC)\n  Join (type: LEFT, on: e.id = departments.id)\n    Scan (table: employees, alias: e)\n    Scan (table: departments)\n",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn display_plan_for_explain() {
+        let plan = LogicalPlan::Explain(Box::new(LogicalPlan::Scan {
+            table_name: "employees".to_string(),
+            alias: None,
+            filter: Some(Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(1),
+            )),
+            schema: Arc::new(Schema::new()),
+            limit: Some(5),
+        }));
+
+        assert_eq!(
+            "Explain\n  Scan (table: employees, filter: id > 1, limit: 5)\n",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_insert() {
+        let planner = planner_for_tests();
+        let plan = planner
+            .plan(Ast::Insert {
+                table_name: "employees".to_string(),
+                columns: Some(vec!["id".to_string()]),
+                rows: vec![vec![Literal::Int(1)], vec![Literal::Int(2)]],
+            })
+            .unwrap();
+
+        assert!(
+            matches!(plan, LogicalPlan::Insert { table_name, columns, rows }
+            if table_name == "employees"
+                && columns == Some(vec!["id".to_string()])
+                && rows == vec![vec![Literal::Int(1)], vec![Literal::Int(2)]])
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_delete() {
+        let planner = planner_for_tests();
+        let plan = planner
+            .plan(Ast::Delete {
+                table_name: "employees".to_string(),
+                where_clause: Some(WhereClause::comparison(
+                    Literal::ColumnReference("id".to_string()),
+                    BinaryOperator::Eq,
+                    Literal::Int(1),
+                )),
+            })
+            .unwrap();
+
+        assert!(
+            matches!(plan, LogicalPlan::Delete { table_name, filter: Some(Predicate::Single(LogicalClause::Comparison { lhs, operator, rhs })) }
+            if table_name == "employees"
+                && lhs == Literal::ColumnReference("id".to_string())
+                && operator == LogicalOperator::Eq
+                && rhs == Literal::Int(1))
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_delete_without_where_clause() {
+        let planner = planner_for_tests();
+        let plan = planner
+            .plan(Ast::Delete {
+                table_name: "employees".to_string(),
+                where_clause: None,
+            })
+            .unwrap();
+
+        assert!(
+            matches!(plan, LogicalPlan::Delete { table_name, filter: None } if table_name == "employees")
+        );
+        assert!(LogicalPlan::Delete {
+            table_name: "employees".to_string(),
+            filter: None
+        }
+        .schema()
+        .is_none());
+    }
+
+    #[test]
+    fn display_plan_for_delete() {
+        let plan = LogicalPlan::Delete {
+            table_name: "employees".to_string(),
+            filter: Some(Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(1),
+            )),
+        };
+
+        assert_eq!(
+            "Delete (table: employees, filter: id = 1)\n",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_update() {
+        let planner = planner_for_tests();
+        let plan = planner
+            .plan(Ast::Update {
+                table_name: "employees".to_string(),
+                assignments: vec![("name".to_string(), Literal::Text("relop".to_string()))],
+                where_clause: None,
+            })
+            .unwrap();
+
+        assert!(
+            matches!(plan, LogicalPlan::Update { table_name, assignments, filter: None }
+            if table_name == "employees"
+                && assignments == vec![("name".to_string(), Literal::Text("relop".to_string()))])
+        );
+    }
+
+    #[test]
+    fn display_plan_for_update() {
+        let plan = LogicalPlan::Update {
+            table_name: "employees".to_string(),
+            assignments: vec![
+                ("name".to_string(), Literal::Text("relop".to_string())),
+                ("age".to_string(), Literal::Int(30)),
+            ],
+            filter: Some(Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(1),
+            )),
+        };
+
+        assert_eq!(
+            "Update (table: employees, set: name = 'relop', age = 30, filter: id = 1)\n",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_create_table() {
+        let planner = planner_for_tests();
+        let plan = planner
+            .plan(Ast::CreateTable {
+                name: "departments".to_string(),
+                columns: vec![("id".to_string(), ColumnType::Int)],
+                primary_key: Some(vec!["id".to_string()]),
+            })
+            .unwrap();
+
+        assert!(
+            matches!(plan, LogicalPlan::CreateTable { table_name, columns, primary_key }
+            if table_name == "departments" && columns == vec![("id".to_string(), ColumnType::Int)]
+                && primary_key == Some(vec!["id".to_string()]))
+        );
+    }
+
+    #[test]
+    fn display_plan_for_create_table() {
+        let plan = LogicalPlan::CreateTable {
+            table_name: "departments".to_string(),
+            columns: vec![
+                ("id".to_string(), ColumnType::Int),
+                ("name".to_string(), ColumnType::Text),
+                ("active".to_string(), ColumnType::Bool),
+            ],
+            primary_key: None,
+        };
+
+        assert_eq!(
+            "CreateTable (table: departments, columns: id int, name text, active bool)\n",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn display_plan_for_create_table_with_a_primary_key() {
+        let plan = LogicalPlan::CreateTable {
+            table_name: "departments".to_string(),
+            columns: vec![
+                ("id".to_string(), ColumnType::Int),
+                ("name".to_string(), ColumnType::Text),
+            ],
+            primary_key: Some(vec!["id".to_string(), "name".to_string()]),
+        };
+
+        assert_eq!(
+            "CreateTable (table: departments, columns: id int, name text, primary key: (id, name))\n",
+            plan.to_string()
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_drop_table() {
+        let plan = planner_for_tests()
+            .plan(Ast::DropTable {
+                table_name: "employees".to_string(),
+            })
+            .unwrap();
+
+        assert!(
+            matches!(&plan, LogicalPlan::DropTable { table_name } if table_name == "employees")
+        );
+        assert_eq!("DropTable (table: employees)\n", plan.to_string());
+    }
+
+    #[test]
+    fn display_plan_for_insert() {
+        let plan = LogicalPlan::Insert {
+            table_name: "employees".to_string(),
+            columns: Some(vec!["id".to_string(), "name".to_string()]),
+            rows: vec![
+                vec![Literal::Int(1), Literal::Text("relop".to_string())],
+                vec![Literal::Int(2), Literal::Text("query".to_string())],
+            ],
+        };
+
+        assert_eq!(
+            "Insert (table: employees, columns: id, name, rows: 2)\n",
+            plan.to_string()
+        );
+    }
+
+    fn select_columns(table_name: &str, columns: Vec<ProjectedColumn>) -> Ast {
+        Ast::Select {
+            source: crate::query::parser::ast::TableSource::table(table_name),
+            projection: Projection::Columns(columns),
+            distinct_on: None,
+            where_clause: None,
+            order_by: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn logical_plan_for_union() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::SetOperation {
+                operator: SetOperator::Union,
+                left: Box::new(select_columns(
+                    "employees",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                right: Box::new(select_columns(
+                    "departments",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                order_by: Some(vec![asc!("id")]),
+                limit: Some(2),
+            })
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Limit { base_plan, count: 2 }
+                if matches!(
+                    base_plan.as_ref(),
+                    LogicalPlan::Sort { base_plan, .. }
+                        if matches!(base_plan.as_ref(), LogicalPlan::Union { all: false, .. })
+                )
+        ));
+    }
+
+    #[test]
+    fn logical_plan_for_union_all() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::SetOperation {
+                operator: SetOperator::UnionAll,
+                left: Box::new(select_columns(
+                    "employees",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                right: Box::new(select_columns(
+                    "departments",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                order_by: None,
+                limit: None,
+            })
+            .unwrap();
+
+        assert!(matches!(logical_plan, LogicalPlan::Union { all: true, .. }));
+        assert_eq!(
+            vec!["employees.id"],
+            logical_plan.schema().unwrap().column_names()
+        );
+    }
+
+    #[test]
+    fn attempt_to_plan_union_with_different_column_counts() {
+        let result = planner_for_tests().plan(Ast::SetOperation {
+            operator: SetOperator::Union,
+            left: Box::new(select_columns(
+                "employees",
+                vec![
+                    ProjectedColumn::new("id"),
+                    ProjectedColumn::aliased("id", "employee_id"),
+                ],
+            )),
+            right: Box::new(select_columns(
+                "departments",
+                vec![ProjectedColumn::new("id")],
+            )),
+            order_by: None,
+            limit: None,
+        });
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::SetOperationColumnCountMismatch { left: 2, right: 1 })
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_union_with_different_column_types() {
+        use crate::query::parser::ast::ScalarFunction;
+
+        let result = planner_for_tests().plan(Ast::SetOperation {
+            operator: SetOperator::Union,
+            left: Box::new(select_columns(
+                "employees",
+                vec![ProjectedColumn::new("id")],
+            )),
+            right: Box::new(select_columns(
+                "departments",
+                vec![ProjectedColumn::computed(
+                    Literal::function_call(
+                        ScalarFunction::Upper,
+                        vec![Literal::Text("a".to_string())],
+                    ),
+                    "name",
+                )],
+            )),
+            order_by: None,
+            limit: None,
+        });
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::SetOperationColumnTypeMismatch {
+                position: 1,
+                left: ColumnType::Int,
+                right: ColumnType::Text
+            })
+        ));
+    }
+
+    #[test]
+    fn plan_with_parameters() {
+        let logical_plan = planner_for_tests()
+            .plan_with_parameters(
+                Ast::Values(vec![vec![Literal::Parameter(0), Literal::Parameter(1)]]),
+                &[ColumnValue::int(1), ColumnValue::text("relop")],
+            )
+            .unwrap();
+
+        assert!(matches!(logical_plan, LogicalPlan::Values { rows, .. }
+            if rows == vec![vec![Literal::Int(1), Literal::Text("relop".to_string())]]));
+    }
+
+    #[test]
+    fn attempt_to_plan_with_fewer_parameters_than_placeholders() {
+        let result = planner_for_tests().plan_with_parameters(
+            Ast::Values(vec![vec![Literal::Parameter(0), Literal::Parameter(1)]]),
+            &[ColumnValue::int(1)],
+        );
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::ParameterCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_with_more_parameters_than_placeholders() {
+        let result = planner_for_tests().plan_with_parameters(
+            Ast::Values(vec![vec![Literal::Int(1)]]),
+            &[ColumnValue::int(1)],
+        );
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::ParameterCountMismatch {
+                expected: 0,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn plan_values() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Values(vec![
+                vec![Literal::Null, Literal::Text("relop".to_string())],
+                vec![Literal::Int(2), Literal::Text("query".to_string())],
+            ]))
+            .unwrap();
+
+        let schema = logical_plan.schema().unwrap();
+        assert_eq!(vec!["column1", "column2"], schema.column_names());
+        assert_eq!(
+            vec![&ColumnType::Int, &ColumnType::Text],
+            schema
+                .columns()
+                .iter()
+                .map(|column| column.column_type())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn attempt_to_plan_values_with_different_row_lengths() {
+        let result = planner_for_tests().plan(Ast::Values(vec![
+            vec![Literal::Int(1), Literal::Text("relop".to_string())],
+            vec![Literal::Int(2)],
+        ]));
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::ValuesColumnCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_values_with_different_column_types() {
+        let result = planner_for_tests().plan(Ast::Values(vec![
+            vec![Literal::Int(1)],
+            vec![Literal::Text("relop".to_string())],
+        ]));
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::ValuesColumnTypeMismatch {
+                position: 1,
+                expected: ColumnType::Int,
+                actual: ColumnType::Text
+            })
+        ));
+    }
+
+    #[test]
+    fn display_values_plan() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::Values(vec![
+                vec![Literal::Int(1)],
+                vec![Literal::Int(2)],
+            ]))
+            .unwrap();
+
+        assert_eq!("Values (rows: 2)\n", logical_plan.to_string());
+    }
+
+    #[test]
+    fn display_union_plan() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::SetOperation {
+                operator: SetOperator::Union,
+                left: Box::new(select_columns(
+                    "employees",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                right: Box::new(select_columns(
+                    "departments",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                order_by: None,
+                limit: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            "Union (all: false)\n  Projection (columns: id)\n    Scan (table: employees)\n  Projection (columns: id)\n    Scan (table: departments)\n",
+            logical_plan.to_string()
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_intersect() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::SetOperation {
+                operator: SetOperator::Intersect,
+                left: Box::new(select_columns(
+                    "employees",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                right: Box::new(select_columns(
+                    "departments",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                order_by: None,
+                limit: None,
+            })
+            .unwrap();
+
+        assert!(matches!(logical_plan, LogicalPlan::Intersect { .. }));
+        assert_eq!(
+            "Intersect\n  Projection (columns: id)\n    Scan (table: employees)\n  Projection (columns: id)\n    Scan (table: departments)\n",
+            logical_plan.to_string()
+        );
+    }
+
+    #[test]
+    fn logical_plan_for_except() {
+        let logical_plan = planner_for_tests()
+            .plan(Ast::SetOperation {
+                operator: SetOperator::Except,
+                left: Box::new(select_columns(
+                    "employees",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                right: Box::new(select_columns(
+                    "departments",
+                    vec![ProjectedColumn::new("id")],
+                )),
+                order_by: None,
+                limit: None,
+            })
+            .unwrap();
+
+        assert!(matches!(logical_plan, LogicalPlan::Except { .. }));
+        assert_eq!(
+            vec!["employees.id"],
+            logical_plan.schema().unwrap().column_names()
+        );
+    }
+
+    #[test]
+    fn attempt_to_plan_except_with_different_column_counts() {
+        let result = planner_for_tests().plan(Ast::SetOperation {
+            operator: SetOperator::Except,
+            left: Box::new(select_columns(
+                "employees",
+                vec![ProjectedColumn::new("id")],
+            )),
+            right: Box::new(select_columns(
+                "departments",
+                vec![
+                    ProjectedColumn::new("id"),
+                    ProjectedColumn::aliased("id", "department_id"),
+                ],
+            )),
+            order_by: None,
+            limit: None,
+        });
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::SetOperationColumnCountMismatch { left: 1, right: 2 })
+        ));
+    }
+
+    fn select_all_where_id_equals(table_name: &str, subquery: Ast) -> Ast {
+        Ast::Select {
+            source: crate::query::parser::ast::TableSource::table(table_name),
+            projection: Projection::All,
+            distinct_on: None,
+            where_clause: Some(WhereClause::comparison(
+                Literal::ColumnReference("id".to_string()),
+                BinaryOperator::Eq,
+                Literal::Subquery(Box::new(subquery)),
+            )),
+            order_by: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn logical_plan_for_filter_with_scalar_subquery() {
+        let logical_plan = planner_for_tests()
+            .plan(select_all_where_id_equals(
+                "employees",
+                select_columns("departments", vec![ProjectedColumn::new("id")]),
+            ))
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Filter { predicate: Predicate::Single(LogicalClause::Comparison { rhs: Literal::PlannedSubquery(plan), .. }), .. }
+                if matches!(
+                    plan.as_ref(),
+                    LogicalPlan::Projection { base_plan, .. }
+                        if matches!(base_plan.as_ref(), LogicalPlan::Scan { table_name, .. } if table_name == "departments")
+                )
+        ));
+    }
+
+    #[test]
+    fn display_filter_with_scalar_subquery() {
+        let logical_plan = planner_for_tests()
+            .plan(select_all_where_id_equals(
+                "employees",
+                select_columns("departments", vec![ProjectedColumn::new("id")]),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            "Filter (predicate: id = (subquery))\n  Scan (table: employees)\n",
+            logical_plan.to_string()
+        );
+    }
+
+    #[test]
+    fn attempt_to_plan_scalar_subquery_selecting_multiple_columns() {
+        let result = planner_for_tests().plan(select_all_where_id_equals(
+            "employees",
+            select_columns(
+                "departments",
+                vec![
+                    ProjectedColumn::new("id"),
+                    ProjectedColumn::aliased("id", "department_id"),
+                ],
+            ),
+        ));
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::SubqueryColumnCountMismatch(2))
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_scalar_subquery_for_non_existent_table() {
+        let result = planner_for_tests().plan(select_all_where_id_equals(
+            "employees",
+            select_columns("managers", vec![ProjectedColumn::new("id")]),
+        ));
+
+        assert!(matches!(result, Err(PlanningError::Catalog(_))));
+    }
+
+    fn select_all_where_id_in(table_name: &str, subquery: Ast) -> Ast {
+        Ast::Select {
+            source: crate::query::parser::ast::TableSource::table(table_name),
+            projection: Projection::All,
+            distinct_on: None,
+            where_clause: Some(WhereClause(Expression::single(
+                crate::query::parser::ast::Clause::in_subquery(
+                    Literal::ColumnReference("id".to_string()),
+                    subquery,
+                    false,
+                ),
+            ))),
+            order_by: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn logical_plan_for_filter_with_in_subquery() {
+        let logical_plan = planner_for_tests()
+            .plan(select_all_where_id_in(
+                "employees",
+                select_columns("departments", vec![ProjectedColumn::new("id")]),
+            ))
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Filter {
+                predicate: Predicate::Single(LogicalClause::In {
+                    values: MembershipValues::PlannedSubquery(plan),
+                    negated: false,
+                    ..
+                }),
+                ..
+            } if matches!(plan.as_ref(), LogicalPlan::Projection { .. })
+        ));
+    }
+
+    #[test]
+    fn attempt_to_plan_in_subquery_selecting_all_columns_of_multiple_tables() {
+        let result = planner_for_tests().plan(select_all_where_id_in(
+            "employees",
+            Ast::Select {
+                source: crate::query::parser::ast::TableSource::Join {
+                    left: Box::new(crate::query::parser::ast::TableSource::table("departments")),
+                    right: Box::new(crate::query::parser::ast::TableSource::table("roles")),
+                    on: None,
+                    join_type: JoinType::Cross,
+                },
+                projection: Projection::All,
+                distinct_on: None,
+                where_clause: None,
+                order_by: None,
+                limit: None,
+            },
+        ));
+
+        assert!(matches!(
+            result,
+            Err(PlanningError::SubqueryColumnCountMismatch(2))
+        ));
+    }
+
+    fn select_all_where_exists(table_name: &str, subquery: Ast) -> Ast {
+        Ast::Select {
+            source: crate::query::parser::ast::TableSource::table(table_name),
+            projection: Projection::All,
+            distinct_on: None,
+            where_clause: Some(WhereClause(Expression::single(
+                crate::query::parser::ast::Clause::exists(subquery),
+            ))),
+            order_by: None,
+            limit: None,
+        }
+    }
+
+    fn select_id_from_employees_where(lhs: &str, rhs: Literal) -> Ast {
+        Ast::Select {
+            source: crate::query::parser::ast::TableSource::table("employees"),
+            projection: Projection::Columns(vec![ProjectedColumn::new("id")]),
+            distinct_on: None,
+            where_clause: Some(WhereClause::comparison(
+                Literal::ColumnReference(lhs.to_string()),
+                BinaryOperator::Eq,
+                rhs,
+            )),
+            order_by: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn logical_plan_for_filter_with_correlated_exists() {
+        let logical_plan = planner_for_tests()
+            .plan(select_all_where_exists(
+                "departments",
+                select_id_from_employees_where(
+                    "employees.id",
+                    Literal::ColumnReference("departments.id".to_string()),
+                ),
+            ))
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Filter {
+                predicate: Predicate::Single(LogicalClause::Exists {
+                    subquery: CorrelatedSubquery::Planned { correlations, .. }
+                }),
+                ..
+            } if correlations == vec![Correlation::new("departments.id")]
+        ));
+    }
+
+    #[test]
+    fn logical_plan_for_filter_with_uncorrelated_exists() {
+        let logical_plan = planner_for_tests()
+            .plan(select_all_where_exists(
+                "departments",
+                select_id_from_employees_where("id", Literal::Int(1)),
+            ))
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Filter {
+                predicate: Predicate::Single(LogicalClause::Exists {
+                    subquery: CorrelatedSubquery::Planned { correlations, .. }
+                }),
+                ..
+            } if correlations.is_empty()
+        ));
+    }
+
+    #[test]
+    fn logical_plan_for_exists_prefers_the_columns_of_the_subquery() {
+        let logical_plan = planner_for_tests()
+            .plan(select_all_where_exists(
+                "departments",
+                select_id_from_employees_where("id", Literal::ColumnReference("id".to_string())),
+            ))
+            .unwrap();
+
+        assert!(matches!(
+            logical_plan,
+            LogicalPlan::Filter {
+                predicate: Predicate::Single(LogicalClause::Exists {
+                    subquery: CorrelatedSubquery::Planned { correlations, .. }
+                }),
+                ..
+            } if correlations.is_empty()
+        ));
+    }
+
+    #[test]
+    fn display_filter_with_exists() {
+        let logical_plan = planner_for_tests()
+            .plan(select_all_where_exists(
+                "departments",
+                select_id_from_employees_where(
+                    "employees.id",
+                    Literal::ColumnReference("departments.id".to_string()),
+                ),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            "Filter (predicate: EXISTS (subquery))\n  Scan (table: departments)\n",
+            logical_plan.to_string()
+        );
+    }
+
+    #[test]
+    fn logical_plan_with_outer_values() {
+        let logical_plan = LogicalPlan::scan("employees")
+            .filter(Predicate::comparison(
+                Literal::ColumnReference("employees.id".to_string()),
+                LogicalOperator::Eq,
+                Literal::ColumnReference("departments.id".to_string()),
+            ))
+            .project(vec!["id"]);
+
+        let logical_plan =
+            logical_plan.with_outer_values(&[("departments.id".to_string(), ColumnValue::Int(10))]);
+
+        assert_eq!(
+            "Projection (columns: id)\n  Filter (predicate: employees.id = 10)\n    Scan (table: employees)\n",
+            logical_plan.to_string()
+        );
+    }
 }
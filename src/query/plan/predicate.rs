@@ -1,11 +1,18 @@
+use crate::catalog::Catalog;
 use crate::query::executor::error::ExecutionError;
-use crate::query::parser::ast::{BinaryOperator, Clause, Expression, Literal, WhereClause};
+use crate::query::executor::Executor;
+use crate::query::parser::ast::{Ast, BinaryOperator, Clause, Expression, Literal, WhereClause};
 use crate::query::plan::error::PlanningError;
+use crate::query::plan::LogicalPlan;
+use crate::schema::error::SchemaError;
 use crate::schema::Schema;
 use crate::storage::row::Row;
 use crate::storage::row_filter::RowFilter;
 use crate::storage::row_view::RowView;
 use crate::types::column_value::ColumnValue;
+use crate::types::date::Date;
+use std::collections::HashSet;
+use std::sync::Arc;
 
 /// A trait for resolving column values from different sources (e.g., RowView, Row).
 pub(crate) trait ValueResolver {
@@ -18,12 +25,31 @@ impl ValueResolver for RowView<'_> {
         match literal {
             Literal::Int(value) => Ok(ColumnValue::Int(*value)),
             Literal::Text(value) => Ok(ColumnValue::Text(value.clone())),
+            Literal::Bool(value) => Ok(ColumnValue::Bool(*value)),
+            Literal::Date(value) => Ok(ColumnValue::Date(*value)),
+            Literal::Null => Ok(ColumnValue::Null),
             Literal::ColumnReference(column_name) => self
                 .column_value_by(column_name)
-                .map_err(ExecutionError::Schema)?
+                .map_err(ExecutionError::from)?
                 .ok_or(ExecutionError::UnknownColumn(column_name.to_string()))
                 .cloned(),
             Literal::ColumnIndex(index) => Ok(self.column_value_at_unchecked(*index).clone()),
+            Literal::Arithmetic { lhs, operator, rhs } => {
+                operator.apply(&self.resolve(lhs)?, &self.resolve(rhs)?)
+            }
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => function.apply(
+                &arguments
+                    .iter()
+                    .map(|argument| self.resolve(argument))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Literal::Parameter(index) => Err(ExecutionError::UnboundParameter(*index)),
+            Literal::Subquery(_) | Literal::PlannedSubquery(_) => {
+                Err(ExecutionError::UnevaluatedSubquery)
+            }
         }
     }
 }
@@ -33,6 +59,9 @@ impl ValueResolver for Row {
         match literal {
             Literal::Int(value) => Ok(ColumnValue::Int(*value)),
             Literal::Text(value) => Ok(ColumnValue::Text(value.clone())),
+            Literal::Bool(value) => Ok(ColumnValue::Bool(*value)),
+            Literal::Date(value) => Ok(ColumnValue::Date(*value)),
+            Literal::Null => Ok(ColumnValue::Null),
             Literal::ColumnIndex(index) => self
                 .column_value_at(*index)
                 .ok_or(ExecutionError::ColumnIndexOutOfBounds(*index))
@@ -40,19 +69,72 @@ impl ValueResolver for Row {
             Literal::ColumnReference(column_name) => {
                 Err(ExecutionError::UnboundColumn(column_name.to_string()))
             }
+            Literal::Arithmetic { lhs, operator, rhs } => {
+                operator.apply(&self.resolve(lhs)?, &self.resolve(rhs)?)
+            }
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => function.apply(
+                &arguments
+                    .iter()
+                    .map(|argument| self.resolve(argument))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Literal::Parameter(index) => Err(ExecutionError::UnboundParameter(*index)),
+            Literal::Subquery(_) | Literal::PlannedSubquery(_) => {
+                Err(ExecutionError::UnevaluatedSubquery)
+            }
+        }
+    }
+}
+
+/// A `ValueResolver` which resolves constants only: a literal referring to a column (or
+/// to a subquery) can not be resolved at plan time.
+pub(crate) struct ConstantResolver;
+
+impl ValueResolver for ConstantResolver {
+    fn resolve(&self, literal: &Literal) -> Result<ColumnValue, ExecutionError> {
+        match literal {
+            Literal::Int(value) => Ok(ColumnValue::Int(*value)),
+            Literal::Text(value) => Ok(ColumnValue::Text(value.clone())),
+            Literal::Bool(value) => Ok(ColumnValue::Bool(*value)),
+            Literal::Date(value) => Ok(ColumnValue::Date(*value)),
+            Literal::Null => Ok(ColumnValue::Null),
+            Literal::Arithmetic { lhs, operator, rhs } => {
+                operator.apply(&self.resolve(lhs)?, &self.resolve(rhs)?)
+            }
+            Literal::FunctionCall {
+                function,
+                arguments,
+            } => function.apply(
+                &arguments
+                    .iter()
+                    .map(|argument| self.resolve(argument))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Literal::ColumnReference(column_name) => {
+                Err(ExecutionError::UnboundColumn(column_name.to_string()))
+            }
+            Literal::ColumnIndex(index) => Err(ExecutionError::ColumnIndexOutOfBounds(*index)),
+            Literal::Parameter(index) => Err(ExecutionError::UnboundParameter(*index)),
+            Literal::Subquery(_) | Literal::PlannedSubquery(_) => {
+                Err(ExecutionError::UnevaluatedSubquery)
+            }
         }
     }
 }
 
 /// `Predicate` represents a filter clause in a logical plan.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum Predicate {
     Single(LogicalClause),
     And(Vec<Predicate>),
     Or(Vec<Predicate>),
+    Not(Box<Predicate>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum LogicalClause {
     /// A comparison clause (e.g., `age > 30`).
     Comparison {
@@ -63,14 +145,233 @@ pub(crate) enum LogicalClause {
         /// The right-hand side literal.
         rhs: Literal,
     },
+    /// A pattern matching clause (e.g., `name like 'J%'`), inverted when negated (`not like`).
     Like {
         /// The column to match against.
         column: Literal,
         /// The compiled regular expression for the pattern.
         regex: regex::Regex,
+        /// Whether the match is negated (`not like`).
+        negated: bool,
+    },
+    /// An inclusive range clause (e.g., `id between 10 and 20`).
+    Between {
+        /// The column to check.
+        column: Literal,
+        /// The inclusive lower bound.
+        low: Literal,
+        /// The inclusive upper bound.
+        high: Literal,
+    },
+    /// A membership clause (e.g., `department_id in (select id from departments)`), inverted
+    /// when negated (`not in`).
+    In {
+        /// The operand to look up among the values.
+        lhs: Literal,
+        /// The values to look the operand up in.
+        values: MembershipValues,
+        /// Whether the membership is negated (`not in`).
+        negated: bool,
+    },
+    /// An existence clause (e.g., `exists (select id from employees where ...)`), matching when
+    /// the subquery produces at least one row.
+    Exists {
+        /// The subquery, which may refer to the columns of the outer query.
+        subquery: CorrelatedSubquery,
+    },
+}
+
+/// `CorrelatedSubquery` holds the subquery of an existence clause (`EXISTS`), which may refer
+/// to the columns of the outer query (e.g., `departments.id` in
+/// `exists (select id from employees where employees.department_id = departments.id)`).
+///
+/// The planner plans the subquery and finds the outer columns it refers to, and the executor
+/// makes it executable against the catalog. The subquery is then executed once per evaluated
+/// row, with the values of the outer columns of that row, so its cost is paid for every row.
+#[derive(Clone)]
+pub(crate) enum CorrelatedSubquery {
+    /// The subquery, as parsed.
+    Subquery(Box<Ast>),
+    /// The plan of the subquery, along with the outer columns it refers to.
+    Planned {
+        /// The plan of the subquery.
+        plan: Box<LogicalPlan>,
+        /// The outer columns the subquery refers to.
+        correlations: Vec<Correlation>,
+    },
+    /// The plan of the subquery, along with the outer columns it refers to and the catalog to
+    /// execute it against.
+    Executable {
+        /// The plan of the subquery.
+        plan: Box<LogicalPlan>,
+        /// The outer columns the subquery refers to.
+        correlations: Vec<Correlation>,
+        /// The catalog to execute the subquery against.
+        catalog: Arc<Catalog>,
     },
 }
 
+/// `Correlation` is a reference of a subquery to a column of the outer query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Correlation {
+    /// The column name, as referenced in the subquery.
+    pub(crate) column_name: String,
+    /// The outer column, resolved (and bound) like the other literals of the outer predicate.
+    pub(crate) outer_column: Literal,
+}
+
+impl Correlation {
+    /// Creates a new `Correlation` to the outer column with the given name.
+    pub(crate) fn new(column_name: &str) -> Self {
+        Self {
+            column_name: column_name.to_string(),
+            outer_column: Literal::ColumnReference(column_name.to_string()),
+        }
+    }
+}
+
+impl CorrelatedSubquery {
+    /// Returns the outer columns the subquery refers to, none until it is planned.
+    fn correlations(&self) -> &[Correlation] {
+        match self {
+            CorrelatedSubquery::Subquery(_) => &[],
+            CorrelatedSubquery::Planned { correlations, .. }
+            | CorrelatedSubquery::Executable { correlations, .. } => correlations,
+        }
+    }
+
+    /// Applies the (fallible) transformation to the outer columns the subquery refers to.
+    fn try_map_outer_columns<E, F>(self, transform: &mut F) -> Result<Self, E>
+    where
+        F: FnMut(Literal) -> Result<Literal, E>,
+    {
+        let mut map = |correlations: Vec<Correlation>| {
+            correlations
+                .into_iter()
+                .map(|correlation| {
+                    Ok(Correlation {
+                        column_name: correlation.column_name,
+                        outer_column: transform(correlation.outer_column)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+        match self {
+            CorrelatedSubquery::Subquery(_) => Ok(self),
+            CorrelatedSubquery::Planned { plan, correlations } => Ok(CorrelatedSubquery::Planned {
+                plan,
+                correlations: map(correlations)?,
+            }),
+            CorrelatedSubquery::Executable {
+                plan,
+                correlations,
+                catalog,
+            } => Ok(CorrelatedSubquery::Executable {
+                plan,
+                correlations: map(correlations)?,
+                catalog,
+            }),
+        }
+    }
+
+    /// Executes the subquery with the values of the outer columns (resolved by the resolver),
+    /// and returns whether it produces at least one row.
+    fn exists<V: ValueResolver>(&self, resolver: &V) -> Result<bool, ExecutionError> {
+        match self {
+            CorrelatedSubquery::Executable {
+                plan,
+                correlations,
+                catalog,
+            } => {
+                let outer_values = correlations
+                    .iter()
+                    .map(|correlation| {
+                        Ok((
+                            correlation.column_name.clone(),
+                            resolver.resolve(&correlation.outer_column)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, ExecutionError>>()?;
+
+                let plan = plan.as_ref().clone().with_outer_values(&outer_values);
+                Executor::new(catalog).has_rows(plan)
+            }
+            _ => Err(ExecutionError::UnevaluatedSubquery),
+        }
+    }
+}
+
+impl std::fmt::Debug for CorrelatedSubquery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorrelatedSubquery::Subquery(query) => f.debug_tuple("Subquery").field(query).finish(),
+            CorrelatedSubquery::Planned { plan, correlations } => f
+                .debug_struct("Planned")
+                .field("plan", plan)
+                .field("correlations", correlations)
+                .finish(),
+            CorrelatedSubquery::Executable {
+                plan, correlations, ..
+            } => f
+                .debug_struct("Executable")
+                .field("plan", plan)
+                .field("correlations", correlations)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl PartialEq for CorrelatedSubquery {
+    /// Compares the subqueries, ignoring the catalog they are executed against.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CorrelatedSubquery::Subquery(first), CorrelatedSubquery::Subquery(second)) => {
+                first == second
+            }
+            (
+                CorrelatedSubquery::Planned {
+                    plan: first_plan,
+                    correlations: first_correlations,
+                }
+                | CorrelatedSubquery::Executable {
+                    plan: first_plan,
+                    correlations: first_correlations,
+                    ..
+                },
+                CorrelatedSubquery::Planned {
+                    plan: second_plan,
+                    correlations: second_correlations,
+                }
+                | CorrelatedSubquery::Executable {
+                    plan: second_plan,
+                    correlations: second_correlations,
+                    ..
+                },
+            ) => {
+                std::mem::discriminant(self) == std::mem::discriminant(other)
+                    && first_plan == second_plan
+                    && first_correlations == second_correlations
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for CorrelatedSubquery {}
+
+/// `MembershipValues` holds the values of a membership clause (`IN`), which are produced by a
+/// subquery: the subquery is planned by the planner and evaluated once by the executor, before
+/// the rows are filtered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum MembershipValues {
+    /// The subquery producing the values, as parsed.
+    Subquery(Box<Ast>),
+    /// The plan of the subquery producing the values.
+    PlannedSubquery(Box<LogicalPlan>),
+    /// The distinct values produced by the subquery.
+    Values(HashSet<ColumnValue>),
+}
+
 impl PartialEq for LogicalClause {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -94,12 +395,58 @@ impl PartialEq for LogicalClause {
                 Self::Like {
                     column: first_column,
                     regex: first_regex,
+                    negated: first_negated,
                 },
                 Self::Like {
                     column: second_column,
                     regex: second_regex,
+                    negated: second_negated,
+                },
+            ) => {
+                first_column == second_column
+                    && first_regex.as_str() == second_regex.as_str()
+                    && first_negated == second_negated
+            }
+            (
+                Self::Between {
+                    column: first_column,
+                    low: first_low,
+                    high: first_high,
+                },
+                Self::Between {
+                    column: second_column,
+                    low: second_low,
+                    high: second_high,
+                },
+            ) => {
+                first_column == second_column
+                    && first_low == second_low
+                    && first_high == second_high
+            }
+            (
+                Self::In {
+                    lhs: first_lhs,
+                    values: first_values,
+                    negated: first_negated,
+                },
+                Self::In {
+                    lhs: second_lhs,
+                    values: second_values,
+                    negated: second_negated,
+                },
+            ) => {
+                first_lhs == second_lhs
+                    && first_values == second_values
+                    && first_negated == second_negated
+            }
+            (
+                Self::Exists {
+                    subquery: first_subquery,
+                },
+                Self::Exists {
+                    subquery: second_subquery,
                 },
-            ) => first_column == second_column && first_regex.as_str() == second_regex.as_str(),
+            ) => first_subquery == second_subquery,
             _ => false,
         }
     }
@@ -108,32 +455,123 @@ impl PartialEq for LogicalClause {
 impl Eq for LogicalClause {}
 
 impl LogicalClause {
-    /// Evaluates the clause against a given `ValueResolver`.
-    pub(crate) fn matches<V: ValueResolver>(&self, resolver: &V) -> Result<bool, ExecutionError> {
+    /// Evaluates the clause against a given `ValueResolver` in three-valued logic.
+    ///
+    /// Returns `Ok(None)` if the clause is unknown: a comparison (other than the null-safe
+    /// ones), `LIKE` or `BETWEEN` involving a `Null`, or an `IN` whose value is `Null` or is not
+    /// among values containing a `Null`.
+    fn truth_value<V: ValueResolver>(&self, resolver: &V) -> Result<Option<bool>, ExecutionError> {
         match self {
-            LogicalClause::Comparison { lhs, operator, rhs } => operator.apply(lhs, rhs, resolver),
-            LogicalClause::Like { column, regex } => {
+            LogicalClause::Comparison { lhs, operator, rhs } => {
+                let lhs_value = resolver.resolve(lhs)?;
+                let rhs_value = resolver.resolve(rhs)?;
+                operator.truth_value(&lhs_value, &rhs_value)
+            }
+            LogicalClause::Like {
+                column,
+                regex,
+                negated,
+            } => {
                 let column_value = resolver.resolve(column)?;
 
                 match column_value {
-                    ColumnValue::Text(value) => Ok(regex.is_match(&value)),
+                    ColumnValue::Text(value) => Ok(Some(regex.is_match(&value) != *negated)),
+                    ColumnValue::Null => Ok(None),
                     _ => Err(ExecutionError::TypeMismatchInComparison),
                 }
             }
+            LogicalClause::Between { column, low, high } => {
+                let column_value = resolver.resolve(column)?;
+                let low_value = resolver.resolve(low)?;
+                let high_value = resolver.resolve(high)?;
+
+                // Both bounds are compared, so that a bound of another type is an error even
+                // when the value is below the other bound.
+                let above_low =
+                    LogicalOperator::GreaterEq.truth_value(&column_value, &low_value)?;
+                let below_high =
+                    LogicalOperator::LesserEq.truth_value(&column_value, &high_value)?;
+                Ok(match (above_low, below_high) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                })
+            }
+            LogicalClause::In {
+                lhs,
+                values,
+                negated,
+            } => match values {
+                MembershipValues::Values(values) => {
+                    Self::is_member(&resolver.resolve(lhs)?, values, *negated)
+                }
+                _ => Err(ExecutionError::UnevaluatedSubquery),
+            },
+            LogicalClause::Exists { subquery } => subquery.exists(resolver).map(Some),
         }
     }
 
-    /// Binds the clause to a given `Schema`, resolving column names to indices.
-    pub(crate) fn bind(self, schema: &Schema) -> Result<Self, PlanningError> {
+    /// Returns whether the value is among the values (or not, when negated).
+    ///
+    /// Like a comparison, whether a `Null` value is a member is unknown; and whether a value
+    /// which is not found is a member of values containing a `Null` is unknown too.
+    fn is_member(
+        value: &ColumnValue,
+        values: &HashSet<ColumnValue>,
+        negated: bool,
+    ) -> Result<Option<bool>, ExecutionError> {
+        if matches!(value, ColumnValue::Null) {
+            return Ok(None);
+        }
+        let other_type = values.iter().find(|candidate| {
+            !matches!(candidate, ColumnValue::Null)
+                && std::mem::discriminant(*candidate) != std::mem::discriminant(value)
+        });
+        if other_type.is_some() {
+            return Err(ExecutionError::TypeMismatchInComparison);
+        }
+        if !values.contains(value) && values.contains(&ColumnValue::Null) {
+            return Ok(None);
+        }
+        Ok(Some(values.contains(value) != negated))
+    }
+
+    /// Applies the (fallible) transformation to each literal of the clause.
+    pub(crate) fn try_map_literals<E, F>(self, transform: &mut F) -> Result<Self, E>
+    where
+        F: FnMut(Literal) -> Result<Literal, E>,
+    {
         match self {
             LogicalClause::Comparison { lhs, operator, rhs } => Ok(LogicalClause::Comparison {
-                lhs: bind_literal(lhs, schema)?,
+                lhs: transform(lhs)?,
                 operator,
-                rhs: bind_literal(rhs, schema)?,
+                rhs: transform(rhs)?,
             }),
-            LogicalClause::Like { column, regex } => Ok(LogicalClause::Like {
-                column: bind_literal(column, schema)?,
+            LogicalClause::Like {
+                column,
+                regex,
+                negated,
+            } => Ok(LogicalClause::Like {
+                column: transform(column)?,
                 regex,
+                negated,
+            }),
+            LogicalClause::Between { column, low, high } => Ok(LogicalClause::Between {
+                column: transform(column)?,
+                low: transform(low)?,
+                high: transform(high)?,
+            }),
+            LogicalClause::In {
+                lhs,
+                values,
+                negated,
+            } => Ok(LogicalClause::In {
+                lhs: transform(lhs)?,
+                values,
+                negated,
+            }),
+            LogicalClause::Exists { subquery } => Ok(LogicalClause::Exists {
+                subquery: subquery.try_map_outer_columns(transform)?,
             }),
         }
     }
@@ -143,16 +581,19 @@ impl LogicalClause {
         let mut columns = Vec::new();
         match self {
             LogicalClause::Comparison { lhs, rhs, .. } => {
-                if let Literal::ColumnReference(name) = lhs {
-                    columns.push(name);
-                }
-                if let Literal::ColumnReference(name) = rhs {
-                    columns.push(name);
+                column_references(lhs, &mut columns);
+                column_references(rhs, &mut columns);
+            }
+            LogicalClause::Like { column, .. } => column_references(column, &mut columns),
+            LogicalClause::Between { column, low, high } => {
+                for literal in [column, low, high] {
+                    column_references(literal, &mut columns);
                 }
             }
-            LogicalClause::Like { column, .. } => {
-                if let Literal::ColumnReference(name) = column {
-                    columns.push(name);
+            LogicalClause::In { lhs, .. } => column_references(lhs, &mut columns),
+            LogicalClause::Exists { subquery } => {
+                for correlation in subquery.correlations() {
+                    column_references(&correlation.outer_column, &mut columns);
                 }
             }
         }
@@ -160,21 +601,67 @@ impl LogicalClause {
     }
 }
 
+/// Collects the column names referenced in the literal, including the ones in the operands of
+/// an arithmetic expression and in the arguments of a function call.
+fn column_references<'a>(literal: &'a Literal, columns: &mut Vec<&'a String>) {
+    match literal {
+        Literal::ColumnReference(name) => columns.push(name),
+        Literal::Arithmetic { lhs, rhs, .. } => {
+            column_references(lhs, columns);
+            column_references(rhs, columns);
+        }
+        Literal::FunctionCall { arguments, .. } => {
+            for argument in arguments {
+                column_references(argument, columns);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn bind_literal(literal: Literal, schema: &Schema) -> Result<Literal, PlanningError> {
     match literal {
         Literal::ColumnReference(column_name) => {
             let index = schema
                 .column_position(&column_name)
-                .map_err(|schema_error| PlanningError::ColumnNotFound(schema_error.to_string()))?
+                .map_err(|schema_error| match schema_error {
+                    SchemaError::AmbiguousColumnName(column_name) => {
+                        PlanningError::AmbiguousColumn(column_name)
+                    }
+                    schema_error => PlanningError::ColumnNotFound(schema_error.to_string()),
+                })?
                 .ok_or_else(|| PlanningError::ColumnNotFound(column_name.clone()))?;
             Ok(Literal::ColumnIndex(index))
         }
+        Literal::Arithmetic { lhs, operator, rhs } => Ok(Literal::arithmetic(
+            bind_literal(*lhs, schema)?,
+            operator,
+            bind_literal(*rhs, schema)?,
+        )),
+        Literal::FunctionCall {
+            function,
+            arguments,
+        } => Ok(Literal::function_call(
+            function,
+            arguments
+                .into_iter()
+                .map(|argument| bind_literal(argument, schema))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
         _ => Ok(literal),
     }
 }
 
 #[cfg(test)]
 impl LogicalClause {
+    /// Evaluates the clause against a given `ValueResolver`.
+    ///
+    /// Returns `Ok(true)` only if the clause is known to hold, i.e., a clause which is unknown
+    /// (because of a `Null`) does not match.
+    pub(crate) fn matches<V: ValueResolver>(&self, resolver: &V) -> Result<bool, ExecutionError> {
+        Ok(self.truth_value(resolver)? == Some(true))
+    }
+
     /// Creates a new `LogicalClause::Comparison` variant.
     ///
     /// # Arguments
@@ -196,8 +683,61 @@ impl LogicalClause {
         LogicalClause::Like {
             column: Literal::ColumnReference(column_name.to_string()),
             regex,
+            negated: false,
+        }
+    }
+
+    /// Creates a new negated `LogicalClause::Like` variant (`not like`).
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - The name of the column to match against.
+    /// * `regex` - The compiled regular expression pattern.
+    pub(crate) fn not_like(column_name: &str, regex: regex::Regex) -> Self {
+        LogicalClause::Like {
+            column: Literal::ColumnReference(column_name.to_string()),
+            regex,
+            negated: true,
+        }
+    }
+
+    /// Creates a new `LogicalClause::Between` variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - The name of the column to check.
+    /// * `low` - The inclusive lower bound.
+    /// * `high` - The inclusive upper bound.
+    pub(crate) fn between(column_name: &str, low: Literal, high: Literal) -> Self {
+        LogicalClause::Between {
+            column: Literal::ColumnReference(column_name.to_string()),
+            low,
+            high,
+        }
+    }
+}
+
+/// Translates a SQL `LIKE` pattern into a regular expression matching the whole text.
+///
+/// `%` matches any sequence of characters (including none), `_` matches a single character,
+/// and `\` escapes the character following it (e.g., `50\%` matches `50%`). Every other
+/// character matches itself. `ilike` sets the case-insensitive flag of the regex.
+fn like_pattern_to_regex(pattern: &str, case_insensitive: bool) -> Result<String, PlanningError> {
+    let mut regex = String::from(if case_insensitive { "(?is)^" } else { "(?s)^" });
+    let mut characters = pattern.chars();
+    while let Some(character) = characters.next() {
+        match character {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '\\' => match characters.next() {
+                Some(escaped) => regex.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4]))),
+                None => return Err(PlanningError::InvalidLikePattern(pattern.to_string())),
+            },
+            _ => regex.push_str(&regex::escape(character.encode_utf8(&mut [0; 4]))),
         }
     }
+    regex.push('$');
+    Ok(regex)
 }
 
 impl TryFrom<WhereClause> for Predicate {
@@ -233,6 +773,9 @@ impl TryFrom<Expression> for Predicate {
                 Ok(Predicate::Or(predicates))
             }
             Expression::Grouped(expression) => Predicate::try_from(*expression),
+            Expression::Not(expression) => {
+                Ok(Predicate::Not(Box::new(Predicate::try_from(*expression)?)))
+            }
         }
     }
 }
@@ -256,9 +799,11 @@ impl TryFrom<Clause> for LogicalClause {
             Clause::Like {
                 column_name,
                 literal,
+                negated,
+                case_insensitive,
             } => {
                 let regex_pattern = match literal {
-                    Literal::Text(pattern) => pattern,
+                    Literal::Text(pattern) => like_pattern_to_regex(&pattern, case_insensitive)?,
                     _ => {
                         return Err(PlanningError::InvalidRegex(
                             "Like clause requires a string literal".to_string(),
@@ -271,57 +816,151 @@ impl TryFrom<Clause> for LogicalClause {
                 Ok(LogicalClause::Like {
                     column: Literal::ColumnReference(column_name),
                     regex,
+                    negated,
+                })
+            }
+            Clause::Between {
+                column_name,
+                low,
+                high,
+            } => {
+                ensure_ordered_bounds(&low, &high)?;
+                Ok(LogicalClause::Between {
+                    column: Literal::ColumnReference(column_name),
+                    low,
+                    high,
                 })
             }
+            Clause::In {
+                lhs,
+                subquery,
+                negated,
+            } => Ok(LogicalClause::In {
+                lhs,
+                values: MembershipValues::Subquery(subquery),
+                negated,
+            }),
+            Clause::Exists { subquery } => Ok(LogicalClause::Exists {
+                subquery: CorrelatedSubquery::Subquery(subquery),
+            }),
         }
     }
 }
 
+/// Ensures that the lower bound of a `BETWEEN` is not greater than its upper bound
+/// (e.g., `id between 20 and 10`), which would match no row.
+///
+/// The bounds are compared only if both are constants (e.g., not column references) of
+/// comparable types, the other bounds are left to be evaluated for the rows.
+fn ensure_ordered_bounds(low: &Literal, high: &Literal) -> Result<(), PlanningError> {
+    let (Ok(low_value), Ok(high_value)) = (
+        ConstantResolver.resolve(low),
+        ConstantResolver.resolve(high),
+    ) else {
+        return Ok(());
+    };
+    if LogicalOperator::Greater
+        .evaluate(&low_value, &high_value)
+        .unwrap_or(false)
+    {
+        return Err(PlanningError::InvalidBetweenRange {
+            low: low.to_string(),
+            high: high.to_string(),
+        });
+    }
+    Ok(())
+}
+
 impl Predicate {
+    /// Evaluates the predicate without a row, returning its value if it does not depend on the
+    /// row (e.g., `1 = 2`, or `1 = 2 AND id = 5`), and `None` otherwise (or if it fails to
+    /// evaluate, leaving the error to be reported for the rows).
+    pub(crate) fn constant_value(&self) -> Option<bool> {
+        self.matches(&ConstantResolver).ok()
+    }
+
     /// Evaluates the predicate against a given `ValueResolver`.
     ///
-    /// Returns `Ok(true)` if the row satisfies the predicate, `Ok(false)` otherwise.
+    /// Returns `Ok(true)` if the row satisfies the predicate, `Ok(false)` otherwise (including
+    /// when the predicate is unknown because of a `Null`).
     /// Returns an `ExecutionError` if the column cannot be found.
     pub(crate) fn matches<R: ValueResolver>(&self, resolver: &R) -> Result<bool, ExecutionError> {
+        Ok(self.truth_value(resolver)? == Some(true))
+    }
+
+    /// Evaluates the predicate against a given `ValueResolver` in three-valued logic, where
+    /// `None` is unknown.
+    ///
+    /// `AND` is false if any of its predicates is false, `OR` is true if any of its predicates is
+    /// true, and both are unknown otherwise if any of their predicates is unknown. `NOT` of
+    /// unknown is unknown, so `not (city = 'London')` does not match a `Null` city.
+    fn truth_value<R: ValueResolver>(&self, resolver: &R) -> Result<Option<bool>, ExecutionError> {
         match self {
-            Predicate::Single(clause) => clause.matches(resolver),
+            Predicate::Single(clause) => clause.truth_value(resolver),
             Predicate::And(predicates) => {
+                let mut truth_value = Some(true);
                 for predicate in predicates {
-                    if !predicate.matches(resolver)? {
-                        return Ok(false);
+                    match predicate.truth_value(resolver)? {
+                        Some(false) => return Ok(Some(false)),
+                        None => truth_value = None,
+                        Some(true) => {}
                     }
                 }
-                Ok(true)
+                Ok(truth_value)
             }
             Predicate::Or(predicates) => {
+                let mut truth_value = Some(false);
                 for predicate in predicates {
-                    if predicate.matches(resolver)? {
-                        return Ok(true);
+                    match predicate.truth_value(resolver)? {
+                        Some(true) => return Ok(Some(true)),
+                        None => truth_value = None,
+                        Some(false) => {}
                     }
                 }
-                Ok(false)
+                Ok(truth_value)
             }
+            Predicate::Not(predicate) => Ok(predicate.truth_value(resolver)?.map(|value| !value)),
         }
     }
 
     /// Binds the predicate to a given `Schema`, resolving column names to indices.
     pub(crate) fn bind(self, schema: &Schema) -> Result<Self, PlanningError> {
+        self.try_map_literals(&mut |literal| bind_literal(literal, schema))
+    }
+
+    /// Applies the (fallible) transformation to each literal of the predicate, e.g. to replace
+    /// the subqueries with their plans or their values.
+    pub(crate) fn try_map_literals<E, F>(self, transform: &mut F) -> Result<Self, E>
+    where
+        F: FnMut(Literal) -> Result<Literal, E>,
+    {
+        self.try_map_clauses(&mut |clause| clause.try_map_literals(transform))
+    }
+
+    /// Applies the (fallible) transformation to each clause of the predicate.
+    pub(crate) fn try_map_clauses<E, F>(self, transform: &mut F) -> Result<Self, E>
+    where
+        F: FnMut(LogicalClause) -> Result<LogicalClause, E>,
+    {
         match self {
-            Predicate::Single(clause) => Ok(Predicate::Single(clause.bind(schema)?)),
+            Predicate::Single(clause) => Ok(Predicate::Single(transform(clause)?)),
             Predicate::And(predicates) => {
-                let bound = predicates
+                let mapped = predicates
                     .into_iter()
-                    .map(|predicate| predicate.bind(schema))
+                    .map(|predicate| predicate.try_map_clauses(transform))
                     .collect::<Result<Vec<_>, _>>()?;
-                Ok(Predicate::And(bound))
+                Ok(Predicate::And(mapped))
             }
             Predicate::Or(predicates) => {
-                let bound = predicates
+                let mapped = predicates
                     .into_iter()
-                    .map(|predicate| predicate.bind(schema))
+                    .map(|predicate| predicate.try_map_clauses(transform))
                     .collect::<Result<Vec<_>, _>>()?;
-                Ok(Predicate::Or(bound))
+                Ok(Predicate::Or(mapped))
             }
+            Predicate::Not(predicate) => Ok(Predicate::Not(Box::new(
+                predicate.try_map_clauses(transform)?,
+            ))),
         }
     }
 
@@ -340,16 +979,66 @@ impl Predicate {
             _ => vec![self],
         }
     }
-    /// Returns `true` if all columns referenced by this predicate exist in the given schema.
-    pub(crate) fn belongs_to(&self, schema: &Schema) -> bool {
+
+    /// Returns a list of all column names referenced in the predicate, collected from the clauses
+    /// of the whole tree (through `AND`, `OR` and `NOT`), in order and with repetitions.
+    pub(crate) fn referenced_column_names(&self) -> Vec<&String> {
         let mut all_columns = Vec::new();
         self.all_column_names(&mut all_columns);
+        all_columns
+    }
+
+    /// Returns `true` if all columns referenced by this predicate exist in the given schema.
+    pub(crate) fn belongs_to(&self, schema: &Schema) -> bool {
+        let all_columns = self.referenced_column_names();
 
         all_columns
             .iter()
             .all(|column_name| schema.has_column(column_name))
     }
 
+    /// Returns the column names on both sides of the predicate, if it is a single equality
+    /// between two columns (e.g., `employees.id = departments.id`).
+    ///
+    /// This is the shape of an equi-join predicate.
+    pub(crate) fn equality_column_names(&self) -> Option<(&str, &str)> {
+        match self {
+            Predicate::Single(LogicalClause::Comparison {
+                lhs: Literal::ColumnReference(lhs),
+                operator: LogicalOperator::Eq,
+                rhs: Literal::ColumnReference(rhs),
+            }) => Some((lhs, rhs)),
+            _ => None,
+        }
+    }
+
+    /// Returns the column names and the constant values of the equalities between a column and a
+    /// constant (e.g., `department_id = 10` or `10 = department_id`) which the predicate requires,
+    /// i.e., the predicate itself or the conjuncts of an `AND`.
+    ///
+    /// A row matching the predicate has the value in the column, which lets a scan look up the
+    /// rows in an index on the column.
+    pub(crate) fn equality_constants(&self) -> Vec<(&str, ColumnValue)> {
+        match self {
+            Predicate::Single(LogicalClause::Comparison {
+                lhs,
+                operator: LogicalOperator::Eq,
+                rhs,
+            }) => match (lhs, rhs) {
+                (Literal::ColumnReference(column_name), constant)
+                | (constant, Literal::ColumnReference(column_name)) => constant_value(constant)
+                    .map(|value| vec![(column_name.as_str(), value)])
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            },
+            Predicate::And(predicates) => predicates
+                .iter()
+                .flat_map(|predicate| predicate.equality_constants())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     fn all_column_names<'a>(&'a self, all_columns: &mut Vec<&'a String>) {
         match self {
             Predicate::Single(clause) => all_columns.extend(clause.referenced_column_names()),
@@ -358,13 +1047,71 @@ impl Predicate {
                     predicate.all_column_names(all_columns);
                 }
             }
+            Predicate::Not(predicate) => predicate.all_column_names(all_columns),
+        }
+    }
+}
+
+impl std::fmt::Display for Predicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |predicates: &[Predicate], separator: &str| {
+            predicates
+                .iter()
+                .map(|predicate| predicate.to_string())
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        match self {
+            Predicate::Single(clause) => write!(f, "{}", clause),
+            Predicate::And(predicates) => write!(f, "({})", join(predicates, " AND ")),
+            Predicate::Or(predicates) => write!(f, "({})", join(predicates, " OR ")),
+            Predicate::Not(predicate) => write!(f, "NOT {}", predicate),
+        }
+    }
+}
+
+impl std::fmt::Display for LogicalClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogicalClause::Comparison { lhs, operator, rhs } => {
+                write!(f, "{} {} {}", lhs, operator, rhs)
+            }
+            LogicalClause::Like {
+                column,
+                regex,
+                negated,
+            } => {
+                let operator = if *negated { "NOT LIKE" } else { "LIKE" };
+                write!(f, "{} {} '{}'", column, operator, regex.as_str())
+            }
+            LogicalClause::Between { column, low, high } => {
+                write!(f, "{} BETWEEN {} AND {}", column, low, high)
+            }
+            LogicalClause::In { lhs, negated, .. } => {
+                let operator = if *negated { "NOT IN" } else { "IN" };
+                write!(f, "{} {} (subquery)", lhs, operator)
+            }
+            LogicalClause::Exists { .. } => write!(f, "EXISTS (subquery)"),
         }
     }
 }
 
+/// Returns the value of a constant (non-null) literal.
+fn constant_value(literal: &Literal) -> Option<ColumnValue> {
+    match literal {
+        Literal::Int(value) => Some(ColumnValue::Int(*value)),
+        Literal::Text(value) => Some(ColumnValue::Text(value.clone())),
+        Literal::Bool(value) => Some(ColumnValue::Bool(*value)),
+        Literal::Date(value) => Some(ColumnValue::Date(*value)),
+        _ => None,
+    }
+}
+
 impl RowFilter for Predicate {
-    fn matches(&self, row: &Row) -> bool {
-        self.matches(row).unwrap_or(false)
+    type Error = ExecutionError;
+
+    fn matches(&self, row: &Row) -> Result<bool, ExecutionError> {
+        Predicate::matches(self, row)
     }
 }
 
@@ -380,6 +1127,16 @@ impl Predicate {
         Predicate::Single(LogicalClause::like(column_name, pattern))
     }
 
+    /// Creates a new negated `Like` predicate (`not like`).
+    pub(crate) fn not_like(column_name: &str, pattern: regex::Regex) -> Self {
+        Predicate::Single(LogicalClause::not_like(column_name, pattern))
+    }
+
+    /// Creates a new `Between` predicate.
+    pub(crate) fn between(column_name: &str, low: Literal, high: Literal) -> Self {
+        Predicate::Single(LogicalClause::between(column_name, low, high))
+    }
+
     /// Creates a new `And` predicate.
     pub(crate) fn and(predicates: Vec<Predicate>) -> Self {
         Predicate::And(predicates)
@@ -390,10 +1147,15 @@ impl Predicate {
     pub(crate) fn or(predicates: Vec<Predicate>) -> Self {
         Predicate::Or(predicates)
     }
+
+    /// Creates a new `Not` predicate.
+    pub(crate) fn not(predicate: Predicate) -> Self {
+        Predicate::Not(Box::new(predicate))
+    }
 }
 
 /// `LogicalOperator` defines the logical comparison operators supported in a predicate.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum LogicalOperator {
     /// Equal to `=`.
     Eq,
@@ -407,6 +1169,10 @@ pub(crate) enum LogicalOperator {
     Lesser,
     /// Lesser than or equal to `<=`.
     LesserEq,
+    /// Null-safe inequality `IS DISTINCT FROM`, which treats two `Null` values as equal.
+    IsDistinctFrom,
+    /// Null-safe equality `IS NOT DISTINCT FROM`, which treats two `Null` values as equal.
+    IsNotDistinctFrom,
 }
 
 impl From<BinaryOperator> for LogicalOperator {
@@ -418,52 +1184,113 @@ impl From<BinaryOperator> for LogicalOperator {
             BinaryOperator::Lesser => LogicalOperator::Lesser,
             BinaryOperator::LesserEq => LogicalOperator::LesserEq,
             BinaryOperator::NotEq => LogicalOperator::NotEq,
+            BinaryOperator::IsDistinctFrom => LogicalOperator::IsDistinctFrom,
+            BinaryOperator::IsNotDistinctFrom => LogicalOperator::IsNotDistinctFrom,
             _ => panic!("unsupported binary operator"),
         }
     }
 }
 
+impl std::fmt::Display for LogicalOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self {
+            LogicalOperator::Eq => "=",
+            LogicalOperator::NotEq => "!=",
+            LogicalOperator::Greater => ">",
+            LogicalOperator::GreaterEq => ">=",
+            LogicalOperator::Lesser => "<",
+            LogicalOperator::LesserEq => "<=",
+            LogicalOperator::IsDistinctFrom => "IS DISTINCT FROM",
+            LogicalOperator::IsNotDistinctFrom => "IS NOT DISTINCT FROM",
+        };
+        write!(f, "{}", operator)
+    }
+}
+
 impl LogicalOperator {
     /// Evaluates the comparison between two column values.
+    ///
+    /// A comparison involving `Null` never matches, except for the null-safe operators (`IS [NOT]
+    /// DISTINCT FROM`), for which two `Null` values are equal and differ from any other value. A
+    /// text compared with a date is read as a date (e.g., `'2024-01-15'`), and is an error if it is
+    /// not one.
     fn evaluate(&self, left: &ColumnValue, right: &ColumnValue) -> Result<bool, ExecutionError> {
         match (left, right) {
-            (ColumnValue::Int(left_value), ColumnValue::Int(right_value)) => Ok(match self {
-                LogicalOperator::Eq => left_value == right_value,
-                LogicalOperator::NotEq => left_value != right_value,
-                LogicalOperator::Greater => left_value > right_value,
-                LogicalOperator::GreaterEq => left_value >= right_value,
-                LogicalOperator::Lesser => left_value < right_value,
-                LogicalOperator::LesserEq => left_value <= right_value,
-            }),
-            (ColumnValue::Text(left_value), ColumnValue::Text(right_value)) => Ok(match self {
-                LogicalOperator::Eq => left_value == right_value,
-                LogicalOperator::NotEq => left_value != right_value,
-                LogicalOperator::Greater => left_value > right_value,
-                LogicalOperator::GreaterEq => left_value >= right_value,
-                LogicalOperator::Lesser => left_value < right_value,
-                LogicalOperator::LesserEq => left_value <= right_value,
-            }),
+            (ColumnValue::Null, _) | (_, ColumnValue::Null) if self.is_null_safe() => {
+                let both_null = left.is_null() && right.is_null();
+                Ok(both_null == (*self == LogicalOperator::IsNotDistinctFrom))
+            }
+            (ColumnValue::Null, _) | (_, ColumnValue::Null) => Ok(false),
+            (ColumnValue::Int(_), ColumnValue::Int(_))
+            | (ColumnValue::Text(_), ColumnValue::Text(_))
+            | (ColumnValue::Bool(_), ColumnValue::Bool(_))
+            | (ColumnValue::Date(_), ColumnValue::Date(_)) => Ok(self.holds_for(left.cmp(right))),
+            (ColumnValue::Date(_), ColumnValue::Text(text)) => {
+                self.evaluate(left, &ColumnValue::Date(Self::date_of(text)?))
+            }
+            (ColumnValue::Text(text), ColumnValue::Date(_)) => {
+                self.evaluate(&ColumnValue::Date(Self::date_of(text)?), right)
+            }
             _ => Err(ExecutionError::TypeMismatchInComparison),
         }
     }
 
-    /// Applies the logical operator to compare values resolved from a `ValueResolver`.
-    pub(crate) fn apply<V: ValueResolver>(
+    /// Evaluates the comparison between two column values in three-valued logic, returning
+    /// `None` (unknown) if a value is `Null` and the operator is not null-safe.
+    fn truth_value(
         &self,
-        lhs: &Literal,
-        rhs: &Literal,
-        resolver: &V,
-    ) -> Result<bool, ExecutionError> {
-        let lhs_value = resolver.resolve(lhs)?;
-        let rhs_value = resolver.resolve(rhs)?;
-        self.evaluate(&lhs_value, &rhs_value)
+        left: &ColumnValue,
+        right: &ColumnValue,
+    ) -> Result<Option<bool>, ExecutionError> {
+        if !self.is_null_safe() && (left.is_null() || right.is_null()) {
+            return Ok(None);
+        }
+        self.evaluate(left, right).map(Some)
+    }
+
+    /// Returns true if the operator holds for two values in the given ordering.
+    fn holds_for(&self, ordering: std::cmp::Ordering) -> bool {
+        match self {
+            LogicalOperator::Eq => ordering.is_eq(),
+            LogicalOperator::NotEq => ordering.is_ne(),
+            LogicalOperator::Greater => ordering.is_gt(),
+            LogicalOperator::GreaterEq => ordering.is_ge(),
+            LogicalOperator::Lesser => ordering.is_lt(),
+            LogicalOperator::LesserEq => ordering.is_le(),
+            LogicalOperator::IsDistinctFrom => ordering.is_ne(),
+            LogicalOperator::IsNotDistinctFrom => ordering.is_eq(),
+        }
+    }
+
+    /// Returns true if the operator treats two `Null` values as equal (`IS [NOT] DISTINCT FROM`).
+    fn is_null_safe(&self) -> bool {
+        matches!(
+            self,
+            LogicalOperator::IsDistinctFrom | LogicalOperator::IsNotDistinctFrom
+        )
+    }
+
+    fn date_of(text: &str) -> Result<Date, ExecutionError> {
+        Date::parse(text).ok_or_else(|| ExecutionError::InvalidDate(text.to_string()))
+    }
+
+    /// Applies the logical operator to compare values resolved from a `ValueResolver`.
+    pub(crate) fn apply<V: ValueResolver>(
+        &self,
+        lhs: &Literal,
+        rhs: &Literal,
+        resolver: &V,
+    ) -> Result<bool, ExecutionError> {
+        let lhs_value = resolver.resolve(lhs)?;
+        let rhs_value = resolver.resolve(rhs)?;
+        self.evaluate(&lhs_value, &rhs_value)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::query::parser::ast::{BinaryOperator, Literal};
+    use crate::query::parser::ast::{ArithmeticOperator, BinaryOperator, Literal, ScalarFunction};
 
     #[test]
     fn logical_clause_columns_for_comparison_with_column_on_lhs() {
@@ -475,6 +1302,33 @@ mod tests {
         assert_eq!(vec!["age"], clause.referenced_column_names());
     }
 
+    #[test]
+    fn logical_clause_columns_for_comparison_with_arithmetic() {
+        let clause = LogicalClause::comparison(
+            Literal::arithmetic(
+                Literal::ColumnReference("salary".to_string()),
+                ArithmeticOperator::Add,
+                Literal::ColumnReference("bonus".to_string()),
+            ),
+            LogicalOperator::Greater,
+            Literal::Int(1000),
+        );
+        assert_eq!(vec!["salary", "bonus"], clause.referenced_column_names());
+    }
+
+    #[test]
+    fn logical_clause_columns_for_comparison_with_function_call() {
+        let clause = LogicalClause::comparison(
+            Literal::function_call(
+                ScalarFunction::Upper,
+                vec![Literal::ColumnReference("name".to_string())],
+            ),
+            LogicalOperator::Eq,
+            Literal::Text("RELOP".to_string()),
+        );
+        assert_eq!(vec!["name"], clause.referenced_column_names());
+    }
+
     #[test]
     fn logical_clause_columns_for_comparison_with_columns_on_both_sides() {
         let clause = LogicalClause::comparison(
@@ -494,6 +1348,12 @@ mod tests {
         assert_eq!(vec!["name"], clause.referenced_column_names());
     }
 
+    #[test]
+    fn logical_clause_columns_for_between() {
+        let clause = LogicalClause::between("age", Literal::Int(10), Literal::Int(20));
+        assert_eq!(vec!["age"], clause.referenced_column_names());
+    }
+
     #[test]
     fn logical_operator_from_eq_operator() {
         assert_eq!(
@@ -558,6 +1418,124 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn apply_eq_on_bools_true() {
+        let schema =
+            crate::schema!["active" => crate::types::column_type::ColumnType::Bool].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(crate::row![true], &schema, &visible_positions);
+        assert!(LogicalOperator::Eq
+            .apply(
+                &Literal::ColumnReference("active".to_string()),
+                &Literal::Bool(true),
+                &row_view
+            )
+            .unwrap());
+    }
+
+    #[test]
+    fn apply_lesser_on_bools_false_before_true() {
+        let schema =
+            crate::schema!["active" => crate::types::column_type::ColumnType::Bool].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(crate::row![false], &schema, &visible_positions);
+        assert!(LogicalOperator::Lesser
+            .apply(&Literal::Bool(false), &Literal::Bool(true), &row_view)
+            .unwrap());
+    }
+
+    #[test]
+    fn apply_eq_on_bool_and_integer_is_a_type_mismatch() {
+        let schema = crate::schema!["id" => crate::types::column_type::ColumnType::Int].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(crate::row![1], &schema, &visible_positions);
+        let result = LogicalOperator::Eq.apply(&Literal::Int(1), &Literal::Bool(true), &row_view);
+        assert!(matches!(
+            result,
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
+    #[test]
+    fn apply_eq_on_integer_and_text_is_a_type_mismatch() {
+        let schema = crate::schema!["id" => crate::types::column_type::ColumnType::Int].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(crate::row![1], &schema, &visible_positions);
+        let result = LogicalOperator::Eq.apply(
+            &Literal::ColumnReference("id".to_string()),
+            &Literal::Text("abc".to_string()),
+            &row_view,
+        );
+        assert!(matches!(
+            result,
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
+    #[test]
+    fn apply_lesser_on_text_and_integer_is_a_type_mismatch() {
+        let schema = crate::schema!["name" => crate::types::column_type::ColumnType::Text].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(crate::row!["relop"], &schema, &visible_positions);
+        let result = LogicalOperator::Lesser.apply(
+            &Literal::ColumnReference("name".to_string()),
+            &Literal::Int(1),
+            &row_view,
+        );
+        assert!(matches!(
+            result,
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
+    fn joined_on(date: &str) -> Row {
+        Row::single(ColumnValue::date(Date::parse(date).unwrap()))
+    }
+
+    #[test]
+    fn apply_lesser_on_dates() {
+        let schema =
+            crate::schema!["joined_on" => crate::types::column_type::ColumnType::Date].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(joined_on("2024-01-15"), &schema, &visible_positions);
+        assert!(LogicalOperator::Lesser
+            .apply(
+                &Literal::ColumnReference("joined_on".to_string()),
+                &Literal::Date(Date::new(2024, 2, 1).unwrap()),
+                &row_view
+            )
+            .unwrap());
+    }
+
+    #[test]
+    fn apply_eq_on_date_and_text_reads_the_text_as_a_date() {
+        let schema =
+            crate::schema!["joined_on" => crate::types::column_type::ColumnType::Date].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(joined_on("2024-01-15"), &schema, &visible_positions);
+        assert!(LogicalOperator::Eq
+            .apply(
+                &Literal::Text("2024-01-15".to_string()),
+                &Literal::ColumnReference("joined_on".to_string()),
+                &row_view
+            )
+            .unwrap());
+    }
+
+    #[test]
+    fn apply_eq_on_date_and_invalid_date_text_is_an_error() {
+        let schema =
+            crate::schema!["joined_on" => crate::types::column_type::ColumnType::Date].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(joined_on("2024-01-15"), &schema, &visible_positions);
+        let result = LogicalOperator::Eq.apply(
+            &Literal::ColumnReference("joined_on".to_string()),
+            &Literal::Text("15/01/2024".to_string()),
+            &row_view,
+        );
+        assert!(matches!(result, Err(ExecutionError::InvalidDate(text)) if text == "15/01/2024"));
+    }
+
     #[test]
     fn apply_eq_on_integers_false() {
         let schema = crate::schema!["id" => crate::types::column_type::ColumnType::Int].unwrap();
@@ -998,6 +1976,70 @@ mod logical_operator_tests {
             .unwrap(),);
     }
 
+    #[test]
+    fn evaluate_with_null_does_not_match() {
+        let null = ColumnValue::null();
+        let one = ColumnValue::int(1);
+
+        assert!(!LogicalOperator::Eq.evaluate(&null, &one).unwrap());
+        assert!(!LogicalOperator::NotEq.evaluate(&one, &null).unwrap());
+        assert!(!LogicalOperator::Eq.evaluate(&null, &null).unwrap());
+    }
+
+    #[test]
+    fn evaluate_is_not_distinct_from_with_both_null() {
+        let null = ColumnValue::null();
+
+        assert!(LogicalOperator::IsNotDistinctFrom
+            .evaluate(&null, &null)
+            .unwrap());
+        assert!(!LogicalOperator::IsDistinctFrom
+            .evaluate(&null, &null)
+            .unwrap());
+    }
+
+    #[test]
+    fn evaluate_is_not_distinct_from_with_one_null() {
+        let null = ColumnValue::null();
+        let one = ColumnValue::int(1);
+
+        assert!(!LogicalOperator::IsNotDistinctFrom
+            .evaluate(&null, &one)
+            .unwrap());
+        assert!(!LogicalOperator::IsNotDistinctFrom
+            .evaluate(&one, &null)
+            .unwrap());
+        assert!(LogicalOperator::IsDistinctFrom
+            .evaluate(&one, &null)
+            .unwrap());
+    }
+
+    #[test]
+    fn evaluate_is_not_distinct_from_without_null() {
+        let one = ColumnValue::int(1);
+        let two = ColumnValue::int(2);
+
+        assert!(LogicalOperator::IsNotDistinctFrom
+            .evaluate(&one, &one)
+            .unwrap());
+        assert!(!LogicalOperator::IsNotDistinctFrom
+            .evaluate(&one, &two)
+            .unwrap());
+        assert!(LogicalOperator::IsDistinctFrom
+            .evaluate(&one, &two)
+            .unwrap());
+    }
+
+    #[test]
+    fn evaluate_is_distinct_from_type_mismatch() {
+        let result =
+            LogicalOperator::IsDistinctFrom.evaluate(&ColumnValue::int(1), &ColumnValue::text("1"));
+        assert!(matches!(
+            result,
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
     #[test]
     fn evaluate_type_mismatch() {
         let result = LogicalOperator::Eq.evaluate(&ColumnValue::int(1), &ColumnValue::text("1"));
@@ -1042,6 +2084,7 @@ mod predicate_tests {
             Predicate::Single(LogicalClause::Like {
                 column,
                 regex: _,
+                negated: false,
             }) if matches!(column, Literal::ColumnReference(ref name) if name == "name")
         ));
     }
@@ -1102,11 +2145,106 @@ mod predicate_tests {
     }
 
     #[test]
-    fn predicate_from_where_clause_with_invalid_regex_like() {
-        let clause = WhereClause::like("name", Literal::Text("[".to_string()));
+    fn predicate_from_where_clause_with_like_pattern_ending_with_escape() {
+        let clause = WhereClause::like("name", Literal::Text("rel\\".to_string()));
+
+        let result = Predicate::try_from(clause);
+        assert!(
+            matches!(result, Err(PlanningError::InvalidLikePattern(ref pattern)) if pattern == "rel\\")
+        );
+    }
+
+    #[test]
+    fn predicate_from_where_clause_with_between_of_equal_bounds() {
+        let clause = WhereClause(Expression::single(Clause::between(
+            "age",
+            Literal::Int(20),
+            Literal::Int(20),
+        )));
+        let predicate = Predicate::try_from(clause).unwrap();
+
+        let schema = schema!["age" => ColumnType::Int].unwrap();
+        let visible_positions = vec![0];
+
+        let row_view = RowView::new(row![20], &schema, &visible_positions);
+        assert!(predicate.matches(&row_view).unwrap());
+
+        let row_view = RowView::new(row![21], &schema, &visible_positions);
+        assert!(!predicate.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn attempt_to_get_predicate_from_where_clause_with_between_of_reversed_bounds() {
+        let clause = WhereClause(Expression::single(Clause::between(
+            "age",
+            Literal::Int(20),
+            Literal::Int(10),
+        )));
+
+        let result = Predicate::try_from(clause);
+        assert_eq!(
+            Err(PlanningError::InvalidBetweenRange {
+                low: "20".to_string(),
+                high: "10".to_string(),
+            }),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn predicate_from_where_clause_with_between_of_column_bounds() {
+        let clause = WhereClause(Expression::single(Clause::between(
+            "age",
+            Literal::ColumnReference("max_age".to_string()),
+            Literal::Int(10),
+        )));
 
         let result = Predicate::try_from(clause);
-        assert!(matches!(result, Err(PlanningError::InvalidRegex(_))));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn like_pattern_with_percent_wildcard() {
+        let regex = regex::Regex::new(&like_pattern_to_regex("rel%", false).unwrap()).unwrap();
+
+        assert!(regex.is_match("relop"));
+        assert!(regex.is_match("rel"));
+        assert!(!regex.is_match("query relop"));
+    }
+
+    #[test]
+    fn like_pattern_with_underscore_wildcard() {
+        let regex = regex::Regex::new(&like_pattern_to_regex("r_lop", false).unwrap()).unwrap();
+
+        assert!(regex.is_match("relop"));
+        assert!(regex.is_match("rulop"));
+        assert!(!regex.is_match("rlop"));
+        assert!(!regex.is_match("reelop"));
+    }
+
+    #[test]
+    fn like_pattern_with_escaped_wildcards() {
+        let regex =
+            regex::Regex::new(&like_pattern_to_regex("50\\%\\_off", false).unwrap()).unwrap();
+
+        assert!(regex.is_match("50%_off"));
+        assert!(!regex.is_match("500_off"));
+        assert!(!regex.is_match("50%xoff"));
+    }
+
+    #[test]
+    fn like_pattern_matches_regex_characters_literally() {
+        let regex = regex::Regex::new(&like_pattern_to_regex("^r.*[", false).unwrap()).unwrap();
+
+        assert!(regex.is_match("^r.*["));
+        assert!(!regex.is_match("relop"));
+    }
+
+    #[test]
+    fn like_pattern_matches_ignoring_case() {
+        let regex = regex::Regex::new(&like_pattern_to_regex("REL%", true).unwrap()).unwrap();
+
+        assert!(regex.is_match("relop"));
     }
 
     #[test]
@@ -1116,10 +2254,37 @@ mod predicate_tests {
         let result = Predicate::try_from(clause);
         assert!(matches!(
             result,
-            Ok(Predicate::Single(LogicalClause::Like { column, regex: _ })) if matches!(column, Literal::ColumnReference(ref name) if name == "name")
+            Ok(Predicate::Single(LogicalClause::Like { column, regex: _, negated: false })) if matches!(column, Literal::ColumnReference(ref name) if name == "name")
         ));
     }
 
+    #[test]
+    fn predicate_from_where_clause_with_ilike_matches_ignoring_case() {
+        let clause = WhereClause::ilike("name", Literal::Text("REL%".to_string()));
+        let predicate = Predicate::try_from(clause).unwrap();
+
+        let schema = schema!["name" => ColumnType::Text].unwrap();
+        let visible_positions = vec![0];
+
+        let row_view = RowView::new(row!["relop"], &schema, &visible_positions);
+        assert!(predicate.matches(&row_view).unwrap());
+
+        let row_view = RowView::new(row!["query"], &schema, &visible_positions);
+        assert!(!predicate.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn predicate_from_where_clause_with_like_matches_respecting_case() {
+        let clause = WhereClause::like("name", Literal::Text("REL%".to_string()));
+        let predicate = Predicate::try_from(clause).unwrap();
+
+        let schema = schema!["name" => ColumnType::Text].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(row!["relop"], &schema, &visible_positions);
+
+        assert!(!predicate.matches(&row_view).unwrap());
+    }
+
     #[test]
     fn matches_for_the_row() {
         let schema = schema!["age" => ColumnType::Int].unwrap();
@@ -1278,11 +2443,11 @@ mod predicate_tests {
                 BinaryOperator::Greater,
                 Literal::Int(30),
             )),
-            Expression::single(Clause::like("city", Literal::Text("[".to_string()))),
+            Expression::single(Clause::like("city", Literal::Text("\\".to_string()))),
         ]);
 
         let result = Predicate::try_from(clause);
-        assert!(matches!(result, Err(PlanningError::InvalidRegex(_))));
+        assert!(matches!(result, Err(PlanningError::InvalidLikePattern(_))));
     }
 
     #[test]
@@ -1467,42 +2632,224 @@ mod predicate_tests {
     }
 
     #[test]
-    fn matches_for_the_row_with_nested_or() {
-        let schema = schema![
-            "age" => ColumnType::Int,
-            "city" => ColumnType::Text,
-            "country" => ColumnType::Text
-        ]
-        .unwrap();
-        let row = row![25, "Paris", "FR"];
-        let visible_positions = vec![0, 1, 2];
-        let row_view = RowView::new(row, &schema, &visible_positions);
+    fn predicate_from_where_clause_with_not() {
+        let clause = WhereClause(Expression::not(Expression::single(Clause::comparison(
+            Literal::ColumnReference("age".to_string()),
+            BinaryOperator::Greater,
+            Literal::Int(30),
+        ))));
 
-        let predicate = Predicate::or(vec![
-            Predicate::comparison(
+        let predicate = Predicate::try_from(clause).unwrap();
+        assert_eq!(
+            Predicate::not(Predicate::comparison(
                 Literal::ColumnReference("age".to_string()),
                 LogicalOperator::Greater,
                 Literal::Int(30),
-            ),
-            Predicate::or(vec![
-                Predicate::comparison(
-                    Literal::ColumnReference("city".to_string()),
-                    LogicalOperator::Eq,
-                    Literal::Text("London".to_string()),
-                ),
-                Predicate::comparison(
-                    Literal::ColumnReference("country".to_string()),
-                    LogicalOperator::Eq,
-                    Literal::Text("FR".to_string()),
-                ),
-            ]),
-        ]);
+            )),
+            predicate
+        );
+    }
+
+    #[test]
+    fn matches_for_the_row_with_not() {
+        let schema = schema!["age" => ColumnType::Int, "city" => ColumnType::Text].unwrap();
+        let row = row![25, "London"];
+        let visible_positions = vec![0, 1];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let predicate = Predicate::not(Predicate::comparison(
+            Literal::ColumnReference("age".to_string()),
+            LogicalOperator::Greater,
+            Literal::Int(30),
+        ));
 
         assert!(predicate.matches(&row_view).unwrap());
     }
-}
 
-#[cfg(test)]
+    #[test]
+    fn does_not_match_for_the_row_with_not() {
+        let schema = schema!["age" => ColumnType::Int, "city" => ColumnType::Text].unwrap();
+        let row = row![35, "London"];
+        let visible_positions = vec![0, 1];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let predicate = Predicate::not(Predicate::comparison(
+            Literal::ColumnReference("age".to_string()),
+            LogicalOperator::Greater,
+            Literal::Int(30),
+        ));
+
+        assert!(!predicate.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn does_not_match_for_the_row_with_not_on_a_null_value() {
+        let schema = schema!["age" => ColumnType::Int, "city" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::int(25), ColumnValue::null()]);
+        let visible_positions = vec![0, 1];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let predicate = Predicate::not(Predicate::comparison(
+            Literal::ColumnReference("city".to_string()),
+            LogicalOperator::Eq,
+            Literal::Text("London".to_string()),
+        ));
+
+        assert!(!predicate.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn matches_for_the_row_with_not_on_an_and_false_despite_a_null_value() {
+        let schema = schema!["age" => ColumnType::Int, "city" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::int(25), ColumnValue::null()]);
+        let visible_positions = vec![0, 1];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let predicate = Predicate::not(Predicate::and(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("city".to_string()),
+                LogicalOperator::Eq,
+                Literal::Text("London".to_string()),
+            ),
+            Predicate::comparison(
+                Literal::ColumnReference("age".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(30),
+            ),
+        ]));
+
+        assert!(predicate.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn does_not_match_for_the_row_with_not_on_an_or_unknown_for_a_null_value() {
+        let schema = schema!["age" => ColumnType::Int, "city" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::int(25), ColumnValue::null()]);
+        let visible_positions = vec![0, 1];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let predicate = Predicate::not(Predicate::or(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("city".to_string()),
+                LogicalOperator::Eq,
+                Literal::Text("London".to_string()),
+            ),
+            Predicate::comparison(
+                Literal::ColumnReference("age".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(30),
+            ),
+        ]));
+
+        assert!(!predicate.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn matches_for_the_row_with_not_on_a_null_safe_comparison_with_a_null_value() {
+        let schema = schema!["age" => ColumnType::Int, "city" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::int(25), ColumnValue::null()]);
+        let visible_positions = vec![0, 1];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let predicate = Predicate::not(Predicate::comparison(
+            Literal::ColumnReference("city".to_string()),
+            LogicalOperator::IsNotDistinctFrom,
+            Literal::Text("London".to_string()),
+        ));
+
+        assert!(predicate.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn matches_for_the_row_with_nested_or() {
+        let schema = schema![
+            "age" => ColumnType::Int,
+            "city" => ColumnType::Text,
+            "country" => ColumnType::Text
+        ]
+        .unwrap();
+        let row = row![25, "Paris", "FR"];
+        let visible_positions = vec![0, 1, 2];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let predicate = Predicate::or(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("age".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(30),
+            ),
+            Predicate::or(vec![
+                Predicate::comparison(
+                    Literal::ColumnReference("city".to_string()),
+                    LogicalOperator::Eq,
+                    Literal::Text("London".to_string()),
+                ),
+                Predicate::comparison(
+                    Literal::ColumnReference("country".to_string()),
+                    LogicalOperator::Eq,
+                    Literal::Text("FR".to_string()),
+                ),
+            ]),
+        ]);
+
+        assert!(predicate.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn referenced_column_names_of_nested_predicates() {
+        let predicate = Predicate::and(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("age".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(18),
+            ),
+            Predicate::or(vec![
+                Predicate::like("name", regex::Regex::new("^J").unwrap()),
+                Predicate::not(Predicate::between(
+                    "salary",
+                    Literal::ColumnReference("min_salary".to_string()),
+                    Literal::Int(5000),
+                )),
+            ]),
+        ]);
+
+        assert_eq!(
+            vec!["age", "name", "salary", "min_salary"],
+            predicate.referenced_column_names()
+        );
+    }
+
+    #[test]
+    fn referenced_column_names_of_a_predicate_repeating_a_column() {
+        let predicate = Predicate::or(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("city".to_string()),
+                LogicalOperator::Eq,
+                Literal::Text("London".to_string()),
+            ),
+            Predicate::comparison(
+                Literal::ColumnReference("city".to_string()),
+                LogicalOperator::Eq,
+                Literal::Text("Paris".to_string()),
+            ),
+        ]);
+
+        assert_eq!(vec!["city", "city"], predicate.referenced_column_names());
+    }
+
+    #[test]
+    fn referenced_column_names_of_a_predicate_without_columns() {
+        let predicate = Predicate::not(Predicate::comparison(
+            Literal::Int(1),
+            LogicalOperator::Eq,
+            Literal::Int(2),
+        ));
+
+        assert!(predicate.referenced_column_names().is_empty());
+    }
+}
+
+#[cfg(test)]
 mod logical_clause_tests {
     use super::*;
     use crate::query::parser::ast::Literal;
@@ -1540,6 +2887,7 @@ mod logical_clause_tests {
             LogicalClause::Like {
                 column,
                 regex: _,
+                negated: false,
             } if matches!(column, Literal::ColumnReference(ref name) if name == "name")
         ));
     }
@@ -1598,6 +2946,98 @@ mod logical_clause_tests {
         assert!(!clause.matches(&row_view).unwrap());
     }
 
+    #[test]
+    fn matches_not_like() {
+        let schema = schema!["name" => ColumnType::Text].unwrap();
+        let row = row!["Doe"];
+        let visible_positions = vec![0];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let regex = regex::Regex::new("^J").unwrap();
+        let clause = LogicalClause::not_like("name", regex);
+        assert!(clause.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn does_not_match_not_like() {
+        let schema = schema!["name" => ColumnType::Text].unwrap();
+        let row = row!["John"];
+        let visible_positions = vec![0];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let regex = regex::Regex::new("^J").unwrap();
+        let clause = LogicalClause::not_like("name", regex);
+        assert!(!clause.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn does_not_match_not_like_on_a_null_value() {
+        let schema = schema!["name" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::null()]);
+        let visible_positions = vec![0];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let regex = regex::Regex::new("^J").unwrap();
+        let clause = LogicalClause::not_like("name", regex);
+        assert!(!clause.matches(&row_view).unwrap());
+    }
+
+    #[test]
+    fn matches_between_inclusive_of_bounds() {
+        let schema = schema!["age" => ColumnType::Int].unwrap();
+        let visible_positions = vec![0];
+        let clause = LogicalClause::between("age", Literal::Int(10), Literal::Int(20));
+
+        for age in [10, 15, 20] {
+            let row_view = RowView::new(row![age], &schema, &visible_positions);
+            assert!(clause.matches(&row_view).unwrap());
+        }
+    }
+
+    #[test]
+    fn does_not_match_between() {
+        let schema = schema!["age" => ColumnType::Int].unwrap();
+        let visible_positions = vec![0];
+        let clause = LogicalClause::between("age", Literal::Int(10), Literal::Int(20));
+
+        for age in [9, 21] {
+            let row_view = RowView::new(row![age], &schema, &visible_positions);
+            assert!(!clause.matches(&row_view).unwrap());
+        }
+    }
+
+    #[test]
+    fn attempt_to_match_between_with_an_upper_bound_type_mismatch_below_the_lower_bound() {
+        let schema = schema!["age" => ColumnType::Int].unwrap();
+        let visible_positions = vec![0];
+        let row_view = RowView::new(row![5], &schema, &visible_positions);
+
+        let clause =
+            LogicalClause::between("age", Literal::Int(10), Literal::Text("40".to_string()));
+        assert!(matches!(
+            clause.matches(&row_view),
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
+    #[test]
+    fn attempt_to_match_between_with_column_type_mismatch() {
+        let schema = schema!["age" => ColumnType::Int].unwrap();
+        let row = row![30];
+        let visible_positions = vec![0];
+        let row_view = RowView::new(row, &schema, &visible_positions);
+
+        let clause = LogicalClause::between(
+            "age",
+            Literal::Text("10".to_string()),
+            Literal::Text("40".to_string()),
+        );
+        assert!(matches!(
+            clause.matches(&row_view),
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
     #[test]
     fn attempt_to_match_clause_with_non_existing_column() {
         let schema = schema!["age" => ColumnType::Int].unwrap();
@@ -1671,7 +3111,7 @@ mod logical_clause_tests {
         let result = clause.matches(&row_view);
         assert!(matches!(
             result,
-            Err(ExecutionError::Schema(schema::error::SchemaError::AmbiguousColumnName(ref column_name))) if column_name == "id"
+            Err(ExecutionError::AmbiguousColumn(ref column_name)) if column_name == "id"
         ));
     }
 
@@ -1690,11 +3130,12 @@ mod logical_clause_tests {
         let clause = LogicalClause::Like {
             column: Literal::ColumnReference("name".to_string()),
             regex: regex::Regex::new("relop").unwrap(),
+            negated: false,
         };
         let result = clause.matches(&row_view);
         assert!(matches!(
             result,
-            Err(ExecutionError::Schema(schema::error::SchemaError::AmbiguousColumnName(ref column_name))) if column_name == "name"
+            Err(ExecutionError::AmbiguousColumn(ref column_name)) if column_name == "name"
         ));
     }
 
@@ -1715,41 +3156,196 @@ mod logical_clause_tests {
     }
 
     #[test]
-    fn comparison_clauses_are_not_equal() {
-        let clause1 = LogicalClause::comparison(
-            Literal::ColumnReference("age".to_string()),
-            LogicalOperator::Greater,
-            Literal::Int(18),
-        );
-        let clause2 = LogicalClause::comparison(
-            Literal::ColumnReference("age".to_string()),
-            LogicalOperator::Lesser,
-            Literal::Int(18),
-        );
+    fn comparison_clauses_are_not_equal() {
+        let clause1 = LogicalClause::comparison(
+            Literal::ColumnReference("age".to_string()),
+            LogicalOperator::Greater,
+            Literal::Int(18),
+        );
+        let clause2 = LogicalClause::comparison(
+            Literal::ColumnReference("age".to_string()),
+            LogicalOperator::Lesser,
+            Literal::Int(18),
+        );
+
+        assert_ne!(clause1, clause2);
+    }
+
+    #[test]
+    fn like_clauses_are_equal() {
+        let clause1 = LogicalClause::like("name", regex::Regex::new("^J").unwrap());
+        let clause2 = LogicalClause::like("name", regex::Regex::new("^J").unwrap());
+
+        assert_eq!(clause1, clause2);
+    }
+
+    #[test]
+    fn like_clauses_are_not_equal() {
+        let clause1 = LogicalClause::like("name", regex::Regex::new("^J").unwrap());
+        let clause2 = LogicalClause::like("name", regex::Regex::new("^P").unwrap());
+
+        assert_ne!(clause1, clause2);
+    }
+
+    fn in_values(lhs: Literal, values: Vec<ColumnValue>, negated: bool) -> LogicalClause {
+        LogicalClause::In {
+            lhs,
+            values: MembershipValues::Values(values.into_iter().collect()),
+            negated,
+        }
+    }
+
+    #[test]
+    fn matches_in_clause() {
+        let row = row![30];
+
+        let clause = in_values(
+            Literal::ColumnIndex(0),
+            vec![ColumnValue::int(20), ColumnValue::int(30)],
+            false,
+        );
+        assert!(clause.matches(&row).unwrap());
+    }
+
+    #[test]
+    fn does_not_match_in_clause() {
+        let row = row![30];
+
+        let clause = in_values(Literal::ColumnIndex(0), vec![ColumnValue::int(20)], false);
+        assert!(!clause.matches(&row).unwrap());
+    }
+
+    #[test]
+    fn does_not_match_in_clause_without_values() {
+        let row = row![30];
+
+        let clause = in_values(Literal::ColumnIndex(0), vec![], false);
+        assert!(!clause.matches(&row).unwrap());
+    }
+
+    #[test]
+    fn matches_not_in_clause() {
+        let row = row![30];
+
+        let clause = in_values(Literal::ColumnIndex(0), vec![ColumnValue::int(20)], true);
+        assert!(clause.matches(&row).unwrap());
+    }
+
+    #[test]
+    fn does_not_match_not_in_clause_with_null_among_values() {
+        let row = row![30];
+
+        let clause = in_values(
+            Literal::ColumnIndex(0),
+            vec![ColumnValue::int(20), ColumnValue::null()],
+            true,
+        );
+        assert!(!clause.matches(&row).unwrap());
+    }
+
+    #[test]
+    fn does_not_match_in_or_not_in_clause_for_null() {
+        let values = vec![ColumnValue::int(20)];
+
+        assert!(!in_values(Literal::Null, values.clone(), false)
+            .matches(&row![30])
+            .unwrap());
+        assert!(!in_values(Literal::Null, values, true)
+            .matches(&row![30])
+            .unwrap());
+    }
+
+    #[test]
+    fn attempt_to_match_in_clause_with_values_of_other_type() {
+        let row = row![30];
+
+        let clause = in_values(
+            Literal::ColumnIndex(0),
+            vec![ColumnValue::text("30")],
+            false,
+        );
+        assert!(matches!(
+            clause.matches(&row),
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
+    }
+
+    #[test]
+    fn attempt_to_match_in_clause_with_unevaluated_subquery() {
+        let row = row![30];
+
+        let clause = LogicalClause::In {
+            lhs: Literal::ColumnIndex(0),
+            values: MembershipValues::PlannedSubquery(Box::new(LogicalPlan::ShowTables)),
+            negated: false,
+        };
+        assert!(matches!(
+            clause.matches(&row),
+            Err(ExecutionError::UnevaluatedSubquery)
+        ));
+    }
+
+    #[test]
+    fn display_in_clauses() {
+        let clause = in_values(Literal::ColumnReference("id".to_string()), vec![], false);
+        assert_eq!("id IN (subquery)", clause.to_string());
 
-        assert_ne!(clause1, clause2);
+        let clause = in_values(Literal::ColumnReference("id".to_string()), vec![], true);
+        assert_eq!("id NOT IN (subquery)", clause.to_string());
+    }
+
+    fn exists(correlations: Vec<Correlation>) -> LogicalClause {
+        LogicalClause::Exists {
+            subquery: CorrelatedSubquery::Planned {
+                plan: Box::new(LogicalPlan::ShowTables),
+                correlations,
+            },
+        }
     }
 
     #[test]
-    fn like_clauses_are_equal() {
-        let clause1 = LogicalClause::like("name", regex::Regex::new("^J").unwrap());
-        let clause2 = LogicalClause::like("name", regex::Regex::new("^J").unwrap());
+    fn attempt_to_match_exists_clause_with_unexecutable_subquery() {
+        let row = row![30];
 
-        assert_eq!(clause1, clause2);
+        let clause = exists(vec![]);
+        assert!(matches!(
+            clause.matches(&row),
+            Err(ExecutionError::UnevaluatedSubquery)
+        ));
     }
 
     #[test]
-    fn like_clauses_are_not_equal() {
-        let clause1 = LogicalClause::like("name", regex::Regex::new("^J").unwrap());
-        let clause2 = LogicalClause::like("name", regex::Regex::new("^P").unwrap());
+    fn logical_clause_columns_for_exists() {
+        let clause = exists(vec![Correlation::new("departments.id")]);
+        assert_eq!(vec!["departments.id"], clause.referenced_column_names());
+    }
 
-        assert_ne!(clause1, clause2);
+    #[test]
+    fn bind_exists_clause() {
+        let schema = schema!["departments.id" => ColumnType::Int].unwrap();
+        let predicate = Predicate::Single(exists(vec![Correlation::new("departments.id")]))
+            .bind(&schema)
+            .unwrap();
+
+        assert!(matches!(
+            predicate,
+            Predicate::Single(LogicalClause::Exists { subquery: CorrelatedSubquery::Planned { correlations, .. } })
+                if correlations[0].column_name == "departments.id"
+                    && correlations[0].outer_column == Literal::ColumnIndex(0)
+        ));
+    }
+
+    #[test]
+    fn display_exists_clause() {
+        let clause = exists(vec![Correlation::new("departments.id")]);
+        assert_eq!("EXISTS (subquery)", clause.to_string());
     }
 }
 
 #[cfg(test)]
 mod row_view_value_resolver_tests {
     use super::*;
+    use crate::query::parser::ast::{ArithmeticOperator, ScalarFunction};
     use crate::schema::Schema;
     use crate::types::column_type::ColumnType;
 
@@ -1774,11 +3370,45 @@ mod row_view_value_resolver_tests {
         let value = row_view.resolve(&literal).unwrap();
         assert_eq!(value, ColumnValue::int(30));
     }
+
+    #[test]
+    fn resolve_arithmetic() {
+        let schema = Schema::new()
+            .add_column("salary", ColumnType::Int)
+            .unwrap()
+            .add_column("bonus", ColumnType::Int)
+            .unwrap();
+        let row = Row::filled(vec![ColumnValue::int(900), ColumnValue::int(200)]);
+        let row_view = RowView::new(row, &schema, &[0, 1]);
+
+        let literal = Literal::arithmetic(
+            Literal::ColumnReference("salary".to_string()),
+            ArithmeticOperator::Add,
+            Literal::ColumnReference("bonus".to_string()),
+        );
+        let value = row_view.resolve(&literal).unwrap();
+        assert_eq!(value, ColumnValue::int(1100));
+    }
+
+    #[test]
+    fn resolve_function_call() {
+        let schema = Schema::new().add_column("name", ColumnType::Text).unwrap();
+        let row = Row::filled(vec![ColumnValue::text("relop")]);
+        let row_view = RowView::new(row, &schema, &[0]);
+
+        let literal = Literal::function_call(
+            ScalarFunction::Upper,
+            vec![Literal::ColumnReference("name".to_string())],
+        );
+        let value = row_view.resolve(&literal).unwrap();
+        assert_eq!(value, ColumnValue::text("RELOP"));
+    }
 }
 
 #[cfg(test)]
 mod row_value_resolver_tests {
     use super::*;
+    use crate::query::parser::ast::ArithmeticOperator;
 
     #[test]
     fn resolve_by_index() {
@@ -1788,6 +3418,30 @@ mod row_value_resolver_tests {
         assert_eq!(value, ColumnValue::int(30));
     }
 
+    #[test]
+    fn resolve_arithmetic() {
+        let row = Row::filled(vec![ColumnValue::int(30)]);
+        let literal = Literal::arithmetic(
+            Literal::ColumnIndex(0),
+            ArithmeticOperator::Mul,
+            Literal::Int(2),
+        );
+        let value = row.resolve(&literal).unwrap();
+        assert_eq!(value, ColumnValue::int(60));
+    }
+
+    #[test]
+    fn resolve_arithmetic_with_division_by_zero() {
+        let row = Row::filled(vec![ColumnValue::int(0)]);
+        let literal = Literal::arithmetic(
+            Literal::Int(30),
+            ArithmeticOperator::Div,
+            Literal::ColumnIndex(0),
+        );
+        let result = row.resolve(&literal);
+        assert!(matches!(result, Err(ExecutionError::DivisionByZero)));
+    }
+
     #[test]
     fn resolve_unbound_error() {
         let row = Row::filled(vec![ColumnValue::int(30)]);
@@ -1809,7 +3463,7 @@ mod row_filter_tests {
             Literal::Int(25),
         );
         let row = Row::filled(vec![ColumnValue::int(30)]);
-        assert!(RowFilter::matches(&predicate, &row));
+        assert!(RowFilter::matches(&predicate, &row).unwrap());
     }
 
     #[test]
@@ -1820,24 +3474,42 @@ mod row_filter_tests {
             Literal::Int(35),
         );
         let row = Row::filled(vec![ColumnValue::int(30)]);
-        assert!(!RowFilter::matches(&predicate, &row));
+        assert!(!RowFilter::matches(&predicate, &row).unwrap());
     }
 
     #[test]
-    fn predicate_unbound_column_returns_false() {
+    fn predicate_unbound_column_is_an_error() {
         let predicate = Predicate::comparison(
             Literal::ColumnReference("age".to_string()),
             LogicalOperator::Eq,
             Literal::Int(30),
         );
         let row = Row::filled(vec![ColumnValue::int(30)]);
-        assert!(!RowFilter::matches(&predicate, &row));
+        assert!(matches!(
+            RowFilter::matches(&predicate, &row),
+            Err(ExecutionError::UnboundColumn(column)) if column == "age"
+        ));
+    }
+
+    #[test]
+    fn predicate_comparing_different_types_is_an_error() {
+        let predicate = Predicate::comparison(
+            Literal::ColumnIndex(0),
+            LogicalOperator::Eq,
+            Literal::Text("abc".to_string()),
+        );
+        let row = Row::filled(vec![ColumnValue::int(30)]);
+        assert!(matches!(
+            RowFilter::matches(&predicate, &row),
+            Err(ExecutionError::TypeMismatchInComparison)
+        ));
     }
 }
 
 #[cfg(test)]
 mod bind_tests {
     use super::*;
+    use crate::query::parser::ast::{ArithmeticOperator, ScalarFunction};
     use crate::types::column_type::ColumnType;
     use regex::Regex;
 
@@ -1866,6 +3538,66 @@ mod bind_tests {
         assert_eq!(bound_predicate, expected);
     }
 
+    #[test]
+    fn bind_comparison_with_function_call() {
+        let schema = crate::schema![
+            "id" => ColumnType::Int,
+            "name" => ColumnType::Text
+        ]
+        .unwrap();
+
+        let predicate = Predicate::comparison(
+            Literal::function_call(
+                ScalarFunction::Length,
+                vec![Literal::ColumnReference("name".to_string())],
+            ),
+            LogicalOperator::Greater,
+            Literal::Int(3),
+        );
+
+        let bound_predicate = predicate.bind(&schema).unwrap();
+
+        let expected = Predicate::comparison(
+            Literal::function_call(ScalarFunction::Length, vec![Literal::ColumnIndex(1)]),
+            LogicalOperator::Greater,
+            Literal::Int(3),
+        );
+        assert_eq!(bound_predicate, expected);
+    }
+
+    #[test]
+    fn bind_comparison_with_arithmetic() {
+        let schema = crate::schema![
+            "salary" => ColumnType::Int,
+            "bonus" => ColumnType::Int
+        ]
+        .unwrap();
+
+        let predicate = Predicate::comparison(
+            Literal::arithmetic(
+                Literal::ColumnReference("salary".to_string()),
+                ArithmeticOperator::Add,
+                Literal::ColumnReference("bonus".to_string()),
+            ),
+            LogicalOperator::Greater,
+            Literal::Int(1000),
+        );
+
+        let bound_predicate = predicate.bind(&schema).unwrap();
+
+        let expected = Predicate::comparison(
+            Literal::arithmetic(
+                Literal::ColumnIndex(0),
+                ArithmeticOperator::Add,
+                Literal::ColumnIndex(1),
+            ),
+            LogicalOperator::Greater,
+            Literal::Int(1000),
+        );
+
+        assert_eq!(bound_predicate, expected);
+    }
+
     #[test]
     fn bind_like() {
         let schema = crate::schema![
@@ -1882,6 +3614,27 @@ mod bind_tests {
         let expected = Predicate::Single(LogicalClause::Like {
             column: Literal::ColumnIndex(1),
             regex: Regex::new("^A").unwrap(),
+            negated: false,
+        });
+
+        assert_eq!(bound_predicate, expected);
+    }
+
+    #[test]
+    fn bind_between() {
+        let schema = crate::schema![
+            "id" => ColumnType::Int,
+            "age" => ColumnType::Int
+        ]
+        .unwrap();
+
+        let predicate = Predicate::between("age", Literal::Int(10), Literal::Int(20));
+        let bound_predicate = predicate.bind(&schema).unwrap();
+
+        let expected = Predicate::Single(LogicalClause::Between {
+            column: Literal::ColumnIndex(1),
+            low: Literal::Int(10),
+            high: Literal::Int(20),
         });
 
         assert_eq!(bound_predicate, expected);
@@ -1965,6 +3718,25 @@ mod bind_tests {
         assert_eq!(1, split_predicates.len());
     }
 
+    #[test]
+    fn split_a_not_clause_by_and() {
+        let predicate = Predicate::not(Predicate::and(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(1),
+            ),
+            Predicate::comparison(
+                Literal::ColumnReference("age".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(18),
+            ),
+        ]));
+
+        let split_predicates = predicate.split_by_and();
+        assert_eq!(1, split_predicates.len());
+    }
+
     #[test]
     fn split_and_clause_by_and_by_flattening() {
         let predicate = Predicate::and(vec![
@@ -2074,4 +3846,157 @@ mod bind_tests {
 
         assert!(!predicate.belongs_to(&schema));
     }
+
+    #[test]
+    fn equality_column_names_of_equality_between_columns() {
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::ColumnReference("departments.id".to_string()),
+        );
+
+        assert_eq!(
+            Some(("employees.id", "departments.id")),
+            predicate.equality_column_names()
+        );
+    }
+
+    #[test]
+    fn no_equality_column_names_for_comparison_with_constant() {
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Int(1),
+        );
+
+        assert!(predicate.equality_column_names().is_none());
+    }
+
+    #[test]
+    fn equality_constants_of_equality_with_constant() {
+        let predicate = Predicate::comparison(
+            Literal::Int(1),
+            LogicalOperator::Eq,
+            Literal::ColumnReference("employees.id".to_string()),
+        );
+
+        assert_eq!(
+            vec![("employees.id", ColumnValue::int(1))],
+            predicate.equality_constants()
+        );
+    }
+
+    #[test]
+    fn equality_constants_of_conjunction() {
+        let predicate = Predicate::And(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Greater,
+                Literal::Int(1),
+            ),
+            Predicate::comparison(
+                Literal::ColumnReference("name".to_string()),
+                LogicalOperator::Eq,
+                Literal::Text("relop".to_string()),
+            ),
+        ]);
+
+        assert_eq!(
+            vec![("name", ColumnValue::text("relop"))],
+            predicate.equality_constants()
+        );
+    }
+
+    #[test]
+    fn no_equality_constants_of_disjunction() {
+        let predicate = Predicate::Or(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(1),
+            ),
+            Predicate::comparison(
+                Literal::ColumnReference("id".to_string()),
+                LogicalOperator::Eq,
+                Literal::Int(2),
+            ),
+        ]);
+
+        assert!(predicate.equality_constants().is_empty());
+    }
+
+    #[test]
+    fn no_equality_constants_of_equality_with_null() {
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("id".to_string()),
+            LogicalOperator::Eq,
+            Literal::Null,
+        );
+
+        assert!(predicate.equality_constants().is_empty());
+    }
+
+    #[test]
+    fn no_equality_column_names_for_non_equality_between_columns() {
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::Greater,
+            Literal::ColumnReference("departments.id".to_string()),
+        );
+
+        assert!(predicate.equality_column_names().is_none());
+    }
+
+    #[test]
+    fn no_equality_column_names_for_conjunction() {
+        let predicate = Predicate::and(vec![
+            Predicate::comparison(
+                Literal::ColumnReference("employees.id".to_string()),
+                LogicalOperator::Eq,
+                Literal::ColumnReference("departments.id".to_string()),
+            ),
+            Predicate::comparison(
+                Literal::ColumnReference("employees.name".to_string()),
+                LogicalOperator::Eq,
+                Literal::ColumnReference("departments.name".to_string()),
+            ),
+        ]);
+
+        assert!(predicate.equality_column_names().is_none());
+    }
+
+    #[test]
+    fn display_comparison_predicate() {
+        let predicate = Predicate::comparison(
+            Literal::ColumnReference("employees.id".to_string()),
+            LogicalOperator::GreaterEq,
+            Literal::Int(10),
+        );
+        assert_eq!("employees.id >= 10", predicate.to_string());
+    }
+
+    #[test]
+    fn display_compound_predicate() {
+        let predicate = Predicate::or(vec![
+            Predicate::and(vec![
+                Predicate::comparison(
+                    Literal::ColumnReference("name".to_string()),
+                    LogicalOperator::Eq,
+                    Literal::Text("relop".to_string()),
+                ),
+                Predicate::like("city", Regex::new("^B.*").unwrap()),
+            ]),
+            Predicate::not(Predicate::between("id", Literal::Int(1), Literal::Int(5))),
+        ]);
+        assert_eq!(
+            "((name = 'relop' AND city LIKE '^B.*') OR NOT id BETWEEN 1 AND 5)",
+            predicate.to_string()
+        );
+    }
+
+    #[test]
+    fn display_not_like_predicate() {
+        let predicate = Predicate::not_like("city", Regex::new("^B.*").unwrap());
+        assert_eq!("city NOT LIKE '^B.*'", predicate.to_string());
+    }
 }
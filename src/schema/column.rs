@@ -1,10 +1,19 @@
 use crate::types::column_type::ColumnType;
+use crate::types::column_value::ColumnValue;
 
 /// Represents a column in a table schema, including its name and type.
+///
+/// A column of a table may also have a default value, filling the column when an insert omits
+/// it, and may reject `Null` values (`NOT NULL`). Columns accept `Null` values by default.
+/// An `Int` column may be auto-increment, receiving the next sequential value when an insert
+/// omits it.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Column {
     name: String,
     column_type: ColumnType,
+    default_value: Option<ColumnValue>,
+    nullable: bool,
+    auto_increment: bool,
 }
 
 impl Column {
@@ -22,9 +31,48 @@ impl Column {
         Column {
             name: name.into(),
             column_type,
+            default_value: None,
+            nullable: true,
+            auto_increment: false,
         }
     }
 
+    /// Sets the default value of the column, which fills the column when an insert omits it.
+    ///
+    /// The value is validated against the type of the column when the column is added to a
+    /// [`Schema`](crate::schema::Schema).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::column::Column;
+    /// use relop::types::column_type::ColumnType;
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let col = Column::new("active", ColumnType::Bool).with_default(ColumnValue::bool(true));
+    /// assert_eq!(col.default_value(), Some(&ColumnValue::bool(true)));
+    /// ```
+    pub fn with_default(mut self, default_value: ColumnValue) -> Column {
+        self.default_value = Some(default_value);
+        self
+    }
+
+    /// Makes the column reject `Null` values (`NOT NULL`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::column::Column;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let col = Column::new("name", ColumnType::Text).not_null();
+    /// assert!(!col.is_nullable());
+    /// ```
+    pub fn not_null(mut self) -> Column {
+        self.nullable = false;
+        self
+    }
+
     /// Returns the name of the column.
     ///
     /// # Examples
@@ -55,6 +103,41 @@ impl Column {
         &self.column_type
     }
 
+    /// Makes the column auto-increment: a row inserted with `Null` in (or without a value for)
+    /// the column receives the next sequential value, starting at 1.
+    ///
+    /// Only an `Int` column may be auto-increment, which is validated when the column is added to
+    /// a [`Schema`](crate::schema::Schema).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::column::Column;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let col = Column::new("id", ColumnType::Int).auto_increment();
+    /// assert!(col.is_auto_increment());
+    /// ```
+    pub fn auto_increment(mut self) -> Column {
+        self.auto_increment = true;
+        self
+    }
+
+    /// Returns the default value of the column, if it has one.
+    pub fn default_value(&self) -> Option<&ColumnValue> {
+        self.default_value.as_ref()
+    }
+
+    /// Returns `true` if the column accepts `Null` values.
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    /// Returns `true` if the column is auto-increment.
+    pub fn is_auto_increment(&self) -> bool {
+        self.auto_increment
+    }
+
     /// Checks if the column name matches the given name, ignoring case.
     ///
     /// # Examples
@@ -158,4 +241,22 @@ mod tests {
     fn has_prefix_returns_false_for_unqualified_column() {
         assert!(!Column::new("id", ColumnType::Int).has_prefix("employees"));
     }
+
+    #[test]
+    fn column_is_nullable_without_a_default_value() {
+        let column = Column::new("id", ColumnType::Int);
+
+        assert!(column.is_nullable());
+        assert_eq!(None, column.default_value());
+    }
+
+    #[test]
+    fn column_with_a_default_value_and_not_null() {
+        let column = Column::new("id", ColumnType::Int)
+            .with_default(ColumnValue::int(1))
+            .not_null();
+
+        assert!(!column.is_nullable());
+        assert_eq!(Some(&ColumnValue::int(1)), column.default_value());
+    }
 }
@@ -34,6 +34,33 @@ pub enum SchemaError {
     AmbiguousColumnName(String),
     /// The table name or alias used as a prefix does not exist in the current scope.
     TableAliasNotFound(String),
+    /// The schema already has a primary key.
+    PrimaryKeyAlreadyDefined,
+    /// The primary key does not name any column.
+    EmptyPrimaryKey,
+    /// A column of the primary key is not defined in the schema.
+    PrimaryKeyColumnNotFound(String),
+    /// A column is repeated in the primary key.
+    DuplicatePrimaryKeyColumn(String),
+    /// The column of a unique constraint is not defined in the schema.
+    UniqueConstraintColumnNotFound(String),
+    /// The column already has a unique constraint.
+    UniqueConstraintAlreadyDefined(String),
+    /// The type of the default value of a column does not match the type of the column.
+    DefaultValueTypeMismatch {
+        /// The name of the column.
+        column: String,
+        /// The type of the column.
+        expected: ColumnType,
+        /// The type of the default value.
+        actual: ColumnType,
+    },
+    /// A `Null` value is given for a column which does not accept `Null` values.
+    NullValueInNotNullColumn(String),
+    /// The auto-increment column is not an `Int` column.
+    AutoIncrementColumnNotInt(String),
+    /// The schema already has an auto-increment column.
+    AutoIncrementAlreadyDefined(String),
 }
 
 impl std::fmt::Display for SchemaError {
@@ -1,15 +1,23 @@
 pub mod column;
 pub mod error;
+pub mod primary_key;
+pub mod unique_constraint;
 
+use crate::query::parser::projection::ProjectedColumn;
 use crate::schema::column::Column;
 use crate::schema::error::SchemaError;
+use crate::schema::primary_key::PrimaryKey;
+use crate::schema::unique_constraint::UniqueConstraint;
 use crate::types::column_type::ColumnType;
 use crate::types::column_value::ColumnValue;
 
-/// Represents the schema of a table, defining its columns.
+/// Represents the schema of a table, defining its columns, its (optional) primary key and its
+/// unique constraints.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Schema {
     columns: Vec<Column>,
+    primary_key: Option<PrimaryKey>,
+    unique_constraints: Vec<UniqueConstraint>,
 }
 
 impl Default for Schema {
@@ -31,6 +39,8 @@ impl Schema {
     pub fn new() -> Self {
         Self {
             columns: Vec::new(),
+            primary_key: None,
+            unique_constraints: Vec::new(),
         }
     }
 
@@ -48,13 +58,167 @@ impl Schema {
     ///     .add_column("id", ColumnType::Int).unwrap()
     ///     .add_column("name", ColumnType::Text).unwrap();
     /// ```
-    pub fn add_column(mut self, name: &str, column_type: ColumnType) -> Result<Self, SchemaError> {
-        self.ensure_column_not_already_defined(name)?;
+    pub fn add_column(self, name: &str, column_type: ColumnType) -> Result<Self, SchemaError> {
+        self.add_column_definition(Column::new(name, column_type))
+    }
+
+    /// Adds a column, with its (optional) default value and nullability, to the schema.
+    ///
+    /// Returns an error if a column with the same name already exists, if the type of the default
+    /// value does not match the type of the column, if the default value is `Null` for a
+    /// column which does not accept `Null` values, or if the column is auto-increment but is not
+    /// an `Int` column, or the schema already has an auto-increment column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::column::Column;
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let schema = Schema::new()
+    ///     .add_column_definition(Column::new("id", ColumnType::Int).not_null()).unwrap()
+    ///     .add_column_definition(
+    ///         Column::new("active", ColumnType::Bool).with_default(ColumnValue::bool(true)),
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn add_column_definition(mut self, column: Column) -> Result<Self, SchemaError> {
+        self.ensure_column_not_already_defined(column.name())?;
+
+        if let Some(default_value) = column.default_value() {
+            match default_value.column_type() {
+                Some(actual) if !column.column_type().accepts(default_value) => {
+                    return Err(SchemaError::DefaultValueTypeMismatch {
+                        column: column.name().to_string(),
+                        expected: column.column_type().clone(),
+                        actual,
+                    });
+                }
+                None if !column.is_nullable() => {
+                    return Err(SchemaError::NullValueInNotNullColumn(
+                        column.name().to_string(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        if column.is_auto_increment() {
+            if *column.column_type() != ColumnType::Int {
+                return Err(SchemaError::AutoIncrementColumnNotInt(
+                    column.name().to_string(),
+                ));
+            }
+            if let Some(existing) = self
+                .columns
+                .iter()
+                .find(|column| column.is_auto_increment())
+            {
+                return Err(SchemaError::AutoIncrementAlreadyDefined(
+                    existing.name().to_string(),
+                ));
+            }
+        }
+
+        self.columns.push(column);
+        Ok(self)
+    }
+
+    /// Adds a primary key to the schema, on columns which are already defined.
+    ///
+    /// Returns an error if the schema already has a primary key, if the primary key has no
+    /// columns, or if any of its columns is not defined or is repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::Schema;
+    /// use relop::schema::primary_key::PrimaryKey;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let schema = Schema::new()
+    ///     .add_column("department_id", ColumnType::Int).unwrap()
+    ///     .add_column("id", ColumnType::Int).unwrap()
+    ///     .add_primary_key(PrimaryKey::composite(["department_id", "id"])).unwrap();
+    /// ```
+    pub fn add_primary_key(mut self, primary_key: PrimaryKey) -> Result<Self, SchemaError> {
+        if self.primary_key.is_some() {
+            return Err(SchemaError::PrimaryKeyAlreadyDefined);
+        }
+        if primary_key.column_names().is_empty() {
+            return Err(SchemaError::EmptyPrimaryKey);
+        }
+        for (index, column_name) in primary_key.column_names().iter().enumerate() {
+            if !self
+                .columns
+                .iter()
+                .any(|column| column.name() == column_name)
+            {
+                return Err(SchemaError::PrimaryKeyColumnNotFound(
+                    column_name.to_string(),
+                ));
+            }
+            if primary_key.column_names()[..index].contains(column_name) {
+                return Err(SchemaError::DuplicatePrimaryKeyColumn(
+                    column_name.to_string(),
+                ));
+            }
+        }
+        self.primary_key = Some(primary_key);
+        Ok(self)
+    }
+
+    /// Returns the primary key of the schema, if it has one.
+    pub fn primary_key(&self) -> Option<&PrimaryKey> {
+        self.primary_key.as_ref()
+    }
 
-        self.columns.push(Column::new(name, column_type));
+    /// Adds a unique constraint to the schema, on a column which is already defined.
+    ///
+    /// Returns an error if the column is not defined, or if it already has a unique constraint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::Schema;
+    /// use relop::schema::unique_constraint::UniqueConstraint;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let schema = Schema::new()
+    ///     .add_column("id", ColumnType::Int).unwrap()
+    ///     .add_column("email", ColumnType::Text).unwrap()
+    ///     .add_unique_constraint(UniqueConstraint::new("email")).unwrap();
+    /// ```
+    pub fn add_unique_constraint(
+        mut self,
+        unique_constraint: UniqueConstraint,
+    ) -> Result<Self, SchemaError> {
+        let column_name = unique_constraint.column_name();
+        if !self
+            .columns
+            .iter()
+            .any(|column| column.name() == column_name)
+        {
+            return Err(SchemaError::UniqueConstraintColumnNotFound(
+                column_name.to_string(),
+            ));
+        }
+        if self.unique_constraints.contains(&unique_constraint) {
+            return Err(SchemaError::UniqueConstraintAlreadyDefined(
+                column_name.to_string(),
+            ));
+        }
+        self.unique_constraints.push(unique_constraint);
         Ok(self)
     }
 
+    /// Returns the unique constraints of the schema, in the order in which they were added.
+    pub fn unique_constraints(&self) -> &[UniqueConstraint] {
+        &self.unique_constraints
+    }
+
     /// Returns the position (index) of the column with the given name.
     ///
     /// This method supports:
@@ -86,13 +250,7 @@ impl Schema {
     /// assert_eq!(schema.column_position("ID").unwrap(), Some(0));
     /// ```
     pub fn column_position(&self, column_name: &str) -> Result<Option<usize>, SchemaError> {
-        let matches: Vec<usize> = self
-            .columns
-            .iter()
-            .enumerate()
-            .filter(|(_, column)| column.matches(column_name))
-            .map(|(position, _)| position)
-            .collect();
+        let matches = self.matching_column_positions(column_name);
 
         match matches.len() {
             1 => Ok(Some(matches[0])),
@@ -101,6 +259,28 @@ impl Schema {
         }
     }
 
+    /// Returns the type of the column with the given name.
+    ///
+    /// The column is looked up like [`Schema::column_position`]: by its exact or unqualified name,
+    /// ignoring ASCII case, with the same errors for ambiguous names and unknown prefixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::Schema;
+    /// use relop::types::column_type::ColumnType;
+    ///
+    /// let schema = Schema::new().add_column("employees.id", ColumnType::Int).unwrap();
+    ///
+    /// assert_eq!(schema.column_type("id").unwrap(), Some(&ColumnType::Int));
+    /// assert_eq!(schema.column_type("employees.name").unwrap(), None);
+    /// ```
+    pub fn column_type(&self, column_name: &str) -> Result<Option<&ColumnType>, SchemaError> {
+        Ok(self
+            .column_position(column_name)?
+            .and_then(|position| self.column_type_at(position)))
+    }
+
     /// Returns the number of columns in the schema.
     ///
     /// # Examples
@@ -116,6 +296,32 @@ impl Schema {
         self.columns.len()
     }
 
+    /// Returns the type of the column at the given position, if the position is within bounds.
+    pub(crate) fn column_type_at(&self, position: usize) -> Option<&ColumnType> {
+        self.columns
+            .get(position)
+            .map(|column| column.column_type())
+    }
+
+    /// Returns the positions of all the columns matching the given name (qualified or unqualified).
+    pub(crate) fn matching_column_positions(&self, column_name: &str) -> Vec<usize> {
+        self.columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.matches(column_name))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Returns the names of all the columns qualified with the given prefix (table name or alias).
+    pub(crate) fn column_names_with_prefix(&self, prefix: &str) -> Vec<&str> {
+        self.columns
+            .iter()
+            .filter(|column| column.has_prefix(prefix))
+            .map(|column| column.name())
+            .collect()
+    }
+
     /// Merges this schema with another schema by combining their columns.
     /// Prefixes column names if the respective table prefix is provided.
     pub(crate) fn merge_with_prefixes(
@@ -131,6 +337,8 @@ impl Schema {
 
         Self {
             columns: merged_columns,
+            primary_key: None,
+            unique_constraints: Vec::new(),
         }
     }
 
@@ -138,29 +346,83 @@ impl Schema {
     pub(crate) fn with_prefix(&self, prefix: &str) -> Self {
         let mut columns = Vec::with_capacity(self.columns.len());
         Self::merge_column_name_with_prefix(Some(prefix), &self.columns, &mut columns);
-        Self { columns }
+        Self {
+            columns,
+            primary_key: None,
+            unique_constraints: Vec::new(),
+        }
     }
 
     /// Creates a new `Schema` containing only the specified columns.
-    pub(crate) fn project(&self, column_names: &[String]) -> Self {
-        let mut projected_columns = Vec::with_capacity(column_names.len());
-
-        for column_name in column_names {
+    ///
+    /// Aliased columns are renamed to their alias, unaliased columns keep their original (possibly qualified) name.
+    /// Computed columns are named after their alias.
+    pub(crate) fn project(&self, projected_columns: &[ProjectedColumn]) -> Self {
+        let mut columns = Vec::with_capacity(projected_columns.len());
+
+        for projected_column in projected_columns {
+            // Computed columns are named after their alias, and typed by their expression.
+            if let (Some(expression), Some(alias)) =
+                (projected_column.expression(), projected_column.alias())
+            {
+                if let Some(column_type) = expression.value_type() {
+                    columns.push(Column::new(alias, column_type));
+                }
+                continue;
+            }
             // Find the column by name.
             if let Some(column) = self
                 .columns
                 .iter()
-                .find(|column| column.matches(column_name))
+                .find(|column| column.matches(projected_column.name()))
             {
-                projected_columns.push(Column::new(
-                    column.name().to_string(),
-                    column.column_type().clone(),
-                ));
+                let name = projected_column.alias().unwrap_or(column.name());
+                columns.push(Column::new(name, column.column_type().clone()));
             }
         }
 
         Self {
-            columns: projected_columns,
+            columns,
+            primary_key: None,
+            unique_constraints: Vec::new(),
+        }
+    }
+
+    /// Creates a new `Schema` with the given computed columns (names and types) appended after the
+    /// existing ones.
+    pub(crate) fn with_computed_columns(&self, computed_columns: &[(&str, ColumnType)]) -> Self {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| Column::new(column.name(), column.column_type().clone()))
+            .chain(
+                computed_columns
+                    .iter()
+                    .map(|(name, column_type)| Column::new(*name, column_type.clone())),
+            )
+            .collect();
+        Self {
+            columns,
+            primary_key: None,
+            unique_constraints: Vec::new(),
+        }
+    }
+
+    /// Creates a new `Schema` containing the columns at the given positions, in the given order.
+    ///
+    /// Each position may carry a new name for the column, columns without one keep their original name.
+    pub(crate) fn project_positions(&self, positions: &[(usize, Option<&str>)]) -> Self {
+        let columns = positions
+            .iter()
+            .map(|(position, name)| {
+                let column = &self.columns[*position];
+                Column::new(name.unwrap_or(column.name()), column.column_type().clone())
+            })
+            .collect();
+        Self {
+            columns,
+            primary_key: None,
+            unique_constraints: Vec::new(),
         }
     }
 
@@ -180,17 +442,70 @@ impl Schema {
 
         for (index, column) in self.columns.iter().enumerate() {
             let value = &values[index];
+            if value.is_null() && !column.is_nullable() && !column.is_auto_increment() {
+                return Err(SchemaError::NullValueInNotNullColumn(
+                    column.name().to_string(),
+                ));
+            }
             if !column.column_type().accepts(value) {
                 return Err(SchemaError::ColumnTypeMismatch {
                     column: column.name().to_string(),
                     expected: column.column_type().clone(),
-                    actual: value.column_type(),
+                    //SAFETY: every column type accepts `Null`, so a rejected value always has a type.
+                    actual: value.column_type().unwrap(),
                 });
             }
         }
         Ok(())
     }
 
+    /// Returns the names of all the columns, in order.
+    pub(crate) fn column_names(&self) -> Vec<&str> {
+        self.columns.iter().map(|column| column.name()).collect()
+    }
+
+    /// Returns all the columns, in order.
+    pub(crate) fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Returns the positions of the columns of the primary key, in the order of the primary key.
+    pub(crate) fn primary_key_column_positions(&self) -> Option<Vec<usize>> {
+        self.primary_key.as_ref().map(|primary_key| {
+            primary_key
+                .column_names()
+                .iter()
+                .filter_map(|column_name| {
+                    self.columns
+                        .iter()
+                        .position(|column| column.name() == column_name)
+                })
+                .collect()
+        })
+    }
+
+    /// Returns the position of the auto-increment column, if the schema has one.
+    pub(crate) fn auto_increment_column_position(&self) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|column| column.is_auto_increment())
+    }
+
+    /// Returns the names and positions of the uniquely-constrained columns, in the order of the
+    /// unique constraints.
+    pub(crate) fn unique_column_positions(&self) -> Vec<(&str, usize)> {
+        self.unique_constraints
+            .iter()
+            .filter_map(|unique_constraint| {
+                let column_name = unique_constraint.column_name();
+                self.columns
+                    .iter()
+                    .position(|column| column.name() == column_name)
+                    .map(|position| (column_name, position))
+            })
+            .collect()
+    }
+
     /// Returns true if `Schema` contains the column_name.
     ///
     /// This handles both qualified and unqualified name match.
@@ -241,14 +556,6 @@ impl Schema {
     fn get_column(&self, index: usize) -> Option<&Column> {
         self.columns.get(index)
     }
-
-    pub(crate) fn column_names(&self) -> Vec<&str> {
-        self.columns.iter().map(|column| column.name()).collect()
-    }
-
-    pub(crate) fn columns(&self) -> &[Column] {
-        &self.columns
-    }
 }
 
 #[cfg(test)]
@@ -512,6 +819,83 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn column_type() {
+        let schema = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_column("name", ColumnType::Text)
+            .unwrap();
+
+        assert_eq!(Some(&ColumnType::Text), schema.column_type("name").unwrap());
+    }
+
+    #[test]
+    fn column_type_of_a_column_that_does_not_exist_in_schema() {
+        let schema = Schema::new().add_column("id", ColumnType::Int).unwrap();
+
+        assert!(schema.column_type("age").unwrap().is_none());
+    }
+
+    #[test]
+    fn column_type_with_qualified_name() {
+        let schema = Schema::new()
+            .add_column("employees.id", ColumnType::Int)
+            .unwrap()
+            .add_column("departments.name", ColumnType::Text)
+            .unwrap();
+
+        assert_eq!(
+            Some(&ColumnType::Int),
+            schema.column_type("employees.id").unwrap()
+        );
+        assert_eq!(
+            Some(&ColumnType::Text),
+            schema.column_type("departments.name").unwrap()
+        );
+    }
+
+    #[test]
+    fn column_type_with_unqualified_and_case_insensitive_lookup() {
+        let schema = Schema::new()
+            .add_column("employees.name", ColumnType::Text)
+            .unwrap();
+
+        assert_eq!(Some(&ColumnType::Text), schema.column_type("name").unwrap());
+        assert_eq!(
+            Some(&ColumnType::Text),
+            schema.column_type("EMPLOYEES.NAME").unwrap()
+        );
+    }
+
+    #[test]
+    fn column_type_with_ambiguous_name() {
+        let schema = Schema::new()
+            .add_column("employees.id", ColumnType::Int)
+            .unwrap()
+            .add_column("departments.id", ColumnType::Int)
+            .unwrap();
+
+        let result = schema.column_type("id");
+        assert!(matches!(
+            result,
+            Err(SchemaError::AmbiguousColumnName(ref column_name)) if column_name == "id"
+        ));
+    }
+
+    #[test]
+    fn column_type_with_invalid_prefix_returns_table_alias_not_found() {
+        let schema = Schema::new()
+            .add_column("employees.id", ColumnType::Int)
+            .unwrap();
+
+        let result = schema.column_type("dep.id");
+        assert!(matches!(
+            result,
+            Err(SchemaError::TableAliasNotFound(ref prefix)) if prefix == "dep"
+        ));
+    }
+
     #[test]
     fn schema_with_prefix() {
         let mut schema = Schema::new();
@@ -540,7 +924,8 @@ mod tests {
             .add_column("age", ColumnType::Int)
             .unwrap();
 
-        let projected_schema = schema.project(&["name".to_string(), "id".to_string()]);
+        let projected_schema =
+            schema.project(&[ProjectedColumn::new("name"), ProjectedColumn::new("id")]);
 
         assert_eq!(2, projected_schema.column_count());
 
@@ -550,4 +935,331 @@ mod tests {
         assert_eq!(ColumnType::Text, *columns[0].column_type());
         assert_eq!(ColumnType::Int, *columns[1].column_type());
     }
+
+    #[test]
+    fn column_names_with_prefix() {
+        let mut schema = Schema::new();
+        schema = schema
+            .add_column("employees.id", ColumnType::Int)
+            .unwrap()
+            .add_column("departments.id", ColumnType::Int)
+            .unwrap()
+            .add_column("employees.name", ColumnType::Text)
+            .unwrap();
+
+        assert_eq!(
+            vec!["employees.id", "employees.name"],
+            schema.column_names_with_prefix("employees")
+        );
+        assert!(schema.column_names_with_prefix("locations").is_empty());
+    }
+
+    #[test]
+    fn project_positions_from_schema() {
+        let mut schema = Schema::new();
+        schema = schema
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_column("name", ColumnType::Text)
+            .unwrap();
+
+        let projected_schema = schema.project_positions(&[(1, None), (0, Some("employee_id"))]);
+
+        let columns = projected_schema.columns();
+        assert_eq!(2, projected_schema.column_count());
+        assert_eq!("name", columns[0].name());
+        assert_eq!("employee_id", columns[1].name());
+        assert_eq!(ColumnType::Int, *columns[1].column_type());
+    }
+
+    #[test]
+    fn project_columns_with_alias_from_schema() {
+        let mut schema = Schema::new();
+        schema = schema
+            .add_column("employees.id", ColumnType::Int)
+            .unwrap()
+            .add_column("employees.name", ColumnType::Text)
+            .unwrap();
+
+        let projected_schema = schema.project(&[
+            ProjectedColumn::aliased("id", "employee_id"),
+            ProjectedColumn::new("name"),
+        ]);
+
+        let columns = projected_schema.columns();
+        assert_eq!("employee_id", columns[0].name());
+        assert_eq!("employees.name", columns[1].name());
+        assert_eq!(ColumnType::Int, *columns[0].column_type());
+    }
+
+    #[test]
+    fn schema_with_computed_columns() {
+        let schema = Schema::new()
+            .add_column("employees.id", ColumnType::Int)
+            .unwrap();
+
+        let extended_schema = schema.with_computed_columns(&[("annual", ColumnType::Int)]);
+
+        let columns = extended_schema.columns();
+        assert_eq!(2, extended_schema.column_count());
+        assert_eq!("employees.id", columns[0].name());
+        assert_eq!("annual", columns[1].name());
+        assert_eq!(ColumnType::Int, *columns[1].column_type());
+    }
+
+    #[test]
+    fn project_computed_columns_from_schema() {
+        use crate::query::parser::ast::{ArithmeticOperator, Literal};
+
+        let mut schema = Schema::new();
+        schema = schema
+            .add_column("employees.id", ColumnType::Int)
+            .unwrap()
+            .add_column("employees.salary", ColumnType::Int)
+            .unwrap();
+
+        let projected_schema = schema.project(&[
+            ProjectedColumn::new("id"),
+            ProjectedColumn::computed(
+                Literal::arithmetic(
+                    Literal::ColumnReference("salary".to_string()),
+                    ArithmeticOperator::Mul,
+                    Literal::Int(12),
+                ),
+                "annual",
+            ),
+        ]);
+
+        let columns = projected_schema.columns();
+        assert_eq!(2, projected_schema.column_count());
+        assert_eq!("employees.id", columns[0].name());
+        assert_eq!("annual", columns[1].name());
+        assert_eq!(ColumnType::Int, *columns[1].column_type());
+    }
+
+    #[test]
+    fn add_composite_primary_key_to_schema() {
+        let schema = Schema::new()
+            .add_column("department_id", ColumnType::Int)
+            .unwrap()
+            .add_column("name", ColumnType::Text)
+            .unwrap()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_primary_key(PrimaryKey::composite(["id", "department_id"]))
+            .unwrap();
+
+        assert_eq!(
+            Some(&PrimaryKey::composite(["id", "department_id"])),
+            schema.primary_key()
+        );
+        assert_eq!(Some(vec![2, 0]), schema.primary_key_column_positions());
+    }
+
+    #[test]
+    fn schema_without_primary_key() {
+        let schema = Schema::new().add_column("id", ColumnType::Int).unwrap();
+
+        assert!(schema.primary_key().is_none());
+        assert!(schema.primary_key_column_positions().is_none());
+    }
+
+    #[test]
+    fn attempt_to_add_primary_key_on_a_non_existent_column() {
+        let result = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_primary_key(PrimaryKey::composite(["id", "department_id"]));
+
+        assert!(
+            matches!(result, Err(SchemaError::PrimaryKeyColumnNotFound(column)) if column == "department_id")
+        );
+    }
+
+    #[test]
+    fn attempt_to_add_primary_key_with_a_repeated_column() {
+        let result = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_primary_key(PrimaryKey::composite(["id", "id"]));
+
+        assert!(
+            matches!(result, Err(SchemaError::DuplicatePrimaryKeyColumn(column)) if column == "id")
+        );
+    }
+
+    #[test]
+    fn attempt_to_add_primary_key_without_columns() {
+        let result = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_primary_key(PrimaryKey::composite(Vec::<String>::new()));
+
+        assert!(matches!(result, Err(SchemaError::EmptyPrimaryKey)));
+    }
+
+    #[test]
+    fn attempt_to_add_a_second_primary_key() {
+        let result = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_primary_key(PrimaryKey::single("id"))
+            .unwrap()
+            .add_primary_key(PrimaryKey::single("id"));
+
+        assert!(matches!(result, Err(SchemaError::PrimaryKeyAlreadyDefined)));
+    }
+
+    #[test]
+    fn add_unique_constraints_to_schema() {
+        let schema = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_column("email", ColumnType::Text)
+            .unwrap()
+            .add_column("phone", ColumnType::Text)
+            .unwrap()
+            .add_unique_constraint(UniqueConstraint::new("phone"))
+            .unwrap()
+            .add_unique_constraint(UniqueConstraint::new("email"))
+            .unwrap();
+
+        assert_eq!(
+            &[
+                UniqueConstraint::new("phone"),
+                UniqueConstraint::new("email")
+            ],
+            schema.unique_constraints()
+        );
+        assert_eq!(
+            vec![("phone", 2), ("email", 1)],
+            schema.unique_column_positions()
+        );
+    }
+
+    #[test]
+    fn attempt_to_add_unique_constraint_on_a_non_existent_column() {
+        let result = Schema::new()
+            .add_column("id", ColumnType::Int)
+            .unwrap()
+            .add_unique_constraint(UniqueConstraint::new("email"));
+
+        assert!(
+            matches!(result, Err(SchemaError::UniqueConstraintColumnNotFound(column)) if column == "email")
+        );
+    }
+
+    #[test]
+    fn attempt_to_add_a_second_unique_constraint_on_a_column() {
+        let result = Schema::new()
+            .add_column("email", ColumnType::Text)
+            .unwrap()
+            .add_unique_constraint(UniqueConstraint::new("email"))
+            .unwrap()
+            .add_unique_constraint(UniqueConstraint::new("email"));
+
+        assert!(
+            matches!(result, Err(SchemaError::UniqueConstraintAlreadyDefined(column)) if column == "email")
+        );
+    }
+
+    #[test]
+    fn add_column_definition_with_a_default_value_to_schema() {
+        let schema = Schema::new()
+            .add_column_definition(
+                Column::new("active", ColumnType::Bool).with_default(ColumnValue::bool(true)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(&ColumnValue::bool(true)),
+            schema.get_column(0).unwrap().default_value()
+        );
+    }
+
+    #[test]
+    fn attempt_to_add_column_definition_with_a_default_value_of_a_different_type() {
+        let result = Schema::new().add_column_definition(
+            Column::new("active", ColumnType::Bool).with_default(ColumnValue::int(1)),
+        );
+
+        assert!(matches!(
+            result,
+            Err(SchemaError::DefaultValueTypeMismatch { column, expected: ColumnType::Bool, actual: ColumnType::Int }) if column == "active"
+        ));
+    }
+
+    #[test]
+    fn attempt_to_add_not_null_column_definition_with_a_null_default_value() {
+        let result = Schema::new().add_column_definition(
+            Column::new("id", ColumnType::Int)
+                .not_null()
+                .with_default(ColumnValue::null()),
+        );
+
+        assert!(
+            matches!(result, Err(SchemaError::NullValueInNotNullColumn(column)) if column == "id")
+        );
+    }
+
+    #[test]
+    fn attempt_to_check_type_compatability_with_null_in_a_not_null_column() {
+        let schema = Schema::new()
+            .add_column_definition(Column::new("id", ColumnType::Int).not_null())
+            .unwrap();
+
+        let result = schema.check_type_compatability(&[ColumnValue::null()]);
+
+        assert!(
+            matches!(result, Err(SchemaError::NullValueInNotNullColumn(column)) if column == "id")
+        );
+    }
+
+    #[test]
+    fn add_auto_increment_column_definition_to_schema() {
+        let schema = Schema::new()
+            .add_column_definition(Column::new("id", ColumnType::Int).auto_increment())
+            .unwrap()
+            .add_column("name", ColumnType::Text)
+            .unwrap();
+
+        assert_eq!(Some(0), schema.auto_increment_column_position());
+    }
+
+    #[test]
+    fn attempt_to_add_auto_increment_column_definition_of_a_non_int_type() {
+        let result = Schema::new()
+            .add_column_definition(Column::new("name", ColumnType::Text).auto_increment());
+
+        assert!(
+            matches!(result, Err(SchemaError::AutoIncrementColumnNotInt(column)) if column == "name")
+        );
+    }
+
+    #[test]
+    fn attempt_to_add_a_second_auto_increment_column_definition() {
+        let result = Schema::new()
+            .add_column_definition(Column::new("id", ColumnType::Int).auto_increment())
+            .unwrap()
+            .add_column_definition(Column::new("code", ColumnType::Int).auto_increment());
+
+        assert!(
+            matches!(result, Err(SchemaError::AutoIncrementAlreadyDefined(column)) if column == "id")
+        );
+    }
+
+    #[test]
+    fn check_type_compatability_with_null_in_a_not_null_auto_increment_column() {
+        let schema = Schema::new()
+            .add_column_definition(
+                Column::new("id", ColumnType::Int)
+                    .not_null()
+                    .auto_increment(),
+            )
+            .unwrap();
+
+        assert!(schema
+            .check_type_compatability(&[ColumnValue::null()])
+            .is_ok());
+    }
 }
@@ -0,0 +1,66 @@
+/// Represents the primary key of a table: the column, or the columns, whose values identify a row.
+///
+/// A composite primary key identifies a row by the combination of the values of its columns, in
+/// order: two rows may share the value of one of its columns, as long as the values of the other
+/// columns differ.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimaryKey {
+    column_names: Vec<String>,
+}
+
+impl PrimaryKey {
+    /// Creates a primary key on a single column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::primary_key::PrimaryKey;
+    ///
+    /// let primary_key = PrimaryKey::single("id");
+    /// assert_eq!(primary_key.column_names(), &["id"]);
+    /// ```
+    pub fn single<N: Into<String>>(column_name: N) -> PrimaryKey {
+        Self {
+            column_names: vec![column_name.into()],
+        }
+    }
+
+    /// Creates a primary key on multiple columns, in the given order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::primary_key::PrimaryKey;
+    ///
+    /// let primary_key = PrimaryKey::composite(["department_id", "id"]);
+    /// assert_eq!(primary_key.column_names(), &["department_id", "id"]);
+    /// ```
+    pub fn composite<N: Into<String>>(column_names: impl IntoIterator<Item = N>) -> PrimaryKey {
+        Self {
+            column_names: column_names.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the names of the columns of the primary key, in order.
+    pub fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_column_primary_key() {
+        let primary_key = PrimaryKey::single("id");
+        assert_eq!(&["id"], primary_key.column_names());
+    }
+
+    #[test]
+    fn composite_primary_key() {
+        let primary_key =
+            PrimaryKey::composite(vec!["department_id".to_string(), "id".to_string()]);
+        assert_eq!(&["department_id", "id"], primary_key.column_names());
+    }
+}
@@ -0,0 +1,32 @@
+/// Represents a unique constraint on a column: no two rows of the table may have the same
+/// (non-null) value in the column.
+///
+/// As in SQL, `Null` values are not considered equal to each other, so any number of rows may
+/// have a `Null` value in a uniquely-constrained column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniqueConstraint {
+    column_name: String,
+}
+
+impl UniqueConstraint {
+    /// Creates a unique constraint on the given column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema::unique_constraint::UniqueConstraint;
+    ///
+    /// let unique_constraint = UniqueConstraint::new("email");
+    /// assert_eq!(unique_constraint.column_name(), "email");
+    /// ```
+    pub fn new<N: Into<String>>(column_name: N) -> UniqueConstraint {
+        Self {
+            column_name: column_name.into(),
+        }
+    }
+
+    /// Returns the name of the constrained column.
+    pub fn column_name(&self) -> &str {
+        &self.column_name
+    }
+}
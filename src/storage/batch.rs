@@ -1,6 +1,7 @@
 use crate::schema::error::SchemaError;
 use crate::schema::Schema;
 use crate::storage::row::Row;
+use crate::types::column_value::ColumnValue;
 
 /// Represents a collection of rows to be processed together.
 ///
@@ -68,6 +69,75 @@ impl From<Vec<Row>> for Batch {
     }
 }
 
+/// Builds a [`Batch`] row by row, validating each row against a schema as it is pushed.
+///
+/// A row with the wrong number of values, or with a value not matching the type of its column,
+/// fails the build with a [`SchemaError`], before the batch reaches an insert.
+///
+/// # Examples
+///
+/// ```
+/// use relop::catalog::Catalog;
+/// use relop::client::Relop;
+/// use relop::schema::Schema;
+/// use relop::storage::batch::BatchBuilder;
+/// use relop::types::column_type::ColumnType;
+/// use relop::types::column_value::ColumnValue;
+///
+/// let schema = Schema::new()
+///     .add_column("id", ColumnType::Int).unwrap()
+///     .add_column("name", ColumnType::Text).unwrap();
+///
+/// let batch = BatchBuilder::new(&schema)
+///     .push_row(vec![ColumnValue::int(1), ColumnValue::text("relop")])
+///     .push_row(vec![ColumnValue::int(2), ColumnValue::text("query")])
+///     .build()
+///     .unwrap();
+///
+/// let relop = Relop::new(Catalog::new());
+/// relop.create_table("employees", schema).unwrap();
+/// assert_eq!(2, relop.insert_all_into("employees", batch).unwrap().len());
+/// ```
+pub struct BatchBuilder<'a> {
+    schema: &'a Schema,
+    rows: Vec<Row>,
+    error: Option<SchemaError>,
+}
+
+impl<'a> BatchBuilder<'a> {
+    /// Creates a new `BatchBuilder` validating rows against the given schema.
+    pub fn new(schema: &'a Schema) -> Self {
+        Self {
+            schema,
+            rows: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Pushes a row of values, validating its arity and the types of its values against the schema.
+    ///
+    /// Once a row fails the validation, the following rows are ignored and the build fails with
+    /// the error of the first invalid row.
+    pub fn push_row(mut self, values: Vec<ColumnValue>) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+        match self.schema.check_type_compatability(&values) {
+            Ok(()) => self.rows.push(Row::filled(values)),
+            Err(error) => self.error = Some(error),
+        }
+        self
+    }
+
+    /// Builds the `Batch`, returning the error of the first invalid row, if any.
+    pub fn build(self) -> Result<Batch, SchemaError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(Batch::new(self.rows)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,6 +146,49 @@ mod tests {
     use crate::schema::error::SchemaError;
     use crate::types::column_type::ColumnType;
 
+    #[test]
+    fn build_batch() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let batch = BatchBuilder::new(&schema)
+            .push_row(vec![ColumnValue::int(1), ColumnValue::text("relop")])
+            .push_row(vec![ColumnValue::int(2), ColumnValue::null()])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            rows![[1, "relop"], [2, ColumnValue::null()]],
+            batch.into_rows()
+        );
+    }
+
+    #[test]
+    fn attempt_to_build_batch_with_a_type_mismatch() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let result = BatchBuilder::new(&schema)
+            .push_row(vec![ColumnValue::int(1), ColumnValue::text("relop")])
+            .push_row(vec![ColumnValue::text("two"), ColumnValue::text("query")])
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(SchemaError::ColumnTypeMismatch {column, expected, actual}) if column == "id" && expected == ColumnType::Int && actual == ColumnType::Text
+        ))
+    }
+
+    #[test]
+    fn attempt_to_build_batch_with_a_column_count_mismatch() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let result = BatchBuilder::new(&schema)
+            .push_row(vec![ColumnValue::int(1)])
+            .push_row(vec![ColumnValue::int(2), ColumnValue::text("query")])
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(SchemaError::ColumnCountMismatch {expected, actual}) if expected == 2 && actual == 1
+        ))
+    }
+
     #[test]
     fn batch_with_incompatible_column_count() {
         let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
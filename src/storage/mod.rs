@@ -1,6 +1,9 @@
 pub mod batch;
 pub mod error;
+pub mod primary_key_index;
 pub mod row;
 pub(crate) mod row_filter;
 pub mod row_view;
+pub(crate) mod secondary_index;
 pub(crate) mod table_store;
+pub(crate) mod unique_index;
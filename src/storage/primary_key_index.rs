@@ -0,0 +1,241 @@
+use crate::catalog::error::InsertError;
+use crate::storage::row::Row;
+use crate::storage::table_store::RowId;
+use crate::types::column_value::ColumnValue;
+use std::collections::{HashMap, HashSet};
+
+/// The values of the primary key columns of a row, in the order of the primary key.
+///
+/// For a composite primary key, two `PrimaryKeyColumnValues` are equal only if all of their
+/// values are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrimaryKeyColumnValues {
+    values: Vec<ColumnValue>,
+}
+
+impl PrimaryKeyColumnValues {
+    /// Builds the key of the row from the values at the given positions (the positions of the
+    /// primary key columns in the schema).
+    pub(crate) fn new(row: &Row, positions: &[usize]) -> Self {
+        Self {
+            values: positions
+                .iter()
+                .map(|position| {
+                    row.column_value_at(*position)
+                        .cloned()
+                        .unwrap_or(ColumnValue::Null)
+                })
+                .collect(),
+        }
+    }
+
+    /// Builds the key from the values of the primary key columns, in the order of the primary key.
+    pub(crate) fn from_values(values: Vec<ColumnValue>) -> Self {
+        Self { values }
+    }
+
+    /// Returns the values of the primary key columns, in the order of the primary key.
+    pub fn values(&self) -> &[ColumnValue] {
+        &self.values
+    }
+}
+
+/// Maps the primary key of each row of a table to its `RowId`, and enforces that the primary
+/// keys are unique and have no `Null` value.
+///
+/// `PrimaryKeyIndex` is not synchronized: `TableEntry` holds it behind a lock, and changes the
+/// rows of the `TableStore` while holding that lock.
+pub(crate) struct PrimaryKeyIndex {
+    column_names: Vec<String>,
+    positions: Vec<usize>,
+    entries: HashMap<PrimaryKeyColumnValues, RowId>,
+}
+
+impl PrimaryKeyIndex {
+    /// Creates an empty index for the primary key with the given column names and positions.
+    pub(crate) fn new(column_names: Vec<String>, positions: Vec<usize>) -> Self {
+        Self {
+            column_names,
+            positions,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the names of the primary key columns, in the order of the primary key.
+    pub(crate) fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    /// Returns the `RowId` of the row with the given key, if it is present.
+    pub(crate) fn get(&self, key: &PrimaryKeyColumnValues) -> Option<RowId> {
+        self.entries.get(key).copied()
+    }
+
+    /// Returns the key of the given row, or an error if any of its values is `Null`.
+    pub(crate) fn key_of(&self, row: &Row) -> Result<PrimaryKeyColumnValues, InsertError> {
+        let key = PrimaryKeyColumnValues::new(row, &self.positions);
+        if let Some(index) = key.values().iter().position(|value| value.is_null()) {
+            return Err(InsertError::NullPrimaryKey(
+                self.column_names[index].clone(),
+            ));
+        }
+        Ok(key)
+    }
+
+    /// Returns the keys of the rows to be inserted, or an error if any of them is `Null`,
+    /// already present in the index, or repeated among the rows.
+    pub(crate) fn keys_for_insert(
+        &self,
+        rows: &[Row],
+    ) -> Result<Vec<PrimaryKeyColumnValues>, InsertError> {
+        let mut keys = Vec::with_capacity(rows.len());
+        let mut seen = HashSet::with_capacity(rows.len());
+        for row in rows {
+            let key = self.key_of(row)?;
+            if self.entries.contains_key(&key) || !seen.insert(key.clone()) {
+                return Err(InsertError::DuplicatePrimaryKey(key));
+            }
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+
+    /// Returns the keys of the rows replacing the rows with the given `RowId`s, or an error if any
+    /// of them is `Null`, repeated among the rows, or present in the index for a row which is
+    /// not replaced.
+    pub(crate) fn keys_for_update(
+        &self,
+        rows: &[(RowId, Row)],
+    ) -> Result<Vec<PrimaryKeyColumnValues>, InsertError> {
+        let updated_row_ids = rows
+            .iter()
+            .map(|(row_id, _)| *row_id)
+            .collect::<HashSet<_>>();
+        let mut keys = Vec::with_capacity(rows.len());
+        let mut seen = HashSet::with_capacity(rows.len());
+        for (_, row) in rows {
+            let key = self.key_of(row)?;
+            let taken_by_other_row = self
+                .entries
+                .get(&key)
+                .is_some_and(|row_id| !updated_row_ids.contains(row_id));
+            if taken_by_other_row || !seen.insert(key.clone()) {
+                return Err(InsertError::DuplicatePrimaryKey(key));
+            }
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+
+    /// Adds the key of the row with the given `RowId`.
+    pub(crate) fn insert(&mut self, key: PrimaryKeyColumnValues, row_id: RowId) {
+        self.entries.insert(key, row_id);
+    }
+
+    /// Removes the key of the given row, if it is present.
+    pub(crate) fn remove(&mut self, row: &Row) {
+        self.entries
+            .remove(&PrimaryKeyColumnValues::new(row, &self.positions));
+    }
+
+    /// Removes all the keys.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::row;
+
+    fn composite_index() -> PrimaryKeyIndex {
+        PrimaryKeyIndex::new(
+            vec!["department_id".to_string(), "id".to_string()],
+            vec![1, 0],
+        )
+    }
+
+    #[test]
+    fn composite_key_of_row() {
+        let key = PrimaryKeyColumnValues::new(&row![1, 10, "relop"], &[1, 0]);
+        assert_eq!(&[ColumnValue::int(10), ColumnValue::int(1)], key.values());
+    }
+
+    #[test]
+    fn keys_sharing_a_column_value_are_distinct() {
+        let index = composite_index();
+        let keys = index
+            .keys_for_insert(&[row![1, 10], row![2, 10], row![1, 20]])
+            .unwrap();
+        assert_eq!(3, keys.len());
+    }
+
+    #[test]
+    fn attempt_to_insert_a_key_present_in_the_index() {
+        let mut index = composite_index();
+        let key = index.key_of(&row![1, 10]).unwrap();
+        index.insert(key.clone(), 1);
+
+        let result = index.keys_for_insert(&[row![1, 10]]);
+        assert!(
+            matches!(result, Err(InsertError::DuplicatePrimaryKey(duplicate)) if duplicate == key)
+        );
+    }
+
+    #[test]
+    fn attempt_to_insert_a_key_repeated_among_the_rows() {
+        let index = composite_index();
+        let result = index.keys_for_insert(&[row![1, 10], row![1, 10]]);
+        assert!(matches!(result, Err(InsertError::DuplicatePrimaryKey(_))));
+    }
+
+    #[test]
+    fn attempt_to_insert_a_key_with_null() {
+        let index = composite_index();
+        let result = index.keys_for_insert(&[row![1, ColumnValue::null()]]);
+        assert!(
+            matches!(result, Err(InsertError::NullPrimaryKey(column)) if column == "department_id")
+        );
+    }
+
+    #[test]
+    fn update_keeping_the_key_of_the_row() {
+        let mut index = composite_index();
+        index.insert(index.key_of(&row![1, 10]).unwrap(), 1);
+
+        let keys = index.keys_for_update(&[(1, row![1, 10])]).unwrap();
+        assert_eq!(1, keys.len());
+    }
+
+    #[test]
+    fn update_swapping_the_keys_of_rows() {
+        let mut index = composite_index();
+        index.insert(index.key_of(&row![1, 10]).unwrap(), 1);
+        index.insert(index.key_of(&row![2, 10]).unwrap(), 2);
+
+        let keys = index
+            .keys_for_update(&[(1, row![2, 10]), (2, row![1, 10])])
+            .unwrap();
+        assert_eq!(2, keys.len());
+    }
+
+    #[test]
+    fn attempt_to_update_to_the_key_of_another_row() {
+        let mut index = composite_index();
+        index.insert(index.key_of(&row![1, 10]).unwrap(), 1);
+        index.insert(index.key_of(&row![2, 10]).unwrap(), 2);
+
+        let result = index.keys_for_update(&[(1, row![2, 10])]);
+        assert!(matches!(result, Err(InsertError::DuplicatePrimaryKey(_))));
+    }
+
+    #[test]
+    fn remove_key_of_row() {
+        let mut index = composite_index();
+        index.insert(index.key_of(&row![1, 10]).unwrap(), 1);
+        index.remove(&row![1, 10]);
+
+        assert!(index.keys_for_insert(&[row![1, 10]]).is_ok());
+    }
+}
@@ -1,7 +1,9 @@
+use crate::query::executor::error::ExecutionError;
+use crate::schema::Schema;
 use crate::types::column_value::ColumnValue;
 
 /// Represents a single row of data in a table, consisting of multiple column values.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct Row {
     values: Vec<ColumnValue>,
 }
@@ -90,6 +92,61 @@ impl Row {
         }
         None
     }
+
+    /// Returns a new row with the values of the named columns only, in the requested order,
+    /// resolving each name to its position in the given schema of this row.
+    ///
+    /// A column may be named more than once, its value is then repeated.
+    ///
+    /// Returns `ExecutionError::UnknownColumn` if a column is not in the schema, and
+    /// `ExecutionError::AmbiguousColumn` if an unqualified name matches more than one column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::schema;
+    /// use relop::storage::row::Row;
+    /// use relop::types::column_type::ColumnType;
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+    /// let row = Row::filled(vec![ColumnValue::int(1), ColumnValue::text("relop")]);
+    ///
+    /// let projected = row.project(&schema, &["name".to_string(), "id".to_string()]).unwrap();
+    /// assert_eq!(
+    ///     Row::filled(vec![ColumnValue::text("relop"), ColumnValue::int(1)]),
+    ///     projected
+    /// );
+    /// ```
+    pub fn project(&self, schema: &Schema, columns: &[String]) -> Result<Row, ExecutionError> {
+        let values = columns
+            .iter()
+            .map(|column| {
+                let position = Self::column_position(schema, column)?;
+                self.column_value_at(position)
+                    .cloned()
+                    .ok_or_else(|| ExecutionError::UnknownColumn(column.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Row::filled(values))
+    }
+
+    /// Resolves the position of the named column in the given schema of a row, the way
+    /// `project` does.
+    ///
+    /// Returns `ExecutionError::UnknownColumn` if the column is not in the schema, and
+    /// `ExecutionError::AmbiguousColumn` if an unqualified name matches more than one column.
+    pub(crate) fn column_position(schema: &Schema, column: &str) -> Result<usize, ExecutionError> {
+        schema
+            .column_position(column)
+            .map_err(ExecutionError::from)?
+            .ok_or_else(|| ExecutionError::UnknownColumn(column.to_string()))
+    }
+
+    /// Replaces the column value at the specified index.
+    pub(crate) fn replace_at(&mut self, index: usize, value: ColumnValue) {
+        self.values[index] = value;
+    }
 }
 
 #[cfg(test)]
@@ -101,7 +158,9 @@ impl Row {
 
 #[cfg(test)]
 mod tests {
+    use crate::query::executor::error::ExecutionError;
     use crate::storage::row::{ColumnValue, Row};
+    use crate::types::column_type::ColumnType;
 
     #[test]
     fn create_a_row_with_a_single_column_value() {
@@ -144,4 +203,71 @@ mod tests {
 
         assert!(column_value.is_none());
     }
+
+    #[test]
+    fn project_a_row_reordering_the_columns() {
+        let schema = crate::schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::int(1), ColumnValue::text("relop")]);
+
+        let projected = row
+            .project(&schema, &["name".to_string(), "id".to_string()])
+            .unwrap();
+
+        assert_eq!(
+            Row::filled(vec![ColumnValue::text("relop"), ColumnValue::int(1)]),
+            projected
+        );
+    }
+
+    #[test]
+    fn project_a_row_with_a_duplicate_column() {
+        let schema = crate::schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::int(1), ColumnValue::text("relop")]);
+
+        let projected = row
+            .project(&schema, &["id".to_string(), "id".to_string()])
+            .unwrap();
+
+        assert_eq!(
+            Row::filled(vec![ColumnValue::int(1), ColumnValue::int(1)]),
+            projected
+        );
+    }
+
+    #[test]
+    fn project_a_row_by_qualified_column_names() {
+        let schema = crate::schema!["id" => ColumnType::Int, "name" => ColumnType::Text]
+            .unwrap()
+            .with_prefix("employees");
+        let row = Row::filled(vec![ColumnValue::int(1), ColumnValue::text("relop")]);
+
+        let projected = row
+            .project(&schema, &["employees.name".to_string()])
+            .unwrap();
+
+        assert_eq!(Row::single(ColumnValue::text("relop")), projected);
+    }
+
+    #[test]
+    fn attempt_to_project_a_row_with_an_unknown_column() {
+        let schema = crate::schema!["id" => ColumnType::Int].unwrap();
+        let row = Row::single(ColumnValue::int(1));
+
+        let result = row.project(&schema, &["name".to_string()]);
+
+        assert!(matches!(result, Err(ExecutionError::UnknownColumn(column)) if column == "name"));
+    }
+
+    #[test]
+    fn attempt_to_project_a_row_with_an_ambiguous_column() {
+        let employees = crate::schema!["id" => ColumnType::Int].unwrap();
+        let departments = crate::schema!["id" => ColumnType::Int].unwrap();
+        let schema =
+            employees.merge_with_prefixes(Some("employees"), &departments, Some("departments"));
+        let row = Row::filled(vec![ColumnValue::int(1), ColumnValue::int(2)]);
+
+        let result = row.project(&schema, &["id".to_string()]);
+
+        assert!(matches!(result, Err(ExecutionError::AmbiguousColumn(column)) if column == "id"));
+    }
 }
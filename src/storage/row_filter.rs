@@ -1,12 +1,18 @@
 use crate::storage::row::Row;
+use std::convert::Infallible;
 
 /// A trait for filtering rows in the storage layer.
 ///
 /// `RowFilter` allows the storage layer to execute filtering logic without
 /// depending on the query layer's `Predicate` implementation.
 pub(crate) trait RowFilter: Send + Sync {
-    /// Returns `true` if the row satisfies the filter, `false` otherwise.
-    fn matches(&self, row: &Row) -> bool;
+    /// The error of a filter which can not be evaluated on a row (e.g., a comparison of
+    /// values of different types).
+    type Error: Send;
+
+    /// Returns `true` if the row satisfies the filter, `false` otherwise, or an error if the
+    /// filter can not be evaluated on the row.
+    fn matches(&self, row: &Row) -> Result<bool, Self::Error>;
 }
 
 /// A filter that always matches all rows.
@@ -14,7 +20,9 @@ pub(crate) trait RowFilter: Send + Sync {
 /// Used as the default for `TableScan` when no predicate is pushed down.
 pub(crate) struct NoFilter;
 impl RowFilter for NoFilter {
-    fn matches(&self, _row: &Row) -> bool {
-        true
+    type Error = Infallible;
+
+    fn matches(&self, _row: &Row) -> Result<bool, Infallible> {
+        Ok(true)
     }
 }
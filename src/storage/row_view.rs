@@ -1,4 +1,5 @@
-use crate::query::parser::ordering_key::{OrderingDirection, OrderingKey};
+use crate::query::executor::error::ExecutionError;
+use crate::query::parser::ordering_key::{NullsOrdering, OrderingDirection, OrderingKey};
 use crate::schema::Schema;
 use crate::storage::error::RowViewComparatorError;
 use crate::storage::row::Row;
@@ -72,6 +73,65 @@ impl<'a> RowView<'a> {
         Ok(None)
     }
 
+    /// Returns whether the value of a column is `Null`.
+    ///
+    /// This pairs with [`RowView::column_value_by`] and saves callers from matching on
+    /// [`ColumnValue::Null`] themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - The name of the column to check.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the column exists and holds `Null`.
+    /// * `Ok(false)` if the column exists and holds a non-null value.
+    /// * `Err(ExecutionError::UnknownColumn)` if the column is not part of the schema or is not visible.
+    /// * `Err(ExecutionError::Schema)` if the unqualified column name matches multiple columns.
+    pub fn is_null(&self, column_name: &str) -> Result<bool, ExecutionError> {
+        self.column_value_by(column_name)
+            .map_err(ExecutionError::from)?
+            .map(|value| value.is_null())
+            .ok_or_else(|| ExecutionError::UnknownColumn(column_name.to_string()))
+    }
+
+    /// Retrieves the value of a visible column by its (0-based) position in the result's columns.
+    ///
+    /// Together with [`RowView::len`], this allows reading a row positionally, without knowing
+    /// the column names.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the column among the visible columns.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&ColumnValue)` if the index is within the visible columns.
+    /// * `Err(ExecutionError::ColumnIndexOutOfBounds)` otherwise.
+    pub fn column_value_at(&self, index: usize) -> Result<&ColumnValue, ExecutionError> {
+        self.visible_positions
+            .get(index)
+            .and_then(|&position| self.row.column_value_at(position))
+            .ok_or(ExecutionError::ColumnIndexOutOfBounds(index))
+    }
+
+    /// Returns the name of a visible column by its (0-based) position in the result's columns.
+    pub(crate) fn column_name_at(&self, index: usize) -> Option<&str> {
+        self.visible_positions
+            .get(index)
+            .map(|&position| self.schema.columns()[position].name())
+    }
+
+    /// Returns the number of visible columns.
+    pub fn len(&self) -> usize {
+        self.visible_positions.len()
+    }
+
+    /// Returns whether the view has no visible columns.
+    pub fn is_empty(&self) -> bool {
+        self.visible_positions.is_empty()
+    }
+
     /// Retrieves the value of a column by its index.
     ///
     /// # Arguments
@@ -81,13 +141,39 @@ impl<'a> RowView<'a> {
         self.row.column_value_at(index).unwrap()
     }
 
-    /// Projects the row view to a new set of visible positions.
-    pub(crate) fn project(self, visible_positions: &'a [usize]) -> Self {
-        Self {
-            row: self.row,
-            schema: self.schema,
-            visible_positions,
-        }
+    /// Creates an owned `Row` holding (clones of) the visible values of this `RowView`, in the
+    /// order of the result's columns.
+    ///
+    /// This respects the projection, so the row can be stored without holding a borrow on the
+    /// result it came from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::catalog::Catalog;
+    /// use relop::client::Relop;
+    /// use relop::storage::row::Row;
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let relop = Relop::new(Catalog::new());
+    /// relop.execute("create table employees (id int, name text)").unwrap();
+    /// relop.execute("insert into employees values (1, 'relop')").unwrap();
+    ///
+    /// let query_result = relop.execute("select name, id from employees").unwrap();
+    /// let result_set = query_result.result_set().unwrap();
+    /// let rows = result_set
+    ///     .iterator()
+    ///     .unwrap()
+    ///     .map(|row_view| row_view.unwrap().to_row())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     vec![Row::filled(vec![ColumnValue::text("relop"), ColumnValue::int(1)])],
+    ///     rows
+    /// );
+    /// ```
+    pub fn to_row(&self) -> Row {
+        self.pad_with_nulls(0, 0)
     }
 
     /// Merges this `RowView` with another `RowView` to create a new `Row`.
@@ -108,6 +194,28 @@ impl<'a> RowView<'a> {
         }
         Row::filled(values)
     }
+
+    /// Creates a new `Row` from the visible values of this `RowView`, surrounded by null values.
+    ///
+    /// This is used in outer join operations where a row has no match on the other side,
+    /// and the columns of the other side are filled with nulls.
+    ///
+    /// # Arguments
+    ///
+    /// * `nulls_before` - The number of null values placed before the visible values.
+    /// * `nulls_after` - The number of null values placed after the visible values.
+    pub(crate) fn pad_with_nulls(&self, nulls_before: usize, nulls_after: usize) -> Row {
+        let mut values =
+            Vec::with_capacity(nulls_before + self.visible_positions.len() + nulls_after);
+
+        values.extend(std::iter::repeat_n(ColumnValue::null(), nulls_before));
+        for &pos in self.visible_positions {
+            // SAFETY: visible_positions are validated at construction to be within bounds of the row.
+            values.push(self.row.column_value_at(pos).unwrap().clone());
+        }
+        values.extend(std::iter::repeat_n(ColumnValue::null(), nulls_after));
+        Row::filled(values)
+    }
 }
 
 /// A comparator for [`RowView`]s that implements multi-column sorting logic.
@@ -155,7 +263,8 @@ impl<'a> RowViewComparator<'a> {
     /// Compares two [`RowView`]s according to the configured ordering keys.
     ///
     /// It iterates through the ordering keys in priority order.
-    /// The first non-equal comparison determines the result.
+    /// The first non-equal comparison determines the result. `Null`s are placed before or after
+    /// the other values as specified by the key, irrespective of its direction.
     /// If all keys are equal, the rows are considered equal.
     pub fn compare(&self, left: &RowView, right: &RowView) -> std::cmp::Ordering {
         for (column_position, key) in self.positions.iter().zip(self.ordering_keys.iter()) {
@@ -165,13 +274,24 @@ impl<'a> RowViewComparator<'a> {
             let left_value = left.column_value_at_unchecked(*column_position);
             let right_value = right.column_value_at_unchecked(*column_position);
 
-            let ordering = left_value.cmp(right_value);
+            let ordering = match (left_value.is_null(), right_value.is_null()) {
+                (true, true) => std::cmp::Ordering::Equal,
+                (true, false) => match key.nulls {
+                    NullsOrdering::First => std::cmp::Ordering::Less,
+                    NullsOrdering::Last => std::cmp::Ordering::Greater,
+                },
+                (false, true) => match key.nulls {
+                    NullsOrdering::First => std::cmp::Ordering::Greater,
+                    NullsOrdering::Last => std::cmp::Ordering::Less,
+                },
+                (false, false) => match key.direction {
+                    OrderingDirection::Ascending => left_value.cmp(right_value),
+                    OrderingDirection::Descending => left_value.cmp(right_value).reverse(),
+                },
+            };
 
             if ordering != std::cmp::Ordering::Equal {
-                return match key.direction {
-                    OrderingDirection::Ascending => ordering,
-                    OrderingDirection::Descending => ordering.reverse(),
-                };
+                return ordering;
             }
         }
         std::cmp::Ordering::Equal
@@ -222,24 +342,13 @@ mod tests {
         );
     }
     #[test]
-    fn project_row_view() {
+    fn row_view_with_restricted_visible_positions() {
         let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
 
         let row = row![200, "relop"];
 
-        let visible_positions = vec![0, 1];
-        let view = RowView::new(row, &schema, &visible_positions);
-        assert_eq!(
-            &ColumnValue::int(200),
-            view.column_value_by("id").unwrap().unwrap()
-        );
-        assert_eq!(
-            &ColumnValue::text("relop"),
-            view.column_value_by("name").unwrap().unwrap()
-        );
-
-        let projection = vec![1];
-        let projected_view = view.project(&projection);
+        let visible_positions = vec![1];
+        let projected_view = RowView::new(row, &schema, &visible_positions);
         assert!(projected_view.column_value_by("id").unwrap().is_none());
         assert_eq!(
             &ColumnValue::text("relop"),
@@ -266,6 +375,32 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn column_is_null() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::int(1), ColumnValue::null()]);
+
+        let visible_positions = vec![0, 1];
+        let view = RowView::new(row, &schema, &visible_positions);
+
+        assert!(!view.is_null("id").unwrap());
+        assert!(view.is_null("name").unwrap());
+    }
+
+    #[test]
+    fn attempt_to_check_null_for_a_column_not_in_visible_position() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let row = Row::filled(vec![ColumnValue::int(1), ColumnValue::null()]);
+
+        let visible_positions = vec![0];
+        let view = RowView::new(row, &schema, &visible_positions);
+
+        assert!(matches!(
+            view.is_null("name"),
+            Err(ExecutionError::UnknownColumn(column_name)) if column_name == "name"
+        ));
+    }
+
     #[test]
     fn merge_row_views() {
         let left_schema = schema!["id" => ColumnType::Int].unwrap();
@@ -284,6 +419,65 @@ mod tests {
             Row::filled(vec![ColumnValue::int(1), ColumnValue::text("relop")])
         );
     }
+
+    #[test]
+    fn column_value_at_an_index_of_the_visible_columns() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let row = row![1, "relop"];
+        let visible_positions = vec![1, 0];
+        let view = RowView::new(row, &schema, &visible_positions);
+
+        assert_eq!(2, view.len());
+        assert!(!view.is_empty());
+        assert_eq!(
+            &ColumnValue::text("relop"),
+            view.column_value_at(0).unwrap()
+        );
+        assert_eq!(&ColumnValue::int(1), view.column_value_at(1).unwrap());
+    }
+
+    #[test]
+    fn attempt_to_get_column_value_at_an_index_beyond_the_visible_columns() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let row = row![1, "relop"];
+        let visible_positions = vec![1];
+        let view = RowView::new(row, &schema, &visible_positions);
+
+        assert_eq!(1, view.len());
+        assert!(matches!(
+            view.column_value_at(1),
+            Err(ExecutionError::ColumnIndexOutOfBounds(1))
+        ));
+    }
+
+    #[test]
+    fn to_row_of_row_view() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let row = row![1, "relop"];
+        let visible_positions = vec![1, 0];
+        let view = RowView::new(row, &schema, &visible_positions);
+
+        assert_eq!(row!["relop", 1], view.to_row());
+    }
+
+    #[test]
+    fn pad_row_view_with_nulls() {
+        let schema = schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap();
+        let row = row![1, "relop"];
+        let visible_positions = vec![1];
+        let view = RowView::new(row, &schema, &visible_positions);
+
+        let padded_row = view.pad_with_nulls(1, 2);
+        assert_eq!(
+            padded_row,
+            Row::filled(vec![
+                ColumnValue::null(),
+                ColumnValue::text("relop"),
+                ColumnValue::null(),
+                ColumnValue::null()
+            ])
+        );
+    }
 }
 
 #[cfg(test)]
@@ -361,6 +555,86 @@ mod row_view_comparator_tests {
         );
     }
 
+    #[test]
+    fn compare_row_views_with_nulls_in_default_placement() {
+        let schema = schema!["id" => ColumnType::Int].unwrap();
+        let visible_positions = [0];
+        let null_view = RowView::new(
+            Row::filled(vec![ColumnValue::null()]),
+            &schema,
+            &visible_positions,
+        );
+        let row_view = RowView::new(row![1], &schema, &visible_positions);
+
+        let ordering_keys = vec![asc!("id")];
+        let comparator = RowViewComparator::new(&schema, &ordering_keys).unwrap();
+        assert_eq!(comparator.compare(&null_view, &row_view), Ordering::Greater);
+
+        let ordering_keys = vec![desc!("id")];
+        let comparator = RowViewComparator::new(&schema, &ordering_keys).unwrap();
+        assert_eq!(comparator.compare(&null_view, &row_view), Ordering::Less);
+    }
+
+    #[test]
+    fn compare_row_views_with_nulls_first_in_ascending_order() {
+        let schema = schema!["id" => ColumnType::Int].unwrap();
+        let ordering_keys = vec![asc!("id").with_nulls(NullsOrdering::First)];
+        let comparator = RowViewComparator::new(&schema, &ordering_keys).unwrap();
+
+        let visible_positions = [0];
+        let null_view = RowView::new(
+            Row::filled(vec![ColumnValue::null()]),
+            &schema,
+            &visible_positions,
+        );
+        let row_view = RowView::new(row![1], &schema, &visible_positions);
+
+        assert_eq!(comparator.compare(&null_view, &row_view), Ordering::Less);
+        assert_eq!(comparator.compare(&row_view, &null_view), Ordering::Greater);
+    }
+
+    #[test]
+    fn compare_row_views_with_nulls_last_in_descending_order() {
+        let schema = schema!["id" => ColumnType::Int].unwrap();
+        let ordering_keys = vec![desc!("id").with_nulls(NullsOrdering::Last)];
+        let comparator = RowViewComparator::new(&schema, &ordering_keys).unwrap();
+
+        let visible_positions = [0];
+        let null_view = RowView::new(
+            Row::filled(vec![ColumnValue::null()]),
+            &schema,
+            &visible_positions,
+        );
+        let row_view = RowView::new(row![1], &schema, &visible_positions);
+
+        assert_eq!(comparator.compare(&null_view, &row_view), Ordering::Greater);
+        assert_eq!(comparator.compare(&row_view, &null_view), Ordering::Less);
+    }
+
+    #[test]
+    fn compare_row_views_with_nulls_on_both_sides() {
+        let schema = schema!["id" => ColumnType::Int, "rank" => ColumnType::Int].unwrap();
+        let ordering_keys = vec![asc!("id").with_nulls(NullsOrdering::First), asc!("rank")];
+        let comparator = RowViewComparator::new(&schema, &ordering_keys).unwrap();
+
+        let visible_positions = [0, 1];
+        let row_view1 = RowView::new(
+            Row::filled(vec![ColumnValue::null(), ColumnValue::int(20)]),
+            &schema,
+            &visible_positions,
+        );
+        let row_view2 = RowView::new(
+            Row::filled(vec![ColumnValue::null(), ColumnValue::int(10)]),
+            &schema,
+            &visible_positions,
+        );
+
+        assert_eq!(
+            comparator.compare(&row_view1, &row_view2),
+            Ordering::Greater
+        );
+    }
+
     #[test]
     fn attempt_compare_row_views_on_with_non_existing_column() {
         let schema = schema!["id" => ColumnType::Int].unwrap();
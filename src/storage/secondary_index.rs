@@ -0,0 +1,121 @@
+use crate::storage::row::Row;
+use crate::storage::table_store::RowId;
+use crate::types::column_value::ColumnValue;
+use std::collections::HashMap;
+
+/// Maps each value of a column to the `RowId`s of the rows having that value, to find the rows
+/// matching an equality (e.g., `department_id = 10`) without scanning the table.
+///
+/// `Null` values are not indexed: an equality never matches a `Null` value.
+///
+/// Like `PrimaryKeyIndex`, `SecondaryIndex` is not synchronized: `TableEntry` holds it behind a
+/// lock, and changes the rows of the `TableStore` while holding that lock.
+pub(crate) struct SecondaryIndex {
+    column_name: String,
+    position: usize,
+    entries: HashMap<ColumnValue, Vec<RowId>>,
+}
+
+impl SecondaryIndex {
+    /// Creates an empty index for the column with the given name and position.
+    pub(crate) fn new(column_name: &str, position: usize) -> Self {
+        Self {
+            column_name: column_name.to_string(),
+            position,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the name of the indexed column.
+    pub(crate) fn column_name(&self) -> &str {
+        &self.column_name
+    }
+
+    /// Returns the value of the indexed column in the given row.
+    pub(crate) fn value_of(&self, row: &Row) -> ColumnValue {
+        row.column_value_at(self.position)
+            .cloned()
+            .unwrap_or(ColumnValue::Null)
+    }
+
+    /// Adds the value of the row with the given `RowId`, unless it is `Null`.
+    pub(crate) fn insert(&mut self, value: ColumnValue, row_id: RowId) {
+        if !value.is_null() {
+            self.entries.entry(value).or_default().push(row_id);
+        }
+    }
+
+    /// Removes the value of the given row with the given `RowId`, if it is present.
+    pub(crate) fn remove(&mut self, row: &Row, row_id: RowId) {
+        let value = self.value_of(row);
+        if let Some(row_ids) = self.entries.get_mut(&value) {
+            row_ids.retain(|existing| *existing != row_id);
+            if row_ids.is_empty() {
+                self.entries.remove(&value);
+            }
+        }
+    }
+
+    /// Removes all the values.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the `RowId`s of the rows having the given value, in ascending order (the order of
+    /// a table scan).
+    pub(crate) fn row_ids(&self, value: &ColumnValue) -> Vec<RowId> {
+        let mut row_ids = self.entries.get(value).cloned().unwrap_or_default();
+        row_ids.sort_unstable();
+        row_ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::row;
+
+    #[test]
+    fn row_ids_of_a_value() {
+        let mut index = SecondaryIndex::new("department_id", 1);
+        index.insert(index.value_of(&row![1, 10]), 1);
+        index.insert(index.value_of(&row![2, 20]), 2);
+        index.insert(index.value_of(&row![3, 10]), 3);
+
+        assert_eq!(vec![1, 3], index.row_ids(&ColumnValue::int(10)));
+        assert_eq!(vec![2], index.row_ids(&ColumnValue::int(20)));
+    }
+
+    #[test]
+    fn row_ids_of_a_non_existent_value() {
+        let index = SecondaryIndex::new("department_id", 1);
+        assert!(index.row_ids(&ColumnValue::int(10)).is_empty());
+    }
+
+    #[test]
+    fn row_ids_in_ascending_order() {
+        let mut index = SecondaryIndex::new("department_id", 1);
+        index.insert(ColumnValue::int(10), 3);
+        index.insert(ColumnValue::int(10), 1);
+
+        assert_eq!(vec![1, 3], index.row_ids(&ColumnValue::int(10)));
+    }
+
+    #[test]
+    fn null_values_are_not_indexed() {
+        let mut index = SecondaryIndex::new("department_id", 1);
+        index.insert(ColumnValue::null(), 1);
+
+        assert!(index.row_ids(&ColumnValue::null()).is_empty());
+    }
+
+    #[test]
+    fn remove_value_of_row() {
+        let mut index = SecondaryIndex::new("department_id", 1);
+        index.insert(ColumnValue::int(10), 1);
+        index.insert(ColumnValue::int(10), 2);
+
+        index.remove(&row![1, 10], 1);
+        assert_eq!(vec![2], index.row_ids(&ColumnValue::int(10)));
+    }
+}
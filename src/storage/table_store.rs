@@ -1,5 +1,5 @@
 use crate::storage::row::Row;
-use crossbeam_skiplist::map::Iter;
+use crossbeam_skiplist::map::{Entry, Iter};
 use crossbeam_skiplist::SkipMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -15,6 +15,21 @@ pub(crate) struct TableStore {
     current_row_id: AtomicU64,
 }
 
+/// Iterator over the rows in a `TableStore`, along with their `RowId`s.
+pub(crate) struct TableStoreEntryIterator<'a> {
+    inner: Iter<'a, RowId, Row>,
+}
+
+impl Iterator for TableStoreEntryIterator<'_> {
+    type Item = (RowId, Row);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|entry| (*entry.key(), entry.value().clone()))
+    }
+}
+
 /// Iterator over the rows in a `TableStore`.
 pub(crate) struct TableStoreIterator<'a> {
     inner: Iter<'a, RowId, Row>,
@@ -59,12 +74,61 @@ impl TableStore {
         row_id
     }
 
+    /// Deletes the rows with the given `RowId`s from the store.
+    ///
+    /// Returns the number of deleted rows, the `RowId`s which are not present are ignored.
+    pub(crate) fn delete_all(&self, row_ids: &[RowId]) -> usize {
+        row_ids
+            .iter()
+            .filter(|row_id| self.entries.remove(row_id).is_some())
+            .count()
+    }
+
+    /// Replaces the rows with the given `RowId`s in the store, keeping their `RowId`s.
+    ///
+    /// Returns the number of updated rows, the `RowId`s which are not present are ignored.
+    pub(crate) fn update_all(&self, rows: Vec<(RowId, Row)>) -> usize {
+        rows.into_iter()
+            .filter(|(row_id, row)| {
+                if self.entries.contains_key(row_id) {
+                    self.entries.insert(*row_id, row.clone());
+                    return true;
+                }
+                false
+            })
+            .count()
+    }
+
+    /// Removes all the rows from the store, and resets the row IDs to start at 1 again.
+    pub(crate) fn clear(&self) {
+        self.entries.clear();
+        self.current_row_id.store(1, Ordering::Release);
+    }
+
+    /// Returns the row with the given `RowId`, if it is present.
+    pub(crate) fn get(&self, row_id: RowId) -> Option<Row> {
+        self.entries.get(&row_id).map(|entry| entry.value().clone())
+    }
+
     /// Returns an iterator over all rows in the table.
     pub(crate) fn iter(&self) -> TableStoreIterator<'_> {
         TableStoreIterator {
             inner: self.entries.iter(),
         }
     }
+
+    /// Returns a reference to each row in the table, in the order of their `RowId`s, without
+    /// cloning the rows.
+    pub(crate) fn row_refs(&self) -> Vec<Entry<'_, RowId, Row>> {
+        self.entries.iter().collect()
+    }
+
+    /// Returns an iterator over all rows in the table, along with their `RowId`s.
+    pub(crate) fn entries(&self) -> TableStoreEntryIterator<'_> {
+        TableStoreEntryIterator {
+            inner: self.entries.iter(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -75,10 +139,6 @@ impl TableStore {
             .map(|entry| entry.value().clone())
             .collect()
     }
-
-    pub(crate) fn get(&self, row_id: RowId) -> Option<Row> {
-        self.entries.get(&row_id).map(|entry| entry.value().clone())
-    }
 }
 
 #[cfg(test)]
@@ -175,6 +235,85 @@ mod tests {
         assert!(iterator.next().is_none());
     }
 
+    #[test]
+    fn iterate_over_all_rows_with_row_ids() {
+        let store = TableStore::new();
+        store.insert_all(rows![[10], [20]]);
+
+        let mut iterator = store.entries();
+
+        assert_eq!(
+            (1, Row::filled(vec![ColumnValue::int(10)])),
+            iterator.next().unwrap()
+        );
+        assert_eq!(
+            (2, Row::filled(vec![ColumnValue::int(20)])),
+            iterator.next().unwrap()
+        );
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn get_references_to_all_rows() {
+        let store = TableStore::new();
+        store.insert_all(rows![[10], [20]]);
+
+        let row_refs = store.row_refs();
+
+        assert_eq!(2, row_refs.len());
+        assert_eq!(&row![10], row_refs[0].value());
+        assert_eq!(&row![20], row_refs[1].value());
+    }
+
+    #[test]
+    fn delete_rows() {
+        let store = TableStore::new();
+        store.insert_all(rows![[10], [20], [30]]);
+
+        let deleted = store.delete_all(&[1, 3]);
+        assert_eq!(2, deleted);
+
+        let rows = store.scan();
+        assert_eq!(vec![Row::filled(vec![ColumnValue::int(20)])], rows);
+    }
+
+    #[test]
+    fn delete_rows_with_a_non_existent_row_id() {
+        let store = TableStore::new();
+        store.insert_all(rows![[10], [20]]);
+
+        let deleted = store.delete_all(&[2, 1000]);
+        assert_eq!(1, deleted);
+
+        assert!(store.get(2).is_none());
+        assert!(store.get(1).is_some());
+    }
+
+    #[test]
+    fn update_rows() {
+        let store = TableStore::new();
+        store.insert_all(rows![[10, "relop"], [20, "query"]]);
+
+        let updated = store.update_all(vec![(2, row![25, "rust"]), (1000, row![0, "none"])]);
+        assert_eq!(1, updated);
+
+        assert_eq!(Some(row![10, "relop"]), store.get(1));
+        assert_eq!(Some(row![25, "rust"]), store.get(2));
+        assert!(store.get(1000).is_none());
+    }
+
+    #[test]
+    fn clear_rows() {
+        let store = TableStore::new();
+        store.insert_all(rows![[10], [20]]);
+
+        store.clear();
+        assert!(store.scan().is_empty());
+
+        let row_id = store.insert(row![30]);
+        assert_eq!(1, row_id);
+    }
+
     #[test]
     fn attempt_to_iterate_over_all_rows_with_empty_table_store() {
         let store = TableStore::new();
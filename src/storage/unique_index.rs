@@ -0,0 +1,188 @@
+use crate::catalog::error::InsertError;
+use crate::storage::row::Row;
+use crate::storage::table_store::RowId;
+use crate::types::column_value::ColumnValue;
+use std::collections::{HashMap, HashSet};
+
+/// Maps the value of a uniquely-constrained column of each row of a table to its `RowId`, and
+/// enforces that the (non-null) values of the column are unique.
+///
+/// `Null` values are not indexed: any number of rows may have a `Null` value in the column.
+///
+/// Like `PrimaryKeyIndex`, `UniqueIndex` is not synchronized: `TableEntry` holds it behind a lock,
+/// and changes the rows of the `TableStore` while holding that lock.
+pub(crate) struct UniqueIndex {
+    column_name: String,
+    position: usize,
+    entries: HashMap<ColumnValue, RowId>,
+}
+
+impl UniqueIndex {
+    /// Creates an empty index for the column with the given name and position.
+    pub(crate) fn new(column_name: &str, position: usize) -> Self {
+        Self {
+            column_name: column_name.to_string(),
+            position,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the values of the column in the rows to be inserted, or an error if any of them is
+    /// already present in the index, or repeated among the rows.
+    pub(crate) fn values_for_insert(&self, rows: &[Row]) -> Result<Vec<ColumnValue>, InsertError> {
+        let mut values = Vec::with_capacity(rows.len());
+        let mut seen = HashSet::with_capacity(rows.len());
+        for row in rows {
+            let value = self.value_of(row);
+            if !value.is_null()
+                && (self.entries.contains_key(&value) || !seen.insert(value.clone()))
+            {
+                return Err(self.duplicate(value));
+            }
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Returns the values of the column in the rows replacing the rows with the given `RowId`s, or
+    /// an error if any of them is repeated among the rows, or present in the index for a row
+    /// which is not replaced.
+    pub(crate) fn values_for_update(
+        &self,
+        rows: &[(RowId, Row)],
+    ) -> Result<Vec<ColumnValue>, InsertError> {
+        let updated_row_ids = rows
+            .iter()
+            .map(|(row_id, _)| *row_id)
+            .collect::<HashSet<_>>();
+        let mut values = Vec::with_capacity(rows.len());
+        let mut seen = HashSet::with_capacity(rows.len());
+        for (_, row) in rows {
+            let value = self.value_of(row);
+            if !value.is_null() {
+                let taken_by_other_row = self
+                    .entries
+                    .get(&value)
+                    .is_some_and(|row_id| !updated_row_ids.contains(row_id));
+                if taken_by_other_row || !seen.insert(value.clone()) {
+                    return Err(self.duplicate(value));
+                }
+            }
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Adds the value of the row with the given `RowId`, unless it is `Null`.
+    pub(crate) fn insert(&mut self, value: ColumnValue, row_id: RowId) {
+        if !value.is_null() {
+            self.entries.insert(value, row_id);
+        }
+    }
+
+    /// Removes the value of the given row, if it is present.
+    pub(crate) fn remove(&mut self, row: &Row) {
+        self.entries.remove(&self.value_of(row));
+    }
+
+    /// Removes all the values.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn value_of(&self, row: &Row) -> ColumnValue {
+        row.column_value_at(self.position)
+            .cloned()
+            .unwrap_or(ColumnValue::Null)
+    }
+
+    fn duplicate(&self, value: ColumnValue) -> InsertError {
+        InsertError::DuplicateUniqueValue {
+            column: self.column_name.clone(),
+            value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::row;
+
+    #[test]
+    fn distinct_values_for_insert() {
+        let index = UniqueIndex::new("email", 1);
+        let values = index
+            .values_for_insert(&[row![1, "a@relop"], row![2, "b@relop"]])
+            .unwrap();
+        assert_eq!(
+            vec![ColumnValue::text("a@relop"), ColumnValue::text("b@relop")],
+            values
+        );
+    }
+
+    #[test]
+    fn attempt_to_insert_a_value_present_in_the_index() {
+        let mut index = UniqueIndex::new("email", 1);
+        index.insert(ColumnValue::text("a@relop"), 1);
+
+        let result = index.values_for_insert(&[row![2, "a@relop"]]);
+        assert_eq!(
+            Err(InsertError::DuplicateUniqueValue {
+                column: "email".to_string(),
+                value: ColumnValue::text("a@relop"),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn attempt_to_insert_a_value_repeated_among_the_rows() {
+        let index = UniqueIndex::new("email", 1);
+        let result = index.values_for_insert(&[row![1, "a@relop"], row![2, "a@relop"]]);
+        assert!(matches!(
+            result,
+            Err(InsertError::DuplicateUniqueValue { .. })
+        ));
+    }
+
+    #[test]
+    fn insert_repeated_null_values() {
+        let mut index = UniqueIndex::new("email", 1);
+        index.insert(ColumnValue::null(), 1);
+
+        let result =
+            index.values_for_insert(&[row![2, ColumnValue::null()], row![3, ColumnValue::null()]]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn update_keeping_the_value_of_the_row() {
+        let mut index = UniqueIndex::new("email", 1);
+        index.insert(ColumnValue::text("a@relop"), 1);
+
+        assert!(index.values_for_update(&[(1, row![1, "a@relop"])]).is_ok());
+    }
+
+    #[test]
+    fn attempt_to_update_to_the_value_of_another_row() {
+        let mut index = UniqueIndex::new("email", 1);
+        index.insert(ColumnValue::text("a@relop"), 1);
+        index.insert(ColumnValue::text("b@relop"), 2);
+
+        let result = index.values_for_update(&[(1, row![1, "b@relop"])]);
+        assert!(matches!(
+            result,
+            Err(InsertError::DuplicateUniqueValue { .. })
+        ));
+    }
+
+    #[test]
+    fn remove_value_of_row() {
+        let mut index = UniqueIndex::new("email", 1);
+        index.insert(ColumnValue::text("a@relop"), 1);
+        index.remove(&row![1, "a@relop"]);
+
+        assert!(index.values_for_insert(&[row![2, "a@relop"]]).is_ok());
+    }
+}
@@ -46,11 +46,31 @@ macro_rules! desc {
 
 /// Asserts that the next row in the iterator matches the specified columns and values.
 ///
+/// Columns prefixed with `!` are asserted to be absent from the row.
+/// Available outside the crate with the `test-utils` feature.
+///
 /// # Examples
 ///
 /// ```
-/// use relop::assert_next_row;
-/// // assert_row!(iter, "id" => 1, "name" => "relop");
+/// use relop::catalog::Catalog;
+/// use relop::client::Relop;
+/// use relop::types::column_type::ColumnType;
+/// use relop::{assert_next_row, assert_no_more_rows, rows, schema};
+///
+/// let relop = Relop::new(Catalog::new());
+/// relop
+///     .create_table("employees", schema!["id" => ColumnType::Int, "name" => ColumnType::Text].unwrap())
+///     .unwrap();
+/// relop
+///     .insert_all_into("employees", rows![[1, "relop"], [2, "query"]])
+///     .unwrap();
+///
+/// let query_result = relop.execute("select name from employees where id = 1").unwrap();
+/// let result_set = query_result.result_set().unwrap();
+/// let mut iterator = result_set.iterator().unwrap();
+///
+/// assert_next_row!(iterator.as_mut(), "name" => "relop", !"id");
+/// assert_no_more_rows!(iterator.as_mut());
 /// ```
 #[macro_export]
 macro_rules! assert_next_row {
@@ -69,7 +89,7 @@ macro_rules! assert_next_row {
 ///
 /// `RowAssertion` provides a fluent interface for checking expected values of columns
 /// within a row. It is typically created via the [`assert_row`] function.
-pub(crate) struct RowAssertion<'a>(RowView<'a>);
+pub struct RowAssertion<'a>(RowView<'a>);
 
 /// Creates a `RowAssertion` for the next row in the given iterator.
 ///
@@ -79,7 +99,7 @@ pub(crate) struct RowAssertion<'a>(RowView<'a>);
 /// # Panics
 ///
 /// Panics if the iterator yields `None` (no more rows) or if it yields an `Err`.
-pub(crate) fn assert_row<'a>(
+pub fn assert_row<'a>(
     iterator: &'a mut dyn Iterator<Item = Result<RowView, ExecutionError>>,
 ) -> RowAssertion<'a> {
     let row_view = iterator.next().unwrap().unwrap();
@@ -88,11 +108,26 @@ pub(crate) fn assert_row<'a>(
 
 /// Asserts that there are no more rows in the iterator.
 ///
+/// Available outside the crate with the `test-utils` feature.
+///
 /// # Examples
 ///
 /// ```
-/// use relop::assert_no_more_rows;
-/// // assert_no_more_rows!(iter);
+/// use relop::catalog::Catalog;
+/// use relop::client::Relop;
+/// use relop::types::column_type::ColumnType;
+/// use relop::{assert_no_more_rows, schema};
+///
+/// let relop = Relop::new(Catalog::new());
+/// relop
+///     .create_table("employees", schema!["id" => ColumnType::Int].unwrap())
+///     .unwrap();
+///
+/// let query_result = relop.execute("select * from employees").unwrap();
+/// let result_set = query_result.result_set().unwrap();
+/// let mut iterator = result_set.iterator().unwrap();
+///
+/// assert_no_more_rows!(iterator.as_mut());
 /// ```
 #[macro_export]
 macro_rules! assert_no_more_rows {
@@ -110,9 +145,7 @@ macro_rules! assert_no_more_rows {
 /// # Panics
 ///
 /// Panics if the iterator yields `Some` (meaning there are more rows).
-pub(crate) fn assert_no_more_rows(
-    iterator: &mut dyn Iterator<Item = Result<RowView, ExecutionError>>,
-) {
+pub fn assert_no_more_rows(iterator: &mut dyn Iterator<Item = Result<RowView, ExecutionError>>) {
     assert!(iterator.next().is_none(), "Expected no more rows");
 }
 
@@ -128,7 +161,7 @@ impl RowAssertion<'_> {
     /// # Panics
     ///
     /// Panics if the column does not exist or if the value does not match.
-    pub(crate) fn match_column<V: Into<ColumnValue>>(self, column: &str, expected: V) -> Self {
+    pub fn match_column<V: Into<ColumnValue>>(self, column: &str, expected: V) -> Self {
         let actual = self
             .0
             .column_value_by(column)
@@ -147,7 +180,7 @@ impl RowAssertion<'_> {
     /// # Panics
     ///
     /// Panics if the column exists (is not `None`).
-    pub(crate) fn does_not_have_column(self, column: &str) -> Self {
+    pub fn does_not_have_column(self, column: &str) -> Self {
         assert!(self.0.column_value_by(column).unwrap().is_none());
         self
     }
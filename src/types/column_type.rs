@@ -9,6 +9,8 @@ use crate::types::column_value::ColumnValue;
 ///
 /// let int_type = ColumnType::Int;
 /// let text_type = ColumnType::Text;
+/// let bool_type = ColumnType::Bool;
+/// let date_type = ColumnType::Date;
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum ColumnType {
@@ -16,20 +18,41 @@ pub enum ColumnType {
     Int,
     /// String type.
     Text,
+    /// Boolean type.
+    Bool,
+    /// Calendar date type.
+    Date,
 }
 
 impl ColumnType {
     /// Checks if the given `ColumnValue` matches this `ColumnType`.
     ///
     /// This is an internal helper to validate data insertion compatibility.
+    /// `Null` is accepted by every column type.
     pub(crate) fn accepts(&self, value: &ColumnValue) -> bool {
         matches!(
             (self, value),
-            (ColumnType::Int, ColumnValue::Int(_)) | (ColumnType::Text, ColumnValue::Text(_))
+            (ColumnType::Int, ColumnValue::Int(_))
+                | (ColumnType::Text, ColumnValue::Text(_))
+                | (ColumnType::Bool, ColumnValue::Bool(_))
+                | (ColumnType::Date, ColumnValue::Date(_))
+                | (_, ColumnValue::Null)
         )
     }
 }
 
+/// Renders the column type the way it is written in SQL (e.g., `int`).
+impl std::fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnType::Int => write!(f, "int"),
+            ColumnType::Text => write!(f, "text"),
+            ColumnType::Bool => write!(f, "bool"),
+            ColumnType::Date => write!(f, "date"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod column_type_tests {
     use super::*;
@@ -57,4 +80,29 @@ mod column_type_tests {
 
         assert!(!column_type.accepts(&column_value));
     }
+
+    #[test]
+    fn column_type_accepts_null_column_value() {
+        assert!(ColumnType::Int.accepts(&ColumnValue::null()));
+        assert!(ColumnType::Text.accepts(&ColumnValue::null()));
+        assert!(ColumnType::Bool.accepts(&ColumnValue::null()));
+    }
+
+    #[test]
+    fn column_type_accepts_same_type_bool_column_value() {
+        assert!(ColumnType::Bool.accepts(&ColumnValue::bool(true)));
+    }
+
+    #[test]
+    fn column_type_does_not_accept_bool_column_value_for_int() {
+        assert!(!ColumnType::Int.accepts(&ColumnValue::bool(true)));
+        assert!(!ColumnType::Bool.accepts(&ColumnValue::int(1)));
+    }
+
+    #[test]
+    fn display_column_types() {
+        assert_eq!("int", ColumnType::Int.to_string());
+        assert_eq!("text", ColumnType::Text.to_string());
+        assert_eq!("bool", ColumnType::Bool.to_string());
+    }
 }
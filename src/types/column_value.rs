@@ -1,4 +1,6 @@
 use crate::types::column_type::ColumnType;
+use crate::types::date::Date;
+use crate::types::error::ConversionError;
 
 /// Represents the value stored in a column.
 ///
@@ -6,16 +8,57 @@ use crate::types::column_type::ColumnType;
 ///
 /// ```
 /// use relop::types::column_value::ColumnValue;
+/// use relop::types::date::Date;
 ///
 /// let int_val = ColumnValue::int(42);
 /// let text_val = ColumnValue::text("hello");
+/// let bool_val = ColumnValue::bool(true);
+/// let date_val = ColumnValue::date(Date::new(2024, 1, 15).unwrap());
+/// let null_val = ColumnValue::null();
 /// ```
-#[derive(Debug, PartialEq, Hash, Eq, Clone, PartialOrd, Ord)]
+///
+/// # Ordering
+///
+/// Column values have a total order. Values of the same type are in their natural order
+/// (numeric for `Int`, lexicographic by bytes for `Text`, `false` before `true` for `Bool`
+/// and chronological for `Date`). Values of different types are ordered by the rank of their
+/// type: `Int` < `Text` < `Bool` < `Date` < `Null`.
+///
+/// ```
+/// use relop::types::column_value::ColumnValue;
+///
+/// assert!(ColumnValue::int(2) < ColumnValue::int(10));
+/// assert!(ColumnValue::text("10") < ColumnValue::text("2"));
+/// assert!(ColumnValue::int(100) < ColumnValue::text("1"));
+/// assert!(ColumnValue::bool(true) < ColumnValue::null());
+/// ```
+///
+/// # Conversions
+///
+/// A value converts into the Rust value of its type (`i64`, `String`, `bool` or `Date`) with
+/// `TryFrom`, failing with a `ConversionError` for a value of another type or a `Null` value.
+///
+/// ```
+/// use relop::types::column_value::ColumnValue;
+///
+/// let id: i64 = ColumnValue::int(42).try_into().unwrap();
+/// assert_eq!(42, id);
+///
+/// let name: Result<String, _> = ColumnValue::null().try_into();
+/// assert!(name.is_err());
+/// ```
+#[derive(Debug, PartialEq, Hash, Eq, Clone)]
 pub enum ColumnValue {
     /// Integer 64-bit value.
     Int(i64),
     /// String value.
     Text(String),
+    /// Boolean value, `false` orders before `true`.
+    Bool(bool),
+    /// Calendar date value, ordered chronologically.
+    Date(Date),
+    /// The absence of a value; accepted by every column type.
+    Null,
 }
 
 impl ColumnValue {
@@ -47,6 +90,64 @@ impl ColumnValue {
         ColumnValue::Text(value.into())
     }
 
+    /// Creates a new `ColumnValue::Bool` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let val = ColumnValue::bool(true);
+    /// assert_eq!(val.bool_value(), Some(true));
+    /// ```
+    pub fn bool(value: bool) -> Self {
+        ColumnValue::Bool(value)
+    }
+
+    /// Creates a new `ColumnValue::Date` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::types::column_value::ColumnValue;
+    /// use relop::types::date::Date;
+    ///
+    /// let date = Date::new(2024, 1, 15).unwrap();
+    /// let val = ColumnValue::date(date);
+    /// assert_eq!(val.date_value(), Some(date));
+    /// ```
+    pub fn date(value: Date) -> Self {
+        ColumnValue::Date(value)
+    }
+
+    /// Creates a new `ColumnValue::Null` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let val = ColumnValue::null();
+    /// assert!(val.is_null());
+    /// ```
+    pub fn null() -> Self {
+        ColumnValue::Null
+    }
+
+    /// Returns `true` if this is the `Null` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// assert!(ColumnValue::null().is_null());
+    /// assert!(!ColumnValue::int(42).is_null());
+    /// ```
+    pub fn is_null(&self) -> bool {
+        matches!(self, ColumnValue::Null)
+    }
+
     /// Extracts the integer value if this is an `Int` variant.
     ///
     /// # Examples
@@ -87,8 +188,57 @@ impl ColumnValue {
         None
     }
 
+    /// Extracts the boolean value if this is a `Bool` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::types::column_value::ColumnValue;
+    ///
+    /// let val = ColumnValue::bool(false);
+    /// assert_eq!(val.bool_value(), Some(false));
+    ///
+    /// let int = ColumnValue::int(42);
+    /// assert_eq!(int.bool_value(), None);
+    /// ```
+    pub fn bool_value(&self) -> Option<bool> {
+        if let ColumnValue::Bool(value) = self {
+            return Some(*value);
+        }
+        None
+    }
+
+    /// Extracts the date if this is a `Date` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::types::column_value::ColumnValue;
+    /// use relop::types::date::Date;
+    ///
+    /// let date = Date::new(2024, 1, 15).unwrap();
+    /// assert_eq!(ColumnValue::date(date).date_value(), Some(date));
+    /// assert_eq!(ColumnValue::int(42).date_value(), None);
+    /// ```
+    pub fn date_value(&self) -> Option<Date> {
+        if let ColumnValue::Date(value) = self {
+            return Some(*value);
+        }
+        None
+    }
+
+    /// Returns the error for converting this value into a Rust value of the `expected` type.
+    fn conversion_error(&self, expected: ColumnType) -> ConversionError {
+        match self.column_type() {
+            Some(actual) => ConversionError::TypeMismatch { expected, actual },
+            None => ConversionError::NullValue { expected },
+        }
+    }
+
     /// Returns the corresponding [`ColumnType`] for this value.
     ///
+    /// Returns `None` for `Null`, which does not carry a type of its own.
+    ///
     /// # Examples
     ///
     /// ```
@@ -96,16 +246,51 @@ impl ColumnValue {
     /// use relop::types::column_type::ColumnType;
     ///
     /// let val = ColumnValue::int(42);
-    /// assert_eq!(val.column_type(), ColumnType::Int);
+    /// assert_eq!(val.column_type(), Some(ColumnType::Int));
+    /// assert_eq!(ColumnValue::null().column_type(), None);
     /// ```
-    pub fn column_type(&self) -> ColumnType {
+    pub fn column_type(&self) -> Option<ColumnType> {
+        match self {
+            ColumnValue::Int(_) => Some(ColumnType::Int),
+            ColumnValue::Text(_) => Some(ColumnType::Text),
+            ColumnValue::Bool(_) => Some(ColumnType::Bool),
+            ColumnValue::Date(_) => Some(ColumnType::Date),
+            ColumnValue::Null => None,
+        }
+    }
+}
+
+impl ColumnValue {
+    /// Returns the rank of the type of the value, which orders values of different types.
+    fn type_rank(&self) -> u8 {
         match self {
-            ColumnValue::Int(_) => ColumnType::Int,
-            ColumnValue::Text(_) => ColumnType::Text,
+            ColumnValue::Int(_) => 0,
+            ColumnValue::Text(_) => 1,
+            ColumnValue::Bool(_) => 2,
+            ColumnValue::Date(_) => 3,
+            ColumnValue::Null => 4,
+        }
+    }
+}
+
+impl Ord for ColumnValue {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (ColumnValue::Int(left), ColumnValue::Int(right)) => left.cmp(right),
+            (ColumnValue::Text(left), ColumnValue::Text(right)) => left.cmp(right),
+            (ColumnValue::Bool(left), ColumnValue::Bool(right)) => left.cmp(right),
+            (ColumnValue::Date(left), ColumnValue::Date(right)) => left.cmp(right),
+            _ => self.type_rank().cmp(&other.type_rank()),
         }
     }
 }
 
+impl PartialOrd for ColumnValue {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl From<i64> for ColumnValue {
     fn from(value: i64) -> Self {
         ColumnValue::int(value)
@@ -130,6 +315,88 @@ impl From<String> for ColumnValue {
     }
 }
 
+impl From<bool> for ColumnValue {
+    fn from(value: bool) -> Self {
+        ColumnValue::bool(value)
+    }
+}
+
+impl TryFrom<&ColumnValue> for i64 {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnValue) -> Result<Self, Self::Error> {
+        value
+            .int_value()
+            .ok_or_else(|| value.conversion_error(ColumnType::Int))
+    }
+}
+
+impl TryFrom<ColumnValue> for i64 {
+    type Error = ConversionError;
+
+    fn try_from(value: ColumnValue) -> Result<Self, Self::Error> {
+        i64::try_from(&value)
+    }
+}
+
+impl TryFrom<&ColumnValue> for String {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnValue) -> Result<Self, Self::Error> {
+        value
+            .text_value()
+            .map(|text| text.to_string())
+            .ok_or_else(|| value.conversion_error(ColumnType::Text))
+    }
+}
+
+impl TryFrom<ColumnValue> for String {
+    type Error = ConversionError;
+
+    fn try_from(value: ColumnValue) -> Result<Self, Self::Error> {
+        match value {
+            ColumnValue::Text(text) => Ok(text),
+            other => Err(other.conversion_error(ColumnType::Text)),
+        }
+    }
+}
+
+impl TryFrom<&ColumnValue> for bool {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnValue) -> Result<Self, Self::Error> {
+        value
+            .bool_value()
+            .ok_or_else(|| value.conversion_error(ColumnType::Bool))
+    }
+}
+
+impl TryFrom<ColumnValue> for bool {
+    type Error = ConversionError;
+
+    fn try_from(value: ColumnValue) -> Result<Self, Self::Error> {
+        bool::try_from(&value)
+    }
+}
+
+impl TryFrom<&ColumnValue> for Date {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnValue) -> Result<Self, Self::Error> {
+        value
+            .date_value()
+            .ok_or_else(|| value.conversion_error(ColumnType::Date))
+    }
+}
+
+impl TryFrom<ColumnValue> for Date {
+    type Error = ConversionError;
+
+    fn try_from(value: ColumnValue) -> Result<Self, Self::Error> {
+        Date::try_from(&value)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -158,6 +425,63 @@ mod test {
         assert_eq!(value, ColumnValue::text("relop"));
     }
 
+    #[test]
+    fn create_bool_value_from_bool() {
+        let value: ColumnValue = true.into();
+        assert_eq!(value, ColumnValue::bool(true));
+    }
+
+    #[test]
+    fn false_orders_before_true() {
+        assert!(ColumnValue::bool(false) < ColumnValue::bool(true));
+    }
+
+    #[test]
+    fn order_int_values_numerically() {
+        assert!(ColumnValue::int(-5) < ColumnValue::int(2));
+        assert!(ColumnValue::int(2) < ColumnValue::int(10));
+        assert_eq!(
+            std::cmp::Ordering::Equal,
+            ColumnValue::int(7).cmp(&ColumnValue::int(7))
+        );
+    }
+
+    #[test]
+    fn order_text_values_lexicographically() {
+        assert!(ColumnValue::text("query") < ColumnValue::text("relop"));
+        assert!(ColumnValue::text("10") < ColumnValue::text("2"));
+        assert!(ColumnValue::text("Relop") < ColumnValue::text("relop"));
+    }
+
+    #[test]
+    fn int_values_order_before_text_values() {
+        assert!(ColumnValue::int(i64::MAX) < ColumnValue::text(""));
+        assert!(ColumnValue::text("1") > ColumnValue::int(100));
+    }
+
+    #[test]
+    fn order_values_of_different_types_by_type_rank() {
+        let mut values = vec![
+            ColumnValue::null(),
+            ColumnValue::date(Date::new(2024, 1, 15).unwrap()),
+            ColumnValue::bool(false),
+            ColumnValue::text("relop"),
+            ColumnValue::int(1),
+        ];
+        values.sort();
+
+        assert_eq!(
+            vec![
+                ColumnValue::int(1),
+                ColumnValue::text("relop"),
+                ColumnValue::bool(false),
+                ColumnValue::date(Date::new(2024, 1, 15).unwrap()),
+                ColumnValue::null(),
+            ],
+            values
+        );
+    }
+
     #[test]
     fn create_int_value() {
         let column_value = ColumnValue::int(100);
@@ -185,12 +509,137 @@ mod test {
     #[test]
     fn get_column_type_as_int() {
         let column_value = ColumnValue::int(100);
-        assert_eq!(column_value.column_type(), ColumnType::Int);
+        assert_eq!(column_value.column_type(), Some(ColumnType::Int));
     }
 
     #[test]
     fn get_column_type_as_text() {
         let column_value = ColumnValue::text("relop");
-        assert_eq!(column_value.column_type(), ColumnType::Text);
+        assert_eq!(column_value.column_type(), Some(ColumnType::Text));
+    }
+
+    #[test]
+    fn null_has_no_column_type() {
+        let column_value = ColumnValue::null();
+        assert_eq!(column_value.column_type(), None);
+    }
+
+    #[test]
+    fn null_value_is_null() {
+        assert!(ColumnValue::null().is_null());
+    }
+
+    #[test]
+    fn non_null_values_are_not_null() {
+        assert!(!ColumnValue::int(100).is_null());
+        assert!(!ColumnValue::text("relop").is_null());
+    }
+
+    #[test]
+    fn convert_int_value_into_i64() {
+        let id: i64 = ColumnValue::int(100).try_into().unwrap();
+        assert_eq!(100, id);
+
+        let id: i64 = (&ColumnValue::int(100)).try_into().unwrap();
+        assert_eq!(100, id);
+    }
+
+    #[test]
+    fn convert_text_value_into_string() {
+        let name: String = ColumnValue::text("relop").try_into().unwrap();
+        assert_eq!("relop", name);
+
+        let name: String = (&ColumnValue::text("relop")).try_into().unwrap();
+        assert_eq!("relop", name);
+    }
+
+    #[test]
+    fn convert_bool_value_into_bool() {
+        let active: bool = ColumnValue::bool(true).try_into().unwrap();
+        assert!(active);
+
+        let active: bool = (&ColumnValue::bool(false)).try_into().unwrap();
+        assert!(!active);
+    }
+
+    #[test]
+    fn convert_date_value_into_date() {
+        let date = Date::new(2024, 1, 15).unwrap();
+
+        let converted: Date = ColumnValue::date(date).try_into().unwrap();
+        assert_eq!(date, converted);
+
+        let converted: Date = (&ColumnValue::date(date)).try_into().unwrap();
+        assert_eq!(date, converted);
+    }
+
+    #[test]
+    fn attempt_to_convert_text_value_into_i64() {
+        let result: Result<i64, _> = ColumnValue::text("relop").try_into();
+        assert_eq!(
+            Err(ConversionError::TypeMismatch {
+                expected: ColumnType::Int,
+                actual: ColumnType::Text,
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn attempt_to_convert_int_value_into_string() {
+        let result: Result<String, _> = (&ColumnValue::int(100)).try_into();
+        assert_eq!(
+            Err(ConversionError::TypeMismatch {
+                expected: ColumnType::Text,
+                actual: ColumnType::Int,
+            }),
+            result
+        );
+
+        let result: Result<String, _> = ColumnValue::int(100).try_into();
+        assert!(matches!(result, Err(ConversionError::TypeMismatch { .. })));
+    }
+
+    #[test]
+    fn attempt_to_convert_int_value_into_bool() {
+        let result: Result<bool, _> = ColumnValue::int(1).try_into();
+        assert_eq!(
+            Err(ConversionError::TypeMismatch {
+                expected: ColumnType::Bool,
+                actual: ColumnType::Int,
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn attempt_to_convert_text_value_into_date() {
+        let result: Result<Date, _> = ColumnValue::text("2024-01-15").try_into();
+        assert_eq!(
+            Err(ConversionError::TypeMismatch {
+                expected: ColumnType::Date,
+                actual: ColumnType::Text,
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn attempt_to_convert_null_value() {
+        let result: Result<i64, _> = ColumnValue::null().try_into();
+        assert_eq!(
+            Err(ConversionError::NullValue {
+                expected: ColumnType::Int
+            }),
+            result
+        );
+
+        let result: Result<String, _> = ColumnValue::null().try_into();
+        assert_eq!(
+            Err(ConversionError::NullValue {
+                expected: ColumnType::Text
+            }),
+            result
+        );
     }
 }
@@ -0,0 +1,149 @@
+/// Represents a calendar date (a year, a month and a day), without a time of day.
+///
+/// Dates are ordered chronologically, and are written in the ISO 8601 format (`YYYY-MM-DD`).
+///
+/// # Examples
+///
+/// ```
+/// use relop::types::date::Date;
+///
+/// let date = Date::parse("2024-01-15").unwrap();
+/// assert_eq!(Date::new(2024, 1, 15), Some(date));
+/// assert_eq!("2024-01-15", date.to_string());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new `Date`, returning `None` if the month or the day does not exist
+    /// (e.g., the 30th of February), or if the year is outside `0..=9999`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::types::date::Date;
+    ///
+    /// assert!(Date::new(2024, 2, 29).is_some());
+    /// assert!(Date::new(2023, 2, 29).is_none());
+    /// ```
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Date> {
+        if !(0..=9999).contains(&year) || !(1..=12).contains(&month) {
+            return None;
+        }
+        if day == 0 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// Parses a date written as `YYYY-MM-DD`, returning `None` if the text is not a valid date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use relop::types::date::Date;
+    ///
+    /// assert!(Date::parse("2024-01-15").is_some());
+    /// assert!(Date::parse("2024-13-01").is_none());
+    /// assert!(Date::parse("15/01/2024").is_none());
+    /// ```
+    pub fn parse(text: &str) -> Option<Date> {
+        let bytes = text.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return None;
+        }
+        let number = |range: std::ops::Range<usize>| {
+            let digits = &text[range];
+            if digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                digits.parse::<i32>().ok()
+            } else {
+                None
+            }
+        };
+        let year = number(0..4)?;
+        let month = number(5..7)?;
+        let day = number(8..10)?;
+        Date::new(year, month as u8, day as u8)
+    }
+
+    /// Returns the year of the date.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the date, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month of the date, from 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+/// Renders the date as `YYYY-MM-DD`.
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_date() {
+        let date = Date::parse("2024-01-15").unwrap();
+
+        assert_eq!(2024, date.year());
+        assert_eq!(1, date.month());
+        assert_eq!(15, date.day());
+    }
+
+    #[test]
+    fn parse_leap_day() {
+        assert!(Date::parse("2000-02-29").is_some());
+        assert!(Date::parse("1900-02-29").is_none());
+    }
+
+    #[test]
+    fn attempt_to_parse_invalid_dates() {
+        assert!(Date::parse("2024-04-31").is_none());
+        assert!(Date::parse("2024-00-10").is_none());
+        assert!(Date::parse("2024-1-15").is_none());
+        assert!(Date::parse("2024-01-+5").is_none());
+        assert!(Date::parse("relop").is_none());
+    }
+
+    #[test]
+    fn order_dates_chronologically() {
+        let dates = [
+            Date::parse("2023-12-31").unwrap(),
+            Date::parse("2024-01-15").unwrap(),
+            Date::parse("2024-02-01").unwrap(),
+        ];
+
+        assert!(dates[0] < dates[1]);
+        assert!(dates[1] < dates[2]);
+    }
+
+    #[test]
+    fn display_date() {
+        assert_eq!("0999-03-07", Date::new(999, 3, 7).unwrap().to_string());
+    }
+}
@@ -0,0 +1,41 @@
+use crate::types::column_type::ColumnType;
+
+/// Represents errors that occur while converting a `ColumnValue` into a Rust value.
+///
+/// # Examples
+///
+/// ```
+/// use relop::types::column_type::ColumnType;
+/// use relop::types::column_value::ColumnValue;
+/// use relop::types::error::ConversionError;
+///
+/// let id: Result<i64, ConversionError> = ColumnValue::text("relop").try_into();
+/// assert_eq!(
+///     Err(ConversionError::TypeMismatch {
+///         expected: ColumnType::Int,
+///         actual: ColumnType::Text,
+///     }),
+///     id
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ConversionError {
+    /// The type of the value does not match the type being converted into.
+    TypeMismatch {
+        /// The type being converted into.
+        expected: ColumnType,
+        /// The type of the value.
+        actual: ColumnType,
+    },
+    /// The value is `Null`, which has no Rust value of the type being converted into.
+    NullValue {
+        /// The type being converted into.
+        expected: ColumnType,
+    },
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
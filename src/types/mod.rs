@@ -1,2 +1,4 @@
 pub mod column_type;
 pub mod column_value;
+pub mod date;
+pub mod error;